@@ -2,7 +2,10 @@
 
 use bevy::prelude::*;
 use crate::core::{
-    animation::{AnimationRegistry, HasAnimations, AnimationPlaybackState, AnimationClipInfo},
+    animation::{
+        AnimationRegistry, HasAnimations, AnimationPlaybackState, AnimationClipInfo,
+        RootMotionState, AnimationEventFired, AnimationBlend1d,
+    },
     entity_id::EntityId,
     pending_commands::PendingCommands,
 };
@@ -78,6 +81,8 @@ pub(super) fn build_animation_state(
 pub(super) struct AnimationGraphState {
     pub entity_id: String,
     pub nodes: Vec<AnimationNodeState>,
+    /// Active 1D blend space, if any; the node weights reflect its value.
+    pub blend_1d: Option<AnimationBlend1d>,
 }
 
 #[derive(serde::Serialize)]
@@ -94,6 +99,7 @@ pub(super) fn build_animation_graph_state(
     entity_id: &str,
     entry: &crate::core::animation::EntityAnimationData,
     player: &AnimationPlayer,
+    blend_1d: Option<&AnimationBlend1d>,
 ) -> AnimationGraphState {
     let mut nodes = Vec::new();
     for (name, (node_index, _duration)) in &entry.clips {
@@ -114,6 +120,7 @@ pub(super) fn build_animation_graph_state(
     AnimationGraphState {
         entity_id: entity_id.to_string(),
         nodes,
+        blend_1d: blend_1d.cloned(),
     }
 }
 
@@ -232,6 +239,7 @@ pub(super) fn register_gltf_animations(
             clip_names: final_names.clone(),
             player_entity,
             graph_handle,
+            events: std::collections::HashMap::new(),
         };
         animation_registry.entries.insert(entity_id_str.clone(), entry);
 
@@ -267,10 +275,15 @@ pub(super) fn register_gltf_animations(
 }
 
 /// System that applies pending animation requests to AnimationPlayer components.
+/// Plays with `root_motion` attach a `RootMotionState` to the top-level entity;
+/// any other play or a stop removes it. Plays and stops also end a 1D blend.
 pub(super) fn apply_animation_requests(
     mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
     animation_registry: Res<AnimationRegistry>,
     mut player_query: Query<(&mut AnimationPlayer, Option<&mut AnimationTransitions>)>,
+    entity_query: Query<(Entity, &EntityId)>,
+    mut blend_query: Query<&mut AnimationBlend1d>,
 ) {
     use crate::core::pending_commands::AnimationAction;
 
@@ -292,8 +305,16 @@ pub(super) fn apply_animation_requests(
         };
 
         match request.action {
-            AnimationAction::Play { clip_name, crossfade_secs } => {
+            AnimationAction::Play { clip_name, crossfade_secs, root_motion } => {
                 if let Some((node_index, _duration)) = entry.clips.get(&clip_name) {
+                    if let Some((entity, _)) = entity_query.iter().find(|(_, eid)| eid.0 == request.entity_id) {
+                        commands.entity(entity).remove::<AnimationBlend1d>();
+                        if root_motion {
+                            commands.entity(entity).insert(RootMotionState::new(entry.player_entity, *node_index));
+                        } else {
+                            commands.entity(entity).remove::<RootMotionState>();
+                        }
+                    }
                     if crossfade_secs > 0.0 {
                         if let Some(transitions) = transitions_opt.as_mut() {
                             transitions.play(
@@ -319,6 +340,9 @@ pub(super) fn apply_animation_requests(
             }
             AnimationAction::Stop => {
                 player.stop_all();
+                if let Some((entity, _)) = entity_query.iter().find(|(_, eid)| eid.0 == request.entity_id) {
+                    commands.entity(entity).remove::<(RootMotionState, AnimationBlend1d)>();
+                }
             }
             AnimationAction::Seek { time_secs } => {
                 for (_name, (node_index, _)) in &entry.clips {
@@ -366,10 +390,59 @@ pub(super) fn apply_animation_requests(
                     tracing::warn!("Unknown clip '{}' for entity: {}", clip_name, request.entity_id);
                 }
             }
+            AnimationAction::SetBlend1d { points } => {
+                if let Some(unknown) = points.iter().find(|p| !entry.clips.contains_key(&p.clip_name)) {
+                    tracing::warn!("Unknown clip '{}' for entity: {}", unknown.clip_name, request.entity_id);
+                    continue;
+                }
+                let Some((entity, _)) = entity_query.iter().find(|(_, eid)| eid.0 == request.entity_id) else {
+                    continue;
+                };
+                player.stop_all();
+                if points.is_empty() {
+                    commands.entity(entity).remove::<AnimationBlend1d>();
+                } else {
+                    commands.entity(entity).insert(AnimationBlend1d::new(points));
+                }
+            }
+            AnimationAction::SetBlend1dValue { value } => {
+                let blend = entity_query.iter()
+                    .find(|(_, eid)| eid.0 == request.entity_id)
+                    .and_then(|(entity, _)| blend_query.get_mut(entity).ok());
+                match blend {
+                    Some(mut blend) => blend.value = value,
+                    None => tracing::warn!("No 1D blend space on entity: {}", request.entity_id),
+                }
+            }
         }
     }
 }
 
+/// System that registers pending event markers on their clips.
+pub(super) fn apply_animation_event_adds(
+    mut pending: ResMut<PendingCommands>,
+    mut animation_registry: ResMut<AnimationRegistry>,
+) {
+    for add in pending.animation_event_adds.drain(..) {
+        let Some(entry) = animation_registry.entries.get_mut(&add.entity_id) else {
+            tracing::warn!("No animation data for entity: {}", add.entity_id);
+            continue;
+        };
+        if !entry.clips.contains_key(&add.clip_name) {
+            tracing::warn!("Clip '{}' not found on entity: {}", add.clip_name, add.entity_id);
+            continue;
+        }
+        entry.add_event(&add.clip_name, add.event);
+    }
+}
+
+/// Forward fired animation event markers to JS.
+pub(super) fn emit_animation_events(mut fired: MessageReader<AnimationEventFired>) {
+    for event in fired.read() {
+        events::emit_animation_event(event);
+    }
+}
+
 /// Emit animation state when selection changes.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn emit_animation_on_selection(
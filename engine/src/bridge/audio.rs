@@ -4,7 +4,7 @@ use bevy::prelude::*;
 use crate::core::{
     entity_id::EntityId,
     audio::{AudioData, AudioEnabled, AudioBusConfig},
-    reverb_zone::{ReverbZoneData, ReverbZoneEnabled},
+    reverb_zone::{ReverbZoneData, ReverbZoneEnabled, ReverbZoneTransition},
     selection::{Selection, SelectionChangedEvent},
     pending_commands::PendingCommands,
     history::{HistoryStack, UndoableAction},
@@ -110,18 +110,25 @@ pub(super) fn apply_audio_bus_updates(
     mut bus_config: ResMut<AudioBusConfig>,
 ) {
     for update in pending.audio_bus_updates.drain(..) {
-        if let Some(bus) = bus_config.buses.iter_mut().find(|b| b.name == update.bus_name) {
-            if let Some(v) = update.volume {
-                bus.volume = v.clamp(0.0, 1.0);
-            }
-            if let Some(v) = update.muted {
-                bus.muted = v;
-            }
-            if let Some(v) = update.soloed {
-                bus.soloed = v;
+        let Some(bus) = bus_config.buses.iter_mut().find(|b| b.name == update.bus_name) else {
+            continue;
+        };
+        if let Some(v) = update.volume {
+            bus.volume = v.clamp(0.0, 1.0);
+        }
+        if let Some(v) = update.muted {
+            bus.muted = v;
+        }
+        if let Some(v) = update.soloed {
+            bus.soloed = v;
+        }
+        // Routing changes that would form a cycle are rejected; other fields still apply
+        if let Some(parent) = update.parent {
+            if let Err(e) = bus_config.set_parent(&update.bus_name, Some(parent)) {
+                tracing::warn!("Rejected audio bus routing: {}", e);
             }
-            events::emit_audio_buses_changed(&bus_config);
         }
+        events::emit_audio_buses_changed(&bus_config);
     }
 }
 
@@ -131,17 +138,19 @@ pub(super) fn apply_audio_bus_creates(
     mut bus_config: ResMut<AudioBusConfig>,
 ) {
     for create in pending.audio_bus_creates.drain(..) {
-        // Prevent duplicates
-        if bus_config.buses.iter().any(|b| b.name == create.name) {
-            continue;
-        }
-        bus_config.buses.push(crate::core::audio::AudioBusDef {
+        // Rejects duplicates, unknown parents, and cycles
+        let result = bus_config.create_bus(crate::core::audio::AudioBusDef {
             name: create.name,
             volume: create.volume.clamp(0.0, 1.0),
             muted: create.muted,
             soloed: create.soloed,
             effects: vec![],
+            parent: create.parent,
         });
+        if let Err(e) = result {
+            tracing::warn!("Rejected audio bus creation: {}", e);
+            continue;
+        }
         events::emit_audio_buses_changed(&bus_config);
     }
 }
@@ -152,11 +161,10 @@ pub(super) fn apply_audio_bus_deletes(
     mut bus_config: ResMut<AudioBusConfig>,
 ) {
     for delete in pending.audio_bus_deletes.drain(..) {
-        if delete.bus_name == "master" {
-            continue; // Cannot delete master
+        // Master cannot be deleted; children move up to the deleted bus's parent
+        if bus_config.delete_bus(&delete.bus_name) {
+            events::emit_audio_buses_changed(&bus_config);
         }
-        bus_config.buses.retain(|b| b.name != delete.bus_name);
-        events::emit_audio_buses_changed(&bus_config);
     }
 }
 
@@ -269,6 +277,46 @@ pub(super) fn apply_reverb_zone_toggles(
     }
 }
 
+/// System that applies pending reverb zone removals (always-active).
+pub(super) fn apply_reverb_zone_removals(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: Query<(Entity, &EntityId, Option<&ReverbZoneData>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for removal in pending.reverb_zone_removals.drain(..) {
+        for (entity, entity_id, current_reverb_zone) in query.iter() {
+            if entity_id.0 == removal.entity_id {
+                // Remove reverb zone components
+                commands.entity(entity)
+                    .remove::<ReverbZoneData>()
+                    .remove::<ReverbZoneEnabled>();
+
+                // Record for undo
+                history.push(UndoableAction::ReverbZoneChange {
+                    entity_id: removal.entity_id.clone(),
+                    old_reverb: current_reverb_zone.cloned(),
+                    new_reverb: None,
+                });
+
+                // Emit removal event
+                events::emit_reverb_zone_removed(&removal.entity_id);
+                break;
+            }
+        }
+    }
+}
+
+/// System that forwards listener reverb zone crossings to JS (always-active;
+/// transitions only fire in Play mode or when returning to Edit).
+pub(super) fn emit_reverb_zone_transitions(
+    mut transitions: MessageReader<ReverbZoneTransition>,
+) {
+    for transition in transitions.read() {
+        events::emit_reverb_zone_transition(&transition.entity_id, &transition.data, transition.entered);
+    }
+}
+
 /// Editor-only: emit reverb zone data when entity is selected.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn emit_reverb_zone_on_selection(
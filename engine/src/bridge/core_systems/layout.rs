@@ -0,0 +1,120 @@
+//! Align and distribute systems (editor-only).
+
+use bevy::prelude::*;
+
+use crate::core::entity_id::EntityId;
+use crate::core::align::{self, LayoutAxis};
+use crate::core::selection::Selection;
+use crate::core::history::HistoryStack;
+use crate::core::pending_commands::PendingCommands;
+use crate::core::{entity_factory, history};
+
+use crate::bridge::events;
+
+/// System that applies pending align/distribute requests to the selected
+/// entities. Each request is a single undo step; locked entities stay put.
+pub(crate) fn apply_layout_requests(
+    mut pending: ResMut<PendingCommands>,
+    selection: Res<Selection>,
+    mut query: Query<(&EntityId, &mut Transform), Without<entity_factory::Locked>>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for request in pending.align_requests.drain(..) {
+        layout_selection(&selection, &mut query, &mut history, request.axis, |values| {
+            align::align_target(values, request.mode)
+                .map(|target| vec![target; values.len()])
+                .unwrap_or_default()
+        });
+    }
+    for request in pending.distribute_requests.drain(..) {
+        layout_selection(&selection, &mut query, &mut history, request.axis, |values| {
+            align::distribute_values(values, request.spacing)
+        });
+    }
+}
+
+/// Move the selected entities along `axis` to the coordinates `layout`
+/// computes from their current ones, recording the moves as one history
+/// entry and emitting the new transforms.
+fn layout_selection(
+    selection: &Selection,
+    query: &mut Query<(&EntityId, &mut Transform), Without<entity_factory::Locked>>,
+    history: &mut HistoryStack,
+    axis: LayoutAxis,
+    layout: impl FnOnce(&[f32]) -> Vec<f32>,
+) {
+    let mut entities: Vec<Entity> = selection.entities.iter().copied().filter(|&e| query.contains(e)).collect();
+    if entities.len() < 2 {
+        return;
+    }
+    entities.sort();
+
+    let values: Vec<f32> = entities.iter()
+        .filter_map(|&e| query.get(e).ok())
+        .map(|(_, transform)| axis.get(transform.translation))
+        .collect();
+    let mut transforms = Vec::new();
+    for (&entity, value) in entities.iter().zip(layout(&values)) {
+        let Ok((entity_id, mut transform)) = query.get_mut(entity) else {
+            continue;
+        };
+        if axis.get(transform.translation) == value {
+            continue;
+        }
+        let old = history::TransformSnapshot::from(&*transform);
+        axis.set(&mut transform.translation, value);
+        transforms.push((entity_id.0.clone(), old, history::TransformSnapshot::from(&*transform)));
+
+        let (rx, ry, rz) = transform.rotation.to_euler(bevy::math::EulerRot::XYZ);
+        events::emit_event("TRANSFORM_CHANGED", &crate::core::gizmo::TransformPayload {
+            entity_id: entity_id.0.clone(),
+            position: transform.translation.to_array(),
+            rotation: [rx, ry, rz],
+            scale: transform.scale.to_array(),
+        });
+    }
+    if !transforms.is_empty() {
+        history.push(history::UndoableAction::MultiTransformChange { transforms });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::align::AlignMode;
+    use crate::core::pending::AlignEntitiesRequest;
+
+    #[test]
+    fn aligning_three_cubes_to_center_x_shares_one_x() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<Selection>()
+            .init_resource::<HistoryStack>()
+            .add_systems(Update, apply_layout_requests);
+        let cubes: Vec<Entity> = [(-4.0, 1.0), (0.5, 2.0), (2.0, 3.0)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y))| {
+                let id = format!("cube-{i}");
+                let entity = app.world_mut().spawn((EntityId::new(id.clone()), Transform::from_xyz(x, y, 0.0))).id();
+                app.world_mut().resource_mut::<Selection>().add(entity, id);
+                entity
+            })
+            .collect();
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_align_entities(AlignEntitiesRequest {
+            axis: LayoutAxis::X,
+            mode: AlignMode::Center,
+        });
+        let emitted = events::capture_events(|| app.update());
+
+        for (i, &cube) in cubes.iter().enumerate() {
+            let translation = app.world().get::<Transform>(cube).unwrap().translation;
+            assert_eq!(translation.x, -1.0);
+            assert_eq!(translation.y, i as f32 + 1.0);
+        }
+        assert_eq!(emitted.iter().filter(|(t, _)| t == "TRANSFORM_CHANGED").count(), 3);
+        assert_eq!(app.world().resource::<HistoryStack>().undo_description().as_deref(), Some("Transform 3 objects"));
+    }
+}
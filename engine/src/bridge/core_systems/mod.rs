@@ -0,0 +1,722 @@
+//! Core editor systems for mode changes, transforms, renaming, and snap settings.
+
+use bevy::prelude::*;
+use bevy::ecs::system::ParamSet;
+
+use crate::core::entity_id::{EntityId, EntityName, EntityTags, EntityVisible};
+use crate::core::gizmo::{ActiveGizmoMode, LastMoveDelta};
+use crate::core::pending::EntityType;
+use crate::core::selection::{Selection, SelectionChangedEvent};
+use crate::core::render_mode::RenderMode;
+use crate::core::viewport::ViewportLayout;
+use crate::core::scene_graph::SceneGraphCache;
+use crate::core::history::HistoryStack;
+use crate::core::material::MaterialData;
+use crate::core::lighting::LightData;
+use crate::core::physics::{PhysicsData, PhysicsEnabled};
+use crate::core::scripting::ScriptData;
+use crate::core::audio::AudioData;
+use crate::core::engine_mode::{EngineMode, SceneSnapshot, ModeChangeRequest};
+use crate::core::snap::SnapSettings;
+use crate::core::pending_commands::PendingCommands;
+use crate::core::asset_manager::AssetRef;
+use crate::core::reverb_zone::{ReverbZoneData, ReverbZoneEnabled};
+use crate::core::particles::{ParticleData, ParticleEnabled};
+use crate::core::shader_effects::ShaderEffectData;
+use crate::core::game_components::GameComponents;
+use crate::core::game_camera::{GameCameraData, ActiveGameCamera};
+use crate::core::physics_2d::{Physics2dData, Physics2dEnabled, PhysicsJoint2d};
+use crate::core::{entity_factory, history, pending_commands};
+
+use super::events;
+use super::log;
+
+#[cfg(not(feature = "runtime"))]
+mod layout;
+#[cfg(not(feature = "runtime"))]
+mod picking;
+
+#[cfg(not(feature = "runtime"))]
+pub(super) use layout::*;
+#[cfg(not(feature = "runtime"))]
+pub(super) use picking::*;
+
+/// System that processes mode change requests (play/stop/pause/resume).
+/// Uses ParamSet because snapshot_query (read) and restore_query (write)
+/// access overlapping components (Transform, EntityName, etc.).
+pub(super) fn apply_mode_change_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut mode: ResMut<EngineMode>,
+    mut snapshot: ResMut<SceneSnapshot>,
+    mut selection: ResMut<Selection>,
+    mut queries: ParamSet<(
+        Query<(
+            Entity,
+            &EntityId,
+            &EntityName,
+            &Transform,
+            &EntityVisible,
+            Option<&EntityType>,
+            Option<&MaterialData>,
+            Option<&LightData>,
+            Option<&PhysicsData>,
+            Option<&PhysicsEnabled>,
+            Option<&Mesh3d>,
+            Option<&PointLight>,
+            Option<&DirectionalLight>,
+            Option<&SpotLight>,
+            Option<&AssetRef>,
+        )>,
+        Query<(
+            Entity,
+            &EntityId,
+            &mut Transform,
+            &mut EntityName,
+            &mut EntityVisible,
+            Option<&mut MaterialData>,
+            Option<&mut LightData>,
+            Option<&mut PhysicsData>,
+        ), Without<entity_factory::Undeletable>>,
+    )>,
+    script_audio_query: Query<(&EntityId, Option<&ScriptData>, Option<&AudioData>)>,
+    reverb_particle_shader_query: Query<(&EntityId, Option<&ReverbZoneData>, Option<&ReverbZoneEnabled>, Option<&ParticleData>, Option<&ParticleEnabled>, Option<&ShaderEffectData>)>,
+    csg_sprite_physics2d_query: Query<(&EntityId, Option<&crate::core::csg::CsgMeshData>, Option<&crate::core::sprite::SpriteData>, Option<&Physics2dData>, Option<&Physics2dEnabled>, Option<&PhysicsJoint2d>)>,
+    procedural_joint_game_query: Query<(&EntityId, Option<&crate::core::procedural_mesh::ProceduralMeshData>, Option<&crate::core::physics::JointData>, Option<&GameComponents>, Option<&GameCameraData>, Option<&ActiveGameCamera>)>,
+    tilemap_skeleton2d_query: Query<(&EntityId, Option<&crate::core::tilemap::TilemapData>, Option<&crate::core::tilemap::TilemapEnabled>, Option<&crate::core::skeleton2d::SkeletonData2d>, Option<&crate::core::skeleton2d::SkeletonEnabled2d>, Option<&crate::core::skeletal_animation2d::SkeletalAnimation2d>, Option<&crate::core::lod::LodData>)>,
+    runtime_query: Query<Entity, With<crate::core::engine_mode::RuntimeEntity>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+) {
+    let requests: Vec<ModeChangeRequest> = pending.mode_change_requests.drain(..).collect();
+    for request in requests {
+        let current = *mode;
+        match request {
+            ModeChangeRequest::Play => {
+                if current != EngineMode::Edit { continue; }
+                // Snapshot the scene (uses read-only query p0)
+                {
+                    let snapshot_query = queries.p0();
+                    *snapshot = crate::core::engine_mode::snapshot_scene(&snapshot_query, &script_audio_query, &reverb_particle_shader_query, &csg_sprite_physics2d_query, &procedural_joint_game_query, &tilemap_skeleton2d_query, &selection);
+                }
+                // Clear selection for play mode
+                selection.clear();
+                selection_events.write(SelectionChangedEvent {
+                    selected_ids: vec![],
+                    primary_id: None,
+                    primary_name: None,
+                });
+                *mode = EngineMode::Play;
+                events::emit_engine_mode_changed(&mode);
+                log("Entered Play mode");
+            }
+            ModeChangeRequest::Stop => {
+                if current == EngineMode::Edit { continue; }
+                // Restore scene from snapshot (uses mutable query p1)
+                {
+                    let mut restore_query = queries.p1();
+                    crate::core::engine_mode::restore_scene(
+                        &mut commands,
+                        &snapshot,
+                        &mut restore_query,
+                        &runtime_query,
+                        &mut meshes,
+                        &mut materials,
+                    );
+                }
+                // Restore transform state for existing entities
+                {
+                    let mut restore_query = queries.p1();
+                    for snap in &snapshot.entities {
+                        for (_, eid, mut transform, mut ename, mut visible, mat_data, light_data, phys_data) in restore_query.iter_mut() {
+                            if eid.0 == snap.entity_id {
+                                *transform = snap.transform.to_transform();
+                                ename.0 = snap.name.clone();
+                                visible.0 = snap.visible;
+                                if let (Some(snap_mat), Some(mut current_mat)) = (&snap.material_data, mat_data) {
+                                    *current_mat = snap_mat.clone();
+                                }
+                                if let (Some(snap_light), Some(mut current_light)) = (&snap.light_data, light_data) {
+                                    *current_light = snap_light.clone();
+                                }
+                                if let (Some(snap_phys), Some(mut current_phys)) = (&snap.physics_data, phys_data) {
+                                    *current_phys = snap_phys.clone();
+                                }
+                                break;
+                            }
+                        }
+                    }
+                }
+                // Restore 2D physics components for existing entities.
+                // These are not in the main restore query (tuple limit), so handled separately.
+                {
+                    let restore_query = queries.p1();
+                    let entity_map: std::collections::HashMap<&str, Entity> = restore_query.iter()
+                        .map(|(entity, eid, ..)| (eid.0.as_str(), entity))
+                        .collect();
+                    for snap in &snapshot.entities {
+                        if let Some(&entity) = entity_map.get(snap.entity_id.as_str()) {
+                            if let Some(p2d) = &snap.physics2d_data {
+                                commands.entity(entity).insert(p2d.clone());
+                            } else {
+                                commands.entity(entity).remove::<Physics2dData>();
+                            }
+                            if snap.physics2d_enabled {
+                                commands.entity(entity).insert(Physics2dEnabled);
+                            } else {
+                                commands.entity(entity).remove::<Physics2dEnabled>();
+                            }
+                            if let Some(j2d) = &snap.joint2d_data {
+                                commands.entity(entity).insert(j2d.clone());
+                            } else {
+                                commands.entity(entity).remove::<PhysicsJoint2d>();
+                            }
+                        }
+                    }
+                }
+                *mode = EngineMode::Edit;
+                events::emit_engine_mode_changed(&mode);
+                log("Exited Play mode (scene restored)");
+            }
+            ModeChangeRequest::Pause => {
+                if current != EngineMode::Play { continue; }
+                *mode = EngineMode::Paused;
+                events::emit_engine_mode_changed(&mode);
+                log("Paused Play mode");
+            }
+            ModeChangeRequest::Resume => {
+                if current != EngineMode::Paused { continue; }
+                *mode = EngineMode::Play;
+                events::emit_engine_mode_changed(&mode);
+                log("Resumed Play mode");
+            }
+        }
+    }
+}
+
+/// System that applies pending set_event_throttle requests.
+pub(super) fn apply_event_throttle_requests(
+    mut pending: ResMut<PendingCommands>,
+) {
+    for request in pending.event_throttle_requests.drain(..) {
+        if let Err(e) = events::set_event_throttle(&request.event_type, request.max_hz) {
+            tracing::warn!("set_event_throttle: {}", e);
+        }
+    }
+}
+
+/// System that sends the throttled events due this frame. Runs last, after
+/// every system that may emit.
+pub(super) fn flush_throttled_events(time: Res<Time>) {
+    events::flush_throttled_events(time.elapsed_secs_f64());
+}
+
+/// System that emits scene graph changes when the graph is dirty: the full
+/// graph after a load or refresh, otherwise a patch of the changed nodes.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn emit_scene_graph_updates(
+    mut cache: ResMut<SceneGraphCache>,
+) {
+    if cache.dirty && events::has_event_callback() {
+        match &cache.emitted {
+            Some(previous) => {
+                let patch = crate::core::scene_graph::SceneGraphPatch::diff(previous, &cache.data);
+                if !patch.is_empty() {
+                    events::emit_scene_graph_patch(&patch);
+                }
+            }
+            None => events::emit_scene_graph_update(&cache.data),
+        }
+        cache.emitted = Some(cache.data.clone());
+        cache.dirty = false;
+    }
+}
+
+/// System that emits history state updates when history changes.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn emit_history_updates(
+    mut history: ResMut<HistoryStack>,
+) {
+    if history.dirty && events::has_event_callback() {
+        events::emit_history_changed(
+            history.can_undo(),
+            history.can_redo(),
+            history.undo_description(),
+            history.redo_description(),
+        );
+        history.dirty = false;
+    }
+}
+
+/// Startup system to register the PendingCommands resource for bridge access.
+pub(super) fn register_pending_commands_resource(
+    mut commands_res: ResMut<PendingCommands>,
+) {
+    let ptr = commands_res.as_mut() as *mut PendingCommands;
+    pending_commands::register_pending_commands(ptr);
+    log("PendingCommands resource registered for bridge access");
+}
+
+/// Startup system to register the HistoryStack resource for bridge access.
+pub(super) fn register_history_stack_resource(
+    mut history_res: ResMut<HistoryStack>,
+) {
+    let ptr = history_res.as_mut() as *mut HistoryStack;
+    history::register_history_stack(ptr);
+    log("HistoryStack resource registered for bridge access");
+}
+
+/// System that applies pending transform updates from the bridge.
+/// Batched updates are applied in one pass over the query and recorded as a
+/// single history entry so a multi-entity drag undoes together. Locked
+/// entities are left where they are.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_pending_transforms(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut Transform), Without<entity_factory::Locked>>,
+    mut history: ResMut<HistoryStack>,
+    mut last_move: ResMut<LastMoveDelta>,
+) {
+    for batch in pending.transform_batch_updates.drain(..) {
+        let transforms = history::apply_transform_batch(&batch.updates, query.iter_mut());
+        last_move.record_changes(&transforms);
+        if !transforms.is_empty() {
+            history.push(history::UndoableAction::MultiTransformChange { transforms });
+        }
+    }
+
+    for update in pending.transform_updates.drain(..) {
+        for (entity_id, mut transform) in query.iter_mut() {
+            if entity_id.0 == update.entity_id {
+                if let Some(pos) = update.position {
+                    last_move.record(pos - transform.translation);
+                    transform.translation = pos;
+                }
+                if let Some(rot) = update.rotation {
+                    transform.rotation = rot;
+                }
+                if let Some(scale) = update.scale {
+                    transform.scale = scale;
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies pending rename requests from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_pending_renames(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut EntityName)>,
+    mut cache: ResMut<SceneGraphCache>,
+) {
+    for request in pending.rename_requests.drain(..) {
+        for (entity_id, mut name) in query.iter_mut() {
+            if entity_id.0 == request.entity_id {
+                name.0 = request.new_name.clone();
+                // Mark scene graph as dirty so it emits an update
+                cache.dirty = true;
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies pending snap settings changes from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_pending_snap_settings(
+    mut pending: ResMut<PendingCommands>,
+    mut snap_settings: ResMut<SnapSettings>,
+) {
+    for update in pending.snap_settings_updates.drain(..) {
+        if let Some(v) = update.translation_snap {
+            snap_settings.set_translation_snap(v);
+        }
+        if let Some(v) = update.translation_snap_x {
+            snap_settings.translation_snap_x = v;
+        }
+        if let Some(v) = update.translation_snap_y {
+            snap_settings.translation_snap_y = v;
+        }
+        if let Some(v) = update.translation_snap_z {
+            snap_settings.translation_snap_z = v;
+        }
+        if let Some(v) = update.rotation_snap_degrees {
+            snap_settings.rotation_snap_degrees = v;
+        }
+        if let Some(v) = update.scale_snap {
+            snap_settings.scale_snap = v;
+        }
+        if let Some(v) = update.grid_visible {
+            snap_settings.grid_visible = v;
+        }
+        if let Some(v) = update.grid_size {
+            snap_settings.grid_size = v;
+        }
+        if let Some(v) = update.grid_extent {
+            snap_settings.grid_extent = v;
+        }
+        if let Some(v) = update.vertex_snap {
+            snap_settings.vertex_snap = v;
+        }
+        if let Some(v) = update.surface_snap {
+            snap_settings.surface_snap = v;
+        }
+
+        // Emit event to React
+        events::emit_snap_settings_changed(&snap_settings);
+    }
+
+    for _ in pending.grid_toggles.drain(..) {
+        snap_settings.grid_visible = !snap_settings.grid_visible;
+        events::emit_snap_settings_changed(&snap_settings);
+    }
+}
+
+/// System that applies pending visibility requests from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_pending_visibility(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut EntityVisible)>,
+) {
+    for request in pending.visibility_requests.drain(..) {
+        for (entity_id, mut entity_visible) in query.iter_mut() {
+            if entity_id.0 == request.entity_id {
+                entity_visible.0 = request.visible;
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies pending set_entity_locked requests from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_entity_lock_requests(
+    mut commands: Commands,
+    mut pending: ResMut<PendingCommands>,
+    query: Query<(Entity, &EntityId)>,
+    mut cache: ResMut<SceneGraphCache>,
+) {
+    for request in pending.entity_lock_requests.drain(..) {
+        let Some((entity, _)) = query.iter().find(|(_, id)| id.0 == request.entity_id) else {
+            continue;
+        };
+        if request.locked {
+            commands.entity(entity).insert(entity_factory::Locked);
+        } else {
+            commands.entity(entity).remove::<entity_factory::Locked>();
+        }
+        cache.dirty = true;
+    }
+}
+
+/// System that applies pending add/remove_entity_tag requests from the bridge.
+/// Entities left without tags lose the component.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_entity_tag_requests(
+    mut commands: Commands,
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(Entity, &EntityId, Option<&mut EntityTags>)>,
+) {
+    for request in pending.entity_tag_requests.drain(..) {
+        let Some((entity, _, tags)) = query.iter_mut().find(|(_, id, _)| id.0 == request.entity_id) else {
+            continue;
+        };
+        match (tags, request.add) {
+            (Some(mut tags), true) => {
+                tags.0.insert(request.tag);
+            }
+            (Some(mut tags), false) => {
+                tags.0.remove(&request.tag);
+                if tags.0.is_empty() {
+                    commands.entity(entity).remove::<EntityTags>();
+                }
+            }
+            (None, true) => {
+                commands.entity(entity).insert(EntityTags([request.tag].into()));
+            }
+            (None, false) => {}
+        }
+    }
+}
+
+/// System that applies pending clear-selection requests from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_pending_clear_selection(
+    mut pending: ResMut<PendingCommands>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+) {
+    for _ in pending.clear_selection_requests.drain(..) {
+        selection.clear();
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: vec![],
+            primary_id: None,
+            primary_name: None,
+        });
+    }
+}
+
+/// System that applies pending gizmo mode requests from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_pending_gizmo_mode(
+    mut pending: ResMut<PendingCommands>,
+    mut active_mode: ResMut<ActiveGizmoMode>,
+) {
+    for request in pending.gizmo_mode_requests.drain(..) {
+        if let Some(mode) = ActiveGizmoMode::from_str(&request.mode) {
+            *active_mode = mode;
+        }
+    }
+}
+
+/// System that applies pending viewport layout requests from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_viewport_layout_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut layout: ResMut<ViewportLayout>,
+) {
+    for request in pending.viewport_layout_requests.drain(..) {
+        *layout = request.layout;
+        events::emit_viewport_layout_changed(request.layout);
+    }
+}
+
+/// System that applies pending render mode requests from the bridge.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_render_mode_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut render_mode: ResMut<RenderMode>,
+) {
+    for request in pending.render_mode_requests.drain(..) {
+        *render_mode = request.mode;
+        events::emit_render_mode_changed(request.mode);
+    }
+}
+
+#[cfg(all(test, not(feature = "runtime")))]
+mod tests {
+    use super::*;
+    use crate::core::pending::{DeleteRequest, EntityLockRequest, RenameRequest, TransformUpdate};
+    use crate::core::scene_file::SceneName;
+    use crate::core::scene_graph;
+
+    #[test]
+    fn locked_entities_ignore_moves_and_deletes_until_unlocked() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<SceneGraphCache>()
+            .init_resource::<Selection>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<LastMoveDelta>()
+            .add_message::<SelectionChangedEvent>()
+            .add_systems(Update, (
+                apply_entity_lock_requests,
+                apply_pending_transforms,
+                entity_factory::apply_delete_requests,
+            ).chain());
+        let wall = app.world_mut().spawn((
+            EntityId::new("wall"),
+            EntityName::new("Wall"),
+            Transform::default(),
+            EntityVisible::default(),
+        )).id();
+
+        let queue = |app: &mut App, locked: bool| {
+            let mut pending = app.world_mut().resource_mut::<PendingCommands>();
+            pending.queue_entity_lock(EntityLockRequest { entity_id: "wall".to_string(), locked });
+            app.update();
+            let mut pending = app.world_mut().resource_mut::<PendingCommands>();
+            pending.queue_transform_update(TransformUpdate {
+                entity_id: "wall".to_string(),
+                position: Some(Vec3::new(1.0, 2.0, 3.0)),
+                rotation: None,
+                scale: None,
+            });
+            pending.queue_delete(DeleteRequest { entity_ids: vec!["wall".to_string()] });
+            app.update();
+        };
+
+        queue(&mut app, true);
+        assert!(app.world().get::<entity_factory::Locked>(wall).is_some());
+        assert_eq!(app.world().get::<Transform>(wall).unwrap().translation, Vec3::ZERO);
+        assert!(app.world().resource::<HistoryStack>().undo_description().is_none());
+
+        queue(&mut app, false);
+        assert!(app.world().get_entity(wall).is_err(), "unlocked entity should be deletable");
+        assert!(app.world().resource::<HistoryStack>().undo_description().is_some());
+    }
+
+    #[test]
+    fn smart_duplicate_repeats_the_last_move() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<Selection>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<LastMoveDelta>()
+            .add_message::<SelectionChangedEvent>()
+            .add_systems(Update, (apply_pending_transforms, entity_factory::apply_duplicate_requests).chain());
+        let post = app.world_mut().spawn((
+            EntityId::new("post"),
+            EntityName::new("Post"),
+            Transform::from_xyz(1.0, 0.0, 0.0),
+            EntityVisible::default(),
+        )).id();
+        app.world_mut().resource_mut::<Selection>().select_one(post, "post".to_string());
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_transform_update(TransformUpdate {
+            entity_id: "post".to_string(),
+            position: Some(Vec3::new(3.0, 0.0, 0.0)),
+            rotation: None,
+            scale: None,
+        });
+        app.update();
+        for _ in 0..2 {
+            app.world_mut().resource_mut::<PendingCommands>().queue_smart_duplicate();
+            app.update();
+        }
+
+        let mut xs: Vec<f32> = app.world_mut()
+            .query::<(&EntityId, &Transform)>()
+            .iter(app.world())
+            .filter(|(id, _)| id.0 != "post")
+            .map(|(_, transform)| transform.translation.x)
+            .collect();
+        xs.sort_by(f32::total_cmp);
+        assert_eq!(xs, vec![5.0, 7.0]);
+        let selection = app.world().resource::<Selection>();
+        assert_eq!(selection.entity_ids.len(), 1);
+        assert!(!selection.is_id_selected("post"));
+    }
+
+    #[test]
+    fn renaming_one_entity_emits_a_patch_for_that_entity_only() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<SceneGraphCache>()
+            .init_resource::<SceneName>()
+            .add_systems(Update, apply_pending_renames)
+            .add_systems(PostUpdate, (
+                scene_graph::detect_scene_replaced,
+                scene_graph::apply_scene_graph_refresh_requests,
+                scene_graph::assign_sort_indices,
+                scene_graph::build_scene_graph,
+                emit_scene_graph_updates,
+            ).chain());
+        let table = app.world_mut().spawn((EntityId::new("table"), EntityName::new("Table"))).id();
+        let legs: Vec<Entity> = ["leg-a", "leg-b"].iter()
+            .map(|id| app.world_mut().spawn((EntityId::new(*id), EntityName::new(*id))).id())
+            .collect();
+        app.world_mut().entity_mut(table).add_children(&legs);
+
+        let emitted = events::capture_events(|| app.update());
+        let types: Vec<&str> = emitted.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(types, vec!["SCENE_GRAPH_UPDATE"], "first emit is the full graph");
+        assert!(events::capture_events(|| app.update()).is_empty(), "nothing changed");
+
+        app.world_mut().resource_mut::<PendingCommands>().rename_requests.push(RenameRequest {
+            entity_id: "leg-b".to_string(),
+            new_name: "Back Leg".to_string(),
+        });
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted.len(), 1);
+        let (event_type, patch) = &emitted[0];
+        assert_eq!(event_type, "SCENE_GRAPH_PATCH");
+        let upserted = patch["upserted"].as_array().unwrap();
+        assert_eq!(upserted.len(), 1);
+        assert_eq!(upserted[0]["entityId"], "leg-b");
+        assert_eq!(upserted[0]["name"], "Back Leg");
+        assert_eq!(patch["removed"], serde_json::json!([]));
+        assert!(patch["rootIds"].is_null());
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_scene_graph_refresh();
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted.iter().map(|(t, _)| t.as_str()).collect::<Vec<_>>(), vec!["SCENE_GRAPH_UPDATE"]);
+    }
+
+    #[test]
+    fn throttled_material_changes_in_one_frame_send_one_event() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, || {
+                for i in 0..100 {
+                    events::emit_material_changed("crate", &MaterialData { metallic: i as f32, ..Default::default() });
+                }
+            })
+            .add_systems(Last, flush_throttled_events);
+        events::set_event_throttle("MATERIAL_CHANGED", Some(30.0)).unwrap();
+
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted.len(), 1);
+        let (event_type, payload) = &emitted[0];
+        assert_eq!(event_type, "MATERIAL_CHANGED");
+        assert_eq!(payload["entityId"], "crate");
+        assert_eq!(payload["metallic"], 99.0, "the latest change wins");
+    }
+
+    #[test]
+    fn replaying_a_recorded_spawn_and_move_rebuilds_the_entity() {
+        use crate::core::command_log::{self, CommandLog, CommandReplay};
+        use crate::core::commands::dispatch;
+        use serde_json::json;
+
+        fn app() -> App {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins)
+                .init_resource::<PendingCommands>()
+                .init_resource::<HistoryStack>()
+                .init_resource::<LastMoveDelta>()
+                .init_resource::<CommandLog>()
+                .init_resource::<CommandReplay>()
+                .init_resource::<Assets<Mesh>>()
+                .init_resource::<Assets<StandardMaterial>>()
+                .add_systems(Update, (
+                    command_log::apply_command_log_requests,
+                    command_log::run_command_replay,
+                    entity_factory::apply_spawn_requests,
+                    apply_pending_transforms,
+                ).chain());
+            let pending = app.world_mut().resource_mut::<PendingCommands>().into_inner() as *mut PendingCommands;
+            crate::core::pending_commands::register_pending_commands(pending);
+            app
+        }
+        fn entities(app: &mut App) -> Vec<(String, String, Vec3)> {
+            app.world_mut()
+                .query::<(&EntityId, &EntityName, &Transform)>()
+                .iter(app.world())
+                .map(|(id, name, transform)| (id.0.clone(), name.0.clone(), transform.translation))
+                .collect()
+        }
+
+        let mut recorder = app();
+        dispatch("start_event_recording", json!({})).unwrap();
+        dispatch("spawn_entity", json!({ "entityType": "cube", "name": "Crate" })).unwrap();
+        recorder.update();
+        let id = entities(&mut recorder)[0].0.clone();
+        dispatch("update_transform", json!({ "entityId": id, "position": [1.0, 2.0, 3.0] })).unwrap();
+        recorder.update();
+        dispatch("stop_event_recording", json!({})).unwrap();
+        let emitted = events::capture_events(|| recorder.update());
+        let (_, payload) = emitted.iter().find(|(t, _)| t == "COMMAND_LOG").expect("COMMAND_LOG");
+        assert_eq!(payload["commandCount"], 2);
+        let recorded = entities(&mut recorder);
+        assert_eq!(recorded, vec![(id, "Crate".to_string(), Vec3::new(1.0, 2.0, 3.0))]);
+
+        let mut player = app();
+        dispatch("replay_commands", json!({ "log": payload["log"] })).unwrap();
+        player.update();
+        for _ in 0..1000 {
+            if player.world().resource::<CommandReplay>().queue.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            player.update();
+        }
+        assert_eq!(entities(&mut player), recorded);
+    }
+}
@@ -0,0 +1,274 @@
+//! Selection, box select and picking systems (editor-only).
+
+use bevy::prelude::*;
+use bevy::input::ButtonInput;
+use bevy::input::keyboard::KeyCode;
+use bevy::picking::pointer::PointerButton;
+use bevy::picking::events::Press;
+use bevy::camera::primitives::Aabb;
+
+use crate::core::entity_id::{EntityId, EntityName};
+use crate::core::pending::EntityType;
+use crate::core::selection::{self, Selection, SelectionChangedEvent};
+use crate::core::camera::EditorCamera;
+use crate::core::viewport::ViewportPane;
+use crate::core::pending_commands::PendingCommands;
+use crate::core::{entity_factory, pending_commands};
+
+use crate::bridge::events;
+
+/// Buffer for collecting picking hits within a frame, so we can select the closest.
+#[derive(Resource, Default)]
+pub(crate) struct PickBuffer {
+    hits: Vec<PickHit>,
+    ctrl_held: bool,
+}
+
+struct PickHit {
+    entity: Entity,
+    entity_id: String,
+    entity_name: Option<String>,
+    depth: f32,
+}
+/// System that processes pending selection requests from the hierarchy panel.
+pub(crate) fn apply_selection_requests(
+    mut pending: ResMut<PendingCommands>,
+    query: Query<(Entity, &EntityId, Option<&EntityName>)>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+) {
+    for request in pending.selection_requests.drain(..) {
+        // Find the entity by ID
+        if let Some((entity, _, entity_name)) = query
+            .iter()
+            .find(|(_, eid, _)| eid.0 == request.entity_id)
+        {
+            let name = entity_name.map(|n| n.0.clone());
+
+            match request.mode {
+                pending_commands::SelectionMode::Replace => {
+                    selection.select_one(entity, request.entity_id.clone());
+                }
+                pending_commands::SelectionMode::Add => {
+                    selection.add(entity, request.entity_id.clone());
+                }
+                pending_commands::SelectionMode::Toggle => {
+                    selection.toggle(entity, request.entity_id.clone());
+                }
+            }
+
+            // Emit selection changed event
+            selection_events.write(SelectionChangedEvent {
+                selected_ids: selection.selected_ids(),
+                primary_id: selection.primary_id.clone(),
+                primary_name: name,
+            });
+        }
+    }
+}
+
+/// System that processes bulk selection requests (multi-ID select, select by type,
+/// invert). Each request emits exactly one selection changed event.
+pub(crate) fn apply_selection_bulk_requests(
+    mut pending: ResMut<PendingCommands>,
+    query: Query<(Entity, &EntityId, Option<&EntityType>, Option<&EntityName>), Without<entity_factory::Undeletable>>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+) {
+    if pending.selection_bulk_requests.is_empty() {
+        return;
+    }
+
+    let candidates: Vec<(Entity, String, Option<EntityType>)> = query
+        .iter()
+        .map(|(entity, eid, ty, _)| (entity, eid.0.clone(), ty.copied()))
+        .collect();
+
+    for request in pending.selection_bulk_requests.drain(..) {
+        selection.apply_bulk(&request, &candidates);
+
+        let primary_name = selection.primary
+            .and_then(|e| query.get(e).ok())
+            .and_then(|(_, _, _, name)| name.map(|n| n.0.clone()));
+
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: selection.selected_ids(),
+            primary_id: selection.primary_id.clone(),
+            primary_name,
+        });
+    }
+}
+
+/// System that processes marquee (box) selection requests.
+/// Each entity's world AABB is projected through the editor camera; anything whose
+/// projected bounds overlap the rectangle is selected. Entities without an AABB
+/// (e.g. lights) are treated as a point at their origin, and entities entirely
+/// behind the camera are skipped.
+pub(crate) fn apply_box_select_requests(
+    mut pending: ResMut<PendingCommands>,
+    camera_query: Query<(&Camera, &GlobalTransform), Or<(With<EditorCamera>, With<ViewportPane>)>>,
+    entity_query: Query<(Entity, &EntityId, &GlobalTransform, Option<&Aabb>, Option<&EntityName>), (Without<entity_factory::Undeletable>, Without<entity_factory::Locked>)>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+) {
+    if pending.box_select_requests.is_empty() {
+        return;
+    }
+
+    for request in pending.box_select_requests.drain(..) {
+        let marquee = Rect::from_corners(request.min, request.max);
+        // In split layouts, select through the view the marquee was drawn in.
+        let camera = camera_query.iter().find(|(camera, _)| {
+            camera.logical_viewport_rect().is_some_and(|view| view.contains(marquee.center()))
+        });
+        let Some((camera, camera_transform)) = camera.or_else(|| camera_query.iter().next()) else {
+            continue;
+        };
+        let project = |p: Vec3| camera.world_to_viewport(camera_transform, p).ok();
+
+        if matches!(request.mode, pending_commands::SelectionMode::Replace) {
+            selection.clear();
+        }
+
+        for (entity, eid, transform, aabb, _) in entity_query.iter() {
+            let bounds = match aabb {
+                Some(aabb) => {
+                    let center = Vec3::from(aabb.center);
+                    let half = Vec3::from(aabb.half_extents);
+                    selection::projected_bounds((0..8).map(|i| {
+                        let sign = Vec3::new(
+                            if i & 1 == 0 { -1.0 } else { 1.0 },
+                            if i & 2 == 0 { -1.0 } else { 1.0 },
+                            if i & 4 == 0 { -1.0 } else { 1.0 },
+                        );
+                        project(transform.transform_point(center + half * sign))
+                    }))
+                }
+                None => selection::projected_bounds([project(transform.translation())]),
+            };
+
+            if bounds.is_some_and(|b| selection::rects_overlap(b, marquee)) {
+                selection.add(entity, eid.0.clone());
+            }
+        }
+
+        let primary_name = selection.primary
+            .and_then(|e| entity_query.get(e).ok())
+            .and_then(|(_, _, _, _, name)| name.map(|n| n.0.clone()));
+
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: selection.selected_ids(),
+            primary_id: selection.primary_id.clone(),
+            primary_name,
+        });
+    }
+}
+
+/// System that emits transform data when the primary selection changes.
+pub(crate) fn emit_transform_on_selection(
+    selection: Res<Selection>,
+    query: Query<(&EntityId, &Transform)>,
+) {
+    // Only run when Selection resource changes
+    if !selection.is_changed() {
+        return;
+    }
+
+    if let Some(primary) = selection.primary {
+        if let Ok((entity_id, transform)) = query.get(primary) {
+            let (rx, ry, rz) = transform.rotation.to_euler(bevy::math::EulerRot::XYZ);
+
+            let payload = crate::core::gizmo::TransformPayload {
+                entity_id: entity_id.0.clone(),
+                position: [
+                    transform.translation.x,
+                    transform.translation.y,
+                    transform.translation.z,
+                ],
+                rotation: [rx, ry, rz],
+                scale: [transform.scale.x, transform.scale.y, transform.scale.z],
+            };
+
+            events::emit_event("TRANSFORM_CHANGED", &payload);
+        }
+    }
+}
+
+/// Observer that collects picking hits into the PickBuffer.
+/// Uses Pointer<Pressed> instead of Pointer<Click> because Click requires
+/// press+release on the same entity, which is unreliable in WASM.
+pub(crate) fn handle_picking_pressed(
+    trigger: On<Pointer<Press>>,
+    query: Query<(&EntityId, Option<&EntityName>)>,
+    mut pick_buffer: ResMut<PickBuffer>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    let event = trigger.event();
+
+    // Only handle left clicks
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    let entity = trigger.event_target();
+
+    if let Ok((entity_id, entity_name)) = query.get(entity) {
+        pick_buffer.hits.push(PickHit {
+            entity,
+            entity_id: entity_id.0.clone(),
+            entity_name: entity_name.map(|n| n.0.clone()),
+            depth: event.hit.depth,
+        });
+        pick_buffer.ctrl_held = keyboard.pressed(KeyCode::ControlLeft)
+            || keyboard.pressed(KeyCode::ControlRight);
+    }
+}
+
+/// System that processes the pick buffer and selects the closest entity.
+/// Locked entities are skipped, so clicks reach whatever is behind them.
+pub(crate) fn process_pick_buffer(
+    mut pick_buffer: ResMut<PickBuffer>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+    locked: Query<(), With<entity_factory::Locked>>,
+) {
+    if pick_buffer.hits.is_empty() {
+        return;
+    }
+
+    // Find the closest unlocked hit (smallest depth)
+    let closest = pick_buffer.hits.iter()
+        .filter(|hit| !locked.contains(hit.entity))
+        .min_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(std::cmp::Ordering::Equal));
+
+    if let Some(hit) = closest {
+        let ctrl_held = pick_buffer.ctrl_held;
+
+        if ctrl_held {
+            selection.toggle(hit.entity, hit.entity_id.clone());
+        } else {
+            selection.select_one(hit.entity, hit.entity_id.clone());
+        }
+
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: selection.selected_ids(),
+            primary_id: selection.primary_id.clone(),
+            primary_name: hit.entity_name.clone(),
+        });
+    }
+
+    pick_buffer.hits.clear();
+}
+
+/// System that emits selection events to JavaScript when selection changes.
+pub(crate) fn emit_selection_events(
+    mut events: MessageReader<SelectionChangedEvent>,
+) {
+    for event in events.read() {
+        events::emit_selection_changed(
+            event.selected_ids.clone(),
+            event.primary_id.clone(),
+            event.primary_name.clone(),
+        );
+    }
+}
@@ -2,10 +2,13 @@
 
 use bevy::prelude::*;
 use bevy::mesh::{Indices, VertexAttributeValues};
+use crate::core::csg::CsgMeshData;
 use crate::core::entity_id::EntityId;
-use crate::core::edit_mode::{EditModeData, SelectionMode};
-use crate::core::pending_commands::PendingCommands;
-use super::events::emit_event;
+use crate::core::edit_mode::{flip_normals, recalculate_normals, EditModeData, SelectionMode};
+use crate::core::history::{HistoryStack, UndoableAction};
+use crate::core::pending_commands::{EntityType, PendingCommands};
+use crate::core::procedural_mesh::ProceduralMeshData;
+use super::events::{emit_event, emit_mesh_modified, emit_procedural_mesh_error};
 
 /// System to apply edit mode requests from pending commands.
 #[cfg(not(feature = "runtime"))]
@@ -121,20 +124,108 @@ pub fn apply_edit_mode_requests(
             }
         }
     }
+}
 
-    // Recalculate normals
-    for request in pending.recalc_normals_requests.drain(..) {
-        let entity_data = edit_mode_query.iter().find(|(_e, id, _)| id.0 == request.entity_id);
-        if let Some((entity, _eid, _)) = entity_data {
-            if let Ok(mesh_handle) = mesh_query.get(entity) {
-                if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
-                    if request.smooth {
-                        recalculate_smooth_normals(mesh);
-                    } else {
-                        recalculate_flat_normals(mesh);
-                    }
-                }
+/// System that applies pending recalc_normals and flip_normals requests.
+/// The edited mesh is added as a new asset (other entities may share the old
+/// one) and stored procedural/CSG mesh data is kept in sync.
+#[cfg(not(feature = "runtime"))]
+#[allow(clippy::type_complexity)]
+pub fn apply_normal_edit_requests(
+    mut commands: Commands,
+    mut pending: ResMut<PendingCommands>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut history: ResMut<HistoryStack>,
+    query: Query<(
+        Entity,
+        &EntityId,
+        &EntityType,
+        &Mesh3d,
+        Option<&ProceduralMeshData>,
+        Option<&CsgMeshData>,
+    )>,
+) {
+    let mut requests: Vec<(String, NormalEdit)> = pending.recalc_normals_requests.drain(..)
+        .map(|r| (r.entity_id, if r.smooth { NormalEdit::Smooth } else { NormalEdit::Flat }))
+        .collect();
+    requests.extend(pending.flip_normals_requests.drain(..).map(|r| (r.entity_id, NormalEdit::Flip)));
+
+    for (entity_id, edit) in requests {
+        let Some((entity, _, entity_type, mesh_h, old_mesh_data, old_csg_data)) = query.iter().find(|(_, eid, ..)| eid.0 == entity_id) else {
+            emit_procedural_mesh_error(&format!("Entity not found or has no mesh: {}", entity_id));
+            continue;
+        };
+        if *entity_type == EntityType::Terrain {
+            emit_procedural_mesh_error("Terrain normals are derived from its heightmap");
+            continue;
+        }
+        let Some(mesh) = meshes.get(&mesh_h.0) else {
+            emit_procedural_mesh_error("Mesh asset not loaded");
+            continue;
+        };
+
+        let edited = match edit {
+            NormalEdit::Smooth => recalculate_normals(mesh, true),
+            NormalEdit::Flat => recalculate_normals(mesh, false),
+            NormalEdit::Flip => flip_normals(mesh),
+        };
+        let edited = match edited {
+            Ok(mesh) => mesh,
+            Err(e) => {
+                emit_procedural_mesh_error(&e);
+                continue;
             }
+        };
+        let new_mesh_data = old_mesh_data.and_then(|d| ProceduralMeshData::from_mesh(&edited, d.operation.clone()));
+        let new_csg_data = old_csg_data.and_then(|_| CsgMeshData::from_mesh(&edited));
+        let new_mesh = meshes.add(edited);
+
+        history.push(UndoableAction::MeshEdit {
+            entity_id: entity_id.clone(),
+            operation: edit.label().to_string(),
+            old_mesh: mesh_h.0.clone(),
+            new_mesh: new_mesh.clone(),
+            old_mesh_data: old_mesh_data.cloned(),
+            new_mesh_data: new_mesh_data.clone(),
+            old_csg_data: old_csg_data.cloned(),
+            new_csg_data: new_csg_data.clone(),
+        });
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(Mesh3d(new_mesh));
+        if let Some(data) = new_mesh_data {
+            entity_commands.insert(data);
+        }
+        if let Some(data) = new_csg_data {
+            entity_commands.insert(data);
+        }
+        emit_mesh_modified(&entity_id, edit.operation());
+    }
+}
+
+/// Normal edit requested through recalc_normals or flip_normals.
+#[derive(Debug, Clone, Copy)]
+enum NormalEdit {
+    Smooth,
+    Flat,
+    Flip,
+}
+
+impl NormalEdit {
+    /// Operation name reported in MESH_MODIFIED.
+    fn operation(self) -> &'static str {
+        match self {
+            NormalEdit::Smooth | NormalEdit::Flat => "recalc_normals",
+            NormalEdit::Flip => "flip_normals",
+        }
+    }
+
+    /// Undo history label.
+    fn label(self) -> &'static str {
+        match self {
+            NormalEdit::Smooth => "Recalculate Normals (Smooth)",
+            NormalEdit::Flat => "Recalculate Normals (Flat)",
+            NormalEdit::Flip => "Flip Normals",
         }
     }
 }
@@ -317,36 +408,3 @@ fn recalculate_smooth_normals(mesh: &mut Mesh) {
 
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
 }
-
-fn recalculate_flat_normals(mesh: &mut Mesh) {
-    let positions = if let Some(VertexAttributeValues::Float32x3(ref pos)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-        pos.clone()
-    } else {
-        return;
-    };
-
-    let mut normals = vec![[0.0f32; 3]; positions.len()];
-
-    let indices = match mesh.indices() {
-        Some(Indices::U32(idx)) => idx.iter().map(|&i| i as usize).collect::<Vec<_>>(),
-        Some(Indices::U16(idx)) => idx.iter().map(|&i| i as usize).collect::<Vec<_>>(),
-        None => return,
-    };
-
-    for face in indices.chunks(3) {
-        if face.len() != 3 { continue; }
-        let (i0, i1, i2) = (face[0], face[1], face[2]);
-
-        let v0 = Vec3::from(positions[i0]);
-        let v1 = Vec3::from(positions[i1]);
-        let v2 = Vec3::from(positions[i2]);
-
-        let normal = (v1 - v0).cross(v2 - v0).normalize_or_zero().to_array();
-
-        normals[i0] = normal;
-        normals[i1] = normal;
-        normals[i2] = normal;
-    }
-
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-}
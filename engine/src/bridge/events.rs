@@ -8,8 +8,22 @@ use std::cell::RefCell;
 use wasm_bindgen::prelude::*;
 use serde_wasm_bindgen::Serializer;
 
+use crate::core::events::EventThrottle;
+
 thread_local! {
     static EVENT_CALLBACK: RefCell<Option<js_sys::Function>> = const { RefCell::new(None) };
+    /// While set, emitted events are collected here instead of sent to JS.
+    static CAPTURED_EVENTS: RefCell<Option<Vec<(String, serde_json::Value)>>> = const { RefCell::new(None) };
+    /// Rate limits for noisy event types (see `core::events`).
+    static THROTTLE: RefCell<EventThrottle> = RefCell::new(EventThrottle::default());
+}
+
+/// Run `f`, returning the events it emitted as `(type, payload)` pairs
+/// instead of sending them to JavaScript.
+pub(super) fn capture_events(f: impl FnOnce()) -> Vec<(String, serde_json::Value)> {
+    let outer = CAPTURED_EVENTS.with(|c| c.borrow_mut().replace(Vec::new()));
+    f();
+    CAPTURED_EVENTS.with(|c| std::mem::replace(&mut *c.borrow_mut(), outer)).unwrap_or_default()
 }
 
 /// Store the event callback function from JavaScript.
@@ -21,14 +35,49 @@ pub fn set_event_callback_impl(callback: js_sys::Function) {
     web_sys::console::log_1(&"Event callback stored".into());
 }
 
-/// Check if an event callback is registered.
+/// Check if an event callback is registered (or events are being captured).
 pub fn has_event_callback() -> bool {
-    EVENT_CALLBACK.with(|cb| cb.borrow().is_some())
+    EVENT_CALLBACK.with(|cb| cb.borrow().is_some()) || CAPTURED_EVENTS.with(|c| c.borrow().is_some())
+}
+
+/// Limit `event_type` to `max_hz` events a second per entity (`None` removes
+/// the limit).
+pub fn set_event_throttle(event_type: &str, max_hz: Option<f64>) -> Result<(), String> {
+    THROTTLE.with(|t| t.borrow_mut().set_rate(event_type, max_hz))
+}
+
+/// Send the throttled events that are due at `now` (seconds).
+pub fn flush_throttled_events(now: f64) {
+    let due = THROTTLE.with(|t| t.borrow_mut().drain_due(now));
+    for (event_type, payload) in due {
+        send_event(&event_type, &payload);
+    }
 }
 
 /// Emit an event to JavaScript.
-/// The event is serialized to JSON and passed to the callback.
+/// The event is serialized to JSON and passed to the callback. Throttled
+/// event types are held until the next flush instead.
 pub fn emit_event<T: Serialize>(event_type: &str, payload: &T) {
+    if THROTTLE.with(|t| t.borrow().is_throttled(event_type)) {
+        let payload = serde_json::to_value(payload).unwrap_or_default();
+        THROTTLE.with(|t| t.borrow_mut().hold(event_type, payload));
+        return;
+    }
+    send_event(event_type, payload);
+}
+
+fn send_event<T: Serialize>(event_type: &str, payload: &T) {
+    let captured = CAPTURED_EVENTS.with(|c| match c.borrow_mut().as_mut() {
+        Some(events) => {
+            events.push((event_type.to_string(), serde_json::to_value(payload).unwrap_or_default()));
+            true
+        }
+        None => false,
+    });
+    if captured {
+        return;
+    }
+
     EVENT_CALLBACK.with(|cb| {
         if let Some(callback) = cb.borrow().as_ref() {
             let event = serde_json::json!({
@@ -72,6 +121,11 @@ pub fn emit_scene_graph_update(data: &crate::core::scene_graph::SceneGraphData)
     emit_event("SCENE_GRAPH_UPDATE", data);
 }
 
+/// Emit the nodes that changed since the last scene graph event.
+pub fn emit_scene_graph_patch(patch: &crate::core::scene_graph::SceneGraphPatch) {
+    emit_event("SCENE_GRAPH_PATCH", patch);
+}
+
 /// Emit a history changed event.
 pub fn emit_history_changed(
     can_undo: bool,
@@ -101,6 +155,15 @@ pub fn emit_snap_settings_changed(settings: &crate::core::snap::SnapSettings) {
     emit_event("SNAP_SETTINGS_CHANGED", settings);
 }
 
+/// Emit the vertex or surface point the current drag is snapped to (`None` when released).
+pub fn emit_snap_target(position: Option<[f32; 3]>) {
+    #[derive(Serialize)]
+    struct SnapTargetPayload {
+        position: Option<[f32; 3]>,
+    }
+    emit_event("SNAP_TARGET", &SnapTargetPayload { position });
+}
+
 /// Emit a light changed event for the selected entity.
 pub fn emit_light_changed(entity_id: &str, data: &crate::core::lighting::LightData) {
     #[derive(Serialize)]
@@ -117,6 +180,19 @@ pub fn emit_light_changed(entity_id: &str, data: &crate::core::lighting::LightDa
     });
 }
 
+/// Emit light probe bake progress for a volume (`baked == total` when done).
+pub fn emit_light_bake_progress(entity_id: &str, baked: usize, total: usize) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct LightBakeProgressPayload<'a> {
+        entity_id: &'a str,
+        baked: usize,
+        total: usize,
+    }
+
+    emit_event("LIGHT_BAKE_PROGRESS", &LightBakeProgressPayload { entity_id, baked, total });
+}
+
 /// Emit an ambient light changed event.
 pub fn emit_ambient_light_changed(color: [f32; 3], brightness: f32) {
     #[derive(Serialize)]
@@ -162,6 +238,21 @@ pub fn emit_input_bindings_changed(input_map: &crate::core::input::InputMap) {
     emit_event("INPUT_BINDINGS_CHANGED", input_map);
 }
 
+/// Emit an input context changed event after a push or pop.
+pub fn emit_input_context_changed(contexts: &crate::core::input::InputContextStack) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct InputContextPayload<'a> {
+        stack: &'a [String],
+        active: Option<&'a str>,
+    }
+
+    emit_event("INPUT_CONTEXT_CHANGED", &InputContextPayload {
+        stack: &contexts.stack,
+        active: contexts.active(),
+    });
+}
+
 /// Emit a physics changed event for an entity.
 pub fn emit_physics_changed(entity_id: &str, physics_data: &crate::core::physics::PhysicsData, enabled: bool) {
     #[derive(Serialize)]
@@ -191,6 +282,35 @@ pub fn emit_debug_physics_changed(enabled: bool) {
     emit_event("DEBUG_PHYSICS_CHANGED", &DebugPhysicsPayload { enabled });
 }
 
+/// Emit the motor settings a joint was driven to by `set_joint_motor_target`.
+pub fn emit_joint_motor_changed(joint_id: &str, target_vel: f32, target_pos: f32, stiffness: f32, damping: f32) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct JointMotorPayload<'a> {
+        joint_id: &'a str,
+        target_vel: f32,
+        target_pos: f32,
+        stiffness: f32,
+        damping: f32,
+    }
+
+    emit_event("JOINT_MOTOR_CHANGED", &JointMotorPayload { joint_id, target_vel, target_pos, stiffness, damping });
+}
+
+/// Emit the result of `bake_physics_to_animation`.
+pub fn emit_physics_baked(entity_ids: &[String], clip_name: &str, duration_secs: f32, frame_count: usize) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct PhysicsBakedPayload<'a> {
+        entity_ids: &'a [String],
+        clip_name: &'a str,
+        duration_secs: f32,
+        frame_count: usize,
+    }
+
+    emit_event("PHYSICS_BAKED", &PhysicsBakedPayload { entity_ids, clip_name, duration_secs, frame_count });
+}
+
 /// Emit a joint changed event.
 pub fn emit_joint_changed(joint_data: &crate::core::physics::JointData) {
     #[derive(Serialize)]
@@ -228,6 +348,107 @@ pub fn emit_scene_loaded(name: &str) {
     emit_event("SCENE_LOADED", &SceneLoadedPayload { name });
 }
 
+/// Emit a prefab created from an entity subtree, with its `PrefabSnapshot` JSON.
+pub fn emit_prefab_created(prefab_id: &str, name: &str, source_entity_id: &str, snapshot_json: &str) {
+    emit_event("PREFAB_CREATED", &serde_json::json!({
+        "prefabId": prefab_id,
+        "name": name,
+        "sourceEntityId": source_entity_id,
+        "snapshotJson": snapshot_json,
+    }));
+}
+
+/// Emit an exported prefab library (see `prefab::PrefabLibrary`).
+pub fn emit_prefab_library_exported(json: &str, prefab_count: usize) {
+    emit_event("PREFAB_LIBRARY_EXPORTED", &serde_json::json!({
+        "json": json,
+        "prefabCount": prefab_count,
+    }));
+}
+
+/// Emit the prefabs of an imported library as `(prefab id, name, snapshot JSON)`.
+pub fn emit_prefab_library_imported(prefabs: &[(String, String, String)]) {
+    let prefabs: Vec<_> = prefabs.iter()
+        .map(|(prefab_id, name, snapshot_json)| serde_json::json!({
+            "prefabId": prefab_id,
+            "name": name,
+            "snapshotJson": snapshot_json,
+        }))
+        .collect();
+    emit_event("PREFAB_LIBRARY_IMPORTED", &serde_json::json!({ "prefabs": prefabs }));
+}
+
+/// Emit the entities whose data changed after an `update_prefab`.
+pub fn emit_prefab_instances_updated(prefab_id: &str, entity_ids: &[String]) {
+    emit_event("PREFAB_INSTANCES_UPDATED", &serde_json::json!({
+        "prefabId": prefab_id,
+        "entityIds": entity_ids,
+    }));
+}
+
+/// Emit the result of a radius or box entity query, nearest first.
+pub fn emit_entities_in_region(hits: &[(String, f32)]) {
+    let entities: Vec<_> = hits.iter()
+        .map(|(entity_id, distance)| serde_json::json!({ "entityId": entity_id, "distance": distance }))
+        .collect();
+    emit_event("QUERY_ENTITIES_IN_REGION", &serde_json::json!({ "entities": entities }));
+}
+
+/// Emit a compressed scene export (see `scene_file::encode_scene_binary`).
+pub fn emit_scene_exported_binary(data_base64: &str, name: &str) {
+    emit_event("SCENE_EXPORTED_BINARY", &serde_json::json!({
+        "dataBase64": data_base64,
+        "name": name,
+    }));
+}
+
+/// Emit a scene merged event with the IDs of the newly created entities.
+pub fn emit_scene_merged(entity_ids: &[String]) {
+    emit_event("SCENE_MERGED", &serde_json::json!({
+        "entityIds": entity_ids,
+    }));
+}
+
+/// Emit the clipboard JSON produced by `copy_entities`.
+pub fn emit_entities_copied(json: &str, entity_count: usize) {
+    emit_event("ENTITIES_COPIED", &serde_json::json!({
+        "json": json,
+        "entityCount": entity_count,
+    }));
+}
+
+/// Emit a recorded command log (see `command_log::CommandLogData`).
+pub fn emit_command_log(json: &str, command_count: usize) {
+    emit_event("COMMAND_LOG", &serde_json::json!({
+        "log": json,
+        "commandCount": command_count,
+    }));
+}
+
+/// Emit the IDs of entities created by `paste_entities`.
+pub fn emit_entities_pasted(entity_ids: &[String]) {
+    emit_event("ENTITIES_PASTED", &serde_json::json!({
+        "entityIds": entity_ids,
+    }));
+}
+
+/// Emit a scene migrated event after an older scene file was upgraded on load.
+pub fn emit_scene_migrated(from_version: u32, to_version: u32, migrations: &[&str]) {
+    emit_event("SCENE_MIGRATED", &serde_json::json!({
+        "fromVersion": from_version,
+        "toVersion": to_version,
+        "migrations": migrations,
+    }));
+}
+
+/// Emit a glTF exported event carrying the GLB bytes as base64.
+pub fn emit_gltf_exported(name: &str, data_base64: &str) {
+    emit_event("GLTF_EXPORTED", &serde_json::json!({
+        "name": name,
+        "dataBase64": data_base64,
+    }));
+}
+
 /// Emit an asset imported event.
 pub fn emit_asset_imported(asset_id: &str, name: &str, kind: &str, file_size: u64) {
     #[derive(Serialize)]
@@ -294,6 +515,32 @@ pub fn emit_script_changed(entity_id: &str, script_data: Option<&crate::core::sc
     });
 }
 
+/// Emit a script error event so the editor can show which entity's script failed.
+pub fn emit_script_error(entity_id: &str, error: &crate::core::scripting::ScriptError) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ScriptErrorPayload<'a> {
+        entity_id: &'a str,
+        #[serde(flatten)]
+        error: &'a crate::core::scripting::ScriptError,
+    }
+
+    emit_event("SCRIPT_ERROR", &ScriptErrorPayload { entity_id, error });
+}
+
+/// Emit a custom event sent by a script, with its payload passed through as-is.
+pub fn emit_script_event(entity_id: &str, event_name: &str, payload: &serde_json::Value) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ScriptEventPayload<'a> {
+        entity_id: &'a str,
+        event_name: &'a str,
+        payload: &'a serde_json::Value,
+    }
+
+    emit_event("SCRIPT_EVENT", &ScriptEventPayload { entity_id, event_name, payload });
+}
+
 /// Emit an audio changed event for an entity.
 pub fn emit_audio_changed(entity_id: &str, audio_data: Option<&crate::core::audio::AudioData>) {
     #[derive(Serialize)]
@@ -350,6 +597,58 @@ pub fn emit_particle_changed(
     });
 }
 
+/// Emit a particle force field changed event for an entity (None = removed).
+pub fn emit_particle_force_field_changed(
+    entity_id: &str,
+    field: Option<&crate::core::particles::ParticleForceField>,
+) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ForceFieldPayload<'a> {
+        entity_id: &'a str,
+        force_field: Option<&'a crate::core::particles::ParticleForceField>,
+    }
+
+    emit_event("PARTICLE_FORCE_FIELD_CHANGED", &ForceFieldPayload { entity_id, force_field: field });
+}
+
+/// Emit a look-at constraint changed event for an entity (None = removed).
+pub fn emit_look_at_constraint_changed(
+    entity_id: &str,
+    constraint: Option<&crate::core::game_components::LookAtConstraint>,
+) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct LookAtPayload<'a> {
+        entity_id: &'a str,
+        constraint: Option<&'a crate::core::game_components::LookAtConstraint>,
+    }
+
+    emit_event("LOOK_AT_CONSTRAINT_CHANGED", &LookAtPayload { entity_id, constraint });
+}
+
+/// Emit a path changed event with its control points and a sampled curve
+/// (both in the path entity's local space) for the editor to draw.
+pub fn emit_path_changed(entity_id: &str, path: &crate::core::path::PathData) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct PathPayload<'a> {
+        entity_id: &'a str,
+        points: &'a [[f32; 3]],
+        closed: bool,
+        curve: Vec<[f32; 3]>,
+        length: f32,
+    }
+
+    emit_event("PATH_CHANGED", &PathPayload {
+        entity_id,
+        points: &path.points,
+        closed: path.closed,
+        curve: path.polyline().into_iter().map(|p| p.to_array()).collect(),
+        length: path.length(),
+    });
+}
+
 /// Emit an animation state changed event.
 pub fn emit_animation_state_changed(state: &crate::core::animation::AnimationPlaybackState) {
     emit_event("ANIMATION_STATE_CHANGED", state);
@@ -360,6 +659,25 @@ pub fn emit_animation_list_changed(state: &crate::core::animation::AnimationPlay
     emit_event("ANIMATION_LIST_CHANGED", state);
 }
 
+/// Emit an animation event marker reached during playback.
+pub fn emit_animation_event(fired: &crate::core::animation::AnimationEventFired) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct AnimationEventPayload<'a> {
+        entity_id: &'a str,
+        clip_name: &'a str,
+        name: &'a str,
+        time_secs: f32,
+    }
+
+    emit_event("ANIMATION_EVENT", &AnimationEventPayload {
+        entity_id: &fired.entity_id,
+        clip_name: &fired.clip_name,
+        name: &fired.event.name,
+        time_secs: fired.event.time_secs,
+    });
+}
+
 /// Emit a shader effect changed event for an entity.
 pub fn emit_shader_changed(entity_id: &str, data: Option<&crate::core::shader_effects::ShaderEffectData>) {
     #[derive(Serialize)]
@@ -398,6 +716,16 @@ pub fn emit_csg_completed(entity_id: &str, name: &str, operation: crate::core::c
     }));
 }
 
+/// Emit a CSG completed event for a multi-operand chain.
+pub fn emit_csg_chain_completed(entity_id: &str, name: &str, operation_count: usize) {
+    emit_event("CSG_COMPLETED", &serde_json::json!({
+        "entityId": entity_id,
+        "name": name,
+        "operation": "chain",
+        "operationCount": operation_count,
+    }));
+}
+
 /// Emit a CSG operation error event.
 pub fn emit_csg_error(message: &str) {
     emit_event("CSG_ERROR", &serde_json::json!({
@@ -429,6 +757,42 @@ pub fn emit_array_completed(source_id: &str, created_ids: &[String]) {
     }));
 }
 
+/// Emit a mesh subdivided event.
+pub fn emit_mesh_subdivided(entity_id: &str, iterations: u32, triangle_count: usize) {
+    emit_event("MESH_SUBDIVIDED", &serde_json::json!({
+        "entityId": entity_id,
+        "iterations": iterations,
+        "triangleCount": triangle_count,
+    }));
+}
+
+/// Emit a mesh modified event (normals recalculated or flipped).
+pub fn emit_mesh_modified(entity_id: &str, operation: &str) {
+    emit_event("MESH_MODIFIED", &serde_json::json!({
+        "entityId": entity_id,
+        "operation": operation,
+    }));
+}
+
+/// Emit a transform applied (frozen into the mesh) event.
+pub fn emit_transform_applied(entity_id: &str, position: bool, rotation: bool, scale: bool) {
+    emit_event("TRANSFORM_APPLIED", &serde_json::json!({
+        "entityId": entity_id,
+        "position": position,
+        "rotation": rotation,
+        "scale": scale,
+    }));
+}
+
+/// Emit an instanced array created event.
+pub fn emit_instanced_array_created(source_id: &str, entity_id: &str, instance_count: usize) {
+    emit_event("INSTANCED_ARRAY_CREATED", &serde_json::json!({
+        "sourceId": source_id,
+        "entityId": entity_id,
+        "instanceCount": instance_count,
+    }));
+}
+
 /// Emit a play tick event with all entity states for the script runtime.
 pub fn emit_play_tick(entities: &[(String, [f32; 3], [f32; 3], [f32; 3], String, String, f32)], input_state: &crate::core::input::InputState) {
     #[derive(Serialize)]
@@ -495,20 +859,43 @@ pub fn emit_play_tick(entities: &[(String, [f32; 3], [f32; 3], [f32; 3], String,
     });
 }
 
+/// Emit the physics stepping configuration after `set_physics_timestep`.
+pub fn emit_physics_config_changed(timestep: &crate::core::physics::PhysicsTimestep) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct PhysicsConfigPayload<'a> {
+        #[serde(flatten)]
+        timestep: &'a crate::core::physics::PhysicsTimestep,
+        /// Step actually applied each frame; null when following frame time.
+        effective_dt: Option<f32>,
+    }
+
+    emit_event("PHYSICS_CONFIG_CHANGED", &PhysicsConfigPayload {
+        timestep,
+        effective_dt: timestep.effective_fixed_dt(),
+    });
+}
+
 /// Emit a quality settings changed event.
 pub fn emit_quality_changed(settings: &crate::core::quality::QualitySettings) {
     emit_event("QUALITY_CHANGED", settings);
 }
 
+/// One changed entity in a delta play-tick: id, position, rotation, scale,
+/// name, type, collider radius, grounded state, flipbook frame and current HP.
+pub type PlayTickEntityDelta = (String, [f32; 3], [f32; 3], [f32; 3], String, String, f32, Option<bool>, Option<usize>, Option<f32>);
+
 /// Emit a delta play-tick event.
 ///
 /// Only includes entities that changed or are new (`changed`), entities that were
-/// removed (`removed`), and the current input state. The JS runtime merges this
-/// delta into its local entity-state cache rather than replacing it wholesale.
+/// removed (`removed`), the current input state and the 2D camera position
+/// (`None` without a managed 2D camera). The JS runtime merges this delta into
+/// its local entity-state cache rather than replacing it wholesale.
 pub fn emit_play_tick_delta(
-    changed: &[(String, [f32; 3], [f32; 3], [f32; 3], String, String, f32)],
+    changed: &[PlayTickEntityDelta],
     removed: &[String],
     input_state: &crate::core::input::InputState,
+    camera_2d_position: Option<[f32; 2]>,
 ) {
     #[derive(Serialize)]
     #[serde(rename_all = "camelCase")]
@@ -516,6 +903,12 @@ pub fn emit_play_tick_delta(
         position: [f32; 3],
         rotation: [f32; 3],
         scale: [f32; 3],
+        #[serde(skip_serializing_if = "Option::is_none")]
+        grounded: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sprite_frame: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        health: Option<f32>,
     }
 
     #[derive(Serialize)]
@@ -534,6 +927,8 @@ pub fn emit_play_tick_delta(
         changed_entity_infos: std::collections::HashMap<String, EntityInfo>,
         removed_entity_ids: Vec<String>,
         input_state: InputStatePayload,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        camera_2d_position: Option<[f32; 2]>,
     }
 
     #[derive(Serialize)]
@@ -548,11 +943,14 @@ pub fn emit_play_tick_delta(
     let mut changed_entities = std::collections::HashMap::new();
     let mut changed_entity_infos = std::collections::HashMap::new();
 
-    for (id, pos, rot, scale, name, etype, collider_r) in changed {
+    for (id, pos, rot, scale, name, etype, collider_r, grounded, sprite_frame, health) in changed {
         changed_entities.insert(id.clone(), EntityState {
             position: *pos,
             rotation: *rot,
             scale: *scale,
+            grounded: *grounded,
+            sprite_frame: *sprite_frame,
+            health: *health,
         });
         changed_entity_infos.insert(id.clone(), EntityInfo {
             name: name.clone(),
@@ -573,9 +971,32 @@ pub fn emit_play_tick_delta(
         changed_entity_infos,
         removed_entity_ids: removed.to_vec(),
         input_state: input_payload,
+        camera_2d_position,
     });
 }
 
+/// Emit an entity died event when a health component reaches zero.
+pub fn emit_entity_died(entity_id: &str, source_entity_id: Option<&str>) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct EntityDiedPayload<'a> {
+        entity_id: &'a str,
+        source_entity_id: Option<&'a str>,
+    }
+    emit_event("ENTITY_DIED", &EntityDiedPayload { entity_id, source_entity_id });
+}
+
+/// Emit a timer fired event with the timer's configured event name.
+pub fn emit_timer_fired(entity_id: &str, name: &str) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TimerFiredPayload<'a> {
+        entity_id: &'a str,
+        name: &'a str,
+    }
+    emit_event("TIMER_FIRED", &TimerFiredPayload { entity_id, name });
+}
+
 /// Emit a collision event (started or stopped).
 pub fn emit_collision_event(entity_a: &str, entity_b: &str, started: bool) {
     #[derive(Serialize)]
@@ -588,6 +1009,18 @@ pub fn emit_collision_event(entity_a: &str, entity_b: &str, started: bool) {
     emit_event("COLLISION_EVENT", &CollisionPayload { entity_a, entity_b, started });
 }
 
+/// Emit a trigger entered/exited event when a body overlaps a sensor.
+pub fn emit_trigger_event(sensor_entity_id: &str, intruder_entity_id: &str, entered: bool) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TriggerPayload<'a> {
+        sensor_entity_id: &'a str,
+        intruder_entity_id: &'a str,
+    }
+    let name = if entered { "TRIGGER_ENTERED" } else { "TRIGGER_EXITED" };
+    emit_event(name, &TriggerPayload { sensor_entity_id, intruder_entity_id });
+}
+
 /// Emit a game component changed event for an entity.
 pub fn emit_game_component_changed(entity_id: &str, components: &[crate::core::game_components::GameComponentData]) {
     #[derive(Serialize)]
@@ -616,16 +1049,76 @@ pub fn emit_raycast_result(request_id: &str, hit_entity: Option<&str>, point: [f
     emit_event("RAYCAST_RESULT", &RaycastPayload { request_id, hit_entity, point, distance });
 }
 
+/// Emit a measurement result event. `anchor` is where the UI draws the value label.
+pub fn emit_measurement_result(
+    mode: crate::core::measurement::MeasureMode,
+    value: f32,
+    points: &[[f32; 3]],
+    anchor: [f32; 3],
+) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct MeasurementPayload<'a> {
+        mode: crate::core::measurement::MeasureMode,
+        value: f32,
+        points: &'a [[f32; 3]],
+        anchor: [f32; 3],
+    }
+    emit_event("MEASUREMENT_RESULT", &MeasurementPayload { mode, value, points, anchor });
+}
+
 /// Emit a game camera changed event.
-pub fn emit_game_camera_changed(entity_id: &str, mode: &crate::core::game_camera::GameCameraMode, target_entity: &Option<String>) {
+pub fn emit_game_camera_changed(entity_id: &str, data: &crate::core::game_camera::GameCameraData) {
     #[derive(Serialize)]
     #[serde(rename_all = "camelCase")]
     struct GameCameraPayload<'a> {
         entity_id: &'a str,
         mode: &'a crate::core::game_camera::GameCameraMode,
         target_entity: &'a Option<String>,
+        follow_target_id: &'a Option<String>,
+        follow_offset: [f32; 3],
+        follow_smoothing: f32,
+        deadzone: [f32; 3],
     }
-    emit_event("GAME_CAMERA_CHANGED", &GameCameraPayload { entity_id, mode, target_entity });
+    emit_event("GAME_CAMERA_CHANGED", &GameCameraPayload {
+        entity_id,
+        mode: &data.mode,
+        target_entity: &data.target_entity,
+        follow_target_id: &data.follow_target_id,
+        follow_offset: data.follow_offset.to_array(),
+        follow_smoothing: data.follow_smoothing,
+        deadzone: data.deadzone.to_array(),
+    });
+}
+
+/// Emit the resolved position of a game camera following a target.
+pub fn emit_game_camera_follow(entity_id: &str, target_id: &str, position: [f32; 3]) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct GameCameraFollowPayload<'a> {
+        entity_id: &'a str,
+        target_id: &'a str,
+        position: [f32; 3],
+    }
+    emit_event("GAME_CAMERA_FOLLOW", &GameCameraFollowPayload { entity_id, target_id, position });
+}
+
+/// Emit a camera shake started event with the resulting combined shake.
+pub fn emit_camera_shake_started(intensity: f32, duration: f32, shake: &crate::core::game_camera::CameraShakeState) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CameraShakeStartedPayload {
+        intensity: f32,
+        duration: f32,
+        trauma: f32,
+        amplitude: f32,
+    }
+    emit_event("CAMERA_SHAKE_STARTED", &CameraShakeStartedPayload {
+        intensity,
+        duration,
+        trauma: shake.trauma,
+        amplitude: shake.amplitude,
+    });
 }
 
 /// Emit an active game camera changed event.
@@ -638,6 +1131,35 @@ pub fn emit_active_game_camera_changed(entity_id: &str) {
     emit_event("ACTIVE_GAME_CAMERA_CHANGED", &ActiveGameCameraPayload { entity_id });
 }
 
+/// Emit a viewport layout changed event with each view's canvas rectangle
+/// as fractions `[x, y, width, height]` from the top-left.
+pub fn emit_viewport_layout_changed(layout: crate::core::viewport::ViewportLayout) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct PanePayload {
+        view: crate::core::camera_presets::CameraPreset,
+        rect: [f32; 4],
+    }
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ViewportLayoutPayload {
+        layout: crate::core::viewport::ViewportLayout,
+        panes: Vec<PanePayload>,
+    }
+    let panes = layout.panes().iter().map(|(view, rect)| PanePayload { view: *view, rect: *rect }).collect();
+    emit_event("VIEWPORT_LAYOUT_CHANGED", &ViewportLayoutPayload { layout, panes });
+}
+
+/// Emit a render mode changed event.
+pub fn emit_render_mode_changed(mode: crate::core::render_mode::RenderMode) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RenderModePayload {
+        mode: crate::core::render_mode::RenderMode,
+    }
+    emit_event("RENDER_MODE_CHANGED", &RenderModePayload { mode });
+}
+
 // ============================================================================
 // 2D Physics Events
 // ============================================================================
@@ -676,35 +1198,18 @@ pub fn emit_joint2d_changed(entity_id: &str, joint_data: &crate::core::physics_2
     });
 }
 
-/// Emit a 2D raycast hit event.
-pub fn emit_raycast2d_hit(entity_id: &str, point_x: f32, point_y: f32, normal_x: f32, normal_y: f32, distance: f32) {
+/// Emit the result of a 2D raycast; `hit_entity` is `None` on a miss.
+pub fn emit_raycast2d_result(request_id: &str, hit_entity: Option<&str>, point: [f32; 2], normal: [f32; 2], toi: f32) {
     #[derive(Serialize)]
     #[serde(rename_all = "camelCase")]
-    struct Raycast2dHitPayload<'a> {
-        entity_id: &'a str,
-        point_x: f32,
-        point_y: f32,
-        normal_x: f32,
-        normal_y: f32,
-        distance: f32,
+    struct Raycast2dPayload<'a> {
+        request_id: &'a str,
+        hit_entity: Option<&'a str>,
+        point: [f32; 2],
+        normal: [f32; 2],
+        toi: f32,
     }
-
-    emit_event("RAYCAST2D_HIT", &Raycast2dHitPayload {
-        entity_id,
-        point_x,
-        point_y,
-        normal_x,
-        normal_y,
-        distance,
-    });
-}
-
-/// Emit a 2D raycast miss event.
-pub fn emit_raycast2d_miss() {
-    #[derive(Serialize)]
-    struct Raycast2dMissPayload {}
-
-    emit_event("RAYCAST2D_MISS", &Raycast2dMissPayload {});
+    emit_event("RAYCAST2D_RESULT", &Raycast2dPayload { request_id, hit_entity, point, normal, toi });
 }
 
 /// Emit a skeleton2d updated event.
@@ -802,6 +1307,31 @@ pub fn emit_tilemap_changed(entity_id: &str, tilemap_data: Option<&crate::core::
     });
 }
 
+/// Emit a tilemap changed event for a tile paint/erase/fill stroke, including
+/// the dirty rectangle so the UI can repaint just that region.
+pub fn emit_tilemap_tiles_changed(
+    entity_id: &str,
+    tilemap_data: &crate::core::tilemap::TilemapData,
+    layer: usize,
+    dirty_rect: crate::core::tilemap::TileRect,
+) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TilemapTilesPayload<'a> {
+        entity_id: &'a str,
+        tilemap: &'a crate::core::tilemap::TilemapData,
+        dirty_layer: usize,
+        dirty_rect: crate::core::tilemap::TileRect,
+    }
+
+    emit_event("TILEMAP_CHANGED", &TilemapTilesPayload {
+        entity_id,
+        tilemap: tilemap_data,
+        dirty_layer: layer,
+        dirty_rect,
+    });
+}
+
 /// Emit a reverb zone removed event.
 pub fn emit_reverb_zone_removed(entity_id: &str) {
     #[derive(Serialize)]
@@ -813,6 +1343,21 @@ pub fn emit_reverb_zone_removed(entity_id: &str) {
     emit_event("REVERB_ZONE_REMOVED", &ReverbZoneRemovedPayload { entity_id });
 }
 
+/// Emit a reverb zone entered/exited event when the audio listener crosses
+/// a zone boundary during Play mode.
+pub fn emit_reverb_zone_transition(entity_id: &str, data: &crate::core::reverb_zone::ReverbZoneData, entered: bool) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ReverbZoneTransitionPayload<'a> {
+        entity_id: &'a str,
+        #[serde(flatten)]
+        data: &'a crate::core::reverb_zone::ReverbZoneData,
+    }
+
+    let event = if entered { "REVERB_ZONE_ENTERED" } else { "REVERB_ZONE_EXITED" };
+    emit_event(event, &ReverbZoneTransitionPayload { entity_id, data });
+}
+
 /// Emit a camera 2D state changed event.
 pub fn emit_camera_2d_changed(data: &crate::core::camera_2d::Camera2dData) {
     #[derive(Serialize)]
@@ -821,6 +1366,7 @@ pub fn emit_camera_2d_changed(data: &crate::core::camera_2d::Camera2dData) {
         zoom: f32,
         pixel_perfect: bool,
         bounds: Option<Camera2dBoundsPayload>,
+        follow_target_id: Option<String>,
     }
 
     #[derive(Serialize)]
@@ -841,6 +1387,7 @@ pub fn emit_camera_2d_changed(data: &crate::core::camera_2d::Camera2dData) {
             min_y: b.min_y,
             max_y: b.max_y,
         }),
+        follow_target_id: data.follow_target_id.clone(),
     });
 }
 
@@ -857,16 +1404,20 @@ pub fn emit_lod_changed(entity_id: &str, current_lod: u8, distances: &[f32; 3])
     emit_event("LOD_CHANGED", &LodChangedPayload { entity_id, current_lod, distances });
 }
 
-/// Emit performance stats with full metrics.
-pub fn emit_performance_stats(
-    fps: f32,
-    frame_time_ms: f32,
-    entity_count: u32,
-    triangle_count: u32,
-    draw_call_estimate: u32,
-    wasm_heap_bytes: u64,
-    mesh_memory_bytes: u64,
-) {
+/// Emit the triangle count of every LOD level (0 = source mesh) after generation.
+pub fn emit_lods_generated(entity_id: &str, triangle_counts: &[u32; 4]) {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct LodsGeneratedPayload<'a> {
+        entity_id: &'a str,
+        triangle_counts: &'a [u32; 4],
+    }
+
+    emit_event("LODS_GENERATED", &LodsGeneratedPayload { entity_id, triangle_counts });
+}
+
+/// Emit performance stats with full metrics, including frustum-culling counts.
+pub fn emit_performance_stats(metrics: &crate::core::lod::PerformanceMetrics) {
     #[derive(Serialize)]
     #[serde(rename_all = "camelCase")]
     struct PerformanceStatsPayload {
@@ -877,19 +1428,32 @@ pub fn emit_performance_stats(
         draw_call_estimate: u32,
         wasm_heap_bytes: u64,
         mesh_memory_bytes: u64,
+        visible_count: u32,
+        culled_count: u32,
+        total_count: u32,
+        occluded_count: u32,
     }
 
     emit_event("PERFORMANCE_STATS", &PerformanceStatsPayload {
-        fps,
-        frame_time_ms,
-        entity_count,
-        triangle_count,
-        draw_call_estimate,
-        wasm_heap_bytes,
-        mesh_memory_bytes,
+        fps: metrics.fps,
+        frame_time_ms: metrics.frame_time_ms,
+        entity_count: metrics.entity_count,
+        triangle_count: metrics.triangle_count,
+        draw_call_estimate: metrics.draw_call_estimate,
+        wasm_heap_bytes: metrics.wasm_heap_bytes,
+        mesh_memory_bytes: metrics.mesh_memory_bytes,
+        visible_count: metrics.visible_count,
+        culled_count: metrics.culled_count,
+        total_count: metrics.total_count,
+        occluded_count: metrics.occluded_count,
     });
 }
 
+/// Emit one sample of the live stats stream.
+pub fn emit_stats_update(stats: &crate::core::lod::EngineStats) {
+    emit_event("STATS_UPDATE", stats);
+}
+
 /// Emit a custom WGSL source changed event.
 pub fn emit_custom_wgsl_source_changed(source: &crate::core::custom_wgsl::CustomWgslSource) {
     emit_event("CUSTOM_WGSL_SOURCE_CHANGED", source);
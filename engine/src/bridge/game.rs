@@ -2,11 +2,13 @@
 
 use bevy::prelude::*;
 use crate::core::{
-    entity_id::EntityId,
+    entity_factory::{EntityNameCounter, EntitySnapshot, TransformSnapshot},
+    entity_id::{EntityId, EntityName, EntityVisible},
     history::{HistoryStack, UndoableAction},
-    pending_commands::{PendingCommands, QueryRequest},
-    game_camera::{GameCameraData, ActiveGameCamera, FirstPersonState, OrbitalState, GameCameraMode},
-    game_components::{GameComponents, build_game_component},
+    path::PathData,
+    pending_commands::{EntityType, PendingCommands, QueryRequest},
+    game_camera::{GameCameraData, ActiveGameCamera, FirstPersonState, OrbitalState, GameCameraMode, CameraShakeState},
+    game_components::{GameComponentRuntime, GameComponents, LookAtConstraint, build_game_component},
 };
 use crate::bridge::{events, log, Selection, SelectionChangedEvent};
 
@@ -114,6 +116,93 @@ pub(super) fn apply_game_component_removals(
     }
 }
 
+/// System that sets or removes look-at constraints.
+pub(super) fn apply_look_at_constraint_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: Query<(Entity, &EntityId, Option<&LookAtConstraint>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for update in pending.look_at_constraint_updates.drain(..) {
+        let Some((entity, _, current)) = query.iter().find(|(_, eid, _)| eid.0 == update.entity_id) else {
+            continue;
+        };
+
+        match &update.constraint {
+            Some(constraint) => commands.entity(entity).insert(constraint.clone()),
+            None => commands.entity(entity).remove::<LookAtConstraint>(),
+        };
+
+        history.push(UndoableAction::LookAtConstraintChange {
+            entity_id: update.entity_id.clone(),
+            old_constraint: current.cloned(),
+            new_constraint: update.constraint.clone(),
+        });
+
+        events::emit_look_at_constraint_changed(&update.entity_id, update.constraint.as_ref());
+    }
+}
+
+/// System that spawns Path entities from create_path requests.
+pub(super) fn apply_create_path_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut name_counter: Local<EntityNameCounter>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for request in pending.create_path_requests.drain(..) {
+        let name = request.name.unwrap_or_else(|| name_counter.next_name(EntityType::Path));
+        let entity_id = EntityId::default();
+        let path = PathData { points: request.points, closed: request.closed };
+        let transform = Transform::from_translation(Vec3::from(request.position.unwrap_or([0.0, 0.0, 0.0])));
+
+        let mut snapshot = EntitySnapshot::new(entity_id.0.clone(), EntityType::Path, name.clone(), TransformSnapshot::from(&transform));
+        snapshot.path_data = Some(path.clone());
+
+        commands.spawn((
+            EntityType::Path,
+            entity_id,
+            EntityName::new(&name),
+            EntityVisible::default(),
+            path,
+            transform,
+            Visibility::default(),
+        ));
+        history.push(UndoableAction::Spawn { snapshot });
+    }
+}
+
+/// System that inserts control points into paths.
+pub(super) fn apply_path_point_adds(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut PathData)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for request in pending.path_point_adds.drain(..) {
+        let Some((_, mut path)) = query.iter_mut().find(|(eid, _)| eid.0 == request.entity_id) else {
+            continue;
+        };
+
+        let old_path = path.clone();
+        let index = request.index.unwrap_or(path.points.len()).min(path.points.len());
+        path.points.insert(index, request.point);
+
+        history.push(UndoableAction::PathChange {
+            entity_id: request.entity_id,
+            old_path,
+            new_path: path.clone(),
+        });
+    }
+}
+
+/// Emit PATH_CHANGED whenever a path is created or edited (including undo/redo).
+#[cfg(not(feature = "runtime"))]
+pub(super) fn emit_path_changes(query: Query<(&EntityId, &PathData), Changed<PathData>>) {
+    for (eid, path) in query.iter() {
+        events::emit_path_changed(&eid.0, path);
+    }
+}
+
 pub(super) fn process_game_component_queries(
     mut pending: ResMut<PendingCommands>,
     gc_query: Query<(&EntityId, Option<&GameComponents>)>,
@@ -159,9 +248,14 @@ pub(super) fn apply_set_game_camera_requests(
         let camera_data = GameCameraData {
             mode: request.mode.clone(),
             target_entity: request.target_entity.clone(),
+            follow_target_id: request.follow_target_id.clone(),
+            follow_offset: request.follow_offset,
+            follow_smoothing: request.follow_smoothing,
+            deadzone: request.deadzone,
             ..Default::default()
         };
 
+        events::emit_game_camera_changed(&request.entity_id, &camera_data);
         commands.entity(entity).insert(camera_data);
 
         // Insert state components if needed
@@ -174,8 +268,6 @@ pub(super) fn apply_set_game_camera_requests(
             }
             _ => {}
         }
-
-        events::emit_game_camera_changed(&request.entity_id, &request.mode, &request.target_entity);
     }
 }
 
@@ -202,18 +294,41 @@ pub(super) fn apply_set_active_game_camera_requests(
 
 pub(super) fn apply_camera_shake_requests(
     mut pending: ResMut<PendingCommands>,
-    mut camera_query: Query<&mut GameCameraData, With<ActiveGameCamera>>,
+    mut shake: ResMut<CameraShakeState>,
 ) {
-    let requests: Vec<_> = pending.camera_shake_requests.drain(..).collect();
-    for request in requests {
-        if let Ok(mut camera_data) = camera_query.single_mut() {
-            camera_data.shake_intensity = request.intensity;
-            camera_data.shake_duration = request.duration;
-            camera_data.shake_timer = request.duration;
+    for request in pending.camera_shake_requests.drain(..) {
+        shake.add(request.intensity, request.duration);
+        events::emit_camera_shake_started(request.intensity, request.duration, &shake);
+    }
+}
+
+/// Emit the following camera's resolved position every Play-mode frame.
+pub(super) fn emit_game_camera_follow(
+    camera_query: Query<(&EntityId, &Transform, &GameCameraData), With<ActiveGameCamera>>,
+) {
+    for (eid, transform, camera_data) in camera_query.iter() {
+        if let Some(target_id) = &camera_data.follow_target_id {
+            events::emit_game_camera_follow(&eid.0, target_id, transform.translation.to_array());
         }
     }
 }
 
+/// Emit ENTITY_DIED for every death reported by the health system this frame.
+pub(super) fn emit_entity_deaths(runtime: Option<ResMut<GameComponentRuntime>>) {
+    let Some(mut runtime) = runtime else { return; };
+    for death in runtime.deaths.drain(..) {
+        events::emit_entity_died(&death.entity_id, death.source_entity_id.as_deref());
+    }
+}
+
+/// Emit TIMER_FIRED for every timer completion this frame.
+pub(super) fn emit_timer_fired(runtime: Option<ResMut<GameComponentRuntime>>) {
+    let Some(mut runtime) = runtime else { return; };
+    for (entity_id, name) in runtime.fired_timers.drain(..) {
+        events::emit_timer_fired(&entity_id, &name);
+    }
+}
+
 pub(super) fn process_game_camera_queries(
     mut pending: ResMut<PendingCommands>,
     camera_query: Query<(&EntityId, Option<&GameCameraData>, Option<&ActiveGameCamera>)>,
@@ -250,7 +365,7 @@ pub(super) fn emit_game_camera_on_selection(
         if let Some(primary) = selection.primary {
             if let Ok((eid, cam_data, active)) = camera_query.get(primary) {
                 if let Some(data) = cam_data {
-                    events::emit_game_camera_changed(&eid.0, &data.mode, &data.target_entity);
+                    events::emit_game_camera_changed(&eid.0, data);
                 }
                 if active.is_some() {
                     events::emit_active_game_camera_changed(&eid.0);
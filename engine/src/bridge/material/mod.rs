@@ -1,20 +1,27 @@
-//! Material, lighting, environment, skybox, post-processing, coordinate mode, and shader systems.
+//! Material, lighting, post-processing, coordinate mode, and shader systems.
 
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility};
 use bevy::prelude::*;
+use std::collections::VecDeque;
 use crate::core::{
-    camera,
-    environment::{self, EnvironmentSettings, SkyboxHandles},
     entity_id::EntityId,
     gizmo::CoordinateMode,
     history::{HistoryStack, UndoableAction},
-    lighting::LightData,
+    lighting::{self, LightData, LightProbeVolume, ProbeSh},
     material::MaterialData,
     pending_commands::PendingCommands,
     post_processing::PostProcessingSettings,
-    shader_effects::{ShaderEffectData, ForgeMaterial, ForgeShaderExtension},
+    selection_outline::OutlineShell,
+    shader_effects::{ShaderEffectData, ForgeMaterial, ForgeShaderExtension, upgrade_to_forge_material},
 };
 use crate::bridge::{events, Selection, SelectionChangedEvent};
 
+#[cfg(not(feature = "runtime"))]
+mod skybox;
+
+#[cfg(not(feature = "runtime"))]
+pub(super) use skybox::*;
+
 /// System that emits material data when the primary selection has a MaterialData component.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn emit_material_on_selection(
@@ -65,252 +72,105 @@ pub(super) fn emit_light_on_selection(
     }
 }
 
-/// System that applies pending environment updates from the bridge.
-#[cfg(not(feature = "runtime"))]
-pub(super) fn apply_environment_updates(
-    mut pending: ResMut<PendingCommands>,
-    mut settings: ResMut<EnvironmentSettings>,
-) {
-    for update in pending.environment_updates.drain(..) {
-        if let Some(v) = update.skybox_brightness { settings.skybox_brightness = v; }
-        if let Some(v) = update.ibl_intensity { settings.ibl_intensity = v; }
-        if let Some(v) = update.ibl_rotation_degrees { settings.ibl_rotation_degrees = v; }
-        if let Some(v) = update.clear_color { settings.clear_color = v; }
-        if let Some(v) = update.fog_enabled { settings.fog_enabled = v; }
-        if let Some(v) = update.fog_color { settings.fog_color = v; }
-        if let Some(v) = update.fog_start { settings.fog_start = v; }
-        if let Some(v) = update.fog_end { settings.fog_end = v; }
-
-        // Emit event back to React with full state
-        events::emit_environment_changed(&settings);
-    }
-}
-
-/// System that applies pending set skybox requests.
-#[cfg(not(feature = "runtime"))]
-pub(super) fn apply_set_skybox_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut settings: ResMut<EnvironmentSettings>,
-    mut skybox_handles: ResMut<SkyboxHandles>,
-    mut images: ResMut<Assets<Image>>,
-    texture_handles: Res<crate::core::asset_manager::TextureHandleMap>,
-    camera_query: Query<Entity, With<camera::EditorCamera>>,
-    mut commands: Commands,
-) {
-    for request in pending.set_skybox_requests.drain(..) {
-        // Update settings fields
-        if let Some(brightness) = request.brightness {
-            settings.skybox_brightness = brightness;
-        }
-        if let Some(intensity) = request.ibl_intensity {
-            settings.ibl_intensity = intensity;
-        }
-        if let Some(rotation) = request.rotation {
-            settings.ibl_rotation_degrees = rotation;
-        }
-
-        // Handle preset or asset ID
-        if let Some(preset) = request.preset {
-            settings.skybox_preset = Some(preset.clone());
-            settings.skybox_asset_id = None;
-
-            // Generate or retrieve cached preset cubemap
-            let handle = if let Some(h) = skybox_handles.handles.get(&preset) {
-                h.clone()
-            } else {
-                let image = environment::generate_preset_cubemap(&preset);
-                let handle = images.add(image);
-                skybox_handles.handles.insert(preset.clone(), handle.clone());
-                handle
-            };
-
-            // Apply to camera
-            if let Ok(camera_entity) = camera_query.single() {
-                commands.entity(camera_entity).insert(bevy::core_pipeline::Skybox {
-                    image: handle,
-                    brightness: settings.skybox_brightness,
-                    ..Default::default()
-                });
-            }
-
-            tracing::info!("Applied skybox preset: {}", preset);
-        } else if let Some(asset_id) = request.asset_id {
-            settings.skybox_asset_id = Some(asset_id.clone());
-            settings.skybox_preset = None;
-
-            // Look up the asset handle from the global texture registry
-            if let Some(handle) = texture_handles.0.get(&asset_id) {
-                if let Ok(camera_entity) = camera_query.single() {
-                    commands.entity(camera_entity).insert(bevy::core_pipeline::Skybox {
-                        image: handle.clone(),
-                        brightness: settings.skybox_brightness,
-                        ..Default::default()
-                    });
-                }
-                tracing::info!("Applied custom skybox asset: {}", asset_id);
-            } else {
-                tracing::warn!("Custom skybox asset not found in texture registry: {}", asset_id);
-            }
-        }
-
-        // Emit event
-        events::emit_environment_changed(&settings);
-    }
-}
-
-/// System that applies pending remove skybox requests.
-#[cfg(not(feature = "runtime"))]
-pub(super) fn apply_remove_skybox_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut settings: ResMut<EnvironmentSettings>,
-    camera_query: Query<Entity, With<camera::EditorCamera>>,
-    mut commands: Commands,
-) {
-    if !pending.remove_skybox_requests.is_empty() {
-        pending.remove_skybox_requests.clear();
-
-        settings.skybox_preset = None;
-        settings.skybox_asset_id = None;
+/// Radiance samples traced per probe while baking.
+const LIGHT_PROBE_BAKE_SAMPLES: usize = 64;
 
-        // Remove Skybox component from camera
-        if let Ok(camera_entity) = camera_query.single() {
-            commands.entity(camera_entity).remove::<bevy::core_pipeline::Skybox>();
-        }
+/// Light probe volumes waiting to be baked, front first.
+#[derive(Resource, Default)]
+pub(super) struct LightProbeBakeQueue(VecDeque<LightProbeBakeJob>);
 
-        tracing::info!("Removed skybox");
-        events::emit_environment_changed(&settings);
-    }
+struct LightProbeBakeJob {
+    entity: Entity,
+    entity_id: String,
+    probes: Vec<ProbeSh>,
 }
 
-/// System that applies pending update skybox requests.
+/// System that queues light probe volumes for baking (editor-only).
 #[cfg(not(feature = "runtime"))]
-pub(super) fn apply_update_skybox_requests(
+pub(super) fn apply_light_probe_bake_requests(
     mut pending: ResMut<PendingCommands>,
-    mut settings: ResMut<EnvironmentSettings>,
-    camera_query: Query<Entity, With<camera::EditorCamera>>,
-    mut skybox_query: Query<&mut bevy::core_pipeline::Skybox>,
-    _commands: Commands,
+    mut queue: ResMut<LightProbeBakeQueue>,
+    volumes: Query<(Entity, &EntityId), With<LightProbeVolume>>,
 ) {
-    for request in pending.update_skybox_requests.drain(..) {
-        if let Some(brightness) = request.brightness {
-            settings.skybox_brightness = brightness;
-        }
-        if let Some(intensity) = request.ibl_intensity {
-            settings.ibl_intensity = intensity;
-        }
-        if let Some(rotation) = request.rotation {
-            settings.ibl_rotation_degrees = rotation;
-        }
-
-        // Update Skybox component brightness
-        if let Ok(camera_entity) = camera_query.single() {
-            if let Ok(mut skybox) = skybox_query.get_mut(camera_entity) {
-                skybox.brightness = settings.skybox_brightness;
+    for request in pending.light_probe_bake_requests.drain(..) {
+        for (entity, entity_id) in volumes.iter() {
+            if request.entity_id.as_ref().is_some_and(|id| *id != entity_id.0) {
+                continue;
             }
+            // Re-baking a volume restarts it from its first probe.
+            queue.0.retain(|job| job.entity != entity);
+            queue.0.push_back(LightProbeBakeJob {
+                entity,
+                entity_id: entity_id.0.clone(),
+                probes: Vec::new(),
+            });
         }
-
-        events::emit_environment_changed(&settings);
     }
 }
 
-/// System that applies custom skybox requests from the bridge.
+/// System that bakes one probe of the front queued volume per frame.
 ///
-/// Receives an equirectangular image (typically from AI skybox generation),
-/// converts it to a 6-face cubemap, and applies it as the scene skybox.
-#[cfg(not(feature = "runtime"))]
-pub(super) fn apply_custom_skybox_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut settings: ResMut<EnvironmentSettings>,
-    mut images: ResMut<Assets<Image>>,
-    camera_query: Query<Entity, With<camera::EditorCamera>>,
-    mut commands: Commands,
+/// Each probe captures incoming radiance by ray casting against scene meshes
+/// in evenly spread directions rather than rendering cubemaps, since GPU
+/// readback is asynchronous in the browser. Hit surfaces are shaded with their
+/// base color under every light (unshadowed) plus emission; rays that escape
+/// contribute nothing, as ambient light is added separately at render time.
+pub(super) fn step_light_probe_bake(
+    mut queue: ResMut<LightProbeBakeQueue>,
+    mut ray_cast: MeshRayCast,
+    mut volumes: Query<(&mut LightProbeVolume, &GlobalTransform)>,
+    lights: Query<(&LightData, &GlobalTransform)>,
+    surfaces: Query<&MaterialData>,
+    outline_shells: Query<(), With<OutlineShell>>,
 ) {
-    use base64::Engine as _;
-    use bevy::image::{ImageType, CompressedImageFormats, ImageSampler};
-    use bevy::asset::RenderAssetUsages;
-
-    for request in pending.custom_skybox_requests.drain(..) {
-        // Guard against excessively large skybox payloads (base64 overhead ~1.33x,
-        // so 10MB decoded ≈ 13.5MB base64).
-        const MAX_SKYBOX_BASE64_LEN: usize = 13_500_000;
-        if request.data_base64.len() > MAX_SKYBOX_BASE64_LEN {
-            tracing::error!(
-                "Custom skybox rejected: base64 payload {} bytes exceeds 10MB limit",
-                request.data_base64.len()
-            );
-            continue;
-        }
-
-        // Parse data URL: "data:image/png;base64,AAAA..."
-        let raw_base64 = if let Some(comma_pos) = request.data_base64.find(',') {
-            &request.data_base64[comma_pos + 1..]
-        } else {
-            // Raw base64 without data URL prefix
-            request.data_base64.as_str()
-        };
-
-        // Decode base64 to raw bytes
-        let bytes = match base64::engine::general_purpose::STANDARD.decode(raw_base64) {
-            Ok(b) => b,
-            Err(e) => {
-                tracing::error!("Failed to decode custom skybox base64: {}", e);
-                continue;
-            }
-        };
+    let Some(job) = queue.0.front_mut() else {
+        return;
+    };
+    let Ok((mut volume, volume_transform)) = volumes.get_mut(job.entity) else {
+        queue.0.pop_front();
+        return;
+    };
 
-        // Create Image from bytes (PNG from AI skybox generation)
-        let source_image = match Image::from_buffer(
-            &bytes,
-            ImageType::Extension("png"),
-            CompressedImageFormats::NONE,
-            true,
-            ImageSampler::Default,
-            RenderAssetUsages::RENDER_WORLD,
-        ) {
-            Ok(img) => img,
-            Err(e) => {
-                tracing::error!("Failed to create image from custom skybox bytes: {}", e);
-                continue;
+    let origin = volume_transform.transform_point(volume.probe_position(job.probes.len()));
+    let filter = |entity: Entity| !outline_shells.contains(entity);
+    let settings = MeshRayCastSettings::default()
+        .with_visibility(RayCastVisibility::Visible)
+        .with_filter(&filter)
+        .always_early_exit();
+    let samples: Vec<(Vec3, [f32; 3])> = lighting::probe_sample_directions(LIGHT_PROBE_BAKE_SAMPLES)
+        .into_iter()
+        .map(|direction| {
+            let Ok(dir) = Dir3::new(direction) else {
+                return (direction, [0.0; 3]);
+            };
+            let Some((entity, hit)) = ray_cast.cast_ray(Ray3d::new(origin, dir), &settings).first() else {
+                return (direction, [0.0; 3]);
+            };
+            // Shade the side of the surface facing the probe.
+            let normal = hit.normal.normalize_or_zero();
+            let normal = if normal.dot(direction) > 0.0 { -normal } else { normal };
+            let (albedo, emissive) = surfaces.get(*entity)
+                .map(|m| ([m.base_color[0], m.base_color[1], m.base_color[2]], [m.emissive[0], m.emissive[1], m.emissive[2]]))
+                .unwrap_or(([0.5; 3], [0.0; 3]));
+
+            let mut radiance = emissive;
+            for (light, light_transform) in lights.iter() {
+                let irradiance = light.irradiance_at(light_transform, hit.point, normal);
+                for ((r, a), e) in radiance.iter_mut().zip(albedo).zip(irradiance) {
+                    *r += a * e / std::f32::consts::PI;
+                }
             }
-        };
-
-        // Check if the image is already a cubemap (6 array layers) or needs conversion
-        let cubemap_image = if source_image.texture_descriptor.size.depth_or_array_layers == 6 {
-            // Already a cubemap — use as-is (just ensure view descriptor is set)
-            let mut img = source_image;
-            img.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
-                dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
-                ..default()
-            });
-            img
-        } else {
-            // Equirectangular image — convert to cubemap
-            // Use face size based on source height, capped at 256 for performance
-            let face_size = (source_image.height() / 2).min(256).max(64);
-            tracing::info!(
-                "Converting equirectangular image ({}x{}) to cubemap ({}x{} per face)",
-                source_image.width(), source_image.height(), face_size, face_size,
-            );
-            environment::equirectangular_to_cubemap(&source_image, face_size)
-        };
-
-        let handle = images.add(cubemap_image);
-
-        // Update settings
-        settings.skybox_preset = None;
-        settings.skybox_asset_id = Some(request.asset_id.clone());
-
-        // Apply to camera
-        if let Ok(camera_entity) = camera_query.single() {
-            commands.entity(camera_entity).insert(bevy::core_pipeline::Skybox {
-                image: handle,
-                brightness: settings.skybox_brightness,
-                rotation: bevy::math::Quat::IDENTITY,
-            });
-        }
-
-        tracing::info!("Applied custom skybox: {}", request.asset_id);
-        events::emit_environment_changed(&settings);
+            (direction, radiance)
+        })
+        .collect();
+    job.probes.push(lighting::project_probe_sh(&samples));
+
+    let total = volume.probe_count();
+    events::emit_light_bake_progress(&job.entity_id, job.probes.len(), total);
+    if job.probes.len() >= total {
+        volume.baked = Some(std::mem::take(&mut job.probes));
+        tracing::info!("Baked {} light probes for entity: {}", total, job.entity_id);
+        queue.0.pop_front();
     }
 }
 
@@ -342,6 +202,9 @@ pub(super) fn apply_post_processing_updates(
         if let Some(mb) = update.motion_blur {
             settings.motion_blur = mb;
         }
+        if let Some(vignette) = update.vignette {
+            settings.vignette = vignette;
+        }
 
         // Emit event back to React with full state
         events::emit_post_processing_changed(&settings);
@@ -395,19 +258,17 @@ pub(super) fn apply_shader_updates(
         if let Ok((_, _, ext_handle)) = ext_mat_query.get(entity) {
             // Update existing extended material
             if let Some(ext_mat) = ext_materials.get_mut(ext_handle) {
+                // Triplanar settings belong to MaterialData; keep them across effect changes.
+                let (triplanar, triplanar_scale) = (ext_mat.extension.triplanar, ext_mat.extension.triplanar_scale);
                 ext_mat.extension = ForgeShaderExtension::from(&update.shader_data);
+                ext_mat.extension.triplanar = triplanar;
+                ext_mat.extension.triplanar_scale = triplanar_scale;
             }
-        } else if let Ok((_, _, std_handle, _mat_data)) = std_mat_query.get(entity) {
+        } else if let Ok((_, _, std_handle, mat_data)) = std_mat_query.get(entity) {
             // Upgrade from StandardMaterial to ExtendedMaterial
-            if let Some(std_mat) = std_materials.get(std_handle.0.id()) {
-                let base = std_mat.clone();
-                let extension = ForgeShaderExtension::from(&update.shader_data);
-                let ext_mat = ForgeMaterial { base, extension };
-                let ext_handle = ext_materials.add(ext_mat);
-                commands.entity(entity)
-                    .remove::<MeshMaterial3d<StandardMaterial>>()
-                    .insert(MeshMaterial3d(ext_handle));
-            }
+            let mut extension = ForgeShaderExtension::from(&update.shader_data);
+            extension.set_triplanar(mat_data);
+            upgrade_to_forge_material(&mut commands, entity, std_handle, &std_materials, &mut ext_materials, extension);
         }
 
         // Insert/update the ShaderEffectData component
@@ -458,14 +319,35 @@ pub(super) fn apply_shader_removals(
 }
 
 /// System that syncs MaterialData changes to ExtendedMaterial entities (always-active).
+///
+/// Turning on triplanar projection upgrades a StandardMaterial entity to
+/// ForgeMaterial, since only the forge shader can sample by world position.
 pub(super) fn sync_extended_material_data(
+    mut commands: Commands,
     query: Query<(&MaterialData, &MeshMaterial3d<ForgeMaterial>), Changed<MaterialData>>,
+    std_query: Query<(Entity, &MaterialData, &MeshMaterial3d<StandardMaterial>), Changed<MaterialData>>,
+    std_materials: Res<Assets<StandardMaterial>>,
     mut ext_materials: ResMut<Assets<ForgeMaterial>>,
     texture_handles: Res<crate::core::asset_manager::TextureHandleMap>,
 ) {
     for (data, handle) in query.iter() {
         if let Some(ext_mat) = ext_materials.get_mut(handle) {
             crate::core::material::apply_material_data_to_standard(&mut ext_mat.base, data, &texture_handles);
+            ext_mat.extension.set_triplanar(data);
+        }
+    }
+
+    for (entity, data, std_handle) in std_query.iter() {
+        if !data.triplanar {
+            continue;
+        }
+        let mut extension = ForgeShaderExtension::default();
+        extension.set_triplanar(data);
+        if let Some(ext_handle) = upgrade_to_forge_material(&mut commands, entity, std_handle, &std_materials, &mut ext_materials, extension) {
+            // The copied StandardMaterial may not have seen this change yet.
+            if let Some(ext_mat) = ext_materials.get_mut(&ext_handle) {
+                crate::core::material::apply_material_data_to_standard(&mut ext_mat.base, data, &texture_handles);
+            }
         }
     }
 }
@@ -507,7 +389,7 @@ pub(super) fn apply_custom_wgsl_source_updates(
 ) {
     use crate::core::custom_wgsl::{validate_wgsl_source, CUSTOM_WGSL_SHADER_HANDLE};
 
-    const TEMPLATE: &str = include_str!("../shaders/custom_wgsl_template.wgsl");
+    const TEMPLATE: &str = include_str!("../../shaders/custom_wgsl_template.wgsl");
     const INJECTION_COMMENT: &str = "    // FORGE_USER_CODE_INJECTION_POINT\n    return base_color;";
 
     for update in pending.custom_wgsl_source_updates.drain(..) {
@@ -635,21 +517,15 @@ pub(super) fn apply_apply_custom_shader_requests(
                 ext_mat.extension.custom_params_2 = p2;
                 ext_mat.extension.custom_params_3 = p3;
             }
-        } else if let Ok((_, _, std_handle, _mat_data)) = std_mat_query.get(entity) {
-            if let Some(std_mat) = std_materials.get(std_handle.0.id()) {
-                let base = std_mat.clone();
-                let mut extension = ForgeShaderExtension::default();
-                extension.custom_slot = req.slot;
-                extension.custom_params_0 = p0;
-                extension.custom_params_1 = p1;
-                extension.custom_params_2 = p2;
-                extension.custom_params_3 = p3;
-                let ext_mat = ForgeMaterial { base, extension };
-                let ext_handle = ext_materials.add(ext_mat);
-                commands.entity(entity)
-                    .remove::<MeshMaterial3d<StandardMaterial>>()
-                    .insert(MeshMaterial3d(ext_handle));
-            }
+        } else if let Ok((_, _, std_handle, mat_data)) = std_mat_query.get(entity) {
+            let mut extension = ForgeShaderExtension::default();
+            extension.custom_slot = req.slot;
+            extension.custom_params_0 = p0;
+            extension.custom_params_1 = p1;
+            extension.custom_params_2 = p2;
+            extension.custom_params_3 = p3;
+            extension.set_triplanar(mat_data);
+            upgrade_to_forge_material(&mut commands, entity, std_handle, &std_materials, &mut ext_materials, extension);
         }
 
         tracing::info!(
@@ -690,7 +566,7 @@ pub(super) fn restitch_custom_shaders(
     }
     registry.dirty = false;
 
-    const BASE: &str = include_str!("../shaders/forge_effects.wgsl");
+    const BASE: &str = include_str!("../../shaders/forge_effects.wgsl");
     const INJECT_START: &str = "// FORGE_CUSTOM_SLOT_INJECTION_START\n";
     const INJECT_END: &str = "// FORGE_CUSTOM_SLOT_INJECTION_END";
 
@@ -0,0 +1,261 @@
+//! Environment and skybox systems (editor-only).
+
+use bevy::prelude::*;
+use crate::core::{
+    camera,
+    environment::{self, EnvironmentSettings, SkyboxHandles},
+    pending_commands::PendingCommands,
+};
+use crate::bridge::events;
+
+/// System that applies pending environment updates from the bridge.
+pub(crate) fn apply_environment_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<EnvironmentSettings>,
+) {
+    for update in pending.environment_updates.drain(..) {
+        if let Some(v) = update.skybox_brightness { settings.skybox_brightness = v; }
+        if let Some(v) = update.ibl_intensity { settings.ibl_intensity = v; }
+        if let Some(v) = update.ibl_rotation_degrees { settings.ibl_rotation_degrees = v; }
+        if let Some(v) = update.clear_color { settings.clear_color = v; }
+        if let Some(v) = update.fog_enabled { settings.fog_enabled = v; }
+        if let Some(v) = update.fog_color { settings.fog_color = v; }
+        if let Some(v) = update.fog_start { settings.fog_start = v; }
+        if let Some(v) = update.fog_end { settings.fog_end = v; }
+        if let Some(v) = update.fog_mode { settings.fog_mode = v; }
+        if let Some(v) = update.fog_density { settings.fog_density = v.max(0.0); }
+        if let Some(v) = update.fog_height_falloff { settings.fog_height_falloff = v.max(0.0); }
+        if let Some(v) = update.fog_base_height { settings.fog_base_height = v; }
+
+        // Emit event back to React with full state
+        events::emit_environment_changed(&settings);
+    }
+}
+
+/// System that applies pending set skybox requests.
+pub(crate) fn apply_set_skybox_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<EnvironmentSettings>,
+    mut skybox_handles: ResMut<SkyboxHandles>,
+    mut images: ResMut<Assets<Image>>,
+    texture_handles: Res<crate::core::asset_manager::TextureHandleMap>,
+    camera_query: Query<Entity, With<camera::EditorCamera>>,
+    mut commands: Commands,
+) {
+    for request in pending.set_skybox_requests.drain(..) {
+        // Update settings fields
+        if let Some(brightness) = request.brightness {
+            settings.skybox_brightness = brightness;
+        }
+        if let Some(intensity) = request.ibl_intensity {
+            settings.ibl_intensity = intensity;
+        }
+        if let Some(rotation) = request.rotation {
+            settings.ibl_rotation_degrees = rotation;
+        }
+
+        // Handle preset or asset ID
+        if let Some(preset) = request.preset {
+            settings.skybox_preset = Some(preset.clone());
+            settings.skybox_asset_id = None;
+            settings.procedural_sky = None;
+
+            // Generate or retrieve cached preset cubemap
+            let handle = if let Some(h) = skybox_handles.handles.get(&preset) {
+                h.clone()
+            } else {
+                let image = environment::generate_preset_cubemap(&preset);
+                let handle = images.add(image);
+                skybox_handles.handles.insert(preset.clone(), handle.clone());
+                handle
+            };
+
+            // Apply to camera
+            if let Ok(camera_entity) = camera_query.single() {
+                commands.entity(camera_entity).insert(bevy::core_pipeline::Skybox {
+                    image: handle,
+                    brightness: settings.skybox_brightness,
+                    ..Default::default()
+                });
+            }
+
+            tracing::info!("Applied skybox preset: {}", preset);
+        } else if let Some(asset_id) = request.asset_id {
+            settings.skybox_asset_id = Some(asset_id.clone());
+            settings.skybox_preset = None;
+            settings.procedural_sky = None;
+
+            // Look up the asset handle from the global texture registry
+            if let Some(handle) = texture_handles.0.get(&asset_id) {
+                if let Ok(camera_entity) = camera_query.single() {
+                    commands.entity(camera_entity).insert(bevy::core_pipeline::Skybox {
+                        image: handle.clone(),
+                        brightness: settings.skybox_brightness,
+                        ..Default::default()
+                    });
+                }
+                tracing::info!("Applied custom skybox asset: {}", asset_id);
+            } else {
+                tracing::warn!("Custom skybox asset not found in texture registry: {}", asset_id);
+            }
+        }
+
+        // Emit event
+        events::emit_environment_changed(&settings);
+    }
+}
+
+/// System that applies pending remove skybox requests.
+pub(crate) fn apply_remove_skybox_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<EnvironmentSettings>,
+    camera_query: Query<Entity, With<camera::EditorCamera>>,
+    mut commands: Commands,
+) {
+    if !pending.remove_skybox_requests.is_empty() {
+        pending.remove_skybox_requests.clear();
+
+        settings.skybox_preset = None;
+        settings.skybox_asset_id = None;
+        settings.procedural_sky = None;
+
+        // Remove Skybox component from camera
+        if let Ok(camera_entity) = camera_query.single() {
+            commands.entity(camera_entity).remove::<bevy::core_pipeline::Skybox>();
+        }
+
+        tracing::info!("Removed skybox");
+        events::emit_environment_changed(&settings);
+    }
+}
+
+/// System that applies pending update skybox requests.
+pub(crate) fn apply_update_skybox_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<EnvironmentSettings>,
+    camera_query: Query<Entity, With<camera::EditorCamera>>,
+    mut skybox_query: Query<&mut bevy::core_pipeline::Skybox>,
+    _commands: Commands,
+) {
+    for request in pending.update_skybox_requests.drain(..) {
+        if let Some(brightness) = request.brightness {
+            settings.skybox_brightness = brightness;
+        }
+        if let Some(intensity) = request.ibl_intensity {
+            settings.ibl_intensity = intensity;
+        }
+        if let Some(rotation) = request.rotation {
+            settings.ibl_rotation_degrees = rotation;
+        }
+
+        // Update Skybox component brightness
+        if let Ok(camera_entity) = camera_query.single() {
+            if let Ok(mut skybox) = skybox_query.get_mut(camera_entity) {
+                skybox.brightness = settings.skybox_brightness;
+            }
+        }
+
+        events::emit_environment_changed(&settings);
+    }
+}
+
+/// System that applies set_procedural_sky requests.
+///
+/// Only updates `EnvironmentSettings`; `environment::sync_procedural_sky`
+/// renders the sky and aims the sun light from there.
+pub(crate) fn apply_procedural_sky_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<EnvironmentSettings>,
+) {
+    for request in pending.procedural_sky_requests.drain(..) {
+        let mut sky = settings.procedural_sky.clone().unwrap_or_default();
+        if let Some(azimuth) = request.sun_azimuth {
+            sky.sun_azimuth = azimuth;
+        }
+        if let Some(elevation) = request.sun_elevation {
+            sky.sun_elevation = elevation.clamp(-90.0, 90.0);
+        }
+        if let Some(turbidity) = request.turbidity {
+            sky.turbidity = turbidity.clamp(1.7, 10.0);
+        }
+        if let Some(albedo) = request.ground_albedo {
+            sky.ground_albedo = albedo.clamp(0.0, 1.0);
+        }
+        if let Some(sun_entity_id) = request.sun_entity_id {
+            sky.sun_entity_id = Some(sun_entity_id).filter(|id| !id.is_empty());
+        }
+
+        settings.skybox_preset = None;
+        settings.skybox_asset_id = None;
+        settings.procedural_sky = Some(sky);
+        events::emit_environment_changed(&settings);
+    }
+}
+
+/// System that applies custom skybox requests from the bridge.
+///
+/// Builds a cubemap from six face images or a single equirectangular image
+/// (typically from AI skybox generation), registers it as a texture asset under
+/// the request's asset id so `set_skybox` can re-apply it, and applies it as
+/// the scene skybox.
+pub(crate) fn apply_custom_skybox_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<EnvironmentSettings>,
+    mut images: ResMut<Assets<Image>>,
+    mut asset_registry: ResMut<crate::core::asset_manager::AssetRegistry>,
+    mut texture_handles: ResMut<crate::core::asset_manager::TextureHandleMap>,
+    camera_query: Query<Entity, With<camera::EditorCamera>>,
+    mut commands: Commands,
+) {
+    use crate::core::asset_manager::{AssetKind, AssetMetadata, AssetSource};
+
+    for request in pending.custom_skybox_requests.drain(..) {
+        // Guard against excessively large skybox payloads (base64 overhead ~1.33x,
+        // so 10MB decoded ≈ 13.5MB base64), counting all six faces together.
+        const MAX_SKYBOX_BASE64_LEN: usize = 13_500_000;
+        let payload_len = request.data_base64.len() + request.faces.iter().map(String::len).sum::<usize>();
+        if payload_len > MAX_SKYBOX_BASE64_LEN {
+            tracing::error!(
+                "Custom skybox rejected: base64 payload {} bytes exceeds 10MB limit",
+                payload_len
+            );
+            continue;
+        }
+
+        let cubemap = match environment::custom_skybox_cubemap(&request) {
+            Ok(image) => image,
+            Err(e) => {
+                tracing::error!("Failed to build custom skybox {}: {}", request.asset_id, e);
+                continue;
+            }
+        };
+
+        let handle = images.add(cubemap);
+        texture_handles.0.insert(request.asset_id.clone(), handle.clone());
+        asset_registry.assets.insert(request.asset_id.clone(), AssetMetadata {
+            id: request.asset_id.clone(),
+            name: request.asset_id.clone(),
+            kind: AssetKind::Texture,
+            file_size: payload_len as u64,
+            source: AssetSource::Upload { filename: request.asset_id.clone() },
+        });
+        events::emit_asset_imported(&request.asset_id, &request.asset_id, "texture", payload_len as u64);
+
+        // Update settings
+        settings.skybox_preset = None;
+        settings.skybox_asset_id = Some(request.asset_id.clone());
+        settings.procedural_sky = None;
+
+        // Apply to camera
+        if let Ok(camera_entity) = camera_query.single() {
+            commands.entity(camera_entity).insert(bevy::core_pipeline::Skybox {
+                image: handle,
+                brightness: settings.skybox_brightness,
+                rotation: bevy::math::Quat::IDENTITY,
+            });
+        }
+
+        tracing::info!("Applied custom skybox: {}", request.asset_id);
+        events::emit_environment_changed(&settings);
+    }
+}
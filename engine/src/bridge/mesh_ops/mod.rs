@@ -0,0 +1,650 @@
+//! Array, clone pattern, subdivide, freeze and combine systems.
+
+use bevy::prelude::*;
+use bevy::mesh::Mesh;
+use crate::core::{
+    self,
+    entity_id::{EntityId, EntityName, EntityVisible},
+    history::{EntitySnapshot as HistEntitySnapshot, HistoryStack, TransformSnapshot},
+    instancing::InstanceData,
+    lighting::LightData,
+    material::MaterialData,
+    particles::{ParticleData, ParticleEnabled},
+    pending_commands::{EntityType, PendingCommands},
+    physics::{PhysicsData, PhysicsEnabled},
+    scripting::ScriptData,
+    selection::{Selection, SelectionChangedEvent},
+    shader_effects::ShaderEffectData,
+    asset_manager::AssetRef,
+    audio::{AudioData, AudioEnabled},
+};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+mod prefab;
+
+pub(super) use prefab::*;
+
+#[wasm_bindgen]
+extern "C" {
+    fn log(s: &str);
+}
+
+/// Entities an array or clone_pattern copy can be made from.
+type CopySourceQuery<'w, 's> = Query<'w, 's, (
+    Entity,
+    &'static EntityId,
+    &'static EntityName,
+    &'static Transform,
+    Option<&'static EntityType>,
+    Option<&'static Mesh3d>,
+    Option<&'static MeshMaterial3d<StandardMaterial>>,
+    Option<&'static PointLight>,
+    Option<&'static DirectionalLight>,
+    Option<&'static SpotLight>,
+    Option<&'static MaterialData>,
+    Option<&'static LightData>,
+    Option<&'static PhysicsData>,
+    Option<&'static PhysicsEnabled>,
+    Option<&'static AssetRef>,
+)>;
+
+/// Everything copied from the source onto each array or clone_pattern copy.
+struct CopySource {
+    name: String,
+    transform: Transform,
+    entity_type: EntityType,
+    mesh: Option<Mesh3d>,
+    material: Option<MeshMaterial3d<StandardMaterial>>,
+    point_light: Option<PointLight>,
+    directional_light: Option<DirectionalLight>,
+    spot_light: Option<SpotLight>,
+    material_data: Option<MaterialData>,
+    light_data: Option<LightData>,
+    physics_data: Option<PhysicsData>,
+    physics_enabled: bool,
+    asset_ref: Option<AssetRef>,
+    script_data: Option<ScriptData>,
+    audio_data: Option<AudioData>,
+    particle_data: Option<ParticleData>,
+    particle_enabled: bool,
+    shader_data: Option<ShaderEffectData>,
+    csg_data: Option<core::csg::CsgMeshData>,
+    procedural_mesh_data: Option<core::procedural_mesh::ProceduralMeshData>,
+}
+
+/// Gather the copy source for `entity_id`, or `None` if no such entity exists.
+#[allow(clippy::too_many_arguments)]
+fn collect_copy_source(
+    entity_id: &str,
+    query: &CopySourceQuery,
+    script_query: &Query<(&EntityId, Option<&ScriptData>)>,
+    audio_query: &Query<(&EntityId, Option<&AudioData>)>,
+    particle_query: &Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
+    shader_query: &Query<(&EntityId, Option<&ShaderEffectData>)>,
+    csg_query: &Query<(&EntityId, Option<&core::csg::CsgMeshData>)>,
+    procedural_mesh_query: &Query<(&EntityId, Option<&core::procedural_mesh::ProceduralMeshData>)>,
+) -> Option<CopySource> {
+    let (_, _, src_name, src_transform, src_entity_type, mesh_h, mat_h, pl, dl, sl, mat_data, light_data, phys_data, phys_enabled, asset_ref) =
+        query.iter().find(|(_, eid, ..)| eid.0 == entity_id)?;
+
+    let (particle_data, particle_enabled) = particle_query.iter().find(|(eid, _, _)| eid.0 == entity_id).map(|(_, pd, pe)| (pd.cloned(), pe.is_some())).unwrap_or((None, false));
+    Some(CopySource {
+        name: src_name.0.clone(),
+        transform: *src_transform,
+        entity_type: src_entity_type.copied().unwrap_or(EntityType::Cube),
+        mesh: mesh_h.cloned(),
+        material: mat_h.cloned(),
+        point_light: pl.cloned(),
+        directional_light: dl.cloned(),
+        spot_light: sl.cloned(),
+        material_data: mat_data.cloned(),
+        light_data: light_data.cloned(),
+        physics_data: phys_data.cloned(),
+        physics_enabled: phys_enabled.is_some(),
+        asset_ref: asset_ref.cloned(),
+        script_data: script_query.iter().find(|(eid, _)| eid.0 == entity_id).and_then(|(_, sd)| sd.cloned()),
+        audio_data: audio_query.iter().find(|(eid, _)| eid.0 == entity_id).and_then(|(_, ad)| ad.cloned()),
+        particle_data,
+        particle_enabled,
+        shader_data: shader_query.iter().find(|(eid, _)| eid.0 == entity_id).and_then(|(_, sed)| sed.cloned()),
+        csg_data: csg_query.iter().find(|(eid, _)| eid.0 == entity_id).and_then(|(_, cmd)| cmd.cloned()),
+        procedural_mesh_data: procedural_mesh_query.iter().find(|(eid, _)| eid.0 == entity_id).and_then(|(_, pmd)| pmd.cloned()),
+    })
+}
+
+/// Spawn one copy of `src` with the given transform and name, returning its
+/// snapshot for the undo history.
+fn spawn_copy(commands: &mut Commands, src: &CopySource, transform: Transform, name: String) -> HistEntitySnapshot {
+    let new_entity_id = EntityId::default();
+    let new_entity_id_str = new_entity_id.0.clone();
+
+    let mut ec = commands.spawn((
+        src.entity_type,
+        new_entity_id,
+        EntityName::new(&name),
+        EntityVisible::default(),
+        transform,
+    ));
+
+    if let Some(ref m) = src.mesh { ec.insert(m.clone()); }
+    if let Some(ref mat) = src.material { ec.insert(mat.clone()); }
+    if let Some(ref p) = src.point_light { ec.insert(p.clone()); }
+    if let Some(ref d) = src.directional_light { ec.insert(d.clone()); }
+    if let Some(ref s) = src.spot_light { ec.insert(s.clone()); }
+    if let Some(ref md) = src.material_data { ec.insert(md.clone()); }
+    if let Some(ref ld) = src.light_data { ec.insert(ld.clone()); }
+    if let Some(ref pd) = src.physics_data { ec.insert(pd.clone()); }
+    if src.physics_enabled { ec.insert(PhysicsEnabled); }
+    if let Some(ref ar) = src.asset_ref { ec.insert(ar.clone()); }
+    if let Some(ref sd) = src.script_data { ec.insert(sd.clone()); }
+    if let Some(ref ad) = src.audio_data { ec.insert(ad.clone()); ec.insert(AudioEnabled); }
+    if let Some(ref pd) = src.particle_data { ec.insert(pd.clone()); }
+    if src.particle_enabled { ec.insert(ParticleEnabled); }
+    if let Some(ref sed) = src.shader_data { ec.insert(sed.clone()); }
+    if let Some(ref cmd) = src.csg_data { ec.insert(cmd.clone()); }
+    if let Some(ref pmd) = src.procedural_mesh_data { ec.insert(pmd.clone()); }
+
+    let mut snap = HistEntitySnapshot::new(new_entity_id_str, src.entity_type, name, TransformSnapshot::from(&transform));
+    snap.material_data = src.material_data.clone();
+    snap.light_data = src.light_data.clone();
+    snap.physics_data = src.physics_data.clone();
+    snap.physics_enabled = src.physics_enabled;
+    snap.asset_ref = src.asset_ref.clone();
+    snap.script_data = src.script_data.clone();
+    snap.audio_data = src.audio_data.clone();
+    snap.particle_data = src.particle_data.clone();
+    snap.particle_enabled = src.particle_enabled;
+    snap.shader_effect_data = src.shader_data.clone();
+    snap.csg_mesh_data = src.csg_data.clone();
+    snap.procedural_mesh_data = src.procedural_mesh_data.clone();
+    snap
+}
+
+/// System that processes pending array requests (duplicate entity in pattern).
+#[allow(clippy::too_many_arguments)]
+pub(super) fn apply_array_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: CopySourceQuery,
+    script_query: Query<(&EntityId, Option<&ScriptData>)>,
+    audio_query: Query<(&EntityId, Option<&AudioData>)>,
+    particle_query: Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
+    shader_query: Query<(&EntityId, Option<&ShaderEffectData>)>,
+    csg_query: Query<(&EntityId, Option<&core::csg::CsgMeshData>)>,
+    procedural_mesh_query: Query<(&EntityId, Option<&core::procedural_mesh::ProceduralMeshData>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    use crate::core::history::UndoableAction;
+    use super::events::{emit_array_completed, emit_procedural_mesh_error};
+
+    for request in pending.array_requests.drain(..) {
+        let Some(src) = collect_copy_source(&request.entity_id, &query, &script_query, &audio_query, &particle_query, &shader_query, &csg_query, &procedural_mesh_query) else {
+            emit_procedural_mesh_error(&format!("Source entity not found: {}", request.entity_id));
+            continue;
+        };
+
+        let offsets = match core::procedural_mesh::array_offsets(&request) {
+            Ok(offsets) => offsets,
+            Err(e) => {
+                emit_procedural_mesh_error(&e);
+                continue;
+            }
+        };
+
+        let created_snapshots: Vec<HistEntitySnapshot> = offsets
+            .into_iter()
+            .map(|offset| {
+                let transform = Transform { translation: src.transform.translation + offset, ..src.transform };
+                spawn_copy(&mut commands, &src, transform, format!("{} (Array)", src.name))
+            })
+            .collect();
+        let created_ids: Vec<String> = created_snapshots.iter().map(|s| s.entity_id.clone()).collect();
+
+        history.push(UndoableAction::ArrayEntity {
+            source_id: request.entity_id.clone(),
+            created_snapshots,
+        });
+
+        emit_array_completed(&request.entity_id, &created_ids);
+    }
+}
+
+/// System that processes pending clone_pattern requests: editable copies in a
+/// linear or radial pattern, each rotated a further step or facing the source.
+/// Recorded as one array action so a single undo removes every clone.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn apply_clone_pattern_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: CopySourceQuery,
+    script_query: Query<(&EntityId, Option<&ScriptData>)>,
+    audio_query: Query<(&EntityId, Option<&AudioData>)>,
+    particle_query: Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
+    shader_query: Query<(&EntityId, Option<&ShaderEffectData>)>,
+    csg_query: Query<(&EntityId, Option<&core::csg::CsgMeshData>)>,
+    procedural_mesh_query: Query<(&EntityId, Option<&core::procedural_mesh::ProceduralMeshData>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    use crate::core::history::UndoableAction;
+    use super::events::{emit_array_completed, emit_procedural_mesh_error};
+
+    for request in pending.clone_pattern_requests.drain(..) {
+        let Some(src) = collect_copy_source(&request.entity_id, &query, &script_query, &audio_query, &particle_query, &shader_query, &csg_query, &procedural_mesh_query) else {
+            emit_procedural_mesh_error(&format!("Source entity not found: {}", request.entity_id));
+            continue;
+        };
+
+        let created_snapshots: Vec<HistEntitySnapshot> = core::procedural_mesh::clone_pattern_transforms(&src.transform, &request)
+            .into_iter()
+            .enumerate()
+            .map(|(i, transform)| spawn_copy(&mut commands, &src, transform, format!("{} ({})", src.name, i + 1)))
+            .collect();
+        let created_ids: Vec<String> = created_snapshots.iter().map(|s| s.entity_id.clone()).collect();
+
+        history.push(UndoableAction::ArrayEntity {
+            source_id: request.entity_id.clone(),
+            created_snapshots,
+        });
+
+        emit_array_completed(&request.entity_id, &created_ids);
+    }
+}
+
+/// System that processes pending instance_array requests.
+/// Spawns one entity carrying the source mesh and material data plus an
+/// `InstanceData` list, drawn by `InstancingPlugin` in a single instanced call.
+pub(super) fn apply_instance_array_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut history: ResMut<HistoryStack>,
+    query: Query<(
+        &EntityId,
+        &EntityName,
+        &Transform,
+        Option<&EntityType>,
+        &Mesh3d,
+        Option<&MaterialData>,
+        Option<&core::procedural_mesh::ProceduralMeshData>,
+    )>,
+) {
+    use crate::core::history::UndoableAction;
+    use crate::core::instancing;
+    use super::events::{emit_instanced_array_created, emit_procedural_mesh_error};
+
+    for request in pending.instance_array_requests.drain(..) {
+        let Some((src_eid, src_name, src_transform, src_entity_type, mesh_h, mat_data, mesh_data)) = query.iter().find(|(eid, ..)| eid.0 == request.entity_id) else {
+            emit_procedural_mesh_error(&format!("Source entity not found or has no mesh: {}", request.entity_id));
+            continue;
+        };
+
+        let offsets = match core::procedural_mesh::array_offsets(&request) {
+            Ok(offsets) => offsets,
+            Err(e) => {
+                emit_procedural_mesh_error(&e);
+                continue;
+            }
+        };
+
+        // Offsets are world-axis aligned (matching array_entity), so undo the
+        // source rotation/scale to express them in the instanced entity's space.
+        let inv_rotation = src_transform.rotation.inverse();
+        let instances = InstanceData {
+            transforms: offsets
+                .iter()
+                .map(|offset| Transform::from_translation((inv_rotation * *offset) / src_transform.scale))
+                .collect(),
+        };
+        let instance_count = instances.transforms.len();
+
+        let entity_type = src_entity_type.copied().unwrap_or(EntityType::Cube);
+        let material = mat_data.cloned().unwrap_or_default();
+        let new_entity_id = EntityId::default();
+        let new_entity_id_str = new_entity_id.0.clone();
+        let name = format!("{} (Instanced)", src_name.0);
+
+        let mut snapshot = HistEntitySnapshot::new(new_entity_id_str.clone(), entity_type, name.clone(), TransformSnapshot::from(src_transform));
+        snapshot.material_data = Some(material.clone());
+        snapshot.procedural_mesh_data = mesh_data.cloned();
+        snapshot.instance_transforms = Some(instances.to_snapshots());
+
+        let mut entity_commands = commands.spawn((
+            entity_type,
+            new_entity_id,
+            EntityName::new(&name),
+            EntityVisible::default(),
+            *src_transform,
+            mesh_h.clone(),
+            material,
+        ));
+        // Procedural sources keep their mesh data so the array survives save/load
+        if let Some(md) = mesh_data {
+            entity_commands.insert(md.clone());
+        }
+        instancing::insert_instances(&mut entity_commands, instances);
+
+        history.push(UndoableAction::InstanceArray {
+            source_id: request.entity_id.clone(),
+            snapshot,
+        });
+
+        tracing::info!("Created instanced array of {} from {}", instance_count, src_eid.0);
+        emit_instanced_array_created(&request.entity_id, &new_entity_id_str, instance_count);
+    }
+}
+
+/// System that processes pending subdivide_mesh requests.
+/// Subdivides the entity's current mesh in place; the entity becomes a
+/// procedural mesh so the result survives save/load.
+pub(super) fn apply_subdivide_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut history: ResMut<HistoryStack>,
+    query: Query<(Entity, &EntityId, &EntityType, &Mesh3d, Option<&core::procedural_mesh::ProceduralMeshData>)>,
+) {
+    use crate::core::history::UndoableAction;
+    use crate::core::procedural_mesh::{ProceduralMeshData, ProceduralOp};
+    use super::events::{emit_mesh_subdivided, emit_procedural_mesh_error};
+
+    for request in pending.subdivide_requests.drain(..) {
+        let Some((entity, _, entity_type, mesh_h, old_mesh_data)) = query.iter().find(|(_, eid, ..)| eid.0 == request.entity_id) else {
+            emit_procedural_mesh_error(&format!("Entity not found or has no mesh: {}", request.entity_id));
+            continue;
+        };
+        if *entity_type == EntityType::Terrain {
+            emit_procedural_mesh_error("Terrain cannot be subdivided; adjust its resolution instead");
+            continue;
+        }
+        let Some(mesh) = meshes.get(&mesh_h.0) else {
+            emit_procedural_mesh_error("Mesh asset not loaded");
+            continue;
+        };
+
+        let subdivided = match core::subdivision::subdivide_mesh(mesh, request.iterations) {
+            Ok(mesh) => mesh,
+            Err(e) => {
+                emit_procedural_mesh_error(&e);
+                continue;
+            }
+        };
+        // Stack passes so a re-subdivided mesh records its total depth.
+        let total_iterations = match old_mesh_data.map(|d| &d.operation) {
+            Some(ProceduralOp::Subdivide { iterations }) => iterations + request.iterations,
+            _ => request.iterations,
+        };
+        let Some(new_mesh_data) = ProceduralMeshData::from_mesh(&subdivided, ProceduralOp::Subdivide { iterations: total_iterations }) else {
+            emit_procedural_mesh_error("Subdivision produced an empty mesh");
+            continue;
+        };
+        let triangle_count = new_mesh_data.indices.len() / 3;
+        let new_mesh = meshes.add(subdivided);
+
+        history.push(UndoableAction::SubdivideMesh {
+            entity_id: request.entity_id.clone(),
+            old_entity_type: *entity_type,
+            old_mesh: mesh_h.0.clone(),
+            new_mesh: new_mesh.clone(),
+            old_mesh_data: old_mesh_data.cloned(),
+            new_mesh_data: new_mesh_data.clone(),
+        });
+
+        commands.entity(entity).insert((EntityType::ProceduralMesh, Mesh3d(new_mesh), new_mesh_data));
+        emit_mesh_subdivided(&request.entity_id, request.iterations, triangle_count);
+    }
+}
+
+/// System that processes pending apply_transform requests.
+/// Bakes the chosen transform components into the entity's mesh and resets
+/// them to identity. Primitives become procedural meshes so the baked shape
+/// survives save/load; CSG results keep their type with updated mesh data.
+#[allow(clippy::type_complexity)]
+pub(super) fn apply_transform_freeze_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut history: ResMut<HistoryStack>,
+    mut query: Query<(
+        Entity,
+        &EntityId,
+        &EntityType,
+        &mut Transform,
+        &Mesh3d,
+        Option<&core::procedural_mesh::ProceduralMeshData>,
+        Option<&core::csg::CsgMeshData>,
+    )>,
+) {
+    use crate::core::csg::CsgMeshData;
+    use crate::core::freeze_transform::{bake_mesh, split_transform, FreezeComponents};
+    use crate::core::history::UndoableAction;
+    use crate::core::procedural_mesh::{ProceduralMeshData, ProceduralOp};
+    use super::events::{emit_procedural_mesh_error, emit_transform_applied};
+
+    for request in pending.apply_transform_requests.drain(..) {
+        let Some((entity, _, entity_type, mut transform, mesh_h, old_mesh_data, old_csg_data)) =
+            query.iter_mut().find(|(_, eid, ..)| eid.0 == request.entity_id)
+        else {
+            emit_procedural_mesh_error(&format!("Entity not found or has no mesh: {}", request.entity_id));
+            continue;
+        };
+        if *entity_type == EntityType::Terrain {
+            emit_procedural_mesh_error("Terrain transforms cannot be applied to its mesh");
+            continue;
+        }
+        let Some(mesh) = meshes.get(&mesh_h.0) else {
+            emit_procedural_mesh_error("Mesh asset not loaded");
+            continue;
+        };
+
+        let freeze = FreezeComponents { position: request.position, rotation: request.rotation, scale: request.scale };
+        let (remaining, baked) = split_transform(&transform, freeze);
+        let frozen = match bake_mesh(mesh, baked) {
+            Ok(mesh) => mesh,
+            Err(e) => {
+                emit_procedural_mesh_error(&e);
+                continue;
+            }
+        };
+
+        // CSG results store their own mesh data; everything else is kept as
+        // procedural mesh data, preserving the generating operation if any.
+        let (new_entity_type, new_mesh_data, new_csg_data) = if old_csg_data.is_some() {
+            let Some(data) = CsgMeshData::from_mesh(&frozen) else {
+                emit_procedural_mesh_error("Applying the transform produced an empty mesh");
+                continue;
+            };
+            (*entity_type, None, Some(data))
+        } else {
+            let operation = old_mesh_data.map(|d| d.operation.clone()).unwrap_or(ProceduralOp::ApplyTransform);
+            let Some(data) = ProceduralMeshData::from_mesh(&frozen, operation) else {
+                emit_procedural_mesh_error("Applying the transform produced an empty mesh");
+                continue;
+            };
+            (EntityType::ProceduralMesh, Some(data), None)
+        };
+        let new_mesh = meshes.add(frozen);
+
+        history.push(UndoableAction::ApplyTransform {
+            entity_id: request.entity_id.clone(),
+            old_entity_type: *entity_type,
+            new_entity_type,
+            old_transform: TransformSnapshot::from(&*transform),
+            new_transform: TransformSnapshot::from(&remaining),
+            old_mesh: mesh_h.0.clone(),
+            new_mesh: new_mesh.clone(),
+            old_mesh_data: old_mesh_data.cloned(),
+            new_mesh_data: new_mesh_data.clone(),
+            old_csg_data: old_csg_data.cloned(),
+            new_csg_data: new_csg_data.clone(),
+        });
+
+        *transform = remaining;
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert((new_entity_type, Mesh3d(new_mesh)));
+        if let Some(data) = new_mesh_data {
+            entity_commands.insert(data);
+        }
+        if let Some(data) = new_csg_data {
+            entity_commands.insert(data);
+        }
+        emit_transform_applied(&request.entity_id, request.position, request.rotation, request.scale);
+    }
+}
+
+/// System that processes pending combine mesh requests.
+pub(super) fn apply_combine_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(
+        Entity,
+        &EntityId,
+        &EntityName,
+        &Transform,
+        Option<&Mesh3d>,
+        Option<&MaterialData>,
+    )>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+    script_query: Query<(&EntityId, Option<&ScriptData>)>,
+    audio_query: Query<(&EntityId, Option<&AudioData>)>,
+    particle_query: Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
+    shader_query: Query<(&EntityId, Option<&ShaderEffectData>)>,
+    csg_query: Query<(&EntityId, Option<&core::csg::CsgMeshData>)>,
+    procedural_mesh_query: Query<(&EntityId, Option<&core::procedural_mesh::ProceduralMeshData>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    use crate::core::history::UndoableAction;
+    use super::events::{emit_procedural_mesh_created, emit_procedural_mesh_error};
+
+    for request in pending.combine_requests.drain(..) {
+        let mut mesh_list: Vec<(Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<u32>, Transform)> = Vec::new();
+        let mut source_snapshots: Vec<HistEntitySnapshot> = Vec::new();
+
+        for entity_id in &request.entity_ids {
+            if let Some((entity, eid, ename, transform, mesh_handle, mat_data)) = query.iter().find(|(_, eid, ..)| &eid.0 == entity_id) {
+                if let Some(mh) = mesh_handle {
+                    if let Some(mesh) = meshes.get(&mh.0) {
+                        use bevy::mesh::VertexAttributeValues;
+                        let positions: Vec<[f32; 3]> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+                            Some(VertexAttributeValues::Float32x3(v)) => v.clone(),
+                            _ => vec![],
+                        };
+                        let normals: Vec<[f32; 3]> = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+                            Some(VertexAttributeValues::Float32x3(v)) => v.clone(),
+                            _ => vec![],
+                        };
+                        let indices: Vec<u32> = match mesh.indices() {
+                            Some(bevy::mesh::Indices::U32(v)) => v.clone(),
+                            Some(bevy::mesh::Indices::U16(v)) => v.iter().map(|i| *i as u32).collect(),
+                            None => vec![],
+                        };
+                        mesh_list.push((positions, normals, indices, *transform));
+                    }
+                }
+
+                let src_script_data = script_query.iter().find(|(sid, _)| sid.0 == eid.0).and_then(|(_, sd)| sd.cloned());
+                let src_audio_data = audio_query.iter().find(|(aid, _)| aid.0 == eid.0).and_then(|(_, ad)| ad.cloned());
+                let (src_particle_data, src_particle_enabled) = particle_query.iter().find(|(pid, _, _)| pid.0 == eid.0).map(|(_, pd, pe)| (pd.cloned(), pe.is_some())).unwrap_or((None, false));
+                let src_shader_data = shader_query.iter().find(|(sid, _)| sid.0 == eid.0).and_then(|(_, sed)| sed.cloned());
+                let src_csg_data = csg_query.iter().find(|(cid, _)| cid.0 == eid.0).and_then(|(_, cmd)| cmd.cloned());
+                let src_procedural_mesh_data = procedural_mesh_query.iter().find(|(pid, _)| pid.0 == eid.0).and_then(|(_, pmd)| pmd.cloned());
+
+                {
+                    let mut snap = HistEntitySnapshot::new(
+                        eid.0.clone(),
+                        EntityType::Cube,
+                        ename.0.clone(),
+                        TransformSnapshot::from(transform),
+                    );
+                    snap.material_data = mat_data.cloned();
+                    snap.script_data = src_script_data;
+                    snap.audio_data = src_audio_data;
+                    snap.particle_data = src_particle_data;
+                    snap.particle_enabled = src_particle_enabled;
+                    snap.shader_effect_data = src_shader_data;
+                    snap.csg_mesh_data = src_csg_data;
+                    snap.procedural_mesh_data = src_procedural_mesh_data;
+                    source_snapshots.push(snap);
+                }
+
+                if request.delete_sources {
+                    commands.entity(entity).despawn();
+                    selection.entities.remove(&entity);
+                    selection.entity_ids.remove(entity_id);
+                }
+            }
+        }
+
+        if mesh_list.is_empty() {
+            emit_procedural_mesh_error("No valid meshes to combine");
+            continue;
+        }
+
+        let (combined_positions, combined_normals, combined_indices) = crate::core::procedural_mesh::combine_meshes_data(mesh_list);
+        let uv_count = combined_normals.len();
+
+        let mesh_data = crate::core::procedural_mesh::ProceduralMeshData {
+            positions: combined_positions,
+            normals: combined_normals,
+            uvs: vec![[0.0, 0.0]; uv_count],
+            indices: combined_indices,
+            operation: crate::core::procedural_mesh::ProceduralOp::Combine,
+        };
+
+        let combined_mesh = crate::core::procedural_mesh::rebuild_procedural_mesh(&mesh_data);
+
+        let name = request.name.unwrap_or_else(|| "Combined Mesh".to_string());
+        let entity_id = EntityId::default();
+        let entity_id_str = entity_id.0.clone();
+
+        let entity = commands.spawn((
+            EntityType::ProceduralMesh,
+            entity_id,
+            EntityName::new(&name),
+            EntityVisible::default(),
+            MaterialData::default(),
+            mesh_data.clone(),
+            Mesh3d(meshes.add(combined_mesh)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.5, 0.5, 0.5),
+                ..default()
+            })),
+            Transform::default(),
+        )).id();
+
+        {
+            let mut result_snap = HistEntitySnapshot::new(
+                entity_id_str.clone(),
+                EntityType::ProceduralMesh,
+                name.clone(),
+                TransformSnapshot {
+                    position: [0.0, 0.0, 0.0],
+                    rotation: [0.0, 0.0, 0.0, 1.0],
+                    scale: [1.0, 1.0, 1.0],
+                },
+            );
+            result_snap.material_data = Some(MaterialData::default());
+            result_snap.procedural_mesh_data = Some(mesh_data);
+            history.push(UndoableAction::CombineMeshes {
+                source_snapshots,
+                result_snapshot: result_snap,
+            });
+        }
+
+        selection.entities.clear();
+        selection.entity_ids.clear();
+        selection.entities.insert(entity);
+        selection.entity_ids.insert(entity_id_str.clone());
+        selection.primary = Some(entity);
+        selection.primary_id = Some(entity_id_str.clone());
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: vec![entity_id_str.clone()],
+            primary_id: Some(entity_id_str.clone()),
+            primary_name: Some(name.clone()),
+        });
+
+        emit_procedural_mesh_created(&entity_id_str, &name, "combine");
+    }
+}
+
@@ -0,0 +1,221 @@
+//! Prefab instantiation system.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::mesh::Mesh;
+use crate::core::{
+    entity_factory,
+    pending_commands::PendingCommands,
+    prefab::{PrefabInstance, PrefabSnapshot},
+    scene_file::{remap_for_merge, SCENE_FORMAT_VERSION},
+    scene_graph::SceneGraphCache,
+};
+
+use super::log;
+
+/// System that processes pending instantiate prefab requests.
+pub(crate) fn apply_instantiate_prefab(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut cache: ResMut<SceneGraphCache>,
+) {
+    for request in pending.instantiate_prefab_requests.drain(..) {
+        // Reject oversized payloads before deserializing (1 MB limit)
+        const MAX_SNAPSHOT_BYTES: usize = 1_048_576;
+        if request.snapshot_json.len() > MAX_SNAPSHOT_BYTES {
+            log(&format!(
+                "Prefab snapshot too large ({} bytes, limit {} bytes) — skipping",
+                request.snapshot_json.len(),
+                MAX_SNAPSHOT_BYTES
+            ));
+            continue;
+        }
+        let prefab = match PrefabSnapshot::from_json(&request.snapshot_json) {
+            Ok(p) => p,
+            Err(e) => {
+                log(&format!("Failed to deserialize prefab snapshot: {}", e));
+                continue;
+            }
+        };
+        if prefab.format_version > SCENE_FORMAT_VERSION {
+            log(&format!("Unsupported prefab format version: {}", prefab.format_version));
+            continue;
+        }
+
+        // Fresh IDs, so a prefab can be instantiated any number of times
+        let mut snapshots = remap_for_merge(prefab.entities, None, None);
+        let Some(root) = snapshots.first_mut() else {
+            continue;
+        };
+
+        // Override position if provided
+        if let Some(pos) = request.position {
+            root.transform.position = pos;
+        }
+
+        // Override name if provided
+        if let Some(name) = request.name {
+            root.name = name;
+        }
+
+        // Link to the prefab so update_prefab can reach this instance
+        if let Some(prefab_id) = request.prefab_id {
+            root.prefab_instance = Some(PrefabInstance::new(prefab_id));
+        }
+
+        // Spawn the subtree from the snapshots, then restore the hierarchy
+        let mut id_to_entity: HashMap<&str, Entity> = HashMap::new();
+        for snap in &snapshots {
+            let entity = entity_factory::spawn_from_snapshot(&mut commands, &mut meshes, &mut materials, snap);
+            id_to_entity.insert(snap.entity_id.as_str(), entity);
+        }
+        for snap in &snapshots {
+            if let Some(ref parent_id) = snap.parent_id {
+                if let (Some(&child), Some(&parent)) =
+                    (id_to_entity.get(snap.entity_id.as_str()), id_to_entity.get(parent_id.as_str()))
+                {
+                    commands.entity(child).insert(ChildOf(parent));
+                }
+            }
+        }
+
+        // Mark scene graph as dirty to trigger update event
+        cache.dirty = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bridge::events;
+    use crate::core::entity_id::{EntityId, EntityName, EntityVisible};
+    use crate::core::history::HistoryStack;
+    use crate::core::material::MaterialData;
+    use crate::core::pending_commands::EntityType;
+    use crate::core::selection::Selection;
+    use crate::core::asset_manager::{AssetKind, AssetMetadata, AssetRegistry, AssetSource};
+    use crate::core::clipboard::{apply_copy_requests, EntityClipboard};
+    use crate::core::pending::{CreatePrefabRequest, ExportPrefabLibraryRequest, InstantiatePrefabRequest};
+    use crate::core::prefab::PrefabLibrary;
+
+    fn spawn_cube(app: &mut App, id: &str) -> Entity {
+        app.world_mut()
+            .spawn((EntityType::Cube, EntityId(id.to_string()), EntityName::new(id), EntityVisible::default(), Transform::from_xyz(1.0, 0.0, 0.0)))
+            .id()
+    }
+
+    #[test]
+    fn prefab_from_parent_instantiates_full_subtree() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<AssetRegistry>()
+            .init_resource::<EntityClipboard>()
+            .init_resource::<Selection>()
+            .init_resource::<SceneGraphCache>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, (apply_copy_requests, apply_instantiate_prefab).chain());
+
+        let parent = spawn_cube(&mut app, "cart");
+        let wheels = [spawn_cube(&mut app, "wheel-l"), spawn_cube(&mut app, "wheel-r")];
+        app.world_mut().entity_mut(parent).add_children(&wheels);
+        app.world_mut().resource_mut::<PendingCommands>().queue_create_prefab(CreatePrefabRequest {
+            entity_id: "cart".to_string(),
+            prefab_name: "Cart".to_string(),
+            link_source: true,
+        });
+
+        let emitted = events::capture_events(|| app.update());
+        let (_, payload) = emitted.iter().find(|(t, _)| t == "PREFAB_CREATED").expect("PREFAB_CREATED");
+        let prefab_id = payload["prefabId"].as_str().unwrap().to_string();
+        assert_eq!(app.world().get::<PrefabInstance>(parent).map(|i| i.prefab_id.clone()), Some(prefab_id.clone()));
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_instantiate_prefab(InstantiatePrefabRequest {
+            snapshot_json: payload["snapshotJson"].as_str().unwrap().to_string(),
+            position: Some([0.0, 0.0, 5.0]),
+            name: None,
+            prefab_id: Some(prefab_id.clone()),
+        });
+        app.update();
+
+        let originals = ["cart", "wheel-l", "wheel-r"];
+        let spawned: Vec<Entity> = app
+            .world_mut()
+            .query::<(Entity, &EntityId)>()
+            .iter(app.world())
+            .filter(|(_, id)| !originals.contains(&id.0.as_str()))
+            .map(|(e, _)| e)
+            .collect();
+        assert_eq!(spawned.len(), 3);
+
+        let roots: Vec<Entity> = spawned.iter().copied().filter(|e| app.world().get::<ChildOf>(*e).is_none()).collect();
+        assert_eq!(roots.len(), 1);
+        let root = roots[0];
+        assert_eq!(app.world().get::<EntityName>(root).unwrap().0, "cart");
+        assert_eq!(app.world().get::<Transform>(root).unwrap().translation, Vec3::new(0.0, 0.0, 5.0));
+        assert_eq!(app.world().get::<PrefabInstance>(root).map(|i| i.prefab_id.clone()), Some(prefab_id));
+
+        let children = app.world().get::<Children>(root).expect("instance has children");
+        assert_eq!(children.len(), 2);
+        for child in children.iter() {
+            assert!(app.world().get::<EntityName>(child).unwrap().0.starts_with("wheel-"));
+            assert_eq!(app.world().get::<Transform>(child).unwrap().translation, Vec3::X);
+        }
+    }
+
+    #[test]
+    fn prefab_library_exports_each_selected_root_with_its_assets() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<AssetRegistry>()
+            .init_resource::<EntityClipboard>()
+            .init_resource::<Selection>()
+            .add_systems(Update, apply_copy_requests);
+
+        let mut registry = app.world_mut().resource_mut::<AssetRegistry>();
+        for id in ["bricks", "planks"] {
+            registry.assets.insert(id.to_string(), AssetMetadata {
+                id: id.to_string(),
+                name: id.to_string(),
+                kind: AssetKind::Texture,
+                file_size: 1,
+                source: AssetSource::Upload { filename: format!("{id}.png") },
+            });
+        }
+
+        let textured = |texture: &str| MaterialData { base_color_texture: Some(texture.to_string()), ..Default::default() };
+        let wall = spawn_cube(&mut app, "wall");
+        app.world_mut().entity_mut(wall).insert(textured("bricks"));
+        let crate_box = spawn_cube(&mut app, "crate");
+        let lid = spawn_cube(&mut app, "lid");
+        app.world_mut().entity_mut(lid).insert(textured("planks"));
+        app.world_mut().entity_mut(crate_box).add_child(lid);
+
+        // The lid is selected with its parent, so it exports inside the crate prefab
+        let mut selection = app.world_mut().resource_mut::<Selection>();
+        selection.entities.extend([wall, crate_box, lid]);
+        app.world_mut().resource_mut::<PendingCommands>().queue_export_prefab_library(ExportPrefabLibraryRequest {
+            entity_ids: None,
+        });
+
+        let emitted = events::capture_events(|| app.update());
+        let (_, payload) = emitted.iter().find(|(t, _)| t == "PREFAB_LIBRARY_EXPORTED").expect("PREFAB_LIBRARY_EXPORTED");
+        assert_eq!(payload["prefabCount"], 2);
+        let library: PrefabLibrary = serde_json::from_str(payload["json"].as_str().unwrap()).unwrap();
+
+        let summary: Vec<(&str, usize, Vec<&str>)> = library.prefabs.iter()
+            .map(|p| (p.name.as_str(), p.snapshot.entities.len(), p.asset_ids.iter().map(String::as_str).collect()))
+            .collect();
+        assert_eq!(summary, vec![("wall", 1, vec!["bricks"]), ("crate", 2, vec!["planks"])]);
+        assert_ne!(library.prefabs[0].prefab_id, library.prefabs[1].prefab_id);
+        let mut shared: Vec<&str> = library.assets.keys().map(String::as_str).collect();
+        shared.sort();
+        assert_eq!(shared, vec!["bricks", "planks"]);
+    }
+}
@@ -22,6 +22,7 @@ mod sprite;
 mod edit_mode;
 
 use bevy::prelude::*;
+use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::window::{PresentMode, Window, WindowPlugin};
 use serde::Serialize;
 use std::cell::RefCell;
@@ -51,6 +52,10 @@ use crate::core::{
     scene_graph::{self, SceneGraphCache},
     selection::{Selection, SelectionChangedEvent},
     shader_effects::ShaderEffectsPlugin,
+    spatial_query::SpatialQueryPlugin,
+    terrain_material::TerrainMaterialPlugin,
+    reverb_zone::ReverbZonePlugin,
+    instancing::InstancingPlugin,
     custom_wgsl::CustomWgslPlugin,
 };
 
@@ -59,7 +64,12 @@ use crate::core::{
 use crate::core::{
     engine_mode::{EditorSystemSet, EditorApplySet, EditorEmitSet, in_edit_mode},
     gizmo::ForgeGizmoPlugin,
+    measurement::MeasurementPlugin,
+    path::PathGizmoPlugin,
+    render_mode::RenderModePlugin,
+    selection_outline::SelectionOutlinePlugin,
     snap::SnapPlugin,
+    viewport::ViewportLayoutPlugin,
     visibility,
 };
 
@@ -188,6 +198,7 @@ pub fn init_engine(canvas_id: &str) -> Result<(), JsValue> {
 
     // Picking and other plugins
     app.add_plugins(MeshPickingPlugin)
+        .add_plugins(FrameTimeDiagnosticsPlugin::default())
         .add_plugins(ObservabilityPlugin)
         .add_plugins(SelectionPlugin)
         .add_plugins(MaterialPlugin)
@@ -198,15 +209,24 @@ pub fn init_engine(canvas_id: &str) -> Result<(), JsValue> {
         .add_plugins(PhysicsPlugin)
         .add_plugins(Physics2dPlugin)
         .add_plugins(ShaderEffectsPlugin)
+        .add_plugins(TerrainMaterialPlugin)
+        .add_plugins(ReverbZonePlugin)
+        .add_plugins(InstancingPlugin)
         .add_plugins(CustomWgslPlugin)
         .add_plugins(CameraControlPlugin)
+        .add_plugins(SpatialQueryPlugin)
         .add_plugins(core::game_camera::GameCameraPlugin)
         .add_plugins(core::game_components::GameComponentsPlugin);
 
     // Editor-only plugins
     #[cfg(not(feature = "runtime"))]
     app.add_plugins(ForgeGizmoPlugin)
-        .add_plugins(SnapPlugin);
+        .add_plugins(SnapPlugin)
+        .add_plugins(MeasurementPlugin)
+        .add_plugins(PathGizmoPlugin)
+        .add_plugins(SelectionOutlinePlugin)
+        .add_plugins(ViewportLayoutPlugin)
+        .add_plugins(RenderModePlugin);
 
     app.add_systems(PreStartup, || {
             emit_init_event("renderer_init", Some("Acquiring GPU adapter..."), None);
@@ -338,10 +358,16 @@ impl Plugin for SelectionPlugin {
             .init_resource::<core::project_type::ProjectType>()
             .init_resource::<core::tilemap::Grid2dConfig>()
             .init_resource::<core::lod::PerformanceMetrics>()
+            .init_resource::<core::lod::StatsStream>()
             .init_resource::<core::lod::SimplificationBackend>()
             .init_resource::<core::custom_wgsl::CustomShaderRegistry>()
             .init_resource::<core::sprite::SortingLayerConfig>()
             .init_resource::<scripts::PlayTickCache>()
+            .init_resource::<core::scripting::ScriptErrorLog>()
+            .init_resource::<material::LightProbeBakeQueue>()
+            .init_resource::<core::clipboard::EntityClipboard>()
+            .init_resource::<core::command_log::CommandLog>()
+            .init_resource::<core::command_log::CommandReplay>()
             .add_message::<SelectionChangedEvent>();
 
         #[cfg(not(feature = "runtime"))]
@@ -351,10 +377,14 @@ impl Plugin for SelectionPlugin {
             .add_systems(Startup, (core_systems::register_pending_commands_resource, core_systems::register_history_stack_resource))
             // Always-active systems: run in both editor and runtime
             .add_systems(Update, query::process_query_requests)
+            .add_systems(Update, core_systems::apply_event_throttle_requests)
+            .add_systems(Update, (core::command_log::apply_command_log_requests, core::command_log::run_command_replay).chain())
+            .add_systems(Last, core_systems::flush_throttled_events)
             .add_systems(Update, query::process_terrain_queries)
             .add_systems(Update, query::process_quality_queries)
             .add_systems(Update, query::process_reverb_zone_queries)
-            .add_systems(Update, query::process_play_state_queries);
+            .add_systems(Update, query::process_play_state_queries)
+            .add_systems(Update, query::process_spatial_queries);
 
         #[cfg(not(feature = "runtime"))]
         app.add_systems(Update, query::process_joint_queries);
@@ -363,10 +393,18 @@ impl Plugin for SelectionPlugin {
             .add_systems(Update, scripts::emit_play_tick_system)
             .add_systems(Update, core_systems::apply_mode_change_requests)
             .add_systems(Update, scripts::apply_input_binding_updates)
+            .add_systems(Update, scripts::apply_input_context_requests)
             .add_systems(Update, physics::apply_physics_updates)
             .add_systems(Update, physics::apply_physics_toggles)
             .add_systems(Update, physics::apply_force_applications)
+            .add_systems(Update, physics::apply_joint_motor_targets)
+            .add_systems(Update, physics::apply_physics_timestep_requests)
             .add_systems(Update, scripts::apply_script_updates)
+            .add_systems(Update, scripts::emit_script_events)
+            .add_systems(Update, (
+                scripts::apply_script_error_reports,
+                scripts::process_script_error_queries,
+            ).chain().before(scripts::apply_script_updates))
             // 2D Physics systems (always-active, metadata-only)
             .add_systems(Update, (
                 physics::apply_physics2d_updates,
@@ -375,7 +413,7 @@ impl Plugin for SelectionPlugin {
             ))
             .add_systems(Update, (
                 physics::apply_impulse_applications2d,
-                physics::apply_raycast2d_requests,
+                physics::apply_raycast2d_queries,
             ))
             // Collision/raycast systems (always-active, split to stay under tuple limit)
             .add_systems(Update, (
@@ -394,27 +432,39 @@ impl Plugin for SelectionPlugin {
                 audio::apply_audio_bus_updates,
                 audio::apply_reverb_zone_updates,
             ))
-            .add_systems(Update, audio::apply_reverb_zone_toggles)
+            .add_systems(Update, (
+                audio::apply_reverb_zone_toggles,
+                audio::apply_reverb_zone_removals,
+                audio::emit_reverb_zone_transitions,
+            ))
             // Audio bus systems (always-active, split to stay under tuple limit)
             .add_systems(Update, (
                 audio::apply_audio_bus_creates,
                 audio::apply_audio_bus_deletes,
                 audio::apply_audio_bus_effects_updates,
             ))
-            .add_systems(Update, query::apply_quality_presets)
+            .add_systems(Update, (query::apply_quality_presets, core::quality::apply_quality_shadows).chain())
             // Entity factory and particle systems (always-active, split to stay under tuple limit)
             .add_systems(Update, entity_factory::apply_spawn_requests)
+            .add_systems(Update, entity_factory::apply_spawn_configured_requests)
             .add_systems(Update, entity_factory::apply_delete_requests)
             .add_systems(Update, particles::apply_particle_updates)
             .add_systems(Update, particles::apply_particle_toggles)
             .add_systems(Update, particles::apply_particle_removals)
             .add_systems(Update, particles::apply_particle_preset_requests)
-            .add_systems(Update, particles::apply_particle_playback);
+            .add_systems(Update, particles::apply_particle_playback)
+            .add_systems(Update, particles::apply_particle_sub_emitter_updates)
+            .add_systems(Update, particles::apply_particle_force_field_updates)
+            .add_systems(Update, core::particles::sync_particle_force_fields);
 
         // WebGPU-only: sync ParticleData to bevy_hanabi GPU effects
         #[cfg(feature = "webgpu")]
         app.add_systems(Update, particles::sync_hanabi_effects);
 
+        // WebGPU-only: coarse occlusion culling toggled by set_occlusion_culling
+        #[cfg(feature = "webgpu")]
+        app.add_plugins(core::occlusion::OcclusionCullingPlugin);
+
         app
             // glTF scene spawn system (always-active): spawns loaded glTF scenes as children
             .add_systems(Update, scene_io::apply_gltf_scene_spawn)
@@ -422,11 +472,15 @@ impl Plugin for SelectionPlugin {
             .add_systems(Update, (
                 animation::register_gltf_animations,
                 animation::apply_animation_requests,
+                animation::apply_animation_event_adds,
+                animation::emit_animation_events,
             ))
             // Shader sync systems (always-active)
             .add_systems(Update, material::sync_extended_material_data)
             .add_systems(Update, material::sync_custom_wgsl_uniforms)
             .add_systems(Update, material::sync_forge_shader_time)
+            // Light probe baking (always-active): bakes one probe per frame
+            .add_systems(Update, material::step_light_probe_bake)
             // Sprite rendering pipeline (always-active): spawn sprites, sync SpriteData -> Bevy Sprite
             .add_systems(Update, sprite::apply_spawn_sprite_requests)
             .add_systems(Update, sprite::apply_sprite_data_updates)
@@ -442,6 +496,10 @@ impl Plugin for SelectionPlugin {
                 sprite::apply_animation_state_machine_removals,
             ))
             .add_systems(Update, sprite::sync_sprite_sheet_atlas)
+            .add_systems(Update, (
+                core::sprite::apply_sprite_animation_requests,
+                core::sprite::advance_sprite_animations,
+            ).chain())
             .add_systems(Update, sprite::evaluate_animation_state_machine)
             .add_systems(Update, sprite::animate_sprite_frames)
             // Tilemap rendering pipeline (always-active): apply updates, build tile sprites
@@ -451,14 +509,15 @@ impl Plugin for SelectionPlugin {
             // Tileset CRUD (always-active): set/remove TilesetData on entities
             .add_systems(Update, sprite::apply_set_tileset_requests)
             .add_systems(Update, sprite::apply_remove_tileset_requests)
+            .add_systems(Update, core::tileset::apply_set_tileset_autotile_requests.after(sprite::apply_set_tileset_requests))
             // Sorting layers (always-active): update SortingLayerConfig resource
             .add_systems(Update, sprite::apply_set_sorting_layers)
             .add_systems(Update, sprite::sync_sprite_z_with_sorting_config)
             // Tile paint/erase/fill (always-active)
             .add_systems(Update, (
-                sprite::apply_paint_tile_requests,
-                sprite::apply_erase_tile_requests,
-                sprite::apply_fill_tiles_requests,
+                core::tilemap::apply_paint_tile_requests,
+                core::tilemap::apply_erase_tile_requests,
+                core::tilemap::apply_fill_tiles_requests,
             ))
             // Animated tiles (always-active)
             .add_systems(Update, sprite::animate_tilemap_tiles)
@@ -471,15 +530,34 @@ impl Plugin for SelectionPlugin {
             .add_systems(Update, sprite::apply_project_type_changes)
             .add_systems(Update, sprite::apply_camera_2d_updates)
             .add_systems(Update, sprite::sync_camera_2d_rendering)
-            // 2D play-mode systems: camera bounds clamping + pixel-perfect snapping
+            // 2D play-mode systems: camera follow, bounds clamping, parallax + pixel-perfect snapping
+            .add_systems(Update, core::camera_2d::follow_camera_2d_target
+                .before(sprite::clamp_camera_2d_bounds))
             .add_systems(Update, sprite::clamp_camera_2d_bounds.in_set(PlaySystemSet))
+            .add_systems(Update, core::camera_2d::apply_sprite_parallax
+                .after(sprite::clamp_camera_2d_bounds)
+                .before(sprite::apply_pixel_perfect_snapping))
             .add_systems(Update, sprite::apply_pixel_perfect_snapping.in_set(PlaySystemSet))
+            // Game camera follow: report the resolved position after the camera moves
+            .add_systems(Update, game::emit_game_camera_follow
+                .after(core::game_camera::game_camera_system)
+                .in_set(PlaySystemSet))
+            // Health deaths: report after the health system detects them
+            .add_systems(Update, game::emit_entity_deaths
+                .after(core::game_components::system_health)
+                .in_set(PlaySystemSet))
+            .add_systems(Update, game::emit_timer_fired
+                .after(core::game_components::system_timer)
+                .in_set(PlaySystemSet))
             .add_systems(PostUpdate, (
                 scene_graph::detect_entity_added,
                 scene_graph::detect_entity_removed,
                 scene_graph::detect_name_changed,
                 scene_graph::detect_visibility_changed,
                 scene_graph::detect_parent_changed,
+                scene_graph::detect_scene_replaced,
+                scene_graph::apply_scene_graph_refresh_requests,
+                scene_graph::assign_sort_indices,
                 scene_graph::build_scene_graph,
             ).chain())
             // Skeletal 2D: init skinned meshes (runs when SkinnedMeshInitialized marker absent).
@@ -488,17 +566,26 @@ impl Plugin for SelectionPlugin {
             // to apply_vertex_skinning_2d within the same frame.
             .add_systems(Update, skeleton2d::init_skinned_meshes_2d
                 .before(skeleton2d::apply_vertex_skinning_2d))
-            // Skeletal 2D runtime: animate -> IK -> compute world transforms -> skin vertices
+            // Skeletal 2D runtime: animate -> blend -> IK -> compute world transforms -> skin vertices
             .add_systems(Update, (
+                core::skeletal_animation2d::apply_set_ik_target2d_requests,
+                core::blend_tree2d::record_blend_clips2d,
+                core::blend_tree2d::apply_blend_tree2d_requests,
                 skeleton2d::advance_skeleton_animation,
-                skeleton2d::solve_ik_constraints_2d,
+                core::blend_tree2d::evaluate_blend_trees_2d,
+                core::skeletal_animation2d::solve_ik_constraints_2d,
                 skeleton2d::compute_bone_world_transforms_2d,
                 skeleton2d::apply_vertex_skinning_2d,
             ).chain())
             // LOD runtime: distance-based LOD level switching + performance metrics
             .add_systems(Update, performance::update_lod_levels)
             .add_systems(Update, performance::regenerate_missing_lod_meshes)
-            .add_systems(Update, performance::collect_performance_metrics);
+            .add_systems(Update, performance::collect_performance_metrics)
+            .add_systems(Update, performance::emit_stats_stream.after(performance::collect_performance_metrics))
+            .add_systems(Update, (
+                core::lod::collect_visibility_stats,
+                performance::apply_culling_commands,
+            ));
 
         // Editor-only systems and observers
         #[cfg(not(feature = "runtime"))]
@@ -523,23 +610,45 @@ impl Plugin for SelectionPlugin {
                     material::apply_remove_skybox_requests,
                     material::apply_update_skybox_requests,
                     material::apply_custom_skybox_requests,
+                    material::apply_procedural_sky_requests,
                     material::apply_post_processing_updates,
                     material::apply_shader_updates,
                     material::apply_shader_removals,
                     entity_factory::apply_undo_requests,
                     entity_factory::apply_redo_requests,
                     core::reparent::apply_reparent_requests,
+                    core::reparent::apply_group_requests,
+                    core::reparent::apply_ungroup_requests,
                     core_systems::apply_selection_requests,
                 ).in_set(EditorApplySet))
+                // History groups open before and close after everything else in the
+                // frame, so a begin/spawn/end batch sent together lands in one group.
+                .add_systems(First, core::history::apply_begin_history_groups)
+                .add_systems(Last, core::history::apply_end_history_groups)
                 .add_systems(Update, material::apply_custom_wgsl_source_updates.in_set(EditorApplySet))
                 .add_systems(Update, material::apply_register_custom_shader_requests.in_set(EditorApplySet))
                 .add_systems(Update, material::apply_apply_custom_shader_requests.in_set(EditorApplySet))
                 .add_systems(Update, material::apply_remove_custom_shader_slot_requests.in_set(EditorApplySet))
                 .add_systems(Update, material::restitch_custom_shaders.in_set(EditorApplySet))
+                .add_systems(Update, material::apply_light_probe_bake_requests.in_set(EditorApplySet))
+                .add_systems(Update, physics::apply_measurement_requests.in_set(EditorApplySet))
+                .add_systems(Update, physics::apply_drop_to_ground_requests.in_set(EditorApplySet))
+                .add_systems(Update, physics::apply_bake_physics_requests.in_set(EditorApplySet))
+                .add_systems(Update, (
+                    core::clipboard::apply_copy_requests,
+                    core::clipboard::apply_paste_requests,
+                ).in_set(EditorApplySet))
                 .add_systems(Update, (
                     core_systems::apply_pending_visibility,
+                    core_systems::apply_entity_lock_requests,
+                    core_systems::apply_entity_tag_requests,
+                    core_systems::apply_layout_requests,
                     core_systems::apply_pending_clear_selection,
+                    core_systems::apply_selection_bulk_requests,
+                    core_systems::apply_box_select_requests,
                     core_systems::apply_pending_gizmo_mode,
+                    core_systems::apply_viewport_layout_requests,
+                    core_systems::apply_render_mode_requests,
                 ).in_set(EditorApplySet))
                 .add_systems(Update, (
                     core_systems::process_pick_buffer,
@@ -561,12 +670,21 @@ impl Plugin for SelectionPlugin {
                     sprite::emit_tilemap_on_selection,
                     visibility::sync_visibility,
                 ).chain().in_set(EditorEmitSet))
+                .add_systems(Update, game::emit_path_changes.in_set(EditorEmitSet))
                 .add_systems(Update, animation::poll_animation_state.in_set(EditorSystemSet))
                 .add_systems(Update, procedural::apply_csg_requests.in_set(EditorSystemSet))
                 .add_systems(Update, (
                     procedural::apply_extrude_requests,
                     procedural::apply_lathe_requests,
+                    procedural::apply_loft_requests,
+                    procedural::apply_sweep_requests,
+                    procedural::apply_terrain_hole_paints,
+                    procedural::apply_terrain_splat_updates,
                     mesh_ops::apply_array_requests,
+                    mesh_ops::apply_clone_pattern_requests,
+                    mesh_ops::apply_instance_array_requests,
+                    mesh_ops::apply_subdivide_requests,
+                    mesh_ops::apply_transform_freeze_requests,
                     mesh_ops::apply_combine_requests,
                 ).in_set(EditorSystemSet))
                 .add_systems(Update, (
@@ -585,7 +703,10 @@ impl Plugin for SelectionPlugin {
                 ))
                 .add_systems(Update, (
                     scene_io::apply_scene_export,
+                    scene_io::apply_gltf_export,
                     scene_io::apply_scene_load,
+                    scene_io::apply_scene_merge,
+                    scene_io::apply_scene_patch,
                 ))
                 .add_systems(Update, (
                     scene_io::apply_new_scene,
@@ -598,11 +719,16 @@ impl Plugin for SelectionPlugin {
                     scene_io::apply_delete_asset,
                     scene_io::apply_audio_import,
                     mesh_ops::apply_instantiate_prefab,
+                    core::prefab::apply_prefab_updates,
+                    core::prefab::apply_prefab_library_imports,
                 ))
                 .add_systems(Update, (
                     game::apply_game_component_adds,
                     game::apply_game_component_updates,
                     game::apply_game_component_removals,
+                    game::apply_look_at_constraint_updates,
+                    game::apply_create_path_requests,
+                    game::apply_path_point_adds,
                     game::process_game_component_queries,
                 ))
                 .add_systems(Update, (
@@ -613,6 +739,7 @@ impl Plugin for SelectionPlugin {
                 ))
                 .add_systems(Update, (
                     edit_mode::apply_edit_mode_requests,
+                    edit_mode::apply_normal_edit_requests,
                     edit_mode::emit_edit_mode_selection,
                 ))
                 .add_systems(Update, (
@@ -636,7 +763,7 @@ impl Plugin for SelectionPlugin {
                 ))
                 .add_systems(Update, sprite::render_2d_grid)
                 .add_systems(PostUpdate, (
-                    core_systems::emit_scene_graph_updates,
+                    core_systems::emit_scene_graph_updates.after(scene_graph::build_scene_graph),
                     core_systems::emit_history_updates,
                 ).chain());
         }
@@ -4,7 +4,7 @@
 use bevy::prelude::*;
 use crate::core::{
     entity_id::EntityId,
-    particles::{ParticleData, ParticleEnabled},
+    particles::{ParticleData, ParticleEnabled, ParticleForceField},
     pending_commands::PendingCommands,
     history::HistoryStack,
 };
@@ -152,6 +152,70 @@ pub(super) fn apply_particle_playback(
     pending.particle_playback.clear();
 }
 
+/// System that applies pending particle sub-emitter updates (always-active).
+pub(super) fn apply_particle_sub_emitter_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: Query<(Entity, &EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for update in pending.particle_sub_emitter_updates.drain(..) {
+        let Some((entity, _, current_particle, part_enabled)) = query.iter()
+            .find(|(_, entity_id, _, _)| entity_id.0 == update.entity_id)
+        else {
+            continue;
+        };
+        let Some(current_particle) = current_particle else {
+            tracing::warn!("Entity {} has no particle system to attach a sub-emitter to", update.entity_id);
+            continue;
+        };
+
+        let mut new_data = current_particle.clone();
+        new_data.sub_emitter = update.sub_emitter.map(Box::new);
+        new_data.sub_emitter_trigger = update.trigger;
+        commands.entity(entity).insert(new_data.clone());
+
+        // Record for undo
+        history.push(crate::core::history::UndoableAction::ParticleChange {
+            entity_id: update.entity_id.clone(),
+            old_particle: Some(current_particle.clone()),
+            new_particle: Some(new_data.clone()),
+        });
+
+        events::emit_particle_changed(&update.entity_id, Some(&new_data), part_enabled.is_some());
+    }
+}
+
+/// System that applies pending particle force field updates (always-active).
+pub(super) fn apply_particle_force_field_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: Query<(Entity, &EntityId, Option<&ParticleForceField>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for update in pending.particle_force_field_updates.drain(..) {
+        let Some((entity, _, current_field)) = query.iter()
+            .find(|(_, entity_id, _)| entity_id.0 == update.entity_id)
+        else {
+            continue;
+        };
+
+        match update.force_field {
+            Some(field) => commands.entity(entity).insert(field),
+            None => commands.entity(entity).remove::<ParticleForceField>(),
+        };
+
+        // Record for undo
+        history.push(crate::core::history::UndoableAction::ParticleForceFieldChange {
+            entity_id: update.entity_id.clone(),
+            old_field: current_field.copied(),
+            new_field: update.force_field,
+        });
+
+        events::emit_particle_force_field_changed(&update.entity_id, update.force_field.as_ref());
+    }
+}
+
 /// Emit particle changed events on selection changes and particle data changes.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn emit_particle_on_selection(
@@ -181,6 +245,9 @@ pub(super) fn emit_particle_on_selection(
 // bevy_hanabi GPU particle rendering (WebGPU only)
 // ---------------------------------------------------------------------------
 
+#[cfg(feature = "webgpu")]
+use crate::core::particles::{AppliedForceField, ParticleForceFieldsInRange};
+
 /// Marker component on an entity that links to its child hanabi effect entity.
 #[cfg(feature = "webgpu")]
 #[derive(Component)]
@@ -191,90 +258,200 @@ pub(super) struct HanabiEffectLink(Entity);
 #[derive(Component)]
 pub(super) struct HanabiEffectParent(Entity);
 
+/// Marker component on a child hanabi effect entity that links to its
+/// sub-emitter effect entity (spawned as its child, so it despawns with it).
+#[cfg(feature = "webgpu")]
+#[derive(Component)]
+pub(super) struct HanabiSubEffectLink(Entity);
+
+/// An effect asset plus the flipbook texture bound to its material, if any.
+#[cfg(feature = "webgpu")]
+pub(super) struct HanabiEffectBuild {
+    pub effect: Handle<bevy_hanabi::EffectAsset>,
+    pub texture: Option<Handle<Image>>,
+}
+
+#[cfg(feature = "webgpu")]
+impl HanabiEffectBuild {
+    /// Insert the effect (and its texture material) on an effect entity.
+    fn apply(self, entity: &mut EntityCommands) {
+        entity.insert(bevy_hanabi::ParticleEffect::new(self.effect));
+        match self.texture {
+            Some(texture) => entity.insert(bevy_hanabi::EffectMaterial { images: vec![texture] }),
+            None => entity.remove::<bevy_hanabi::EffectMaterial>(),
+        };
+    }
+}
+
+/// Effect assets built from one `ParticleData`: the main effect plus the
+/// sub-emitter effect fed by its GPU spawn events, if any.
+#[cfg(feature = "webgpu")]
+pub(super) struct HanabiEffectHandles {
+    pub effect: HanabiEffectBuild,
+    pub sub_effect: Option<HanabiEffectBuild>,
+}
+
+/// Spawn the sub-emitter effect entity under a child effect entity.
+#[cfg(feature = "webgpu")]
+fn spawn_hanabi_sub_effect(
+    commands: &mut Commands,
+    effect_entity: Entity,
+    build: HanabiEffectBuild,
+) {
+    let mut sub = commands.spawn((
+        Name::new("particle_sub_effect"),
+        bevy_hanabi::EffectParent::new(effect_entity),
+        Transform::default(),
+        Visibility::default(),
+    ));
+    build.apply(&mut sub);
+    let sub = sub.id();
+    commands.entity(effect_entity)
+        .insert(HanabiSubEffectLink(sub))
+        .add_child(sub);
+}
+
+/// Emitters whose particles were just enabled.
+#[cfg(feature = "webgpu")]
+type HanabiAddedQuery<'w, 's> = Query<'w, 's,
+    (Entity, &'static ParticleData, Option<&'static ParticleForceFieldsInRange>),
+    (With<ParticleEnabled>, Added<ParticleEnabled>),
+>;
+
+/// Enabled emitters, with change ticks for rebuild detection.
+#[cfg(feature = "webgpu")]
+type HanabiEnabledQuery<'w, 's> = Query<'w, 's,
+    (Entity, Ref<'static, ParticleData>, Option<Ref<'static, ParticleForceFieldsInRange>>),
+    With<ParticleEnabled>,
+>;
+
 /// System that synchronises ParticleData/ParticleEnabled ECS components with
 /// actual bevy_hanabi GPU particle effect entities (WebGPU only).
 ///
 /// For each entity that has `ParticleData` + `ParticleEnabled`:
-///   - If no `HanabiEffectLink` exists, create a child effect entity.
-///   - If data changed, recreate the effect asset.
+///   - If no `HanabiEffectLink` exists, create a child effect entity (plus a
+///     sub-emitter effect entity under it when `sub_emitter` is set).
+///   - If data changed, a texture it uses finished loading, or the force
+///     fields in range changed, recreate the effect assets.
 /// For entities that lost `ParticleEnabled` or `ParticleData`:
 ///   - Despawn the child effect entity and remove the link.
 #[cfg(feature = "webgpu")]
 pub(super) fn sync_hanabi_effects(
     mut commands: Commands,
     mut effects: ResMut<Assets<bevy_hanabi::EffectAsset>>,
+    texture_handles: Res<crate::core::asset_manager::TextureHandleMap>,
     // Entities with particle data — we need Added/Changed detection
-    added_q: Query<
-        (Entity, &ParticleData),
-        (With<ParticleEnabled>, Added<ParticleEnabled>),
-    >,
-    changed_q: Query<
-        (Entity, &ParticleData),
-        (With<ParticleEnabled>, Changed<ParticleData>),
-    >,
-    // Entities that have the link but no longer have ParticleEnabled
-    orphan_link_q: Query<
-        (Entity, &HanabiEffectLink),
-        Without<ParticleEnabled>,
-    >,
-    // All entities with link (for data-removed check)
-    all_link_q: Query<(Entity, &HanabiEffectLink, Option<&ParticleData>)>,
+    added_q: HanabiAddedQuery,
+    enabled_q: HanabiEnabledQuery,
+    // All entities with link (for disabled / data-removed checks)
+    link_q: Query<(Entity, &HanabiEffectLink, Has<ParticleData>, Has<ParticleEnabled>)>,
     // Child effect entities
-    effect_parent_q: Query<(Entity, &HanabiEffectParent)>,
+    effect_parent_q: Query<(Entity, &HanabiEffectParent, Option<&HanabiSubEffectLink>)>,
 ) {
     // --- Handle newly enabled particles: spawn child effect entity ---
-    for (entity, data) in added_q.iter() {
-        let handle = build_hanabi_effect(data, &mut effects);
-        let child = commands.spawn((
+    for (entity, data, fields) in added_q.iter() {
+        let fields = fields.map_or(&[][..], |f| &f.0);
+        let handles = build_hanabi_effect(data, fields, &texture_handles, &mut effects);
+        let mut child = commands.spawn((
             Name::new("particle_effect"),
-            bevy_hanabi::ParticleEffect::new(handle),
             HanabiEffectParent(entity),
             Transform::default(),
             Visibility::default(),
-        )).id();
+        ));
+        handles.effect.apply(&mut child);
+        let child = child.id();
+        if let Some(sub_handle) = handles.sub_effect {
+            spawn_hanabi_sub_effect(&mut commands, child, sub_handle);
+        }
         commands.entity(entity).insert(HanabiEffectLink(child));
         commands.entity(entity).add_child(child);
     }
 
     // --- Handle data changes: recreate effect asset ---
-    for (entity, data) in changed_q.iter() {
+    let textures_changed = texture_handles.is_changed();
+    for (entity, data, fields) in enabled_q.iter() {
+        let uses_texture = data.texture_asset_id.is_some()
+            || data.sub_emitter.as_ref().is_some_and(|sub| sub.texture_asset_id.is_some());
+        let texture_loaded = textures_changed && uses_texture;
+        let fields_changed = fields.as_ref().is_some_and(|f| f.is_changed());
+        if !data.is_changed() && !texture_loaded && !fields_changed {
+            continue;
+        }
+        let fields = fields.as_deref().map_or(&[][..], |f| &f.0);
         // Skip if this entity was just added (handled above)
         if added_q.get(entity).is_ok() {
             continue;
         }
         // Find existing child effect entity
-        for (child_entity, parent_link) in effect_parent_q.iter() {
+        for (child_entity, parent_link, sub_link) in effect_parent_q.iter() {
             if parent_link.0 == entity {
-                let new_handle = build_hanabi_effect(data, &mut effects);
-                commands.entity(child_entity).insert(
-                    bevy_hanabi::ParticleEffect::new(new_handle),
-                );
+                let handles = build_hanabi_effect(&data, fields, &texture_handles, &mut effects);
+                handles.effect.apply(&mut commands.entity(child_entity));
+                // The sub-emitter is rebuilt along with its parent effect.
+                if let Some(sub_link) = sub_link {
+                    commands.entity(sub_link.0).despawn();
+                    commands.entity(child_entity).remove::<HanabiSubEffectLink>();
+                }
+                if let Some(sub_handle) = handles.sub_effect {
+                    spawn_hanabi_sub_effect(&mut commands, child_entity, sub_handle);
+                }
                 break;
             }
         }
     }
 
-    // --- Handle disabled particles: despawn child effect entity ---
-    for (entity, link) in orphan_link_q.iter() {
-        commands.entity(link.0).despawn();
-        commands.entity(entity).remove::<HanabiEffectLink>();
-    }
-
-    // --- Handle removed ParticleData: despawn child ---
-    for (entity, link, data) in all_link_q.iter() {
-        if data.is_none() {
+    // --- Handle disabled particles or removed ParticleData: despawn child ---
+    for (entity, link, has_data, enabled) in link_q.iter() {
+        if !has_data || !enabled {
             commands.entity(link.0).despawn();
             commands.entity(entity).remove::<HanabiEffectLink>();
         }
     }
 }
 
-/// Convert a `ParticleData` component into a bevy_hanabi `EffectAsset`.
+/// Convert a `ParticleData` component into bevy_hanabi effect assets. When
+/// the data has a sub-emitter, the main effect emits GPU spawn events on
+/// channel 0 and a second, linked effect consumes them. Flipbook textures
+/// are resolved from `TextureHandleMap`; until one is loaded the effect
+/// renders untextured. Force fields in range apply to both effects.
 #[cfg(feature = "webgpu")]
 pub(super) fn build_hanabi_effect(
     data: &crate::core::particles::ParticleData,
+    force_fields: &[AppliedForceField],
+    textures: &crate::core::asset_manager::TextureHandleMap,
     effects: &mut Assets<bevy_hanabi::EffectAsset>,
-) -> Handle<bevy_hanabi::EffectAsset> {
+) -> HanabiEffectHandles {
+    let mut build = |data: &ParticleData, is_sub_emitter: bool| {
+        let texture = data.texture_asset_id.as_ref()
+            .and_then(|id| textures.0.get(id))
+            .cloned();
+        let asset = build_effect_asset(data, is_sub_emitter, texture.is_some(), force_fields);
+        HanabiEffectBuild { effect: effects.add(asset), texture }
+    };
+
+    let effect = build(data, false);
+    // Sub-emitters inherit the parent's simulation space so the inherited
+    // particle positions stay in the same frame.
+    let sub_effect = data.sub_emitter.as_ref().map(|sub| {
+        let mut sub = (**sub).clone();
+        sub.world_space = data.world_space;
+        build(&sub, true)
+    });
+    HanabiEffectHandles { effect, sub_effect }
+}
+
+///
+/// A sub-emitter effect (`is_sub_emitter`) spawns at the parent particle's
+/// position instead of its emission shape, and its CPU spawner is unused.
+/// `textured` adds a texture slot (bound through `EffectMaterial`) and, for
+/// sprite sheets, advances the flipbook cell by particle age.
+#[cfg(feature = "webgpu")]
+fn build_effect_asset(
+    data: &crate::core::particles::ParticleData,
+    is_sub_emitter: bool,
+    textured: bool,
+    force_fields: &[AppliedForceField],
+) -> bevy_hanabi::EffectAsset {
     use bevy_hanabi::prelude::*;
     use crate::core::particles::*;
 
@@ -343,11 +520,76 @@ pub(super) fn build_hanabi_effect(
     // --- Linear drag ---
     let drag_expr = writer.lit(data.linear_drag).expr();
 
+    // --- Sub-emitter spawn events ---
+    // Hanabi has no particle collision events, so OnCollision falls back to
+    // emitting when the parent particle dies.
+    let spawn_events = data.sub_emitter.as_ref()
+        .filter(|_| !is_sub_emitter)
+        .map(|sub| {
+            let condition = match data.sub_emitter_trigger {
+                SubEmitterTrigger::OnDeath | SubEmitterTrigger::OnCollision => EventEmitCondition::OnDie,
+            };
+            EmitSpawnEventModifier {
+                condition,
+                count: writer.lit(sub.sub_emitter_spawn_count()).expr(),
+                child_index: 0,
+            }
+        });
+
+    // --- Texture / flipbook ---
+    let frame_count = data.flipbook_frame_count();
+    let texture_slot = textured.then(|| writer.lit(0u32).expr());
+    let sprite_index = (textured && frame_count > 1).then(|| {
+        // fps > 0 loops at that rate; 0 plays the sheet once per lifetime.
+        let frame = if data.flipbook_fps > 0.0 {
+            writer.attr(Attribute::AGE).mul(writer.lit(data.flipbook_fps))
+        } else {
+            writer.attr(Attribute::AGE)
+                .div(writer.attr(Attribute::LIFETIME))
+                .mul(writer.lit(frame_count as f32))
+                .min(writer.lit(frame_count as f32 - 1.0))
+        };
+        frame.cast(ScalarType::Int)
+            .rem(writer.lit(frame_count as i32))
+            .expr()
+    });
+
+    // --- Force fields ---
+    // Each field only pushes particles inside its radius: the acceleration
+    // is scaled by step(distance, radius).
+    let force_modifiers: Vec<Box<dyn Modifier + Send + Sync>> = force_fields.iter()
+        .map(|field| {
+            let origin = writer.lit(field.center);
+            let in_range = writer.lit(field.radius)
+                .step(writer.attr(Attribute::POSITION).distance(origin.clone()));
+            let modifier: Box<dyn Modifier + Send + Sync> = match field.kind {
+                ForceFieldKind::Attractor => Box::new(RadialAccelModifier::new(
+                    origin.expr(),
+                    (writer.lit(-field.strength) * in_range).expr(),
+                )),
+                ForceFieldKind::Repulsor => Box::new(RadialAccelModifier::new(
+                    origin.expr(),
+                    (writer.lit(field.strength) * in_range).expr(),
+                )),
+                ForceFieldKind::Vortex => Box::new(TangentAccelModifier::new(
+                    origin.expr(),
+                    writer.lit(Vec3::Y).expr(),
+                    (writer.lit(field.strength) * in_range).expr(),
+                )),
+            };
+            modifier
+        })
+        .collect();
+
     // --- Finish the expression module ---
-    let module = writer.finish();
+    let mut module = writer.finish();
+    if textured {
+        module.add_texture_slot("color");
+    }
 
     // --- Spawner settings ---
     let spawner = match &data.spawner_mode {
+        _ if is_sub_emitter => SpawnerSettings::default(),
         SpawnerMode::Continuous { rate } => SpawnerSettings::rate((*rate).into()),
         SpawnerMode::Burst { count } => SpawnerSettings::once((*count as f32).into()),
         SpawnerMode::Once { count } => SpawnerSettings::once((*count as f32).into()),
@@ -415,10 +657,96 @@ pub(super) fn build_hanabi_effect(
         })
         .render(orient);
 
-    // Add position modifier if not Point
-    if let Some(pos_mod) = position_modifier {
+    if is_sub_emitter {
+        effect = effect.init(InheritAttributeModifier::new(Attribute::POSITION));
+    } else if let Some(pos_mod) = position_modifier {
+        // Add position modifier if not Point
         effect = effect.add_modifier(ModifierContext::Init, pos_mod);
     }
 
-    effects.add(effect)
+    if let Some(spawn_events) = spawn_events {
+        effect = effect.update(spawn_events);
+    }
+
+    for force in force_modifiers {
+        effect = effect.add_modifier(ModifierContext::Update, force);
+    }
+
+    if let Some(texture_slot) = texture_slot {
+        effect = effect.render(ParticleTextureModifier::new(texture_slot));
+    }
+    if let Some(sprite_index) = sprite_index {
+        effect = effect
+            .update(SetAttributeModifier::new(Attribute::SPRITE_INDEX, sprite_index))
+            .render(FlipbookModifier {
+                sprite_grid_size: UVec2::new(data.flipbook_cols.max(1), data.flipbook_rows.max(1)),
+            });
+    }
+
+    effect
+}
+
+#[cfg(all(test, feature = "webgpu"))]
+mod tests {
+    use super::*;
+    use crate::core::particles::SubEmitterTrigger;
+
+    #[test]
+    fn sub_emitter_builds_linked_effect() {
+        let mut effects = Assets::<bevy_hanabi::EffectAsset>::default();
+        let mut data = ParticleData::sparks();
+        data.sub_emitter = Some(Box::new(ParticleData::smoke()));
+        data.sub_emitter_trigger = SubEmitterTrigger::OnCollision;
+
+        let textures = crate::core::asset_manager::TextureHandleMap::default();
+        let handles = build_hanabi_effect(&data, &[], &textures, &mut effects);
+        assert!(effects.get(&handles.effect.effect).is_some());
+        let sub = handles.sub_effect.expect("sub-emitter effect");
+        assert!(effects.get(&sub.effect).is_some());
+
+        let plain = build_hanabi_effect(&ParticleData::sparks(), &[], &textures, &mut effects);
+        assert!(plain.sub_effect.is_none());
+    }
+
+    #[test]
+    fn flipbook_texture_builds_textured_effect() {
+        let mut effects = Assets::<bevy_hanabi::EffectAsset>::default();
+        let mut textures = crate::core::asset_manager::TextureHandleMap::default();
+        textures.0.insert("explosion_sheet".to_string(), Handle::default());
+        let mut data = ParticleData::explosion();
+        data.texture_asset_id = Some("explosion_sheet".to_string());
+        data.flipbook_cols = 4;
+        data.flipbook_rows = 4;
+        data.flipbook_fps = 24.0;
+
+        let handles = build_hanabi_effect(&data, &[], &textures, &mut effects);
+        assert!(handles.effect.texture.is_some());
+        let asset = effects.get(&handles.effect.effect).expect("effect asset");
+        assert_eq!(asset.texture_layout().layout.len(), 1);
+
+        // Unresolved textures build an untextured effect.
+        data.texture_asset_id = Some("missing".to_string());
+        let handles = build_hanabi_effect(&data, &[], &textures, &mut effects);
+        assert!(handles.effect.texture.is_none());
+    }
+
+    #[test]
+    fn force_fields_add_update_modifiers() {
+        use crate::core::particles::ForceFieldKind;
+
+        let mut effects = Assets::<bevy_hanabi::EffectAsset>::default();
+        let textures = crate::core::asset_manager::TextureHandleMap::default();
+        let data = ParticleData::magic_sparkle();
+        let fields = [ForceFieldKind::Attractor, ForceFieldKind::Vortex].map(|kind| AppliedForceField {
+            kind,
+            strength: 6.0,
+            radius: 4.0,
+            center: Vec3::new(1.0, 2.0, 0.0),
+        });
+
+        let plain = build_hanabi_effect(&data, &[], &textures, &mut effects);
+        let with_fields = build_hanabi_effect(&data, &fields, &textures, &mut effects);
+        let count = |handle| effects.get(handle).expect("effect asset").update_modifiers().count();
+        assert_eq!(count(&with_fields.effect.effect), count(&plain.effect.effect) + 2);
+    }
 }
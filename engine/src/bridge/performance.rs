@@ -2,10 +2,13 @@
 
 use bevy::prelude::*;
 use bevy::mesh::Mesh;
+use bevy::camera::visibility::NoFrustumCulling;
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use crate::core::{
     camera::EditorCamera,
     entity_id::EntityId,
-    lod::{LodData, LodMeshes, PerformanceBudget, PerformanceMetrics, SimplificationBackend},
+    instancing::InstanceData,
+    lod::{self, EngineStats, LodData, LodMeshes, PerformanceBudget, PerformanceMetrics, SimplificationBackend, StatsStream},
     pending::PendingCommands,
 };
 use crate::bridge::events;
@@ -13,6 +16,32 @@ use crate::bridge::events;
 /// Number of frames between expensive metrics collection passes.
 const METRICS_COLLECTION_INTERVAL: u32 = 10;
 
+/// Seconds between STATS_UPDATE events while the stats stream is on.
+const STATS_STREAM_INTERVAL: f32 = 0.25;
+
+/// Simplify `source` into LOD levels 1-3, store them as assets and report the
+/// triangle count of every level.
+fn build_lod_meshes(
+    entity_id: &str,
+    source_handle: Handle<Mesh>,
+    source: &Mesh,
+    ratios: &[f32; 3],
+    backend: &SimplificationBackend,
+    meshes: &mut Assets<Mesh>,
+) -> LodMeshes {
+    let mut lod_meshes = LodMeshes::default();
+    let mut triangle_counts = [lod::triangle_count(source), 0, 0, 0];
+    lod_meshes.levels[0] = Some(source_handle);
+
+    for (i, simplified) in lod::generate_lods(source, ratios, backend.backend.as_ref()).into_iter().enumerate() {
+        triangle_counts[i + 1] = lod::triangle_count(&simplified);
+        lod_meshes.levels[i + 1] = Some(meshes.add(simplified));
+    }
+
+    events::emit_lods_generated(entity_id, &triangle_counts);
+    lod_meshes
+}
+
 /// System that processes pending LOD commands including mesh generation.
 #[cfg(not(feature = "runtime"))]
 pub fn apply_lod_commands(
@@ -70,16 +99,9 @@ pub fn apply_lod_commands(
                 // Clone the original mesh to avoid borrow conflicts when adding new meshes
                 let original_mesh_clone = meshes.get(&mesh_handle).cloned();
                 if let Some(original_mesh) = original_mesh_clone {
-                    // Generate simplified meshes using QEM
-                    let mut lod_meshes = LodMeshes::default();
-                    lod_meshes.levels[0] = Some(mesh_handle.clone());
-
-                    for (i, &ratio) in ratios.iter().enumerate() {
-                        let simplified = backend.backend.simplify(&original_mesh, ratio);
-                        let handle = meshes.add(simplified);
-                        lod_meshes.levels[i + 1] = Some(handle);
-                    }
-
+                    let lod_meshes = build_lod_meshes(
+                        &request.entity_id, mesh_handle, &original_mesh, &ratios, &backend, &mut meshes,
+                    );
                     commands.entity(entity).insert(lod_meshes);
 
                     // Also ensure LodData is set
@@ -154,21 +176,41 @@ pub fn apply_performance_budget_commands(
     // Process get_performance_stats requests — emit cached metrics
     for _request in pending.get_performance_stats_requests.drain(..) {
         if let Some(ref m) = metrics {
-            events::emit_performance_stats(
-                m.fps,
-                m.frame_time_ms,
-                m.entity_count,
-                m.triangle_count,
-                m.draw_call_estimate,
-                m.wasm_heap_bytes,
-                m.mesh_memory_bytes,
-            );
+            events::emit_performance_stats(m);
         } else {
-            events::emit_performance_stats(0.0, 0.0, 0, 0, 0, 0, 0);
+            events::emit_performance_stats(&PerformanceMetrics::default());
         }
     }
 }
 
+/// System that processes set_culling_enabled requests.
+/// Kept separate from apply_performance_budget_commands to stay under the
+/// 16 system parameter limit. Only affects mesh entities that exist now;
+/// instanced arrays always keep `NoFrustumCulling`.
+pub(super) fn apply_culling_commands(
+    mut commands: Commands,
+    mut pending: ResMut<PendingCommands>,
+    mesh_query: Query<(Entity, Has<NoFrustumCulling>), (With<Mesh3d>, Without<InstanceData>)>,
+) {
+    for request in pending.set_culling_enabled_requests.drain(..) {
+        let mut changed = 0u32;
+        for (entity, has_no_culling) in mesh_query.iter() {
+            if request.enabled && has_no_culling {
+                commands.entity(entity).remove::<NoFrustumCulling>();
+                changed += 1;
+            } else if !request.enabled && !has_no_culling {
+                commands.entity(entity).insert(NoFrustumCulling);
+                changed += 1;
+            }
+        }
+        tracing::info!(
+            "Frustum culling {} on {} mesh entities",
+            if request.enabled { "enabled" } else { "disabled" },
+            changed
+        );
+    }
+}
+
 /// System that detects entities with `LodData` (auto_generate=true) but missing
 /// `LodMeshes` and regenerates simplified meshes. This handles scene load where
 /// only `LodData` is serialized — `LodMeshes` (containing GPU mesh handles) cannot
@@ -194,15 +236,9 @@ pub(super) fn regenerate_missing_lod_meshes(
         let mesh_handle = mesh3d.0.clone();
         let original_mesh_clone = meshes.get(&mesh_handle).cloned();
         if let Some(original_mesh) = original_mesh_clone {
-            let mut lod_meshes = LodMeshes::default();
-            lod_meshes.levels[0] = Some(mesh_handle);
-
-            for (i, &ratio) in lod.lod_ratios.iter().enumerate() {
-                let simplified = backend.backend.simplify(&original_mesh, ratio);
-                let handle = meshes.add(simplified);
-                lod_meshes.levels[i + 1] = Some(handle);
-            }
-
+            let lod_meshes = build_lod_meshes(
+                &eid.0, mesh_handle, &original_mesh, &lod.lod_ratios, &backend, &mut meshes,
+            );
             commands.entity(entity).insert(lod_meshes);
             tracing::info!("Regenerated LOD meshes for entity after scene load");
         }
@@ -225,15 +261,7 @@ pub(super) fn update_lod_levels(
     for (entity_id, transform, mut lod, lod_meshes, mesh3d) in lod_query.iter_mut() {
         let distance = camera_pos.distance(transform.translation);
 
-        let new_lod = if distance < lod.lod_distances[0] {
-            0 // Full detail
-        } else if distance < lod.lod_distances[1] {
-            1 // Medium detail
-        } else if distance < lod.lod_distances[2] {
-            2 // Low detail
-        } else {
-            3 // Lowest detail
-        };
+        let new_lod = lod.level_for_distance(distance);
 
         if new_lod != lod.current_lod {
             lod.current_lod = new_lod;
@@ -283,14 +311,7 @@ pub(super) fn collect_performance_metrics(
         }
         draw_calls += 1;
         if let Some(mesh) = mesh_assets.get(&mesh3d.0) {
-            let tri_count = match mesh.indices() {
-                Some(indices) => indices.len() as u32 / 3,
-                None => {
-                    let vert_count = mesh.count_vertices() as u32;
-                    vert_count / 3
-                }
-            };
-            total_triangles += tri_count;
+            total_triangles += lod::triangle_count(mesh);
         }
     }
 
@@ -322,3 +343,93 @@ pub(super) fn collect_performance_metrics(
     }
     metrics.mesh_memory_bytes = mesh_mem;
 }
+
+/// Apply start/stop_stats_stream requests and, while streaming, emit
+/// STATS_UPDATE (throttled to ~4 per second). The first sample goes out on
+/// the frame the stream starts.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn emit_stats_stream(
+    mut pending: ResMut<PendingCommands>,
+    mut stream: ResMut<StatsStream>,
+    time: Res<Time>,
+    diagnostics: Option<Res<DiagnosticsStore>>,
+    metrics: Res<PerformanceMetrics>,
+    entity_query: Query<(), With<EntityId>>,
+    mesh_query: Query<&Mesh3d>,
+    mesh_assets: Res<Assets<Mesh>>,
+    material_assets: Res<Assets<StandardMaterial>>,
+    mut last_emit: Local<f32>,
+) {
+    for request in pending.set_stats_stream_requests.drain(..) {
+        if request.active && !stream.active {
+            *last_emit = f32::NEG_INFINITY;
+        }
+        stream.active = request.active;
+    }
+    if !stream.active {
+        return;
+    }
+
+    let elapsed = time.elapsed_secs();
+    if elapsed - *last_emit < STATS_STREAM_INTERVAL {
+        return;
+    }
+    *last_emit = elapsed;
+
+    // Prefer the smoothed diagnostic; fall back to the raw frame delta.
+    let frame_time_ms = diagnostics.as_ref()
+        .and_then(|store| store.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME))
+        .and_then(|diagnostic| diagnostic.smoothed())
+        .map(|ms| ms as f32)
+        .unwrap_or(time.delta_secs() * 1000.0);
+
+    let triangle_count = mesh_query.iter()
+        .filter_map(|mesh3d| mesh_assets.get(&mesh3d.0))
+        .map(lod::triangle_count)
+        .sum();
+
+    events::emit_stats_update(&EngineStats {
+        fps: if frame_time_ms > 0.0 { 1000.0 / frame_time_ms } else { 0.0 },
+        frame_time_ms,
+        draw_call_estimate: metrics.draw_call_estimate,
+        entity_count: entity_query.iter().count() as u32,
+        triangle_count,
+        mesh_asset_count: mesh_assets.len() as u32,
+        material_asset_count: material_assets.len() as u32,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending::SetStatsStreamRequest;
+
+    #[test]
+    fn stats_stream_emits_updates_until_stopped() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .init_resource::<PendingCommands>()
+            .init_resource::<PerformanceMetrics>()
+            .init_resource::<StatsStream>()
+            .add_systems(Update, emit_stats_stream);
+        let cube = app.world_mut().resource_mut::<Assets<Mesh>>().add(Cuboid::default());
+        app.world_mut().spawn((EntityId("cube".to_string()), Mesh3d(cube)));
+
+        assert!(events::capture_events(|| app.update()).is_empty());
+
+        app.world_mut().resource_mut::<PendingCommands>()
+            .queue_set_stats_stream(SetStatsStreamRequest { active: true });
+        let emitted: Vec<_> = (0..3).flat_map(|_| events::capture_events(|| app.update())).collect();
+        let (event_type, stats) = emitted.first().expect("a stats update");
+        assert_eq!(event_type, "STATS_UPDATE");
+        assert_eq!(stats["entityCount"], 1);
+        assert_eq!(stats["triangleCount"], 12);
+        assert_eq!(stats["meshAssetCount"], 1);
+
+        app.world_mut().resource_mut::<PendingCommands>()
+            .queue_set_stats_stream(SetStatsStreamRequest { active: false });
+        assert!(events::capture_events(|| app.update()).is_empty());
+    }
+}
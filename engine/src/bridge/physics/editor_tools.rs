@@ -0,0 +1,305 @@
+//! Editor-only physics tools: drop to ground, physics baking and measurements.
+//!
+//! Systems are `pub(crate)` so the bridge module can register them through
+//! the `physics` re-export.
+
+use bevy::prelude::*;
+use bevy::animation::{AnimatedBy, AnimationTargetId};
+use bevy::camera::primitives::Aabb;
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility};
+
+use crate::core::{
+    animation::{AnimationRegistry, EntityAnimationData, HasAnimations},
+    entity_id::EntityId,
+    history::HistoryStack,
+    pending_commands::PendingCommands,
+    physics::{body_collider, PhysicsData, PhysicsEnabled},
+    physics_bake::{bake_frame_count, baked_clip, simulate_bake, BakeBody, BAKED_CLIP_NAME},
+    terrain::{TerrainData, TerrainMeshData},
+};
+
+use crate::bridge::events;
+
+/// System that applies drop_to_ground requests (editor-only). Each entity is
+/// moved vertically so the bottom of its bounds sits `offset` above the first
+/// mesh surface below it. Colliders only exist in Play mode, so this casts
+/// against meshes rather than through Rapier. Hidden meshes are skipped, but
+/// meshes outside every camera's view still count. Entities with nothing below
+/// them stay put; the rest of a request is one undo step.
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_drop_to_ground_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut history: ResMut<HistoryStack>,
+    mut ray_cast: MeshRayCast,
+    mut targets: Query<(Entity, &EntityId, &mut Transform, &GlobalTransform, Option<&Aabb>, Option<&ChildOf>), Without<crate::core::entity_factory::Locked>>,
+    global_transforms: Query<&GlobalTransform>,
+    children: Query<&Children>,
+    shells: Query<(), With<crate::core::selection_outline::OutlineShell>>,
+    visibility: Query<&InheritedVisibility>,
+) {
+    for request in pending.drop_to_ground_requests.drain(..) {
+        let mut transforms = Vec::new();
+        for entity_id in &request.entity_ids {
+            let Some((entity, _, _, global, aabb, _)) = targets.iter().find(|row| row.1 .0 == *entity_id) else {
+                continue;
+            };
+            let (bottom, top) = world_y_extent(global, aabb);
+            let origin = global.translation().with_y(top);
+
+            // Don't land on the entity itself or its children
+            let excluded: Vec<Entity> = std::iter::once(entity).chain(children.iter_descendants(entity)).collect();
+            // Filter on hierarchy visibility rather than per-view culling, so
+            // ground outside the camera frustum still catches the entity
+            let filter = |e: Entity| {
+                !excluded.contains(&e) && !shells.contains(e) && visibility.get(e).is_ok_and(|v| v.get())
+            };
+            let settings = MeshRayCastSettings::default()
+                .with_filter(&filter)
+                .with_visibility(RayCastVisibility::Any);
+            let Some(ground) = ray_cast.cast_ray(Ray3d::new(origin, Dir3::NEG_Y), &settings).first().map(|(_, hit)| hit.point.y) else {
+                tracing::warn!("drop_to_ground: no surface below {}", entity_id);
+                continue;
+            };
+
+            let Ok((_, _, mut transform, _, _, child_of)) = targets.get_mut(entity) else {
+                continue;
+            };
+            let parent = child_of
+                .and_then(|c| global_transforms.get(c.parent()).ok())
+                .copied()
+                .unwrap_or_default();
+            let old = crate::core::history::TransformSnapshot::from(&*transform);
+            transform.translation += parent.affine().inverse().transform_vector3(Vec3::Y * (ground + request.offset - bottom));
+            transforms.push((entity_id.clone(), old, crate::core::history::TransformSnapshot::from(&*transform)));
+        }
+        if !transforms.is_empty() {
+            history.push(crate::core::history::UndoableAction::MultiTransformChange { transforms });
+        }
+    }
+}
+
+/// World-space lowest and highest Y of an entity's bounds, or of its origin
+/// when it has none.
+fn world_y_extent(global: &GlobalTransform, aabb: Option<&Aabb>) -> (f32, f32) {
+    let Some(aabb) = aabb else {
+        let y = global.translation().y;
+        return (y, y);
+    };
+    let (center, half) = (Vec3::from(aabb.center), Vec3::from(aabb.half_extents));
+    (0..8)
+        .map(|corner| {
+            let sign = Vec3::new(
+                if corner & 1 == 0 { -1.0 } else { 1.0 },
+                if corner & 2 == 0 { -1.0 } else { 1.0 },
+                if corner & 4 == 0 { -1.0 } else { 1.0 },
+            );
+            global.transform_point(center + half * sign).y
+        })
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), y| (lo.min(y), hi.max(y)))
+}
+
+/// Physics entities a bake copies into its own world, with their meshes and
+/// terrain data so they get the same colliders as in Play.
+type BakeSourceQuery<'w, 's> = Query<'w, 's,
+    (
+        Entity,
+        &'static EntityId,
+        &'static PhysicsData,
+        &'static Transform,
+        Option<&'static Mesh3d>,
+        Option<(&'static TerrainData, &'static TerrainMeshData)>,
+    ),
+    With<PhysicsEnabled>,
+>;
+
+/// System that applies bake_physics_to_animation requests (editor-only).
+/// Each requested entity gets a "Physics Bake" clip in the AnimationRegistry,
+/// replacing an earlier bake; entities without animations get their own
+/// player and graph. The scene itself is not moved.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_bake_physics_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut registry: ResMut<AnimationRegistry>,
+    mut clips: ResMut<Assets<AnimationClip>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+    meshes: Res<Assets<Mesh>>,
+    rapier_config: Query<&bevy_rapier3d::prelude::RapierConfiguration>,
+    sources: BakeSourceQuery,
+) {
+    let gravity = rapier_config.iter().next().map_or(Vec3::new(0.0, -9.81, 0.0), |config| config.gravity);
+    for request in pending.bake_physics_requests.drain(..) {
+        let mut entities = Vec::new();
+        let mut bodies = Vec::new();
+        for (entity, entity_id, data, transform, mesh, terrain) in sources.iter() {
+            let mesh = mesh.filter(|_| data.collider_shape.uses_mesh()).and_then(|m| meshes.get(&m.0));
+            let collider = body_collider(data, transform, mesh, terrain);
+            entities.push((entity, entity_id.0.clone()));
+            bodies.push(BakeBody { data: data.clone(), transform: *transform, collider });
+        }
+        let samples = simulate_bake(&bodies, gravity, request.duration, request.fps);
+
+        let mut baked = Vec::new();
+        for entity_id in &request.entity_ids {
+            let Some(index) = entities.iter().position(|(_, id)| id == entity_id) else {
+                tracing::warn!("bake_physics_to_animation: {} has no physics", entity_id);
+                continue;
+            };
+            let entity = entities[index].0;
+            let target = AnimationTargetId::from_name(&Name::new(entity_id.clone()));
+            let Some(clip) = baked_clip(target, &samples[index], request.fps) else {
+                continue;
+            };
+            let duration = clip.duration();
+            let clip = clips.add(clip);
+
+            let player_entity = match registry.entries.get_mut(entity_id) {
+                Some(entry) => {
+                    let Some(graph) = graphs.get_mut(&entry.graph_handle) else {
+                        continue;
+                    };
+                    let existing = entry.clips.get(BAKED_CLIP_NAME).map(|(node, _)| *node);
+                    let node = match existing.and_then(|node| graph.get_mut(node).map(|n| (node, n))) {
+                        Some((node, graph_node)) => {
+                            graph_node.node_type = AnimationNodeType::Clip(clip);
+                            node
+                        }
+                        None => {
+                            let root = graph.root;
+                            entry.clip_names.push(BAKED_CLIP_NAME.to_string());
+                            graph.add_clip(clip, 1.0, root)
+                        }
+                    };
+                    entry.clips.insert(BAKED_CLIP_NAME.to_string(), (node, duration));
+                    entry.player_entity
+                }
+                None => {
+                    let (graph, node) = AnimationGraph::from_clip(clip);
+                    let graph_handle = graphs.add(graph);
+                    commands.entity(entity).insert((
+                        AnimationPlayer::default(),
+                        AnimationGraphHandle(graph_handle.clone()),
+                        AnimationTransitions::new(),
+                        HasAnimations,
+                    ));
+                    registry.entries.insert(entity_id.clone(), EntityAnimationData {
+                        clips: std::collections::HashMap::from([(BAKED_CLIP_NAME.to_string(), (node, duration))]),
+                        clip_names: vec![BAKED_CLIP_NAME.to_string()],
+                        player_entity: entity,
+                        graph_handle,
+                        events: std::collections::HashMap::new(),
+                    });
+                    entity
+                }
+            };
+            commands.entity(entity).insert((target, AnimatedBy(player_entity)));
+            baked.push(entity_id.clone());
+        }
+
+        if !baked.is_empty() {
+            let frame_count = bake_frame_count(request.duration, request.fps);
+            events::emit_physics_baked(&baked, BAKED_CLIP_NAME, frame_count as f32 / request.fps, frame_count);
+        }
+    }
+}
+
+/// System that applies measure / clear_measurement requests (editor-only).
+/// Points asked to snap are moved onto the first collider hit along the ray
+/// from the editor camera through them; points with no hit stay where they are.
+pub(crate) fn apply_measurement_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut state: ResMut<crate::core::measurement::MeasurementState>,
+    rapier_context: bevy_rapier3d::prelude::ReadRapierContext,
+    camera_query: Query<&GlobalTransform, With<crate::core::camera::EditorCamera>>,
+) {
+    if pending.clear_measurement_requests.drain(..).count() > 0 {
+        state.points.clear();
+    }
+
+    for request in pending.measure_requests.drain(..) {
+        let mut points = request.points;
+        if request.snap_to_surface {
+            if let (Ok(rapier_context), Ok(camera)) = (rapier_context.single(), camera_query.single()) {
+                let origin = camera.translation();
+                for point in points.iter_mut() {
+                    let Ok(direction) = Dir3::new(*point - origin) else {
+                        continue;
+                    };
+                    if let Some((_, toi)) = rapier_context.cast_ray(
+                        origin,
+                        *direction,
+                        f32::MAX,
+                        true,
+                        crate::core::physics::raycast_filter(None),
+                    ) {
+                        *point = origin + *direction * toi;
+                    }
+                }
+            }
+        }
+
+        state.mode = request.mode;
+        state.points = points;
+        if let (Some(value), Some(anchor)) = (state.value(), state.label_anchor()) {
+            let points: Vec<[f32; 3]> = state.points.iter().map(|p| p.to_array()).collect();
+            events::emit_measurement_result(state.mode, value, &points, anchor.to_array());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::camera::primitives::MeshAabb;
+    use crate::core::pending::DropToGroundRequest;
+    use crate::core::terrain::build_terrain_mesh;
+
+    #[test]
+    fn entity_above_terrain_drops_to_terrain_height() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .add_systems(Update, apply_drop_to_ground_requests);
+
+        // 4x4 terrain sloping up along X: height = 1 + x / 2
+        let heights: Vec<f32> = (0..9).map(|i| (i % 3) as f32).collect();
+        let terrain = build_terrain_mesh(&heights, 3, 4.0, &[]);
+        let cube = Mesh::from(Cuboid::new(1.0, 1.0, 1.0));
+        let (terrain_aabb, cube_aabb) = (terrain.compute_aabb().unwrap(), cube.compute_aabb().unwrap());
+        let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
+        let (terrain, cube) = (meshes.add(terrain), meshes.add(cube));
+
+        // No camera renders these, so their ViewVisibility stays hidden
+        app.world_mut().spawn((
+            Mesh3d(terrain),
+            Transform::IDENTITY,
+            GlobalTransform::IDENTITY,
+            Visibility::default(),
+            InheritedVisibility::VISIBLE,
+            terrain_aabb,
+        ));
+        let rock_transform = Transform::from_xyz(1.0, 10.0, 0.5);
+        let rock = app.world_mut().spawn((
+            EntityId::new("rock"),
+            Mesh3d(cube),
+            rock_transform,
+            GlobalTransform::from(rock_transform),
+            Visibility::default(),
+            InheritedVisibility::VISIBLE,
+            cube_aabb,
+        )).id();
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_drop_to_ground(DropToGroundRequest {
+            entity_ids: vec!["rock".to_string(), "missing".to_string()],
+            offset: 0.0,
+        });
+        app.update();
+
+        let translation = app.world().get::<Transform>(rock).unwrap().translation;
+        assert!((translation.y - 0.5 - 1.5).abs() < 1e-4, "cube bottom should rest at 1.5, got center {}", translation.y);
+        assert_eq!((translation.x, translation.z), (1.0, 0.5));
+        assert!(app.world().resource::<HistoryStack>().undo_description().is_some());
+    }
+}
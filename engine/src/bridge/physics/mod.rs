@@ -7,15 +7,23 @@ use bevy::prelude::*;
 use crate::core::{
     entity_id::EntityId,
     history::HistoryStack,
-    pending_commands::{self, PendingCommands},
-    physics::{DebugPhysicsEnabled, PhysicsData, PhysicsEnabled},
-    physics_2d::{Physics2dData, Physics2dEnabled, PhysicsJoint2d},
+    pending_commands::PendingCommands,
+    physics::{classify_contact, radial_impulse, ContactKind, DebugPhysicsEnabled, PhysicsData, PhysicsEnabled, PhysicsTimestep},
+    prefab::PrefabInstance,
     selection::{Selection, SelectionChangedEvent},
     engine_mode::EngineMode,
 };
 
 use super::events;
 
+#[cfg(not(feature = "runtime"))]
+mod editor_tools;
+mod physics2d;
+
+#[cfg(not(feature = "runtime"))]
+pub(super) use editor_tools::*;
+pub(super) use physics2d::*;
+
 // ============================================================================
 // 3D Physics Systems
 // ============================================================================
@@ -23,15 +31,18 @@ use super::events;
 /// System that applies pending physics updates (always-active — edit physics in any mode).
 pub(super) fn apply_physics_updates(
     mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut PhysicsData)>,
+    mut query: Query<(&EntityId, &mut PhysicsData, Option<&mut PrefabInstance>)>,
     phys_enabled_query: Query<&EntityId, With<PhysicsEnabled>>,
     mut history: ResMut<HistoryStack>,
 ) {
     for update in pending.physics_updates.drain(..) {
-        for (entity_id, mut current_physics) in query.iter_mut() {
+        for (entity_id, mut current_physics, prefab_instance) in query.iter_mut() {
             if entity_id.0 == update.entity_id {
                 let old_physics = current_physics.clone();
                 *current_physics = update.physics_data.clone();
+                if let Some(mut instance) = prefab_instance {
+                    instance.record_overrides("physics", &old_physics, &update.physics_data);
+                }
 
                 // Record for undo
                 history.push(crate::core::history::UndoableAction::PhysicsChange {
@@ -101,20 +112,38 @@ pub(super) fn apply_debug_physics_toggle(
     }
 }
 
-/// System that applies pending force applications (only works during Play mode).
+/// System that applies pending set_physics_timestep requests.
+pub(super) fn apply_physics_timestep_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut timestep: ResMut<PhysicsTimestep>,
+) {
+    for request in pending.physics_timestep_requests.drain(..) {
+        *timestep = PhysicsTimestep {
+            fixed_dt: request.fixed_dt,
+            substeps: request.substeps,
+            physics_determinism: request.physics_determinism,
+        };
+        events::emit_physics_config_changed(&timestep);
+        tracing::info!("Physics timestep: {:?}", *timestep);
+    }
+}
+
+/// System that applies pending force applications and radial impulses (only
+/// works during Play mode).
 pub(super) fn apply_force_applications(
     mut pending: ResMut<PendingCommands>,
     mut commands: Commands,
     engine_mode: Res<EngineMode>,
-    query: Query<(Entity, &EntityId), With<bevy_rapier3d::prelude::RigidBody>>,
+    query: Query<(Entity, &EntityId, &GlobalTransform), With<bevy_rapier3d::prelude::RigidBody>>,
 ) {
     if !engine_mode.is_playing() {
         pending.force_applications.clear();
+        pending.radial_impulse_requests.clear();
         return;
     }
 
     for application in pending.force_applications.drain(..) {
-        for (entity, entity_id) in query.iter() {
+        for (entity, entity_id, _) in query.iter() {
             if entity_id.0 == application.entity_id {
                 let force_vec = bevy::math::Vec3::new(
                     application.force[0],
@@ -146,6 +175,69 @@ pub(super) fn apply_force_applications(
             }
         }
     }
+
+    // Sum every explosion of the frame per body, so overlapping blasts add up.
+    let explosions: Vec<_> = pending.radial_impulse_requests.drain(..).collect();
+    if explosions.is_empty() {
+        return;
+    }
+    for (entity, _, global) in query.iter() {
+        let position = global.translation();
+        let impulse: Vec3 = explosions.iter()
+            .filter_map(|blast| radial_impulse(Vec3::from(blast.center), blast.radius, blast.strength, blast.falloff, position))
+            .sum();
+        if impulse != Vec3::ZERO {
+            commands.entity(entity).insert(bevy_rapier3d::prelude::ExternalImpulse {
+                impulse,
+                torque_impulse: Vec3::ZERO,
+            });
+        }
+    }
+}
+
+/// Motor damping used when a joint without a motor is first driven. A velocity
+/// target with no stiffness is tracked through damping alone, so this is high
+/// enough for the joint to reach the target speed within a few steps.
+const DEFAULT_MOTOR_DAMPING: f32 = 50.0;
+
+/// System that drives live joint motors (only works during Play mode). The
+/// Rapier joint is edited in place, so the connected bodies keep their state.
+pub(super) fn apply_joint_motor_targets(
+    mut pending: ResMut<PendingCommands>,
+    engine_mode: Res<EngineMode>,
+    mut joints: Query<(&EntityId, &mut bevy_rapier3d::prelude::ImpulseJoint)>,
+) {
+    use bevy_rapier3d::prelude::{JointAxis, TypedJoint};
+
+    if !engine_mode.is_playing() {
+        pending.joint_motor_target_requests.clear();
+        return;
+    }
+
+    for request in pending.joint_motor_target_requests.drain(..) {
+        let Some((_, mut joint)) = joints.iter_mut().find(|(eid, _)| eid.0 == request.joint_id) else {
+            tracing::warn!("set_joint_motor_target: no live joint on {}", request.joint_id);
+            continue;
+        };
+        let axis = match joint.data {
+            TypedJoint::RevoluteJoint(_) => JointAxis::AngX,
+            TypedJoint::PrismaticJoint(_) => JointAxis::LinX,
+            _ => {
+                tracing::warn!("set_joint_motor_target: joint on {} has no motor axis", request.joint_id);
+                continue;
+            }
+        };
+
+        let generic = joint.data.as_mut();
+        let current = generic.motor(axis).copied();
+        let target_vel = request.target_vel.or(current.map(|m| m.target_vel)).unwrap_or(0.0);
+        let target_pos = request.target_pos.or(current.map(|m| m.target_pos)).unwrap_or(0.0);
+        let stiffness = request.stiffness.or(current.map(|m| m.stiffness)).unwrap_or(0.0);
+        let damping = request.damping.or(current.map(|m| m.damping)).unwrap_or(DEFAULT_MOTOR_DAMPING);
+        generic.set_motor(axis, target_pos, target_vel, stiffness, damping);
+
+        events::emit_joint_motor_changed(&request.joint_id, target_vel, target_pos, stiffness, damping);
+    }
 }
 
 /// System that applies pending create joint requests.
@@ -255,318 +347,18 @@ pub(super) fn apply_remove_joint_requests(
     }
 }
 
-// ============================================================================
-// 2D Physics Systems (Metadata-only)
-// ============================================================================
-
-/// System that applies pending 2D physics update requests (always-active, metadata-only).
-pub(super) fn apply_physics2d_updates(
-    mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut Physics2dData)>,
-    phys2d_enabled_query: Query<&EntityId, With<Physics2dEnabled>>,
-    mut history: ResMut<HistoryStack>,
-) {
-    for update in pending.physics2d_updates.drain(..) {
-        for (entity_id, mut current_physics) in query.iter_mut() {
-            if entity_id.0 == update.entity_id {
-                let old_physics = current_physics.clone();
-                *current_physics = update.physics_data.clone();
-
-                // Record for undo (using Physics2dChange action)
-                history.push(crate::core::history::UndoableAction::Physics2dChange {
-                    entity_id: update.entity_id.clone(),
-                    old_physics: Some(old_physics),
-                    new_physics: Some(update.physics_data.clone()),
-                });
-
-                // Emit change event
-                let enabled = phys2d_enabled_query.iter().any(|eid| eid.0 == update.entity_id);
-                events::emit_physics2d_changed(&update.entity_id, &update.physics_data, enabled);
-                break;
-            }
-        }
-    }
-}
-
-/// System that applies pending 2D physics toggle requests (always-active, metadata-only).
-pub(super) fn apply_physics2d_toggles(
-    mut pending: ResMut<PendingCommands>,
-    mut commands: Commands,
-    query: Query<(Entity, &EntityId, Option<&Physics2dData>, Option<&Physics2dEnabled>)>,
-) {
-    for toggle in pending.physics2d_toggles.drain(..) {
-        for (entity, entity_id, physics_data, phys2d_enabled) in query.iter() {
-            if entity_id.0 == toggle.entity_id {
-                if toggle.enabled {
-                    // Enable physics: add Physics2dEnabled marker and Physics2dData if missing
-                    if phys2d_enabled.is_none() {
-                        commands.entity(entity).insert(Physics2dEnabled);
-                    }
-                    if physics_data.is_none() {
-                        commands.entity(entity).insert(Physics2dData::default());
-                    }
-                    let data = physics_data.cloned().unwrap_or_default();
-                    events::emit_physics2d_changed(&toggle.entity_id, &data, true);
-                } else {
-                    // Disable physics: remove Physics2dEnabled marker
-                    if phys2d_enabled.is_some() {
-                        commands.entity(entity).remove::<Physics2dEnabled>();
-                    }
-                    if let Some(data) = physics_data {
-                        events::emit_physics2d_changed(&toggle.entity_id, data, false);
-                    }
-                }
-                break;
-            }
-        }
-    }
-}
-
-/// System that applies 2D joint creation requests (editor-only, metadata-only).
-pub(super) fn apply_create_joint2d_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut commands: Commands,
-    query: Query<(Entity, &EntityId)>,
-    mut history: ResMut<HistoryStack>,
-) {
-    for request in pending.create_joint2d_requests.drain(..) {
-        // Find the entity to add the joint to
-        for (entity, entity_id) in query.iter() {
-            if entity_id.0 == request.entity_id {
-                commands.entity(entity).insert(request.joint_data.clone());
-
-                // Record for undo
-                history.push(crate::core::history::UndoableAction::Joint2dChange {
-                    entity_id: request.entity_id.clone(),
-                    old_joint: None,
-                    new_joint: Some(request.joint_data.clone()),
-                });
-
-                // Emit change event
-                events::emit_joint2d_changed(&request.entity_id, &request.joint_data);
-                break;
-            }
-        }
-    }
-}
-
-/// System that applies 2D joint update requests (editor-only, metadata-only).
-pub(super) fn apply_update_joint2d_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut PhysicsJoint2d)>,
-    mut history: ResMut<HistoryStack>,
-) {
-    for update in pending.update_joint2d_requests.drain(..) {
-        for (entity_id, mut current_joint) in query.iter_mut() {
-            if entity_id.0 == update.entity_id {
-                let old_joint = current_joint.clone();
-                *current_joint = update.joint_data.clone();
-
-                // Record for undo
-                history.push(crate::core::history::UndoableAction::Joint2dChange {
-                    entity_id: update.entity_id.clone(),
-                    old_joint: Some(old_joint),
-                    new_joint: Some(update.joint_data.clone()),
-                });
-
-                // Emit change event
-                events::emit_joint2d_changed(&update.entity_id, &update.joint_data);
-                break;
-            }
-        }
-    }
-}
-
-/// System that applies 2D joint removal requests (editor-only, metadata-only).
-pub(super) fn apply_remove_joint2d_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut commands: Commands,
-    query: Query<(Entity, &EntityId, &PhysicsJoint2d)>,
-    mut history: ResMut<HistoryStack>,
-) {
-    for request in pending.remove_joint2d_requests.drain(..) {
-        for (entity, entity_id, joint_data) in query.iter() {
-            if entity_id.0 == request.entity_id {
-                let old_joint = joint_data.clone();
-                commands.entity(entity).remove::<PhysicsJoint2d>();
-
-                // Record for undo
-                history.push(crate::core::history::UndoableAction::Joint2dChange {
-                    entity_id: request.entity_id.clone(),
-                    old_joint: Some(old_joint),
-                    new_joint: None,
-                });
-
-                // No event needed — removal is implicit
-                break;
-            }
-        }
-    }
-}
-
-/// System that applies 2D force applications (only works during Play mode).
-pub(super) fn apply_force_applications2d(
-    mut pending: ResMut<PendingCommands>,
-    mut commands: Commands,
-    engine_mode: Res<EngineMode>,
-    query: Query<(Entity, &EntityId), With<bevy_rapier2d::prelude::RigidBody>>,
-) {
-    if !engine_mode.is_playing() {
-        pending.force_applications2d.clear();
-        return;
-    }
-
-    for application in pending.force_applications2d.drain(..) {
-        for (entity, entity_id) in query.iter() {
-            if entity_id.0 == application.entity_id {
-                commands.entity(entity).insert(
-                    bevy_rapier2d::prelude::ExternalForce {
-                        force: bevy_rapier2d::prelude::Vect::new(
-                            application.force_x,
-                            application.force_y,
-                        ),
-                        torque: 0.0,
-                    }
-                );
-                break;
-            }
-        }
-    }
-}
-
-/// System that applies 2D impulse applications (only works during Play mode).
-pub(super) fn apply_impulse_applications2d(
-    mut pending: ResMut<PendingCommands>,
-    mut commands: Commands,
-    engine_mode: Res<EngineMode>,
-    query: Query<(Entity, &EntityId), With<bevy_rapier2d::prelude::RigidBody>>,
-) {
-    if !engine_mode.is_playing() {
-        pending.impulse_applications2d.clear();
-        return;
-    }
-
-    for application in pending.impulse_applications2d.drain(..) {
-        for (entity, entity_id) in query.iter() {
-            if entity_id.0 == application.entity_id {
-                commands.entity(entity).insert(
-                    bevy_rapier2d::prelude::ExternalImpulse {
-                        impulse: bevy_rapier2d::prelude::Vect::new(
-                            application.impulse_x,
-                            application.impulse_y,
-                        ),
-                        torque_impulse: 0.0,
-                    }
-                );
-                break;
-            }
-        }
-    }
-}
-
-/// System that processes 2D raycast requests using Rapier 2D context.
-pub(super) fn apply_raycast2d_requests(
-    mut pending: ResMut<PendingCommands>,
-    rapier_context: bevy_rapier2d::prelude::ReadRapierContext,
-    entity_id_query: Query<&EntityId>,
-) {
-    for request in pending.raycast2d_requests.drain(..) {
-        let Ok(rapier_context) = rapier_context.single() else {
-            events::emit_raycast2d_miss();
-            continue;
-        };
-
-        let origin = bevy_rapier2d::prelude::Vect::new(request.origin_x, request.origin_y);
-        let direction = bevy_rapier2d::prelude::Vect::new(request.dir_x, request.dir_y);
-
-        if let Some((entity, toi)) = rapier_context.cast_ray(
-            origin,
-            direction,
-            request.max_distance,
-            true,
-            bevy_rapier2d::prelude::QueryFilter::default(),
-        ) {
-            let hit_point = origin + direction * toi;
-            if let Ok(eid) = entity_id_query.get(entity) {
-                // Compute a simple 2D normal (perpendicular to ray direction)
-                let dir_len = (direction.x * direction.x + direction.y * direction.y).sqrt();
-                let normal_x = if dir_len > 0.0 { -direction.y / dir_len } else { 0.0 };
-                let normal_y = if dir_len > 0.0 { direction.x / dir_len } else { 1.0 };
-                events::emit_raycast2d_hit(
-                    &eid.0,
-                    hit_point.x,
-                    hit_point.y,
-                    normal_x,
-                    normal_y,
-                    toi,
-                );
-            } else {
-                events::emit_raycast2d_miss();
-            }
-        } else {
-            events::emit_raycast2d_miss();
-        }
-    }
-}
-
-/// System that applies 2D gravity updates to the Gravity2d resource.
-pub(super) fn apply_gravity2d_updates(
-    mut pending: ResMut<PendingCommands>,
-    mut gravity: ResMut<crate::core::physics_2d_sim::Gravity2d>,
-) {
-    for update in pending.gravity2d_updates.drain(..) {
-        gravity.x = update.gravity_x;
-        gravity.y = update.gravity_y;
-        tracing::info!(
-            "2D gravity updated: ({}, {})",
-            update.gravity_x,
-            update.gravity_y
-        );
-    }
-}
-
-/// System that applies 2D debug physics toggles.
-pub(super) fn apply_debug_physics2d_toggle(
-    mut pending: ResMut<PendingCommands>,
-    mut debug_enabled: ResMut<crate::core::physics_2d_sim::DebugPhysics2dEnabled>,
-) {
-    for toggle in pending.debug_physics2d_toggles.drain(..) {
-        debug_enabled.0 = toggle.enabled;
-        tracing::info!("2D debug physics rendering: {}", toggle.enabled);
-    }
-}
-
-/// System that handles 2D physics query requests (editor-only).
-pub(super) fn handle_physics2d_query(
-    mut pending: ResMut<PendingCommands>,
-    physics_query: Query<(&EntityId, &Physics2dData, Option<&Physics2dEnabled>)>,
-) {
-    let requests: Vec<pending_commands::QueryRequest> = pending.query_requests
-        .drain(..)
-        .filter(|req| matches!(req, pending_commands::QueryRequest::Physics2dState { .. }))
-        .collect();
-
-    for request in requests {
-        if let pending_commands::QueryRequest::Physics2dState { entity_id } = request {
-            // Find the entity
-            let found = physics_query.iter().find(|(eid, _, _)| eid.0 == entity_id);
-            if let Some((eid, physics_data, phys2d_enabled)) = found {
-                let enabled = phys2d_enabled.is_some();
-                events::emit_physics2d_changed(&eid.0, physics_data, enabled);
-            }
-        }
-    }
-}
-
 // ============================================================================
 // Collision & Raycast Systems
 // ============================================================================
 
 /// System that reads collision events from Rapier and emits them to JS.
 /// Runs always (mode-gated internally by checking if physics is active).
+/// Contacts involving a sensor emit only a trigger enter/exit event, not a
+/// collision event.
 pub(super) fn read_collision_events(
     mut collision_events: MessageReader<bevy_rapier3d::prelude::CollisionEvent>,
     entity_id_query: Query<&EntityId>,
+    sensor_query: Query<(), With<bevy_rapier3d::prelude::Sensor>>,
     engine_mode: Res<EngineMode>,
 ) {
     if !engine_mode.is_playing() {
@@ -581,16 +373,24 @@ pub(super) fn read_collision_events(
         };
 
         if let (Ok(id_a), Ok(id_b)) = (entity_id_query.get(entity_a), entity_id_query.get(entity_b)) {
-            events::emit_collision_event(&id_a.0, &id_b.0, started);
+            let a_is_sensor = sensor_query.contains(entity_a);
+            let b_is_sensor = sensor_query.contains(entity_b);
+            match classify_contact(&id_a.0, &id_b.0, a_is_sensor, b_is_sensor) {
+                ContactKind::Trigger { sensor, intruder } => events::emit_trigger_event(sensor, intruder, started),
+                ContactKind::Collision => events::emit_collision_event(&id_a.0, &id_b.0, started),
+            }
         }
     }
 }
 
 /// System that reads 2D collision events from Rapier 2D and emits them to JS.
 /// Runs always (mode-gated internally by checking if physics is active).
+/// Contacts involving a sensor emit only a trigger enter/exit event, not a
+/// collision event.
 pub(super) fn read_collision_events_2d(
     mut collision_events: MessageReader<bevy_rapier2d::prelude::CollisionEvent>,
     entity_id_query: Query<&EntityId>,
+    sensor_query: Query<(), With<bevy_rapier2d::prelude::Sensor>>,
     engine_mode: Res<EngineMode>,
 ) {
     if !engine_mode.is_playing() {
@@ -605,7 +405,12 @@ pub(super) fn read_collision_events_2d(
         };
 
         if let (Ok(id_a), Ok(id_b)) = (entity_id_query.get(entity_a), entity_id_query.get(entity_b)) {
-            events::emit_collision_event(&id_a.0, &id_b.0, started);
+            let a_is_sensor = sensor_query.contains(entity_a);
+            let b_is_sensor = sensor_query.contains(entity_b);
+            match classify_contact(&id_a.0, &id_b.0, a_is_sensor, b_is_sensor) {
+                ContactKind::Trigger { sensor, intruder } => events::emit_trigger_event(sensor, intruder, started),
+                ContactKind::Collision => events::emit_collision_event(&id_a.0, &id_b.0, started),
+            }
         }
     }
 }
@@ -631,7 +436,7 @@ pub(super) fn apply_raycast_queries(
             direction,
             request.max_distance,
             true,
-            bevy_rapier3d::prelude::QueryFilter::default(),
+            crate::core::physics::raycast_filter(request.filter_groups),
         ) {
             let hit_point = origin + direction * toi;
             if let Ok(eid) = entity_id_query.get(entity) {
@@ -707,3 +512,95 @@ pub(super) fn emit_joint_on_selection(
         }
     }
 }
+
+#[cfg(all(test, not(feature = "runtime")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radial_impulse_pushes_nearer_bodies_harder() {
+        use bevy_rapier3d::prelude::{ExternalImpulse, RigidBody};
+        use crate::core::pending::RadialImpulseRequest;
+        use crate::core::physics::RadialFalloff;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .insert_resource(EngineMode::Play)
+            .add_systems(Update, apply_force_applications);
+        let mut body = |id: &str, x: f32| {
+            app.world_mut()
+                .spawn((EntityId::new(id), RigidBody::Dynamic, GlobalTransform::from_xyz(x, 0.0, 0.0)))
+                .id()
+        };
+        let (near, far, outside) = (body("near", 1.0), body("far", -3.0), body("outside", 6.0));
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_radial_impulse(RadialImpulseRequest {
+            center: [0.0; 3],
+            radius: 4.0,
+            strength: 10.0,
+            falloff: RadialFalloff::Linear,
+        });
+        app.update();
+
+        let impulse = |entity| app.world().get::<ExternalImpulse>(entity).map(|i| i.impulse);
+        let (near, far) = (impulse(near).unwrap(), impulse(far).unwrap());
+        assert!(near.abs_diff_eq(Vec3::new(7.5, 0.0, 0.0), 1e-5), "{near:?}");
+        assert!(far.abs_diff_eq(Vec3::new(-2.5, 0.0, 0.0), 1e-5), "{far:?}");
+        assert!(near.length() > far.length());
+        assert!(impulse(outside).is_none());
+    }
+
+    #[test]
+    fn motor_target_velocity_spins_a_revolute_joint() {
+        use bevy_rapier3d::prelude::{
+            Collider, GravityScale, ImpulseJoint, NoUserData, RapierPhysicsPlugin, RevoluteJointBuilder, RigidBody,
+            TimestepMode, Velocity,
+        };
+        use crate::core::pending::JointMotorTargetRequest;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), bevy::scene::ScenePlugin))
+            .init_asset::<Mesh>()
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+            .insert_resource(TimestepMode::Fixed { dt: 1.0 / 60.0, substeps: 1 })
+            .insert_resource(EngineMode::Play)
+            .init_resource::<PendingCommands>()
+            .add_systems(Update, apply_joint_motor_targets);
+        let axle = app.world_mut().spawn((RigidBody::Fixed, Transform::IDENTITY)).id();
+        let wheel = app.world_mut().spawn((
+            EntityId::new("wheel"),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            GravityScale(0.0),
+            Velocity::zero(),
+            Transform::IDENTITY,
+            ImpulseJoint::new(axle, RevoluteJointBuilder::new(Vec3::Z)),
+        )).id();
+        for _ in 0..5 {
+            app.update();
+        }
+        let rest = app.world().get::<Transform>(wheel).unwrap().rotation;
+        assert!(rest.angle_between(Quat::IDENTITY) < 1e-4);
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_joint_motor_target(JointMotorTargetRequest {
+            joint_id: "wheel".to_string(),
+            target_vel: Some(3.0),
+            target_pos: None,
+            stiffness: None,
+            damping: None,
+        });
+        for _ in 0..30 {
+            app.update();
+        }
+
+        // Half a second at 3 rad/s, less the few steps spent spinning up
+        let spin = app.world().get::<Velocity>(wheel).unwrap().angvel;
+        assert!((spin.z.abs() - 3.0).abs() < 0.05, "wheel should reach the target speed, got {spin:?}");
+        let transform = app.world().get::<Transform>(wheel).unwrap();
+        let angle = transform.rotation.angle_between(Quat::IDENTITY);
+        assert!(angle > 1.2 && angle < 1.55, "wheel should turn about 1.5 rad, got {angle}");
+        assert!(transform.translation.length() < 1e-3, "the axle should hold the wheel in place");
+    }
+
+}
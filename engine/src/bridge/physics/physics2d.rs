@@ -0,0 +1,357 @@
+//! 2D physics systems (metadata-only updates, joints, forces and raycasts).
+//!
+//! Systems are `pub(crate)` so the bridge module can register them through
+//! the `physics` re-export.
+
+use bevy::prelude::*;
+
+use crate::core::{
+    entity_id::EntityId,
+    history::HistoryStack,
+    pending_commands::{self, PendingCommands},
+    physics_2d::{Physics2dData, Physics2dEnabled, PhysicsJoint2d},
+    engine_mode::EngineMode,
+};
+
+use crate::bridge::events;
+
+/// System that applies pending 2D physics update requests (always-active, metadata-only).
+pub(crate) fn apply_physics2d_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut Physics2dData)>,
+    phys2d_enabled_query: Query<&EntityId, With<Physics2dEnabled>>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for update in pending.physics2d_updates.drain(..) {
+        for (entity_id, mut current_physics) in query.iter_mut() {
+            if entity_id.0 == update.entity_id {
+                let old_physics = current_physics.clone();
+                *current_physics = update.physics_data.clone();
+
+                // Record for undo (using Physics2dChange action)
+                history.push(crate::core::history::UndoableAction::Physics2dChange {
+                    entity_id: update.entity_id.clone(),
+                    old_physics: Some(old_physics),
+                    new_physics: Some(update.physics_data.clone()),
+                });
+
+                // Emit change event
+                let enabled = phys2d_enabled_query.iter().any(|eid| eid.0 == update.entity_id);
+                events::emit_physics2d_changed(&update.entity_id, &update.physics_data, enabled);
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies pending 2D physics toggle requests (always-active, metadata-only).
+pub(crate) fn apply_physics2d_toggles(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: Query<(Entity, &EntityId, Option<&Physics2dData>, Option<&Physics2dEnabled>)>,
+) {
+    for toggle in pending.physics2d_toggles.drain(..) {
+        for (entity, entity_id, physics_data, phys2d_enabled) in query.iter() {
+            if entity_id.0 == toggle.entity_id {
+                if toggle.enabled {
+                    // Enable physics: add Physics2dEnabled marker and Physics2dData if missing
+                    if phys2d_enabled.is_none() {
+                        commands.entity(entity).insert(Physics2dEnabled);
+                    }
+                    if physics_data.is_none() {
+                        commands.entity(entity).insert(Physics2dData::default());
+                    }
+                    let data = physics_data.cloned().unwrap_or_default();
+                    events::emit_physics2d_changed(&toggle.entity_id, &data, true);
+                } else {
+                    // Disable physics: remove Physics2dEnabled marker
+                    if phys2d_enabled.is_some() {
+                        commands.entity(entity).remove::<Physics2dEnabled>();
+                    }
+                    if let Some(data) = physics_data {
+                        events::emit_physics2d_changed(&toggle.entity_id, data, false);
+                    }
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies 2D joint creation requests (editor-only, metadata-only).
+pub(crate) fn apply_create_joint2d_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: Query<(Entity, &EntityId)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for request in pending.create_joint2d_requests.drain(..) {
+        // Find the entity to add the joint to
+        for (entity, entity_id) in query.iter() {
+            if entity_id.0 == request.entity_id {
+                commands.entity(entity).insert(request.joint_data.clone());
+
+                // Record for undo
+                history.push(crate::core::history::UndoableAction::Joint2dChange {
+                    entity_id: request.entity_id.clone(),
+                    old_joint: None,
+                    new_joint: Some(request.joint_data.clone()),
+                });
+
+                // Emit change event
+                events::emit_joint2d_changed(&request.entity_id, &request.joint_data);
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies 2D joint update requests (editor-only, metadata-only).
+pub(crate) fn apply_update_joint2d_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut PhysicsJoint2d)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for update in pending.update_joint2d_requests.drain(..) {
+        for (entity_id, mut current_joint) in query.iter_mut() {
+            if entity_id.0 == update.entity_id {
+                let old_joint = current_joint.clone();
+                *current_joint = update.joint_data.clone();
+
+                // Record for undo
+                history.push(crate::core::history::UndoableAction::Joint2dChange {
+                    entity_id: update.entity_id.clone(),
+                    old_joint: Some(old_joint),
+                    new_joint: Some(update.joint_data.clone()),
+                });
+
+                // Emit change event
+                events::emit_joint2d_changed(&update.entity_id, &update.joint_data);
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies 2D joint removal requests (editor-only, metadata-only).
+pub(crate) fn apply_remove_joint2d_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: Query<(Entity, &EntityId, &PhysicsJoint2d)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for request in pending.remove_joint2d_requests.drain(..) {
+        for (entity, entity_id, joint_data) in query.iter() {
+            if entity_id.0 == request.entity_id {
+                let old_joint = joint_data.clone();
+                commands.entity(entity).remove::<PhysicsJoint2d>();
+
+                // Record for undo
+                history.push(crate::core::history::UndoableAction::Joint2dChange {
+                    entity_id: request.entity_id.clone(),
+                    old_joint: Some(old_joint),
+                    new_joint: None,
+                });
+
+                // No event needed — removal is implicit
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies 2D force applications (only works during Play mode).
+pub(crate) fn apply_force_applications2d(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    engine_mode: Res<EngineMode>,
+    query: Query<(Entity, &EntityId), With<bevy_rapier2d::prelude::RigidBody>>,
+) {
+    if !engine_mode.is_playing() {
+        pending.force_applications2d.clear();
+        return;
+    }
+
+    for application in pending.force_applications2d.drain(..) {
+        for (entity, entity_id) in query.iter() {
+            if entity_id.0 == application.entity_id {
+                commands.entity(entity).insert(
+                    bevy_rapier2d::prelude::ExternalForce {
+                        force: bevy_rapier2d::prelude::Vect::new(
+                            application.force_x,
+                            application.force_y,
+                        ),
+                        torque: 0.0,
+                    }
+                );
+                break;
+            }
+        }
+    }
+}
+
+/// System that applies 2D impulse applications (only works during Play mode).
+pub(crate) fn apply_impulse_applications2d(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    engine_mode: Res<EngineMode>,
+    query: Query<(Entity, &EntityId), With<bevy_rapier2d::prelude::RigidBody>>,
+) {
+    if !engine_mode.is_playing() {
+        pending.impulse_applications2d.clear();
+        return;
+    }
+
+    for application in pending.impulse_applications2d.drain(..) {
+        for (entity, entity_id) in query.iter() {
+            if entity_id.0 == application.entity_id {
+                commands.entity(entity).insert(
+                    bevy_rapier2d::prelude::ExternalImpulse {
+                        impulse: bevy_rapier2d::prelude::Vect::new(
+                            application.impulse_x,
+                            application.impulse_y,
+                        ),
+                        torque_impulse: 0.0,
+                    }
+                );
+                break;
+            }
+        }
+    }
+}
+
+/// System that processes 2D raycast requests against the Rapier 2D world and
+/// reports each as a `RAYCAST2D_RESULT` event.
+/// Runs always-active, like the 3D `apply_raycast_queries`.
+pub(crate) fn apply_raycast2d_queries(
+    mut pending: ResMut<PendingCommands>,
+    rapier_context: bevy_rapier2d::prelude::ReadRapierContext,
+    entity_id_query: Query<&EntityId>,
+) {
+    for request in pending.raycast2d_requests.drain(..) {
+        let miss = || events::emit_raycast2d_result(&request.request_id, None, [0.0; 2], [0.0; 2], 0.0);
+        let Ok(rapier_context) = rapier_context.single() else {
+            miss();
+            continue;
+        };
+
+        let origin = bevy_rapier2d::prelude::Vect::new(request.origin_x, request.origin_y);
+        let direction = bevy_rapier2d::prelude::Vect::new(request.dir_x, request.dir_y);
+
+        let hit = rapier_context.cast_ray_and_get_normal(
+            origin,
+            direction,
+            request.max_distance,
+            true,
+            crate::core::physics_2d_sim::raycast_filter_2d(request.filter_groups),
+        );
+        match hit.and_then(|(entity, hit)| entity_id_query.get(entity).ok().map(|eid| (eid, hit))) {
+            Some((eid, hit)) => events::emit_raycast2d_result(
+                &request.request_id,
+                Some(&eid.0),
+                hit.point.to_array(),
+                hit.normal.to_array(),
+                hit.time_of_impact,
+            ),
+            None => miss(),
+        }
+    }
+}
+
+/// System that applies 2D gravity updates to the Gravity2d resource.
+pub(crate) fn apply_gravity2d_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut gravity: ResMut<crate::core::physics_2d_sim::Gravity2d>,
+) {
+    for update in pending.gravity2d_updates.drain(..) {
+        gravity.x = update.gravity_x;
+        gravity.y = update.gravity_y;
+        tracing::info!(
+            "2D gravity updated: ({}, {})",
+            update.gravity_x,
+            update.gravity_y
+        );
+    }
+}
+
+/// System that applies 2D debug physics toggles.
+pub(crate) fn apply_debug_physics2d_toggle(
+    mut pending: ResMut<PendingCommands>,
+    mut debug_enabled: ResMut<crate::core::physics_2d_sim::DebugPhysics2dEnabled>,
+) {
+    for toggle in pending.debug_physics2d_toggles.drain(..) {
+        debug_enabled.0 = toggle.enabled;
+        tracing::info!("2D debug physics rendering: {}", toggle.enabled);
+    }
+}
+
+/// System that handles 2D physics query requests (editor-only).
+pub(crate) fn handle_physics2d_query(
+    mut pending: ResMut<PendingCommands>,
+    physics_query: Query<(&EntityId, &Physics2dData, Option<&Physics2dEnabled>)>,
+) {
+    let requests: Vec<pending_commands::QueryRequest> = pending.query_requests
+        .drain(..)
+        .filter(|req| matches!(req, pending_commands::QueryRequest::Physics2dState { .. }))
+        .collect();
+
+    for request in requests {
+        if let pending_commands::QueryRequest::Physics2dState { entity_id } = request {
+            // Find the entity
+            let found = physics_query.iter().find(|(eid, _, _)| eid.0 == entity_id);
+            if let Some((eid, physics_data, phys2d_enabled)) = found {
+                let enabled = phys2d_enabled.is_some();
+                events::emit_physics2d_changed(&eid.0, physics_data, enabled);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "runtime")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raycast2d_reports_the_collider_it_hits() {
+        use bevy_rapier2d::prelude::{Collider, NoUserData, RapierPhysicsPlugin, TimestepMode};
+        use crate::core::pending::Raycast2dRequest;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), bevy::scene::ScenePlugin))
+            .init_asset::<Mesh>()
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+            .insert_resource(TimestepMode::Fixed { dt: 1.0 / 60.0, substeps: 1 })
+            .init_resource::<PendingCommands>()
+            .add_systems(Update, apply_raycast2d_queries);
+        app.world_mut().spawn((EntityId::new("wall"), Collider::cuboid(0.5, 2.0), Transform::from_xyz(5.0, 0.0, 0.0)));
+        // Let Rapier pick up the collider.
+        app.update();
+        app.update();
+
+        let ray = |request_id: &str, dir_x: f32| Raycast2dRequest {
+            request_id: request_id.to_string(),
+            origin_x: 0.0,
+            origin_y: 0.0,
+            dir_x,
+            dir_y: 0.0,
+            max_distance: 20.0,
+            filter_groups: None,
+        };
+        let mut pending = app.world_mut().resource_mut::<PendingCommands>();
+        pending.queue_raycast2d(ray("toward-wall", 1.0));
+        pending.queue_raycast2d(ray("into-space", -1.0));
+        let emitted = events::capture_events(|| app.update());
+
+        let results: Vec<&serde_json::Value> = emitted.iter()
+            .filter(|(name, _)| name == "RAYCAST2D_RESULT")
+            .map(|(_, payload)| payload)
+            .collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["requestId"], "toward-wall");
+        assert_eq!(results[0]["hitEntity"], "wall");
+        let toi = results[0]["toi"].as_f64().unwrap();
+        assert!((toi - 4.5).abs() < 1e-3, "toi {toi}");
+        assert_eq!(results[1]["requestId"], "into-space");
+        assert!(results[1]["hitEntity"].is_null());
+    }
+}
@@ -0,0 +1,395 @@
+//! CSG boolean operation system (editor-only).
+
+use bevy::prelude::*;
+use bevy::mesh::Mesh;
+use crate::core::{
+    self,
+    entity_id::{EntityId, EntityName, EntityVisible},
+    history::HistoryStack,
+    lighting::LightData,
+    material::MaterialData,
+    audio::AudioData,
+    particles::{ParticleData, ParticleEnabled},
+    pending_commands::{EntityType, PendingCommands},
+    physics::{PhysicsData, PhysicsEnabled},
+    scripting::ScriptData,
+    selection::{Selection, SelectionChangedEvent},
+    shader_effects::ShaderEffectData,
+    asset_manager::AssetRef,
+};
+
+use crate::bridge::events;
+
+/// System that processes pending CSG boolean operation requests (editor-only).
+pub(crate) fn apply_csg_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mesh_query: Query<(
+        Entity,
+        &EntityId,
+        &EntityName,
+        &Transform,
+        &EntityVisible,
+        Option<&EntityType>,
+        Option<&MaterialData>,
+        Option<&Mesh3d>,
+        Option<&AssetRef>,
+    )>,
+    // Separate queries for components beyond the 15-tuple limit
+    light_query: Query<(&EntityId, Option<&LightData>)>,
+    physics_query: Query<(&EntityId, Option<&PhysicsData>, Option<&PhysicsEnabled>)>,
+    script_query: Query<(&EntityId, Option<&ScriptData>)>,
+    audio_query: Query<(&EntityId, Option<&AudioData>)>,
+    particle_query: Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
+    shader_query: Query<(&EntityId, Option<&ShaderEffectData>)>,
+    csg_data_query: Query<(&EntityId, Option<&core::csg::CsgMeshData>)>,
+    mut history: ResMut<HistoryStack>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+) {
+    // Snapshot helper shared by single and chained operations
+    let build_snapshot = |eid: &EntityId, ename: &EntityName, etransform: &Transform,
+                          evisible: &EntityVisible, etype: Option<&EntityType>,
+                          emat: Option<&MaterialData>, easset: Option<&AssetRef>| -> core::history::EntitySnapshot {
+        let light_data = light_query.iter()
+            .find(|(lid, _)| lid.0 == eid.0)
+            .and_then(|(_, ld)| ld.cloned());
+
+        let (physics_data, physics_enabled) = physics_query.iter()
+            .find(|(pid, _, _)| pid.0 == eid.0)
+            .map(|(_, pd, pe)| (pd.cloned(), pe.is_some()))
+            .unwrap_or((None, false));
+
+        let script_data = script_query.iter()
+            .find(|(sid, _)| sid.0 == eid.0)
+            .and_then(|(_, sd)| sd.cloned());
+
+        let audio_data = audio_query.iter()
+            .find(|(aid, _)| aid.0 == eid.0)
+            .and_then(|(_, ad)| ad.cloned());
+
+        let (particle_data, particle_enabled) = particle_query.iter()
+            .find(|(pid, _, _)| pid.0 == eid.0)
+            .map(|(_, pd, pe)| (pd.cloned(), pe.is_some()))
+            .unwrap_or((None, false));
+
+        let shader_effect_data = shader_query.iter()
+            .find(|(sid, _)| sid.0 == eid.0)
+            .and_then(|(_, sed)| sed.cloned());
+
+        let csg_mesh_data = csg_data_query.iter()
+            .find(|(cid, _)| cid.0 == eid.0)
+            .and_then(|(_, cmd)| cmd.cloned());
+
+        let asset_ref = easset.cloned();
+
+        let mut snap = core::history::EntitySnapshot::new(
+            eid.0.clone(),
+            etype.copied().unwrap_or(EntityType::Cube),
+            ename.0.clone(),
+            core::history::TransformSnapshot::from(etransform),
+        );
+        snap.visible = evisible.0;
+        snap.material_data = emat.cloned();
+        snap.light_data = light_data;
+        snap.physics_data = physics_data;
+        snap.physics_enabled = physics_enabled;
+        snap.asset_ref = asset_ref;
+        snap.script_data = script_data;
+        snap.audio_data = audio_data;
+        snap.particle_data = particle_data;
+        snap.particle_enabled = particle_enabled;
+        snap.shader_effect_data = shader_effect_data;
+        snap.csg_mesh_data = csg_mesh_data;
+        snap
+    };
+
+    for request in pending.csg_requests.drain(..) {
+        let operation_name = request.operation;
+
+        // 1. Find both entities
+        let entity_a = mesh_query.iter()
+            .find(|(_, eid, ..)| eid.0 == request.entity_id_a);
+        let entity_b = mesh_query.iter()
+            .find(|(_, eid, ..)| eid.0 == request.entity_id_b);
+
+        let (Some(a_data), Some(b_data)) = (entity_a, entity_b) else {
+            tracing::warn!("CSG: one or both entities not found");
+            events::emit_csg_error("One or both entities not found");
+            continue;
+        };
+
+        // 2. Get Mesh handles
+        let (entity_a_ent, a_eid, a_name, a_transform, a_visible, a_etype,
+             a_mat, a_mesh3d, a_asset_ref) = a_data;
+        let (entity_b_ent, _b_eid, _b_name, b_transform, _b_visible, _b_etype,
+             _b_mat, b_mesh3d, _b_asset_ref) = b_data;
+
+        let Some(a_mesh_handle) = a_mesh3d else {
+            tracing::warn!("CSG: entity A has no Mesh3d component");
+            events::emit_csg_error("Entity A has no mesh");
+            continue;
+        };
+        let Some(b_mesh_handle) = b_mesh3d else {
+            tracing::warn!("CSG: entity B has no Mesh3d component");
+            events::emit_csg_error("Entity B has no mesh");
+            continue;
+        };
+
+        // 3. Get actual Mesh assets
+        let Some(a_mesh) = meshes.get(&a_mesh_handle.0) else {
+            tracing::warn!("CSG: could not load mesh asset for entity A");
+            events::emit_csg_error("Could not load mesh for entity A");
+            continue;
+        };
+        let Some(b_mesh) = meshes.get(&b_mesh_handle.0) else {
+            tracing::warn!("CSG: could not load mesh asset for entity B");
+            events::emit_csg_error("Could not load mesh for entity B");
+            continue;
+        };
+
+        // 4. Convert to csgrs format (world space)
+        let csg_a = match core::csg::bevy_mesh_to_csg(a_mesh, a_transform) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!("CSG: failed to convert entity A mesh: {}", e);
+                events::emit_csg_error(&format!("Failed to convert mesh A: {}", e));
+                continue;
+            }
+        };
+        let csg_b = match core::csg::bevy_mesh_to_csg(b_mesh, b_transform) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!("CSG: failed to convert entity B mesh: {}", e);
+                events::emit_csg_error(&format!("Failed to convert mesh B: {}", e));
+                continue;
+            }
+        };
+
+        // 5. Perform CSG operation
+        let result_csg = core::csg::perform_csg(&csg_a, &csg_b, operation_name);
+
+        // 6. Convert result back to Bevy Mesh
+        let (result_mesh, mesh_data) = match core::csg::csg_to_bevy_mesh(&result_csg) {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::warn!("CSG: operation produced invalid result: {}", e);
+                events::emit_csg_error(&format!("CSG operation failed: {}", e));
+                continue;
+            }
+        };
+
+        // 7. Create result entity
+        let result_material = a_mat.cloned().unwrap_or_default();
+        let result_entity_id = EntityId::default();
+        let result_entity_id_str = result_entity_id.0.clone();
+        let result_name = request.result_name.unwrap_or_else(|| {
+            let op_name = match operation_name {
+                core::csg::CsgOperation::Union => "Union",
+                core::csg::CsgOperation::Subtract => "Subtract",
+                core::csg::CsgOperation::Intersect => "Intersect",
+            };
+            format!("{} Result", op_name)
+        });
+
+        // Position at identity transform (mesh is already in world space)
+        let result_transform = Transform::IDENTITY;
+
+        commands.spawn((
+            EntityType::CsgResult,
+            result_entity_id.clone(),
+            EntityName::new(&result_name),
+            EntityVisible::default(),
+            result_material.clone(),
+            Mesh3d(meshes.add(result_mesh)),
+            MeshMaterial3d(materials.add(StandardMaterial::default())),
+            result_transform,
+            mesh_data.clone(),  // CsgMeshData component
+        ));
+
+        // Build source snapshots if we're deleting them
+        let source_a_snapshot = if request.delete_sources {
+            Some(build_snapshot(a_eid, a_name, a_transform, a_visible, a_etype, a_mat, a_asset_ref))
+        } else {
+            None
+        };
+        let source_b_snapshot = if request.delete_sources {
+            // Re-query entity B to obtain the full tuple needed for snapshot.
+            // The entity was already confirmed present above, so this should always succeed.
+            if let Some((_, b_eid, b_name, b_transform, b_visible, b_etype, b_mat, _, b_asset)) =
+                mesh_query.iter().find(|(_, eid, ..)| eid.0 == request.entity_id_b)
+            {
+                Some(build_snapshot(b_eid, b_name, b_transform, b_visible, b_etype, b_mat, b_asset))
+            } else {
+                tracing::warn!("CSG: entity B disappeared before snapshot could be taken");
+                None
+            }
+        } else {
+            None
+        };
+
+        // Build result snapshot
+        let result_snapshot = {
+            let mut snap = core::history::EntitySnapshot::new(
+                result_entity_id_str.clone(),
+                EntityType::CsgResult,
+                result_name.clone(),
+                core::history::TransformSnapshot::from(&result_transform),
+            );
+            snap.material_data = Some(result_material);
+            snap.csg_mesh_data = Some(mesh_data);
+            snap
+        };
+
+        // 8. Push history action
+        history.push(core::history::UndoableAction::CsgOperation {
+            source_a_snapshot,
+            source_b_snapshot,
+            result_snapshot,
+            sources_deleted: request.delete_sources,
+        });
+
+        // 9. Delete source entities if requested
+        if request.delete_sources {
+            commands.entity(entity_a_ent).despawn();
+            commands.entity(entity_b_ent).despawn();
+        }
+
+        // 10. Select the result entity (entity not yet spawned, clear and add ID only)
+        selection.clear();
+        selection.entity_ids.insert(result_entity_id_str.clone());
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: vec![result_entity_id_str.clone()],
+            primary_id: Some(result_entity_id_str.clone()),
+            primary_name: Some(result_name.clone()),
+        });
+
+        // 11. Emit completion event
+        events::emit_csg_completed(&result_entity_id_str, &result_name, operation_name);
+
+        tracing::info!("CSG operation completed: {}", result_name);
+    }
+
+    for request in pending.csg_chain_requests.drain(..) {
+        // 1. Resolve the base and every operand to a world-space csgrs mesh,
+        //    aborting the whole chain on the first failure
+        let ids: Vec<&str> = std::iter::once(request.base_entity_id.as_str())
+            .chain(request.operations.iter().map(|step| step.entity_id.as_str()))
+            .collect();
+
+        let mut sources = Vec::with_capacity(ids.len());
+        let mut csg_meshes = Vec::with_capacity(ids.len());
+        let mut failure = None;
+        for id in &ids {
+            let Some(source) = mesh_query.iter().find(|(_, eid, ..)| eid.0 == *id) else {
+                failure = Some(format!("Entity not found: {}", id));
+                break;
+            };
+            let Some(mesh) = source.7.and_then(|mesh3d| meshes.get(&mesh3d.0)) else {
+                failure = Some(format!("Entity {} has no mesh", id));
+                break;
+            };
+            match core::csg::bevy_mesh_to_csg(mesh, source.3) {
+                Ok(m) => csg_meshes.push(m),
+                Err(e) => {
+                    failure = Some(format!("Failed to convert mesh {}: {}", id, e));
+                    break;
+                }
+            }
+            sources.push(source);
+        }
+        if let Some(message) = failure {
+            tracing::warn!("CSG chain aborted: {}", message);
+            events::emit_csg_error(&message);
+            continue;
+        }
+
+        // 2. Fold the operations left to right
+        let mut operands = csg_meshes.into_iter();
+        let Some(base_csg) = operands.next() else {
+            continue;
+        };
+        let steps = request.operations.iter().map(|step| step.operation).zip(operands);
+        let result_csg = core::csg::perform_csg_chain(base_csg, steps);
+
+        // 3. Convert result back to Bevy Mesh
+        let (result_mesh, mesh_data) = match core::csg::csg_to_bevy_mesh(&result_csg) {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::warn!("CSG chain produced invalid result: {}", e);
+                events::emit_csg_error(&format!("CSG chain failed: {}", e));
+                continue;
+            }
+        };
+
+        // 4. Create result entity (material from the base entity)
+        let result_material = sources[0].6.cloned().unwrap_or_default();
+        let result_entity_id = EntityId::default();
+        let result_entity_id_str = result_entity_id.0.clone();
+        let result_name = request.result_name.unwrap_or_else(|| "CSG Chain Result".to_string());
+        let result_transform = Transform::IDENTITY;
+
+        commands.spawn((
+            EntityType::CsgResult,
+            result_entity_id,
+            EntityName::new(&result_name),
+            EntityVisible::default(),
+            result_material.clone(),
+            Mesh3d(meshes.add(result_mesh)),
+            MeshMaterial3d(materials.add(StandardMaterial::default())),
+            result_transform,
+            mesh_data.clone(),
+        ));
+
+        // 5. Record a single undo action covering every source
+        let source_snapshots = if request.delete_sources {
+            sources.iter()
+                .map(|(_, eid, ename, etransform, evisible, etype, emat, _, easset)| {
+                    build_snapshot(eid, ename, etransform, evisible, *etype, *emat, *easset)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let result_snapshot = {
+            let mut snap = core::history::EntitySnapshot::new(
+                result_entity_id_str.clone(),
+                EntityType::CsgResult,
+                result_name.clone(),
+                core::history::TransformSnapshot::from(&result_transform),
+            );
+            snap.material_data = Some(result_material);
+            snap.csg_mesh_data = Some(mesh_data);
+            snap
+        };
+
+        history.push(core::history::UndoableAction::CsgChain {
+            source_snapshots,
+            result_snapshot,
+            sources_deleted: request.delete_sources,
+        });
+
+        // 6. Delete source entities if requested
+        if request.delete_sources {
+            for (entity, ..) in &sources {
+                commands.entity(*entity).despawn();
+            }
+        }
+
+        // 7. Select the result entity
+        selection.clear();
+        selection.entity_ids.insert(result_entity_id_str.clone());
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: vec![result_entity_id_str.clone()],
+            primary_id: Some(result_entity_id_str.clone()),
+            primary_name: Some(result_name.clone()),
+        });
+
+        events::emit_csg_chain_completed(&result_entity_id_str, &result_name, request.operations.len());
+
+        tracing::info!("CSG chain completed: {}", result_name);
+    }
+}
@@ -1,4 +1,4 @@
-//! Procedural mesh generation and CSG operations.
+//! Procedural mesh generation (extrude, lathe, loft, sweep) and terrain painting.
 
 use bevy::prelude::*;
 use bevy::mesh::Mesh;
@@ -6,281 +6,26 @@ use crate::core::{
     self,
     entity_id::{EntityId, EntityName, EntityVisible},
     history::{EntitySnapshot as HistEntitySnapshot, HistoryStack, TransformSnapshot},
-    lighting::LightData,
     material::MaterialData,
-    audio::AudioData,
-    particles::{ParticleData, ParticleEnabled},
     pending_commands::{EntityType, PendingCommands},
-    physics::{PhysicsData, PhysicsEnabled},
-    scripting::ScriptData,
     selection::{Selection, SelectionChangedEvent},
-    shader_effects::ShaderEffectData,
-    asset_manager::AssetRef,
 };
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use super::events;
 
+#[cfg(not(feature = "runtime"))]
+mod csg;
+
+#[cfg(not(feature = "runtime"))]
+pub(super) use csg::*;
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     fn log(s: &str);
 }
 
-/// System that processes pending CSG boolean operation requests (editor-only).
-#[cfg(not(feature = "runtime"))]
-pub(super) fn apply_csg_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    mesh_query: Query<(
-        Entity,
-        &EntityId,
-        &EntityName,
-        &Transform,
-        &EntityVisible,
-        Option<&EntityType>,
-        Option<&MaterialData>,
-        Option<&Mesh3d>,
-        Option<&AssetRef>,
-    )>,
-    // Separate queries for components beyond the 15-tuple limit
-    light_query: Query<(&EntityId, Option<&LightData>)>,
-    physics_query: Query<(&EntityId, Option<&PhysicsData>, Option<&PhysicsEnabled>)>,
-    script_query: Query<(&EntityId, Option<&ScriptData>)>,
-    audio_query: Query<(&EntityId, Option<&AudioData>)>,
-    particle_query: Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
-    shader_query: Query<(&EntityId, Option<&ShaderEffectData>)>,
-    csg_data_query: Query<(&EntityId, Option<&core::csg::CsgMeshData>)>,
-    mut history: ResMut<HistoryStack>,
-    mut selection: ResMut<Selection>,
-    mut selection_events: MessageWriter<SelectionChangedEvent>,
-) {
-    for request in pending.csg_requests.drain(..) {
-        let operation_name = request.operation;
-
-        // 1. Find both entities
-        let entity_a = mesh_query.iter()
-            .find(|(_, eid, ..)| eid.0 == request.entity_id_a);
-        let entity_b = mesh_query.iter()
-            .find(|(_, eid, ..)| eid.0 == request.entity_id_b);
-
-        let (Some(a_data), Some(b_data)) = (entity_a, entity_b) else {
-            tracing::warn!("CSG: one or both entities not found");
-            events::emit_csg_error("One or both entities not found");
-            continue;
-        };
-
-        // 2. Get Mesh handles
-        let (entity_a_ent, a_eid, a_name, a_transform, a_visible, a_etype,
-             a_mat, a_mesh3d, a_asset_ref) = a_data;
-        let (entity_b_ent, _b_eid, _b_name, b_transform, _b_visible, _b_etype,
-             _b_mat, b_mesh3d, _b_asset_ref) = b_data;
-
-        let Some(a_mesh_handle) = a_mesh3d else {
-            tracing::warn!("CSG: entity A has no Mesh3d component");
-            events::emit_csg_error("Entity A has no mesh");
-            continue;
-        };
-        let Some(b_mesh_handle) = b_mesh3d else {
-            tracing::warn!("CSG: entity B has no Mesh3d component");
-            events::emit_csg_error("Entity B has no mesh");
-            continue;
-        };
-
-        // 3. Get actual Mesh assets
-        let Some(a_mesh) = meshes.get(&a_mesh_handle.0) else {
-            tracing::warn!("CSG: could not load mesh asset for entity A");
-            events::emit_csg_error("Could not load mesh for entity A");
-            continue;
-        };
-        let Some(b_mesh) = meshes.get(&b_mesh_handle.0) else {
-            tracing::warn!("CSG: could not load mesh asset for entity B");
-            events::emit_csg_error("Could not load mesh for entity B");
-            continue;
-        };
-
-        // 4. Convert to csgrs format (world space)
-        let csg_a = match core::csg::bevy_mesh_to_csg(a_mesh, a_transform) {
-            Ok(m) => m,
-            Err(e) => {
-                tracing::warn!("CSG: failed to convert entity A mesh: {}", e);
-                events::emit_csg_error(&format!("Failed to convert mesh A: {}", e));
-                continue;
-            }
-        };
-        let csg_b = match core::csg::bevy_mesh_to_csg(b_mesh, b_transform) {
-            Ok(m) => m,
-            Err(e) => {
-                tracing::warn!("CSG: failed to convert entity B mesh: {}", e);
-                events::emit_csg_error(&format!("Failed to convert mesh B: {}", e));
-                continue;
-            }
-        };
-
-        // 5. Perform CSG operation
-        let result_csg = core::csg::perform_csg(&csg_a, &csg_b, operation_name);
-
-        // 6. Convert result back to Bevy Mesh
-        let (result_mesh, mesh_data) = match core::csg::csg_to_bevy_mesh(&result_csg) {
-            Ok(r) => r,
-            Err(e) => {
-                tracing::warn!("CSG: operation produced invalid result: {}", e);
-                events::emit_csg_error(&format!("CSG operation failed: {}", e));
-                continue;
-            }
-        };
-
-        // 7. Create result entity
-        let result_material = a_mat.cloned().unwrap_or_default();
-        let result_entity_id = EntityId::default();
-        let result_entity_id_str = result_entity_id.0.clone();
-        let result_name = request.result_name.unwrap_or_else(|| {
-            let op_name = match operation_name {
-                core::csg::CsgOperation::Union => "Union",
-                core::csg::CsgOperation::Subtract => "Subtract",
-                core::csg::CsgOperation::Intersect => "Intersect",
-            };
-            format!("{} Result", op_name)
-        });
-
-        // Position at identity transform (mesh is already in world space)
-        let result_transform = Transform::IDENTITY;
-
-        commands.spawn((
-            EntityType::CsgResult,
-            result_entity_id.clone(),
-            EntityName::new(&result_name),
-            EntityVisible::default(),
-            result_material.clone(),
-            Mesh3d(meshes.add(result_mesh)),
-            MeshMaterial3d(materials.add(StandardMaterial::default())),
-            result_transform,
-            mesh_data.clone(),  // CsgMeshData component
-        ));
-
-        // 8. Build helper function for snapshots
-        let build_snapshot = |eid: &EntityId, ename: &EntityName, etransform: &Transform,
-                              evisible: &EntityVisible, etype: Option<&EntityType>,
-                              emat: Option<&MaterialData>, easset: Option<&AssetRef>| -> core::history::EntitySnapshot {
-            let light_data = light_query.iter()
-                .find(|(lid, _)| lid.0 == eid.0)
-                .and_then(|(_, ld)| ld.cloned());
-
-            let (physics_data, physics_enabled) = physics_query.iter()
-                .find(|(pid, _, _)| pid.0 == eid.0)
-                .map(|(_, pd, pe)| (pd.cloned(), pe.is_some()))
-                .unwrap_or((None, false));
-
-            let script_data = script_query.iter()
-                .find(|(sid, _)| sid.0 == eid.0)
-                .and_then(|(_, sd)| sd.cloned());
-
-            let audio_data = audio_query.iter()
-                .find(|(aid, _)| aid.0 == eid.0)
-                .and_then(|(_, ad)| ad.cloned());
-
-            let (particle_data, particle_enabled) = particle_query.iter()
-                .find(|(pid, _, _)| pid.0 == eid.0)
-                .map(|(_, pd, pe)| (pd.cloned(), pe.is_some()))
-                .unwrap_or((None, false));
-
-            let shader_effect_data = shader_query.iter()
-                .find(|(sid, _)| sid.0 == eid.0)
-                .and_then(|(_, sed)| sed.cloned());
-
-            let csg_mesh_data = csg_data_query.iter()
-                .find(|(cid, _)| cid.0 == eid.0)
-                .and_then(|(_, cmd)| cmd.cloned());
-
-            let asset_ref = easset.cloned();
-
-            let mut snap = core::history::EntitySnapshot::new(
-                eid.0.clone(),
-                etype.copied().unwrap_or(EntityType::Cube),
-                ename.0.clone(),
-                core::history::TransformSnapshot::from(etransform),
-            );
-            snap.visible = evisible.0;
-            snap.material_data = emat.cloned();
-            snap.light_data = light_data;
-            snap.physics_data = physics_data;
-            snap.physics_enabled = physics_enabled;
-            snap.asset_ref = asset_ref;
-            snap.script_data = script_data;
-            snap.audio_data = audio_data;
-            snap.particle_data = particle_data;
-            snap.particle_enabled = particle_enabled;
-            snap.shader_effect_data = shader_effect_data;
-            snap.csg_mesh_data = csg_mesh_data;
-            snap
-        };
-
-        // Build source snapshots if we're deleting them
-        let source_a_snapshot = if request.delete_sources {
-            Some(build_snapshot(a_eid, a_name, a_transform, a_visible, a_etype, a_mat, a_asset_ref))
-        } else {
-            None
-        };
-        let source_b_snapshot = if request.delete_sources {
-            // Re-query entity B to obtain the full tuple needed for snapshot.
-            // The entity was already confirmed present above, so this should always succeed.
-            if let Some((_, b_eid, b_name, b_transform, b_visible, b_etype, b_mat, _, b_asset)) =
-                mesh_query.iter().find(|(_, eid, ..)| eid.0 == request.entity_id_b)
-            {
-                Some(build_snapshot(b_eid, b_name, b_transform, b_visible, b_etype, b_mat, b_asset))
-            } else {
-                tracing::warn!("CSG: entity B disappeared before snapshot could be taken");
-                None
-            }
-        } else {
-            None
-        };
-
-        // Build result snapshot
-        let result_snapshot = {
-            let mut snap = core::history::EntitySnapshot::new(
-                result_entity_id_str.clone(),
-                EntityType::CsgResult,
-                result_name.clone(),
-                core::history::TransformSnapshot::from(&result_transform),
-            );
-            snap.material_data = Some(result_material);
-            snap.csg_mesh_data = Some(mesh_data);
-            snap
-        };
-
-        // 9. Push history action
-        history.push(core::history::UndoableAction::CsgOperation {
-            source_a_snapshot,
-            source_b_snapshot,
-            result_snapshot,
-            sources_deleted: request.delete_sources,
-        });
-
-        // 10. Delete source entities if requested
-        if request.delete_sources {
-            commands.entity(entity_a_ent).despawn();
-            commands.entity(entity_b_ent).despawn();
-        }
-
-        // 11. Select the result entity (entity not yet spawned, clear and add ID only)
-        selection.clear();
-        selection.entity_ids.insert(result_entity_id_str.clone());
-        selection_events.write(SelectionChangedEvent {
-            selected_ids: vec![result_entity_id_str.clone()],
-            primary_id: Some(result_entity_id_str.clone()),
-            primary_name: Some(result_name.clone()),
-        });
-
-        // 12. Emit completion event
-        events::emit_csg_completed(&result_entity_id_str, &result_name, operation_name);
-
-        tracing::info!("CSG operation completed: {}", result_name);
-    }
-}
-
 /// System that processes pending extrude requests.
 pub(super) fn apply_extrude_requests(
     mut pending: ResMut<PendingCommands>,
@@ -552,3 +297,218 @@ pub(super) fn apply_lathe_requests(
         emit_procedural_mesh_created(&entity_id_str, &name, "lathe");
     }
 }
+
+/// Spawn a procedural mesh entity with the default grey material and return
+/// it with its id and a history snapshot. Shared by the loft and sweep systems.
+fn spawn_procedural_result(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    mesh: Mesh,
+    mesh_data: crate::core::procedural_mesh::ProceduralMeshData,
+    name: &str,
+    position: Vec3,
+) -> (Entity, String, HistEntitySnapshot) {
+    let entity_id = EntityId::default();
+    let entity_id_str = entity_id.0.clone();
+
+    let entity = commands.spawn((
+        EntityType::ProceduralMesh,
+        entity_id,
+        EntityName::new(name),
+        EntityVisible::default(),
+        MaterialData::default(),
+        mesh_data.clone(),
+        Mesh3d(meshes.add(mesh)),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.5, 0.5, 0.5),
+            ..default()
+        })),
+        Transform::from_translation(position),
+    )).id();
+
+    let mut snapshot = HistEntitySnapshot::new(
+        entity_id_str.clone(),
+        EntityType::ProceduralMesh,
+        name.to_string(),
+        TransformSnapshot {
+            position: [position.x, position.y, position.z],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [1.0, 1.0, 1.0],
+        },
+    );
+    snapshot.material_data = Some(MaterialData::default());
+    snapshot.procedural_mesh_data = Some(mesh_data);
+
+    (entity, entity_id_str, snapshot)
+}
+
+/// Make a newly created procedural entity the sole selection.
+fn select_procedural_result(
+    selection: &mut Selection,
+    selection_events: &mut MessageWriter<SelectionChangedEvent>,
+    entity: Entity,
+    entity_id: &str,
+    name: &str,
+) {
+    selection.entities.clear();
+    selection.entity_ids.clear();
+    selection.entities.insert(entity);
+    selection.entity_ids.insert(entity_id.to_string());
+    selection.primary = Some(entity);
+    selection.primary_id = Some(entity_id.to_string());
+    selection_events.write(SelectionChangedEvent {
+        selected_ids: vec![entity_id.to_string()],
+        primary_id: Some(entity_id.to_string()),
+        primary_name: Some(name.to_string()),
+    });
+}
+
+/// System that processes pending loft requests.
+pub(super) fn apply_loft_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+    mut history: ResMut<HistoryStack>,
+) {
+    use crate::core::history::UndoableAction;
+    use crate::core::procedural_mesh::{generate_loft_mesh, ProceduralMeshData, ProceduralOp};
+    use events::{emit_procedural_mesh_created, emit_procedural_mesh_error};
+
+    for request in pending.loft_requests.drain(..) {
+        let mesh = generate_loft_mesh(&request.profiles, request.closed);
+        let operation = ProceduralOp::Loft {
+            profiles: request.profiles,
+            closed: request.closed,
+        };
+        let Some(mesh_data) = ProceduralMeshData::from_mesh(&mesh, operation) else {
+            emit_procedural_mesh_error("Loft produced an empty mesh");
+            continue;
+        };
+
+        let name = request.name.unwrap_or_else(|| "Lofted Mesh".to_string());
+        let position = request.position.unwrap_or(Vec3::ZERO);
+        let (entity, entity_id_str, snapshot) = spawn_procedural_result(
+            &mut commands, &mut meshes, &mut materials, mesh, mesh_data, &name, position,
+        );
+
+        history.push(UndoableAction::LoftShape { snapshot });
+        select_procedural_result(&mut selection, &mut selection_events, entity, &entity_id_str, &name);
+        emit_procedural_mesh_created(&entity_id_str, &name, "loft");
+    }
+}
+
+/// System that processes pending sweep requests.
+pub(super) fn apply_sweep_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+    mut history: ResMut<HistoryStack>,
+) {
+    use crate::core::history::UndoableAction;
+    use crate::core::procedural_mesh::{generate_sweep_mesh, ProceduralMeshData, ProceduralOp};
+    use events::{emit_procedural_mesh_created, emit_procedural_mesh_error};
+
+    for request in pending.sweep_requests.drain(..) {
+        let mesh = generate_sweep_mesh(&request.profile, &request.path, request.segments);
+        let operation = ProceduralOp::Sweep {
+            profile: request.profile,
+            path: request.path,
+            segments: request.segments,
+        };
+        let Some(mesh_data) = ProceduralMeshData::from_mesh(&mesh, operation) else {
+            emit_procedural_mesh_error("Sweep produced an empty mesh (is the path zero length?)");
+            continue;
+        };
+
+        let name = request.name.unwrap_or_else(|| "Swept Mesh".to_string());
+        let position = request.position.unwrap_or(Vec3::ZERO);
+        let (entity, entity_id_str, snapshot) = spawn_procedural_result(
+            &mut commands, &mut meshes, &mut materials, mesh, mesh_data, &name, position,
+        );
+
+        history.push(UndoableAction::SweepShape { snapshot });
+        select_procedural_result(&mut selection, &mut selection_events, entity, &entity_id_str, &name);
+        emit_procedural_mesh_created(&entity_id_str, &name, "sweep");
+    }
+}
+
+/// System that processes pending terrain hole paints: updates the hole flags,
+/// rebuilds the mesh without the flagged quads, and records undo history.
+/// Live colliders are rebuilt by the physics plugin once TerrainData changes.
+pub(super) fn apply_terrain_hole_paints(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &Transform, &mut core::terrain::TerrainData, &core::terrain::TerrainMeshData, &mut Mesh3d)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut history: ResMut<HistoryStack>,
+) {
+    use crate::core::history::UndoableAction;
+    use crate::core::terrain::{paint_holes, rebuild_terrain_mesh};
+
+    for paint in pending.terrain_hole_paints.drain(..) {
+        let Some((_, transform, mut terrain, mesh_data, mut mesh3d)) =
+            query.iter_mut().find(|(eid, ..)| eid.0 == paint.entity_id)
+        else {
+            tracing::warn!("paint_terrain_hole: terrain entity {} not found", paint.entity_id);
+            continue;
+        };
+
+        // Brush position arrives in world space; the hole grid is centered on the entity.
+        let local = [paint.position[0] - transform.translation.x, paint.position[1] - transform.translation.z];
+        let old_holes = terrain.holes.clone();
+        let mut holes = old_holes.clone();
+        if !paint_holes(&mut holes, mesh_data.resolution, mesh_data.size, local, paint.radius, paint.fill) {
+            continue;
+        }
+
+        mesh3d.0 = meshes.add(rebuild_terrain_mesh(mesh_data, &holes));
+        terrain.holes = holes.clone();
+
+        history.push(UndoableAction::TerrainHoleEdit {
+            entity_id: paint.entity_id.clone(),
+            old_holes,
+            new_holes: holes,
+            mesh_data: mesh_data.clone(),
+        });
+        events::emit_terrain_changed(&paint.entity_id, &terrain);
+    }
+}
+
+/// System that applies terrain splat layer changes. The material itself is
+/// rebuilt by `sync_terrain_materials` once TerrainData changes.
+pub(super) fn apply_terrain_splat_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut core::terrain::TerrainData, Option<&core::terrain::TerrainMeshData>)>,
+    mut history: ResMut<HistoryStack>,
+) {
+    use crate::core::history::UndoableAction;
+
+    for update in pending.terrain_splat_updates.drain(..) {
+        let Some((_, mut terrain, mesh_data)) = query.iter_mut().find(|(eid, _, _)| eid.0 == update.entity_id) else {
+            tracing::warn!("set_terrain_splat: terrain entity {} not found", update.entity_id);
+            continue;
+        };
+
+        let old_terrain = terrain.clone();
+        terrain.splat_layers = update.layers;
+        terrain.splat_map = update.splat_map;
+
+        // Heights are untouched, so old and new mesh data are the same.
+        if let Some(mesh_data) = mesh_data {
+            history.push(UndoableAction::TerrainChange {
+                entity_id: update.entity_id.clone(),
+                old_terrain,
+                new_terrain: terrain.clone(),
+                old_mesh_data: mesh_data.clone(),
+                new_mesh_data: mesh_data.clone(),
+            });
+        }
+        events::emit_terrain_changed(&update.entity_id, &terrain);
+    }
+}
@@ -0,0 +1,227 @@
+//! Per-domain query systems kept out of `process_query_requests` to stay
+//! under the system parameter limit.
+
+use bevy::prelude::*;
+use crate::core::{
+    entity_id::EntityId,
+    entity_id::EntityName,
+    reverb_zone::{ReverbZoneData, ReverbZoneEnabled},
+    terrain::TerrainData,
+    quality::QualitySettings,
+    spatial_query::SpatialGrid,
+    pending_commands::PendingCommands,
+    engine_mode::EngineMode,
+};
+use crate::bridge::events;
+
+#[cfg(not(feature = "runtime"))]
+use crate::core::physics::JointData;
+
+/// Process query_play_state requests separately to stay under 16 system parameter limit.
+pub(crate) fn process_play_state_queries(
+    mut pending: ResMut<PendingCommands>,
+    engine_mode: Res<EngineMode>,
+    play_state_query: Query<(&EntityId, Option<&EntityName>, &Transform)>,
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    let has_play_state = pending.query_requests.iter().any(|r| matches!(r, QueryRequest::PlayState));
+    if !has_play_state {
+        return;
+    }
+
+    if !engine_mode.is_playing() && !matches!(*engine_mode, EngineMode::Paused) {
+        events::emit_event("QUERY_PLAY_STATE_ERROR", &serde_json::json!({
+            "error": "query_play_state is only available in Play or Paused mode",
+            "engineMode": engine_mode.as_str(),
+        }));
+        pending.query_requests.retain(|r| !matches!(r, QueryRequest::PlayState));
+        return;
+    }
+
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct EntityPlayState {
+        id: String,
+        name: String,
+        position: [f32; 3],
+        rotation: [f32; 4],
+        scale: [f32; 3],
+    }
+
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct PlayStateResponse {
+        entities: Vec<EntityPlayState>,
+        entity_count: usize,
+        engine_mode: String,
+    }
+
+    let entities: Vec<EntityPlayState> = play_state_query.iter()
+        .map(|(eid, ename, transform)| {
+            let quat = transform.rotation;
+            EntityPlayState {
+                id: eid.0.clone(),
+                name: ename.map(|n| n.0.clone()).unwrap_or_default(),
+                position: [transform.translation.x, transform.translation.y, transform.translation.z],
+                rotation: [quat.x, quat.y, quat.z, quat.w],
+                scale: [transform.scale.x, transform.scale.y, transform.scale.z],
+            }
+        })
+        .collect();
+
+    let entity_count = entities.len();
+    events::emit_event("QUERY_PLAY_STATE", &PlayStateResponse {
+        entities,
+        entity_count,
+        engine_mode: engine_mode.as_str().to_string(),
+    });
+
+    pending.query_requests.retain(|r| !matches!(r, QueryRequest::PlayState));
+}
+
+/// Process terrain query requests separately to stay under 16 system parameter limit.
+pub(crate) fn process_terrain_queries(
+    mut pending: ResMut<PendingCommands>,
+    terrain_query: Query<(&EntityId, Option<&TerrainData>)>,
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    let requests: Vec<QueryRequest> = pending.query_requests.iter().filter_map(|req| {
+        if matches!(req, QueryRequest::TerrainState { .. }) {
+            Some(req.clone())
+        } else {
+            None
+        }
+    }).collect();
+
+    for request in requests {
+        if let QueryRequest::TerrainState { entity_id } = request {
+            for (eid, terrain_data) in terrain_query.iter() {
+                if eid.0 == entity_id {
+                    if let Some(terrain) = terrain_data {
+                        events::emit_terrain_changed(&entity_id, terrain);
+                    }
+                    break;
+                }
+            }
+            // Remove the processed request
+            pending.query_requests.retain(|r| !matches!(r, QueryRequest::TerrainState { entity_id: ref eid } if eid == &entity_id));
+        }
+    }
+}
+
+/// Process region queries against the spatial grid.
+pub(crate) fn process_spatial_queries(
+    mut pending: ResMut<PendingCommands>,
+    grid: Res<SpatialGrid>,
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    let requests: Vec<QueryRequest> = pending.query_requests.iter()
+        .filter(|r| matches!(r, QueryRequest::EntitiesInRadius { .. } | QueryRequest::EntitiesInBox { .. }))
+        .cloned()
+        .collect();
+    if requests.is_empty() {
+        return;
+    }
+    pending.query_requests.retain(|r| !matches!(r, QueryRequest::EntitiesInRadius { .. } | QueryRequest::EntitiesInBox { .. }));
+
+    for request in requests {
+        let hits = match request {
+            QueryRequest::EntitiesInRadius { center, radius } => grid.query_radius(Vec3::from(center), radius),
+            QueryRequest::EntitiesInBox { min, max } => grid.query_box(Vec3::from(min), Vec3::from(max)),
+            _ => continue,
+        };
+        events::emit_entities_in_region(&hits);
+    }
+}
+
+/// Process quality query requests separately to stay under 16 system parameter limit.
+pub(crate) fn process_quality_queries(
+    mut pending: ResMut<PendingCommands>,
+    quality_settings: Res<QualitySettings>,
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    let has_quality = pending.query_requests.iter().any(|r| matches!(r, QueryRequest::QualitySettings));
+    if has_quality {
+        events::emit_quality_changed(&quality_settings);
+        pending.query_requests.retain(|r| !matches!(r, QueryRequest::QualitySettings));
+    }
+}
+
+/// System that applies quality preset requests.
+pub(crate) fn apply_quality_presets(
+    mut pending: ResMut<PendingCommands>,
+    mut quality: ResMut<QualitySettings>,
+) {
+    for request in pending.quality_preset_requests.drain(..) {
+        if let Some(preset) = crate::core::quality::QualitySettings::parse_preset(&request.preset) {
+            *quality = crate::core::quality::QualitySettings::from_preset(preset);
+            events::emit_quality_changed(&quality);
+            tracing::info!("Applied quality preset: {}", request.preset);
+        }
+    }
+}
+
+/// Process reverb zone query requests separately to stay under 16 system parameter limit.
+pub(crate) fn process_reverb_zone_queries(
+    mut pending: ResMut<PendingCommands>,
+    reverb_zone_query: Query<(&EntityId, Option<&ReverbZoneData>, Option<&ReverbZoneEnabled>)>,
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    let requests: Vec<QueryRequest> = pending.query_requests.iter().filter_map(|req| {
+        if matches!(req, QueryRequest::ReverbZoneState { .. }) {
+            Some(req.clone())
+        } else {
+            None
+        }
+    }).collect();
+
+    for request in requests {
+        if let QueryRequest::ReverbZoneState { entity_id } = request {
+            for (eid, reverb_zone_data, rz_enabled) in reverb_zone_query.iter() {
+                if eid.0 == entity_id {
+                    if let Some(data) = reverb_zone_data {
+                        events::emit_reverb_zone_changed(&entity_id, data, rz_enabled.is_some());
+                    }
+                    break;
+                }
+            }
+            // Remove the processed request
+            pending.query_requests.retain(|r| !matches!(r, QueryRequest::ReverbZoneState { entity_id: ref eid } if eid == &entity_id));
+        }
+    }
+}
+
+/// Process joint list query requests.
+#[cfg(not(feature = "runtime"))]
+pub(crate) fn process_joint_queries(
+    mut pending: ResMut<PendingCommands>,
+    joint_query: Query<(&EntityId, &JointData)>,
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    let has_list_joints = pending.query_requests.iter().any(|r| matches!(r, QueryRequest::ListJoints));
+    if has_list_joints {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct JointInfo {
+            entity_id: String,
+            #[serde(flatten)]
+            joint_data: JointData,
+        }
+
+        let joints: Vec<JointInfo> = joint_query.iter()
+            .map(|(eid, jd)| JointInfo {
+                entity_id: eid.0.clone(),
+                joint_data: jd.clone(),
+            })
+            .collect();
+
+        events::emit_event("QUERY_JOINTS_LIST", &joints);
+        pending.query_requests.retain(|r| !matches!(r, QueryRequest::ListJoints));
+    }
+}
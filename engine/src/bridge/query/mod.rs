@@ -0,0 +1,619 @@
+//! Query processing systems for the bridge layer.
+
+use bevy::prelude::*;
+use crate::core::{
+    entity_id::EntityId,
+    entity_id::EntityName,
+    entity_id::EntityTags,
+    material::{MaterialData, MaterialPreset},
+    lighting::LightData,
+    physics::{PhysicsData, PhysicsEnabled, JointData},
+    pending_commands::EntityType,
+    game_components::GameComponents,
+    animation_clip::AnimationClipData,
+    sprite::SpriteData,
+    scripting::ScriptData,
+    audio::{AudioData, AudioBusConfig},
+    reverb_zone::ReverbZoneData,
+    shader_effects::ShaderEffectData,
+    particles::{ParticleData, ParticleEnabled},
+    terrain::TerrainData,
+    selection::Selection,
+    pending_commands::PendingCommands,
+    engine_mode::EngineMode,
+    input::{InputMap, InputState},
+    asset_manager::AssetRegistry,
+    post_processing::PostProcessingSettings,
+    animation::{AnimationRegistry, AnimationBlend1d},
+    camera_presets::CameraProjectionMode,
+};
+use super::{
+    events,
+    scene_graph::SceneGraphCache,
+};
+
+mod domains;
+
+pub(super) use domains::*;
+
+/// Process query requests from MCP and emit response events.
+pub(super) fn process_query_requests(
+    mut pending: ResMut<PendingCommands>,
+    selection: Res<Selection>,
+    scene_cache: Res<SceneGraphCache>,
+    engine_mode: Res<EngineMode>,
+    input_map: Res<InputMap>,
+    input_state: Res<InputState>,
+    asset_registry: Res<AssetRegistry>,
+    post_processing_settings: Res<PostProcessingSettings>,
+    bus_config: Res<AudioBusConfig>,
+    query_entities: Query<(
+        &EntityId,
+        Option<&EntityName>,
+        &Transform,
+        Option<&MaterialData>,
+        Option<&LightData>,
+        Option<&PhysicsData>,
+        Option<&PhysicsEnabled>,
+        Option<&ScriptData>,
+        Option<&EntityTags>,
+    )>,
+    audio_query: Query<(Entity, &EntityId, Option<&AudioData>)>,
+    particle_q: Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
+    shader_data_query: Query<(&EntityId, Option<&ShaderEffectData>)>,
+    (animation_registry, animation_player_query, blend_1d_query): (
+        Res<AnimationRegistry>,
+        Query<&AnimationPlayer>,
+        Query<(&EntityId, &AnimationBlend1d)>,
+    ),
+    camera_query: Query<(&bevy_panorbit_camera::PanOrbitCamera, &Projection)>,
+    full_query: Query<(
+        &EntityId,
+        Option<&EntityType>,
+        Option<&GameComponents>,
+        Option<&AnimationClipData>,
+        Option<&JointData>,
+        Option<&ReverbZoneData>,
+        Option<&TerrainData>,
+        Option<&SpriteData>,
+    )>,
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    // Take only the requests this system handles; leave the rest for dedicated handlers
+    // (sprite, terrain, quality, reverb zone, etc.) that run as separate systems.
+    let mut handled = Vec::new();
+    let mut remaining = Vec::new();
+    for request in pending.query_requests.drain(..) {
+        if handled_elsewhere(&request) {
+            remaining.push(request);
+        } else {
+            handled.push(request);
+        }
+    }
+    pending.query_requests = remaining;
+
+    let answer = |request: QueryRequest| match request {
+        QueryRequest::SceneGraph => {
+            // Emit the cached scene graph
+            events::emit_event("QUERY_SCENE_GRAPH", &scene_cache.data);
+        }
+        QueryRequest::Selection => {
+            #[derive(serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct SelectionResponse {
+                selected_ids: Vec<String>,
+                primary_id: Option<String>,
+            }
+            events::emit_event("QUERY_SELECTION", &SelectionResponse {
+                selected_ids: selection.selected_ids(),
+                primary_id: selection.primary_id.clone(),
+            });
+        }
+        QueryRequest::EntityDetails { entity_id } => {
+            #[derive(serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct EntityDetails {
+                entity_id: String,
+                name: Option<String>,
+                position: [f32; 3],
+                rotation: [f32; 3],
+                scale: [f32; 3],
+                material: Option<MaterialData>,
+                light: Option<LightData>,
+                physics: Option<PhysicsData>,
+                physics_enabled: bool,
+                tags: Vec<String>,
+            }
+
+            for (eid, ename, transform, mat, light, physics, phys_enabled, _script, tags) in query_entities.iter() {
+                if eid.0 == entity_id {
+                    let (rx, ry, rz) = transform.rotation.to_euler(bevy::math::EulerRot::XYZ);
+                    events::emit_event("QUERY_ENTITY_DETAILS", &EntityDetails {
+                        entity_id: eid.0.clone(),
+                        name: ename.map(|n| n.0.clone()),
+                        position: [transform.translation.x, transform.translation.y, transform.translation.z],
+                        rotation: [rx.to_degrees(), ry.to_degrees(), rz.to_degrees()],
+                        scale: [transform.scale.x, transform.scale.y, transform.scale.z],
+                        material: mat.cloned(),
+                        light: light.cloned(),
+                        physics: physics.cloned(),
+                        physics_enabled: phys_enabled.is_some(),
+                        tags: tags.map(EntityTags::sorted).unwrap_or_default(),
+                    });
+                    break;
+                }
+            }
+        }
+        QueryRequest::EntityFull { entity_id } => {
+            #[derive(serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct TransformState {
+                position: [f32; 3],
+                rotation: [f32; 3],
+                scale: [f32; 3],
+            }
+
+            /// Every component of an entity; absent ones are left out.
+            #[derive(serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct EntityFull {
+                entity_id: String,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                name: Option<String>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                entity_type: Option<EntityType>,
+                transform: TransformState,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                material: Option<MaterialData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                light: Option<LightData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                physics: Option<PhysicsData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                physics_enabled: Option<bool>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                joint: Option<JointData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                script: Option<ScriptData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                audio: Option<AudioData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                reverb_zone: Option<ReverbZoneData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                particle: Option<ParticleData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                particle_enabled: Option<bool>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                shader: Option<ShaderEffectData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                terrain: Option<TerrainData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                sprite: Option<SpriteData>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                game_components: Option<GameComponents>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                animation_clip: Option<AnimationClipData>,
+                /// Clip names of an imported model's animations.
+                #[serde(skip_serializing_if = "Option::is_none")]
+                animations: Option<Vec<String>>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                tags: Option<Vec<String>>,
+            }
+
+            let Some((eid, ename, transform, mat, light, physics, phys_enabled, script, tags)) =
+                query_entities.iter().find(|row| row.0.0 == entity_id)
+            else {
+                return;
+            };
+            let (_, entity_type, game_components, clip, joint, reverb_zone, terrain, sprite) = full_query.iter()
+                .find(|row| row.0.0 == entity_id)
+                .unwrap_or((eid, None, None, None, None, None, None, None));
+            let audio = audio_query.iter().find(|row| row.1.0 == entity_id).and_then(|row| row.2);
+            let (particle, particle_enabled) = particle_q.iter()
+                .find(|row| row.0.0 == entity_id)
+                .map_or((None, None), |(_, pd, pe)| (pd, pd.map(|_| pe.is_some())));
+            let shader = shader_data_query.iter().find(|row| row.0.0 == entity_id).and_then(|row| row.1);
+
+            let (rx, ry, rz) = transform.rotation.to_euler(bevy::math::EulerRot::XYZ);
+            events::emit_event("QUERY_ENTITY_FULL", &EntityFull {
+                entity_id: eid.0.clone(),
+                name: ename.map(|n| n.0.clone()),
+                entity_type: entity_type.copied(),
+                transform: TransformState {
+                    position: transform.translation.to_array(),
+                    rotation: [rx.to_degrees(), ry.to_degrees(), rz.to_degrees()],
+                    scale: transform.scale.to_array(),
+                },
+                material: mat.cloned(),
+                light: light.cloned(),
+                physics: physics.cloned(),
+                physics_enabled: physics.map(|_| phys_enabled.is_some()),
+                joint: joint.cloned(),
+                script: script.cloned(),
+                audio: audio.cloned(),
+                reverb_zone: reverb_zone.cloned(),
+                particle: particle.cloned(),
+                particle_enabled,
+                shader: shader.cloned(),
+                terrain: terrain.cloned(),
+                sprite: sprite.cloned(),
+                game_components: game_components.cloned(),
+                animation_clip: clip.cloned(),
+                animations: animation_registry.entries.get(&entity_id).map(|entry| entry.clip_names.clone()),
+                tags: tags.filter(|t| !t.0.is_empty()).map(EntityTags::sorted),
+            });
+        }
+        QueryRequest::CameraState => {
+            #[derive(serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct CameraStateResponse {
+                yaw: f32,
+                pitch: f32,
+                radius: f32,
+                focus: [f32; 3],
+                projection: CameraProjectionMode,
+            }
+
+            if let Ok((cam, projection)) = camera_query.single() {
+                events::emit_event("QUERY_CAMERA_STATE", &CameraStateResponse {
+                    yaw: cam.yaw.unwrap_or(0.0),
+                    pitch: cam.pitch.unwrap_or(0.0),
+                    radius: cam.radius.unwrap_or(10.0),
+                    focus: [cam.focus.x, cam.focus.y, cam.focus.z],
+                    projection: CameraProjectionMode::of(projection),
+                });
+            }
+        }
+        QueryRequest::EngineMode => {
+            #[derive(serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct EngineModeResponse {
+                mode: String,
+            }
+
+            events::emit_event("ENGINE_MODE", &EngineModeResponse {
+                mode: engine_mode.as_str().to_string(),
+            });
+        }
+        QueryRequest::InputBindings => {
+            events::emit_event("QUERY_INPUT_BINDINGS", &*input_map);
+        }
+        QueryRequest::InputState => {
+            events::emit_event("QUERY_INPUT_STATE", &*input_state);
+        }
+        QueryRequest::AssetList => {
+            events::emit_asset_list(&asset_registry);
+        }
+        QueryRequest::PhysicsState { entity_id } => {
+            for (eid, _, _, _, _, physics, phys_enabled, _script, _) in query_entities.iter() {
+                if eid.0 == entity_id {
+                    if let Some(physics_data) = physics {
+                        events::emit_physics_changed(&entity_id, physics_data, phys_enabled.is_some());
+                    }
+                    break;
+                }
+            }
+        }
+        QueryRequest::ScriptData { entity_id } => {
+            for (eid, _, _, _, _, _, _, script_data, _) in query_entities.iter() {
+                if eid.0 == entity_id {
+                    events::emit_script_changed(&entity_id, script_data);
+                    break;
+                }
+            }
+        }
+        QueryRequest::AudioData { entity_id } => {
+            for (_entity, eid, audio_data) in audio_query.iter() {
+                if eid.0 == entity_id {
+                    events::emit_audio_changed(&entity_id, audio_data);
+                    break;
+                }
+            }
+        }
+        QueryRequest::ReverbZoneState { entity_id: _ } => {
+            // Note: reverb zone query handled in separate process_reverb_zone_queries to avoid param limit
+        }
+        QueryRequest::ScriptTemplates => {
+            #[derive(serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct TemplateList {
+                templates: Vec<&'static str>,
+                details: &'static [crate::core::scripting::ScriptTemplate],
+            }
+
+            let details = crate::core::scripting::SCRIPT_TEMPLATES;
+            events::emit_event("QUERY_SCRIPT_TEMPLATES", &TemplateList {
+                templates: details.iter().map(|template| template.id).collect(),
+                details,
+            });
+        }
+        QueryRequest::PostProcessingState => {
+            events::emit_event("QUERY_POST_PROCESSING", &*post_processing_settings);
+        }
+        QueryRequest::AudioBuses => {
+            events::emit_event("QUERY_AUDIO_BUSES", &*bus_config);
+        }
+        QueryRequest::ParticleState { entity_id } => {
+            for (eid, pd, pe) in particle_q.iter() {
+                if eid.0 == entity_id {
+                    events::emit_particle_changed(&entity_id, pd, pe.is_some());
+                    break;
+                }
+            }
+        }
+        QueryRequest::AnimationState { entity_id } => {
+            if let Some(state) = super::animation::build_animation_state(&entity_id, &animation_registry, &animation_player_query) {
+                events::emit_animation_state_changed(&state);
+            }
+        }
+        QueryRequest::AnimationGraph { entity_id } => {
+            if let Some(entry) = animation_registry.entries.get(&entity_id) {
+                if let Ok(player) = animation_player_query.get(entry.player_entity) {
+                    let blend_1d = blend_1d_query.iter().find(|(eid, _)| eid.0 == entity_id).map(|(_, b)| b);
+                    let graph_state = super::animation::build_animation_graph_state(&entity_id, entry, &player, blend_1d);
+                    events::emit_event("QUERY_ANIMATION_GRAPH", &graph_state);
+                }
+            }
+        }
+        QueryRequest::ShaderData { entity_id } => {
+            for (eid, shader_data) in shader_data_query.iter() {
+                if eid.0 == entity_id {
+                    events::emit_shader_changed(&entity_id, shader_data);
+                    break;
+                }
+            }
+        }
+        QueryRequest::QualitySettings => {
+            // Handled by process_quality_queries system to avoid system parameter limit
+        }
+        QueryRequest::TerrainState { .. } => {
+            // Handled by process_terrain_queries system to avoid system parameter limit
+        }
+        QueryRequest::ListJoints => {
+            // Handled by process_joint_queries system to avoid system parameter limit
+        }
+        QueryRequest::GameComponentState { .. } => {
+            // Handled by process_game_component_queries system to avoid system parameter limit
+        }
+        QueryRequest::AnimationClipState { .. } => {
+            // Animation clip state is emitted via selection events and other apply systems
+        }
+        QueryRequest::Physics2dState { .. } => {
+            // 2D physics state handled separately
+        }
+        QueryRequest::GameCameraState { .. } => {
+            // Game camera state handled separately
+        }
+        QueryRequest::SpriteState { .. } => {
+            // Sprite state handled separately
+        }
+        QueryRequest::Camera2dState => {
+            // 2D camera state handled separately
+        }
+        QueryRequest::ProjectType => {
+            // Project type handled separately
+        }
+        QueryRequest::Skeleton2dState { .. } => {
+            // Skeleton 2D state handled separately
+        }
+        QueryRequest::SpriteSheetState { .. } => {
+            // Handled by sprite::handle_sprite_sheet_state_queries
+        }
+        QueryRequest::SpriteAnimatorState { .. } => {
+            // Handled by sprite::handle_sprite_animator_state_queries
+        }
+        QueryRequest::PlayState => {
+            // Handled by process_play_state_queries system to avoid system parameter limit
+        }
+        QueryRequest::ScriptErrors { .. } => {
+            // Handled by scripts::process_script_error_queries
+        }
+        QueryRequest::EntitiesInRadius { .. } | QueryRequest::EntitiesInBox { .. } => {
+            // Handled by process_spatial_queries system
+        }
+        QueryRequest::EntitiesByTag { tag } => {
+            let mut entity_ids: Vec<String> = query_entities.iter()
+                .filter(|row| row.8.is_some_and(|tags| tags.contains(&tag)))
+                .map(|row| row.0.0.clone())
+                .collect();
+            entity_ids.sort();
+            events::emit_event("QUERY_ENTITIES_BY_TAG", &serde_json::json!({ "tag": tag, "entityIds": entity_ids }));
+        }
+        QueryRequest::GameComponentTypes => {
+            // Static list of all available game component type names
+            let types_json = serde_json::json!({
+                "types": [
+                    "character_controller",
+                    "health",
+                    "collectible",
+                    "damage_zone",
+                    "checkpoint",
+                    "teleporter",
+                    "moving_platform",
+                    "trigger_zone",
+                    "spawner",
+                    "follower",
+                    "projectile",
+                    "win_condition",
+                    "dialogue_trigger",
+                    "path_follower",
+                    "timer"
+                ]
+            });
+            events::emit_event("QUERY_GAME_COMPONENT_TYPES", &types_json.to_string());
+        }
+        QueryRequest::MaterialPresets => {
+            let names: Vec<&str> = MaterialPreset::ALL.iter().map(|p| p.as_str()).collect();
+            events::emit_event("QUERY_MATERIAL_PRESETS", &serde_json::json!({ "presets": names }));
+        }
+        QueryRequest::Batch(_) => {
+            // Nested batches are rejected in answer_batch
+        }
+    };
+
+    for request in handled {
+        match request {
+            QueryRequest::Batch(items) => answer_batch(items, &answer),
+            request => answer(request),
+        }
+    }
+}
+
+/// Whether a query is answered by one of the dedicated systems below rather
+/// than by `process_query_requests`.
+fn handled_elsewhere(request: &crate::core::pending_commands::QueryRequest) -> bool {
+    use crate::core::pending_commands::QueryRequest;
+
+    matches!(
+        request,
+        QueryRequest::SpriteSheetState { .. }
+            | QueryRequest::SpriteAnimatorState { .. }
+            | QueryRequest::TerrainState { .. }
+            | QueryRequest::QualitySettings
+            | QueryRequest::ReverbZoneState { .. }
+            | QueryRequest::ListJoints
+            | QueryRequest::PlayState
+            | QueryRequest::ScriptErrors { .. }
+            | QueryRequest::EntitiesInRadius { .. }
+            | QueryRequest::EntitiesInBox { .. }
+    )
+}
+
+/// Answer every query in a batch and emit the responses, in request order,
+/// as one `QUERY_BATCH_RESULT` event. Each result carries the response's
+/// event type as its discriminator, or an error if the query produced none.
+fn answer_batch(
+    items: Vec<crate::core::pending_commands::QueryRequest>,
+    answer: &impl Fn(crate::core::pending_commands::QueryRequest),
+) {
+    use crate::core::pending_commands::QueryRequest;
+
+    let results: Vec<serde_json::Value> = items.into_iter().enumerate().map(|(index, item)| {
+        if matches!(item, QueryRequest::Batch(_)) {
+            return serde_json::json!({ "index": index, "error": "Nested query batches are not supported" });
+        }
+        if handled_elsewhere(&item) {
+            return serde_json::json!({ "index": index, "error": format!("{:?} cannot be batched", item) });
+        }
+        match events::capture_events(|| answer(item)).into_iter().next() {
+            Some((event_type, payload)) => serde_json::json!({ "index": index, "type": event_type, "payload": payload }),
+            None => serde_json::json!({ "index": index, "error": "No result" }),
+        }
+    }).collect();
+
+    events::emit_event("QUERY_BATCH_RESULT", &serde_json::json!({ "results": results }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending_commands::QueryRequest;
+
+    fn query_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<Selection>()
+            .init_resource::<SceneGraphCache>()
+            .init_resource::<EngineMode>()
+            .init_resource::<InputMap>()
+            .init_resource::<InputState>()
+            .init_resource::<AssetRegistry>()
+            .init_resource::<PostProcessingSettings>()
+            .init_resource::<AudioBusConfig>()
+            .init_resource::<AnimationRegistry>()
+            .add_systems(Update, process_query_requests);
+        app
+    }
+
+    #[test]
+    fn batch_answers_all_queries_in_one_event() {
+        let mut app = query_app();
+        let cube = app.world_mut().spawn((EntityId("cube-1".to_string()), Transform::from_xyz(1.0, 2.0, 3.0))).id();
+        app.world_mut().resource_mut::<Selection>().select_one(cube, "cube-1".to_string());
+        app.world_mut().resource_mut::<PendingCommands>().queue_query(QueryRequest::Batch(vec![
+            QueryRequest::Selection,
+            QueryRequest::EngineMode,
+            QueryRequest::EntityDetails { entity_id: "cube-1".to_string() },
+            QueryRequest::Batch(vec![]),
+        ]));
+
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted.len(), 1);
+        let (event_type, payload) = &emitted[0];
+        assert_eq!(event_type, "QUERY_BATCH_RESULT");
+
+        let results = payload["results"].as_array().unwrap();
+        let types: Vec<_> = results.iter().map(|r| r["type"].as_str()).collect();
+        assert_eq!(types, vec![Some("QUERY_SELECTION"), Some("ENGINE_MODE"), Some("QUERY_ENTITY_DETAILS"), None]);
+        assert_eq!(results[0]["payload"]["primaryId"], "cube-1");
+        assert_eq!(results[2]["payload"]["position"], serde_json::json!([1.0, 2.0, 3.0]));
+        assert!(results[3]["error"].as_str().unwrap().contains("Nested"));
+    }
+
+    #[test]
+    fn entities_by_tag_returns_exactly_the_tagged_entities() {
+        let mut app = query_app();
+        for i in 0..3 {
+            let tags = EntityTags(["enemy".to_string()].into());
+            app.world_mut().spawn((EntityId(format!("enemy-{i}")), Transform::default(), tags));
+        }
+        app.world_mut().spawn((EntityId("ally".to_string()), Transform::default(), EntityTags(["friendly".to_string()].into())));
+        app.world_mut().spawn((EntityId("untagged".to_string()), Transform::default()));
+        app.world_mut().resource_mut::<PendingCommands>().queue_query(QueryRequest::EntitiesByTag { tag: "enemy".to_string() });
+
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted.len(), 1);
+        let (event_type, payload) = &emitted[0];
+        assert_eq!(event_type, "QUERY_ENTITIES_BY_TAG");
+        assert_eq!(payload["entityIds"], serde_json::json!(["enemy-0", "enemy-1", "enemy-2"]));
+    }
+
+    #[test]
+    fn entity_full_reports_every_present_component() {
+        let mut app = query_app();
+        app.world_mut().spawn((
+            EntityId("crate".to_string()),
+            EntityName("Crate".to_string()),
+            EntityType::Cube,
+            Transform::from_xyz(1.0, 2.0, 3.0),
+            MaterialData::default(),
+            PhysicsData::default(),
+            ScriptData { source: "// noop".to_string(), enabled: true, template: None },
+            AudioData::default(),
+            ParticleData::default(),
+            ShaderEffectData::default(),
+            GameComponents::default(),
+            EntityTags(["loot".to_string()].into()),
+        ));
+        app.world_mut().spawn((EntityId("bare".to_string()), EntityType::Cube, Transform::default()));
+        {
+            let mut pending = app.world_mut().resource_mut::<PendingCommands>();
+            pending.queue_query(QueryRequest::EntityFull { entity_id: "crate".to_string() });
+            pending.queue_query(QueryRequest::EntityFull { entity_id: "bare".to_string() });
+        }
+
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted.len(), 2);
+        assert!(emitted.iter().all(|(event_type, _)| event_type == "QUERY_ENTITY_FULL"));
+        let keys = |payload: &serde_json::Value| {
+            let mut keys: Vec<String> = payload.as_object().unwrap().keys().cloned().collect();
+            keys.sort();
+            keys
+        };
+
+        let full = &emitted[0].1;
+        assert_eq!(keys(full), vec![
+            "audio", "entityId", "entityType", "gameComponents", "material", "name", "particle",
+            "particleEnabled", "physics", "physicsEnabled", "script", "shader", "tags", "transform",
+        ]);
+        assert_eq!(full["transform"]["position"], serde_json::json!([1.0, 2.0, 3.0]));
+        assert_eq!(full["physicsEnabled"], false);
+        assert_eq!(full["tags"], serde_json::json!(["loot"]));
+
+        let bare = &emitted[1].1;
+        assert_eq!(keys(bare), vec!["entityId", "entityType", "transform"]);
+        assert_eq!(bare["entityType"], "cube");
+    }
+}
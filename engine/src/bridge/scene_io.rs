@@ -1,7 +1,6 @@
 //! Scene import/export and asset loading systems.
 
-/// Maximum size of a scene JSON payload in bytes (50 MB).
-pub const MAX_SCENE_JSON_BYTES: usize = 50 * 1024 * 1024;
+pub use crate::core::scene_file::MAX_SCENE_JSON_BYTES;
 /// Maximum number of entities permitted in a loaded scene.
 pub const MAX_SCENE_ENTITIES: usize = 10_000;
 /// Maximum byte length of a glTF base64 payload (~50 MB decoded, 1.33× overhead).
@@ -16,28 +15,42 @@ use crate::core::{
     csg::CsgMeshData,
     custom_wgsl::CustomWgslSource,
     entity_factory,
-    entity_id::{EntityId, EntityName, EntityVisible},
+    entity_id::{EntityId, EntityName, EntityTags, EntityVisible},
     environment::EnvironmentSettings,
     game_camera::{GameCameraData, ActiveGameCamera},
-    game_components::GameComponents,
-    history::{EntitySnapshot as HistEntitySnapshot, HistoryStack, TransformSnapshot},
+    game_components::{GameComponents, LookAtConstraint},
+    history::{EntitySnapshot as HistEntitySnapshot, HistoryStack, TransformSnapshot, UndoableAction},
     input::InputMap,
-    lighting::{LightData, LightType},
+    instancing::InstanceData,
+    lighting::{LightData, LightProbeVolume, LightType},
     lod::LodData,
     material::MaterialData,
-    particles::{ParticleData, ParticleEnabled},
+    path::PathData,
+    particles::{ParticleData, ParticleEnabled, ParticleForceField},
     pending_commands::{EntityType, PendingCommands},
     physics::{JointData, PhysicsData, PhysicsEnabled},
     post_processing::PostProcessingSettings,
+    prefab::PrefabInstance,
+    tileset::TilesetData,
     procedural_mesh::ProceduralMeshData,
     scene_file::{self, SceneName},
     scripting::ScriptData,
+    sprite::SpriteData,
     selection::{Selection, SelectionChangedEvent},
     shader_effects::ShaderEffectData,
 };
 
 use super::events;
 
+/// Particle data, enabled flag, and force field for scene export.
+#[cfg(not(feature = "runtime"))]
+type ParticleExportQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static ParticleData>,
+    Option<&'static ParticleEnabled>,
+    Option<&'static ParticleForceField>,
+)>;
+
 /// System that processes scene export requests.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn apply_scene_export(
@@ -65,15 +78,16 @@ pub(super) fn apply_scene_export(
     ), Without<entity_factory::Undeletable>>,
     script_query: Query<(&EntityId, Option<&ScriptData>)>,
     audio_export_query: Query<(&EntityId, Option<&AudioData>)>,
-    particle_export_query: Query<(&EntityId, Option<&ParticleData>, Option<&ParticleEnabled>)>,
-    shader_lod_query: Query<(&EntityId, Option<&ShaderEffectData>, Option<&LodData>)>,
-    csg_procedural_joint_query: Query<(&EntityId, Option<&CsgMeshData>, Option<&ProceduralMeshData>, Option<&JointData>, Option<&GameComponents>, Option<&GameCameraData>, Option<&ActiveGameCamera>)>,
-    child_eid_query: Query<(Option<&ChildOf>, &EntityId)>,
+    particle_export_query: ParticleExportQuery,
+    shader_lod_probe_query: Query<(&EntityId, Option<&ShaderEffectData>, Option<&LodData>, Option<&LightProbeVolume>, Option<&InstanceData>)>,
+    csg_procedural_joint_query: Query<(&EntityId, Option<&CsgMeshData>, Option<&ProceduralMeshData>, Option<&JointData>, Option<&GameComponents>, Option<&GameCameraData>, Option<&ActiveGameCamera>, Option<&LookAtConstraint>, Option<&PathData>)>,
+    child_eid_query: Query<(Option<&ChildOf>, &EntityId, Option<&PrefabInstance>, Option<&TilesetData>, Option<&SpriteData>, Option<&EntityTags>)>,
 ) {
     if pending.scene_export_requests.is_empty() {
         return;
     }
-    pending.scene_export_requests.clear();
+    let (want_binary, want_json) = pending.scene_export_requests.drain(..)
+        .fold((false, false), |(bin, json), r| (bin || r.binary, json || !r.binary));
 
     // Build entity snapshots
     let mut snapshots = Vec::new();
@@ -92,11 +106,14 @@ pub(super) fn apply_scene_export(
         });
 
         // Resolve parent_id via ChildOf
-        let parent_id = child_eid_query.get(entity).ok().and_then(|(child_of_opt, _)| {
+        let parent_id = child_eid_query.get(entity).ok().and_then(|(child_of_opt, _, _, _, _, _)| {
             child_of_opt.and_then(|child_of| {
-                child_eid_query.get(child_of.parent()).ok().map(|(_, parent_eid)| parent_eid.0.clone())
+                child_eid_query.get(child_of.parent()).ok().map(|(_, parent_eid, _, _, _, _)| parent_eid.0.clone())
             })
         });
+        let (prefab_instance, tileset_data, sprite_data, tags) = child_eid_query.get(entity).ok()
+            .map(|(_, _, pi, tsd, sd, tags)| (pi.cloned(), tsd.cloned(), sd.cloned(), tags.map(EntityTags::sorted).unwrap_or_default()))
+            .unwrap_or((None, None, None, Vec::new()));
 
         // Look up script data separately
         let script_data = script_query.iter()
@@ -108,23 +125,23 @@ pub(super) fn apply_scene_export(
             .find(|(audio_eid, _)| audio_eid.0 == eid.0)
             .and_then(|(_, ad)| ad.cloned());
 
-        // Look up particle data separately
-        let (particle_data, particle_enabled) = particle_export_query.iter()
-            .find(|(peid, _, _)| peid.0 == eid.0)
-            .map(|(_, pd, pe)| (pd.cloned(), pe.is_some()))
-            .unwrap_or((None, false));
+        // Look up particle data + force field separately
+        let (particle_data, particle_enabled, particle_force_field) = particle_export_query.iter()
+            .find(|(peid, _, _, _)| peid.0 == eid.0)
+            .map(|(_, pd, pe, pff)| (pd.cloned(), pe.is_some(), pff.copied()))
+            .unwrap_or((None, false, None));
 
-        // Look up shader & LOD data
-        let (shader_effect_data, lod_data) = shader_lod_query.iter()
-            .find(|(seid, _, _)| seid.0 == eid.0)
-            .map(|(_, sed, ld)| (sed.cloned(), ld.cloned()))
-            .unwrap_or((None, None));
+        // Look up shader, LOD, light probe volume & instance data
+        let (shader_effect_data, lod_data, light_probe_volume, instance_transforms) = shader_lod_probe_query.iter()
+            .find(|(seid, _, _, _, _)| seid.0 == eid.0)
+            .map(|(_, sed, ld, lpv, inst)| (sed.cloned(), ld.cloned(), lpv.cloned(), inst.map(InstanceData::to_snapshots)))
+            .unwrap_or((None, None, None, None));
 
-        // Look up csg + procedural mesh + joint + game component + game camera data from combined query
-        let (csg_mesh_data, procedural_mesh_data, joint_data, game_components, game_camera_data, active_game_camera) = csg_procedural_joint_query.iter()
-            .find(|(ceid, _, _, _, _, _, _)| ceid.0 == eid.0)
-            .map(|(_, cmd, pmd, jd, gc, gcd, agc)| (cmd.cloned(), pmd.cloned(), jd.cloned(), gc.cloned(), gcd.cloned(), agc.is_some()))
-            .unwrap_or((None, None, None, None, None, false));
+        // Look up csg + procedural mesh + joint + game component + game camera + look-at + path data from combined query
+        let (csg_mesh_data, procedural_mesh_data, joint_data, game_components, game_camera_data, active_game_camera, look_at_constraint, path_data) = csg_procedural_joint_query.iter()
+            .find(|(ceid, _, _, _, _, _, _, _, _)| ceid.0 == eid.0)
+            .map(|(_, cmd, pmd, jd, gc, gcd, agc, lac, path)| (cmd.cloned(), pmd.cloned(), jd.cloned(), gc.cloned(), gcd.cloned(), agc.is_some(), lac.cloned(), path.cloned()))
+            .unwrap_or((None, None, None, None, None, false, None, None));
 
         let mut snap = HistEntitySnapshot::new(
             eid.0.clone(),
@@ -136,6 +153,7 @@ pub(super) fn apply_scene_export(
         snap.visible = visible.0;
         snap.material_data = mat_data.cloned();
         snap.light_data = light_data.cloned();
+        snap.light_probe_volume = light_probe_volume;
         snap.physics_data = phys_data.cloned();
         snap.physics_enabled = phys_enabled.is_some();
         snap.asset_ref = asset_ref.cloned();
@@ -143,15 +161,23 @@ pub(super) fn apply_scene_export(
         snap.audio_data = audio_data;
         snap.particle_data = particle_data;
         snap.particle_enabled = particle_enabled;
+        snap.particle_force_field = particle_force_field;
         snap.shader_effect_data = shader_effect_data;
         snap.csg_mesh_data = csg_mesh_data;
         snap.procedural_mesh_data = procedural_mesh_data;
         snap.joint_data = joint_data;
         snap.game_components = game_components;
+        snap.look_at_constraint = look_at_constraint;
+        snap.path_data = path_data;
         snap.game_camera_data = game_camera_data;
         snap.active_game_camera = active_game_camera;
 
         snap.lod_data = lod_data;
+        snap.instance_transforms = instance_transforms;
+        snap.prefab_instance = prefab_instance;
+        snap.tileset_data = tileset_data;
+        snap.sprite_data = sprite_data;
+        snap.tags = tags;
 
         snapshots.push(snap);
     }
@@ -176,17 +202,43 @@ pub(super) fn apply_scene_export(
         wgsl_source,
     );
 
-    match serde_json::to_string(&scene_file) {
-        Ok(json) => {
-            events::emit_scene_exported(&json, &scene_name.0);
-            tracing::info!("Scene exported: {} entities", scene_file.entities.len());
+    if want_json {
+        match serde_json::to_string(&scene_file) {
+            Ok(json) => {
+                events::emit_scene_exported(&json, &scene_name.0);
+                tracing::info!("Scene exported: {} entities", scene_file.entities.len());
+            }
+            Err(e) => {
+                tracing::error!("Failed to serialize scene: {}", e);
+            }
         }
-        Err(e) => {
-            tracing::error!("Failed to serialize scene: {}", e);
+    }
+
+    if want_binary {
+        use base64::Engine as _;
+        match scene_file::encode_scene_binary(&scene_file) {
+            Ok(bytes) => {
+                let data_base64 = base64::engine::general_purpose::STANDARD.encode(&bytes);
+                events::emit_scene_exported_binary(&data_base64, &scene_name.0);
+                tracing::info!("Scene exported (binary): {} entities, {} bytes", scene_file.entities.len(), bytes.len());
+            }
+            Err(e) => {
+                tracing::error!("{}", e);
+            }
         }
     }
 }
 
+/// Upgrade an older scene file before spawning it, notifying the UI if it changed.
+#[cfg(not(feature = "runtime"))]
+fn migrate_loaded_scene(scene: &mut scene_file::SceneFile) {
+    let from_version = scene.format_version;
+    let migrations = scene_file::migrate_scene_file(scene);
+    if !migrations.is_empty() {
+        events::emit_scene_migrated(from_version, scene.format_version, &migrations);
+    }
+}
+
 /// System that processes scene load requests.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn apply_scene_load(
@@ -223,7 +275,7 @@ pub(super) fn apply_scene_load(
         return;
     }
 
-    let scene_file: scene_file::SceneFile = match serde_json::from_str(&request.json) {
+    let mut scene_file: scene_file::SceneFile = match serde_json::from_str(&request.json) {
         Ok(sf) => sf,
         Err(e) => {
             tracing::error!("Failed to deserialize scene file: {}", e);
@@ -231,10 +283,11 @@ pub(super) fn apply_scene_load(
         }
     };
 
-    if scene_file.format_version > 3 {
+    if scene_file.format_version > scene_file::SCENE_FORMAT_VERSION {
         tracing::error!("Unsupported scene format version: {}", scene_file.format_version);
         return;
     }
+    migrate_loaded_scene(&mut scene_file);
 
     // Cap entity count to prevent runaway scene loading.
     if scene_file.entities.len() > MAX_SCENE_ENTITIES {
@@ -363,6 +416,230 @@ pub(super) fn apply_scene_load(
     tracing::info!("Scene loaded: '{}' with {} entities", scene_name.0, scene_file.entities.len());
 }
 
+/// System that processes scene merge requests.
+///
+/// Unlike `apply_scene_load`, existing entities, history, and scene-level
+/// settings are left untouched; only the file's entities (and any assets they
+/// reference that are not registered yet) are brought in.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_scene_merge(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut history: ResMut<HistoryStack>,
+    mut asset_registry: ResMut<AssetRegistry>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for request in pending.scene_merge_requests.drain(..) {
+        if request.json.len() > MAX_SCENE_JSON_BYTES {
+            tracing::error!(
+                "Scene merge rejected: JSON payload {} bytes exceeds 50MB limit",
+                request.json.len()
+            );
+            continue;
+        }
+
+        let mut scene_file: scene_file::SceneFile = match serde_json::from_str(&request.json) {
+            Ok(sf) => sf,
+            Err(e) => {
+                tracing::error!("Failed to deserialize scene file for merge: {}", e);
+                continue;
+            }
+        };
+
+        if scene_file.format_version > scene_file::SCENE_FORMAT_VERSION {
+            tracing::error!("Unsupported scene format version: {}", scene_file.format_version);
+            continue;
+        }
+        migrate_loaded_scene(&mut scene_file);
+
+        if scene_file.entities.len() > MAX_SCENE_ENTITIES {
+            tracing::error!(
+                "Scene merge rejected: {} entities exceeds limit of {}",
+                scene_file.entities.len(),
+                MAX_SCENE_ENTITIES
+            );
+            continue;
+        }
+
+        if scene_file.entities.is_empty() {
+            events::emit_scene_merged(&[]);
+            continue;
+        }
+
+        for (asset_id, metadata) in scene_file.assets {
+            asset_registry.assets.entry(asset_id).or_insert(metadata);
+        }
+
+        let snapshots = scene_file::remap_for_merge(
+            scene_file.entities,
+            request.offset,
+            request.prefix.as_deref(),
+        );
+
+        // Spawn all entities, then restore the (remapped) hierarchy
+        let mut id_to_entity: std::collections::HashMap<&str, Entity> = std::collections::HashMap::new();
+        for snap in &snapshots {
+            let entity = entity_factory::spawn_from_snapshot(&mut commands, &mut meshes, &mut materials, snap);
+            id_to_entity.insert(snap.entity_id.as_str(), entity);
+        }
+        for snap in &snapshots {
+            if let Some(ref parent_id) = snap.parent_id {
+                if let (Some(&child_entity), Some(&parent_entity)) =
+                    (id_to_entity.get(snap.entity_id.as_str()), id_to_entity.get(parent_id.as_str()))
+                {
+                    commands.entity(child_entity).insert(ChildOf(parent_entity));
+                }
+            }
+        }
+
+        let created_ids: Vec<String> = snapshots.iter().map(|s| s.entity_id.clone()).collect();
+        tracing::info!("Scene merged: {} entities", created_ids.len());
+
+        history.push(UndoableAction::MergeScene { created_snapshots: snapshots });
+
+        events::emit_scene_merged(&created_ids);
+    }
+}
+
+/// Live entity state compared against incoming scene patches.
+#[cfg(not(feature = "runtime"))]
+type PatchTargetQuery<'w, 's> = Query<'w, 's, (
+    Entity,
+    &'static EntityId,
+    &'static Transform,
+    &'static EntityName,
+    &'static EntityVisible,
+    Option<&'static ChildOf>,
+    Option<&'static MaterialData>,
+    Option<&'static LightData>,
+    Option<&'static PhysicsData>,
+)>;
+
+/// System that applies scene patches (see `scene_file::diff_scene`).
+///
+/// Added entities are spawned with their original IDs. Removed and modified
+/// entities are routed through the regular delete/transform/rename/visibility/
+/// reparent/material/light/physics requests, queued only for fields that
+/// actually differ, so untouched entities and components are left alone.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_scene_patch(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut history: ResMut<HistoryStack>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: PatchTargetQuery,
+) {
+    use crate::core::pending_commands::{
+        DeleteRequest, LightUpdate, MaterialUpdate, PhysicsUpdate, RenameRequest, ReparentRequest,
+        TransformUpdate, VisibilityRequest,
+    };
+
+    fn differs<T: serde::Serialize>(current: Option<&T>, target: &T) -> bool {
+        current.and_then(|c| serde_json::to_value(c).ok()) != serde_json::to_value(target).ok()
+    }
+
+    let requests: Vec<_> = pending.scene_patch_requests.drain(..).collect();
+    for request in requests {
+        let patch = request.patch;
+        if patch.added.len() + patch.modified.len() > MAX_SCENE_ENTITIES {
+            tracing::error!("Scene patch rejected: more than {} entities", MAX_SCENE_ENTITIES);
+            continue;
+        }
+
+        let mut id_to_entity: std::collections::HashMap<String, Entity> = query
+            .iter()
+            .map(|(entity, eid, ..)| (eid.0.clone(), entity))
+            .collect();
+        let entity_to_id: std::collections::HashMap<Entity, &str> = query
+            .iter()
+            .map(|(entity, eid, ..)| (entity, eid.0.as_str()))
+            .collect();
+
+        if !patch.removed.is_empty() {
+            pending.queue_delete(DeleteRequest { entity_ids: patch.removed.clone() });
+        }
+
+        // Entries are matched to live entities by ID: known IDs are updated in
+        // place, unknown ones spawned, whichever list they arrived in
+        let (existing, created): (Vec<HistEntitySnapshot>, Vec<HistEntitySnapshot>) = patch
+            .added
+            .into_iter()
+            .chain(patch.modified)
+            .partition(|snap| id_to_entity.contains_key(&snap.entity_id));
+
+        for snap in &created {
+            let entity = entity_factory::spawn_from_snapshot(&mut commands, &mut meshes, &mut materials, snap);
+            id_to_entity.insert(snap.entity_id.clone(), entity);
+        }
+        for snap in &created {
+            if let Some(parent) = snap.parent_id.as_ref().and_then(|pid| id_to_entity.get(pid)) {
+                commands.entity(id_to_entity[&snap.entity_id]).insert(ChildOf(*parent));
+            }
+        }
+
+        for snap in &existing {
+            let Ok((_, _, transform, name, visible, child_of, mat, light, physics)) =
+                query.get(id_to_entity[&snap.entity_id])
+            else {
+                continue;
+            };
+            let entity_id = snap.entity_id.clone();
+
+            let target = snap.transform.to_transform();
+            if target != *transform {
+                pending.queue_transform_update(TransformUpdate {
+                    entity_id: entity_id.clone(),
+                    position: Some(target.translation),
+                    rotation: Some(target.rotation),
+                    scale: Some(target.scale),
+                });
+            }
+            if snap.name != name.0 {
+                pending.queue_rename(RenameRequest { entity_id: entity_id.clone(), new_name: snap.name.clone() });
+            }
+            if snap.visible != visible.0 {
+                pending.queue_visibility(VisibilityRequest { entity_id: entity_id.clone(), visible: snap.visible });
+            }
+            let current_parent = child_of.and_then(|c| entity_to_id.get(&c.parent()).copied());
+            if snap.parent_id.as_deref() != current_parent {
+                pending.queue_reparent(ReparentRequest {
+                    entity_id: entity_id.clone(),
+                    new_parent_id: snap.parent_id.clone(),
+                    insert_index: None,
+                });
+            }
+            if let Some(ref data) = snap.material_data {
+                if differs(mat, data) {
+                    pending.queue_material_update(MaterialUpdate { entity_id: entity_id.clone(), material_data: data.clone() });
+                }
+            }
+            if let Some(ref data) = snap.light_data {
+                if differs(light, data) {
+                    pending.queue_light_update(LightUpdate { entity_id: entity_id.clone(), light_data: data.clone() });
+                }
+            }
+            if let Some(ref data) = snap.physics_data {
+                if differs(physics, data) {
+                    pending.queue_physics_update(PhysicsUpdate { entity_id: entity_id.clone(), physics_data: data.clone() });
+                }
+            }
+        }
+
+        tracing::info!(
+            "Scene patch applied: {} added, {} removed, {} modified",
+            created.len(),
+            patch.removed.len(),
+            existing.len()
+        );
+
+        if !created.is_empty() {
+            history.push(UndoableAction::MergeScene { created_snapshots: created });
+        }
+    }
+}
+
 /// System that processes new scene requests.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn apply_new_scene(
@@ -422,6 +699,94 @@ pub(super) fn apply_new_scene(
     tracing::info!("New scene created");
 }
 
+/// System that processes glTF export requests.
+/// Walks every mesh entity and empty, bakes transforms (local under an exported
+/// parent, world otherwise), and emits the resulting GLB as base64. Empties are
+/// written as mesh-less nodes so groups keep their hierarchy.
+/// Procedural and CSG meshes are written from their stored vertex data;
+/// skinned meshes are skipped since only static geometry is exported.
+#[cfg(not(feature = "runtime"))]
+pub(super) fn apply_gltf_export(
+    mut pending: ResMut<PendingCommands>,
+    scene_name: Res<SceneName>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_query: Query<(
+        Entity,
+        Option<&Mesh3d>,
+        Option<&EntityType>,
+        &Transform,
+        &GlobalTransform,
+        Option<&EntityName>,
+        Option<&MaterialData>,
+        Option<&ProceduralMeshData>,
+        Option<&CsgMeshData>,
+        Option<&ChildOf>,
+        Has<bevy::mesh::skinning::SkinnedMesh>,
+    ), (With<EntityId>, Without<entity_factory::Undeletable>)>,
+) {
+    use base64::Engine as _;
+    use crate::core::gltf_export::{self, ExportMesh, ExportNode};
+
+    let Some(request) = pending.gltf_export_requests.drain(..).last() else {
+        return;
+    };
+
+    let mut indices: std::collections::HashMap<Entity, usize> = std::collections::HashMap::new();
+    let mut entries = Vec::new();
+    for (entity, mesh3d, entity_type, transform, global, name, mat_data, procedural, csg, child_of, skinned) in mesh_query.iter() {
+        let name = name.map(|n| n.0.clone()).unwrap_or_else(|| format!("Entity {}", entity.index()));
+        if entity_type == Some(&EntityType::Empty) {
+            indices.insert(entity, entries.len());
+            entries.push((name, None, *transform, *global, None, child_of.map(|c| c.parent())));
+            continue;
+        }
+        let Some(mesh3d) = mesh3d else {
+            continue;
+        };
+        if skinned {
+            tracing::warn!("glTF export: skipping skinned mesh '{}' (only static geometry is exported)", name);
+            continue;
+        }
+        let mesh = if let Some(data) = procedural {
+            Some(ExportMesh::from_procedural(data))
+        } else if let Some(data) = csg {
+            Some(ExportMesh::from_csg(data))
+        } else {
+            meshes.get(&mesh3d.0).and_then(ExportMesh::from_mesh)
+        };
+        let Some(mesh) = mesh else {
+            tracing::warn!("glTF export: skipping '{}' (mesh data unavailable)", name);
+            continue;
+        };
+        indices.insert(entity, entries.len());
+        entries.push((name, Some(mesh), *transform, *global, mat_data.cloned(), child_of.map(|c| c.parent())));
+    }
+
+    let nodes: Vec<ExportNode> = entries.into_iter().map(|(name, mesh, transform, global, material, parent)| {
+        let parent = parent.and_then(|p| indices.get(&p).copied());
+        let transform = if parent.is_some() { transform } else { global.compute_transform() };
+        ExportNode {
+            name,
+            translation: transform.translation.to_array(),
+            rotation: transform.rotation.to_array(),
+            scale: transform.scale.to_array(),
+            mesh,
+            material,
+            parent,
+        }
+    }).collect();
+
+    let name = request.name.unwrap_or_else(|| scene_name.0.clone());
+    match gltf_export::build_glb(&nodes) {
+        Ok(bytes) => {
+            let data_base64 = base64::engine::general_purpose::STANDARD.encode(&bytes);
+            events::emit_gltf_exported(&name, &data_base64);
+            tracing::info!("Exported glTF '{}' with {} nodes ({} bytes)", name, nodes.len(), bytes.len());
+        }
+        Err(e) => tracing::error!("glTF export failed: {}", e),
+    }
+}
+
 /// System that processes glTF import requests.
 /// Decodes base64 glTF/GLB data, writes to the in-memory asset source,
 /// loads via AssetServer, and spawns an entity with GltfSourceHandle.
@@ -656,7 +1021,7 @@ pub(super) fn apply_texture_load(
         };
 
         // Determine sRGB based on slot (normal maps, depth maps, and roughness maps are linear)
-        let is_srgb = !matches!(request.slot.as_str(), "normal_map" | "depth_map" | "clearcoat_normal" | "clearcoat_roughness" | "metallic_roughness");
+        let is_srgb = crate::core::material::is_srgb_texture_slot(&request.slot);
 
         // Create Bevy Image from raw bytes
         let image_result = Image::from_buffer(
@@ -692,19 +1057,8 @@ pub(super) fn apply_texture_load(
         // Update the entity's MaterialData with the texture reference
         for (eid, mut mat_data) in mat_query.iter_mut() {
             if eid.0 == request.entity_id {
-                match request.slot.as_str() {
-                    "base_color" => mat_data.base_color_texture = Some(asset_id.clone()),
-                    "normal_map" => mat_data.normal_map_texture = Some(asset_id.clone()),
-                    "metallic_roughness" => mat_data.metallic_roughness_texture = Some(asset_id.clone()),
-                    "emissive" => mat_data.emissive_texture = Some(asset_id.clone()),
-                    "occlusion" => mat_data.occlusion_texture = Some(asset_id.clone()),
-                    "depth_map" => mat_data.depth_map_texture = Some(asset_id.clone()),
-                    "clearcoat" => mat_data.clearcoat_texture = Some(asset_id.clone()),
-                    "clearcoat_roughness" => mat_data.clearcoat_roughness_texture = Some(asset_id.clone()),
-                    "clearcoat_normal" => mat_data.clearcoat_normal_texture = Some(asset_id.clone()),
-                    _ => {
-                        tracing::warn!("Unknown texture slot: {}", request.slot);
-                    }
+                if !crate::core::material::set_texture_slot(&mut mat_data, &request.slot, Some(asset_id.clone())) {
+                    tracing::warn!("Unknown texture slot: {}", request.slot);
                 }
                 events::emit_material_changed(&request.entity_id, &mat_data);
                 break;
@@ -725,19 +1079,8 @@ pub(super) fn apply_remove_texture(
     for request in pending.remove_texture_requests.drain(..) {
         for (eid, mut mat_data) in mat_query.iter_mut() {
             if eid.0 == request.entity_id {
-                match request.slot.as_str() {
-                    "base_color" => mat_data.base_color_texture = None,
-                    "normal_map" => mat_data.normal_map_texture = None,
-                    "metallic_roughness" => mat_data.metallic_roughness_texture = None,
-                    "emissive" => mat_data.emissive_texture = None,
-                    "occlusion" => mat_data.occlusion_texture = None,
-                    "depth_map" => mat_data.depth_map_texture = None,
-                    "clearcoat" => mat_data.clearcoat_texture = None,
-                    "clearcoat_roughness" => mat_data.clearcoat_roughness_texture = None,
-                    "clearcoat_normal" => mat_data.clearcoat_normal_texture = None,
-                    _ => {
-                        tracing::warn!("Unknown texture slot: {}", request.slot);
-                    }
+                if !crate::core::material::set_texture_slot(&mut mat_data, &request.slot, None) {
+                    tracing::warn!("Unknown texture slot: {}", request.slot);
                 }
                 events::emit_material_changed(&request.entity_id, &mat_data);
                 break;
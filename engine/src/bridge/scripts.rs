@@ -2,14 +2,18 @@
 //! Actual script execution happens in the JS Web Worker sandbox, not in Rust.
 
 use bevy::prelude::*;
+use bevy_rapier3d::prelude::KinematicCharacterControllerOutput;
 use std::collections::HashMap;
 use crate::core::{
+    camera_2d::Managed2dCamera,
     entity_id::{EntityId, EntityName},
     history::{HistoryStack, UndoableAction},
-    input::{InputMap, InputState},
-    pending_commands::{EntityType, PendingCommands},
-    scripting::ScriptData,
+    input::{InputContextStack, InputMap, InputState},
+    pending_commands::{EntityType, PendingCommands, QueryRequest},
+    scripting::{ScriptData, ScriptError, ScriptErrorLog},
     engine_mode::EngineMode,
+    game_components::{GameComponentData, GameComponents},
+    sprite::SpriteAnimation,
 };
 use crate::bridge::{events, Selection, SelectionChangedEvent};
 
@@ -23,6 +27,12 @@ pub(crate) struct CachedEntityState {
     pub name: String,
     pub entity_type: String,
     pub collider_radius: f32,
+    /// Character controller grounded state (`None` for non-character entities).
+    pub grounded: Option<bool>,
+    /// Current flipbook atlas index (`None` for entities without a sprite animation).
+    pub sprite_frame: Option<usize>,
+    /// Current HP (`None` for entities without a health component).
+    pub health: Option<f32>,
 }
 
 /// Resource that holds the previous frame's play-tick state.
@@ -69,6 +79,33 @@ pub(super) fn apply_input_binding_updates(
     }
 }
 
+/// System that applies pending input context pushes and pops (works in all modes).
+pub(super) fn apply_input_context_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut contexts: ResMut<InputContextStack>,
+) {
+    use crate::core::pending_commands::InputContextRequest;
+
+    for request in pending.input_context_requests.drain(..) {
+        match request {
+            InputContextRequest::Push { name, context } => {
+                if let Some(context) = context {
+                    contexts.define_context(&name, context);
+                }
+                contexts.push_context(&name);
+                tracing::info!("Pushed input context: {}", name);
+            }
+            InputContextRequest::Pop => {
+                if contexts.pop_context().is_none() {
+                    tracing::warn!("pop_input_context with an empty context stack");
+                    continue;
+                }
+            }
+        }
+        events::emit_input_context_changed(&contexts);
+    }
+}
+
 /// System that emits entity states every frame during Play mode for the script runtime.
 ///
 /// Uses delta compression: only entities whose state changed since the previous frame
@@ -76,8 +113,9 @@ pub(super) fn apply_input_binding_updates(
 /// reduces JS deserialization work proportionally to scene size.
 pub(super) fn emit_play_tick_system(
     mode: Res<EngineMode>,
-    query: Query<(&EntityId, &Transform, &EntityName, Option<&EntityType>)>,
+    query: Query<(&EntityId, &Transform, &EntityName, Option<&EntityType>, Option<&KinematicCharacterControllerOutput>, Option<&SpriteAnimation>, Option<&GameComponents>)>,
     input_state: Res<InputState>,
+    camera_2d: Query<&Transform, With<Managed2dCamera>>,
     mut cache: ResMut<PlayTickCache>,
 ) {
     if !matches!(*mode, EngineMode::Play) {
@@ -90,7 +128,7 @@ pub(super) fn emit_play_tick_system(
 
     // Build current frame state
     let current_frame: HashMap<String, CachedEntityState> = query.iter()
-        .map(|(eid, transform, ename, etype)| {
+        .map(|(eid, transform, ename, etype, controller_output, sprite_animation, game_components)| {
             let pos = transform.translation;
             let rot = transform.rotation.to_euler(bevy::math::EulerRot::XYZ);
             let scale = transform.scale;
@@ -103,12 +141,18 @@ pub(super) fn emit_play_tick_system(
                 name: ename.0.clone(),
                 entity_type: type_str,
                 collider_radius: collider_r,
+                grounded: controller_output.map(|o| o.grounded),
+                sprite_frame: sprite_animation.and_then(SpriteAnimation::current_frame),
+                health: game_components.and_then(|gc| match gc.get("health") {
+                    Some(GameComponentData::Health(health)) => Some(health.current_hp),
+                    _ => None,
+                }),
             })
         })
         .collect();
 
     // Compute delta: entities that are new or changed
-    let mut changed: Vec<(String, [f32; 3], [f32; 3], [f32; 3], String, String, f32)> = Vec::new();
+    let mut changed: Vec<events::PlayTickEntityDelta> = Vec::new();
     for (id, state) in &current_frame {
         let is_changed = cache.states.get(id.as_str()).map_or(true, |prev| prev != state);
         if is_changed {
@@ -120,6 +164,9 @@ pub(super) fn emit_play_tick_system(
                 state.name.clone(),
                 state.entity_type.clone(),
                 state.collider_radius,
+                state.grounded,
+                state.sprite_frame,
+                state.health,
             ));
         }
     }
@@ -134,7 +181,8 @@ pub(super) fn emit_play_tick_system(
     cache.states = current_frame;
 
     // Always emit (script runtime needs input state every frame even if no entity changed)
-    events::emit_play_tick_delta(&changed, &removed, &input_state);
+    let camera_2d_position = camera_2d.single().ok().map(|t| [t.translation.x, t.translation.y]);
+    events::emit_play_tick_delta(&changed, &removed, &input_state, camera_2d_position);
 }
 
 /// System that applies pending script updates (always-active).
@@ -143,10 +191,13 @@ pub(super) fn apply_script_updates(
     mut commands: Commands,
     query: Query<(Entity, &EntityId, Option<&ScriptData>)>,
     mut history: ResMut<HistoryStack>,
+    mut error_log: ResMut<ScriptErrorLog>,
 ) {
     for update in pending.script_updates.drain(..) {
         for (entity, entity_id, current_script) in query.iter() {
             if entity_id.0 == update.entity_id {
+                // Errors belong to the old source; the new one starts clean.
+                error_log.clear(&update.entity_id);
                 let old_script = current_script.cloned();
                 let new_script = ScriptData {
                     source: update.source.clone(),
@@ -178,10 +229,12 @@ pub(super) fn apply_script_removals(
     mut commands: Commands,
     query: Query<(Entity, &EntityId, Option<&ScriptData>)>,
     mut history: ResMut<HistoryStack>,
+    mut error_log: ResMut<ScriptErrorLog>,
 ) {
     for removal in pending.script_removals.drain(..) {
         for (entity, entity_id, current_script) in query.iter() {
             if entity_id.0 == removal.entity_id {
+                error_log.clear(&removal.entity_id);
                 let old_script = current_script.cloned();
 
                 // Remove script component
@@ -202,6 +255,49 @@ pub(super) fn apply_script_removals(
     }
 }
 
+/// System that logs script errors reported by the JS runtime (always-active).
+pub(super) fn apply_script_error_reports(
+    mut pending: ResMut<PendingCommands>,
+    mut error_log: ResMut<ScriptErrorLog>,
+) {
+    for report in pending.script_error_reports.drain(..) {
+        let error = ScriptError { message: report.message, line: report.line };
+        events::emit_script_error(&report.entity_id, &error);
+        error_log.record(&report.entity_id, error);
+    }
+}
+
+/// System that answers get_script_errors queries.
+pub(super) fn process_script_error_queries(
+    mut pending: ResMut<PendingCommands>,
+    error_log: Res<ScriptErrorLog>,
+) {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ScriptErrorsResponse<'a> {
+        errors: HashMap<&'a str, &'a [ScriptError]>,
+    }
+
+    pending.query_requests.retain(|request| {
+        let QueryRequest::ScriptErrors { entity_id } = request else {
+            return true;
+        };
+        let errors = match entity_id {
+            Some(id) => HashMap::from([(id.as_str(), error_log.errors_for(id))]),
+            None => error_log.iter().collect(),
+        };
+        events::emit_event("QUERY_SCRIPT_ERRORS", &ScriptErrorsResponse { errors });
+        false
+    });
+}
+
+/// System that forwards script-sent events to JS (always-active).
+pub(super) fn emit_script_events(mut pending: ResMut<PendingCommands>) {
+    for request in pending.script_events.drain(..) {
+        events::emit_script_event(&request.entity_id, &request.event_name, &request.payload);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +310,9 @@ mod tests {
             name: "Cube".to_string(),
             entity_type: "cube".to_string(),
             collider_radius: 0.5,
+            grounded: None,
+            sprite_frame: None,
+            health: None,
         }
     }
 
@@ -292,6 +391,93 @@ mod tests {
             .collect();
         assert!(changed.is_empty(), "unchanged entity should not appear in delta");
     }
+
+    #[test]
+    fn patrol_template_inserts_its_source() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<ScriptErrorLog>()
+            .add_systems(Update, apply_script_updates);
+        let pending = app.world_mut().resource_mut::<PendingCommands>().into_inner() as *mut PendingCommands;
+        crate::core::pending_commands::register_pending_commands(pending);
+        let guard = app.world_mut().spawn(EntityId("guard".to_string())).id();
+
+        crate::core::commands::dispatch("apply_script_template", serde_json::json!({
+            "entityId": "guard",
+            "template": "patrol",
+        })).expect("pending commands are registered");
+        events::capture_events(|| app.update());
+
+        let script = app.world().get::<ScriptData>(guard).expect("script inserted");
+        let template = crate::core::scripting::script_template("patrol").unwrap();
+        assert!(!script.source.is_empty());
+        assert_eq!(script.source, template.source);
+        assert_eq!(script.template.as_deref(), Some("patrol"));
+    }
+
+    #[test]
+    fn script_errors_are_stored_until_the_script_is_updated() {
+        use crate::core::commands::dispatch;
+        use serde_json::json;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<ScriptErrorLog>()
+            .add_systems(Update, (apply_script_error_reports, process_script_error_queries, apply_script_updates).chain());
+        let pending = app.world_mut().resource_mut::<PendingCommands>().into_inner() as *mut PendingCommands;
+        crate::core::pending_commands::register_pending_commands(pending);
+        app.world_mut().spawn(EntityId("guard".to_string()));
+
+        dispatch("report_script_error", json!({"entityId": "guard", "message": "x is not defined", "line": 4})).unwrap();
+        dispatch("report_script_error", json!({"entityId": "guard", "message": "boom"})).unwrap();
+        dispatch("get_script_errors", json!({"entityId": "guard"})).unwrap();
+        let emitted = events::capture_events(|| app.update());
+
+        let types: Vec<_> = emitted.iter().map(|(event_type, _)| event_type.as_str()).collect();
+        assert_eq!(types, ["SCRIPT_ERROR", "SCRIPT_ERROR", "QUERY_SCRIPT_ERRORS"]);
+        assert_eq!(emitted[0].1, json!({"entityId": "guard", "message": "x is not defined", "line": 4}));
+        assert_eq!(emitted[2].1["errors"]["guard"], json!([
+            {"message": "x is not defined", "line": 4},
+            {"message": "boom"},
+        ]));
+
+        dispatch("set_script", json!({"entityId": "guard", "source": "function onUpdate(dt) {}", "enabled": true})).unwrap();
+        events::capture_events(|| app.update());
+        assert!(app.world().resource::<ScriptErrorLog>().errors_for("guard").is_empty());
+
+        dispatch("get_script_errors", json!({})).unwrap();
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted[0].1, json!({"errors": {}}));
+    }
+
+    #[test]
+    fn emit_script_event_forwards_payload_untouched() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .add_systems(Update, emit_script_events);
+        let pending = app.world_mut().resource_mut::<PendingCommands>().into_inner() as *mut PendingCommands;
+        crate::core::pending_commands::register_pending_commands(pending);
+
+        let payload = serde_json::json!({"score": 1200, "combo": [3, 5], "bonus": {"perfect": true, "note": null}});
+        crate::core::commands::dispatch("emit_script_event", serde_json::json!({
+            "entityId": "player",
+            "eventName": "score_changed",
+            "payload": payload,
+        })).expect("pending commands are registered");
+
+        let emitted = events::capture_events(|| app.update());
+        assert_eq!(emitted.len(), 1);
+        let (event_type, event) = &emitted[0];
+        assert_eq!(event_type, "SCRIPT_EVENT");
+        assert_eq!(event["entityId"], "player");
+        assert_eq!(event["eventName"], "score_changed");
+        assert_eq!(event["payload"], payload);
+    }
 }
 
 /// Emit script changed events on selection changes and script data changes.
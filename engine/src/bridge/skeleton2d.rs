@@ -8,10 +8,11 @@ use crate::core::{
     entity_factory,
     pending_commands::{PendingCommands, QueryRequest},
     skeleton2d::{
-        SkeletonData2d, SkeletonEnabled2d, Bone2dDef, IkConstraint2d, AttachmentData,
+        SkeletonData2d, SkeletonEnabled2d, Bone2dDef, AttachmentData,
         SkinnedMesh2d, BoneWorldTransforms2d, VertexWeights, SkinnedMeshInitialized,
+        compute_bone_world_transforms,
     },
-    skeletal_animation2d::{SkeletalAnimation2d, SkeletalAnimPlayer2d, EasingType2d, BoneKeyframe},
+    skeletal_animation2d::{SkeletalAnimation2d, SkeletalAnimPlayer2d},
     history::UndoableAction,
 };
 use crate::bridge::{events, Selection, SelectionChangedEvent};
@@ -137,6 +138,9 @@ pub(super) fn apply_bone2d_updates(
                 if let Some(col) = request.color {
                     bone.color = col;
                 }
+                if let Some(limits) = request.angle_limits {
+                    bone.angle_limits = limits;
+                }
             }
 
             history.push(UndoableAction::SkeletonChange {
@@ -474,132 +478,8 @@ pub(super) fn advance_skeleton_animation(
         let t = player.time;
 
         // Interpolate each bone track
-        for bone in skeleton.bones.iter_mut() {
-            if let Some(keyframes) = animation.tracks.get(&bone.name) {
-                if keyframes.is_empty() {
-                    continue;
-                }
-                interpolate_bone(bone, keyframes, t);
-            }
-        }
-    }
-}
-
-/// Interpolate bone properties from keyframes at the given time.
-fn interpolate_bone(bone: &mut Bone2dDef, keyframes: &[BoneKeyframe], t: f32) {
-    // Find the two keyframes surrounding `t`
-    let mut prev_idx = 0;
-    for (i, kf) in keyframes.iter().enumerate() {
-        if kf.time <= t {
-            prev_idx = i;
-        }
-    }
-
-    let prev = &keyframes[prev_idx];
-    let next_idx = if prev_idx + 1 < keyframes.len() {
-        prev_idx + 1
-    } else if keyframes.len() > 1 {
-        0 // wrap for looping
-    } else {
-        prev_idx // single keyframe
-    };
-    let next = &keyframes[next_idx];
-
-    let alpha = if (next.time - prev.time).abs() < 1e-6 {
-        0.0
-    } else {
-        ((t - prev.time) / (next.time - prev.time)).clamp(0.0, 1.0)
-    };
-
-    let eased = apply_easing(alpha, prev.easing);
-
-    if let (Some(p0), Some(p1)) = (prev.position, next.position) {
-        // Preserve Z from current bone — only interpolate XY from 2D keyframes.
-        bone.local_position = [
-            p0[0] + (p1[0] - p0[0]) * eased,
-            p0[1] + (p1[1] - p0[1]) * eased,
-            bone.local_position[2],
-        ];
-    } else if let Some(p) = prev.position {
-        bone.local_position = [p[0], p[1], bone.local_position[2]];
-    }
-
-    if let (Some(r0), Some(r1)) = (prev.rotation, next.rotation) {
-        bone.local_rotation = r0 + (r1 - r0) * eased;
-    } else if let Some(r) = prev.rotation {
-        bone.local_rotation = r;
-    }
-
-    if let (Some(s0), Some(s1)) = (prev.scale, next.scale) {
-        bone.local_scale = [
-            s0[0] + (s1[0] - s0[0]) * eased,
-            s0[1] + (s1[1] - s0[1]) * eased,
-        ];
-    } else if let Some(s) = prev.scale {
-        bone.local_scale = s;
-    }
-}
-
-fn apply_easing(t: f32, easing: EasingType2d) -> f32 {
-    match easing {
-        EasingType2d::Linear => t,
-        EasingType2d::EaseIn => t * t,
-        EasingType2d::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
-        EasingType2d::EaseInOut => {
-            if t < 0.5 {
-                2.0 * t * t
-            } else {
-                1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
-            }
-        }
-        EasingType2d::Step => if t >= 1.0 { 1.0 } else { 0.0 },
-    }
-}
-
-/// Compute world-space bone transforms from the skeleton hierarchy.
-fn compute_bone_world_transforms(bones: &[Bone2dDef]) -> HashMap<String, (Vec2, f32, Vec2)> {
-    let mut world_transforms: HashMap<String, (Vec2, f32, Vec2)> = HashMap::new();
-
-    // Build parent index for efficient lookup
-    let bone_index: HashMap<&str, usize> = bones
-        .iter()
-        .enumerate()
-        .map(|(i, b)| (b.name.as_str(), i))
-        .collect();
-
-    // Process bones in order (parents before children assumed by convention)
-    for bone in bones {
-        let (parent_pos, parent_rot) = if let Some(ref parent_name) = bone.parent_bone {
-            if let Some(&(pos, rot, _scale)) = world_transforms.get(parent_name.as_str()) {
-                // Offset along parent bone's direction by parent length
-                let parent_bone = &bones[bone_index[parent_name.as_str()]];
-                let parent_rad = rot.to_radians();
-                let end_pos = pos + Vec2::new(
-                    parent_bone.length * parent_rad.cos(),
-                    parent_bone.length * parent_rad.sin(),
-                );
-                (end_pos, rot)
-            } else {
-                (Vec2::ZERO, 0.0)
-            }
-        } else {
-            (Vec2::ZERO, 0.0)
-        };
-
-        let world_rot = parent_rot + bone.local_rotation;
-        let rot_rad = parent_rot.to_radians();
-        let local_offset = Vec2::new(bone.local_position[0], bone.local_position[1]);
-        let rotated_offset = Vec2::new(
-            local_offset.x * rot_rad.cos() - local_offset.y * rot_rad.sin(),
-            local_offset.x * rot_rad.sin() + local_offset.y * rot_rad.cos(),
-        );
-        let world_pos = parent_pos + rotated_offset;
-        let world_scale = Vec2::new(bone.local_scale[0], bone.local_scale[1]);
-
-        world_transforms.insert(bone.name.clone(), (world_pos, world_rot, world_scale));
+        animation.sample(&mut skeleton.bones, t);
     }
-
-    world_transforms
 }
 
 // ========== Pure Functions (Bind-Pose Init + Corrected LBS) ==========
@@ -968,77 +848,6 @@ pub(super) fn render_skeleton_bones(
     }
 }
 
-/// Solve 2-bone IK constraints using analytical method.
-pub(super) fn solve_ik_constraints_2d(
-    mut skeleton_query: Query<(&EntityId, &mut SkeletonData2d)>,
-    target_query: Query<(&EntityId, &Transform)>,
-) {
-    for (_, mut skeleton) in skeleton_query.iter_mut() {
-        let constraints: Vec<IkConstraint2d> = skeleton.ik_constraints.clone();
-        for constraint in &constraints {
-            if constraint.bone_chain.len() < 2 || constraint.mix <= 0.0 {
-                continue;
-            }
-
-            // Find target position
-            let target_pos = target_query
-                .iter()
-                .find(|(eid, _)| eid.0 == constraint.target_entity_id)
-                .map(|(_, t)| t.translation.truncate());
-
-            let Some(target) = target_pos else {
-                continue;
-            };
-
-            let bone1_name = &constraint.bone_chain[0];
-            let bone2_name = &constraint.bone_chain[1];
-
-            // Get bone lengths
-            let bone1_len = skeleton.bones.iter().find(|b| &b.name == bone1_name).map(|b| b.length).unwrap_or(1.0);
-            let bone2_len = skeleton.bones.iter().find(|b| &b.name == bone2_name).map(|b| b.length).unwrap_or(1.0);
-
-            // Get bone1 world position (from its parent chain)
-            let world_transforms = compute_bone_world_transforms(&skeleton.bones);
-            let bone1_pos = world_transforms.get(bone1_name.as_str()).map(|t| t.0).unwrap_or(Vec2::ZERO);
-
-            // 2-bone analytical IK
-            let dx = target.x - bone1_pos.x;
-            let dy = target.y - bone1_pos.y;
-            let dist = (dx * dx + dy * dy).sqrt().min(bone1_len + bone2_len - 0.001);
-
-            if dist < 0.001 {
-                continue;
-            }
-
-            // Law of cosines for bone1 angle
-            let cos_angle1 = ((bone1_len * bone1_len + dist * dist - bone2_len * bone2_len)
-                / (2.0 * bone1_len * dist))
-                .clamp(-1.0, 1.0);
-            let angle_to_target = dy.atan2(dx);
-            let ik_angle1 = angle_to_target + constraint.bend_direction * cos_angle1.acos();
-
-            // Law of cosines for bone2 angle relative to bone1
-            let cos_angle2 = ((bone1_len * bone1_len + bone2_len * bone2_len - dist * dist)
-                / (2.0 * bone1_len * bone2_len))
-                .clamp(-1.0, 1.0);
-            let ik_angle2 = std::f32::consts::PI - constraint.bend_direction * cos_angle2.acos();
-
-            // Apply with mix factor
-            let mix = constraint.mix;
-            if let Some(bone1) = skeleton.bones.iter_mut().find(|b| &b.name == bone1_name) {
-                let fk_rot = bone1.local_rotation.to_radians();
-                let blended = fk_rot * (1.0 - mix) + ik_angle1 * mix;
-                bone1.local_rotation = blended.to_degrees();
-            }
-            if let Some(bone2) = skeleton.bones.iter_mut().find(|b| &b.name == bone2_name) {
-                let fk_rot = bone2.local_rotation.to_radians();
-                let blended = fk_rot * (1.0 - mix) + ik_angle2 * mix;
-                bone2.local_rotation = blended.to_degrees();
-            }
-        }
-    }
-}
-
 /// Emit skeleton2d changed events on selection changes and skeleton2d data changes.
 #[cfg(not(feature = "runtime"))]
 pub(super) fn emit_skeleton2d_on_selection(
@@ -1080,6 +889,7 @@ mod tests {
             local_scale: [1.0, 1.0],
             length,
             color: [1.0, 1.0, 1.0, 1.0],
+            angle_limits: None,
         }
     }
 
@@ -165,6 +165,12 @@ pub(super) fn apply_sprite_data_updates(
         if let Some(sorting_order) = update.sorting_order {
             sprite_data.sorting_order = sorting_order;
         }
+        if let Some(nine_slice) = update.nine_slice {
+            sprite_data.nine_slice = nine_slice;
+        }
+        if let Some(parallax_factor) = update.parallax_factor {
+            sprite_data.parallax_factor = parallax_factor;
+        }
         if let Some(anchor_str) = update.anchor {
             sprite_data.anchor = match anchor_str.as_str() {
                 "TopLeft" => SpriteAnchor::TopLeft,
@@ -222,9 +228,11 @@ pub(super) fn apply_sprite_removals(
 /// Runs every frame on entities where SpriteData has changed.
 /// Also updates Transform.translation.z based on sorting layer/order.
 /// Preserves the existing texture_atlas if present (set by sprite sheet systems).
+/// 9-slice borders are clamped to the texture once its size is known.
 pub(super) fn sync_sprite_rendering(
     mut query: Query<(Entity, &SpriteData, Option<&mut Sprite>), Changed<SpriteData>>,
     texture_handles: Res<TextureHandleMap>,
+    images: Res<Assets<Image>>,
     mut commands: Commands,
     mut transform_query: Query<&mut Transform>,
 ) {
@@ -249,6 +257,18 @@ pub(super) fn sync_sprite_rendering(
             None
         };
 
+        // 9-slice: Bevy splits the sprite into 9 quads; corners keep their size
+        let image_mode = match sprite_data.nine_slice {
+            Some(borders) => {
+                let borders = match images.get(&image_handle) {
+                    Some(image) => borders.clamped(image.size().as_vec2()),
+                    None => borders,
+                };
+                SpriteImageMode::Sliced(borders.to_slicer())
+            }
+            None => SpriteImageMode::Auto,
+        };
+
         // Build and insert/update the Bevy Sprite component
         // In Bevy 0.17+, Anchor is a separate required component on Sprite.
         let bevy_sprite = Sprite {
@@ -263,6 +283,7 @@ pub(super) fn sync_sprite_rendering(
             flip_y: sprite_data.flip_y,
             custom_size: effective_custom_size,
             texture_atlas: existing_atlas,
+            image_mode,
             ..default()
         };
 
@@ -376,7 +397,7 @@ pub(super) fn apply_project_type_changes(
 // ========== Camera 2D Systems ==========
 
 /// System that processes pending Camera2dData updates from the bridge.
-/// Applies partial updates (zoom, pixel_perfect, bounds) to the Camera2dData component.
+/// Applies partial updates (zoom, pixel_perfect, bounds, follow target) to the Camera2dData component.
 pub(super) fn apply_camera_2d_updates(
     mut pending: ResMut<PendingCommands>,
     mut camera_query: Query<&mut Camera2dData, With<Managed2dCamera>>,
@@ -400,6 +421,9 @@ pub(super) fn apply_camera_2d_updates(
                 max_y: b.max_y,
             });
         }
+        if let Some(follow_target_id) = update.follow_target_id {
+            camera_data.follow_target_id = follow_target_id;
+        }
     }
 }
 
@@ -1011,89 +1035,6 @@ pub(super) fn sync_tilemap_rendering(
     }
 }
 
-// ========== Tile Paint / Erase / Fill Systems ==========
-
-/// System that applies pending paint_tile requests: sets a single tile in a TilemapData layer.
-pub(super) fn apply_paint_tile_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut TilemapData)>,
-) {
-    for request in pending.paint_tile_requests.drain(..) {
-        let found = query.iter_mut().find(|(eid, _)| eid.0 == request.entity_id);
-        let Some((_, mut tilemap_data)) = found else { continue };
-
-        let map_w = tilemap_data.map_size[0] as usize;
-        let map_h = tilemap_data.map_size[1] as usize;
-
-        if request.layer >= tilemap_data.layers.len() {
-            continue;
-        }
-        if request.x >= map_w || request.y >= map_h {
-            continue;
-        }
-
-        let tile_index = request.y * map_w + request.x;
-        if tile_index < tilemap_data.layers[request.layer].tiles.len() {
-            tilemap_data.layers[request.layer].tiles[tile_index] = Some(request.tile_index);
-        }
-    }
-}
-
-/// System that applies pending erase_tile requests: clears a single tile in a TilemapData layer.
-pub(super) fn apply_erase_tile_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut TilemapData)>,
-) {
-    for request in pending.erase_tile_requests.drain(..) {
-        let found = query.iter_mut().find(|(eid, _)| eid.0 == request.entity_id);
-        let Some((_, mut tilemap_data)) = found else { continue };
-
-        let map_w = tilemap_data.map_size[0] as usize;
-        let map_h = tilemap_data.map_size[1] as usize;
-
-        if request.layer >= tilemap_data.layers.len() {
-            continue;
-        }
-        if request.x >= map_w || request.y >= map_h {
-            continue;
-        }
-
-        let tile_index = request.y * map_w + request.x;
-        if tile_index < tilemap_data.layers[request.layer].tiles.len() {
-            tilemap_data.layers[request.layer].tiles[tile_index] = None;
-        }
-    }
-}
-
-/// System that applies pending fill_tiles requests: batch-sets tiles in a TilemapData layer.
-pub(super) fn apply_fill_tiles_requests(
-    mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut TilemapData)>,
-) {
-    for request in pending.fill_tiles_requests.drain(..) {
-        let found = query.iter_mut().find(|(eid, _)| eid.0 == request.entity_id);
-        let Some((_, mut tilemap_data)) = found else { continue };
-
-        let map_w = tilemap_data.map_size[0] as usize;
-        let map_h = tilemap_data.map_size[1] as usize;
-        let layer_count = tilemap_data.layers.len();
-
-        if request.layer >= layer_count {
-            continue;
-        }
-
-        for placement in &request.tiles {
-            if placement.x >= map_w || placement.y >= map_h {
-                continue;
-            }
-            let tile_index = placement.y * map_w + placement.x;
-            if tile_index < tilemap_data.layers[request.layer].tiles.len() {
-                tilemap_data.layers[request.layer].tiles[tile_index] = Some(placement.tile_index);
-            }
-        }
-    }
-}
-
 // ========== Animated Tiles System ==========
 
 /// System that advances tile animation frames.
@@ -0,0 +1,95 @@
+//! Align and distribute: lay out several entities along one axis.
+//!
+//! Both work on entity positions (local translations, so children of
+//! different parents line up in their parents' spaces). Aligning moves every
+//! position on the axis to the group's minimum, center or maximum.
+//! Distributing keeps the order along the axis and spaces the positions
+//! evenly, either between the two outermost entities or at a fixed spacing
+//! from the first one.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Axis to align or distribute along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LayoutAxis {
+    X,
+    Y,
+    Z,
+}
+
+impl LayoutAxis {
+    pub fn get(self, v: Vec3) -> f32 {
+        match self {
+            LayoutAxis::X => v.x,
+            LayoutAxis::Y => v.y,
+            LayoutAxis::Z => v.z,
+        }
+    }
+
+    pub fn set(self, v: &mut Vec3, value: f32) {
+        match self {
+            LayoutAxis::X => v.x = value,
+            LayoutAxis::Y => v.y = value,
+            LayoutAxis::Z => v.z = value,
+        }
+    }
+}
+
+/// Which edge of the group to align to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlignMode {
+    Min,
+    Center,
+    Max,
+}
+
+/// Shared coordinate for aligning `values`, or `None` if there are none.
+/// `Center` is the midpoint of the extremes, not the mean.
+pub fn align_target(values: &[f32], mode: AlignMode) -> Option<f32> {
+    let min = values.iter().copied().reduce(f32::min)?;
+    let max = values.iter().copied().reduce(f32::max)?;
+    Some(match mode {
+        AlignMode::Min => min,
+        AlignMode::Center => (min + max) * 0.5,
+        AlignMode::Max => max,
+    })
+}
+
+/// Evenly spaced coordinates for `values`, returned in input order. The
+/// order along the axis is kept; without `spacing` the outermost values stay
+/// put, with it the lowest value does.
+pub fn distribute_values(values: &[f32], spacing: Option<f32>) -> Vec<f32> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    let (Some(&first), Some(&last)) = (order.first(), order.last()) else {
+        return Vec::new();
+    };
+    let start = values[first];
+    let step = match spacing {
+        Some(spacing) => spacing,
+        None if order.len() > 1 => (values[last] - start) / (order.len() - 1) as f32,
+        None => 0.0,
+    };
+
+    let mut result = values.to_vec();
+    for (rank, &index) in order.iter().enumerate() {
+        result[index] = start + step * rank as f32;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distribute_keeps_order_and_ends() {
+        assert_eq!(distribute_values(&[10.0, 0.0, 1.0, 4.0], None), vec![10.0, 0.0, 10.0 / 3.0, 20.0 / 3.0]);
+        assert_eq!(distribute_values(&[3.0, -1.0, 0.0], Some(2.0)), vec![3.0, -1.0, 1.0]);
+        assert!(distribute_values(&[], Some(1.0)).is_empty());
+    }
+}
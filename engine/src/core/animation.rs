@@ -1,13 +1,24 @@
 //! Animation management for glTF skeletal animation playback.
 //!
 //! Pure Rust, no browser dependencies. Provides the AnimationRegistry
-//! resource that maps entities to their available animation clips.
+//! resource that maps entities to their available animation clips, event
+//! markers that fire during playback, 1D blend spaces, and root-motion
+//! extraction for clips played with `root_motion`.
 
 use bevy::prelude::*;
-use bevy::animation::graph::AnimationNodeIndex;
+use bevy::animation::{
+    animated_field,
+    animation_curves::{AnimatableProperty, AnimatedField, EvaluatorId},
+    graph::{AnimationGraphHandle, AnimationNodeIndex, AnimationNodeType},
+    AnimationClip, AnimationPlayer, AnimationTargetId,
+};
+use bevy::app::AnimationSystems;
+use bevy::transform::TransformSystems;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::core::entity_id::EntityId;
+
 /// Information about a single animation clip available on an entity.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -46,6 +57,14 @@ pub struct AnimationPlaybackState {
     pub is_finished: bool,
 }
 
+/// A named marker on a clip's timeline (e.g. "footstep", "hit").
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationEvent {
+    pub time_secs: f32,
+    pub name: String,
+}
+
 /// Per-entity record in the AnimationRegistry.
 /// Stores the mapping from clip names to AnimationGraph node indices.
 #[derive(Debug, Clone)]
@@ -58,6 +77,17 @@ pub struct EntityAnimationData {
     pub player_entity: Entity,
     /// Handle to the AnimationGraph asset for this entity
     pub graph_handle: Handle<AnimationGraph>,
+    /// Map from clip name -> event markers, sorted by time
+    pub events: HashMap<String, Vec<AnimationEvent>>,
+}
+
+impl EntityAnimationData {
+    /// Register an event marker on a clip, keeping the list sorted by time.
+    pub fn add_event(&mut self, clip_name: &str, event: AnimationEvent) {
+        let events = self.events.entry(clip_name.to_string()).or_default();
+        let at = events.partition_point(|e| e.time_secs <= event.time_secs);
+        events.insert(at, event);
+    }
 }
 
 /// Global resource tracking all entities that have animation data.
@@ -72,11 +102,467 @@ pub struct AnimationRegistry {
 #[derive(Component)]
 pub struct HasAnimations;
 
-/// Plugin that registers the AnimationRegistry resource.
+/// Message sent when a playing clip passes one of its event markers.
+#[derive(Message, Debug, Clone)]
+pub struct AnimationEventFired {
+    pub entity_id: String,
+    pub clip_name: String,
+    pub event: AnimationEvent,
+}
+
+/// Playback position of one clip as last seen by `fire_animation_events`.
+#[derive(Default)]
+pub struct AnimationEventCursor {
+    seek_time: f32,
+    completions: u32,
+    /// Per-event flag, reset each time a looping clip wraps.
+    fired: Vec<bool>,
+}
+
+/// System that fires `AnimationEventFired` when a playing clip's seek time
+/// crosses an event marker. Each marker fires once per loop: when the clip
+/// wraps, markers skipped at the end of the previous loop fire first, then
+/// the fired-set resets.
+pub fn fire_animation_events(
+    registry: Res<AnimationRegistry>,
+    players: Query<&AnimationPlayer>,
+    mut cursors: Local<HashMap<(String, String), AnimationEventCursor>>,
+    mut fired_events: MessageWriter<AnimationEventFired>,
+) {
+    let mut seen = Vec::new();
+    for (entity_id, entry) in &registry.entries {
+        let Ok(player) = players.get(entry.player_entity) else { continue };
+        for (clip_name, events) in &entry.events {
+            let Some((node_index, _)) = entry.clips.get(clip_name) else { continue };
+            let Some(active) = player.animation(*node_index) else { continue };
+            let key = (entity_id.clone(), clip_name.clone());
+            seen.push(key.clone());
+
+            let cursor = cursors.entry(key).or_default();
+            cursor.fired.resize(events.len(), false);
+            let seek_time = active.seek_time();
+            let wrapped = active.completions() != cursor.completions || seek_time < cursor.seek_time;
+
+            let mut fire = |fired: &mut Vec<bool>, until: f32| {
+                for (event, done) in events.iter().zip(fired.iter_mut()) {
+                    if !*done && event.time_secs <= until {
+                        *done = true;
+                        fired_events.write(AnimationEventFired {
+                            entity_id: entity_id.clone(),
+                            clip_name: clip_name.clone(),
+                            event: event.clone(),
+                        });
+                    }
+                }
+            };
+            if wrapped {
+                fire(&mut cursor.fired, f32::INFINITY);
+                cursor.fired.fill(false);
+            }
+            fire(&mut cursor.fired, seek_time);
+
+            cursor.seek_time = seek_time;
+            cursor.completions = active.completions();
+        }
+    }
+    // Clips that stopped playing start from scratch next time.
+    cursors.retain(|key, _| seen.contains(key));
+}
+
+/// One clip in a 1D blend space and the parameter value where it plays alone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlendPoint1d {
+    pub clip_name: String,
+    pub threshold: f32,
+}
+
+/// 1D blend space on the top-level entity of an animated model, e.g. a
+/// walk/run blend driven by speed. The two clips whose thresholds bracket
+/// `value` are crossfaded; values outside the range play the nearest clip.
+#[derive(Component, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationBlend1d {
+    /// Blend points, sorted by threshold.
+    pub points: Vec<BlendPoint1d>,
+    /// Current blend parameter.
+    pub value: f32,
+}
+
+impl AnimationBlend1d {
+    /// Create a blend space starting at the lowest threshold.
+    pub fn new(mut points: Vec<BlendPoint1d>) -> Self {
+        points.sort_by(|a, b| a.threshold.total_cmp(&b.threshold));
+        let value = points.first().map_or(0.0, |p| p.threshold);
+        Self { points, value }
+    }
+
+    /// Weight of every clip in the blend space for the current value. At most
+    /// two are non-zero, and the weights sum to 1.0.
+    pub fn weights(&self) -> Vec<(&str, f32)> {
+        let mut weights: Vec<(&str, f32)> =
+            self.points.iter().map(|p| (p.clip_name.as_str(), 0.0)).collect();
+        let upper = self.points.partition_point(|p| p.threshold <= self.value);
+        match upper {
+            0 => {
+                if let Some(first) = weights.first_mut() {
+                    first.1 = 1.0;
+                }
+            }
+            i if i == weights.len() => weights[i - 1].1 = 1.0,
+            i => {
+                let (lo, hi) = (self.points[i - 1].threshold, self.points[i].threshold);
+                let t = (self.value - lo) / (hi - lo);
+                weights[i - 1].1 = 1.0 - t;
+                weights[i].1 = t;
+            }
+        }
+        weights
+    }
+}
+
+/// System that applies 1D blend spaces to their AnimationPlayer: every clip
+/// in the space is kept playing (looped) and weighted by `weights()`.
+pub fn apply_animation_blend_1d(
+    registry: Res<AnimationRegistry>,
+    blends: Query<(&EntityId, Ref<AnimationBlend1d>)>,
+    mut players: Query<&mut AnimationPlayer>,
+) {
+    for (entity_id, blend) in &blends {
+        if !blend.is_changed() && !registry.is_changed() {
+            continue;
+        }
+        let Some(entry) = registry.entries.get(&entity_id.0) else { continue };
+        let Ok(mut player) = players.get_mut(entry.player_entity) else { continue };
+        for (clip_name, weight) in blend.weights() {
+            if let Some((node_index, _)) = entry.clips.get(clip_name) {
+                player.play(*node_index).repeat().set_weight(weight);
+            }
+        }
+    }
+}
+
+/// Root-motion extraction state, placed on the top-level entity of a model
+/// whose clip was started with `root_motion`. Each frame the root bone's
+/// horizontal movement is moved onto the entity's Transform and the bone is
+/// pinned back in place, so the clip plays in place while the entity walks.
+#[derive(Component, Debug, Clone)]
+pub struct RootMotionState {
+    /// Entity holding the AnimationPlayer.
+    pub player_entity: Entity,
+    /// Graph node of the clip driving the motion.
+    pub node_index: AnimationNodeIndex,
+    /// Root bone, resolved on the first frame the clip is active.
+    root_bone: Option<Entity>,
+    /// Bone translation when extraction started; the horizontal root track is
+    /// measured from (and pinned back to) this.
+    rest_translation: Vec3,
+    /// Sampled (unpinned) bone translation and clip time from the last frame.
+    last_translation: Vec3,
+    last_seek_time: f32,
+    last_delta: Vec3,
+}
+
+impl RootMotionState {
+    pub fn new(player_entity: Entity, node_index: AnimationNodeIndex) -> Self {
+        Self {
+            player_entity,
+            node_index,
+            root_bone: None,
+            rest_translation: Vec3::ZERO,
+            last_translation: Vec3::ZERO,
+            last_seek_time: 0.0,
+            last_delta: Vec3::ZERO,
+        }
+    }
+}
+
+/// Whether the clip animates the translation of `target`.
+fn has_translation_curve(clip: &AnimationClip, target: AnimationTargetId) -> bool {
+    let translation: AnimatedField<Transform, Vec3, _> = animated_field!(Transform::translation);
+    let EvaluatorId::ComponentField(wanted) = translation.evaluator_id() else {
+        return false;
+    };
+    clip.curves_for_target(target).is_some_and(|curves| {
+        curves.iter().any(|curve| {
+            matches!(curve.0.evaluator_id(), EvaluatorId::ComponentField(id) if id == wanted)
+        })
+    })
+}
+
+/// Animated bones (never the root-motion entity itself).
+type RootBoneQuery<'w, 's> = Query<
+    'w, 's,
+    (&'static AnimationTargetId, &'static mut Transform, Option<&'static ChildOf>),
+    Without<RootMotionState>,
+>;
+
+/// System that extracts root motion after the animation pass has posed the
+/// skeleton and before transforms propagate. Runs on every entity with a
+/// `RootMotionState`; only horizontal (world XZ) motion is extracted, so
+/// vertical bob stays in the clip.
+pub fn apply_root_motion(
+    mut owners: Query<(&mut RootMotionState, &mut Transform, Option<&ChildOf>)>,
+    players: Query<(&AnimationPlayer, &AnimationGraphHandle)>,
+    graphs: Res<Assets<AnimationGraph>>,
+    clips: Res<Assets<AnimationClip>>,
+    children: Query<&Children>,
+    mut bones: RootBoneQuery,
+    globals: Query<&GlobalTransform>,
+) {
+    for (mut state, mut transform, owner_parent) in owners.iter_mut() {
+        let Ok((player, graph_handle)) = players.get(state.player_entity) else { continue };
+        let Some(active) = player.animation(state.node_index) else { continue };
+        let seek_time = active.seek_time();
+
+        // Resolve the root bone: the shallowest target with a translation track.
+        let bone_entity = match state.root_bone {
+            Some(bone) => bone,
+            None => {
+                let clip = graphs.get(graph_handle)
+                    .and_then(|graph| graph.get(state.node_index))
+                    .and_then(|node| match &node.node_type {
+                        AnimationNodeType::Clip(handle) => clips.get(handle),
+                        _ => None,
+                    });
+                let Some(clip) = clip else { continue };
+                let root = std::iter::once(state.player_entity)
+                    .chain(children.iter_descendants(state.player_entity))
+                    .find(|e| bones.get(*e).is_ok_and(|(target, _, _)| has_translation_curve(clip, *target)));
+                let Some(root) = root else { continue };
+                let Ok((_, bone_transform, _)) = bones.get(root) else { continue };
+                state.root_bone = Some(root);
+                state.rest_translation = bone_transform.translation;
+                state.last_translation = bone_transform.translation;
+                state.last_seek_time = seek_time;
+                continue;
+            }
+        };
+        let Ok((_, mut bone_transform, bone_parent)) = bones.get_mut(bone_entity) else { continue };
+
+        // Bone-parent space -> world, dropping the vertical component.
+        let parent_affine = bone_parent
+            .and_then(|p| globals.get(p.parent()).ok())
+            .map(|g| g.affine())
+            .unwrap_or_default();
+        let horizontal = |local: Vec3| {
+            let world = parent_affine.transform_vector3(local);
+            Vec3::new(world.x, 0.0, world.z)
+        };
+
+        let sampled = bone_transform.translation;
+        // When the clip loops the root track jumps back to its start; carry
+        // the previous frame's motion across the seam instead.
+        let local_delta = if seek_time < state.last_seek_time {
+            state.last_delta
+        } else {
+            sampled - state.last_translation
+        };
+        state.last_translation = sampled;
+        state.last_seek_time = seek_time;
+        state.last_delta = local_delta;
+
+        // Play the clip in place: remove the horizontal root offset.
+        let offset = horizontal(sampled - state.rest_translation);
+        bone_transform.translation = sampled - parent_affine.inverse().transform_vector3(offset);
+
+        let mut world_delta = horizontal(local_delta);
+        if let Some(parent) = owner_parent.and_then(|p| globals.get(p.parent()).ok()) {
+            world_delta = parent.affine().inverse().transform_vector3(world_delta);
+        }
+        transform.translation += world_delta;
+    }
+}
+
+/// Plugin that registers the AnimationRegistry resource, animation event
+/// markers, 1D blend spaces, and root motion.
 pub struct AnimationPlugin;
 
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<AnimationRegistry>();
+        app.init_resource::<AnimationRegistry>()
+            .add_message::<AnimationEventFired>()
+            .add_systems(Update, apply_animation_blend_1d)
+            .add_systems(
+                PostUpdate,
+                (
+                    fire_animation_events.after(AnimationSystems),
+                    apply_root_motion
+                        .after(AnimationSystems)
+                        .before(TransformSystems::Propagate),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::animation::{animation_curves::{AnimatableCurve, AnimatableKeyframeCurve}, AnimatedBy};
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    /// Spawns model -> player -> hips with a 1s walk clip moving hips 2 units
+    /// along +Z, and starts playback. Returns (model, hips).
+    fn walking_model(app: &mut App, root_motion: bool) -> (Entity, Entity) {
+        let hips_name = Name::new("Hips");
+        let target = AnimationTargetId::from_name(&hips_name);
+        let mut clip = AnimationClip::default();
+        let curve = AnimatableKeyframeCurve::new([(0.0, Vec3::ZERO), (1.0, Vec3::new(0.0, 0.1, 2.0))])
+            .expect("walk curve");
+        clip.add_curve_to_target(target, AnimatableCurve::new(animated_field!(Transform::translation), curve));
+        let clip = app.world_mut().resource_mut::<Assets<AnimationClip>>().add(clip);
+        let (graph, node) = AnimationGraph::from_clip(clip);
+        let graph = app.world_mut().resource_mut::<Assets<AnimationGraph>>().add(graph);
+
+        let mut player = AnimationPlayer::default();
+        player.play(node).repeat();
+        let model = app.world_mut().spawn(Transform::default()).id();
+        let player = app.world_mut()
+            .spawn((player, AnimationGraphHandle(graph), Transform::default(), ChildOf(model)))
+            .id();
+        let hips = app.world_mut()
+            .spawn((hips_name, target, AnimatedBy(player), Transform::default(), ChildOf(player)))
+            .id();
+        if root_motion {
+            app.world_mut().entity_mut(model).insert(RootMotionState::new(player, node));
+        }
+        (model, hips)
+    }
+
+    fn animation_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            TransformPlugin,
+            bevy::animation::AnimationPlugin,
+            AnimationPlugin,
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app
+    }
+
+    #[test]
+    fn root_motion_moves_entity_and_pins_root_bone() {
+        let mut app = animation_app();
+        let (model, hips) = walking_model(&mut app, true);
+        for _ in 0..6 {
+            app.update();
+        }
+
+        let model_pos = app.world().get::<Transform>(model).expect("model").translation;
+        assert!(model_pos.z > 0.5, "model should walk forward, got {model_pos}");
+        assert_eq!(model_pos.y, 0.0, "vertical motion stays in the clip");
+        let hips_pos = app.world().get::<Transform>(hips).expect("hips").translation;
+        assert!(hips_pos.z.abs() < 1e-4, "root bone should play in place, got {hips_pos}");
+        assert!(hips_pos.y > 0.0, "vertical bob is kept on the bone");
+    }
+
+    #[test]
+    fn without_root_motion_entity_stays_put() {
+        let mut app = animation_app();
+        let (model, hips) = walking_model(&mut app, false);
+        for _ in 0..6 {
+            app.update();
+        }
+
+        assert_eq!(app.world().get::<Transform>(model).expect("model").translation, Vec3::ZERO);
+        assert!(app.world().get::<Transform>(hips).expect("hips").translation.z > 0.5);
+    }
+
+    #[test]
+    fn animation_event_fires_once_per_loop() {
+        let mut app = animation_app();
+        let mut clip = AnimationClip::default();
+        clip.set_duration(1.0);
+        let clip = app.world_mut().resource_mut::<Assets<AnimationClip>>().add(clip);
+        let (graph, node) = AnimationGraph::from_clip(clip);
+        let graph = app.world_mut().resource_mut::<Assets<AnimationGraph>>().add(graph);
+        let mut player = AnimationPlayer::default();
+        player.play(node).repeat();
+        let player_entity = app.world_mut().spawn((player, AnimationGraphHandle(graph.clone()))).id();
+
+        let mut entry = EntityAnimationData {
+            clips: HashMap::from([("Walk".to_string(), (node, 1.0))]),
+            clip_names: vec!["Walk".to_string()],
+            player_entity,
+            graph_handle: graph,
+            events: HashMap::new(),
+        };
+        entry.add_event("Walk", AnimationEvent { time_secs: 0.5, name: "footstep".to_string() });
+        app.world_mut().resource_mut::<AnimationRegistry>().entries.insert("hero".to_string(), entry);
+
+        let run = |app: &mut App, frames: usize| {
+            (0..frames)
+                .map(|_| {
+                    app.update();
+                    app.world_mut().resource_mut::<Messages<AnimationEventFired>>().drain().count()
+                })
+                .sum::<usize>()
+        };
+        // ~0.0s -> 0.9s: one footstep.
+        assert_eq!(run(&mut app, 10), 1);
+        // Through the wrap and up to ~1.4s: nothing new yet.
+        assert_eq!(run(&mut app, 5), 0);
+        // ~1.5s -> 2.4s: the second loop's footstep, exactly once.
+        assert_eq!(run(&mut app, 10), 1);
+    }
+
+    fn blend_points() -> Vec<BlendPoint1d> {
+        vec![
+            BlendPoint1d { clip_name: "Run".to_string(), threshold: 4.0 },
+            BlendPoint1d { clip_name: "Idle".to_string(), threshold: 0.0 },
+            BlendPoint1d { clip_name: "Walk".to_string(), threshold: 2.0 },
+        ]
+    }
+
+    #[test]
+    fn blend_1d_weights_are_proportional_between_thresholds() {
+        let mut blend = AnimationBlend1d::new(blend_points());
+        assert_eq!(blend.value, 0.0);
+
+        blend.value = 2.5;
+        let weights = blend.weights();
+        assert_eq!(weights, vec![("Idle", 0.0), ("Walk", 0.75), ("Run", 0.25)]);
+        assert!((weights.iter().map(|(_, w)| w).sum::<f32>() - 1.0).abs() < 1e-6);
+
+        // Outside the range the nearest clip plays alone.
+        blend.value = 10.0;
+        assert_eq!(blend.weights(), vec![("Idle", 0.0), ("Walk", 0.0), ("Run", 1.0)]);
+        blend.value = -1.0;
+        assert_eq!(blend.weights(), vec![("Idle", 1.0), ("Walk", 0.0), ("Run", 0.0)]);
+    }
+
+    #[test]
+    fn blend_1d_sets_player_weights() {
+        let mut app = animation_app();
+        let mut graph = AnimationGraph::new();
+        let mut clips = HashMap::new();
+        for point in blend_points() {
+            let clip = app.world_mut().resource_mut::<Assets<AnimationClip>>().add(AnimationClip::default());
+            clips.insert(point.clip_name, (graph.add_clip(clip, 1.0, graph.root), 1.0));
+        }
+        let graph = app.world_mut().resource_mut::<Assets<AnimationGraph>>().add(graph);
+        let player_entity = app.world_mut()
+            .spawn((AnimationPlayer::default(), AnimationGraphHandle(graph.clone())))
+            .id();
+        app.world_mut().resource_mut::<AnimationRegistry>().entries.insert("hero".to_string(), EntityAnimationData {
+            clip_names: clips.keys().cloned().collect(),
+            clips: clips.clone(),
+            player_entity,
+            graph_handle: graph,
+            events: HashMap::new(),
+        });
+        let mut blend = AnimationBlend1d::new(blend_points());
+        blend.value = 1.0;
+        app.world_mut().spawn((EntityId("hero".to_string()), blend));
+        app.update();
+
+        let player = app.world().get::<AnimationPlayer>(player_entity).expect("player");
+        let weight = |name: &str| player.animation(clips[name].0).map(|a| a.weight());
+        assert_eq!(weight("Idle"), Some(0.5));
+        assert_eq!(weight("Walk"), Some(0.5));
+        assert_eq!(weight("Run"), Some(0.0));
     }
 }
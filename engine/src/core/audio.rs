@@ -83,6 +83,7 @@ impl Default for AudioBusConfig {
                     muted: false,
                     soloed: false,
                     effects: vec![],
+                    parent: None,
                 },
                 AudioBusDef {
                     name: "sfx".to_string(),
@@ -90,6 +91,7 @@ impl Default for AudioBusConfig {
                     muted: false,
                     soloed: false,
                     effects: vec![],
+                    parent: None,
                 },
                 AudioBusDef {
                     name: "music".to_string(),
@@ -97,6 +99,7 @@ impl Default for AudioBusConfig {
                     muted: false,
                     soloed: false,
                     effects: vec![],
+                    parent: None,
                 },
                 AudioBusDef {
                     name: "ambient".to_string(),
@@ -104,6 +107,7 @@ impl Default for AudioBusConfig {
                     muted: false,
                     soloed: false,
                     effects: vec![],
+                    parent: None,
                 },
                 AudioBusDef {
                     name: "voice".to_string(),
@@ -111,6 +115,7 @@ impl Default for AudioBusConfig {
                     muted: false,
                     soloed: false,
                     effects: vec![],
+                    parent: None,
                 },
             ],
         }
@@ -127,6 +132,79 @@ pub struct AudioBusDef {
     pub soloed: bool,
     #[serde(default)]
     pub effects: Vec<AudioEffectDef>,
+    /// Bus this one mixes into. None routes straight to master.
+    #[serde(default)]
+    pub parent: Option<String>,
+}
+
+impl AudioBusConfig {
+    pub fn get(&self, name: &str) -> Option<&AudioBusDef> {
+        self.buses.iter().find(|b| b.name == name)
+    }
+
+    /// Check that `bus` may mix into `parent` (None or "master" = master).
+    /// Fails if the parent is unknown or the chain up from it reaches `bus`.
+    pub fn validate_parent(&self, bus: &str, parent: Option<&str>) -> Result<(), String> {
+        let Some(parent) = parent.filter(|p| *p != "master") else {
+            return Ok(());
+        };
+        if bus == "master" {
+            return Err("The 'master' bus cannot have a parent".to_string());
+        }
+
+        let mut current = Some(parent);
+        // Each bus is visited at most once on an acyclic chain.
+        for _ in 0..=self.buses.len() {
+            let Some(name) = current else { return Ok(()) };
+            if name == bus {
+                return Err(format!("Routing '{}' into '{}' would create a cycle", bus, parent));
+            }
+            let Some(def) = self.get(name) else {
+                return Err(format!("Parent bus '{}' not found", name));
+            };
+            current = def.parent.as_deref();
+        }
+        Err(format!("Bus chain above '{}' already contains a cycle", parent))
+    }
+
+    /// Add a bus after validating its name and parent.
+    pub fn create_bus(&mut self, mut def: AudioBusDef) -> Result<(), String> {
+        if self.get(&def.name).is_some() {
+            return Err(format!("Bus '{}' already exists", def.name));
+        }
+        self.validate_parent(&def.name, def.parent.as_deref())?;
+        def.parent = def.parent.filter(|p| p != "master");
+        self.buses.push(def);
+        Ok(())
+    }
+
+    /// Re-route an existing bus, rejecting changes that would form a cycle.
+    pub fn set_parent(&mut self, bus: &str, parent: Option<String>) -> Result<(), String> {
+        self.validate_parent(bus, parent.as_deref())?;
+        let def = self.buses.iter_mut()
+            .find(|b| b.name == bus)
+            .ok_or_else(|| format!("Bus '{}' not found", bus))?;
+        def.parent = parent.filter(|p| p != "master");
+        Ok(())
+    }
+
+    /// Remove a bus, handing its children to its own parent so they keep
+    /// their place in the chain. Master cannot be deleted.
+    pub fn delete_bus(&mut self, name: &str) -> bool {
+        if name == "master" {
+            return false;
+        }
+        let Some(index) = self.buses.iter().position(|b| b.name == name) else {
+            return false;
+        };
+        let removed = self.buses.remove(index);
+        for bus in self.buses.iter_mut() {
+            if bus.parent.as_deref() == Some(name) {
+                bus.parent = removed.parent.clone();
+            }
+        }
+        true
+    }
 }
 
 /// Definition of an audio effect on a bus (Phase A-2).
@@ -137,3 +215,54 @@ pub struct AudioEffectDef {
     pub params: HashMap<String, f32>,
     pub enabled: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bus(name: &str, parent: Option<&str>) -> AudioBusDef {
+        AudioBusDef {
+            name: name.to_string(),
+            volume: 1.0,
+            muted: false,
+            soloed: false,
+            effects: vec![],
+            parent: parent.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn bus_routing_rejects_cycles() {
+        let mut config = AudioBusConfig::default();
+        config.create_bus(bus("footsteps", Some("sfx"))).unwrap();
+
+        let err = config.create_bus(bus("loop", Some("loop"))).unwrap_err();
+        assert!(err.contains("cycle"), "{}", err);
+        assert!(config.get("loop").is_none());
+
+        let err = config.set_parent("sfx", Some("footsteps".to_string())).unwrap_err();
+        assert!(err.contains("cycle"), "{}", err);
+        assert_eq!(config.get("sfx").and_then(|b| b.parent.as_deref()), None);
+
+        assert!(config.create_bus(bus("ghost", Some("missing"))).is_err());
+        assert!(config.set_parent("master", Some("sfx".to_string())).is_err());
+    }
+
+    #[test]
+    fn deleting_mid_chain_bus_reparents_children() {
+        let mut config = AudioBusConfig::default();
+        config.create_bus(bus("world", Some("sfx"))).unwrap();
+        config.create_bus(bus("footsteps", Some("world"))).unwrap();
+        config.create_bus(bus("doors", Some("world"))).unwrap();
+
+        assert!(config.delete_bus("world"));
+        assert!(config.get("world").is_none());
+        assert_eq!(config.get("footsteps").and_then(|b| b.parent.as_deref()), Some("sfx"));
+        assert_eq!(config.get("doors").and_then(|b| b.parent.as_deref()), Some("sfx"));
+
+        // Children of a top-level bus fall back to master.
+        assert!(config.delete_bus("sfx"));
+        assert_eq!(config.get("footsteps").and_then(|b| b.parent.as_deref()), None);
+        assert!(!config.delete_bus("master"));
+    }
+}
@@ -2,9 +2,21 @@
 //!
 //! This module provides blend trees for mixing multiple animations,
 //! including 1D blending, 2D blending, and additive blending.
+//!
+//! A skeleton only carries one `SkeletalAnimation2d` at a time, so every clip
+//! authored on it is also kept in its `Skeleton2dBlendState`. The blend tree
+//! names clips from that library and weights them by the state's parameters.
+//! All clips share one normalized phase so blended cycles stay in step.
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+
+use super::entity_id::EntityId;
+use super::pending_commands::PendingCommands;
+use super::skeletal_animation2d::{wrap_degrees, SkeletalAnimation2d};
+use super::skeleton2d::{Bone2dDef, SkeletonData2d};
 
 /// Component defining a blend tree.
 #[derive(Component, Clone, Debug, Serialize, Deserialize)]
@@ -22,6 +34,12 @@ pub enum BlendTreeType2d {
     Simple1D {
         clips: Vec<BlendClip1D>,
     },
+    /// Clips placed by direction around the origin (e.g. walk N/E/S/W). A clip
+    /// at the origin is blended in as the parameter vector shrinks.
+    #[serde(rename = "directional2d")]
+    Directional2D {
+        clips: Vec<BlendClip2D>,
+    },
     #[serde(rename = "additive")]
     Additive {
         base_clip: String,
@@ -37,3 +55,341 @@ pub struct BlendClip1D {
     pub animation_name: String,
     pub threshold: f32, // parameter value where this clip is at 100%
 }
+
+/// A clip in a 2D blend space.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlendClip2D {
+    pub animation_name: String,
+    pub position: [f32; 2], // parameter vector where this clip is at 100%
+}
+
+/// Component holding blend parameters, playback phase and the clip library
+/// a blend tree draws from.
+#[derive(Component, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Skeleton2dBlendState {
+    /// Named parameters; 1D trees and additive weights read the x component.
+    pub parameters: HashMap<String, [f32; 2]>,
+    /// Normalized playback position shared by every blended clip (0..1).
+    pub phase: f32,
+    pub clips: HashMap<String, SkeletalAnimation2d>,
+}
+
+impl Skeleton2dBlendState {
+    pub fn parameter(&self, name: &str) -> Vec2 {
+        self.parameters.get(name).copied().map(Vec2::from).unwrap_or(Vec2::ZERO)
+    }
+}
+
+/// Weights of the clips bracketing `value` in a 1D blend space.
+fn simple_1d_weights(clips: &[BlendClip1D], value: f32) -> Vec<(&str, f32)> {
+    let mut sorted: Vec<&BlendClip1D> = clips.iter().collect();
+    sorted.sort_by(|a, b| a.threshold.total_cmp(&b.threshold));
+    let (Some(first), Some(last)) = (sorted.first(), sorted.last()) else { return Vec::new() };
+    if value <= first.threshold {
+        return vec![(first.animation_name.as_str(), 1.0)];
+    }
+    if value >= last.threshold {
+        return vec![(last.animation_name.as_str(), 1.0)];
+    }
+    sorted
+        .windows(2)
+        .find(|pair| value <= pair[1].threshold)
+        .map(|pair| {
+            let span = pair[1].threshold - pair[0].threshold;
+            let t = if span > f32::EPSILON { (value - pair[0].threshold) / span } else { 0.0 };
+            vec![(pair[0].animation_name.as_str(), 1.0 - t), (pair[1].animation_name.as_str(), t)]
+        })
+        .unwrap_or_default()
+}
+
+/// Weights for a directional blend space: the two clips whose directions
+/// bracket the parameter's angle share the weight by angle, and a clip at the
+/// origin (if any) takes over as the parameter gets shorter than theirs.
+fn directional_weights(clips: &[BlendClip2D], value: Vec2) -> Vec<(&str, f32)> {
+    let center = clips.iter().find(|c| Vec2::from(c.position).length() < 1e-4);
+    let mut directions: Vec<(&BlendClip2D, f32)> = clips
+        .iter()
+        .filter(|c| Vec2::from(c.position).length() >= 1e-4)
+        .map(|c| (c, Vec2::from(c.position).to_angle().rem_euclid(TAU)))
+        .collect();
+    directions.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    if value.length() < 1e-4 || directions.is_empty() {
+        let fallback = center.or(directions.first().map(|(clip, _)| *clip));
+        return fallback.map(|clip| vec![(clip.animation_name.as_str(), 1.0)]).unwrap_or_default();
+    }
+
+    let angle = value.to_angle().rem_euclid(TAU);
+    let mut weights = if directions.len() == 1 {
+        vec![(directions[0].0, 1.0)]
+    } else {
+        // The bracketing pair is the last clip at or below the angle and the
+        // one after it, wrapping around the circle.
+        let lo = directions.iter().rposition(|(_, a)| *a <= angle).unwrap_or(directions.len() - 1);
+        let hi = (lo + 1) % directions.len();
+        let span = (directions[hi].1 - directions[lo].1).rem_euclid(TAU);
+        let t = if span > f32::EPSILON { (angle - directions[lo].1).rem_euclid(TAU) / span } else { 0.0 };
+        vec![(directions[lo].0, 1.0 - t), (directions[hi].0, t)]
+    };
+
+    let mut result = Vec::new();
+    if let Some(center) = center {
+        let reach: f32 = weights.iter().map(|(c, w)| Vec2::from(c.position).length() * w).sum();
+        let radial = (value.length() / reach.max(f32::EPSILON)).min(1.0);
+        weights.iter_mut().for_each(|(_, w)| *w *= radial);
+        result.push((center.animation_name.as_str(), 1.0 - radial));
+    }
+    result.extend(weights.into_iter().map(|(c, w)| (c.animation_name.as_str(), w)));
+    result
+}
+
+impl BlendTree2d {
+    /// Clip weights for the current parameters (additive trees are handled
+    /// separately by `blend_pose`).
+    pub fn weights(&self, state: &Skeleton2dBlendState) -> Vec<(String, f32)> {
+        let value = state.parameter(&self.parameter_name);
+        let weights = match &self.tree_type {
+            BlendTreeType2d::Simple1D { clips } => simple_1d_weights(clips, value.x),
+            BlendTreeType2d::Directional2D { clips } => directional_weights(clips, value),
+            BlendTreeType2d::Additive { base_clip, .. } => vec![(base_clip.as_str(), 1.0)],
+        };
+        weights
+            .into_iter()
+            .filter(|(_, w)| *w > 1e-6)
+            .map(|(name, w)| (name.to_string(), w))
+            .collect()
+    }
+}
+
+fn sample_pose(clip: &SkeletalAnimation2d, bones: &[Bone2dDef], phase: f32) -> Vec<Bone2dDef> {
+    let mut pose = bones.to_vec();
+    clip.sample(&mut pose, phase * clip.duration);
+    pose
+}
+
+/// Evaluate `tree` at the state's phase on top of `bones`. Bones without a
+/// track in any blended clip keep their current transform. Returns `None`
+/// when none of the weighted clips are in the library.
+pub fn blend_pose(tree: &BlendTree2d, state: &Skeleton2dBlendState, bones: &[Bone2dDef]) -> Option<Vec<Bone2dDef>> {
+    let poses: Vec<(f32, Vec<Bone2dDef>)> = tree
+        .weights(state)
+        .into_iter()
+        .filter_map(|(name, w)| state.clips.get(&name).map(|clip| (w, sample_pose(clip, bones, state.phase))))
+        .collect();
+    let total: f32 = poses.iter().map(|(w, _)| w).sum();
+    if poses.is_empty() || total <= f32::EPSILON {
+        return None;
+    }
+
+    let mut out = bones.to_vec();
+    for (i, bone) in out.iter_mut().enumerate() {
+        // Rotations blend as offsets from the first pose so they take the short way round
+        let reference = poses[0].1[i].local_rotation;
+        let (mut position, mut rotation, mut scale) = (Vec2::ZERO, 0.0, Vec2::ZERO);
+        for (w, pose) in &poses {
+            let w = w / total;
+            let posed = &pose[i];
+            position += Vec2::new(posed.local_position[0], posed.local_position[1]) * w;
+            rotation += wrap_degrees(posed.local_rotation - reference) * w;
+            scale += Vec2::from(posed.local_scale) * w;
+        }
+        bone.local_position = [position.x, position.y, bone.local_position[2]];
+        bone.local_rotation = reference + rotation;
+        bone.local_scale = scale.into();
+    }
+
+    if let BlendTreeType2d::Additive { additive_clip, weight_param, .. } = &tree.tree_type {
+        if let Some(clip) = state.clips.get(additive_clip) {
+            let weight = state.parameter(weight_param).x.clamp(0.0, 1.0);
+            // The additive clip's first frame is its reference pose
+            let rest = sample_pose(clip, bones, 0.0);
+            let posed = sample_pose(clip, bones, state.phase);
+            for ((bone, rest), posed) in out.iter_mut().zip(&rest).zip(&posed) {
+                bone.local_position[0] += (posed.local_position[0] - rest.local_position[0]) * weight;
+                bone.local_position[1] += (posed.local_position[1] - rest.local_position[1]) * weight;
+                bone.local_rotation += wrap_degrees(posed.local_rotation - rest.local_rotation) * weight;
+                bone.local_scale[0] += (posed.local_scale[0] - rest.local_scale[0]) * weight;
+                bone.local_scale[1] += (posed.local_scale[1] - rest.local_scale[1]) * weight;
+            }
+        }
+    }
+
+    Some(out)
+}
+
+// ========== Systems ==========
+
+/// System that keeps a copy of every clip authored on a skeleton in its blend
+/// state, adding the state on first use.
+pub fn record_blend_clips2d(
+    mut commands: Commands,
+    mut query: Query<(Entity, &SkeletalAnimation2d, Option<&mut Skeleton2dBlendState>), Changed<SkeletalAnimation2d>>,
+) {
+    for (entity, clip, state) in query.iter_mut() {
+        match state {
+            Some(mut state) => {
+                state.clips.insert(clip.name.clone(), clip.clone());
+            }
+            None => {
+                let mut state = Skeleton2dBlendState::default();
+                state.clips.insert(clip.name.clone(), clip.clone());
+                commands.entity(entity).insert(state);
+            }
+        }
+    }
+}
+
+/// System that applies pending set_blend_tree2d / remove_blend_tree2d /
+/// set_blend_param2d requests.
+pub fn apply_blend_tree2d_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &EntityId, Option<&mut Skeleton2dBlendState>)>,
+) {
+    for request in pending.set_blend_tree2d_requests.drain(..) {
+        let Some((entity, _, state)) = query.iter().find(|(_, eid, _)| eid.0 == request.entity_id) else {
+            continue;
+        };
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(request.tree);
+        if state.is_none() {
+            entity_commands.insert(Skeleton2dBlendState::default());
+        }
+    }
+
+    for request in pending.remove_blend_tree2d_requests.drain(..) {
+        if let Some((entity, _, _)) = query.iter().find(|(_, eid, _)| eid.0 == request.entity_id) {
+            commands.entity(entity).remove::<BlendTree2d>();
+        }
+    }
+
+    for request in pending.set_blend_param2d_requests.drain(..) {
+        let Some((entity, _, state)) = query.iter_mut().find(|(_, eid, _)| eid.0 == request.entity_id) else {
+            continue;
+        };
+        match state {
+            Some(mut state) => {
+                state.parameters.insert(request.name, request.value);
+            }
+            None => {
+                let mut state = Skeleton2dBlendState::default();
+                state.parameters.insert(request.name, request.value);
+                commands.entity(entity).insert(state);
+            }
+        }
+    }
+}
+
+/// System that advances each blend tree's phase and writes the blended pose
+/// into the skeleton's bones.
+pub fn evaluate_blend_trees_2d(
+    time: Res<Time>,
+    mut query: Query<(&BlendTree2d, &mut Skeleton2dBlendState, &mut SkeletonData2d)>,
+) {
+    for (tree, mut state, mut skeleton) in query.iter_mut() {
+        // The cycle length is the weighted average of the blended clips' durations
+        let weights = tree.weights(&state);
+        let (weighted, total) = weights.iter().fold((0.0, 0.0), |(sum, total), (name, w)| {
+            match state.clips.get(name) {
+                Some(clip) => (sum + clip.duration * w, total + w),
+                None => (sum, total),
+            }
+        });
+        if total > f32::EPSILON && weighted > f32::EPSILON {
+            let duration = weighted / total;
+            state.phase = (state.phase + time.delta_secs() / duration).rem_euclid(1.0);
+        }
+
+        if let Some(bones) = blend_pose(tree, &state, &skeleton.bones) {
+            skeleton.bones = bones;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending_commands::{SetBlendParam2dRequest, SetBlendTree2dRequest};
+    use crate::core::skeletal_animation2d::{BoneKeyframe, EasingType2d};
+
+    fn hip() -> Bone2dDef {
+        Bone2dDef {
+            name: "hip".to_string(),
+            parent_bone: None,
+            local_position: [0.0; 3],
+            local_rotation: 0.0,
+            local_scale: [1.0, 1.0],
+            length: 20.0,
+            color: [1.0; 4],
+            angle_limits: None,
+        }
+    }
+
+    /// A one-second clip holding the hip at a fixed rotation.
+    fn pose_clip(name: &str, rotation: f32) -> SkeletalAnimation2d {
+        let key = |time| BoneKeyframe { time, position: None, rotation: Some(rotation), scale: None, easing: EasingType2d::Linear };
+        SkeletalAnimation2d {
+            name: name.to_string(),
+            duration: 1.0,
+            looping: true,
+            tracks: HashMap::from([("hip".to_string(), vec![key(0.0), key(1.0)])]),
+        }
+    }
+
+    fn locomotion() -> BlendTree2d {
+        let clip = |name: &str, x, y| BlendClip2D { animation_name: name.to_string(), position: [x, y] };
+        BlendTree2d {
+            tree_type: BlendTreeType2d::Directional2D {
+                clips: vec![clip("walk_n", 0.0, 1.0), clip("walk_e", 1.0, 0.0), clip("walk_s", 0.0, -1.0), clip("walk_w", -1.0, 0.0)],
+            },
+            parameter_name: "move".to_string(),
+        }
+    }
+
+    #[test]
+    fn directional_weights_pick_the_bracketing_clips() {
+        let tree = locomotion();
+        let mut state = Skeleton2dBlendState::default();
+
+        state.parameters.insert("move".to_string(), [1.0, 0.0]);
+        assert_eq!(tree.weights(&state), vec![("walk_e".to_string(), 1.0)]);
+
+        // Halfway between west and south
+        state.parameters.insert("move".to_string(), [-1.0, -1.0]);
+        let weights = tree.weights(&state);
+        assert_eq!(weights.len(), 2);
+        assert!(weights.iter().all(|(name, w)| (name == "walk_w" || name == "walk_s") && (w - 0.5).abs() < 1e-5), "{:?}", weights);
+    }
+
+    #[test]
+    fn midpoint_parameter_blends_rotations_halfway() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .add_systems(Update, (apply_blend_tree2d_requests, evaluate_blend_trees_2d).chain());
+
+        let mut state = Skeleton2dBlendState::default();
+        for (name, rotation) in [("walk_n", 50.0), ("walk_e", 10.0), ("walk_s", -90.0), ("walk_w", 170.0)] {
+            state.clips.insert(name.to_string(), pose_clip(name, rotation));
+        }
+        let skeleton = SkeletonData2d { bones: vec![hip()], ..Default::default() };
+        let entity = app.world_mut().spawn((EntityId("hero".to_string()), skeleton, state)).id();
+
+        {
+            let mut pending = app.world_mut().resource_mut::<PendingCommands>();
+            pending.set_blend_tree2d_requests.push(SetBlendTree2dRequest { entity_id: "hero".to_string(), tree: locomotion() });
+            pending.set_blend_param2d_requests.push(SetBlendParam2dRequest {
+                entity_id: "hero".to_string(),
+                name: "move".to_string(),
+                value: [0.5, 0.5],
+            });
+        }
+        app.update();
+        app.update();
+
+        let rotation = app.world().get::<SkeletonData2d>(entity).unwrap().bones[0].local_rotation;
+        assert!((rotation - 30.0).abs() < 1e-3, "hip at {}", rotation);
+    }
+}
@@ -1,16 +1,23 @@
 //! Camera controls for the editor viewport.
 //!
 //! Uses `bevy_panorbit_camera` for orbit, pan, and zoom functionality.
-//! Adds focus-on-selection (F key) and command integration.
+//! Adds frame-selection (F key) and command integration.
 
+use bevy::camera::primitives::Aabb;
 use bevy::prelude::*;
 use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
 
 use super::camera_presets::{self, CameraViewState};
-use super::entity_id::EntityId;
-use super::pending_commands::PendingCommands;
+use super::entity_id::{EntityId, EntityVisible};
+use super::pending_commands::{FrameSelectionRequest, PendingCommands};
 use super::selection::Selection;
 
+/// Margin around framed bounds so they don't touch the viewport edges.
+const FRAME_PADDING: f32 = 1.1;
+/// Smallest bounding radius to frame, so a light or a tiny mesh doesn't put
+/// the camera on top of it.
+const MIN_FRAME_EXTENT: f32 = 0.5;
+
 /// Plugin that adds camera control functionality.
 pub struct CameraControlPlugin;
 
@@ -21,9 +28,11 @@ impl Plugin for CameraControlPlugin {
             .add_systems(Update, (
                 apply_pending_camera_focus,
                 apply_pending_camera_orbit,
+                apply_frame_selection_requests,
                 focus_on_selection_system,
                 camera_presets::camera_preset_keyboard_system,
                 camera_presets::apply_camera_preset_system,
+                camera_presets::apply_camera_projection_system,
                 camera_presets::detect_animation_complete_system,
                 camera_presets::detect_manual_orbit_system,
             ));
@@ -91,26 +100,106 @@ fn apply_pending_camera_orbit(
     }
 }
 
-/// System that handles 'F' key to focus on selected entity.
-fn focus_on_selection_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+/// Entities the frame-selection system can fit into view.
+type FrameableQuery<'w, 's> = Query<'w, 's,
+    (Entity, &'static GlobalTransform, Option<&'static Aabb>, Option<&'static EntityVisible>),
+    With<EntityId>,
+>;
+
+/// System that frames the selected entities, or every visible entity when
+/// nothing is selected. The focus moves to the center of their combined world
+/// bounds and the radius is set so those bounds fit the camera's view. Only
+/// the last request of a frame is applied.
+fn apply_frame_selection_requests(
+    mut pending: ResMut<PendingCommands>,
     selection: Res<Selection>,
-    entity_query: Query<(&EntityId, &Transform)>,
-    mut camera_query: Query<&mut PanOrbitCamera, With<EditorCamera>>,
+    entity_query: FrameableQuery,
+    mut camera_query: Query<(&mut PanOrbitCamera, Option<&Projection>), With<EditorCamera>>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyF) {
-        if let Some(primary_id) = &selection.primary_id {
-            // Find the selected entity's transform
-            if let Some((_, transform)) = entity_query
-                .iter()
-                .find(|(eid, _)| &eid.0 == primary_id)
-            {
-                // Update camera focus target
-                if let Ok(mut camera) = camera_query.single_mut() {
-                    camera.target_focus = transform.translation;
-                }
+    let Some(request) = pending.frame_selection_requests.drain(..).next_back() else {
+        return;
+    };
+    let Ok((mut camera, projection)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let whole_scene = selection.entities.is_empty();
+    let bounds = entity_query
+        .iter()
+        .filter(|(entity, _, _, visible)| {
+            if whole_scene {
+                visible.is_none_or(|v| v.0)
+            } else {
+                selection.is_selected(*entity)
             }
+        })
+        .map(|(_, global, aabb, _)| world_bounds(global, aabb))
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)));
+    let Some((min, max)) = bounds else {
+        return;
+    };
+
+    let focus = (min + max) * 0.5;
+    let extent = ((max - min) * 0.5).length().max(MIN_FRAME_EXTENT) * FRAME_PADDING;
+    let radius = frame_radius(extent, projection);
+
+    camera.target_focus = focus;
+    camera.target_radius = radius;
+    if !request.animate {
+        camera.focus = focus;
+        camera.radius = Some(radius);
+        camera.force_update = true;
+    }
+}
+
+/// World-space corners of an entity's bounds, or its origin when it has none.
+fn world_bounds(global: &GlobalTransform, aabb: Option<&Aabb>) -> (Vec3, Vec3) {
+    let Some(aabb) = aabb else {
+        let origin = global.translation();
+        return (origin, origin);
+    };
+    let (center, half) = (Vec3::from(aabb.center), Vec3::from(aabb.half_extents));
+    (0..8)
+        .map(|corner| {
+            let sign = Vec3::new(
+                if corner & 1 == 0 { -1.0 } else { 1.0 },
+                if corner & 2 == 0 { -1.0 } else { 1.0 },
+                if corner & 4 == 0 { -1.0 } else { 1.0 },
+            );
+            global.transform_point(center + half * sign)
+        })
+        .fold((Vec3::INFINITY, Vec3::NEG_INFINITY), |(lo, hi), p| (lo.min(p), hi.max(p)))
+}
+
+/// Orbit radius at which a sphere of radius `extent` fits the view along its
+/// narrower axis. PanOrbitCamera treats the radius as the view height in
+/// orthographic mode.
+fn frame_radius(extent: f32, projection: Option<&Projection>) -> f32 {
+    match projection {
+        Some(Projection::Orthographic(ortho)) => {
+            let aspect = if ortho.area.height() > 0.0 { ortho.area.width() / ortho.area.height() } else { 1.0 };
+            2.0 * extent / aspect.min(1.0)
         }
+        Some(Projection::Perspective(perspective)) => perspective_distance(extent, perspective),
+        _ => perspective_distance(extent, &PerspectiveProjection::default()),
+    }
+}
+
+/// Distance from which a sphere of radius `extent` fills a perspective view.
+fn perspective_distance(extent: f32, perspective: &PerspectiveProjection) -> f32 {
+    let half_vertical = perspective.fov * 0.5;
+    let half_horizontal = (half_vertical.tan() * perspective.aspect_ratio).atan();
+    extent / half_vertical.min(half_horizontal).sin()
+}
+
+/// System that handles the 'F' key: frame the selection, or the whole scene
+/// when nothing is selected.
+fn focus_on_selection_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut pending: ResMut<PendingCommands>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        pending.queue_frame_selection(FrameSelectionRequest { animate: true });
     }
 }
 
@@ -136,3 +225,45 @@ pub fn focus_camera_on_entity(
         Err("No editor camera found".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn framing_two_far_apart_cubes_centers_between_them() {
+        let mut app = App::new();
+        app.init_resource::<PendingCommands>()
+            .init_resource::<Selection>()
+            .add_systems(Update, apply_frame_selection_requests);
+        let camera = app.world_mut().spawn((
+            EditorCamera,
+            PanOrbitCamera { radius: Some(5.0), target_radius: 5.0, ..default() },
+            Projection::Perspective(PerspectiveProjection::default()),
+        )).id();
+
+        let cube = Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5));
+        for (id, x) in [("left", -10.0), ("right", 30.0)] {
+            let entity = app.world_mut().spawn((
+                EntityId(id.to_string()),
+                GlobalTransform::from_translation(Vec3::new(x, 2.0, 0.0)),
+                cube,
+            )).id();
+            app.world_mut().resource_mut::<Selection>().add(entity, id.to_string());
+        }
+        // Unselected entities are left out of the framing.
+        app.world_mut().spawn((EntityId("far".to_string()), GlobalTransform::from_translation(Vec3::splat(500.0))));
+
+        app.world_mut().resource_mut::<PendingCommands>()
+            .queue_frame_selection(FrameSelectionRequest { animate: false });
+        app.update();
+
+        let orbit = app.world().get::<PanOrbitCamera>(camera).expect("camera");
+        assert!(orbit.focus.abs_diff_eq(Vec3::new(10.0, 2.0, 0.0), 1e-4), "focus {:?}", orbit.focus);
+        assert_eq!(orbit.target_focus, orbit.focus);
+        // Both cubes, 20.5 units from the focus, must fit in the view cone.
+        let fov = PerspectiveProjection::default().fov;
+        let radius = orbit.radius.expect("radius");
+        assert!(radius * (fov * 0.5).tan() > 20.5, "radius {radius}");
+    }
+}
@@ -1,8 +1,16 @@
 //! 2D camera configuration.
+//!
+//! In Play mode the managed camera can follow a target entity, and sprites
+//! with a `parallax_factor` below 1 are dragged along with it so background
+//! layers appear farther away.
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::engine_mode::EngineMode;
+use super::entity_id::EntityId;
+use super::sprite::SpriteData;
+
 /// Camera configuration for 2D projects.
 #[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Camera2dData {
@@ -12,6 +20,9 @@ pub struct Camera2dData {
     pub pixel_perfect: bool,
     /// Optional camera movement bounds
     pub bounds: Option<CameraBounds>,
+    /// Entity the camera centers on during Play mode
+    #[serde(default)]
+    pub follow_target_id: Option<String>,
 }
 
 /// Bounds for restricting camera movement.
@@ -29,6 +40,7 @@ impl Default for Camera2dData {
             zoom: 1.0,
             pixel_perfect: false,
             bounds: None,
+            follow_target_id: None,
         }
     }
 }
@@ -41,3 +53,104 @@ pub struct Camera2dEnabled;
 /// This is placed on the Bevy Camera entity that renders 2D content.
 #[derive(Component)]
 pub struct Managed2dCamera;
+
+/// Offset that keeps a sprite with `parallax_factor` at the right depth when
+/// the camera moves by `camera_delta`: 1 stays put in the world, 0 moves with
+/// the camera.
+pub fn parallax_offset(camera_delta: Vec2, parallax_factor: f32) -> Vec2 {
+    camera_delta * (1.0 - parallax_factor)
+}
+
+/// System that centers the managed 2D camera on its follow target in Play
+/// mode. The camera's Edit position is put back when returning to Edit.
+pub fn follow_camera_2d_target(
+    mode: Res<EngineMode>,
+    mut edit_position: Local<Option<Vec3>>,
+    mut cameras: Query<(&Camera2dData, &mut Transform), With<Managed2dCamera>>,
+    targets: Query<(&EntityId, &Transform), Without<Managed2dCamera>>,
+) {
+    if mode.is_edit() {
+        if let Some(position) = edit_position.take() {
+            for (_, mut transform) in cameras.iter_mut() {
+                transform.translation = position;
+            }
+        }
+        return;
+    }
+    if !mode.is_playing() {
+        return;
+    }
+
+    for (camera, mut transform) in cameras.iter_mut() {
+        let Some(target_id) = camera.follow_target_id.as_deref() else { continue };
+        let Some((_, target)) = targets.iter().find(|(eid, _)| eid.0 == target_id) else { continue };
+        edit_position.get_or_insert(transform.translation);
+        transform.translation.x = target.translation.x;
+        transform.translation.y = target.translation.y;
+    }
+}
+
+/// System that moves parallax sprites by their share of the camera's
+/// movement since the previous Play frame.
+pub fn apply_sprite_parallax(
+    mode: Res<EngineMode>,
+    mut last_camera: Local<Option<Vec2>>,
+    cameras: Query<&Transform, With<Managed2dCamera>>,
+    mut sprites: Query<(&SpriteData, &mut Transform), Without<Managed2dCamera>>,
+) {
+    if !mode.is_playing() {
+        if mode.is_edit() {
+            *last_camera = None;
+        }
+        return;
+    }
+    let Ok(camera) = cameras.single() else { return };
+    let position = camera.translation.truncate();
+    let Some(previous) = last_camera.replace(position) else { return };
+    let delta = position - previous;
+    if delta == Vec2::ZERO {
+        return;
+    }
+
+    for (sprite, mut transform) in sprites.iter_mut() {
+        if sprite.parallax_factor == 1.0 {
+            continue;
+        }
+        let offset = parallax_offset(delta, sprite.parallax_factor);
+        transform.translation.x += offset.x;
+        transform.translation.y += offset.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_parallax_sprite_moves_half_as_far_as_the_camera() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(EngineMode::Play)
+            .add_systems(Update, (follow_camera_2d_target, apply_sprite_parallax).chain());
+        let camera_data = Camera2dData { follow_target_id: Some("hero".to_string()), ..default() };
+        app.world_mut().spawn((Managed2dCamera, camera_data, Transform::default()));
+        let hero = app.world_mut().spawn((EntityId("hero".to_string()), Transform::default())).id();
+        let background = app.world_mut()
+            .spawn((SpriteData { parallax_factor: 0.5, ..default() }, Transform::default()))
+            .id();
+        let foreground = app.world_mut().spawn((SpriteData::default(), Transform::default())).id();
+        app.update();
+
+        app.world_mut().get_mut::<Transform>(hero).unwrap().translation = Vec3::new(4.0, -2.0, 0.0);
+        app.update();
+        let moved = app.world().get::<Transform>(background).unwrap().translation;
+        assert!(moved.abs_diff_eq(Vec3::new(2.0, -1.0, 0.0), 1e-5), "{moved:?}");
+        assert_eq!(app.world().get::<Transform>(foreground).unwrap().translation, Vec3::ZERO);
+
+        // Stopping puts the camera back where the editor left it
+        *app.world_mut().resource_mut::<EngineMode>() = EngineMode::Edit;
+        app.update();
+        let mut cameras = app.world_mut().query_filtered::<&Transform, With<Managed2dCamera>>();
+        assert_eq!(cameras.single(app.world()).unwrap().translation, Vec3::ZERO);
+    }
+}
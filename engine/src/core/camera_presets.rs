@@ -1,8 +1,10 @@
 //! Camera preset views for quick viewport navigation.
 //!
 //! Provides preset camera angles (Top, Front, Right, Perspective) with smooth
-//! animated transitions using bevy_panorbit_camera's built-in interpolation.
+//! animated transitions using bevy_panorbit_camera's built-in interpolation,
+//! and switching the editor camera between perspective and orthographic.
 
+use bevy::camera::ScalingMode;
 use bevy::prelude::*;
 use bevy_panorbit_camera::PanOrbitCamera;
 use serde::{Deserialize, Serialize};
@@ -58,6 +60,14 @@ impl CameraPreset {
         }
     }
 
+    /// Projection the preset switches to: axis-aligned views are orthographic.
+    pub fn default_projection(&self) -> CameraProjectionMode {
+        match self {
+            CameraPreset::Top | CameraPreset::Front | CameraPreset::Right => CameraProjectionMode::Orthographic,
+            CameraPreset::Perspective => CameraProjectionMode::Perspective,
+        }
+    }
+
     /// Human-readable name for UI display.
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -69,6 +79,25 @@ impl CameraPreset {
     }
 }
 
+/// Editor camera projection mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CameraProjectionMode {
+    #[default]
+    Perspective,
+    Orthographic,
+}
+
+impl CameraProjectionMode {
+    /// Mode of a camera's current projection.
+    pub fn of(projection: &Projection) -> Self {
+        match projection {
+            Projection::Orthographic(_) => CameraProjectionMode::Orthographic,
+            _ => CameraProjectionMode::Perspective,
+        }
+    }
+}
+
 /// Resource tracking the current camera view state.
 #[derive(Resource, Debug, Clone)]
 pub struct CameraViewState {
@@ -77,7 +106,6 @@ pub struct CameraViewState {
     pub current_preset: Option<CameraPreset>,
 
     /// Whether the camera is in orthographic projection mode.
-    /// (Future enhancement - initially always false)
     pub is_orthographic: bool,
 
     /// Whether an animation is currently in progress.
@@ -99,9 +127,13 @@ impl Default for CameraViewState {
 const PRESET_ORBIT_SMOOTHNESS: f32 = 0.8;
 const PRESET_ZOOM_SMOOTHNESS: f32 = 0.8;
 
+/// Editor camera query shared by the preset and projection systems.
+type EditorCameraQuery<'w, 's> =
+    Query<'w, 's, (&'static mut PanOrbitCamera, &'static mut Projection), With<EditorCamera>>;
+
 /// System that handles applying camera presets from pending commands.
 pub fn apply_camera_preset_system(
-    mut camera_query: Query<&mut PanOrbitCamera, With<EditorCamera>>,
+    mut camera_query: EditorCameraQuery,
     mut view_state: ResMut<CameraViewState>,
     mut pending: ResMut<PendingCommands>,
 ) {
@@ -113,11 +145,13 @@ pub fn apply_camera_preset_system(
 
 /// Apply a preset to the camera.
 fn apply_preset_to_camera(
-    camera_query: &mut Query<&mut PanOrbitCamera, With<EditorCamera>>,
+    camera_query: &mut EditorCameraQuery,
     view_state: &mut CameraViewState,
     preset: CameraPreset,
 ) {
-    if let Ok(mut camera) = camera_query.single_mut() {
+    if let Ok((mut camera, mut projection)) = camera_query.single_mut() {
+        set_projection(&mut camera, &mut projection, view_state, preset.default_projection(), None);
+
         // Set target values for smooth animation
         camera.target_yaw = preset.target_yaw();
         camera.target_pitch = preset.target_pitch();
@@ -143,7 +177,7 @@ fn apply_preset_to_camera(
 /// System that handles keyboard shortcuts for camera presets.
 pub fn camera_preset_keyboard_system(
     keyboard: Res<ButtonInput<KeyCode>>,
-    mut camera_query: Query<&mut PanOrbitCamera, With<EditorCamera>>,
+    mut camera_query: EditorCameraQuery,
     mut view_state: ResMut<CameraViewState>,
 ) {
     // Check for numpad keys
@@ -183,6 +217,64 @@ pub fn camera_preset_keyboard_system(
     }
 }
 
+/// System that applies pending projection switches from bridge commands.
+pub fn apply_camera_projection_system(
+    mut camera_query: EditorCameraQuery,
+    mut view_state: ResMut<CameraViewState>,
+    mut pending: ResMut<PendingCommands>,
+) {
+    for request in pending.camera_projection_requests.drain(..) {
+        if let Ok((mut camera, mut projection)) = camera_query.single_mut() {
+            set_projection(&mut camera, &mut projection, &mut view_state, request.mode, request.ortho_scale);
+        }
+    }
+}
+
+/// Vertical field of view used when converting between projections.
+fn perspective_fov(projection: &Projection) -> f32 {
+    match projection {
+        Projection::Perspective(p) => p.fov,
+        _ => PerspectiveProjection::default().fov,
+    }
+}
+
+/// Swap the camera's projection, keeping yaw, pitch, and focus. PanOrbitCamera
+/// treats `radius` as the ortho scale in orthographic mode, so the orbit
+/// distance is converted to the view height that frames the focus the same
+/// way, and back. `ortho_scale` overrides the converted view height.
+fn set_projection(
+    camera: &mut PanOrbitCamera,
+    projection: &mut Projection,
+    view_state: &mut CameraViewState,
+    mode: CameraProjectionMode,
+    ortho_scale: Option<f32>,
+) {
+    let fov = perspective_fov(projection);
+    let view_height_per_unit = 2.0 * (fov / 2.0).tan();
+    let radius = camera.radius.unwrap_or(camera.target_radius);
+
+    let new_radius = match (CameraProjectionMode::of(projection), mode) {
+        (CameraProjectionMode::Perspective, CameraProjectionMode::Orthographic) => {
+            *projection = Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical { viewport_height: 1.0 },
+                ..OrthographicProjection::default_3d()
+            });
+            ortho_scale.unwrap_or(radius * view_height_per_unit)
+        }
+        (CameraProjectionMode::Orthographic, CameraProjectionMode::Perspective) => {
+            *projection = Projection::Perspective(PerspectiveProjection { fov, ..default() });
+            radius / view_height_per_unit
+        }
+        (_, CameraProjectionMode::Orthographic) => ortho_scale.unwrap_or(radius),
+        (_, CameraProjectionMode::Perspective) => radius,
+    };
+
+    camera.radius = Some(new_radius);
+    camera.target_radius = new_radius;
+    camera.force_update = true;
+    view_state.is_orthographic = mode == CameraProjectionMode::Orthographic;
+}
+
 /// System that detects when camera animation completes.
 pub fn detect_animation_complete_system(
     camera_query: Query<&PanOrbitCamera, With<EditorCamera>>,
@@ -264,3 +356,59 @@ fn emit_view_preset_changed_cleared() {
 /// No-op on non-wasm targets.
 #[cfg(not(target_arch = "wasm32"))]
 fn emit_view_preset_changed_cleared() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending_commands::CameraProjectionRequest;
+
+    fn camera_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<PendingCommands>()
+            .init_resource::<CameraViewState>()
+            .add_systems(Update, (apply_camera_projection_system, apply_camera_preset_system));
+        let camera = app.world_mut().spawn((
+            EditorCamera,
+            PanOrbitCamera { radius: Some(8.0), target_radius: 8.0, ..default() },
+            Projection::Perspective(PerspectiveProjection::default()),
+        )).id();
+        (app, camera)
+    }
+
+    fn request_projection(app: &mut App, mode: CameraProjectionMode) {
+        app.world_mut().resource_mut::<PendingCommands>()
+            .queue_camera_projection(CameraProjectionRequest { mode, ortho_scale: None });
+        app.update();
+    }
+
+    #[test]
+    fn switching_to_ortho_changes_projection_variant() {
+        let (mut app, camera) = camera_app();
+
+        request_projection(&mut app, CameraProjectionMode::Orthographic);
+        let projection = app.world().get::<Projection>(camera).expect("projection");
+        assert!(matches!(projection, Projection::Orthographic(_)));
+        assert!(app.world().resource::<CameraViewState>().is_orthographic);
+
+        // Switching back restores the orbit distance.
+        request_projection(&mut app, CameraProjectionMode::Perspective);
+        let projection = app.world().get::<Projection>(camera).expect("projection");
+        assert!(matches!(projection, Projection::Perspective(_)));
+        let radius = app.world().get::<PanOrbitCamera>(camera).and_then(|c| c.radius).expect("radius");
+        assert!((radius - 8.0).abs() < 1e-4, "radius {radius}");
+    }
+
+    #[test]
+    fn axis_presets_default_to_orthographic() {
+        let (mut app, camera) = camera_app();
+        app.world_mut().resource_mut::<PendingCommands>()
+            .queue_camera_preset(crate::core::pending_commands::CameraPresetRequest { preset: CameraPreset::Front });
+        app.update();
+        assert!(matches!(app.world().get::<Projection>(camera), Some(Projection::Orthographic(_))));
+
+        app.world_mut().resource_mut::<PendingCommands>()
+            .queue_camera_preset(crate::core::pending_commands::CameraPresetRequest { preset: CameraPreset::Perspective });
+        app.update();
+        assert!(matches!(app.world().get::<Projection>(camera), Some(Projection::Perspective(_))));
+    }
+}
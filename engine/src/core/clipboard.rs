@@ -0,0 +1,398 @@
+//! Entity clipboard: copy entities out of one scene and paste them into another.
+//!
+//! A copy is a self-contained JSON blob holding snapshots of the copied
+//! entities and their descendants, plus the metadata of every asset they
+//! reference, so pasting into a fresh project doesn't leave dangling asset
+//! refs. Pasting assigns fresh IDs and is undone as a single action.
+//!
+//! Prefabs are created from the same subtree snapshots (see `prefab`).
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::asset_manager::{AssetMetadata, AssetRegistry};
+use super::entity_factory::{self, snapshot_entity, AuxComponentData, AuxQueries, SnapshotBase, SnapshotData, Undeletable};
+use super::history::{EntitySnapshot, HistoryStack, UndoableAction};
+use super::pending_commands::{EntityType, PendingCommands};
+use super::prefab::{LibraryPrefab, PrefabInstance, PrefabLibrary, PrefabSnapshot};
+use super::scene_file::{remap_for_merge, MAX_SCENE_JSON_BYTES, SCENE_FORMAT_VERSION};
+use super::selection::Selection;
+
+/// Serialized clipboard contents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardData {
+    pub format_version: u32,
+    /// Parents come before their children.
+    pub entities: Vec<EntitySnapshot>,
+    #[serde(default)]
+    pub assets: HashMap<String, AssetMetadata>,
+}
+
+impl ClipboardData {
+    /// Bundle `entities` with the registry entries for every asset they reference.
+    pub fn new(entities: Vec<EntitySnapshot>, registry: &AssetRegistry) -> Self {
+        let assets = referenced_assets(&entities, registry);
+        Self { format_version: SCENE_FORMAT_VERSION, entities, assets }
+    }
+}
+
+/// Registry entries for every asset referenced by `entities`.
+pub fn referenced_assets(entities: &[EntitySnapshot], registry: &AssetRegistry) -> HashMap<String, AssetMetadata> {
+    let mut ids = HashSet::new();
+    for snapshot in entities {
+        if let Ok(value) = serde_json::to_value(snapshot) {
+            collect_strings(&value, &mut ids);
+        }
+    }
+    registry
+        .assets
+        .iter()
+        .filter(|(id, _)| ids.contains(id.as_str()))
+        .map(|(id, metadata)| (id.clone(), metadata.clone()))
+        .collect()
+}
+
+/// Collect every string in a JSON value. Asset references (asset refs,
+/// texture slots, audio clips, splat layers...) are all stored as asset ID
+/// strings, so this finds them without listing each field.
+fn collect_strings(value: &serde_json::Value, out: &mut HashSet<String>) {
+    match value {
+        serde_json::Value::String(s) => {
+            out.insert(s.clone());
+        }
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_strings(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+/// The last copy made in this session, pasted when `paste_entities` has no JSON.
+#[derive(Resource, Debug, Default)]
+pub struct EntityClipboard {
+    pub json: Option<String>,
+}
+
+/// Everything the copy system reads from the copied entities.
+#[derive(SystemParam)]
+pub struct CopySources<'w, 's> {
+    query: Query<'w, 's, SnapshotData, Without<Undeletable>>,
+    hierarchy: Query<'w, 's, (Option<&'static ChildOf>, Option<&'static Children>)>,
+    aux: AuxQueries<'w, 's>,
+}
+
+/// System that snapshots copied entities and their descendants. New prefabs
+/// and prefab libraries are snapshotted here too, since they need the same
+/// queries.
+pub fn apply_copy_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut clipboard: ResMut<EntityClipboard>,
+    asset_registry: Res<AssetRegistry>,
+    selection: Res<Selection>,
+    sources: CopySources,
+) {
+    if pending.copy_requests.is_empty()
+        && pending.create_prefab_requests.is_empty()
+        && pending.export_prefab_library_requests.is_empty()
+    {
+        return;
+    }
+
+    let id_to_entity: HashMap<&str, Entity> = sources.query.iter().map(|row| (row.1 .0.as_str(), row.0)).collect();
+    let aux_index = sources.aux.build_index();
+    let empty_aux = AuxComponentData::default();
+
+    // Snapshot the given entities and their descendants, parents first
+    let snapshot_subtrees = |entity_ids: &[String]| -> Vec<EntitySnapshot> {
+        // Breadth-first from each requested root so parents precede children
+        let mut order: Vec<Entity> = Vec::new();
+        let mut seen: HashSet<Entity> = HashSet::new();
+        let mut queue: std::collections::VecDeque<Entity> = entity_ids
+            .iter()
+            .filter_map(|id| id_to_entity.get(id.as_str()).copied())
+            .collect();
+        while let Some(entity) = queue.pop_front() {
+            if !seen.insert(entity) {
+                continue;
+            }
+            order.push(entity);
+            if let Ok((_, Some(children))) = sources.hierarchy.get(entity) {
+                queue.extend(children.iter());
+            }
+        }
+
+        let mut snapshots = Vec::with_capacity(order.len());
+        for &entity in &order {
+            let Ok((_, eid, name, transform, visible, ent_type, mat_data, light_data, phys_data, phys_enabled, asset_ref)) = sources.query.get(entity) else {
+                continue;
+            };
+            let aux = aux_index.get(&eid.0).unwrap_or(&empty_aux);
+            let base = SnapshotBase {
+                mat_data, light_data, phys_data, phys_enabled: phys_enabled.is_some(), asset_ref,
+            };
+            let entity_type = ent_type.copied().unwrap_or(EntityType::Cube);
+            let mut snapshot = snapshot_entity(&eid.0, entity_type, &name.0, transform, visible.0, base, aux);
+            // Only keep parent links inside the snapshotted set
+            snapshot.parent_id = sources.hierarchy
+                .get(entity)
+                .ok()
+                .and_then(|(child_of, _)| child_of)
+                .filter(|child_of| seen.contains(&child_of.parent()))
+                .and_then(|child_of| sources.query.get(child_of.parent()).ok())
+                .map(|row| row.1 .0.clone());
+            snapshots.push(snapshot);
+        }
+        snapshots
+    };
+
+    for request in pending.copy_requests.drain(..) {
+        let snapshots = snapshot_subtrees(&request.entity_ids);
+        if snapshots.is_empty() {
+            tracing::warn!("copy_entities: none of {:?} exist", request.entity_ids);
+            continue;
+        }
+
+        let data = ClipboardData::new(snapshots, &asset_registry);
+        match serde_json::to_string(&data) {
+            Ok(json) => {
+                tracing::info!("Copied {} entities ({} assets)", data.entities.len(), data.assets.len());
+                #[cfg(target_arch = "wasm32")]
+                crate::bridge::events::emit_entities_copied(&json, data.entities.len());
+                clipboard.json = Some(json);
+            }
+            Err(e) => tracing::error!("Failed to serialize clipboard: {}", e),
+        }
+    }
+
+    for request in pending.create_prefab_requests.drain(..) {
+        let mut snapshots = snapshot_subtrees(std::slice::from_ref(&request.entity_id));
+        let Some(root) = snapshots.first_mut() else {
+            tracing::warn!("create_prefab_from_entity: entity {} does not exist", request.entity_id);
+            continue;
+        };
+        // The prefab's root is the template, not an instance of another prefab
+        root.prefab_instance = None;
+
+        let prefab = PrefabSnapshot::new(snapshots);
+        let prefab_id = uuid::Uuid::new_v4().to_string();
+        if request.link_source {
+            if let Some(&entity) = id_to_entity.get(request.entity_id.as_str()) {
+                commands.entity(entity).insert(PrefabInstance::new(prefab_id.clone()));
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        match serde_json::to_string(&prefab) {
+            Ok(json) => crate::bridge::events::emit_prefab_created(&prefab_id, &request.prefab_name, &request.entity_id, &json),
+            Err(e) => tracing::error!("Failed to serialize prefab: {}", e),
+        }
+        tracing::info!("Created prefab '{}' from {} entities", request.prefab_name, prefab.entities.len());
+    }
+
+    for request in pending.export_prefab_library_requests.drain(..) {
+        let candidates: HashSet<Entity> = match &request.entity_ids {
+            Some(ids) => ids.iter().filter_map(|id| id_to_entity.get(id.as_str()).copied()).collect(),
+            None => selection.entities.clone(),
+        };
+        // Only top-level entities become prefabs; selected descendants ride along
+        let is_top_level = |entity: Entity| {
+            let mut current = entity;
+            while let Ok((Some(child_of), _)) = sources.hierarchy.get(current) {
+                if candidates.contains(&child_of.parent()) {
+                    return false;
+                }
+                current = child_of.parent();
+            }
+            true
+        };
+        let mut roots: Vec<(&str, Entity)> = id_to_entity
+            .iter()
+            .filter(|(_, entity)| candidates.contains(entity) && is_top_level(**entity))
+            .map(|(id, entity)| (*id, *entity))
+            .collect();
+        roots.sort_by_key(|(_, entity)| entity.index_u32());
+
+        let prefabs: Vec<LibraryPrefab> = roots
+            .iter()
+            .filter_map(|(id, _)| {
+                let mut snapshots = snapshot_subtrees(&[id.to_string()]);
+                let root = snapshots.first_mut()?;
+                root.prefab_instance = None;
+                let name = root.name.clone();
+                Some(LibraryPrefab::new(name, PrefabSnapshot::new(snapshots)))
+            })
+            .collect();
+        if prefabs.is_empty() {
+            tracing::warn!("export_prefab_library: nothing selected to export");
+            continue;
+        }
+
+        let library = PrefabLibrary::new(prefabs, &asset_registry);
+        #[cfg(target_arch = "wasm32")]
+        match serde_json::to_string(&library) {
+            Ok(json) => crate::bridge::events::emit_prefab_library_exported(&json, library.prefabs.len()),
+            Err(e) => tracing::error!("Failed to serialize prefab library: {}", e),
+        }
+        tracing::info!("Exported prefab library ({} prefabs, {} assets)", library.prefabs.len(), library.assets.len());
+    }
+}
+
+/// System that pastes clipboard JSON with fresh IDs as one undoable action.
+pub fn apply_paste_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut history: ResMut<HistoryStack>,
+    mut asset_registry: ResMut<AssetRegistry>,
+    clipboard: Res<EntityClipboard>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for request in pending.paste_requests.drain(..) {
+        let Some(json) = request.json.as_ref().or(clipboard.json.as_ref()) else {
+            tracing::warn!("paste_entities: clipboard is empty");
+            continue;
+        };
+        if json.len() > MAX_SCENE_JSON_BYTES {
+            tracing::error!("Paste rejected: JSON payload {} bytes exceeds 50MB limit", json.len());
+            continue;
+        }
+        let data: ClipboardData = match serde_json::from_str(json) {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::error!("Failed to deserialize clipboard: {}", e);
+                continue;
+            }
+        };
+        if data.format_version > SCENE_FORMAT_VERSION {
+            tracing::error!("Unsupported clipboard format version: {}", data.format_version);
+            continue;
+        }
+        if data.entities.is_empty() {
+            continue;
+        }
+
+        for (asset_id, metadata) in data.assets {
+            asset_registry.assets.entry(asset_id).or_insert(metadata);
+        }
+
+        let snapshots = remap_for_merge(data.entities, request.offset, None);
+        let mut id_to_entity: HashMap<&str, Entity> = HashMap::new();
+        for snap in &snapshots {
+            let entity = entity_factory::spawn_from_snapshot(&mut commands, &mut meshes, &mut materials, snap);
+            id_to_entity.insert(snap.entity_id.as_str(), entity);
+        }
+        for snap in &snapshots {
+            if let Some(ref parent_id) = snap.parent_id {
+                if let (Some(&child), Some(&parent)) =
+                    (id_to_entity.get(snap.entity_id.as_str()), id_to_entity.get(parent_id.as_str()))
+                {
+                    commands.entity(child).insert(ChildOf(parent));
+                }
+            }
+        }
+
+        let created_ids: Vec<String> = snapshots.iter().map(|s| s.entity_id.clone()).collect();
+        tracing::info!("Pasted {} entities", created_ids.len());
+        history.push(UndoableAction::PasteEntities { created_snapshots: snapshots });
+
+        #[cfg(target_arch = "wasm32")]
+        crate::bridge::events::emit_entities_pasted(&created_ids);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asset_manager::{AssetKind, AssetSource};
+    use crate::core::entity_id::{EntityId, EntityName, EntityVisible};
+    use crate::core::material::MaterialData;
+    use crate::core::pending_commands::{CopyEntitiesRequest, PasteEntitiesRequest};
+
+    fn clipboard_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<AssetRegistry>()
+            .init_resource::<EntityClipboard>()
+            .init_resource::<Selection>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, (apply_copy_requests, apply_paste_requests).chain());
+        app
+    }
+
+    fn spawn_cube(app: &mut App, id: &str) -> Entity {
+        app.world_mut()
+            .spawn((EntityType::Cube, EntityId(id.to_string()), EntityName::new(id), EntityVisible::default(), Transform::IDENTITY))
+            .id()
+    }
+
+    #[test]
+    fn clipboard_bundles_referenced_assets_only() {
+        let metadata = |id: &str| AssetMetadata {
+            id: id.to_string(),
+            name: id.to_string(),
+            kind: AssetKind::Texture,
+            file_size: 1,
+            source: AssetSource::Upload { filename: format!("{id}.png") },
+        };
+        let mut registry = AssetRegistry::default();
+        registry.assets.insert("bricks".to_string(), metadata("bricks"));
+        registry.assets.insert("unused".to_string(), metadata("unused"));
+
+        let mut snapshot = EntitySnapshot::new(
+            "wall".to_string(),
+            EntityType::Cube,
+            "Wall".to_string(),
+            crate::core::history::TransformSnapshot::from(&Transform::IDENTITY),
+        );
+        snapshot.material_data = Some(MaterialData { base_color_texture: Some("bricks".to_string()), ..Default::default() });
+
+        let data = ClipboardData::new(vec![snapshot], &registry);
+        assert_eq!(data.assets.keys().collect::<Vec<_>>(), vec!["bricks"]);
+    }
+
+    #[test]
+    fn copy_and_paste_parent_creates_fresh_subtree() {
+        let mut app = clipboard_app();
+        let parent = spawn_cube(&mut app, "parent");
+        let children = [spawn_cube(&mut app, "child-a"), spawn_cube(&mut app, "child-b")];
+        app.world_mut().entity_mut(parent).add_children(&children);
+        spawn_cube(&mut app, "unrelated");
+
+        app.world_mut().resource_mut::<PendingCommands>().copy_requests.push(CopyEntitiesRequest {
+            entity_ids: vec!["parent".to_string()],
+        });
+        app.update();
+        let json = app.world().resource::<EntityClipboard>().json.clone().expect("copied");
+        assert_eq!(serde_json::from_str::<ClipboardData>(&json).unwrap().entities.len(), 3);
+
+        app.world_mut().resource_mut::<PendingCommands>().paste_requests.push(PasteEntitiesRequest {
+            json: Some(json),
+            offset: Some(Vec3::X),
+        });
+        app.update();
+
+        let original = ["parent", "child-a", "child-b", "unrelated"];
+        let pasted: Vec<(Entity, String)> = app
+            .world_mut()
+            .query::<(Entity, &EntityId)>()
+            .iter(app.world())
+            .filter(|(_, id)| !original.contains(&id.0.as_str()))
+            .map(|(e, id)| (e, id.0.clone()))
+            .collect();
+        assert_eq!(pasted.len(), 3);
+
+        let roots: Vec<Entity> = pasted.iter().map(|(e, _)| *e).filter(|e| app.world().get::<ChildOf>(*e).is_none()).collect();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(app.world().get::<Children>(roots[0]).map(|c| c.len()), Some(2));
+        assert_eq!(app.world().get::<Transform>(roots[0]).unwrap().translation, Vec3::X);
+
+        let history = app.world().resource::<HistoryStack>();
+        assert_eq!(history.undo_description().as_deref(), Some("Paste 3 entities"));
+    }
+}
@@ -0,0 +1,199 @@
+//! Command recording and replay, for attaching reproductions to bug reports.
+//!
+//! While recording, every command sent to the engine is logged with its
+//! payload and the time since recording started. Stopping emits the log as
+//! `COMMAND_LOG`; `replay_commands` sends a log's commands again with the
+//! same spacing. Replays are meant for a fresh scene: spawns are recorded
+//! with the entity ID they were given, so later commands in the log still
+//! find their entity. Base64 asset data is left out of the log unless
+//! requested, leaving a placeholder that fails to replay.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::entity_id::EntityId;
+use super::pending_commands::{CommandLogRequest, PendingCommands};
+use super::scene_file::SCENE_FORMAT_VERSION;
+
+/// Commands that control recording, which are never recorded themselves.
+const LOG_COMMANDS: [&str; 3] = ["start_event_recording", "stop_event_recording", "replay_commands"];
+
+/// Commands that create an entity, and accept the `entityId` to give it.
+const SPAWN_COMMANDS: [&str; 2] = ["spawn_entity", "spawn_configured"];
+
+/// One recorded command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedCommand {
+    /// Seconds since recording started.
+    pub time: f64,
+    pub command: String,
+    pub payload: serde_json::Value,
+}
+
+/// Serialized command log, as emitted in `COMMAND_LOG`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandLogData {
+    pub format_version: u32,
+    pub commands: Vec<RecordedCommand>,
+}
+
+/// Options for the recording in progress, read when commands are dispatched.
+#[derive(Debug, Clone, Copy)]
+pub struct RecordingOptions {
+    /// Keep base64 asset data in the log instead of a placeholder.
+    pub inline_blobs: bool,
+}
+
+/// The command log being recorded.
+#[derive(Resource, Debug, Default)]
+pub struct CommandLog {
+    pub recording: bool,
+    /// `Time::elapsed_secs_f64` when recording started.
+    pub started_at: f64,
+    pub entries: Vec<RecordedCommand>,
+}
+
+/// Commands of a log being replayed, and when the replay started.
+#[derive(Resource, Debug, Default)]
+pub struct CommandReplay {
+    pub queue: VecDeque<RecordedCommand>,
+    pub started_at: f64,
+}
+
+/// Record `command` if a recording is in progress. Spawns without an
+/// `entityId` are given one in `payload`, so the live entity and the
+/// replayed one share it.
+pub fn record(pending: &mut PendingCommands, command: &str, payload: &mut serde_json::Value) {
+    let Some(options) = pending.command_recording else {
+        return;
+    };
+    if LOG_COMMANDS.contains(&command) {
+        return;
+    }
+    if SPAWN_COMMANDS.contains(&command) {
+        if let Some(fields) = payload.as_object_mut() {
+            fields.entry("entityId").or_insert_with(|| EntityId::default().0.into());
+        }
+    }
+    let mut logged = payload.clone();
+    if !options.inline_blobs {
+        omit_blobs(&mut logged);
+    }
+    pending.recorded_commands.push((command.to_string(), logged));
+}
+
+/// Replace base64 data (fields named `*base64`) with a placeholder.
+fn omit_blobs(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                match field {
+                    serde_json::Value::String(data) if key.to_ascii_lowercase().ends_with("base64") => {
+                        *field = format!("<omitted {} bytes>", data.len()).into();
+                    }
+                    _ => omit_blobs(field),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(omit_blobs),
+        _ => {}
+    }
+}
+
+/// System that starts and stops recordings, timestamps recorded commands and
+/// queues replays.
+pub fn apply_command_log_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut log: ResMut<CommandLog>,
+    mut replay: ResMut<CommandReplay>,
+    time: Res<Time>,
+) {
+    if pending.command_log_requests.is_empty() && pending.recorded_commands.is_empty() {
+        return;
+    }
+    let now = time.elapsed_secs_f64();
+    let requests: Vec<_> = pending.command_log_requests.drain(..).collect();
+
+    // Commands are only recorded after the last start, so starts go first
+    if requests.iter().any(|r| matches!(r, CommandLogRequest::Start)) {
+        log.recording = true;
+        log.started_at = now;
+        log.entries.clear();
+    }
+    let started_at = log.started_at;
+    log.entries.extend(pending.recorded_commands.drain(..).map(|(command, payload)| RecordedCommand {
+        time: now - started_at,
+        command,
+        payload,
+    }));
+
+    for request in requests {
+        match request {
+            CommandLogRequest::Start => {}
+            CommandLogRequest::Stop => {
+                if !log.recording {
+                    tracing::warn!("stop_event_recording: not recording");
+                    continue;
+                }
+                log.recording = false;
+                let data = CommandLogData { format_version: SCENE_FORMAT_VERSION, commands: std::mem::take(&mut log.entries) };
+                #[cfg(target_arch = "wasm32")]
+                match serde_json::to_string(&data) {
+                    Ok(json) => crate::bridge::events::emit_command_log(&json, data.commands.len()),
+                    Err(e) => tracing::error!("Failed to serialize command log: {}", e),
+                }
+                tracing::info!("Recorded {} commands", data.commands.len());
+            }
+            CommandLogRequest::Replay(commands) => {
+                tracing::info!("Replaying {} commands", commands.len());
+                replay.queue = commands.into();
+                replay.started_at = now;
+            }
+        }
+    }
+}
+
+/// Exclusive system that dispatches the replayed commands that are due.
+/// It runs alone because dispatching writes to `PendingCommands` through the
+/// bridge pointer.
+pub fn run_command_replay(world: &mut World) {
+    let now = world.resource::<Time>().elapsed_secs_f64();
+    let mut due = Vec::new();
+    {
+        let mut replay = world.resource_mut::<CommandReplay>();
+        let elapsed = now - replay.started_at;
+        while replay.queue.front().is_some_and(|c| c.time <= elapsed) {
+            due.extend(replay.queue.pop_front());
+        }
+    }
+    for recorded in due {
+        if let Err(e) = super::commands::dispatch(&recorded.command, recorded.payload) {
+            tracing::warn!("Replayed {} failed: {}", recorded.command, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn recording_assigns_spawn_ids_and_omits_blobs() {
+        let mut pending = PendingCommands { command_recording: Some(RecordingOptions { inline_blobs: false }), ..Default::default() };
+
+        let mut spawned = json!({ "entityType": "cube" });
+        record(&mut pending, "spawn_entity", &mut spawned);
+        assert!(spawned["entityId"].is_string());
+        record(&mut pending, "import_gltf", &mut json!({ "name": "ship", "dataBase64": "AAAA" }));
+        record(&mut pending, "stop_event_recording", &mut json!({}));
+
+        assert_eq!(pending.recorded_commands.len(), 2);
+        assert_eq!(pending.recorded_commands[0].1, spawned);
+        assert_eq!(pending.recorded_commands[1].1, json!({ "name": "ship", "dataBase64": "<omitted 4 bytes>" }));
+    }
+}
@@ -1,8 +1,8 @@
 //! Animation command handlers
 
 use crate::core::pending_commands::{
-    queue_animation_request_from_bridge,
-    AnimationRequest, AnimationAction,
+    queue_animation_request_from_bridge, queue_animation_event_add_from_bridge,
+    AnimationRequest, AnimationAction, AnimationEventAdd,
     QueryRequest,
 };
 
@@ -18,6 +18,9 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "set_animation_loop" => Some(handle_set_animation_loop(payload.clone())),
         "set_animation_blend_weight" => Some(handle_set_blend_weight(payload.clone())),
         "set_clip_speed" => Some(handle_set_clip_speed(payload.clone())),
+        "set_animation_blend_1d" => Some(handle_set_animation_blend_1d(payload.clone())),
+        "blend_1d_value" => Some(handle_blend_1d_value(payload.clone())),
+        "add_animation_event" => Some(handle_add_animation_event(payload.clone())),
         "get_animation_state" | "list_animations" => {
             let entity_id = payload.get("entityId")
                 .and_then(|v| v.as_str())
@@ -56,7 +59,7 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
 }
 
 /// Handle play_animation command.
-/// Payload: { entityId: string, clipName: string, crossfadeSecs?: number }
+/// Payload: { entityId: string, clipName: string, crossfadeSecs?: number, rootMotion?: boolean }
 fn handle_play_animation(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
         .and_then(|v| v.as_str())
@@ -69,10 +72,13 @@ fn handle_play_animation(payload: serde_json::Value) -> super::CommandResult {
     let crossfade_secs = payload.get("crossfadeSecs")
         .and_then(|v| v.as_f64())
         .unwrap_or(0.3) as f32;
+    let root_motion = payload.get("rootMotion")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
 
     let request = AnimationRequest {
         entity_id: entity_id.clone(),
-        action: AnimationAction::Play { clip_name, crossfade_secs },
+        action: AnimationAction::Play { clip_name, crossfade_secs, root_motion },
     };
 
     if queue_animation_request_from_bridge(request) {
@@ -239,6 +245,62 @@ fn handle_set_blend_weight(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Handle set_animation_blend_1d command.
+/// Payload: { entityId: string, clips: [{ clipName: string, threshold: number }] }
+/// An empty `clips` list removes the blend space.
+fn handle_set_animation_blend_1d(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let points: Vec<crate::core::animation::BlendPoint1d> = payload.get("clips")
+        .cloned()
+        .map(serde_json::from_value)
+        .ok_or("Missing clips")?
+        .map_err(|e| format!("Invalid clips: {}", e))?;
+    if points.len() == 1 {
+        return Err("A blend space needs at least two clips".to_string());
+    }
+    if points.iter().any(|p| !p.threshold.is_finite()) {
+        return Err("Blend thresholds must be finite".to_string());
+    }
+
+    let request = AnimationRequest {
+        entity_id: entity_id.clone(),
+        action: AnimationAction::SetBlend1d { points },
+    };
+
+    if queue_animation_request_from_bridge(request) {
+        tracing::info!("Queued set_animation_blend_1d for entity: {}", entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle blend_1d_value command.
+fn handle_blend_1d_value(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let value = payload.get("value")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing value")? as f32;
+
+    let request = AnimationRequest {
+        entity_id: entity_id.clone(),
+        action: AnimationAction::SetBlend1dValue { value },
+    };
+
+    if queue_animation_request_from_bridge(request) {
+        tracing::info!("Queued blend_1d_value for entity: {}", entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Handle set_clip_speed command.
 fn handle_set_clip_speed(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
@@ -265,3 +327,39 @@ fn handle_set_clip_speed(payload: serde_json::Value) -> super::CommandResult {
         Err("PendingCommands resource not initialized".to_string())
     }
 }
+
+/// Handle add_animation_event command.
+/// Payload: { entityId: string, clipName: string, timeSecs: number, name: string }
+fn handle_add_animation_event(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let clip_name = payload.get("clipName")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing clipName")?
+        .to_string();
+    let time_secs = payload.get("timeSecs")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing timeSecs")? as f32;
+    let name = payload.get("name")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing name")?
+        .to_string();
+    if !time_secs.is_finite() || time_secs < 0.0 {
+        return Err("Animation event time must be non-negative".to_string());
+    }
+
+    let add = AnimationEventAdd {
+        entity_id: entity_id.clone(),
+        clip_name,
+        event: crate::core::animation::AnimationEvent { time_secs, name },
+    };
+
+    if queue_animation_event_add_from_bridge(add) {
+        tracing::info!("Queued add_animation_event for entity: {}", entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
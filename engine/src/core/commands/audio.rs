@@ -190,6 +190,7 @@ struct UpdateAudioBusPayload {
     volume: Option<f32>,
     muted: Option<bool>,
     soloed: Option<bool>,
+    parent: Option<String>,
 }
 
 /// Handle update_audio_bus command.
@@ -202,6 +203,7 @@ fn handle_update_audio_bus(payload: serde_json::Value) -> super::CommandResult {
         volume: data.volume,
         muted: data.muted,
         soloed: data.soloed,
+        parent: data.parent,
     };
 
     if queue_audio_bus_update_from_bridge(update) {
@@ -219,6 +221,7 @@ struct CreateAudioBusPayload {
     name: String,
     #[serde(default = "super::default_volume")]
     volume: f32,
+    parent: Option<String>,
 }
 
 /// Handle create_audio_bus command.
@@ -240,6 +243,7 @@ fn handle_create_audio_bus(payload: serde_json::Value) -> super::CommandResult {
         volume: data.volume.clamp(0.0, 1.0),
         muted: false,
         soloed: false,
+        parent: data.parent,
     };
 
     if queue_audio_bus_create_from_bridge(create) {
@@ -8,12 +8,14 @@ use crate::core::pending_commands::{
     queue_select_elements_from_bridge,
     queue_mesh_operation_from_bridge,
     queue_recalc_normals_from_bridge,
+    queue_flip_normals_from_bridge,
     EnterEditModeRequest,
     ExitEditModeRequest,
     SetSelectionModeRequest,
     SelectElementsRequest,
     MeshOperationRequest,
     RecalcNormalsRequest,
+    FlipNormalsRequest,
 };
 
 /// Dispatch edit mode commands
@@ -25,6 +27,7 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "select_elements" => Some(handle_select_elements(payload.clone())),
         "mesh_operation" => Some(handle_mesh_operation(payload.clone())),
         "recalc_normals" => Some(handle_recalc_normals(payload.clone())),
+        "flip_normals" => Some(handle_flip_normals(payload.clone())),
         _ => None,
     }
 }
@@ -182,3 +185,27 @@ fn handle_recalc_normals(payload: serde_json::Value) -> super::CommandResult {
         Err("PendingCommands resource not initialized".to_string())
     }
 }
+
+/// Payload for flip_normals command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FlipNormalsPayload {
+    entity_id: String,
+}
+
+/// Handle flip_normals command.
+fn handle_flip_normals(payload: serde_json::Value) -> super::CommandResult {
+    let data: FlipNormalsPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid flip_normals payload: {}", e))?;
+
+    let request = FlipNormalsRequest {
+        entity_id: data.entity_id.clone(),
+    };
+
+    if queue_flip_normals_from_bridge(request) {
+        tracing::info!("Queued flip_normals for entity: {}", data.entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
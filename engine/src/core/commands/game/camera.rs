@@ -0,0 +1,239 @@
+//! Game camera and mouse-look command handlers.
+
+use bevy::math::Vec3;
+use crate::core::commands::{handle_query, CommandResult};
+use crate::core::pending_commands::{
+    queue_set_game_camera_from_bridge, queue_set_active_game_camera_from_bridge, queue_camera_shake_from_bridge,
+    queue_mouse_delta_from_bridge,
+    SetGameCameraRequest, SetActiveGameCameraRequest, CameraShakeRequest, QueryRequest,
+};
+
+use super::parse_point;
+
+/// Dispatch game camera commands.
+pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<CommandResult> {
+    match command {
+        "set_game_camera" => Some(handle_set_game_camera(payload.clone())),
+        "set_active_game_camera" => Some(handle_set_active_game_camera(payload.clone())),
+        "camera_shake" => Some(handle_camera_shake(payload.clone())),
+        "mouse_delta" => Some(handle_mouse_delta(payload.clone())),
+        "get_game_camera" => {
+            // NOTE: checks both "entityId" and "entity_id" field names
+            let entity_id = payload.get("entityId")
+                .or_else(|| payload.get("entity_id"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_default();
+            Some(handle_query(QueryRequest::GameCameraState { entity_id }))
+        }
+        _ => None,
+    }
+}
+
+/// Read an optional `[x, y, z]` field, accepting either spelling of its name.
+fn optional_vec3(payload: &serde_json::Value, snake: &str, camel: &str) -> Result<Option<Vec3>, String> {
+    match payload.get(snake).or_else(|| payload.get(camel)) {
+        Some(v) => parse_point(v).map(|p| Some(Vec3::from(p))).ok_or_else(|| format!("Invalid {} (expected [x, y, z])", camel)),
+        None => Ok(None),
+    }
+}
+
+/// Handle set_game_camera command.
+/// Payload: { entity_id, mode, target_entity?, follow_target_id?, follow_offset?, follow_smoothing?, deadzone? }
+fn handle_set_game_camera(payload: serde_json::Value) -> CommandResult {
+    let entity_id = payload.get("entity_id")
+        .or_else(|| payload.get("entityId"))
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entity_id")?
+        .to_string();
+
+    let mode: crate::core::game_camera::GameCameraMode = serde_json::from_value(
+        payload.get("mode").cloned().ok_or("Missing mode")?
+    ).map_err(|e| format!("Invalid mode: {}", e))?;
+
+    let target_entity = payload.get("target_entity")
+        .or_else(|| payload.get("targetEntity"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    let follow_target_id = payload.get("follow_target_id")
+        .or_else(|| payload.get("followTargetId"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    let defaults = crate::core::game_camera::GameCameraData::default();
+    let follow_offset = optional_vec3(&payload, "follow_offset", "followOffset")?.unwrap_or(defaults.follow_offset);
+    let deadzone = optional_vec3(&payload, "deadzone", "deadzone")?.unwrap_or(defaults.deadzone);
+    if deadzone.min_element() < 0.0 {
+        return Err("deadzone must not be negative".to_string());
+    }
+
+    let follow_smoothing = match payload.get("follow_smoothing").or_else(|| payload.get("followSmoothing")) {
+        Some(v) => v.as_f64().ok_or("followSmoothing must be a number")? as f32,
+        None => defaults.follow_smoothing,
+    };
+    if !follow_smoothing.is_finite() || follow_smoothing < 0.0 {
+        return Err("followSmoothing must be a non-negative number".to_string());
+    }
+
+    if queue_set_game_camera_from_bridge(SetGameCameraRequest {
+        entity_id,
+        mode,
+        target_entity,
+        follow_target_id,
+        follow_offset,
+        follow_smoothing,
+        deadzone,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_active_game_camera command.
+/// Payload: { entity_id }
+fn handle_set_active_game_camera(payload: serde_json::Value) -> CommandResult {
+    let entity_id = payload.get("entity_id")
+        .or_else(|| payload.get("entityId"))
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entity_id")?
+        .to_string();
+
+    if queue_set_active_game_camera_from_bridge(SetActiveGameCameraRequest {
+        entity_id,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle camera_shake command.
+/// Payload: { intensity, duration }
+fn handle_camera_shake(payload: serde_json::Value) -> CommandResult {
+    let intensity = payload.get("intensity")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing intensity")? as f32;
+
+    let duration = payload.get("duration")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing duration")? as f32;
+
+    if !intensity.is_finite() || !duration.is_finite() {
+        return Err("camera_shake: intensity and duration must be finite numbers".to_string());
+    }
+
+    let intensity = intensity.clamp(0.0, 100.0);
+    let duration = duration.clamp(0.0, 30.0);
+
+    if queue_camera_shake_from_bridge(CameraShakeRequest {
+        intensity,
+        duration,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle mouse_delta command.
+/// Payload: { dx, dy }
+fn handle_mouse_delta(payload: serde_json::Value) -> CommandResult {
+    let dx = payload.get("dx").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+    let dy = payload.get("dy").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+
+    if !dx.is_finite() || !dy.is_finite() {
+        return Err("mouse_delta values must be finite".into());
+    }
+
+    if queue_mouse_delta_from_bridge(dx, dy) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::core::commands::game::tests::run;
+
+    // === camera_shake ===
+
+    #[test]
+    fn camera_shake_accepts_intensity_and_duration() {
+        let result = run("camera_shake", json!({
+            "intensity": 0.5,
+            "duration": 1.0
+        }));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        // Should reach handler (not "Unknown")
+        assert!(!err.contains("Unknown"), "Should reach camera_shake handler, got: {}", err);
+    }
+    // === mouse_delta ===
+
+    #[test]
+    fn mouse_delta_accepts_empty_payload_with_defaults() {
+        // dx and dy default to 0.0 if missing
+        let result = run("mouse_delta", json!({}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn mouse_delta_accepts_dx_dy_values() {
+        let result = run("mouse_delta", json!({"dx": 5.0, "dy": -3.0}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    // === set_game_camera ===
+
+    #[test]
+    fn set_game_camera_accepts_follow_settings() {
+        let result = run("set_game_camera", json!({
+            "entityId": "camera",
+            "mode": { "Fixed": { "look_at": null } },
+            "followTargetId": "player",
+            "followOffset": [0.0, 2.0, 6.0],
+            "followSmoothing": 0.2,
+            "deadzone": [1.0, 0.5, 1.0]
+        }));
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn set_game_camera_validates_follow_settings() {
+        let base = |extra: serde_json::Value| {
+            let mut payload = json!({ "entityId": "camera", "mode": { "Fixed": { "look_at": null } } });
+            payload.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            run("set_game_camera", payload).unwrap_err()
+        };
+        assert_eq!(base(json!({ "deadzone": [1.0, -1.0, 0.0] })), "deadzone must not be negative");
+        assert_eq!(base(json!({ "followOffset": [1.0] })), "Invalid followOffset (expected [x, y, z])");
+        assert_eq!(base(json!({ "followSmoothing": -0.5 })), "followSmoothing must be a non-negative number");
+    }
+
+    // === set_active_game_camera ===
+
+    #[test]
+    fn set_active_game_camera_accepts_entity_id() {
+        let result = run("set_active_game_camera", json!({"entityId": "entity-1"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn set_active_game_camera_rejects_missing_entity_id() {
+        let result = run("set_active_game_camera", json!({}));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.contains("entity_id") || err.contains("Missing"),
+            "Expected missing entity_id error, got: {}",
+            err
+        );
+    }
+}
@@ -0,0 +1,614 @@
+//! Game component, path, character and timer command handlers.
+
+use crate::core::pending_commands::{
+    queue_game_component_add_from_bridge, queue_game_component_update_from_bridge,
+    queue_game_component_removal_from_bridge, queue_look_at_constraint_update_from_bridge,
+    queue_move_character_from_bridge, queue_apply_damage_from_bridge,
+    queue_timer_request_from_bridge,
+    queue_create_path_from_bridge, queue_path_point_add_from_bridge,
+    GameComponentAddRequest, GameComponentUpdateRequest, GameComponentRemovalRequest, LookAtConstraintUpdate,
+    CreatePathRequest, PathPointAddRequest,
+    MoveCharacterRequest, ApplyDamageRequest, QueryRequest,
+    TimerAction, TimerRequest,
+};
+use crate::core::game_components::{LookAtConstraint, PathFollowerData};
+use bevy::math::Vec3;
+
+mod camera;
+
+/// Handle add_game_component command.
+/// Payload: { entityId, componentType, properties? }
+fn handle_add_game_component(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let component_type = payload.get("componentType")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing componentType")?
+        .to_string();
+
+    let properties = payload.get("properties")
+        .cloned()
+        .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
+
+    let properties_json = properties.to_string();
+
+    let request = GameComponentAddRequest {
+        entity_id,
+        component_type,
+        properties_json,
+    };
+
+    if queue_game_component_add_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle update_game_component command.
+/// Payload: { entityId, componentType, properties }
+fn handle_update_game_component(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let component_type = payload.get("componentType")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing componentType")?
+        .to_string();
+
+    let properties = payload.get("properties")
+        .cloned()
+        .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
+
+    let properties_json = properties.to_string();
+
+    let request = GameComponentUpdateRequest {
+        entity_id,
+        component_type,
+        properties_json,
+    };
+
+    if queue_game_component_update_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle remove_game_component command.
+/// Payload: { entityId, componentName }
+fn handle_remove_game_component(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let component_name = payload.get("componentName")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing componentName")?
+        .to_string();
+
+    let request = GameComponentRemovalRequest {
+        entity_id,
+        component_name,
+    };
+
+    if queue_game_component_removal_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_look_at_constraint command.
+/// Payload: { entityId, targetEntityId, upAxis?, weight? }
+fn handle_set_look_at_constraint(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let constraint: LookAtConstraint = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_look_at_constraint payload: {}", e))?;
+    if constraint.target_entity_id == entity_id {
+        return Err("An entity cannot look at itself".to_string());
+    }
+    if !constraint.up_axis.iter().all(|v| v.is_finite()) || Vec3::from(constraint.up_axis).length_squared() < 1e-6 {
+        return Err("upAxis must be a non-zero vector".to_string());
+    }
+    if !(0.0..=1.0).contains(&constraint.weight) {
+        return Err("weight must be between 0 and 1".to_string());
+    }
+
+    if queue_look_at_constraint_update_from_bridge(LookAtConstraintUpdate { entity_id, constraint: Some(constraint) }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle remove_look_at_constraint command.
+/// Payload: { entityId }
+fn handle_remove_look_at_constraint(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    if queue_look_at_constraint_update_from_bridge(LookAtConstraintUpdate { entity_id, constraint: None }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Parse a `[x, y, z]` array of finite numbers.
+pub(super) fn parse_point(value: &serde_json::Value) -> Option<[f32; 3]> {
+    let point: [f32; 3] = serde_json::from_value(value.clone()).ok()?;
+    point.iter().all(|v| v.is_finite()).then_some(point)
+}
+
+/// Handle create_path command.
+/// Payload: { name?, position?, points?: [[x, y, z], ...], closed? }
+fn handle_create_path(payload: serde_json::Value) -> super::CommandResult {
+    let name = payload.get("name").and_then(|v| v.as_str()).map(|s| s.to_string());
+    let position = match payload.get("position") {
+        Some(v) => Some(parse_point(v).ok_or("Invalid position (expected [x, y, z])")?),
+        None => None,
+    };
+    let points = match payload.get("points").and_then(|v| v.as_array()) {
+        Some(points) => points.iter()
+            .map(|p| parse_point(p).ok_or("Invalid path point (expected [x, y, z])"))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Vec::new(),
+    };
+    let closed = payload.get("closed").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    if queue_create_path_from_bridge(CreatePathRequest { name, position, points, closed }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle add_path_point command.
+/// Payload: { entityId, point: [x, y, z], index? }
+fn handle_add_path_point(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let point = payload.get("point")
+        .and_then(parse_point)
+        .ok_or("Missing or invalid point (expected [x, y, z])")?;
+
+    let index = match payload.get("index") {
+        Some(v) => Some(v.as_u64().ok_or("index must be a non-negative integer")? as usize),
+        None => None,
+    };
+
+    if queue_path_point_add_from_bridge(PathPointAddRequest { entity_id, point, index }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_path_follower command. Adds (or replaces) the entity's
+/// path_follower game component.
+/// Payload: { entityId, pathEntityId, speed?, loopMode?, t?, orientToPath? }
+fn handle_set_path_follower(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let data: PathFollowerData = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_path_follower payload: {}", e))?;
+    match &data.path_entity_id {
+        None => return Err("Missing pathEntityId".to_string()),
+        Some(path_id) if *path_id == entity_id => return Err("An entity cannot follow itself".to_string()),
+        Some(_) => {}
+    }
+    if !data.speed.is_finite() || data.speed < 0.0 {
+        return Err("speed must be a non-negative number".to_string());
+    }
+    if !(0.0..=1.0).contains(&data.t) {
+        return Err("t must be between 0 and 1".to_string());
+    }
+
+    let request = GameComponentAddRequest {
+        entity_id,
+        component_type: "path_follower".to_string(),
+        properties_json: serde_json::to_string(&data).map_err(|e| e.to_string())?,
+    };
+
+    if queue_game_component_add_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle list_game_component_types command.
+/// Queues a static query response with all available game component type names.
+/// The query system will emit a QUERY_GAME_COMPONENT_TYPES event with the full list.
+fn handle_list_game_component_types(_payload: serde_json::Value) -> super::CommandResult {
+    use crate::core::pending_commands::{queue_query_from_bridge, QueryRequest};
+    // Best-effort queue — may fail in test context (no PendingCommands resource).
+    // The command itself always succeeds; the event fires on the next frame if the
+    // engine is running.
+    let _ = queue_query_from_bridge(QueryRequest::GameComponentTypes);
+    Ok(())
+}
+
+/// Handle move_character command.
+/// Payload: { entityId, direction: [x, y, z], dt }
+fn handle_move_character(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let direction: [f32; 3] = payload.get("direction")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .ok_or("Missing or invalid direction (expected [x, y, z])")?;
+
+    let dt = payload.get("dt")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing dt")? as f32;
+
+    if !direction.iter().all(|v| v.is_finite()) || !dt.is_finite() {
+        return Err("move_character: direction and dt must be finite numbers".to_string());
+    }
+
+    // Cap dt so a stalled frame can't launch the character across the level.
+    let dt = dt.clamp(0.0, 0.1);
+
+    if queue_move_character_from_bridge(MoveCharacterRequest {
+        entity_id,
+        direction,
+        dt,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle apply_damage command. Negative amounts heal.
+/// Payload: { entityId, amount, source? }
+fn handle_apply_damage(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let amount = payload.get("amount")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing amount")? as f32;
+    if !amount.is_finite() {
+        return Err("apply_damage: amount must be a finite number".to_string());
+    }
+
+    let source = payload.get("source")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    if queue_apply_damage_from_bridge(ApplyDamageRequest { entity_id, amount, source }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle start_timer / stop_timer / reset_timer commands.
+/// Payload: { entityId }
+fn handle_timer_control(payload: serde_json::Value, action: TimerAction) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    if queue_timer_request_from_bridge(TimerRequest { entity_id, action }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::CommandResult> {
+    match command {
+        "add_game_component" => Some(handle_add_game_component(payload.clone())),
+        "update_game_component" => Some(handle_update_game_component(payload.clone())),
+        "remove_game_component" => Some(handle_remove_game_component(payload.clone())),
+        "set_look_at_constraint" => Some(handle_set_look_at_constraint(payload.clone())),
+        "remove_look_at_constraint" => Some(handle_remove_look_at_constraint(payload.clone())),
+        "create_path" => Some(handle_create_path(payload.clone())),
+        "add_path_point" => Some(handle_add_path_point(payload.clone())),
+        "set_path_follower" => Some(handle_set_path_follower(payload.clone())),
+        "get_game_components" => {
+            let entity_id = payload.get("entityId")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_default();
+            Some(super::handle_query(QueryRequest::GameComponentState { entity_id }))
+        }
+        "list_game_component_types" => Some(handle_list_game_component_types(payload.clone())),
+        "move_character" => Some(handle_move_character(payload.clone())),
+        "apply_damage" => Some(handle_apply_damage(payload.clone())),
+        "start_timer" => Some(handle_timer_control(payload.clone(), TimerAction::Start)),
+        "stop_timer" => Some(handle_timer_control(payload.clone(), TimerAction::Stop)),
+        "reset_timer" => Some(handle_timer_control(payload.clone(), TimerAction::Reset)),
+        _ => camera::dispatch(command, payload),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    pub(super) fn run(command: &str, payload: serde_json::Value) -> Result<(), String> {
+        dispatch(command, &payload).expect("game dispatch returned None for known command")
+    }
+
+    // === add_game_component ===
+
+    #[test]
+    fn add_game_component_accepts_valid_payload() {
+        let result = run("add_game_component", json!({
+            "entityId": "entity-1",
+            "componentType": "Health"
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn add_game_component_accepts_optional_properties() {
+        let result = run("add_game_component", json!({
+            "entityId": "entity-1",
+            "componentType": "CharacterController",
+            "properties": {"speed": 5.0, "jumpHeight": 2.0}
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn add_game_component_rejects_missing_entity_id() {
+        let result = run("add_game_component", json!({
+            "componentType": "Health"
+        }));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.contains("entityId") || err.contains("Missing"),
+            "Expected missing entityId error, got: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn add_game_component_rejects_missing_component_type() {
+        let result = run("add_game_component", json!({
+            "entityId": "entity-1"
+        }));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.contains("componentType") || err.contains("Missing"),
+            "Expected missing componentType error, got: {}",
+            err
+        );
+    }
+
+    // === update_game_component ===
+
+    #[test]
+    fn update_game_component_accepts_valid_payload() {
+        let result = run("update_game_component", json!({
+            "entityId": "entity-1",
+            "componentType": "Health",
+            "properties": {"maxHealth": 100}
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    // === remove_game_component ===
+
+    #[test]
+    fn remove_game_component_accepts_valid_payload() {
+        let result = run("remove_game_component", json!({
+            "entityId": "entity-1",
+            "componentName": "Health"
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn remove_game_component_rejects_missing_component_name() {
+        let result = run("remove_game_component", json!({
+            "entityId": "entity-1"
+        }));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.contains("componentName") || err.contains("Missing"),
+            "Expected missing componentName error, got: {}",
+            err
+        );
+    }
+
+    // === set_look_at_constraint / remove_look_at_constraint ===
+
+    #[test]
+    fn set_look_at_constraint_accepts_valid_payload() {
+        let result = run("set_look_at_constraint", json!({
+            "entityId": "turret",
+            "targetEntityId": "player",
+            "weight": 0.25
+        }));
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn set_look_at_constraint_validates_payload() {
+        let result = run("set_look_at_constraint", json!({ "entityId": "turret" }));
+        assert!(result.unwrap_err().starts_with("Invalid set_look_at_constraint payload"));
+
+        let result = run("set_look_at_constraint", json!({ "entityId": "turret", "targetEntityId": "turret" }));
+        assert_eq!(result.unwrap_err(), "An entity cannot look at itself");
+
+        let result = run("set_look_at_constraint", json!({ "entityId": "turret", "targetEntityId": "player", "upAxis": [0.0, 0.0, 0.0] }));
+        assert_eq!(result.unwrap_err(), "upAxis must be a non-zero vector");
+
+        let result = run("set_look_at_constraint", json!({ "entityId": "turret", "targetEntityId": "player", "weight": 1.5 }));
+        assert_eq!(result.unwrap_err(), "weight must be between 0 and 1");
+    }
+
+    #[test]
+    fn remove_look_at_constraint_requires_entity_id() {
+        assert_eq!(run("remove_look_at_constraint", json!({})).unwrap_err(), "Missing entityId");
+        let result = run("remove_look_at_constraint", json!({ "entityId": "turret" }));
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    // === create_path / add_path_point / set_path_follower ===
+
+    #[test]
+    fn create_path_validates_points() {
+        let result = run("create_path", json!({ "points": [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]], "closed": true }));
+        assert!(result.unwrap_err().contains("not initialized"));
+
+        let result = run("create_path", json!({ "points": [[0.0, 0.0]] }));
+        assert_eq!(result.unwrap_err(), "Invalid path point (expected [x, y, z])");
+    }
+
+    #[test]
+    fn add_path_point_validates_payload() {
+        assert_eq!(run("add_path_point", json!({ "point": [0.0, 1.0, 2.0] })).unwrap_err(), "Missing entityId");
+
+        let result = run("add_path_point", json!({ "entityId": "path", "point": "up" }));
+        assert_eq!(result.unwrap_err(), "Missing or invalid point (expected [x, y, z])");
+
+        let result = run("add_path_point", json!({ "entityId": "path", "point": [0.0, 1.0, 2.0], "index": -1 }));
+        assert_eq!(result.unwrap_err(), "index must be a non-negative integer");
+
+        let result = run("add_path_point", json!({ "entityId": "path", "point": [0.0, 1.0, 2.0], "index": 1 }));
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn set_path_follower_validates_payload() {
+        let result = run("set_path_follower", json!({ "entityId": "cart", "pathEntityId": "track", "loopMode": "pingPong" }));
+        assert!(result.unwrap_err().contains("not initialized"));
+
+        let result = run("set_path_follower", json!({ "entityId": "cart" }));
+        assert_eq!(result.unwrap_err(), "Missing pathEntityId");
+
+        let result = run("set_path_follower", json!({ "entityId": "cart", "pathEntityId": "cart" }));
+        assert_eq!(result.unwrap_err(), "An entity cannot follow itself");
+
+        let result = run("set_path_follower", json!({ "entityId": "cart", "pathEntityId": "track", "speed": -1.0 }));
+        assert_eq!(result.unwrap_err(), "speed must be a non-negative number");
+
+        let result = run("set_path_follower", json!({ "entityId": "cart", "pathEntityId": "track", "t": 2.0 }));
+        assert_eq!(result.unwrap_err(), "t must be between 0 and 1");
+
+        let result = run("set_path_follower", json!({ "entityId": "cart", "pathEntityId": "track", "loopMode": "sideways" }));
+        assert!(result.unwrap_err().starts_with("Invalid set_path_follower payload"));
+    }
+
+    // === list_game_component_types ===
+
+    #[test]
+    fn list_game_component_types_returns_ok() {
+        let result = run("list_game_component_types", json!({}));
+        // This is a synchronous query that returns Ok immediately
+        assert!(result.is_ok(), "list_game_component_types should return Ok");
+    }
+
+    // === get_game_components (query) ===
+
+    #[test]
+    fn get_game_components_queues_query() {
+        let result = run("get_game_components", json!({"entityId": "entity-1"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    // === move_character ===
+
+    #[test]
+    fn move_character_accepts_valid_payload() {
+        let result = run("move_character", json!({
+            "entityId": "player",
+            "direction": [1.0, 0.0, 0.0],
+            "dt": 0.016
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn move_character_rejects_missing_entity_id() {
+        let result = run("move_character", json!({"direction": [1.0, 0.0, 0.0], "dt": 0.016}));
+        assert_eq!(result.unwrap_err(), "Missing entityId");
+    }
+
+    #[test]
+    fn move_character_rejects_malformed_direction() {
+        let result = run("move_character", json!({"entityId": "player", "direction": [1.0, 0.0], "dt": 0.016}));
+        assert!(result.unwrap_err().contains("invalid direction"));
+    }
+
+    #[test]
+    fn move_character_rejects_missing_dt() {
+        let result = run("move_character", json!({"entityId": "player", "direction": [1.0, 0.0, 0.0]}));
+        assert_eq!(result.unwrap_err(), "Missing dt");
+    }
+
+    // === apply_damage ===
+
+    #[test]
+    fn apply_damage_accepts_amount_and_optional_source() {
+        let result = run("apply_damage", json!({"entityId": "player", "amount": 30.0, "source": "spikes"}));
+        assert!(result.unwrap_err().contains("not initialized"));
+        let result = run("apply_damage", json!({"entityId": "player", "amount": -10.0}));
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn apply_damage_rejects_missing_amount() {
+        let result = run("apply_damage", json!({"entityId": "player"}));
+        assert_eq!(result.unwrap_err(), "Missing amount");
+    }
+
+    // === timers ===
+
+    #[test]
+    fn timer_commands_require_entity_id() {
+        for command in ["start_timer", "stop_timer", "reset_timer"] {
+            assert!(run(command, json!({"entityId": "spawner"})).unwrap_err().contains("not initialized"));
+            assert_eq!(run(command, json!({})).unwrap_err(), "Missing entityId");
+        }
+    }
+
+    // === dispatch returns None for unknown commands ===
+
+    #[test]
+    fn dispatch_returns_none_for_unknown_command() {
+        let result = dispatch("definitely_not_game", &json!({}));
+        assert!(result.is_none(), "Unknown command should return None");
+    }
+}
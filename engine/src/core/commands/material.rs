@@ -1,26 +1,28 @@
 //! Material, lighting, and environment command handlers.
 
 use serde::Deserialize;
-use crate::core::material::{MaterialData, MaterialAlphaMode, ParallaxMethod};
+use crate::core::material::{MaterialData, MaterialAlphaMode, MaterialPreset, ParallaxMethod};
+use crate::core::environment::FogMode;
 use crate::core::lighting::LightData;
 use crate::core::shader_effects::ShaderEffectData;
 use crate::core::post_processing::{
     BloomSettings, ChromaticAberrationSettings, ColorGradingSettings, SharpeningSettings,
-    SsaoSettings, DepthOfFieldSettings, MotionBlurSettings,
+    SsaoSettings, DepthOfFieldSettings, MotionBlurSettings, VignetteSettings,
 };
 use crate::core::pending_commands::{
     queue_material_update_from_bridge, queue_light_update_from_bridge,
+    queue_light_probe_bake_from_bridge,
     queue_ambient_light_update_from_bridge, queue_environment_update_from_bridge,
     queue_post_processing_update_from_bridge,
     queue_shader_update_from_bridge, queue_shader_removal_from_bridge,
     queue_set_skybox_from_bridge, queue_remove_skybox_from_bridge,
-    queue_update_skybox_from_bridge, queue_custom_skybox_from_bridge,
+    queue_update_skybox_from_bridge, queue_custom_skybox_from_bridge, queue_procedural_sky_from_bridge,
     queue_custom_wgsl_source_update_from_bridge,
     queue_register_custom_shader_from_bridge, queue_apply_custom_shader_from_bridge,
     queue_remove_custom_shader_slot_from_bridge,
-    MaterialUpdate, LightUpdate, AmbientLightUpdate, EnvironmentUpdate,
+    MaterialUpdate, LightUpdate, LightProbeBakeRequest, AmbientLightUpdate, EnvironmentUpdate,
     PostProcessingUpdate, ShaderUpdate, ShaderRemoval,
-    SetSkyboxRequest, UpdateSkyboxRequest, SetCustomSkyboxRequest,
+    SetSkyboxRequest, UpdateSkyboxRequest, SetCustomSkyboxRequest, SetProceduralSkyRequest,
     CustomWgslSourceUpdate, QueryRequest,
     RegisterCustomShaderRequest, ApplyCustomShaderRequest, RemoveCustomShaderRequest,
 };
@@ -30,6 +32,8 @@ use crate::core::custom_wgsl::{validate_wgsl_source, CUSTOM_SHADER_SLOT_COUNT};
 pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::CommandResult> {
     match command {
         "update_material" => Some(handle_update_material(payload.clone())),
+        "apply_material_preset" => Some(handle_apply_material_preset(payload.clone())),
+        "list_material_presets" => Some(super::handle_query(QueryRequest::MaterialPresets)),
         "set_custom_shader" => Some(handle_set_custom_shader(payload.clone())),
         "remove_custom_shader" => Some(handle_remove_custom_shader(payload.clone())),
         "get_shader" => {
@@ -45,6 +49,7 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         }
         "list_shaders" => Some(handle_list_shaders(payload.clone())),
         "update_light" => Some(handle_update_light(payload.clone())),
+        "bake_light_probes" => Some(handle_bake_light_probes(payload.clone())),
         "update_ambient_light" => Some(handle_update_ambient_light(payload.clone())),
         "update_environment" => Some(handle_update_environment(payload.clone())),
         "update_post_processing" => Some(handle_update_post_processing(payload.clone())),
@@ -53,6 +58,7 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "remove_skybox" => Some(handle_remove_skybox(payload.clone())),
         "update_skybox" => Some(handle_update_skybox(payload.clone())),
         "set_custom_skybox" => Some(handle_set_custom_skybox(payload.clone())),
+        "set_procedural_sky" => Some(handle_set_procedural_sky(payload.clone())),
         "set_custom_wgsl_source" => Some(handle_set_custom_wgsl_source(payload.clone())),
         "validate_wgsl" => Some(handle_validate_wgsl(payload.clone())),
         // Mega-shader commands
@@ -97,6 +103,14 @@ struct UpdateMaterialPayload {
     thickness: Option<f32>,
     attenuation_distance: Option<f32>,
     attenuation_color: Option<[f32; 3]>,
+    // Anisotropy and sheen
+    anisotropy_strength: Option<f32>,
+    anisotropy_rotation: Option<f32>,
+    sheen_color: Option<[f32; 3]>,
+    sheen_roughness: Option<f32>,
+    // Triplanar projection
+    triplanar: Option<bool>,
+    triplanar_scale: Option<f32>,
 }
 
 /// Handle update_material command from React.
@@ -148,6 +162,13 @@ fn handle_update_material(payload: serde_json::Value) -> super::CommandResult {
     if let Some(v) = data.thickness { mat.thickness = v; }
     if let Some(v) = data.attenuation_distance { mat.attenuation_distance = v; }
     if let Some(v) = data.attenuation_color { mat.attenuation_color = v; }
+    // Anisotropy and sheen
+    if let Some(v) = data.anisotropy_strength { mat.anisotropy_strength = v; }
+    if let Some(v) = data.anisotropy_rotation { mat.anisotropy_rotation = v; }
+    if let Some(v) = data.sheen_color { mat.sheen_color = v; }
+    if let Some(v) = data.sheen_roughness { mat.sheen_roughness = v; }
+    if let Some(v) = data.triplanar { mat.triplanar = v; }
+    if let Some(v) = data.triplanar_scale { mat.triplanar_scale = v.max(0.001); }
 
     let update = MaterialUpdate {
         entity_id: data.entity_id.clone(),
@@ -162,6 +183,34 @@ fn handle_update_material(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Handle apply_material_preset command.
+/// Replaces the entity's PBR parameters with a named preset; textures are kept.
+fn handle_apply_material_preset(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload
+        .get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let preset_name = payload
+        .get("preset")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing preset")?;
+    let preset = MaterialPreset::from_name(preset_name)
+        .ok_or_else(|| format!("Unknown material preset: {}", preset_name))?;
+
+    let update = MaterialUpdate {
+        entity_id: entity_id.clone(),
+        material_data: MaterialData::from_preset(preset),
+    };
+
+    if queue_material_update_from_bridge(update) {
+        tracing::info!("Queued material preset '{}' for entity: {}", preset_name, entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Payload for set_custom_shader command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -228,6 +277,7 @@ struct UpdateLightPayload {
     radius: Option<f32>,
     inner_angle: Option<f32>,
     outer_angle: Option<f32>,
+    cookie_texture: Option<String>,
 }
 
 /// Handle update_light command from React.
@@ -248,6 +298,7 @@ fn handle_update_light(payload: serde_json::Value) -> super::CommandResult {
     if let Some(v) = data.radius { light.radius = v; }
     if let Some(v) = data.inner_angle { light.inner_angle = v; }
     if let Some(v) = data.outer_angle { light.outer_angle = v; }
+    light.cookie_texture = data.cookie_texture;
 
     let update = LightUpdate {
         entity_id: data.entity_id.clone(),
@@ -262,6 +313,29 @@ fn handle_update_light(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Payload for bake_light_probes command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BakeLightProbesPayload {
+    /// Omitted or null bakes every light probe volume.
+    #[serde(default)]
+    entity_id: Option<String>,
+}
+
+/// Handle bake_light_probes command.
+/// The bake runs over several frames and reports LIGHT_BAKE_PROGRESS events.
+fn handle_bake_light_probes(payload: serde_json::Value) -> super::CommandResult {
+    let data: BakeLightProbesPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid bake_light_probes payload: {}", e))?;
+
+    if queue_light_probe_bake_from_bridge(LightProbeBakeRequest { entity_id: data.entity_id }) {
+        tracing::info!("Queued light probe bake");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Payload for update_ambient_light command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -300,6 +374,10 @@ struct UpdateEnvironmentPayload {
     fog_color: Option<[f32; 3]>,
     fog_start: Option<f32>,
     fog_end: Option<f32>,
+    fog_mode: Option<FogMode>,
+    fog_density: Option<f32>,
+    fog_height_falloff: Option<f32>,
+    fog_base_height: Option<f32>,
 }
 
 /// Handle update_environment command from React.
@@ -316,6 +394,10 @@ fn handle_update_environment(payload: serde_json::Value) -> super::CommandResult
         fog_color: data.fog_color,
         fog_start: data.fog_start,
         fog_end: data.fog_end,
+        fog_mode: data.fog_mode,
+        fog_density: data.fog_density,
+        fog_height_falloff: data.fog_height_falloff,
+        fog_base_height: data.fog_base_height,
     };
 
     if queue_environment_update_from_bridge(update) {
@@ -336,6 +418,7 @@ struct UpdatePostProcessingPayload {
     ssao: Option<Option<SsaoSettings>>,
     depth_of_field: Option<Option<DepthOfFieldSettings>>,
     motion_blur: Option<Option<MotionBlurSettings>>,
+    vignette: Option<Option<VignetteSettings>>,
 }
 
 fn handle_update_post_processing(payload: serde_json::Value) -> super::CommandResult {
@@ -350,6 +433,7 @@ fn handle_update_post_processing(payload: serde_json::Value) -> super::CommandRe
         ssao: data.ssao,
         depth_of_field: data.depth_of_field,
         motion_blur: data.motion_blur,
+        vignette: data.vignette,
     };
 
     if queue_post_processing_update_from_bridge(update) {
@@ -435,7 +519,10 @@ fn handle_update_skybox(payload: serde_json::Value) -> super::CommandResult {
 #[serde(rename_all = "camelCase")]
 struct SetCustomSkyboxPayload {
     asset_id: String,
+    #[serde(default)]
     data_base64: String,
+    #[serde(default)]
+    faces: Vec<String>,
 }
 
 /// Handle set_custom_skybox command.
@@ -443,9 +530,17 @@ fn handle_set_custom_skybox(payload: serde_json::Value) -> super::CommandResult
     let data: SetCustomSkyboxPayload = serde_json::from_value(payload)
         .map_err(|e| format!("Invalid set_custom_skybox payload: {}", e))?;
 
+    if !data.faces.is_empty() && data.faces.len() != 6 {
+        return Err(format!("A cubemap skybox needs 6 faces, got {}", data.faces.len()));
+    }
+    if data.faces.is_empty() && data.data_base64.is_empty() {
+        return Err("Missing dataBase64 or faces".to_string());
+    }
+
     let request = SetCustomSkyboxRequest {
         asset_id: data.asset_id,
         data_base64: data.data_base64,
+        faces: data.faces,
     };
 
     if queue_custom_skybox_from_bridge(request) {
@@ -456,6 +551,43 @@ fn handle_set_custom_skybox(payload: serde_json::Value) -> super::CommandResult
     }
 }
 
+/// Payload for set_procedural_sky command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetProceduralSkyPayload {
+    sun_azimuth: Option<f32>,
+    sun_elevation: Option<f32>,
+    turbidity: Option<f32>,
+    ground_albedo: Option<f32>,
+    sun_entity_id: Option<String>,
+}
+
+/// Handle set_procedural_sky command.
+fn handle_set_procedural_sky(payload: serde_json::Value) -> super::CommandResult {
+    let data: SetProceduralSkyPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_procedural_sky payload: {}", e))?;
+
+    let values = [data.sun_azimuth, data.sun_elevation, data.turbidity, data.ground_albedo];
+    if values.iter().flatten().any(|v| !v.is_finite()) {
+        return Err("Procedural sky parameters must be finite".to_string());
+    }
+
+    let request = SetProceduralSkyRequest {
+        sun_azimuth: data.sun_azimuth,
+        sun_elevation: data.sun_elevation,
+        turbidity: data.turbidity,
+        ground_albedo: data.ground_albedo,
+        sun_entity_id: data.sun_entity_id,
+    };
+
+    if queue_procedural_sky_from_bridge(request) {
+        tracing::info!("Queued procedural sky request");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 // === Custom WGSL Command Handlers ===
 
 /// Payload for set_custom_wgsl_source command.
@@ -696,6 +828,30 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    #[test]
+    fn update_material_accepts_triplanar_fields() {
+        let result = run("update_material", json!({
+            "entityId": "entity-1",
+            "triplanar": true,
+            "triplanarScale": 0.5
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn update_material_accepts_anisotropy_and_sheen_fields() {
+        let result = run("update_material", json!({
+            "entityId": "entity-1",
+            "anisotropyStrength": 0.8,
+            "anisotropyRotation": 1.57,
+            "sheenColor": [1.0, 0.5, 0.5],
+            "sheenRoughness": 0.3
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
     #[test]
     fn update_material_rejects_missing_entity_id() {
         let result = run("update_material", json!({"metallic": 0.5}));
@@ -730,6 +886,27 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    // === apply_material_preset ===
+
+    #[test]
+    fn apply_material_preset_accepts_known_preset() {
+        let result = run("apply_material_preset", json!({"entityId": "entity-1", "preset": "gold"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn apply_material_preset_rejects_unknown_preset() {
+        let result = run("apply_material_preset", json!({"entityId": "entity-1", "preset": "unobtainium"}));
+        assert_eq!(result.unwrap_err(), "Unknown material preset: unobtainium");
+    }
+
+    #[test]
+    fn apply_material_preset_rejects_missing_entity_id() {
+        let result = run("apply_material_preset", json!({"preset": "gold"}));
+        assert_eq!(result.unwrap_err(), "Missing entityId");
+    }
+
     // === update_light ===
 
     #[test]
@@ -35,6 +35,7 @@ mod scene;
 mod game;
 mod sprites;
 mod edit_mode;
+mod query;
 
 use serde::Serialize;
 use super::pending_commands::{QueryRequest, queue_query_from_bridge, queue_mode_change_from_bridge};
@@ -66,7 +67,7 @@ impl CommandResponse {
 /// Returns a domain index:
 ///   0 = transform, 1 = material, 2 = physics, 3 = audio, 4 = animation,
 ///   5 = particles, 6 = performance, 7 = procedural, 8 = scene, 9 = game,
-///   10 = sprites, 11 = edit_mode, 12 = engine-mode/query (handled inline)
+///   10 = sprites, 11 = edit_mode, 12 = engine-mode (handled inline) / query
 ///   255 = unknown
 fn route_domain(command: &str) -> u8 {
     match command {
@@ -78,7 +79,16 @@ fn route_domain(command: &str) -> u8 {
         | "focus_camera" | "orbit_camera" | "delete_entities" | "duplicate_entity"
         | "undo" | "redo" | "set_snap_settings" | "toggle_grid"
         | "set_camera_preset" | "set_input_binding" | "remove_input_binding"
-        | "set_input_preset" | "get_input_bindings" | "get_input_state" => 0,
+        | "set_input_preset" | "get_input_bindings" | "get_input_state"
+        | "spawn_configured" | "update_transforms" | "select_all_by_type"
+        | "invert_selection" | "box_select" | "set_entity_locked" | "add_entity_tag"
+        | "remove_entity_tag" | "align_entities" | "distribute_entities"
+        | "group_entities" | "ungroup_entity" | "frame_selection" | "smart_duplicate"
+        | "copy_entities" | "paste_entities" | "begin_history_group"
+        | "end_history_group" | "set_outline_color" | "measure" | "clear_measurement"
+        | "drop_to_ground" | "set_camera_projection" | "set_viewport_layout"
+        | "set_render_mode" | "set_event_throttle" | "push_input_context"
+        | "pop_input_context" => 0,
 
         // --- material domain ---
         "update_material" | "set_custom_shader" | "remove_custom_shader"
@@ -86,7 +96,8 @@ fn route_domain(command: &str) -> u8 {
         | "update_environment" | "update_post_processing" | "get_post_processing"
         | "set_skybox" | "remove_skybox" | "update_skybox" | "set_custom_skybox"
         | "set_custom_wgsl_source" | "validate_wgsl" | "register_custom_shader"
-        | "apply_custom_shader" | "remove_custom_shader_slot" => 1,
+        | "apply_custom_shader" | "remove_custom_shader_slot" | "bake_light_probes"
+        | "set_procedural_sky" | "apply_material_preset" | "list_material_presets" => 1,
 
         // --- physics domain ---
         "update_physics" | "toggle_physics" | "toggle_debug_physics"
@@ -106,7 +117,9 @@ fn route_domain(command: &str) -> u8 {
         | "get_physics_2d" | "set_joint_2d" | "remove_joint_2d" | "get_joint_2d"
         | "list_joints_2d" | "apply_force_2d" | "apply_impulse_2d"
         | "set_linear_velocity_2d" | "set_angular_velocity_2d"
-        | "get_velocity_2d" | "get_collisions" | "get_collisions_2d" => 2,
+        | "get_velocity_2d" | "get_collisions" | "get_collisions_2d"
+        | "set_physics_timestep" | "apply_radial_impulse"
+        | "bake_physics_to_animation" | "set_joint_motor_target" => 2,
 
         // --- audio domain ---
         "set_audio" | "remove_audio" | "play_audio" | "stop_audio"
@@ -124,23 +137,30 @@ fn route_domain(command: &str) -> u8 {
         | "remove_keyframe" | "update_keyframe" | "get_animation_clips"
         | "play_animation_clip" | "stop_animation_clip"
         | "set_animation_state_machine" | "remove_animation_state_machine"
-        | "list_skeleton_animations" | "get_skeleton_animation" => 4,
+        | "list_skeleton_animations" | "get_skeleton_animation"
+        | "add_animation_event" | "set_animation_blend_1d" | "blend_1d_value" => 4,
 
         // --- particles domain ---
         "set_particle" | "remove_particle" | "toggle_particle"
         | "set_particle_preset" | "play_particle" | "stop_particle"
-        | "burst_particle" | "get_particle" | "list_particle_presets" => 5,
+        | "burst_particle" | "get_particle" | "list_particle_presets"
+        | "set_particle_sub_emitter" | "set_particle_force_field" => 5,
 
         // --- performance / LOD domain ---
         "set_lod" | "generate_lods" | "set_performance_budget"
         | "get_performance_stats" | "optimize_scene" | "set_lod_distances"
-        | "set_simplification_backend" => 6,
+        | "set_simplification_backend" | "set_culling_enabled"
+        | "set_occlusion_culling" | "start_stats_stream" | "stop_stats_stream" => 6,
 
         // --- procedural domain ---
         "csg_union" | "csg_subtract" | "csg_intersect"
         | "spawn_terrain" | "update_terrain" | "sculpt_terrain" | "get_terrain"
         | "extrude_shape" | "lathe_shape" | "array_entity" | "combine_meshes"
-        | "instantiate_prefab" | "set_quality_preset" | "get_quality_settings" => 7,
+        | "instantiate_prefab" | "set_quality_preset" | "get_quality_settings"
+        | "csg_chain" | "paint_terrain_hole" | "set_terrain_splat" | "loft_shape"
+        | "sweep_shape" | "subdivide_mesh" | "apply_transform" | "instance_array"
+        | "clone_pattern" | "create_prefab_from_entity" | "update_prefab"
+        | "export_prefab_library" | "import_prefab_library" => 7,
 
         // --- scene domain ---
         "export_scene" | "load_scene" | "new_scene" | "import_gltf"
@@ -151,13 +171,20 @@ fn route_domain(command: &str) -> u8 {
         | "delete_scene" | "duplicate_scene" | "rename_scene" | "export_scene_json"
         | "import_scene_json"
         // Additional scene management commands
-        | "save_scene" | "get_scene_info" | "list_scene_assets" => 8,
+        | "save_scene" | "get_scene_info" | "list_scene_assets" | "export_gltf"
+        | "export_scene_binary" | "load_scene_binary" | "merge_scene"
+        | "apply_scene_patch" | "refresh_scene_graph" | "start_event_recording"
+        | "stop_event_recording" | "replay_commands" | "emit_script_event"
+        | "report_script_error" | "get_script_errors" => 8,
 
         // --- game domain ---
         "add_game_component" | "update_game_component" | "remove_game_component"
         | "get_game_components" | "list_game_component_types" | "set_game_camera"
         | "set_active_game_camera" | "camera_shake" | "mouse_delta"
-        | "get_game_camera" => 9,
+        | "get_game_camera" | "move_character" | "apply_damage"
+        | "set_look_at_constraint" | "remove_look_at_constraint" | "create_path"
+        | "add_path_point" | "set_path_follower" | "start_timer" | "stop_timer"
+        | "reset_timer" => 9,
 
         // --- sprites / 2D domain ---
         "spawn_sprite" | "set_project_type" | "get_project_type"
@@ -171,17 +198,23 @@ fn route_domain(command: &str) -> u8 {
         | "create_tileset" | "update_tileset" | "delete_tileset"
         | "create_tilemap" | "update_tilemap" | "delete_tilemap"
         | "get_tilemap" | "set_tile" | "clear_tilemap" | "fill_tiles"
-        | "get_sorting_layers" | "set_sorting_layers" => 10,
+        | "get_sorting_layers" | "set_sorting_layers" | "set_camera_2d_follow"
+        | "set_sprite_parallax" | "set_sprite_animation" | "play_sprite_animation"
+        | "stop_sprite_animation" | "create_ik_chain2d" | "set_ik_target2d"
+        | "get_skeleton2d" | "set_blend_param2d" | "paint_tile" | "erase_tile"
+        | "set_tilemap_data" | "remove_tilemap_data" | "set_tileset"
+        | "remove_tileset" | "set_tileset_autotile" => 10,
 
         // --- edit_mode domain ---
         "enter_edit_mode" | "exit_edit_mode" | "set_selection_mode"
         | "select_elements" | "mesh_operation" | "recalc_normals"
-        | "extrude_faces" => 11,
+        | "extrude_faces" | "flip_normals" => 11,
 
-        // --- engine-mode and query commands handled inline ---
+        // --- engine-mode (handled inline) and query commands ---
         "play" | "stop" | "pause" | "resume" | "get_mode"
         | "get_scene_graph" | "get_selection" | "get_entity_details"
-        | "get_camera_state" => 12,
+        | "get_camera_state" | "get_entity_full" | "get_entities_in_radius"
+        | "get_entities_in_box" | "get_entities_by_tag" | "query_batch" => 12,
 
         _ => 255,
     }
@@ -189,7 +222,8 @@ fn route_domain(command: &str) -> u8 {
 
 /// Dispatch a command to the appropriate handler.
 /// Uses a routing table for O(1) domain selection before the domain-level match.
-pub fn dispatch(command: &str, payload: serde_json::Value) -> CommandResult {
+pub fn dispatch(command: &str, mut payload: serde_json::Value) -> CommandResult {
+    crate::core::pending::with_pending(|pc| crate::core::command_log::record(pc, command, &mut payload));
     match route_domain(command) {
         0 => transform::dispatch(command, &payload)
                 .unwrap_or_else(|| Err(format!("Unknown transform command: {}", command))),
@@ -220,18 +254,8 @@ pub fn dispatch(command: &str, payload: serde_json::Value) -> CommandResult {
             "stop" => handle_mode_change(ModeChangeRequest::Stop),
             "pause" => handle_mode_change(ModeChangeRequest::Pause),
             "resume" => handle_mode_change(ModeChangeRequest::Resume),
-            "get_mode" => handle_query(QueryRequest::EngineMode),
-            "get_scene_graph" => handle_query(QueryRequest::SceneGraph),
-            "get_selection" => handle_query(QueryRequest::Selection),
-            "get_entity_details" => {
-                let entity_id = payload.get("entityId")
-                    .and_then(|v| v.as_str())
-                    .ok_or("Missing entityId")?
-                    .to_string();
-                handle_query(QueryRequest::EntityDetails { entity_id })
-            },
-            "get_camera_state" => handle_query(QueryRequest::CameraState),
-            _ => Err(format!("Unknown command: {}", command)),
+            _ => query::dispatch(command, &payload)
+                .unwrap_or_else(|| Err(format!("Unknown command: {}", command))),
         },
         _ => Err(format!("Unknown command: {}", command)),
     }
@@ -422,6 +446,14 @@ mod tests {
         assert!(err.contains("not initialized"), "got: {}", err);
     }
 
+    #[test]
+    fn dispatch_bake_light_probes_accepts_optional_entity_id() {
+        for payload in [json!({}), json!({ "entityId": "volume-1" })] {
+            let err = dispatch("bake_light_probes", payload).unwrap_err();
+            assert!(err.contains("not initialized"), "got: {}", err);
+        }
+    }
+
     #[test]
     fn dispatch_export_scene_reaches_scene_domain() {
         let result = dispatch("export_scene", json!({}));
@@ -495,6 +527,43 @@ mod tests {
         assert!(!err.contains("Unknown command"), "got: {}", err);
     }
 
+    #[test]
+    fn dispatch_set_particle_sub_emitter_rejects_nesting() {
+        use crate::core::particles::ParticleData;
+
+        let mut nested = ParticleData::sparks();
+        nested.sub_emitter = Some(Box::new(ParticleData::smoke()));
+        let result = dispatch("set_particle_sub_emitter", json!({
+            "entityId": "entity-1",
+            "subEmitter": nested
+        }));
+        assert_eq!(result.unwrap_err(), "Sub-emitters cannot be nested");
+
+        let result = dispatch("set_particle_sub_emitter", json!({
+            "entityId": "entity-1",
+            "subEmitter": ParticleData::smoke(),
+            "trigger": "on_collision"
+        }));
+        let err = result.unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_set_particle_force_field_validates_radius() {
+        let result = dispatch("set_particle_force_field", json!({
+            "entityId": "entity-1",
+            "forceField": { "kind": "vortex", "strength": 4.0, "radius": 0.0 }
+        }));
+        assert_eq!(result.unwrap_err(), "Force field radius must be positive");
+
+        let result = dispatch("set_particle_force_field", json!({
+            "entityId": "entity-1",
+            "forceField": { "kind": "repulsor", "strength": 4.0, "radius": 2.5 }
+        }));
+        let err = result.unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     #[test]
     fn dispatch_play_animation_reaches_animation_domain() {
         let result = dispatch("play_animation", json!({
@@ -506,6 +575,34 @@ mod tests {
         assert!(err.contains("not initialized"), "got: {}", err);
     }
 
+    #[test]
+    fn dispatch_add_animation_event_validates_time() {
+        let err = dispatch("add_animation_event", json!({
+            "entityId": "entity-1", "clipName": "Walk", "timeSecs": -1.0, "name": "footstep"
+        })).unwrap_err();
+        assert!(err.contains("non-negative"), "got: {}", err);
+
+        let err = dispatch("add_animation_event", json!({
+            "entityId": "entity-1", "clipName": "Walk", "timeSecs": 0.5, "name": "footstep"
+        })).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_set_animation_blend_1d_validates_clips() {
+        let err = dispatch("set_animation_blend_1d", json!({
+            "entityId": "entity-1",
+            "clips": [{ "clipName": "Walk", "threshold": 0.0 }]
+        })).unwrap_err();
+        assert!(err.contains("at least two"), "got: {}", err);
+
+        let err = dispatch("set_animation_blend_1d", json!({
+            "entityId": "entity-1",
+            "clips": [{ "clipName": "Walk", "threshold": 0.0 }, { "clipName": "Run", "threshold": 4.0 }]
+        })).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     // === dispatch — engine mode commands ===
     // play/stop/pause/resume all require PendingCommands too
 
@@ -545,6 +642,85 @@ mod tests {
         assert!(err.contains("not initialized"), "got: {}", err);
     }
 
+    #[test]
+    fn dispatch_get_entity_full_requires_entity_id() {
+        assert!(dispatch("get_entity_full", json!({})).unwrap_err().contains("entityId"));
+        let err = dispatch("get_entity_full", json!({"entityId": "entity-1"})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_get_entities_in_radius_validates_payload() {
+        let err = dispatch("get_entities_in_radius", json!({"radius": 2.0})).unwrap_err();
+        assert!(err.contains("Missing center"), "got: {}", err);
+
+        let err = dispatch("get_entities_in_radius", json!({"center": [0, 0, 0], "radius": -1.0})).unwrap_err();
+        assert!(err.contains("negative"), "got: {}", err);
+
+        let err = dispatch("get_entities_in_radius", json!({"center": [0, 0, 0], "radius": 2.0})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_get_entities_in_box_reaches_query() {
+        let err = dispatch("get_entities_in_box", json!({"min": [0, 0], "max": [1, 1, 1]})).unwrap_err();
+        assert!(err.contains("Invalid min"), "got: {}", err);
+
+        let err = dispatch("get_entities_in_box", json!({"min": [0, 0, 0], "max": [1, 1, 1]})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_query_batch_parses_each_query() {
+        let err = dispatch("query_batch", json!({"queries": [
+            {"command": "get_selection"},
+            {"command": "get_entity_details", "payload": {}},
+        ]})).unwrap_err();
+        assert!(err.contains("Missing entityId"), "got: {}", err);
+
+        let err = dispatch("query_batch", json!({"queries": [
+            {"command": "get_selection"},
+            {"command": "get_mode"},
+            {"command": "get_entity_details", "payload": {"entityId": "entity-1"}},
+        ]})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_query_batch_rejects_nested_batches_and_non_queries() {
+        let err = dispatch("query_batch", json!({"queries": [
+            {"command": "query_batch", "payload": {"queries": []}},
+        ]})).unwrap_err();
+        assert!(err.contains("Nested"), "got: {}", err);
+
+        let err = dispatch("query_batch", json!({"queries": [{"command": "play"}]})).unwrap_err();
+        assert!(err.contains("Not a batchable query"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_tile_painting_commands_reach_sprites_handler() {
+        for (command, payload) in [
+            ("paint_tile", json!({"entityId": "map", "x": 1, "y": 2, "tileIndex": 3})),
+            ("erase_tile", json!({"entityId": "map", "x": 1, "y": 2})),
+            ("fill_tiles", json!({"entityId": "map", "rect": {"x": 0, "y": 0, "width": 4, "height": 4}, "tileIndex": 3})),
+        ] {
+            let err = dispatch(command, payload).unwrap_err();
+            assert!(err.contains("not initialized"), "{}: {}", command, err);
+        }
+
+        let err = dispatch("fill_tiles", json!({"entityId": "map", "rect": {"x": 0, "y": 0, "width": 4096, "height": 4096}, "tileIndex": 3})).unwrap_err();
+        assert!(err.contains("more than"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_set_tileset_autotile_validates_rules() {
+        let err = dispatch("set_tileset_autotile", json!({"entityId": "tiles", "rules": [{"mask": 255, "tileIndex": 4}]})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+
+        let err = dispatch("set_tileset_autotile", json!({"entityId": "tiles", "rules": [{"mask": 256, "tileIndex": 4}]})).unwrap_err();
+        assert!(err.contains("Invalid auto-tile rules"), "got: {}", err);
+    }
+
     // === dispatch — invalid payload parsing ===
 
     #[test]
@@ -691,6 +867,92 @@ mod tests {
         assert!(!err.contains("Unknown command"), "got: {}", err);
     }
 
+    #[test]
+    fn dispatch_loft_shape_validates_profiles() {
+        let result = dispatch("loft_shape", json!({ "profiles": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]] }));
+        assert_eq!(result.unwrap_err(), "Loft requires at least 2 profiles");
+
+        let result = dispatch("sweep_shape", json!({
+            "profile": [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]],
+            "path": [[0.0, 0.0, 0.0]],
+            "segments": 4
+        }));
+        assert_eq!(result.unwrap_err(), "Path must have at least 2 points");
+    }
+
+    #[test]
+    fn dispatch_csg_chain_rejects_repeated_entities() {
+        let result = dispatch("csg_chain", json!({
+            "baseEntityId": "a",
+            "operations": [
+                { "op": "union", "entityId": "b" },
+                { "op": "subtract", "entityId": "a" }
+            ]
+        }));
+        assert_eq!(result.unwrap_err(), "Entity a appears more than once in the CSG chain");
+
+        let result = dispatch("csg_chain", json!({ "baseEntityId": "a", "operations": [] }));
+        assert_eq!(result.unwrap_err(), "CSG chain requires at least one operation");
+    }
+
+    #[test]
+    fn dispatch_subdivide_mesh_requires_entity_id() {
+        let result = dispatch("subdivide_mesh", json!({ "iterations": 2 }));
+        assert_eq!(result.unwrap_err(), "Missing entityId");
+    }
+
+    #[test]
+    fn dispatch_apply_transform_validates_components() {
+        let result = dispatch("apply_transform", json!({ "entityId": "cube-1" }));
+        assert_eq!(result.unwrap_err(), "apply_transform needs at least one of position, rotation or scale");
+
+        let result = dispatch("apply_transform", json!({ "scale": true }));
+        assert!(result.unwrap_err().starts_with("Invalid apply_transform payload"));
+
+        let result = dispatch("apply_transform", json!({ "entityId": "cube-1", "rotation": true, "scale": true }));
+        assert_eq!(result.unwrap_err(), "PendingCommands resource not initialized");
+    }
+
+    #[test]
+    fn dispatch_paint_terrain_hole_reaches_procedural_domain() {
+        let result = dispatch("paint_terrain_hole", json!({ "entityId": "terrain-1", "radius": 2.0 }));
+        assert!(result.unwrap_err().starts_with("Invalid paint_terrain_hole payload"));
+
+        let result = dispatch("paint_terrain_hole", json!({
+            "entityId": "terrain-1",
+            "position": [0.0, 0.0],
+            "radius": 2.0
+        }));
+        let err = result.unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_set_terrain_splat_limits_layers() {
+        let layer = json!({ "textureAssetId": "grass", "tiling": 0.2 });
+        let result = dispatch("set_terrain_splat", json!({
+            "entityId": "terrain-1",
+            "layers": [layer, layer, layer, layer, layer]
+        }));
+        assert_eq!(result.unwrap_err(), "Terrain supports at most 4 splat layers");
+
+        let result = dispatch("set_terrain_splat", json!({ "entityId": "terrain-1", "layers": [layer] }));
+        let err = result.unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_instance_array_reaches_procedural_domain() {
+        let result = dispatch("instance_array", json!({
+            "entityId": "entity-1",
+            "pattern": "grid",
+            "countX": 10, "countY": 10, "countZ": 10
+        }));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     #[test]
     fn dispatch_set_lod_reaches_performance_domain() {
         let result = dispatch("set_lod", json!({
@@ -712,6 +974,15 @@ mod tests {
         assert!(!err.contains("Unknown command"), "got: {}", err);
     }
 
+    #[test]
+    fn dispatch_flip_normals_reaches_edit_mode_domain() {
+        let result = dispatch("flip_normals", json!({}));
+        assert!(result.unwrap_err().starts_with("Invalid flip_normals payload"));
+
+        let result = dispatch("flip_normals", json!({ "entityId": "entity-1" }));
+        assert_eq!(result.unwrap_err(), "PendingCommands resource not initialized");
+    }
+
     #[test]
     fn dispatch_set_sprite_data_reaches_sprites_domain() {
         let result = dispatch("set_sprite_data", json!({
@@ -722,6 +993,85 @@ mod tests {
         assert!(!err.contains("Unknown command"), "got: {}", err);
     }
 
+    #[test]
+    fn dispatch_set_sprite_data_parses_nine_slice() {
+        for nine_slice in [json!({"left": 4, "right": 4, "top": 2, "bottom": 2}), json!(null)] {
+            let err = dispatch("set_sprite_data", json!({"entityId": "entity-1", "nineSlice": nine_slice})).unwrap_err();
+            assert!(err.contains("not initialized"), "got: {}", err);
+        }
+
+        let err = dispatch("set_sprite_data", json!({"entityId": "entity-1", "nineSlice": {"left": 4}})).unwrap_err();
+        assert!(err.contains("Invalid nineSlice"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_camera_2d_follow_and_parallax() {
+        for (command, payload) in [
+            ("set_camera_2d_follow", json!({"targetId": "hero"})),
+            ("set_camera_2d_follow", json!({"targetId": null})),
+            ("set_sprite_parallax", json!({"entityId": "sky", "parallaxFactor": 0.25})),
+        ] {
+            let err = dispatch(command, payload).unwrap_err();
+            assert!(err.contains("not initialized"), "{}: {}", command, err);
+        }
+
+        assert!(dispatch("set_camera_2d_follow", json!({})).unwrap_err().contains("Missing targetId"));
+        assert!(dispatch("set_sprite_parallax", json!({"entityId": "sky"})).unwrap_err().contains("Missing parallaxFactor"));
+    }
+
+    #[test]
+    fn dispatch_sprite_animation_commands() {
+        for (command, payload) in [
+            ("set_sprite_animation", json!({"entityId": "hero", "frames": [0, 1, 2, 3], "fps": 10})),
+            ("play_sprite_animation", json!({"entityId": "hero"})),
+            ("stop_sprite_animation", json!({"entityId": "hero"})),
+        ] {
+            let err = dispatch(command, payload).unwrap_err();
+            assert!(err.contains("not initialized"), "{}: {}", command, err);
+        }
+
+        let err = dispatch("set_sprite_animation", json!({"entityId": "hero", "frames": [0], "fps": 0})).unwrap_err();
+        assert!(err.contains("fps"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_ik_2d_commands_reach_sprites_handler() {
+        for (command, payload) in [
+            ("create_ik_chain2d", json!({"entityId": "hero", "chainName": "leg", "targetBone": "shin", "chainLength": 2})),
+            ("set_ik_target2d", json!({"entityId": "hero", "chainName": "leg", "x": 10.0, "y": -70.0})),
+            ("set_ik_target2d", json!({"entityId": "hero", "chainName": "leg", "targetEntityId": "foot-target"})),
+        ] {
+            let err = dispatch(command, payload).unwrap_err();
+            assert!(err.contains("not initialized"), "{}: {}", command, err);
+        }
+
+        let err = dispatch("set_ik_target2d", json!({"entityId": "hero", "chainName": "leg"})).unwrap_err();
+        assert!(err.contains("Missing target"), "got: {}", err);
+    }
+
+    #[test]
+    fn dispatch_blend_tree_2d_commands_reach_sprites_handler() {
+        let tree = json!({
+            "entityId": "hero",
+            "parameterName": "move",
+            "treeType": {"type": "directional2d", "clips": [
+                {"animationName": "walk_n", "position": [0.0, 1.0]},
+                {"animationName": "walk_e", "position": [1.0, 0.0]}
+            ]}
+        });
+        for (command, payload) in [
+            ("set_blend_tree2d", tree),
+            ("remove_blend_tree2d", json!({"entityId": "hero"})),
+            ("set_blend_param2d", json!({"entityId": "hero", "name": "move", "x": 0.5, "y": 0.5})),
+        ] {
+            let err = dispatch(command, payload).unwrap_err();
+            assert!(err.contains("not initialized"), "{}: {}", command, err);
+        }
+
+        let err = dispatch("set_blend_tree2d", json!({"entityId": "hero", "parameterName": "move"})).unwrap_err();
+        assert!(err.contains("Invalid set_blend_tree2d payload"), "got: {}", err);
+    }
+
     // === dispatch_batch tests (PF-663) ===
 
     #[test]
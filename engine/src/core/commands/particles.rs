@@ -7,10 +7,13 @@ use crate::core::pending_commands::{
     queue_particle_toggle_from_bridge,
     queue_particle_preset_from_bridge,
     queue_particle_playback_from_bridge,
+    queue_particle_sub_emitter_update_from_bridge,
+    queue_particle_force_field_update_from_bridge,
     ParticleUpdate, ParticleRemoval, ParticleToggle, ParticlePresetRequest, ParticlePlayback,
+    ParticleSubEmitterUpdate, ParticleForceFieldUpdate,
     QueryRequest,
 };
-use crate::core::particles::ParticleData as CoreParticleData;
+use crate::core::particles::{ParticleData as CoreParticleData, ParticleForceField, SubEmitterTrigger};
 
 /// Dispatch particle commands
 pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::CommandResult> {
@@ -22,6 +25,8 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "play_particle" => Some(handle_play_particle(payload.clone())),
         "stop_particle" => Some(handle_stop_particle(payload.clone())),
         "burst_particle" => Some(handle_burst_particle(payload.clone())),
+        "set_particle_sub_emitter" => Some(handle_set_particle_sub_emitter(payload.clone())),
+        "set_particle_force_field" => Some(handle_set_particle_force_field(payload.clone())),
         "get_particle" => {
             let entity_id = payload.get("entityId")
                 .and_then(|v| v.as_str())
@@ -220,3 +225,72 @@ fn handle_burst_particle(payload: serde_json::Value) -> super::CommandResult {
         Err("PendingCommands resource not initialized".to_string())
     }
 }
+
+/// Payload for set_particle_sub_emitter command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetParticleSubEmitterPayload {
+    entity_id: String,
+    /// Omitted or null removes the sub-emitter.
+    #[serde(default)]
+    sub_emitter: Option<CoreParticleData>,
+    #[serde(default)]
+    trigger: SubEmitterTrigger,
+}
+
+/// Handle set_particle_sub_emitter command.
+fn handle_set_particle_sub_emitter(payload: serde_json::Value) -> super::CommandResult {
+    let data: SetParticleSubEmitterPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_particle_sub_emitter payload: {}", e))?;
+
+    if data.sub_emitter.as_ref().is_some_and(|sub| sub.sub_emitter.is_some()) {
+        return Err("Sub-emitters cannot be nested".to_string());
+    }
+
+    let update = ParticleSubEmitterUpdate {
+        entity_id: data.entity_id.clone(),
+        sub_emitter: data.sub_emitter,
+        trigger: data.trigger,
+    };
+
+    if queue_particle_sub_emitter_update_from_bridge(update) {
+        tracing::info!("Queued particle sub-emitter update for entity: {}", data.entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for set_particle_force_field command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetParticleForceFieldPayload {
+    entity_id: String,
+    /// Omitted or null removes the force field.
+    #[serde(default)]
+    force_field: Option<ParticleForceField>,
+}
+
+/// Handle set_particle_force_field command.
+fn handle_set_particle_force_field(payload: serde_json::Value) -> super::CommandResult {
+    let data: SetParticleForceFieldPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_particle_force_field payload: {}", e))?;
+
+    if let Some(field) = &data.force_field {
+        if !field.radius.is_finite() || field.radius <= 0.0 {
+            return Err("Force field radius must be positive".to_string());
+        }
+    }
+
+    let update = ParticleForceFieldUpdate {
+        entity_id: data.entity_id.clone(),
+        force_field: data.force_field,
+    };
+
+    if queue_particle_force_field_update_from_bridge(update) {
+        tracing::info!("Queued particle force field update for entity: {}", data.entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
@@ -6,10 +6,12 @@ use serde_json::Value;
 use crate::core::pending::{
     SetLodRequest, GenerateLodsRequest, SetPerformanceBudgetRequest,
     GetPerformanceStatsRequest, OptimizeSceneRequest, SetLodDistancesRequest,
-    SetSimplificationBackendRequest,
+    SetSimplificationBackendRequest, SetCullingEnabledRequest, SetOcclusionCullingRequest,
+    SetStatsStreamRequest,
     bridge_set_lod, bridge_generate_lods, bridge_set_performance_budget,
     bridge_get_performance_stats, bridge_optimize_scene, bridge_set_lod_distances,
-    bridge_set_simplification_backend,
+    bridge_set_simplification_backend, bridge_set_culling_enabled, bridge_set_occlusion_culling,
+    bridge_set_stats_stream,
 };
 use super::CommandResult;
 
@@ -23,6 +25,10 @@ pub fn dispatch(command: &str, payload: &Value) -> Option<CommandResult> {
         "optimize_scene" => Some(handle_optimize_scene()),
         "set_lod_distances" => Some(handle_set_lod_distances(payload)),
         "set_simplification_backend" => Some(handle_set_simplification_backend(payload)),
+        "set_culling_enabled" => Some(handle_set_culling_enabled(payload)),
+        "set_occlusion_culling" => Some(handle_set_occlusion_culling(payload)),
+        "start_stats_stream" => Some(handle_set_stats_stream(true)),
+        "stop_stats_stream" => Some(handle_set_stats_stream(false)),
         _ => None,
     }
 }
@@ -151,3 +157,80 @@ fn handle_set_simplification_backend(payload: &Value) -> CommandResult {
 
     Ok(())
 }
+
+#[derive(Deserialize)]
+struct SetCullingEnabledPayload {
+    enabled: bool,
+}
+
+/// Toggle CPU frustum culling on all mesh entities, for A/B-ing its cost.
+fn handle_set_culling_enabled(payload: &Value) -> CommandResult {
+    let params: SetCullingEnabledPayload = serde_json::from_value(payload.clone())
+        .map_err(|e| format!("Invalid set_culling_enabled payload: {}", e))?;
+
+    tracing::info!("Frustum culling {}", if params.enabled { "enabled" } else { "disabled" });
+
+    #[cfg(target_arch = "wasm32")]
+    bridge_set_culling_enabled(params.enabled);
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct SetOcclusionCullingPayload {
+    enabled: bool,
+}
+
+/// Toggle coarse occlusion culling. Only the WebGPU build carries it.
+fn handle_set_occlusion_culling(payload: &Value) -> CommandResult {
+    let params: SetOcclusionCullingPayload = serde_json::from_value(payload.clone())
+        .map_err(|e| format!("Invalid set_occlusion_culling payload: {}", e))?;
+
+    if !cfg!(feature = "webgpu") {
+        return Err("Occlusion culling requires the WebGPU backend".to_string());
+    }
+
+    tracing::info!("Occlusion culling {}", if params.enabled { "enabled" } else { "disabled" });
+
+    #[cfg(target_arch = "wasm32")]
+    bridge_set_occlusion_culling(params.enabled);
+
+    Ok(())
+}
+
+/// Start or stop the throttled `STATS_UPDATE` event stream.
+fn handle_set_stats_stream(active: bool) -> CommandResult {
+    tracing::info!("Stats stream {}", if active { "started" } else { "stopped" });
+
+    #[cfg(target_arch = "wasm32")]
+    bridge_set_stats_stream(active);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn set_culling_enabled_accepts_bool() {
+        let result = dispatch("set_culling_enabled", &json!({"enabled": false}));
+        assert_eq!(result, Some(Ok(())));
+    }
+
+    #[test]
+    fn set_culling_enabled_rejects_missing_flag() {
+        let err = dispatch("set_culling_enabled", &json!({})).unwrap().unwrap_err();
+        assert!(err.contains("Invalid set_culling_enabled payload"), "got: {}", err);
+    }
+
+    #[test]
+    fn set_occlusion_culling_requires_webgpu() {
+        let err = dispatch("set_occlusion_culling", &json!({})).unwrap().unwrap_err();
+        assert!(err.contains("Invalid set_occlusion_culling payload"), "got: {}", err);
+
+        let result = dispatch("set_occlusion_culling", &json!({"enabled": true})).unwrap();
+        assert_eq!(result.is_ok(), cfg!(feature = "webgpu"), "got: {:?}", result);
+    }
+}
@@ -4,7 +4,7 @@ use serde::Deserialize;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::core::pending_commands::*;
-use crate::core::physics::{PhysicsData, JointData, JointType, JointLimits, JointMotor};
+use crate::core::physics::{PhysicsData, JointData, JointType, JointLimits, JointMotor, RadialFalloff};
 
 /// Monotonic counter for request IDs — avoids `SystemTime::now()` which panics on WASM.
 static RAYCAST_COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -24,17 +24,21 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "update_physics" => Some(handle_update_physics(payload.clone())),
         "toggle_physics" => Some(handle_toggle_physics(payload.clone())),
         "toggle_debug_physics" => Some(handle_toggle_debug_physics(payload.clone())),
+        "set_physics_timestep" => Some(handle_set_physics_timestep(payload.clone())),
         "get_physics" => {
             let entity_id = payload.get("entityId")?.as_str()?.to_string();
             Some(super::handle_query(QueryRequest::PhysicsState { entity_id }))
         }
         "apply_force" => Some(handle_apply_force(payload.clone())),
+        "apply_radial_impulse" => Some(handle_apply_radial_impulse(payload.clone())),
+        "bake_physics_to_animation" => Some(handle_bake_physics_to_animation(payload.clone())),
         "raycast_query" => Some(handle_raycast_query(payload.clone())),
 
         // 3D Joints
         "create_joint" => Some(handle_create_joint(payload.clone())),
         "update_joint" => Some(handle_update_joint(payload.clone())),
         "remove_joint" => Some(handle_remove_joint(payload.clone())),
+        "set_joint_motor_target" => Some(handle_set_joint_motor_target(payload.clone())),
         "list_joints" => Some(super::handle_query(QueryRequest::ListJoints)),
 
         // 2D Physics
@@ -207,6 +211,75 @@ fn handle_apply_force(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Payload for apply_radial_impulse command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RadialImpulsePayload {
+    center: [f32; 3],
+    radius: f32,
+    strength: f32,
+    #[serde(default)]
+    falloff: RadialFalloff,
+}
+
+/// Handle apply_radial_impulse command (Play mode only).
+fn handle_apply_radial_impulse(payload: serde_json::Value) -> super::CommandResult {
+    let data: RadialImpulsePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid apply_radial_impulse payload: {}", e))?;
+    if !(data.radius.is_finite() && data.radius > 0.0) {
+        return Err("radius must be a positive number".to_string());
+    }
+
+    let request = RadialImpulseRequest {
+        center: data.center,
+        radius: data.radius,
+        strength: data.strength,
+        falloff: data.falloff,
+    };
+
+    if queue_radial_impulse_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for bake_physics_to_animation command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BakePhysicsPayload {
+    entity_ids: Vec<String>,
+    duration: f32,
+    fps: f32,
+}
+
+/// Handle bake_physics_to_animation command.
+fn handle_bake_physics_to_animation(payload: serde_json::Value) -> super::CommandResult {
+    let data: BakePhysicsPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid bake_physics_to_animation payload: {}", e))?;
+    if data.entity_ids.is_empty() {
+        return Err("entityIds must not be empty".to_string());
+    }
+    if !(data.duration.is_finite() && data.duration > 0.0) {
+        return Err("duration must be a positive number".to_string());
+    }
+    if !(data.fps.is_finite() && data.fps > 0.0) {
+        return Err("fps must be a positive number".to_string());
+    }
+
+    let request = BakePhysicsRequest {
+        entity_ids: data.entity_ids,
+        duration: data.duration,
+        fps: data.fps,
+    };
+
+    if queue_bake_physics_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct RaycastPayload {
@@ -214,6 +287,7 @@ struct RaycastPayload {
     origin: [f32; 3],
     direction: [f32; 3],
     max_distance: Option<f32>,
+    filter_groups: Option<u32>,
 }
 
 /// Handle raycast_query command.
@@ -229,6 +303,7 @@ fn handle_raycast_query(payload: serde_json::Value) -> super::CommandResult {
         origin: data.origin,
         direction: data.direction,
         max_distance,
+        filter_groups: data.filter_groups,
     };
 
     if queue_raycast_from_bridge(request) {
@@ -492,6 +567,41 @@ fn handle_remove_joint(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Payload for set_joint_motor_target command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JointMotorTargetPayload {
+    joint_id: String,
+    target_vel: Option<f32>,
+    target_pos: Option<f32>,
+    stiffness: Option<f32>,
+    damping: Option<f32>,
+}
+
+/// Handle set_joint_motor_target command (Play mode only).
+/// `jointId` is the entity the joint was created on.
+fn handle_set_joint_motor_target(payload: serde_json::Value) -> super::CommandResult {
+    let data: JointMotorTargetPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_joint_motor_target payload: {}", e))?;
+    if data.stiffness.is_some_and(|s| s < 0.0) || data.damping.is_some_and(|d| d < 0.0) {
+        return Err("stiffness and damping must not be negative".to_string());
+    }
+
+    let request = JointMotorTargetRequest {
+        joint_id: data.joint_id,
+        target_vel: data.target_vel,
+        target_pos: data.target_pos,
+        stiffness: data.stiffness,
+        damping: data.damping,
+    };
+
+    if queue_joint_motor_target_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 // ============================================================================
 // 2D Physics Handlers
 // ============================================================================
@@ -737,11 +847,13 @@ fn handle_apply_impulse2d(payload: serde_json::Value) -> super::CommandResult {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Raycast2dPayload {
+    request_id: Option<String>,
     origin_x: f32,
     origin_y: f32,
     dir_x: f32,
     dir_y: f32,
     max_distance: f32,
+    filter_groups: Option<u32>,
 }
 
 /// Handle raycast2d command.
@@ -749,16 +861,19 @@ fn handle_raycast2d(payload: serde_json::Value) -> super::CommandResult {
     let data: Raycast2dPayload = serde_json::from_value(payload)
         .map_err(|e| format!("Invalid raycast2d payload: {}", e))?;
 
+    let request_id = data.request_id.unwrap_or_else(next_request_id);
     let request = Raycast2dRequest {
+        request_id: request_id.clone(),
         origin_x: data.origin_x,
         origin_y: data.origin_y,
         dir_x: data.dir_x,
         dir_y: data.dir_y,
         max_distance: data.max_distance,
+        filter_groups: data.filter_groups,
     };
 
     if queue_raycast2d_from_bridge(request) {
-        tracing::info!("Queued 2D raycast");
+        tracing::info!("Queued 2D raycast: {}", request_id);
         Ok(())
     } else {
         Err("PendingCommands resource not initialized".to_string())
@@ -815,6 +930,50 @@ fn handle_set_debug_physics2d(payload: serde_json::Value) -> super::CommandResul
     }
 }
 
+/// Payload for set_physics_timestep command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetPhysicsTimestepPayload {
+    fixed_dt: Option<f32>,
+    #[serde(default = "default_substeps")]
+    substeps: usize,
+    #[serde(default)]
+    physics_determinism: bool,
+}
+
+fn default_substeps() -> usize {
+    1
+}
+
+/// Handle set_physics_timestep command. A missing `fixedDt` returns to
+/// frame-time stepping unless `physicsDeterminism` is set.
+fn handle_set_physics_timestep(payload: serde_json::Value) -> super::CommandResult {
+    let data: SetPhysicsTimestepPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_physics_timestep payload: {}", e))?;
+
+    if let Some(dt) = data.fixed_dt {
+        if !dt.is_finite() || dt <= 0.0 || dt > 1.0 {
+            return Err(format!("fixedDt must be in (0, 1] seconds, got {}", dt));
+        }
+    }
+    if !(1..=16).contains(&data.substeps) {
+        return Err(format!("substeps must be between 1 and 16, got {}", data.substeps));
+    }
+
+    let request = PhysicsTimestepRequest {
+        fixed_dt: data.fixed_dt,
+        substeps: data.substeps,
+        physics_determinism: data.physics_determinism,
+    };
+
+    if queue_physics_timestep_from_bridge(request) {
+        tracing::info!("Queued physics timestep: {:?} x{}", data.fixed_dt, data.substeps);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -824,6 +983,20 @@ mod tests {
         dispatch(command, &payload).expect("physics dispatch returned None for known command")
     }
 
+    // === set_physics_timestep ===
+
+    #[test]
+    fn set_physics_timestep_validates_payload() {
+        let err = run("set_physics_timestep", json!({"fixedDt": 0.0})).unwrap_err();
+        assert!(err.contains("fixedDt"), "got: {}", err);
+
+        let err = run("set_physics_timestep", json!({"fixedDt": 0.016, "substeps": 0})).unwrap_err();
+        assert!(err.contains("substeps"), "got: {}", err);
+
+        let err = run("set_physics_timestep", json!({"fixedDt": 0.016, "substeps": 4, "physicsDeterminism": true})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     // === update_physics ===
 
     #[test]
@@ -861,6 +1034,30 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    #[test]
+    fn update_physics_accepts_collision_layers() {
+        let result = run("update_physics", json!({
+            "entityId": "entity-1",
+            "bodyType": "dynamic",
+            "colliderShape": "auto",
+            "restitution": 0.3,
+            "friction": 0.5,
+            "density": 1.0,
+            "gravityScale": 1.0,
+            "lockTranslationX": false,
+            "lockTranslationY": false,
+            "lockTranslationZ": false,
+            "lockRotationX": false,
+            "lockRotationY": false,
+            "lockRotationZ": false,
+            "isSensor": true,
+            "collisionMembership": 2,
+            "collisionFilter": 1
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
     #[test]
     fn update_physics_rejects_missing_required_physics_fields() {
         // bodyType and colliderShape are required (no default) in PhysicsData
@@ -941,6 +1138,54 @@ mod tests {
         );
     }
 
+    // === set_joint_motor_target ===
+
+    #[test]
+    fn set_joint_motor_target_validates_payload() {
+        let result = run("set_joint_motor_target", json!({"jointId": "wheel", "targetVel": 2.0}));
+        assert!(result.unwrap_err().contains("not initialized"));
+
+        let result = run("set_joint_motor_target", json!({"targetVel": 2.0}));
+        assert!(result.unwrap_err().contains("Invalid"));
+        let result = run("set_joint_motor_target", json!({"jointId": "wheel", "damping": -1.0}));
+        assert!(result.unwrap_err().contains("negative"));
+    }
+
+    // === apply_radial_impulse ===
+
+    #[test]
+    fn apply_radial_impulse_validates_payload() {
+        for falloff in ["linear", "none"] {
+            let result = run("apply_radial_impulse", json!({
+                "center": [0.0, 1.0, 0.0],
+                "radius": 5.0,
+                "strength": 20.0,
+                "falloff": falloff
+            }));
+            assert!(result.unwrap_err().contains("not initialized"));
+        }
+
+        let result = run("apply_radial_impulse", json!({"center": [0.0, 0.0, 0.0], "radius": 0.0, "strength": 1.0}));
+        assert!(result.unwrap_err().contains("radius"));
+        let result = run("apply_radial_impulse", json!({"center": [0.0, 0.0, 0.0], "radius": 2.0, "strength": 1.0, "falloff": "cubic"}));
+        assert!(result.unwrap_err().contains("Invalid"));
+    }
+
+    // === bake_physics_to_animation ===
+
+    #[test]
+    fn bake_physics_to_animation_validates_payload() {
+        let result = run("bake_physics_to_animation", json!({"entityIds": ["ball"], "duration": 2.0, "fps": 30.0}));
+        assert!(result.unwrap_err().contains("not initialized"));
+
+        let result = run("bake_physics_to_animation", json!({"entityIds": [], "duration": 2.0, "fps": 30.0}));
+        assert!(result.unwrap_err().contains("entityIds"));
+        let result = run("bake_physics_to_animation", json!({"entityIds": ["ball"], "duration": 0.0, "fps": 30.0}));
+        assert!(result.unwrap_err().contains("duration"));
+        let result = run("bake_physics_to_animation", json!({"entityIds": ["ball"], "duration": 2.0, "fps": -1.0}));
+        assert!(result.unwrap_err().contains("fps"));
+    }
+
     // === set_physics2d ===
 
     #[test]
@@ -989,4 +1234,28 @@ mod tests {
         let result = dispatch("definitely_not_physics", &json!({}));
         assert!(result.is_none(), "Unknown command should return None");
     }
+
+    // === raycast_query ===
+
+    #[test]
+    fn raycast_query_accepts_filter_groups() {
+        let result = run("raycast_query", json!({
+            "origin": [0.0, 1.0, 0.0],
+            "direction": [0.0, -1.0, 0.0],
+            "filterGroups": 4
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn raycast_query_rejects_negative_filter_groups() {
+        let result = run("raycast_query", json!({
+            "origin": [0.0, 1.0, 0.0],
+            "direction": [0.0, -1.0, 0.0],
+            "filterGroups": -1
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid raycast_query payload"));
+    }
 }
@@ -0,0 +1,621 @@
+//! CSG, procedural mesh, prefab and quality command handlers.
+
+use bevy::math::Vec3;
+use serde::Deserialize;
+use crate::core::csg::CsgOperation;
+use crate::core::pending::procedural::{
+    queue_csg_from_bridge, queue_csg_chain_from_bridge, queue_extrude_from_bridge, queue_lathe_from_bridge,
+    queue_loft_from_bridge, queue_sweep_from_bridge, queue_subdivide_from_bridge, queue_apply_transform_from_bridge,
+    queue_array_from_bridge, queue_instance_array_from_bridge, queue_clone_pattern_from_bridge, queue_combine_from_bridge,
+    ClonePattern, ClonePatternRequest, CsgRequest, CsgChainRequest, CsgChainStep,
+    ExtrudeRequest, LatheRequest, LoftRequest, SweepRequest, SubdivideRequest, ApplyTransformRequest, ArrayRequest, CombineRequest,
+};
+use crate::core::pending::scene::{
+    queue_create_prefab_from_bridge, queue_export_prefab_library_from_bridge, queue_import_prefab_library_from_bridge,
+    queue_instantiate_prefab_from_bridge, queue_prefab_update_from_bridge, queue_quality_preset_from_bridge,
+    CreatePrefabRequest, ExportPrefabLibraryRequest, ImportPrefabLibraryRequest, InstantiatePrefabRequest,
+    PrefabUpdateRequest, QualityPresetRequest,
+};
+use crate::core::pending_commands::QueryRequest;
+
+mod terrain;
+
+/// Dispatch procedural commands, then terrain commands.
+pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::CommandResult> {
+    match command {
+        "csg_union" => Some(handle_csg(payload.clone(), CsgOperation::Union)),
+        "csg_subtract" => Some(handle_csg(payload.clone(), CsgOperation::Subtract)),
+        "csg_intersect" => Some(handle_csg(payload.clone(), CsgOperation::Intersect)),
+        "csg_chain" => Some(handle_csg_chain(payload.clone())),
+        "extrude_shape" => Some(handle_extrude_shape(payload.clone())),
+        "lathe_shape" => Some(handle_lathe_shape(payload.clone())),
+        "loft_shape" => Some(handle_loft_shape(payload.clone())),
+        "sweep_shape" => Some(handle_sweep_shape(payload.clone())),
+        "subdivide_mesh" => Some(handle_subdivide_mesh(payload.clone())),
+        "apply_transform" => Some(handle_apply_transform(payload.clone())),
+        "array_entity" => Some(handle_array_entity(payload.clone())),
+        "instance_array" => Some(handle_instance_array(payload.clone())),
+        "clone_pattern" => Some(handle_clone_pattern(payload.clone())),
+        "combine_meshes" => Some(handle_combine_meshes(payload.clone())),
+        "instantiate_prefab" => Some(handle_instantiate_prefab(payload.clone())),
+        "create_prefab_from_entity" => Some(handle_create_prefab_from_entity(payload.clone())),
+        "update_prefab" => Some(handle_update_prefab(payload.clone())),
+        "export_prefab_library" => Some(handle_export_prefab_library(payload.clone())),
+        "import_prefab_library" => Some(handle_import_prefab_library(payload.clone())),
+        "set_quality_preset" => Some(handle_set_quality_preset(payload.clone())),
+        "get_quality_settings" => Some(super::handle_query(QueryRequest::QualitySettings)),
+        _ => terrain::dispatch(command, payload),
+    }
+}
+
+// ===== Handler Functions =====
+
+/// Handle CSG boolean operation commands.
+fn handle_csg(payload: serde_json::Value, operation: CsgOperation) -> super::CommandResult {
+    let entity_id_a = payload.get("entityIdA")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityIdA")?
+        .to_string();
+    let entity_id_b = payload.get("entityIdB")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityIdB")?
+        .to_string();
+    let delete_sources = payload.get("deleteSources")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    let result_name = payload.get("name")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    let request = CsgRequest {
+        entity_id_a,
+        entity_id_b,
+        operation,
+        delete_sources,
+        result_name,
+    };
+
+    if queue_csg_from_bridge(request) {
+        tracing::info!("Queued CSG {:?} operation", operation);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// One step of a csg_chain payload.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CsgChainStepPayload {
+    op: CsgOperation,
+    entity_id: String,
+}
+
+/// Payload for csg_chain command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CsgChainPayload {
+    base_entity_id: String,
+    operations: Vec<CsgChainStepPayload>,
+    result_name: Option<String>,
+    #[serde(default = "super::default_true")]
+    delete_sources: bool,
+}
+
+fn handle_csg_chain(payload: serde_json::Value) -> super::CommandResult {
+    let data: CsgChainPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid csg_chain payload: {}", e))?;
+
+    if data.operations.is_empty() {
+        return Err("CSG chain requires at least one operation".to_string());
+    }
+    let mut seen = std::collections::HashSet::new();
+    seen.insert(data.base_entity_id.as_str());
+    for step in &data.operations {
+        if !seen.insert(step.entity_id.as_str()) {
+            return Err(format!("Entity {} appears more than once in the CSG chain", step.entity_id));
+        }
+    }
+
+    let request = CsgChainRequest {
+        base_entity_id: data.base_entity_id,
+        operations: data.operations
+            .into_iter()
+            .map(|s| CsgChainStep { operation: s.op, entity_id: s.entity_id })
+            .collect(),
+        delete_sources: data.delete_sources,
+        result_name: data.result_name,
+    };
+
+    if queue_csg_chain_from_bridge(request) {
+        tracing::info!("Queued CSG chain");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for extrude_shape command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExtrudeShapePayload {
+    shape: String,
+    radius: f32,
+    length: f32,
+    segments: u32,
+    inner_radius: Option<f32>,
+    star_points: Option<u32>,
+    size: Option<f32>,
+    name: Option<String>,
+    position: Option<[f32; 3]>,
+}
+
+fn handle_extrude_shape(payload: serde_json::Value) -> super::CommandResult {
+    let data: ExtrudeShapePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid extrude_shape payload: {}", e))?;
+
+    let request = ExtrudeRequest {
+        shape: data.shape,
+        radius: data.radius.max(0.01),
+        length: data.length.max(0.01),
+        segments: data.segments.clamp(3, 64),
+        inner_radius: data.inner_radius.map(|r| r.max(0.01)),
+        star_points: data.star_points.map(|p| p.clamp(3, 16)),
+        size: data.size.map(|s| s.max(0.01)),
+        name: data.name,
+        position: data.position.map(|p| Vec3::new(p[0], p[1], p[2])),
+    };
+
+    if queue_extrude_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for lathe_shape command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LatheShapePayload {
+    profile: Vec<[f32; 2]>,
+    segments: u32,
+    name: Option<String>,
+    position: Option<[f32; 3]>,
+}
+
+fn handle_lathe_shape(payload: serde_json::Value) -> super::CommandResult {
+    let data: LatheShapePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid lathe_shape payload: {}", e))?;
+
+    if data.profile.len() < 2 {
+        return Err("Profile must have at least 2 points".to_string());
+    }
+
+    let request = LatheRequest {
+        profile: data.profile,
+        segments: data.segments.clamp(8, 64),
+        name: data.name,
+        position: data.position.map(|p| Vec3::new(p[0], p[1], p[2])),
+    };
+
+    if queue_lathe_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for loft_shape command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoftShapePayload {
+    profiles: Vec<Vec<[f32; 2]>>,
+    #[serde(default = "super::default_true")]
+    closed: bool,
+    name: Option<String>,
+    position: Option<[f32; 3]>,
+}
+
+fn handle_loft_shape(payload: serde_json::Value) -> super::CommandResult {
+    let data: LoftShapePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid loft_shape payload: {}", e))?;
+
+    if data.profiles.len() < 2 {
+        return Err("Loft requires at least 2 profiles".to_string());
+    }
+    let min_points = if data.closed { 3 } else { 2 };
+    if data.profiles.iter().any(|p| p.len() < min_points) {
+        return Err(format!("Each profile must have at least {} points", min_points));
+    }
+
+    let request = LoftRequest {
+        profiles: data.profiles,
+        closed: data.closed,
+        name: data.name,
+        position: data.position.map(|p| Vec3::new(p[0], p[1], p[2])),
+    };
+
+    if queue_loft_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for sweep_shape command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SweepShapePayload {
+    profile: Vec<[f32; 2]>,
+    path: Vec<[f32; 3]>,
+    segments: u32,
+    name: Option<String>,
+    position: Option<[f32; 3]>,
+}
+
+fn handle_sweep_shape(payload: serde_json::Value) -> super::CommandResult {
+    let data: SweepShapePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid sweep_shape payload: {}", e))?;
+
+    if data.profile.len() < 3 {
+        return Err("Profile must have at least 3 points".to_string());
+    }
+    if data.path.len() < 2 {
+        return Err("Path must have at least 2 points".to_string());
+    }
+
+    let request = SweepRequest {
+        profile: data.profile,
+        path: data.path,
+        segments: data.segments.clamp(1, 128),
+        name: data.name,
+        position: data.position.map(|p| Vec3::new(p[0], p[1], p[2])),
+    };
+
+    if queue_sweep_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle subdivide_mesh command.
+/// Payload: { entityId: string, iterations?: number }
+fn handle_subdivide_mesh(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    // Each pass quadruples the face count, so keep it small.
+    let iterations = payload.get("iterations")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1)
+        .clamp(1, 4) as u32;
+
+    if queue_subdivide_from_bridge(SubdivideRequest { entity_id, iterations }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for apply_transform command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApplyTransformPayload {
+    entity_id: String,
+    #[serde(default)]
+    position: bool,
+    #[serde(default)]
+    rotation: bool,
+    #[serde(default)]
+    scale: bool,
+}
+
+/// Handle apply_transform command.
+/// Payload: { entityId: string, position?: bool, rotation?: bool, scale?: bool }
+fn handle_apply_transform(payload: serde_json::Value) -> super::CommandResult {
+    let data: ApplyTransformPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid apply_transform payload: {}", e))?;
+    if !(data.position || data.rotation || data.scale) {
+        return Err("apply_transform needs at least one of position, rotation or scale".to_string());
+    }
+
+    let request = ApplyTransformRequest {
+        entity_id: data.entity_id,
+        position: data.position,
+        rotation: data.rotation,
+        scale: data.scale,
+    };
+    if queue_apply_transform_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for array_entity command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArrayEntityPayload {
+    entity_id: String,
+    pattern: String,
+    count_x: Option<u32>,
+    count_y: Option<u32>,
+    count_z: Option<u32>,
+    spacing_x: Option<f32>,
+    spacing_y: Option<f32>,
+    spacing_z: Option<f32>,
+    circle_count: Option<u32>,
+    circle_radius: Option<f32>,
+}
+
+/// Build an ArrayRequest, clamping grid axes to `max_axis` and circle copies to `max_circle`.
+fn array_request_from_payload(data: ArrayEntityPayload, max_axis: u32, max_circle: u32) -> ArrayRequest {
+    ArrayRequest {
+        entity_id: data.entity_id,
+        pattern: data.pattern,
+        count_x: data.count_x.map(|c| c.clamp(1, max_axis)),
+        count_y: data.count_y.map(|c| c.clamp(1, max_axis)),
+        count_z: data.count_z.map(|c| c.clamp(1, max_axis)),
+        spacing_x: data.spacing_x,
+        spacing_y: data.spacing_y,
+        spacing_z: data.spacing_z,
+        circle_count: data.circle_count.map(|c| c.clamp(2, max_circle)),
+        circle_radius: data.circle_radius,
+    }
+}
+
+fn handle_array_entity(payload: serde_json::Value) -> super::CommandResult {
+    let data: ArrayEntityPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid array_entity payload: {}", e))?;
+
+    let request = array_request_from_payload(data, 20, 32);
+
+    if queue_array_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle instance_array: same payload as array_entity, but the copies are
+/// GPU instances on a single entity, so much larger counts are allowed.
+fn handle_instance_array(payload: serde_json::Value) -> super::CommandResult {
+    let data: ArrayEntityPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid instance_array payload: {}", e))?;
+
+    let request = array_request_from_payload(data, 50, 1024);
+
+    if queue_instance_array_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for clone_pattern command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClonePatternPayload {
+    entity_id: String,
+    pattern: String,
+    count: u32,
+    offset: Option<[f32; 3]>,
+    radius: Option<f32>,
+    #[serde(default)]
+    rotate_each: [f32; 3],
+    #[serde(default)]
+    look_at_center: bool,
+}
+
+/// Handle clone_pattern: editable clones in a linear or radial pattern, each
+/// rotated a further step (or facing the source for radial patterns).
+fn handle_clone_pattern(payload: serde_json::Value) -> super::CommandResult {
+    let data: ClonePatternPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid clone_pattern payload: {}", e))?;
+
+    let pattern = match data.pattern.as_str() {
+        "linear" => ClonePattern::Linear { offset: Vec3::from(data.offset.unwrap_or([2.0, 0.0, 0.0])) },
+        "radial" => {
+            let radius = data.radius.unwrap_or(5.0);
+            if radius <= 0.0 {
+                return Err("radius must be positive".to_string());
+            }
+            ClonePattern::Radial { radius }
+        }
+        other => return Err(format!("Unknown clone pattern: {}. Must be linear or radial", other)),
+    };
+    if data.look_at_center && !matches!(pattern, ClonePattern::Radial { .. }) {
+        return Err("lookAtCenter requires the radial pattern".to_string());
+    }
+
+    let request = ClonePatternRequest {
+        entity_id: data.entity_id,
+        pattern,
+        count: data.count.clamp(1, 64),
+        rotate_each: Vec3::from(data.rotate_each),
+        look_at_center: data.look_at_center,
+    };
+
+    if queue_clone_pattern_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for combine_meshes command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CombineMeshesPayload {
+    entity_ids: Vec<String>,
+    delete_sources: bool,
+    name: Option<String>,
+}
+
+fn handle_combine_meshes(payload: serde_json::Value) -> super::CommandResult {
+    let data: CombineMeshesPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid combine_meshes payload: {}", e))?;
+
+    if data.entity_ids.len() < 2 {
+        return Err("Must provide at least 2 entities to combine".to_string());
+    }
+
+    let request = CombineRequest {
+        entity_ids: data.entity_ids,
+        delete_sources: data.delete_sources,
+        name: data.name,
+    };
+
+    if queue_combine_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle instantiate_prefab command.
+/// Payload: { snapshot_json: string, position?: [x, y, z], name?: string, prefab_id?: string }
+fn handle_instantiate_prefab(payload: serde_json::Value) -> super::CommandResult {
+    let snapshot_json = payload.get("snapshot_json")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing snapshot_json")?
+        .to_string();
+
+    let position = payload.get("position").and_then(|v| {
+        let arr = v.as_array()?;
+        if arr.len() == 3 {
+            Some([
+                arr[0].as_f64()? as f32,
+                arr[1].as_f64()? as f32,
+                arr[2].as_f64()? as f32,
+            ])
+        } else { None }
+    });
+
+    let name = payload.get("name").and_then(|v| v.as_str()).map(|s| s.to_string());
+    let prefab_id = payload.get("prefab_id").and_then(|v| v.as_str()).map(|s| s.to_string());
+
+    let request = InstantiatePrefabRequest {
+        snapshot_json,
+        position,
+        name,
+        prefab_id,
+    };
+
+    if queue_instantiate_prefab_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle create_prefab_from_entity command — snapshots the entity and its
+/// descendants into prefab JSON, emitted as `PREFAB_CREATED`.
+/// Payload: { entityId: string, prefabName: string, linkSource?: boolean }
+fn handle_create_prefab_from_entity(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let prefab_name = payload.get("prefabName")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .ok_or("Missing prefabName")?
+        .to_string();
+    let link_source = payload.get("linkSource").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    if queue_create_prefab_from_bridge(CreatePrefabRequest { entity_id, prefab_name, link_source }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle update_prefab command — re-applies the prefab's material, light and
+/// physics data to every linked instance, keeping their local overrides.
+/// Payload: { prefab_id: string, snapshot_json: string }
+fn handle_update_prefab(payload: serde_json::Value) -> super::CommandResult {
+    let prefab_id = payload.get("prefab_id")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing prefab_id")?
+        .to_string();
+    let snapshot_json = payload.get("snapshot_json")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing snapshot_json")?;
+
+    // Instances link to the root; descendants are not updated.
+    let snapshot = crate::core::prefab::PrefabSnapshot::from_json(snapshot_json)
+        .map_err(|e| format!("Invalid prefab snapshot: {}", e))?
+        .entities
+        .into_iter()
+        .next()
+        .ok_or("Prefab snapshot has no entities")?;
+
+    if queue_prefab_update_from_bridge(PrefabUpdateRequest { prefab_id, snapshot: Box::new(snapshot) }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle export_prefab_library command — turns each top-level entity into its
+/// own prefab and emits them as one library JSON via `PREFAB_LIBRARY_EXPORTED`.
+/// Payload: { entityIds?: string[] } (defaults to the current selection)
+fn handle_export_prefab_library(payload: serde_json::Value) -> super::CommandResult {
+    let entity_ids = match payload.get("entityIds") {
+        Some(v) => Some(
+            v.as_array()
+                .ok_or("entityIds must be an array")?
+                .iter()
+                .map(|id| id.as_str().map(str::to_string).ok_or("entityIds must contain strings"))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        None => None,
+    };
+
+    if queue_export_prefab_library_from_bridge(ExportPrefabLibraryRequest { entity_ids }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle import_prefab_library command — adds the library's assets and emits
+/// its prefabs via `PREFAB_LIBRARY_IMPORTED` for the editor to register.
+/// Payload: { json: string }
+fn handle_import_prefab_library(payload: serde_json::Value) -> super::CommandResult {
+    let json = payload.get("json")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing json")?
+        .to_string();
+
+    if queue_import_prefab_library_from_bridge(ImportPrefabLibraryRequest { json }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_quality_preset command.
+/// Payload: { preset: "low" | "medium" | "high" | "ultra" }
+fn handle_set_quality_preset(payload: serde_json::Value) -> super::CommandResult {
+    let preset = payload.get("preset")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing preset")?
+        .to_string();
+
+    // Validate preset name
+    if !matches!(preset.as_str(), "low" | "medium" | "high" | "ultra") {
+        return Err(format!("Invalid quality preset: {}. Must be low, medium, high, or ultra", preset));
+    }
+
+    if queue_quality_preset_from_bridge(QualityPresetRequest { preset }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
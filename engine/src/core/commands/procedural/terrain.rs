@@ -0,0 +1,264 @@
+//! Terrain command handlers.
+
+use bevy::math::Vec3;
+use serde::Deserialize;
+use crate::core::commands::{handle_query, CommandResult};
+use crate::core::terrain::{TerrainData, NoiseType, SplatLayer, MAX_SPLAT_LAYERS};
+use crate::core::pending::procedural::{
+    queue_terrain_spawn_from_bridge, queue_terrain_update_from_bridge, queue_terrain_sculpt_from_bridge,
+    queue_terrain_hole_paint_from_bridge, queue_terrain_splat_from_bridge,
+    TerrainSpawnRequest, TerrainUpdate, TerrainSculpt, TerrainHolePaint, TerrainSplatUpdate,
+};
+use crate::core::pending_commands::QueryRequest;
+
+/// Dispatch terrain commands.
+pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<CommandResult> {
+    match command {
+        "spawn_terrain" => Some(handle_spawn_terrain(payload.clone())),
+        "update_terrain" => Some(handle_update_terrain(payload.clone())),
+        "sculpt_terrain" => Some(handle_sculpt_terrain(payload.clone())),
+        "paint_terrain_hole" => Some(handle_paint_terrain_hole(payload.clone())),
+        "set_terrain_splat" => Some(handle_set_terrain_splat(payload.clone())),
+        "get_terrain" => {
+            let entity_id = payload.get("entityId")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+            Some(handle_query(QueryRequest::TerrainState { entity_id }))
+        }
+        _ => None,
+    }
+}
+
+/// Payload for spawn_terrain command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpawnTerrainPayload {
+    name: Option<String>,
+    position: Option<[f32; 3]>,
+    noise_type: Option<String>,
+    octaves: Option<u32>,
+    frequency: Option<f64>,
+    amplitude: Option<f64>,
+    height_scale: Option<f32>,
+    seed: Option<u32>,
+    resolution: Option<u32>,
+    size: Option<f32>,
+}
+
+fn handle_spawn_terrain(payload: serde_json::Value) -> CommandResult {
+    let data: SpawnTerrainPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid spawn_terrain payload: {}", e))?;
+
+    let mut td = TerrainData::default();
+    if let Some(ref nt) = data.noise_type {
+        td.noise_type = match nt.as_str() {
+            "simplex" => NoiseType::Simplex,
+            "value" => NoiseType::Value,
+            _ => NoiseType::Perlin,
+        };
+    }
+    if let Some(v) = data.octaves {
+        td.octaves = v.clamp(1, 8);
+    }
+    if let Some(v) = data.frequency {
+        td.frequency = v;
+    }
+    if let Some(v) = data.amplitude {
+        td.amplitude = v;
+    }
+    if let Some(v) = data.height_scale {
+        td.height_scale = v;
+    }
+    if let Some(v) = data.seed {
+        td.seed = v;
+    }
+    if let Some(v) = data.resolution {
+        td.resolution = match v {
+            0..=48 => 32,
+            49..=96 => 64,
+            97..=192 => 128,
+            _ => 256,
+        };
+    }
+    if let Some(v) = data.size {
+        td.size = v.max(1.0);
+    }
+
+    let request = TerrainSpawnRequest {
+        name: data.name,
+        position: data.position.map(|p| Vec3::new(p[0], p[1], p[2])),
+        terrain_data: td,
+    };
+
+    if queue_terrain_spawn_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for update_terrain command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateTerrainPayload {
+    entity_id: String,
+    noise_type: Option<String>,
+    octaves: Option<u32>,
+    frequency: Option<f64>,
+    amplitude: Option<f64>,
+    height_scale: Option<f32>,
+    seed: Option<u32>,
+    resolution: Option<u32>,
+    size: Option<f32>,
+}
+
+fn handle_update_terrain(payload: serde_json::Value) -> CommandResult {
+    let data: UpdateTerrainPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid update_terrain payload: {}", e))?;
+
+    // Build a full TerrainData from partial payload with defaults.
+    // The apply system will merge with existing component data.
+    let mut td = TerrainData::default();
+    if let Some(ref nt) = data.noise_type {
+        td.noise_type = match nt.as_str() {
+            "simplex" => NoiseType::Simplex,
+            "value" => NoiseType::Value,
+            _ => NoiseType::Perlin,
+        };
+    }
+    if let Some(v) = data.octaves {
+        td.octaves = v.clamp(1, 8);
+    }
+    if let Some(v) = data.frequency {
+        td.frequency = v;
+    }
+    if let Some(v) = data.amplitude {
+        td.amplitude = v;
+    }
+    if let Some(v) = data.height_scale {
+        td.height_scale = v;
+    }
+    if let Some(v) = data.seed {
+        td.seed = v;
+    }
+    if let Some(v) = data.resolution {
+        td.resolution = match v {
+            0..=48 => 32,
+            49..=96 => 64,
+            97..=192 => 128,
+            _ => 256,
+        };
+    }
+    if let Some(v) = data.size {
+        td.size = v.max(1.0);
+    }
+
+    let update = TerrainUpdate {
+        entity_id: data.entity_id,
+        terrain_data: td,
+    };
+
+    if queue_terrain_update_from_bridge(update) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for sculpt_terrain command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SculptTerrainPayload {
+    entity_id: String,
+    position: [f32; 2], // x, z in world space
+    radius: f32,
+    strength: f32,
+}
+
+fn handle_sculpt_terrain(payload: serde_json::Value) -> CommandResult {
+    let data: SculptTerrainPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid sculpt_terrain payload: {}", e))?;
+
+    let sculpt = TerrainSculpt {
+        entity_id: data.entity_id,
+        position: data.position,
+        radius: data.radius.max(0.1),
+        strength: data.strength,
+    };
+
+    if queue_terrain_sculpt_from_bridge(sculpt) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for paint_terrain_hole command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PaintTerrainHolePayload {
+    entity_id: String,
+    position: [f32; 2], // x, z in world space
+    radius: f32,
+    /// true restores ground inside the brush, false cuts holes
+    #[serde(default)]
+    fill: bool,
+}
+
+fn handle_paint_terrain_hole(payload: serde_json::Value) -> CommandResult {
+    let data: PaintTerrainHolePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid paint_terrain_hole payload: {}", e))?;
+
+    let paint = TerrainHolePaint {
+        entity_id: data.entity_id,
+        position: data.position,
+        radius: data.radius.max(0.1),
+        fill: data.fill,
+    };
+
+    if queue_terrain_hole_paint_from_bridge(paint) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for set_terrain_splat command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetTerrainSplatPayload {
+    entity_id: String,
+    #[serde(default)]
+    layers: Vec<SplatLayer>,
+    splat_map: Option<String>,
+}
+
+fn handle_set_terrain_splat(payload: serde_json::Value) -> CommandResult {
+    let data: SetTerrainSplatPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_terrain_splat payload: {}", e))?;
+
+    if data.layers.len() > MAX_SPLAT_LAYERS {
+        return Err(format!("Terrain supports at most {} splat layers", MAX_SPLAT_LAYERS));
+    }
+
+    let layers = data.layers.into_iter()
+        .map(|mut layer| {
+            layer.tiling = layer.tiling.max(0.001);
+            layer.slope_range = [layer.slope_range[0].clamp(0.0, 90.0), layer.slope_range[1].clamp(0.0, 90.0)];
+            layer
+        })
+        .collect();
+
+    let update = TerrainSplatUpdate {
+        entity_id: data.entity_id,
+        layers,
+        splat_map: data.splat_map,
+    };
+
+    if queue_terrain_splat_from_bridge(update) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
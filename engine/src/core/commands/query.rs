@@ -0,0 +1,91 @@
+//! Read-only query command handlers (`get_*` and `query_batch`)
+
+use crate::core::pending_commands::QueryRequest;
+
+/// Dispatch query commands
+pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::CommandResult> {
+    if command == "query_batch" {
+        return Some(handle_query_batch(payload));
+    }
+    match parse_query_command(command, payload) {
+        Ok(request) => request.map(super::handle_query),
+        Err(e) => Some(Err(e)),
+    }
+}
+
+/// Queue several queries at once; their answers arrive in one event.
+fn handle_query_batch(payload: &serde_json::Value) -> super::CommandResult {
+    let items = payload.get("queries")
+        .and_then(|v| v.as_array())
+        .ok_or("Missing queries array")?;
+    let mut requests = Vec::with_capacity(items.len());
+    for item in items {
+        let query = item.get("command")
+            .and_then(|v| v.as_str())
+            .ok_or("Missing \"command\" field in batch query")?;
+        if query == "query_batch" {
+            return Err("Nested query batches are not supported".to_string());
+        }
+        let query_payload = item.get("payload").cloned().unwrap_or(serde_json::Value::Null);
+        let request = parse_query_command(query, &query_payload)?
+            .ok_or_else(|| format!("Not a batchable query: {}", query))?;
+        requests.push(request);
+    }
+    super::handle_query(QueryRequest::Batch(requests))
+}
+
+/// Parse a read-only query command (`get_*`) into its `QueryRequest`.
+/// Returns `Ok(None)` if `command` is not one of these queries.
+fn parse_query_command(command: &str, payload: &serde_json::Value) -> Result<Option<QueryRequest>, String> {
+    let request = match command {
+        "get_mode" => QueryRequest::EngineMode,
+        "get_scene_graph" => QueryRequest::SceneGraph,
+        "get_selection" => QueryRequest::Selection,
+        "get_entity_details" => {
+            let entity_id = payload.get("entityId")
+                .and_then(|v| v.as_str())
+                .ok_or("Missing entityId")?
+                .to_string();
+            QueryRequest::EntityDetails { entity_id }
+        },
+        "get_entity_full" => {
+            let entity_id = payload.get("entityId")
+                .and_then(|v| v.as_str())
+                .ok_or("Missing entityId")?
+                .to_string();
+            QueryRequest::EntityFull { entity_id }
+        },
+        "get_camera_state" => QueryRequest::CameraState,
+        "get_entities_in_radius" => {
+            let center = vec3_field(payload, "center")?;
+            let radius = payload.get("radius")
+                .and_then(|v| v.as_f64())
+                .ok_or("Missing radius")? as f32;
+            if radius < 0.0 {
+                return Err("radius must not be negative".to_string());
+            }
+            QueryRequest::EntitiesInRadius { center, radius }
+        },
+        "get_entities_in_box" => {
+            let min = vec3_field(payload, "min")?;
+            let max = vec3_field(payload, "max")?;
+            QueryRequest::EntitiesInBox { min, max }
+        },
+        "get_entities_by_tag" => {
+            let tag = payload.get("tag")
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .ok_or("Missing tag")?;
+            QueryRequest::EntitiesByTag { tag: tag.to_string() }
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(request))
+}
+
+/// Read a `[x, y, z]` field from a command payload.
+fn vec3_field(payload: &serde_json::Value, key: &str) -> Result<[f32; 3], String> {
+    let value = payload.get(key).ok_or_else(|| format!("Missing {}", key))?;
+    serde_json::from_value(value.clone()).map_err(|e| format!("Invalid {}: {}", key, e))
+}
@@ -1,26 +1,36 @@
-//! Scene, asset, and script command handlers.
+//! Scene and asset command handlers.
 
 use bevy::math::Vec3;
 use serde::Deserialize;
+use crate::core::command_log::{CommandLogData, RecordingOptions};
 use crate::core::pending::scene::{
-    queue_scene_export_from_bridge, queue_scene_load_from_bridge, queue_new_scene_from_bridge,
+    queue_scene_export_from_bridge, queue_gltf_export_from_bridge, queue_scene_load_from_bridge, queue_scene_merge_from_bridge,
+    queue_scene_patch_from_bridge, queue_new_scene_from_bridge, queue_scene_graph_refresh_from_bridge, queue_binary_scene_export_from_bridge,
+    queue_start_command_recording_from_bridge, queue_stop_command_recording_from_bridge, queue_command_replay_from_bridge,
     queue_gltf_import_from_bridge, queue_texture_load_from_bridge, queue_place_asset_from_bridge,
     queue_delete_asset_from_bridge, queue_remove_texture_from_bridge, queue_audio_import_from_bridge,
-    SceneLoadRequest, GltfImportRequest, TextureLoadRequest, RemoveTextureRequest,
+    GltfExportRequest, SceneLoadRequest, SceneMergeRequest, ScenePatchRequest, GltfImportRequest, TextureLoadRequest, RemoveTextureRequest,
     PlaceAssetRequest, DeleteAssetRequest, AudioImportRequest,
 };
-use crate::core::pending::audio::{
-    queue_script_update_from_bridge, queue_script_removal_from_bridge,
-    ScriptUpdate, ScriptRemoval,
-};
 use crate::core::pending_commands::QueryRequest;
 
-/// Dispatch scene and asset commands.
+mod scripts;
+
+/// Dispatch scene and asset commands, then script commands.
 pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::CommandResult> {
     match command {
         "export_scene" => Some(handle_export_scene(payload.clone())),
+        "export_gltf" => Some(handle_export_gltf(payload.clone())),
         "load_scene" => Some(handle_load_scene(payload.clone())),
+        "export_scene_binary" => Some(handle_export_scene_binary(payload.clone())),
+        "load_scene_binary" => Some(handle_load_scene_binary(payload.clone())),
+        "merge_scene" => Some(handle_merge_scene(payload.clone())),
+        "apply_scene_patch" => Some(handle_apply_scene_patch(payload.clone())),
         "new_scene" => Some(handle_new_scene(payload.clone())),
+        "refresh_scene_graph" => Some(handle_refresh_scene_graph(payload.clone())),
+        "start_event_recording" => Some(handle_start_event_recording(payload.clone())),
+        "stop_event_recording" => Some(handle_stop_event_recording(payload.clone())),
+        "replay_commands" => Some(handle_replay_commands(payload.clone())),
         "import_gltf" => Some(handle_import_gltf(payload.clone())),
         "load_texture" => Some(handle_load_texture(payload.clone())),
         "remove_texture" => Some(handle_remove_texture(payload.clone())),
@@ -28,17 +38,6 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "delete_asset" => Some(handle_delete_asset(payload.clone())),
         "import_audio" => Some(handle_import_audio(payload.clone())),
         "list_assets" => Some(super::handle_query(QueryRequest::AssetList)),
-        "set_script" => Some(handle_set_script(payload.clone())),
-        "remove_script" => Some(handle_remove_script(payload.clone())),
-        "get_script" => {
-            let entity_id = payload.get("entityId")
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string();
-            Some(super::handle_query(QueryRequest::ScriptData { entity_id }))
-        }
-        "list_script_templates" => Some(super::handle_query(QueryRequest::ScriptTemplates)),
-        "apply_script_template" => Some(handle_apply_script_template(payload.clone())),
         "query_play_state" => Some(super::handle_query(QueryRequest::PlayState)),
         // Multi-scene management stubs (scene management handled JS-side)
         "list_scenes" => Some(Err("Not yet implemented: list_scenes".to_string())),
@@ -53,7 +52,7 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "get_scene_info" => Some(Err("Not yet implemented: get_scene_info".to_string())),
         "list_scene_assets" => Some(super::handle_query(QueryRequest::AssetList)),
 
-        _ => None,
+        _ => scripts::dispatch(command, payload),
     }
 }
 
@@ -69,6 +68,27 @@ fn handle_export_scene(_payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Payload for export_gltf command.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportGltfPayload {
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// Handle export_gltf command — queues a binary glTF export of all static meshes.
+fn handle_export_gltf(payload: serde_json::Value) -> super::CommandResult {
+    let data: ExportGltfPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid export_gltf payload: {}", e))?;
+
+    if queue_gltf_export_from_bridge(GltfExportRequest { name: data.name }) {
+        tracing::info!("Queued glTF export");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Handle load_scene command — receives JSON, queues full scene load.
 fn handle_load_scene(payload: serde_json::Value) -> super::CommandResult {
     let json = payload.get("json")
@@ -84,6 +104,99 @@ fn handle_load_scene(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Handle export_scene_binary command — like export_scene, but emits the scene
+/// gzip-compressed and base64-encoded in a `SCENE_EXPORTED_BINARY` event.
+fn handle_export_scene_binary(_payload: serde_json::Value) -> super::CommandResult {
+    if queue_binary_scene_export_from_bridge() {
+        tracing::info!("Queued binary scene export");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for load_scene_binary command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoadSceneBinaryPayload {
+    data_base64: String,
+}
+
+/// Handle load_scene_binary command — decompresses an `export_scene_binary`
+/// payload and queues it as a regular scene load.
+fn handle_load_scene_binary(payload: serde_json::Value) -> super::CommandResult {
+    use base64::Engine as _;
+    use crate::core::scene_file::{decode_scene_binary, MAX_SCENE_JSON_BYTES};
+
+    let data: LoadSceneBinaryPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid load_scene_binary payload: {}", e))?;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(data.data_base64.as_bytes())
+        .map_err(|e| format!("Invalid base64 scene data: {}", e))?;
+    let json = decode_scene_binary(&bytes, MAX_SCENE_JSON_BYTES)?;
+
+    if queue_scene_load_from_bridge(SceneLoadRequest { json }) {
+        tracing::info!("Queued binary scene load");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for merge_scene command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeScenePayload {
+    json: String,
+    offset: Option<[f32; 3]>,
+    prefix: Option<String>,
+}
+
+/// Handle merge_scene command — spawns a scene's entities into the current world
+/// without clearing it.
+fn handle_merge_scene(payload: serde_json::Value) -> super::CommandResult {
+    let data: MergeScenePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid merge_scene payload: {}", e))?;
+
+    let request = SceneMergeRequest {
+        json: data.json,
+        offset: data.offset.map(|o| Vec3::new(o[0], o[1], o[2])),
+        prefix: data.prefix,
+    };
+
+    if queue_scene_merge_from_bridge(request) {
+        tracing::info!("Queued scene merge");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for apply_scene_patch command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApplyScenePatchPayload {
+    patch: crate::core::scene_file::ScenePatch,
+}
+
+/// Handle apply_scene_patch command — applies an entity-level diff (see
+/// `scene_file::diff_scene`) to the current world.
+fn handle_apply_scene_patch(payload: serde_json::Value) -> super::CommandResult {
+    let data: ApplyScenePatchPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid apply_scene_patch payload: {}", e))?;
+
+    if data.patch.is_empty() {
+        return Ok(());
+    }
+
+    if queue_scene_patch_from_bridge(ScenePatchRequest { patch: data.patch }) {
+        tracing::info!("Queued scene patch");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Handle new_scene command — clears everything to defaults.
 fn handle_new_scene(_payload: serde_json::Value) -> super::CommandResult {
     if queue_new_scene_from_bridge() {
@@ -94,6 +207,57 @@ fn handle_new_scene(_payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Handle refresh_scene_graph command — re-sends the full graph as
+/// SCENE_GRAPH_UPDATE, for resyncing the hierarchy after missed patches.
+fn handle_refresh_scene_graph(_payload: serde_json::Value) -> super::CommandResult {
+    if queue_scene_graph_refresh_from_bridge() {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle start_event_recording command — records every following command
+/// until stop_event_recording.
+/// Payload: { inlineBlobs?: boolean } (keep base64 asset data in the log)
+fn handle_start_event_recording(payload: serde_json::Value) -> super::CommandResult {
+    let inline_blobs = payload.get("inlineBlobs").and_then(|v| v.as_bool()).unwrap_or(false);
+    if queue_start_command_recording_from_bridge(RecordingOptions { inline_blobs }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle stop_event_recording command — emits the log as `COMMAND_LOG`.
+fn handle_stop_event_recording(_payload: serde_json::Value) -> super::CommandResult {
+    if queue_stop_command_recording_from_bridge() {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle replay_commands command — re-sends a recorded log's commands with
+/// their original timing.
+/// Payload: { log: string } (the JSON from `COMMAND_LOG`)
+fn handle_replay_commands(payload: serde_json::Value) -> super::CommandResult {
+    let json = payload.get("log")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing log")?;
+    let log: CommandLogData = serde_json::from_str(json)
+        .map_err(|e| format!("Invalid command log: {}", e))?;
+    if log.format_version > crate::core::scene_file::SCENE_FORMAT_VERSION {
+        return Err(format!("Unsupported command log format version: {}", log.format_version));
+    }
+
+    if queue_command_replay_from_bridge(log.commands) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Payload for import_gltf command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -254,92 +418,12 @@ fn handle_import_audio(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
-/// Payload for set_script command.
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct SetScriptPayload {
-    entity_id: String,
-    source: String,
-    #[serde(default = "super::default_true")]
-    enabled: bool,
-    template: Option<String>,
-}
-
-/// Handle set_script command.
-fn handle_set_script(payload: serde_json::Value) -> super::CommandResult {
-    let data: SetScriptPayload = serde_json::from_value(payload)
-        .map_err(|e| format!("Invalid set_script payload: {}", e))?;
-
-    let update = ScriptUpdate {
-        entity_id: data.entity_id.clone(),
-        source: data.source,
-        enabled: data.enabled,
-        template: data.template,
-    };
-
-    if queue_script_update_from_bridge(update) {
-        tracing::info!("Queued script update for entity: {}", data.entity_id);
-        Ok(())
-    } else {
-        Err("PendingCommands resource not initialized".to_string())
-    }
-}
-
-/// Handle remove_script command.
-fn handle_remove_script(payload: serde_json::Value) -> super::CommandResult {
-    let entity_id = payload.get("entityId")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing entityId")?
-        .to_string();
-
-    let removal = ScriptRemoval {
-        entity_id: entity_id.clone(),
-    };
-
-    if queue_script_removal_from_bridge(removal) {
-        tracing::info!("Queued script removal for entity: {}", entity_id);
-        Ok(())
-    } else {
-        Err("PendingCommands resource not initialized".to_string())
-    }
-}
-
-/// Payload for apply_script_template command.
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct ApplyScriptTemplatePayload {
-    entity_id: String,
-    template: String,
-    #[serde(default)]
-    source: String,
-}
-
-/// Handle apply_script_template command.
-fn handle_apply_script_template(payload: serde_json::Value) -> super::CommandResult {
-    let data: ApplyScriptTemplatePayload = serde_json::from_value(payload)
-        .map_err(|e| format!("Invalid apply_script_template payload: {}", e))?;
-
-    let update = ScriptUpdate {
-        entity_id: data.entity_id.clone(),
-        source: data.source,
-        enabled: true,
-        template: Some(data.template.clone()),
-    };
-
-    if queue_script_update_from_bridge(update) {
-        tracing::info!("Queued script template '{}' for entity: {}", data.template, data.entity_id);
-        Ok(())
-    } else {
-        Err("PendingCommands resource not initialized".to_string())
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
 
-    fn run(command: &str, payload: serde_json::Value) -> Result<(), String> {
+    pub(super) fn run(command: &str, payload: serde_json::Value) -> Result<(), String> {
         dispatch(command, &payload).expect("scene dispatch returned None for known command")
     }
 
@@ -352,6 +436,22 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    // === export_gltf ===
+
+    #[test]
+    fn export_gltf_accepts_empty_payload() {
+        let result = run("export_gltf", json!({}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn export_gltf_rejects_non_string_name() {
+        let result = run("export_gltf", json!({"name": 42}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid export_gltf payload"));
+    }
+
     // === load_scene ===
 
     #[test]
@@ -363,6 +463,78 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    // === merge_scene ===
+
+    #[test]
+    fn merge_scene_accepts_json_only() {
+        let result = run("merge_scene", json!({
+            "json": "{\"entities\":[]}"
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn merge_scene_accepts_offset_and_prefix() {
+        let result = run("merge_scene", json!({
+            "json": "{\"entities\":[]}",
+            "offset": [10.0, 0.0, -5.0],
+            "prefix": "Imported_"
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    // === export_scene_binary / load_scene_binary ===
+
+    #[test]
+    fn export_scene_binary_without_init_returns_error() {
+        let result = run("export_scene_binary", json!({}));
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn load_scene_binary_rejects_bad_base64() {
+        let result = run("load_scene_binary", json!({"dataBase64": "%%%"}));
+        assert!(result.unwrap_err().contains("Invalid base64"));
+    }
+
+    #[test]
+    fn load_scene_binary_rejects_non_gzip_data() {
+        let result = run("load_scene_binary", json!({"dataBase64": "aGVsbG8="}));
+        assert!(result.unwrap_err().contains("decompress"));
+    }
+
+    // === apply_scene_patch ===
+
+    #[test]
+    fn apply_scene_patch_queues_non_empty_patch() {
+        let result = run("apply_scene_patch", json!({
+            "patch": { "removed": ["entity-1"] }
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn apply_scene_patch_empty_patch_is_noop() {
+        let result = run("apply_scene_patch", json!({ "patch": {} }));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn apply_scene_patch_rejects_missing_patch() {
+        let result = run("apply_scene_patch", json!({}));
+        assert!(result.unwrap_err().contains("Invalid apply_scene_patch payload"));
+    }
+
+    #[test]
+    fn merge_scene_rejects_missing_json_field() {
+        let result = run("merge_scene", json!({"prefix": "A_"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid merge_scene payload"));
+    }
+
     #[test]
     fn load_scene_rejects_missing_json_field() {
         let result = run("load_scene", json!({}));
@@ -393,6 +565,15 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    // === refresh_scene_graph ===
+
+    #[test]
+    fn refresh_scene_graph_accepts_any_payload() {
+        let result = run("refresh_scene_graph", json!({}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
     // === import_gltf ===
 
     #[test]
@@ -431,74 +612,6 @@ mod tests {
         );
     }
 
-    // === set_script ===
-
-    #[test]
-    fn set_script_accepts_valid_payload() {
-        let result = run("set_script", json!({
-            "entityId": "entity-1",
-            "source": "console.log('hello');"
-        }));
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not initialized"));
-    }
-
-    #[test]
-    fn set_script_enabled_defaults_to_true() {
-        // enabled has default = true, so it's optional
-        let result = run("set_script", json!({
-            "entityId": "entity-1",
-            "source": ""
-        }));
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not initialized"));
-    }
-
-    #[test]
-    fn set_script_rejects_missing_entity_id() {
-        let result = run("set_script", json!({"source": "code"}));
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(
-            err.contains("entity_id") || err.contains("entityId") || err.contains("Invalid"),
-            "Expected parse error, got: {}",
-            err
-        );
-    }
-
-    #[test]
-    fn set_script_rejects_missing_source() {
-        let result = run("set_script", json!({"entityId": "entity-1"}));
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(
-            err.contains("source") || err.contains("Invalid"),
-            "Expected parse error for missing source, got: {}",
-            err
-        );
-    }
-
-    // === remove_script ===
-
-    #[test]
-    fn remove_script_accepts_valid_entity_id() {
-        let result = run("remove_script", json!({"entityId": "entity-1"}));
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not initialized"));
-    }
-
-    #[test]
-    fn remove_script_rejects_missing_entity_id() {
-        let result = run("remove_script", json!({}));
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(
-            err.contains("entityId") || err.contains("Missing"),
-            "Expected missing entityId error, got: {}",
-            err
-        );
-    }
-
     // === place_asset ===
 
     #[test]
@@ -0,0 +1,282 @@
+//! Script command handlers.
+
+use serde::Deserialize;
+use crate::core::pending::audio::{
+    queue_script_update_from_bridge, queue_script_removal_from_bridge, queue_script_event_from_bridge,
+    queue_script_error_report_from_bridge,
+    ScriptUpdate, ScriptRemoval, ScriptEventRequest, ScriptErrorReport,
+};
+use crate::core::commands::{handle_query, CommandResult};
+use crate::core::pending_commands::QueryRequest;
+use crate::core::scripting::script_template;
+
+/// Dispatch script commands.
+pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<CommandResult> {
+    match command {
+        "set_script" => Some(handle_set_script(payload.clone())),
+        "remove_script" => Some(handle_remove_script(payload.clone())),
+        "emit_script_event" => Some(handle_emit_script_event(payload.clone())),
+        "report_script_error" => Some(handle_report_script_error(payload.clone())),
+        "get_script_errors" => {
+            let entity_id = payload.get("entityId")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            Some(handle_query(QueryRequest::ScriptErrors { entity_id }))
+        }
+        "get_script" => {
+            let entity_id = payload.get("entityId")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+            Some(handle_query(QueryRequest::ScriptData { entity_id }))
+        }
+        "list_script_templates" => Some(handle_query(QueryRequest::ScriptTemplates)),
+        "apply_script_template" => Some(handle_apply_script_template(payload.clone())),
+        _ => None,
+    }
+}
+
+/// Payload for set_script command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetScriptPayload {
+    entity_id: String,
+    source: String,
+    #[serde(default = "crate::core::commands::default_true")]
+    enabled: bool,
+    template: Option<String>,
+}
+
+/// Handle set_script command.
+fn handle_set_script(payload: serde_json::Value) -> CommandResult {
+    let data: SetScriptPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_script payload: {}", e))?;
+
+    let update = ScriptUpdate {
+        entity_id: data.entity_id.clone(),
+        source: data.source,
+        enabled: data.enabled,
+        template: data.template,
+    };
+
+    if queue_script_update_from_bridge(update) {
+        tracing::info!("Queued script update for entity: {}", data.entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle remove_script command.
+fn handle_remove_script(payload: serde_json::Value) -> CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let removal = ScriptRemoval {
+        entity_id: entity_id.clone(),
+    };
+
+    if queue_script_removal_from_bridge(removal) {
+        tracing::info!("Queued script removal for entity: {}", entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle emit_script_event command. The payload is forwarded untouched.
+/// Payload: { entityId, eventName, payload? }
+fn handle_emit_script_event(payload: serde_json::Value) -> CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let event_name = payload.get("eventName")
+        .and_then(|v| v.as_str())
+        .filter(|name| !name.is_empty())
+        .ok_or("Missing eventName")?
+        .to_string();
+    let payload = payload.get("payload").cloned().unwrap_or(serde_json::Value::Null);
+
+    if queue_script_event_from_bridge(ScriptEventRequest { entity_id, event_name, payload }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for report_script_error command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportScriptErrorPayload {
+    entity_id: String,
+    message: String,
+    #[serde(default)]
+    line: Option<u32>,
+}
+
+/// Handle report_script_error command, sent by the JS runtime when a script throws.
+fn handle_report_script_error(payload: serde_json::Value) -> CommandResult {
+    let data: ReportScriptErrorPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid report_script_error payload: {}", e))?;
+
+    if queue_script_error_report_from_bridge(ScriptErrorReport {
+        entity_id: data.entity_id,
+        message: data.message,
+        line: data.line,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for apply_script_template command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApplyScriptTemplatePayload {
+    entity_id: String,
+    template: String,
+    /// Overrides the built-in template source when non-empty.
+    #[serde(default)]
+    source: String,
+}
+
+/// Handle apply_script_template command.
+fn handle_apply_script_template(payload: serde_json::Value) -> CommandResult {
+    let data: ApplyScriptTemplatePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid apply_script_template payload: {}", e))?;
+
+    let source = if data.source.is_empty() {
+        script_template(&data.template)
+            .ok_or_else(|| format!("Unknown script template: {}", data.template))?
+            .source
+            .to_string()
+    } else {
+        data.source
+    };
+
+    let update = ScriptUpdate {
+        entity_id: data.entity_id.clone(),
+        source,
+        enabled: true,
+        template: Some(data.template.clone()),
+    };
+
+    if queue_script_update_from_bridge(update) {
+        tracing::info!("Queued script template '{}' for entity: {}", data.template, data.entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::core::commands::scene::tests::run;
+
+    // === set_script ===
+
+    #[test]
+    fn set_script_accepts_valid_payload() {
+        let result = run("set_script", json!({
+            "entityId": "entity-1",
+            "source": "console.log('hello');"
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn set_script_enabled_defaults_to_true() {
+        // enabled has default = true, so it's optional
+        let result = run("set_script", json!({
+            "entityId": "entity-1",
+            "source": ""
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn set_script_rejects_missing_entity_id() {
+        let result = run("set_script", json!({"source": "code"}));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.contains("entity_id") || err.contains("entityId") || err.contains("Invalid"),
+            "Expected parse error, got: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn set_script_rejects_missing_source() {
+        let result = run("set_script", json!({"entityId": "entity-1"}));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.contains("source") || err.contains("Invalid"),
+            "Expected parse error for missing source, got: {}",
+            err
+        );
+    }
+
+    // === apply_script_template ===
+
+    #[test]
+    fn apply_script_template_resolves_built_in_templates() {
+        let result = run("apply_script_template", json!({"entityId": "guard", "template": "patrol"}));
+        assert!(result.unwrap_err().contains("not initialized"));
+        let result = run("apply_script_template", json!({"entityId": "guard", "template": "jetpack"}));
+        assert_eq!(result.unwrap_err(), "Unknown script template: jetpack");
+        let result = run("apply_script_template", json!({"entityId": "guard", "template": "jetpack", "source": "function onUpdate(dt) {}"}));
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    // === report_script_error ===
+
+    #[test]
+    fn report_script_error_accepts_optional_line() {
+        let result = run("report_script_error", json!({"entityId": "guard", "message": "x is not defined", "line": 12}));
+        assert!(result.unwrap_err().contains("not initialized"));
+        let result = run("report_script_error", json!({"entityId": "guard", "message": "boom"}));
+        assert!(result.unwrap_err().contains("not initialized"));
+        let result = run("report_script_error", json!({"entityId": "guard"}));
+        assert!(result.unwrap_err().contains("Invalid report_script_error payload"));
+    }
+
+    // === emit_script_event ===
+
+    #[test]
+    fn emit_script_event_requires_entity_and_event_name() {
+        let result = run("emit_script_event", json!({"entityId": "player", "eventName": "level_complete"}));
+        assert!(result.unwrap_err().contains("not initialized"));
+        let result = run("emit_script_event", json!({"entityId": "player", "payload": {}}));
+        assert_eq!(result.unwrap_err(), "Missing eventName");
+    }
+
+    // === remove_script ===
+
+    #[test]
+    fn remove_script_accepts_valid_entity_id() {
+        let result = run("remove_script", json!({"entityId": "entity-1"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn remove_script_rejects_missing_entity_id() {
+        let result = run("remove_script", json!({}));
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(
+            err.contains("entityId") || err.contains("Missing"),
+            "Expected missing entityId error, got: {}",
+            err
+        );
+    }
+}
@@ -64,7 +64,8 @@ fn handle_spawn_sprite(payload: serde_json::Value) -> super::CommandResult {
 }
 
 /// Handle set_sprite_data command.
-/// Payload: { entityId, textureAssetId?, colorTint?, flipX?, flipY?, customSize?, sortingLayer?, sortingOrder?, anchor? }
+/// Payload: { entityId, textureAssetId?, colorTint?, flipX?, flipY?, customSize?, sortingLayer?, sortingOrder?, anchor?,
+///            nineSlice?: { left, right, top, bottom } | null }
 fn handle_set_sprite_data(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
         .and_then(|v| v.as_str())
@@ -118,6 +119,11 @@ fn handle_set_sprite_data(payload: serde_json::Value) -> super::CommandResult {
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
+    let nine_slice = payload.get("nineSlice")
+        .map(|v| if v.is_null() { Ok(None) } else { serde_json::from_value(v.clone()).map(Some) })
+        .transpose()
+        .map_err(|e| format!("Invalid nineSlice: {}", e))?;
+
     if queue_sprite_data_update_from_bridge(SpriteDataUpdate {
         entity_id,
         texture_asset_id,
@@ -128,6 +134,8 @@ fn handle_set_sprite_data(payload: serde_json::Value) -> super::CommandResult {
         sorting_layer,
         sorting_order,
         anchor,
+        nine_slice,
+        parallax_factor: None,
     }) {
         Ok(())
     } else {
@@ -176,6 +184,62 @@ fn handle_update_camera_2d(payload: serde_json::Value) -> super::CommandResult {
         zoom,
         pixel_perfect,
         bounds,
+        follow_target_id: None,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_camera_2d_follow command.
+/// Payload: { targetId } (null stops following)
+fn handle_set_camera_2d_follow(payload: serde_json::Value) -> super::CommandResult {
+    let target = payload.get("targetId").ok_or("Missing targetId")?;
+    let follow_target_id = if target.is_null() {
+        None
+    } else {
+        Some(target.as_str().ok_or("targetId must be a string or null")?.to_string())
+    };
+
+    if queue_camera_2d_data_update_from_bridge(Camera2dDataUpdate {
+        zoom: None,
+        pixel_perfect: None,
+        bounds: None,
+        follow_target_id: Some(follow_target_id),
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_sprite_parallax command.
+/// Payload: { entityId, parallaxFactor }
+fn handle_set_sprite_parallax(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let parallax_factor = payload.get("parallaxFactor")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing parallaxFactor")? as f32;
+    if !parallax_factor.is_finite() {
+        return Err("parallaxFactor must be a finite number".to_string());
+    }
+
+    if queue_sprite_data_update_from_bridge(SpriteDataUpdate {
+        entity_id,
+        texture_asset_id: None,
+        color_tint: None,
+        flip_x: None,
+        flip_y: None,
+        custom_size: None,
+        sorting_layer: None,
+        sorting_order: None,
+        anchor: None,
+        nine_slice: None,
+        parallax_factor: Some(parallax_factor),
     }) {
         Ok(())
     } else {
@@ -208,7 +272,7 @@ fn handle_create_skeleton2d(payload: serde_json::Value) -> super::CommandResult
 }
 
 /// Handle add_bone2d command.
-/// Payload: { entityId, boneName, parentBone?, positionX, positionY, rotation, length, order? }
+/// Payload: { entityId, boneName, parentBone?, positionX, positionY, rotation, length, order?, angleLimits?: [min, max] }
 fn handle_add_bone2d(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
         .and_then(|v| v.as_str())
@@ -264,6 +328,7 @@ fn handle_add_bone2d(payload: serde_json::Value) -> super::CommandResult {
         local_scale: [1.0, 1.0],
         length,
         color,
+        angle_limits: angle_limits_field(&payload)?.flatten(),
     };
 
     if queue_add_bone2d_from_bridge(AddBone2dRequest { entity_id, bone }) {
@@ -297,7 +362,7 @@ fn handle_remove_bone2d(payload: serde_json::Value) -> super::CommandResult {
 }
 
 /// Handle update_bone2d command.
-/// Payload: { entityId, boneName, positionX?, positionY?, rotation?, length? }
+/// Payload: { entityId, boneName, positionX?, positionY?, rotation?, length?, angleLimits?: [min, max] | null }
 fn handle_update_bone2d(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
         .and_then(|v| v.as_str())
@@ -351,6 +416,7 @@ fn handle_update_bone2d(payload: serde_json::Value) -> super::CommandResult {
         local_scale: None,
         length,
         color,
+        angle_limits: angle_limits_field(&payload)?,
     }) {
         Ok(())
     } else {
@@ -527,6 +593,14 @@ fn handle_set_skeleton2d_skin(payload: serde_json::Value) -> super::CommandResul
     }
 }
 
+/// Parse an optional `angleLimits: [min, max] | null` field (degrees).
+fn angle_limits_field(payload: &serde_json::Value) -> Result<Option<Option<[f32; 2]>>, String> {
+    payload.get("angleLimits")
+        .map(|v| if v.is_null() { Ok(None) } else { serde_json::from_value(v.clone()).map(Some) })
+        .transpose()
+        .map_err(|e| format!("Invalid angleLimits: {}", e))
+}
+
 /// Handle create_ik_chain2d command.
 /// Payload: { entityId, chainName, targetBone, chainLength, bendPositive }
 fn handle_create_ik_chain2d(payload: serde_json::Value) -> super::CommandResult {
@@ -563,6 +637,7 @@ fn handle_create_ik_chain2d(payload: serde_json::Value) -> super::CommandResult
         name: chain_name,
         bone_chain,
         target_entity_id: String::new(), // Placeholder
+        target_position: None,
         bend_direction: if bend_positive { 1.0 } else { -1.0 },
         mix: 1.0,
     };
@@ -577,6 +652,109 @@ fn handle_create_ik_chain2d(payload: serde_json::Value) -> super::CommandResult
     }
 }
 
+/// Handle set_ik_target2d command.
+/// Payload: { entityId, chainName, x, y } or { entityId, chainName, targetEntityId }
+fn handle_set_ik_target2d(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let chain_name = payload.get("chainName")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing chainName")?
+        .to_string();
+
+    let target_position = match (
+        payload.get("x").and_then(|v| v.as_f64()),
+        payload.get("y").and_then(|v| v.as_f64()),
+    ) {
+        (Some(x), Some(y)) => Some([x as f32, y as f32]),
+        _ => None,
+    };
+    let target_entity_id = payload.get("targetEntityId")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    if target_position.is_none() && target_entity_id.is_none() {
+        return Err("Missing target: provide x and y, or targetEntityId".to_string());
+    }
+
+    if queue_set_ik_target2d_from_bridge(SetIkTarget2dRequest {
+        entity_id,
+        chain_name,
+        target_position,
+        target_entity_id,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_blend_tree2d command.
+/// Payload: { entityId, parameterName, treeType: { type: "simple1d" | "directional2d" | "additive", ... } }
+fn handle_set_blend_tree2d(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let tree: crate::core::blend_tree2d::BlendTree2d = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_blend_tree2d payload: {}", e))?;
+
+    if queue_set_blend_tree2d_from_bridge(SetBlendTree2dRequest { entity_id, tree }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle remove_blend_tree2d command.
+/// Payload: { entityId }
+fn handle_remove_blend_tree2d(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    if queue_remove_blend_tree2d_from_bridge(RemoveBlendTree2dRequest { entity_id }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_blend_param2d command.
+/// Payload: { entityId, name, x, y? } (1D parameters only need x)
+fn handle_set_blend_param2d(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let name = payload.get("name")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing name")?
+        .to_string();
+
+    let x = payload.get("x")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing x")? as f32;
+    let y = payload.get("y")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0) as f32;
+
+    if queue_set_blend_param2d_from_bridge(SetBlendParam2dRequest {
+        entity_id,
+        name,
+        value: [x, y],
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Handle auto_weight_skeleton2d command.
 /// Payload: { entityId, method?, iterations? }
 fn handle_auto_weight_skeleton2d(payload: serde_json::Value) -> super::CommandResult {
@@ -912,6 +1090,77 @@ fn handle_remove_tileset(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Handle set_tileset_autotile command.
+/// Payload: { entityId, enabled?, rules: [{ mask, care?, tileIndex }] }
+fn handle_set_tileset_autotile(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+    let rules: Vec<crate::core::tileset::AutoTileRule> = payload.get("rules")
+        .cloned()
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| format!("Invalid auto-tile rules: {}", e))?
+        .ok_or("Missing rules")?;
+    let enabled = payload.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true);
+
+    let autotile = crate::core::tileset::AutoTileSet { enabled, rules };
+    if queue_set_tileset_autotile_from_bridge(SetTilesetAutotileRequest { entity_id, autotile }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle set_sprite_animation command.
+/// Payload: { entityId, frames: number[], fps, looping?, playing? }
+fn handle_set_sprite_animation(payload: serde_json::Value) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    let frames: Vec<usize> = payload.get("frames")
+        .cloned()
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| format!("Invalid frames: {}", e))?
+        .ok_or("Missing frames")?;
+
+    let fps = payload.get("fps")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing fps")? as f32;
+    if fps <= 0.0 || !fps.is_finite() {
+        return Err("fps must be a positive number".to_string());
+    }
+
+    let looping = payload.get("looping").and_then(|v| v.as_bool()).unwrap_or(true);
+    let mut animation = crate::core::sprite::SpriteAnimation::new(frames, fps, looping);
+    animation.playing = payload.get("playing").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    if queue_set_sprite_animation_from_bridge(SetSpriteAnimationRequest { entity_id, animation }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle play_sprite_animation / stop_sprite_animation commands.
+/// Payload: { entityId }
+fn handle_sprite_animation_playback(payload: serde_json::Value, playing: bool) -> super::CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?
+        .to_string();
+
+    if queue_sprite_animation_playback_from_bridge(SpriteAnimationPlaybackRequest { entity_id, playing }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::CommandResult> {
     match command {
         "spawn_sprite" => Some(handle_spawn_sprite(payload.clone())),
@@ -928,10 +1177,15 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         }
         "update_camera_2d" => Some(handle_update_camera_2d(payload.clone())),
         "get_camera_2d" => Some(super::handle_query(QueryRequest::Camera2dState)),
+        "set_camera_2d_follow" => Some(handle_set_camera_2d_follow(payload.clone())),
+        "set_sprite_parallax" => Some(handle_set_sprite_parallax(payload.clone())),
         "set_sprite_sheet" => Some(handle_set_sprite_sheet(payload.clone())),
         "remove_sprite_sheet" => Some(handle_remove_sprite_sheet(payload.clone())),
         "set_sprite_animator" => Some(handle_set_sprite_animator(payload.clone())),
         "remove_sprite_animator" => Some(handle_remove_sprite_animator(payload.clone())),
+        "set_sprite_animation" => Some(handle_set_sprite_animation(payload.clone())),
+        "play_sprite_animation" => Some(handle_sprite_animation_playback(payload.clone(), true)),
+        "stop_sprite_animation" => Some(handle_sprite_animation_playback(payload.clone(), false)),
         "set_animation_state_machine" => Some(handle_set_animation_state_machine(payload.clone())),
         "remove_animation_state_machine" => Some(handle_remove_animation_state_machine(payload.clone())),
         "create_skeleton2d" => Some(handle_create_skeleton2d(payload.clone())),
@@ -943,6 +1197,10 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "play_skeletal_animation2d" => Some(handle_play_skeletal_animation2d(payload.clone())),
         "set_skeleton2d_skin" => Some(handle_set_skeleton2d_skin(payload.clone())),
         "create_ik_chain2d" => Some(handle_create_ik_chain2d(payload.clone())),
+        "set_ik_target2d" => Some(handle_set_ik_target2d(payload.clone())),
+        "set_blend_tree2d" => Some(handle_set_blend_tree2d(payload.clone())),
+        "remove_blend_tree2d" => Some(handle_remove_blend_tree2d(payload.clone())),
+        "set_blend_param2d" => Some(handle_set_blend_param2d(payload.clone())),
         "get_skeleton2d" => {
             let entity_id = payload.get("entityId")
                 .and_then(|v| v.as_str())
@@ -957,6 +1215,7 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<super::Com
         "set_sorting_layers" => Some(handle_set_sorting_layers(payload.clone())),
         "set_tileset" => Some(handle_set_tileset(payload.clone())),
         "remove_tileset" => Some(handle_remove_tileset(payload.clone())),
+        "set_tileset_autotile" => Some(handle_set_tileset_autotile(payload.clone())),
         "get_sprite_sheet_state" => {
             let entity_id = payload.get("entityId")
                 .and_then(|v| v.as_str())
@@ -1021,7 +1280,7 @@ fn handle_remove_tilemap_data(payload: serde_json::Value) -> super::CommandResul
 }
 
 /// Handle paint_tile command.
-/// Payload: { entityId, layer, x, y, tileIndex }
+/// Payload: { entityId, layer?, x, y, tileIndex } (layer defaults to 0)
 fn handle_paint_tile(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
         .and_then(|v| v.as_str())
@@ -1030,7 +1289,7 @@ fn handle_paint_tile(payload: serde_json::Value) -> super::CommandResult {
 
     let layer = payload.get("layer")
         .and_then(|v| v.as_u64())
-        .ok_or("Missing layer")? as usize;
+        .unwrap_or(0) as usize;
 
     let x = payload.get("x")
         .and_then(|v| v.as_u64())
@@ -1052,7 +1311,7 @@ fn handle_paint_tile(payload: serde_json::Value) -> super::CommandResult {
 }
 
 /// Handle erase_tile command.
-/// Payload: { entityId, layer, x, y }
+/// Payload: { entityId, layer?, x, y }
 fn handle_erase_tile(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
         .and_then(|v| v.as_str())
@@ -1061,7 +1320,7 @@ fn handle_erase_tile(payload: serde_json::Value) -> super::CommandResult {
 
     let layer = payload.get("layer")
         .and_then(|v| v.as_u64())
-        .ok_or("Missing layer")? as usize;
+        .unwrap_or(0) as usize;
 
     let x = payload.get("x")
         .and_then(|v| v.as_u64())
@@ -1078,8 +1337,12 @@ fn handle_erase_tile(payload: serde_json::Value) -> super::CommandResult {
     }
 }
 
+/// Maximum number of tiles a single fill_tiles rect may cover.
+const MAX_FILL_TILES: u64 = 1 << 20;
+
 /// Handle fill_tiles command (batch tile placement).
-/// Payload: { entityId, layer, tiles: [{ x, y, tileIndex }] }
+/// Payload: { entityId, layer?, tiles: [{ x, y, tileIndex }] }
+/// or { entityId, layer?, rect: { x, y, width, height }, tileIndex }
 fn handle_fill_tiles(payload: serde_json::Value) -> super::CommandResult {
     let entity_id = payload.get("entityId")
         .and_then(|v| v.as_str())
@@ -1088,11 +1351,30 @@ fn handle_fill_tiles(payload: serde_json::Value) -> super::CommandResult {
 
     let layer = payload.get("layer")
         .and_then(|v| v.as_u64())
-        .ok_or("Missing layer")? as usize;
+        .unwrap_or(0) as usize;
+
+    if let Some(rect) = payload.get("rect") {
+        let rect: crate::core::tilemap::TileRect = serde_json::from_value(rect.clone())
+            .map_err(|e| format!("Invalid rect: {}", e))?;
+        let tile_index = payload.get("tileIndex")
+            .and_then(|v| v.as_u64())
+            .ok_or("Missing tileIndex")? as u32;
+        if rect.width as u64 * rect.height as u64 > MAX_FILL_TILES {
+            return Err(format!("rect covers more than {} tiles", MAX_FILL_TILES));
+        }
+        let tiles = rect.cells()
+            .map(|(x, y)| TilePlacement { x: x as usize, y: y as usize, tile_index })
+            .collect();
+        return if queue_fill_tiles_from_bridge(FillTilesRequest { entity_id, layer, tiles }) {
+            Ok(())
+        } else {
+            Err("PendingCommands resource not initialized".to_string())
+        };
+    }
 
     let raw_tiles = payload.get("tiles")
         .and_then(|v| v.as_array())
-        .ok_or("Missing tiles array")?;
+        .ok_or("Missing tiles array or rect")?;
     let mut tiles = Vec::with_capacity(raw_tiles.len());
     for (i, item) in raw_tiles.iter().enumerate() {
         let x = item.get("x").and_then(|v| v.as_u64())
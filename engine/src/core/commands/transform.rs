@@ -1,29 +1,58 @@
 //! Transform and basic entity commands - handling entity CRUD, transform, selection, gizmo, and undo/redo.
 
-use bevy::math::{Quat, Vec3, EulerRot};
+use bevy::math::{Quat, Vec2, Vec3, EulerRot};
 use serde::Deserialize;
 use crate::core::{
-    camera_presets::CameraPreset,
+    align::{AlignMode, LayoutAxis},
+    audio::AudioData,
+    camera_presets::{CameraPreset, CameraProjectionMode},
+    entity_id::EntityId,
+    game_components::GameComponents,
+    history::{EntitySnapshot, TransformSnapshot},
+    lighting::{LightData, LightProbeVolume},
+    material::MaterialData,
+    particles::ParticleData,
+    physics::PhysicsData,
+    scripting::ScriptData,
     gizmo::CoordinateMode,
-    input::{ActionDef, ActionType, InputPreset, InputSource},
+    measurement::MeasureMode,
+    snap::ROTATION_SNAP_PRESETS,
+    input::{ActionDef, ActionType, InputContext, InputPreset, InputSource},
     pending_commands::{
-        queue_transform_update_from_bridge, queue_rename_from_bridge, queue_camera_focus_from_bridge,
+        queue_transform_update_from_bridge, queue_transform_batch_update_from_bridge, queue_rename_from_bridge, queue_camera_focus_from_bridge,
         queue_camera_orbit_from_bridge,
-        queue_spawn_from_bridge, queue_delete_from_bridge, queue_duplicate_from_bridge,
+        queue_spawn_from_bridge, queue_spawn_configured_from_bridge, SpawnConfiguredRequest, queue_delete_from_bridge, queue_duplicate_from_bridge, queue_smart_duplicate_from_bridge,
         queue_reparent_from_bridge, queue_snap_settings_update_from_bridge, queue_grid_toggle_from_bridge,
-        queue_camera_preset_from_bridge, queue_coordinate_mode_update_from_bridge,
+        queue_outline_settings_update_from_bridge, OutlineSettingsUpdate,
+        queue_camera_preset_from_bridge, queue_camera_projection_from_bridge, queue_coordinate_mode_update_from_bridge,
+        queue_viewport_layout_from_bridge, ViewportLayoutRequest, queue_render_mode_from_bridge, RenderModeRequest,
+        queue_event_throttle_from_bridge, EventThrottleRequest,
         queue_input_binding_update_from_bridge, queue_input_preset_from_bridge,
-        queue_input_binding_removal_from_bridge,
-        TransformUpdate, RenameRequest, CameraFocusRequest, CameraOrbitRequest, SpawnRequest, DeleteRequest, DuplicateRequest,
-        ReparentRequest, SnapSettingsUpdate, CameraPresetRequest, EntityType,
-        InputBindingUpdate, InputPresetRequest, InputBindingRemoval,
+        queue_input_binding_removal_from_bridge, queue_input_context_from_bridge,
+        TransformUpdate, TransformBatchUpdate, RenameRequest, CameraFocusRequest, CameraOrbitRequest, SpawnRequest, DeleteRequest, DuplicateRequest,
+        ReparentRequest, SnapSettingsUpdate, CameraPresetRequest, CameraProjectionRequest, EntityType,
+        InputBindingUpdate, InputPresetRequest, InputBindingRemoval, InputContextRequest,
         QueryRequest, SelectionRequest, SelectionMode, queue_selection_from_bridge,
+        SelectionBulkRequest, queue_selection_bulk_from_bridge,
+        BoxSelectRequest, queue_box_select_from_bridge,
         VisibilityRequest, queue_visibility_from_bridge,
+        EntityLockRequest, queue_entity_lock_from_bridge,
+        EntityTagRequest, queue_entity_tag_from_bridge,
+        AlignEntitiesRequest, DistributeEntitiesRequest, queue_align_entities_from_bridge, queue_distribute_entities_from_bridge,
         queue_clear_selection_from_bridge,
         GizmoModeRequest, queue_gizmo_mode_from_bridge,
+        MeasureRequest, queue_measure_from_bridge, queue_clear_measurement_from_bridge,
+        DropToGroundRequest, queue_drop_to_ground_from_bridge,
+        FrameSelectionRequest, queue_frame_selection_from_bridge,
+        GroupRequest, UngroupRequest, queue_group_from_bridge, queue_ungroup_from_bridge,
+        CopyEntitiesRequest, PasteEntitiesRequest, queue_copy_entities_from_bridge, queue_paste_entities_from_bridge,
     },
-    history::{queue_undo_from_bridge, queue_redo_from_bridge},
-    viewport::{self, ResizePayload},
+    history::{
+        queue_undo_from_bridge, queue_redo_from_bridge, queue_begin_history_group_from_bridge,
+        queue_end_history_group_from_bridge,
+    },
+    render_mode::RenderMode,
+    viewport::{self, ResizePayload, ViewportLayout},
 };
 
 /// Result type for command execution
@@ -35,28 +64,56 @@ pub fn dispatch(command: &str, payload: &serde_json::Value) -> Option<CommandRes
         "resize" => handle_resize(payload.clone()),
         "update_scene" => handle_update_scene(payload.clone()),
         "spawn_entity" => handle_spawn_entity(payload.clone()),
+        "spawn_configured" => handle_spawn_configured(payload.clone()),
         "despawn_entity" => handle_despawn_entity(payload.clone()),
         "update_transform" => handle_update_transform(payload.clone()),
+        "update_transforms" => handle_update_transforms(payload.clone()),
         "set_camera" => handle_set_camera(payload.clone()),
         "select_entity" => handle_select_entity(payload.clone()),
         "select_entities" => handle_select_entities(payload.clone()),
         "clear_selection" => handle_clear_selection(payload.clone()),
+        "select_all_by_type" => handle_select_all_by_type(payload.clone()),
+        "invert_selection" => handle_invert_selection(payload.clone()),
+        "box_select" => handle_box_select(payload.clone()),
         "set_visibility" => handle_set_visibility(payload.clone()),
+        "set_entity_locked" => handle_set_entity_locked(payload.clone()),
+        "add_entity_tag" => handle_entity_tag(payload.clone(), true),
+        "remove_entity_tag" => handle_entity_tag(payload.clone(), false),
+        "align_entities" => handle_align_entities(payload.clone()),
+        "distribute_entities" => handle_distribute_entities(payload.clone()),
         "set_gizmo_mode" => handle_set_gizmo_mode(payload.clone()),
         "set_coordinate_mode" => handle_set_coordinate_mode(payload.clone()),
         "rename_entity" => handle_rename_entity(payload.clone()),
         "reparent_entity" => handle_reparent_entity(payload.clone()),
+        "group_entities" => handle_group_entities(payload.clone()),
+        "ungroup_entity" => handle_ungroup_entity(payload.clone()),
         "focus_camera" => handle_focus_camera(payload.clone()),
         "orbit_camera" => handle_orbit_camera(payload.clone()),
+        "frame_selection" => handle_frame_selection(payload.clone()),
         "delete_entities" => handle_delete_entities(payload.clone()),
         "duplicate_entity" => handle_duplicate_entity(payload.clone()),
+        "smart_duplicate" => handle_smart_duplicate(payload.clone()),
+        "copy_entities" => handle_copy_entities(payload.clone()),
+        "paste_entities" => handle_paste_entities(payload.clone()),
         "undo" => handle_undo(payload.clone()),
         "redo" => handle_redo(payload.clone()),
+        "begin_history_group" => handle_begin_history_group(payload.clone()),
+        "end_history_group" => handle_end_history_group(payload.clone()),
         "set_snap_settings" => handle_set_snap_settings(payload.clone()),
         "toggle_grid" => handle_toggle_grid(payload.clone()),
+        "set_outline_color" => handle_set_outline_color(payload.clone()),
+        "measure" => handle_measure(payload.clone()),
+        "clear_measurement" => handle_clear_measurement(payload.clone()),
+        "drop_to_ground" => handle_drop_to_ground(payload.clone()),
         "set_camera_preset" => handle_set_camera_preset(payload.clone()),
+        "set_camera_projection" => handle_set_camera_projection(payload.clone()),
+        "set_viewport_layout" => handle_set_viewport_layout(payload.clone()),
+        "set_render_mode" => handle_set_render_mode(payload.clone()),
+        "set_event_throttle" => handle_set_event_throttle(payload.clone()),
         "set_input_binding" => handle_set_input_binding(payload.clone()),
         "remove_input_binding" => handle_remove_input_binding(payload.clone()),
+        "push_input_context" => handle_push_input_context(payload.clone()),
+        "pop_input_context" => handle_pop_input_context(payload.clone()),
         "set_input_preset" => handle_set_input_preset(payload.clone()),
         "get_input_bindings" => super::handle_query(QueryRequest::InputBindings),
         "get_input_state" => super::handle_query(QueryRequest::InputState),
@@ -87,6 +144,8 @@ struct SpawnEntityPayload {
     entity_type: String,
     name: Option<String>,
     position: Option<[f32; 3]>,
+    /// Set by command recording so replays reuse the ID.
+    entity_id: Option<String>,
 }
 
 /// Spawn a new entity with the given components.
@@ -101,6 +160,7 @@ fn handle_spawn_entity(payload: serde_json::Value) -> CommandResult {
         entity_type,
         name: data.name,
         position: data.position.map(|p| Vec3::new(p[0], p[1], p[2])),
+        entity_id: data.entity_id,
     };
 
     if queue_spawn_from_bridge(request) {
@@ -111,6 +171,100 @@ fn handle_spawn_entity(payload: serde_json::Value) -> CommandResult {
     }
 }
 
+fn default_visible() -> bool {
+    true
+}
+
+/// Payload for spawn_configured command. Field names follow the entity
+/// snapshot, so a snapshot's component data can be passed straight through.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct SpawnConfiguredPayload {
+    entity_type: String,
+    /// Set by command recording so replays reuse the ID; generated otherwise.
+    entity_id: Option<String>,
+    name: Option<String>,
+    transform: Option<TransformSnapshot>,
+    parent_id: Option<String>,
+    #[serde(default = "default_visible")]
+    visible: bool,
+    material_data: Option<MaterialData>,
+    light_data: Option<LightData>,
+    physics_data: Option<PhysicsData>,
+    /// Defaults to whether `physics_data` is given.
+    physics_enabled: Option<bool>,
+    script_data: Option<ScriptData>,
+    audio_data: Option<AudioData>,
+    particle_data: Option<ParticleData>,
+    game_components: Option<GameComponents>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Spawn one fully configured entity, recorded as a single undo step.
+fn handle_spawn_configured(payload: serde_json::Value) -> CommandResult {
+    let data: SpawnConfiguredPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid spawn_configured payload: {}", e))?;
+
+    let entity_type = EntityType::from_str(&data.entity_type)
+        .ok_or_else(|| format!("Unknown entity type: {}", data.entity_type))?;
+    let is_light = matches!(entity_type, EntityType::PointLight | EntityType::DirectionalLight | EntityType::SpotLight);
+    // These types need data (imported meshes, CSG results, heightmaps,
+    // path points...) that only their own creation commands provide.
+    if matches!(
+        entity_type,
+        EntityType::GltfModel | EntityType::GltfMesh | EntityType::CsgResult | EntityType::Terrain
+            | EntityType::ProceduralMesh | EntityType::Sprite | EntityType::Path
+    ) {
+        return Err(format!("spawn_configured cannot create {} entities", data.entity_type));
+    }
+    let has_mesh = !is_light && !matches!(entity_type, EntityType::LightProbeVolume | EntityType::Empty);
+    if data.light_data.is_some() && !is_light {
+        return Err(format!("lightData is only valid for lights, not {}", data.entity_type));
+    }
+    if data.material_data.is_some() && !has_mesh {
+        return Err(format!("materialData is only valid for meshes, not {}", data.entity_type));
+    }
+
+    let transform = data.transform.unwrap_or(TransformSnapshot {
+        position: [0.0; 3],
+        rotation: [0.0, 0.0, 0.0, 1.0],
+        scale: [1.0; 3],
+    });
+    let name = data.name.unwrap_or_else(|| entity_type.default_name().to_string());
+    let entity_id = data.entity_id.unwrap_or_else(|| EntityId::default().0);
+    let mut snapshot = EntitySnapshot::new(entity_id, entity_type, name, transform);
+    snapshot.parent_id = data.parent_id;
+    snapshot.visible = data.visible;
+    snapshot.material_data = has_mesh.then(|| data.material_data.unwrap_or_default());
+    snapshot.light_data = match entity_type {
+        EntityType::PointLight => Some(data.light_data.unwrap_or_else(LightData::point)),
+        EntityType::DirectionalLight => Some(data.light_data.unwrap_or_else(LightData::directional)),
+        EntityType::SpotLight => Some(data.light_data.unwrap_or_else(LightData::spot)),
+        _ => None,
+    };
+    if entity_type == EntityType::LightProbeVolume {
+        snapshot.light_probe_volume = Some(LightProbeVolume::default());
+    }
+    snapshot.physics_enabled = data.physics_enabled.unwrap_or(data.physics_data.is_some());
+    snapshot.physics_data = data.physics_data.or_else(|| snapshot.physics_enabled.then(PhysicsData::default));
+    snapshot.particle_enabled = data.particle_data.is_some();
+    snapshot.particle_data = data.particle_data;
+    snapshot.script_data = data.script_data;
+    snapshot.audio_data = data.audio_data;
+    snapshot.game_components = data.game_components;
+    snapshot.tags = data.tags;
+    snapshot.tags.sort();
+    snapshot.tags.dedup();
+
+    if queue_spawn_configured_from_bridge(SpawnConfiguredRequest { snapshot }) {
+        tracing::info!("Queued configured spawn for entity type: {:?}", entity_type);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Remove an entity by ID.
 fn handle_despawn_entity(payload: serde_json::Value) -> CommandResult {
     let entity_id = payload.get("id")
@@ -138,39 +292,45 @@ struct UpdateTransformPayload {
     scale: Option<[f32; 3]>,
 }
 
-/// Update an entity's transform.
-/// Payload: { entityId: string, position?: [x,y,z], rotation?: [x,y,z], scale?: [x,y,z] }
-fn handle_update_transform(payload: serde_json::Value) -> CommandResult {
-    let data: UpdateTransformPayload = serde_json::from_value(payload)
-        .map_err(|e| format!("Invalid update_transform payload: {}", e))?;
-
+/// Validate an update_transform-shaped payload and convert it to a `TransformUpdate`.
+/// `command` prefixes validation errors so batch and single updates report the same way.
+fn build_transform_update(data: &UpdateTransformPayload, command: &str) -> Result<TransformUpdate, String> {
     // Validate all components are finite
     if let Some(p) = &data.position {
         if p.iter().any(|v| !v.is_finite()) {
-            return Err("update_transform: position contains non-finite values".to_string());
+            return Err(format!("{}: position contains non-finite values", command));
         }
     }
     if let Some(r) = &data.rotation {
         if r.iter().any(|v| !v.is_finite()) {
-            return Err("update_transform: rotation contains non-finite values".to_string());
+            return Err(format!("{}: rotation contains non-finite values", command));
         }
     }
     if let Some(s) = &data.scale {
         if s.iter().any(|v| !v.is_finite()) {
-            return Err("update_transform: scale contains non-finite values".to_string());
+            return Err(format!("{}: scale contains non-finite values", command));
         }
         // Near-zero scale (including denormals) collapses entity geometry and can cause NaN in physics
         if s.iter().any(|v| v.abs() < f32::EPSILON) {
-            return Err("update_transform: scale components must be non-zero".to_string());
+            return Err(format!("{}: scale components must be non-zero", command));
         }
     }
 
-    let update = TransformUpdate {
+    Ok(TransformUpdate {
         entity_id: data.entity_id.clone(),
         position: data.position.map(|p| Vec3::new(p[0], p[1], p[2])),
         rotation: data.rotation.map(|r| Quat::from_euler(EulerRot::XYZ, r[0], r[1], r[2])),
         scale: data.scale.map(|s| Vec3::new(s[0], s[1], s[2])),
-    };
+    })
+}
+
+/// Update an entity's transform.
+/// Payload: { entityId: string, position?: [x,y,z], rotation?: [x,y,z], scale?: [x,y,z] }
+fn handle_update_transform(payload: serde_json::Value) -> CommandResult {
+    let data: UpdateTransformPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid update_transform payload: {}", e))?;
+
+    let update = build_transform_update(&data, "update_transform")?;
 
     if queue_transform_update_from_bridge(update) {
         tracing::info!("Queued transform update for entity: {}", data.entity_id);
@@ -180,6 +340,37 @@ fn handle_update_transform(payload: serde_json::Value) -> CommandResult {
     }
 }
 
+/// Payload for update_transforms command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateTransformsPayload {
+    updates: Vec<UpdateTransformPayload>,
+}
+
+/// Update several entities' transforms in one batch (e.g. dragging a multi-selection).
+/// The whole batch is applied in a single frame and undoes as one history entry.
+/// Payload: { updates: [{ entityId, position?, rotation?, scale? }] }
+fn handle_update_transforms(payload: serde_json::Value) -> CommandResult {
+    let data: UpdateTransformsPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid update_transforms payload: {}", e))?;
+
+    if data.updates.is_empty() {
+        return Ok(());
+    }
+
+    let updates = data.updates.iter()
+        .map(|u| build_transform_update(u, "update_transforms"))
+        .collect::<Result<Vec<_>, _>>()?;
+    let count = updates.len();
+
+    if queue_transform_batch_update_from_bridge(TransformBatchUpdate { updates }) {
+        tracing::info!("Queued batch transform update for {} entities", count);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Set the active camera parameters.
 fn handle_set_camera(_payload: serde_json::Value) -> CommandResult {
     Err("set_camera is not implemented. Camera is controlled via orbit camera. Use focus_camera or set camera_preset commands instead.".to_string())
@@ -210,8 +401,8 @@ fn handle_select_entity(payload: serde_json::Value) -> CommandResult {
     Ok(())
 }
 
-/// Select multiple entities by ID.
-/// Payload: { entityIds: string[], mode: 'replace' | 'add' }
+/// Select multiple entities by ID in one step (single selection event).
+/// Payload: { entityIds: string[], mode: 'replace' | 'add' | 'toggle' }
 fn handle_select_entities(payload: serde_json::Value) -> CommandResult {
     let entity_ids = payload.get("entityIds")
         .and_then(|v| v.as_array())
@@ -229,25 +420,81 @@ fn handle_select_entities(payload: serde_json::Value) -> CommandResult {
         return Ok(());
     }
 
-    // First entity uses the requested mode, rest use Add
-    for (i, id) in ids.iter().enumerate() {
-        let selection_mode = if i == 0 {
-            match mode {
-                "add" => SelectionMode::Add,
-                "toggle" => SelectionMode::Toggle,
-                _ => SelectionMode::Replace,
-            }
-        } else {
-            SelectionMode::Add
-        };
+    let selection_mode = match mode {
+        "add" => SelectionMode::Add,
+        "toggle" => SelectionMode::Toggle,
+        _ => SelectionMode::Replace,
+    };
 
-        queue_selection_from_bridge(SelectionRequest {
-            entity_id: id.clone(),
-            mode: selection_mode,
-        });
+    if queue_selection_bulk_from_bridge(SelectionBulkRequest::Ids { entity_ids: ids, mode: selection_mode }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
     }
+}
 
-    Ok(())
+/// Select every entity of one type, replacing the current selection.
+/// Payload: { entityType: string }
+fn handle_select_all_by_type(payload: serde_json::Value) -> CommandResult {
+    let type_str = payload.get("entityType")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityType")?;
+
+    let entity_type = EntityType::from_str(type_str)
+        .ok_or_else(|| format!("Unknown entity type: {}", type_str))?;
+
+    if queue_selection_bulk_from_bridge(SelectionBulkRequest::AllByType(entity_type)) {
+        tracing::info!("Queued select all by type: {:?}", entity_type);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Invert the selection: select every selectable entity that is not selected.
+fn handle_invert_selection(_payload: serde_json::Value) -> CommandResult {
+    if queue_selection_bulk_from_bridge(SelectionBulkRequest::Invert) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for box_select command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BoxSelectPayload {
+    min: [f32; 2],
+    max: [f32; 2],
+    mode: Option<String>,
+}
+
+/// Marquee-select every entity whose projected bounds overlap a viewport rectangle.
+/// Payload: { min: [x,y], max: [x,y], mode?: 'replace' | 'add' } in viewport pixels.
+fn handle_box_select(payload: serde_json::Value) -> CommandResult {
+    let data: BoxSelectPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid box_select payload: {}", e))?;
+
+    if data.min.iter().chain(data.max.iter()).any(|v| !v.is_finite()) {
+        return Err("box_select: rectangle contains non-finite values".to_string());
+    }
+
+    let mode = match data.mode.as_deref().unwrap_or("replace") {
+        "replace" => SelectionMode::Replace,
+        "add" => SelectionMode::Add,
+        other => return Err(format!("Invalid box_select mode: {}. Must be replace or add", other)),
+    };
+
+    // Accept the corners in either order (dragging up/left yields min > max).
+    let a = Vec2::from(data.min);
+    let b = Vec2::from(data.max);
+    let request = BoxSelectRequest { min: a.min(b), max: a.max(b), mode };
+
+    if queue_box_select_from_bridge(request) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
 }
 
 /// Clear all selection.
@@ -280,6 +527,96 @@ fn handle_set_visibility(payload: serde_json::Value) -> CommandResult {
     }
 }
 
+/// Lock or unlock an entity against picking, moving and deleting.
+/// Payload: { entityId: string, locked: boolean }
+fn handle_set_entity_locked(payload: serde_json::Value) -> CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?;
+
+    let locked = payload.get("locked")
+        .and_then(|v| v.as_bool())
+        .ok_or("Missing locked boolean")?;
+
+    if queue_entity_lock_from_bridge(EntityLockRequest {
+        entity_id: entity_id.to_string(),
+        locked,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Add or remove a tag on an entity.
+/// Payload: { entityId: string, tag: string }
+fn handle_entity_tag(payload: serde_json::Value, add: bool) -> CommandResult {
+    let entity_id = payload.get("entityId")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing entityId")?;
+
+    let tag = payload.get("tag")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .ok_or("Missing tag")?;
+
+    if queue_entity_tag_from_bridge(EntityTagRequest {
+        entity_id: entity_id.to_string(),
+        tag: tag.to_string(),
+        add,
+    }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for align_entities command.
+#[derive(Debug, Deserialize)]
+struct AlignEntitiesPayload {
+    axis: LayoutAxis,
+    mode: AlignMode,
+}
+
+/// Align the selected entities' positions on one axis.
+/// Payload: { axis: 'x' | 'y' | 'z', mode: 'min' | 'center' | 'max' }
+fn handle_align_entities(payload: serde_json::Value) -> CommandResult {
+    let data: AlignEntitiesPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid align_entities payload: {}", e))?;
+
+    if queue_align_entities_from_bridge(AlignEntitiesRequest { axis: data.axis, mode: data.mode }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for distribute_entities command.
+#[derive(Debug, Deserialize)]
+struct DistributeEntitiesPayload {
+    axis: LayoutAxis,
+    #[serde(default)]
+    spacing: Option<f32>,
+}
+
+/// Space the selected entities evenly along one axis, between the outermost
+/// two or at a fixed spacing.
+/// Payload: { axis: 'x' | 'y' | 'z', spacing?: number }
+fn handle_distribute_entities(payload: serde_json::Value) -> CommandResult {
+    let data: DistributeEntitiesPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid distribute_entities payload: {}", e))?;
+    if data.spacing.is_some_and(|s| !s.is_finite()) {
+        return Err("spacing must be a finite number".to_string());
+    }
+
+    if queue_distribute_entities_from_bridge(DistributeEntitiesRequest { axis: data.axis, spacing: data.spacing }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Set gizmo mode.
 /// Payload: { mode: 'translate' | 'rotate' | 'scale' }
 fn handle_set_gizmo_mode(payload: serde_json::Value) -> CommandResult {
@@ -383,6 +720,51 @@ fn handle_reparent_entity(payload: serde_json::Value) -> CommandResult {
     }
 }
 
+/// Payload for group_entities command.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GroupEntitiesPayload {
+    name: Option<String>,
+}
+
+/// Handle group_entities command from React. Groups the current selection.
+fn handle_group_entities(payload: serde_json::Value) -> CommandResult {
+    let data: GroupEntitiesPayload = if payload.is_null() {
+        GroupEntitiesPayload::default()
+    } else {
+        serde_json::from_value(payload)
+            .map_err(|e| format!("Invalid group_entities payload: {}", e))?
+    };
+
+    if queue_group_from_bridge(GroupRequest { name: data.name }) {
+        tracing::info!("Queued group of selected entities");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for ungroup_entity command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UngroupEntityPayload {
+    entity_id: String,
+}
+
+/// Handle ungroup_entity command from React.
+fn handle_ungroup_entity(payload: serde_json::Value) -> CommandResult {
+    let data: UngroupEntityPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid ungroup_entity payload: {}", e))?;
+
+    let request = UngroupRequest { entity_id: data.entity_id.clone() };
+    if queue_ungroup_from_bridge(request) {
+        tracing::info!("Queued ungroup: {}", data.entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Payload for focus_camera command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -454,6 +836,27 @@ fn handle_orbit_camera(payload: serde_json::Value) -> CommandResult {
     }
 }
 
+/// Payload for frame_selection command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FrameSelectionPayload {
+    #[serde(default = "super::default_true")]
+    animate: bool,
+}
+
+/// Frame the selected entities, or the whole scene when nothing is selected.
+/// Payload: { animate?: boolean } (default true)
+fn handle_frame_selection(payload: serde_json::Value) -> CommandResult {
+    let data: FrameSelectionPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid frame_selection payload: {}", e))?;
+
+    if queue_frame_selection_from_bridge(FrameSelectionRequest { animate: data.animate }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Payload for delete_entities command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -475,121 +878,447 @@ fn handle_delete_entities(payload: serde_json::Value) -> CommandResult {
         entity_ids: data.entity_ids.clone(),
     };
 
-    if queue_delete_from_bridge(request) {
-        tracing::info!("Queued delete for entities: {:?}", data.entity_ids);
+    if queue_delete_from_bridge(request) {
+        tracing::info!("Queued delete for entities: {:?}", data.entity_ids);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for duplicate_entity command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateEntityPayload {
+    entity_id: String,
+}
+
+/// Duplicate an entity.
+/// Payload: { entityId: string }
+fn handle_duplicate_entity(payload: serde_json::Value) -> CommandResult {
+    let data: DuplicateEntityPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid duplicate_entity payload: {}", e))?;
+
+    let request = DuplicateRequest {
+        entity_id: data.entity_id.clone(),
+    };
+
+    if queue_duplicate_from_bridge(request) {
+        tracing::info!("Queued duplicate for entity: {}", data.entity_id);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Duplicate the selection, offsetting the copies by the last move, and
+/// select the copies so repeating it builds an evenly spaced row.
+/// Payload: {}
+fn handle_smart_duplicate(_payload: serde_json::Value) -> CommandResult {
+    if queue_smart_duplicate_from_bridge() {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for copy_entities command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CopyEntitiesPayload {
+    entity_ids: Vec<String>,
+}
+
+/// Copy entities and their descendants to the clipboard (emits ENTITIES_COPIED).
+/// Payload: { entityIds: string[] }
+fn handle_copy_entities(payload: serde_json::Value) -> CommandResult {
+    let data: CopyEntitiesPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid copy_entities payload: {}", e))?;
+
+    if data.entity_ids.is_empty() {
+        return Err("entityIds must not be empty".to_string());
+    }
+
+    let count = data.entity_ids.len();
+    if queue_copy_entities_from_bridge(CopyEntitiesRequest { entity_ids: data.entity_ids }) {
+        tracing::info!("Queued copy of {} entities", count);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for paste_entities command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PasteEntitiesPayload {
+    json: Option<String>,
+    offset: Option<[f32; 3]>,
+}
+
+/// Paste copied entities with fresh IDs, as one undoable action.
+/// Payload: { json?: string, offset?: [x, y, z] } — without `json`, pastes the last copy.
+fn handle_paste_entities(payload: serde_json::Value) -> CommandResult {
+    let data: PasteEntitiesPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid paste_entities payload: {}", e))?;
+
+    let request = PasteEntitiesRequest {
+        json: data.json,
+        offset: data.offset.map(Vec3::from),
+    };
+
+    if queue_paste_entities_from_bridge(request) {
+        tracing::info!("Queued paste");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle undo command.
+fn handle_undo(_payload: serde_json::Value) -> CommandResult {
+    if queue_undo_from_bridge() {
+        tracing::info!("Queued undo request");
+        Ok(())
+    } else {
+        Err("History system not initialized".to_string())
+    }
+}
+
+/// Handle redo command.
+fn handle_redo(_payload: serde_json::Value) -> CommandResult {
+    if queue_redo_from_bridge() {
+        tracing::info!("Queued redo request");
+        Ok(())
+    } else {
+        Err("History system not initialized".to_string())
+    }
+}
+
+/// Payload for begin_history_group command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BeginHistoryGroupPayload {
+    label: String,
+}
+
+/// Handle begin_history_group command.
+/// Actions recorded until the matching end_history_group undo as one step named `label`.
+fn handle_begin_history_group(payload: serde_json::Value) -> CommandResult {
+    let data: BeginHistoryGroupPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid payload: {}", e))?;
+
+    if data.label.trim().is_empty() {
+        return Err("label must not be empty".to_string());
+    }
+
+    if queue_begin_history_group_from_bridge(data.label.clone()) {
+        tracing::info!("Queued begin history group: {}", data.label);
+        Ok(())
+    } else {
+        Err("History system not initialized".to_string())
+    }
+}
+
+/// Handle end_history_group command.
+fn handle_end_history_group(_payload: serde_json::Value) -> CommandResult {
+    if queue_end_history_group_from_bridge() {
+        tracing::info!("Queued end history group");
+        Ok(())
+    } else {
+        Err("History system not initialized".to_string())
+    }
+}
+
+/// Payload for set_snap_settings command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetSnapSettingsPayload {
+    translation_snap: Option<f32>,
+    translation_snap_x: Option<f32>,
+    translation_snap_y: Option<f32>,
+    translation_snap_z: Option<f32>,
+    rotation_snap_degrees: Option<f32>,
+    /// One of `ROTATION_SNAP_PRESETS`, in degrees.
+    rotation_snap_preset: Option<f32>,
+    scale_snap: Option<f32>,
+    grid_visible: Option<bool>,
+    grid_size: Option<f32>,
+    grid_extent: Option<u32>,
+    vertex_snap: Option<bool>,
+    surface_snap: Option<bool>,
+}
+
+/// Handle set_snap_settings command from React.
+fn handle_set_snap_settings(payload: serde_json::Value) -> CommandResult {
+    let settings: SetSnapSettingsPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_snap_settings payload: {}", e))?;
+
+    let rotation_snap_degrees = match (settings.rotation_snap_degrees, settings.rotation_snap_preset) {
+        (Some(_), Some(_)) => {
+            return Err("set_snap_settings takes rotationSnapDegrees or rotationSnapPreset, not both".to_string());
+        }
+        (_, Some(preset)) if !ROTATION_SNAP_PRESETS.contains(&preset) => {
+            return Err(format!("rotationSnapPreset must be one of {:?}", ROTATION_SNAP_PRESETS));
+        }
+        (degrees, preset) => degrees.or(preset),
+    };
+
+    let update = SnapSettingsUpdate {
+        translation_snap: settings.translation_snap,
+        translation_snap_x: settings.translation_snap_x,
+        translation_snap_y: settings.translation_snap_y,
+        translation_snap_z: settings.translation_snap_z,
+        rotation_snap_degrees,
+        scale_snap: settings.scale_snap,
+        grid_visible: settings.grid_visible,
+        grid_size: settings.grid_size,
+        grid_extent: settings.grid_extent,
+        vertex_snap: settings.vertex_snap,
+        surface_snap: settings.surface_snap,
+    };
+
+    if queue_snap_settings_update_from_bridge(update) {
+        tracing::info!("Queued snap settings update");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle toggle_grid command from React.
+fn handle_toggle_grid(_payload: serde_json::Value) -> CommandResult {
+    if queue_grid_toggle_from_bridge() {
+        tracing::info!("Queued grid toggle");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for set_outline_color command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetOutlineColorPayload {
+    primary_color: Option<[f32; 4]>,
+    secondary_color: Option<[f32; 4]>,
+    thickness: Option<f32>,
+}
+
+/// Handle set_outline_color command from React.
+fn handle_set_outline_color(payload: serde_json::Value) -> CommandResult {
+    let data: SetOutlineColorPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_outline_color payload: {}", e))?;
+
+    if let Some(thickness) = data.thickness {
+        if !thickness.is_finite() || thickness < 0.0 {
+            return Err(format!("thickness must be a non-negative number, got {}", thickness));
+        }
+    }
+
+    let update = OutlineSettingsUpdate {
+        primary_color: data.primary_color,
+        secondary_color: data.secondary_color,
+        thickness: data.thickness,
+    };
+
+    if queue_outline_settings_update_from_bridge(update) {
+        tracing::info!("Queued outline settings update");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for measure command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MeasurePayload {
+    mode: MeasureMode,
+    points: Vec<[f32; 3]>,
+    #[serde(default = "default_snap_to_surface")]
+    snap_to_surface: bool,
+}
+
+fn default_snap_to_surface() -> bool {
+    true
+}
+
+/// Handle measure command from React.
+fn handle_measure(payload: serde_json::Value) -> CommandResult {
+    let data: MeasurePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid measure payload: {}", e))?;
+    let expected = data.mode.point_count();
+    if data.points.len() != expected {
+        return Err(format!(
+            "{:?} measurement needs {} points, got {}",
+            data.mode,
+            expected,
+            data.points.len()
+        ));
+    }
+
+    let request = MeasureRequest {
+        mode: data.mode,
+        points: data.points.into_iter().map(Vec3::from).collect(),
+        snap_to_surface: data.snap_to_surface,
+    };
+
+    if queue_measure_from_bridge(request) {
+        tracing::info!("Queued {:?} measurement", data.mode);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle clear_measurement command from React.
+fn handle_clear_measurement(_payload: serde_json::Value) -> CommandResult {
+    if queue_clear_measurement_from_bridge() {
+        tracing::info!("Queued measurement clear");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for drop_to_ground command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DropToGroundPayload {
+    entity_ids: Vec<String>,
+    #[serde(default)]
+    offset: f32,
+}
+
+/// Handle drop_to_ground command from React.
+fn handle_drop_to_ground(payload: serde_json::Value) -> CommandResult {
+    let data: DropToGroundPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid drop_to_ground payload: {}", e))?;
+    if data.entity_ids.is_empty() {
+        return Err("entityIds must not be empty".to_string());
+    }
+    if !data.offset.is_finite() {
+        return Err("offset must be a finite number".to_string());
+    }
+
+    if queue_drop_to_ground_from_bridge(DropToGroundRequest { entity_ids: data.entity_ids, offset: data.offset }) {
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Payload for set_camera_preset command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetCameraPresetPayload {
+    preset: CameraPreset,
+}
+
+/// Handle set_camera_preset command from React.
+fn handle_set_camera_preset(payload: serde_json::Value) -> CommandResult {
+    let data: SetCameraPresetPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_camera_preset payload: {}", e))?;
+
+    let request = CameraPresetRequest {
+        preset: data.preset,
+    };
+
+    if queue_camera_preset_from_bridge(request) {
+        tracing::info!("Queued camera preset: {:?}", data.preset);
         Ok(())
     } else {
         Err("PendingCommands resource not initialized".to_string())
     }
 }
 
-/// Payload for duplicate_entity command.
+/// Payload for set_camera_projection command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DuplicateEntityPayload {
-    entity_id: String,
+struct SetCameraProjectionPayload {
+    mode: CameraProjectionMode,
+    ortho_scale: Option<f32>,
 }
 
-/// Duplicate an entity.
-/// Payload: { entityId: string }
-fn handle_duplicate_entity(payload: serde_json::Value) -> CommandResult {
-    let data: DuplicateEntityPayload = serde_json::from_value(payload)
-        .map_err(|e| format!("Invalid duplicate_entity payload: {}", e))?;
+/// Handle set_camera_projection command from React.
+fn handle_set_camera_projection(payload: serde_json::Value) -> CommandResult {
+    let data: SetCameraProjectionPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_camera_projection payload: {}", e))?;
+    if data.ortho_scale.is_some_and(|s| s <= 0.0) {
+        return Err("orthoScale must be positive".to_string());
+    }
 
-    let request = DuplicateRequest {
-        entity_id: data.entity_id.clone(),
+    let request = CameraProjectionRequest {
+        mode: data.mode,
+        ortho_scale: data.ortho_scale,
     };
 
-    if queue_duplicate_from_bridge(request) {
-        tracing::info!("Queued duplicate for entity: {}", data.entity_id);
+    if queue_camera_projection_from_bridge(request) {
+        tracing::info!("Queued camera projection: {:?}", data.mode);
         Ok(())
     } else {
         Err("PendingCommands resource not initialized".to_string())
     }
 }
 
-/// Handle undo command.
-fn handle_undo(_payload: serde_json::Value) -> CommandResult {
-    if queue_undo_from_bridge() {
-        tracing::info!("Queued undo request");
-        Ok(())
-    } else {
-        Err("History system not initialized".to_string())
-    }
+/// Payload for set_viewport_layout command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetViewportLayoutPayload {
+    layout: ViewportLayout,
 }
 
-/// Handle redo command.
-fn handle_redo(_payload: serde_json::Value) -> CommandResult {
-    if queue_redo_from_bridge() {
-        tracing::info!("Queued redo request");
+/// Handle set_viewport_layout command from React.
+fn handle_set_viewport_layout(payload: serde_json::Value) -> CommandResult {
+    let data: SetViewportLayoutPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_viewport_layout payload: {}", e))?;
+
+    if queue_viewport_layout_from_bridge(ViewportLayoutRequest { layout: data.layout }) {
+        tracing::info!("Queued viewport layout: {:?}", data.layout);
         Ok(())
     } else {
-        Err("History system not initialized".to_string())
+        Err("PendingCommands resource not initialized".to_string())
     }
 }
 
-/// Payload for set_snap_settings command.
+/// Payload for set_render_mode command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SetSnapSettingsPayload {
-    translation_snap: Option<f32>,
-    rotation_snap_degrees: Option<f32>,
-    scale_snap: Option<f32>,
-    grid_visible: Option<bool>,
-    grid_size: Option<f32>,
-    grid_extent: Option<u32>,
+struct SetRenderModePayload {
+    mode: RenderMode,
 }
 
-/// Handle set_snap_settings command from React.
-fn handle_set_snap_settings(payload: serde_json::Value) -> CommandResult {
-    let settings: SetSnapSettingsPayload = serde_json::from_value(payload)
-        .map_err(|e| format!("Invalid set_snap_settings payload: {}", e))?;
-
-    let update = SnapSettingsUpdate {
-        translation_snap: settings.translation_snap,
-        rotation_snap_degrees: settings.rotation_snap_degrees,
-        scale_snap: settings.scale_snap,
-        grid_visible: settings.grid_visible,
-        grid_size: settings.grid_size,
-        grid_extent: settings.grid_extent,
-    };
-
-    if queue_snap_settings_update_from_bridge(update) {
-        tracing::info!("Queued snap settings update");
-        Ok(())
-    } else {
-        Err("PendingCommands resource not initialized".to_string())
-    }
-}
+/// Handle set_render_mode command from React.
+fn handle_set_render_mode(payload: serde_json::Value) -> CommandResult {
+    let data: SetRenderModePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_render_mode payload: {}", e))?;
 
-/// Handle toggle_grid command from React.
-fn handle_toggle_grid(_payload: serde_json::Value) -> CommandResult {
-    if queue_grid_toggle_from_bridge() {
-        tracing::info!("Queued grid toggle");
+    if queue_render_mode_from_bridge(RenderModeRequest { mode: data.mode }) {
+        tracing::info!("Queued render mode: {:?}", data.mode);
         Ok(())
     } else {
         Err("PendingCommands resource not initialized".to_string())
     }
 }
 
-/// Payload for set_camera_preset command.
+/// Payload for set_event_throttle command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SetCameraPresetPayload {
-    preset: CameraPreset,
+struct SetEventThrottlePayload {
+    event_type: String,
+    /// Omit or pass null to remove the limit.
+    #[serde(default)]
+    max_hz: Option<f64>,
 }
 
-/// Handle set_camera_preset command from React.
-fn handle_set_camera_preset(payload: serde_json::Value) -> CommandResult {
-    let data: SetCameraPresetPayload = serde_json::from_value(payload)
-        .map_err(|e| format!("Invalid set_camera_preset payload: {}", e))?;
-
-    let request = CameraPresetRequest {
-        preset: data.preset,
-    };
+/// Handle set_event_throttle command — rate-limits a noisy event type.
+fn handle_set_event_throttle(payload: serde_json::Value) -> CommandResult {
+    let data: SetEventThrottlePayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid set_event_throttle payload: {}", e))?;
+    crate::core::events::validate_rate(&data.event_type, data.max_hz)?;
 
-    if queue_camera_preset_from_bridge(request) {
-        tracing::info!("Queued camera preset: {:?}", data.preset);
+    if queue_event_throttle_from_bridge(EventThrottleRequest { event_type: data.event_type, max_hz: data.max_hz }) {
         Ok(())
     } else {
         Err("PendingCommands resource not initialized".to_string())
@@ -619,19 +1348,13 @@ fn handle_set_input_binding(payload: serde_json::Value) -> CommandResult {
 
     let action_type = match data.action_type.as_str() {
         "axis" => ActionType::Axis {
-            positive: data.positive_keys.iter().map(|k| InputSource::Key(k.clone())).collect(),
-            negative: data.negative_keys.iter().map(|k| InputSource::Key(k.clone())).collect(),
+            positive: data.positive_keys.iter().map(|k| InputSource::parse(k)).collect(),
+            negative: data.negative_keys.iter().map(|k| InputSource::parse(k)).collect(),
         },
         _ => ActionType::Digital,
     };
 
-    let sources: Vec<InputSource> = data.sources.iter().map(|s| {
-        if s == "Left" || s == "Right" || s == "Middle" {
-            InputSource::MouseButton(s.clone())
-        } else {
-            InputSource::Key(s.clone())
-        }
-    }).collect();
+    let sources: Vec<InputSource> = data.sources.iter().map(|s| InputSource::parse(s)).collect();
 
     let action_def = ActionDef {
         name: data.action_name.clone(),
@@ -674,6 +1397,49 @@ fn handle_remove_input_binding(payload: serde_json::Value) -> CommandResult {
     }
 }
 
+/// Payload for push_input_context command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PushInputContextPayload {
+    name: String,
+    /// Actions live only while this context is active; omit to keep the existing set.
+    actions: Option<Vec<String>>,
+    #[serde(default)]
+    always_active: bool,
+}
+
+/// Handle push_input_context command.
+fn handle_push_input_context(payload: serde_json::Value) -> CommandResult {
+    let data: PushInputContextPayload = serde_json::from_value(payload)
+        .map_err(|e| format!("Invalid push_input_context payload: {}", e))?;
+    if data.name.is_empty() {
+        return Err("Input context name must not be empty".to_string());
+    }
+
+    let context = data.actions.map(|actions| InputContext {
+        actions: actions.into_iter().collect(),
+        always_active: data.always_active,
+    });
+    let request = InputContextRequest::Push { name: data.name.clone(), context };
+
+    if queue_input_context_from_bridge(request) {
+        tracing::info!("Queued input context push: {}", data.name);
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
+/// Handle pop_input_context command.
+fn handle_pop_input_context(_payload: serde_json::Value) -> CommandResult {
+    if queue_input_context_from_bridge(InputContextRequest::Pop) {
+        tracing::info!("Queued input context pop");
+        Ok(())
+    } else {
+        Err("PendingCommands resource not initialized".to_string())
+    }
+}
+
 /// Payload for set_input_preset command.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -687,7 +1453,7 @@ fn handle_set_input_preset(payload: serde_json::Value) -> CommandResult {
         .map_err(|e| format!("Invalid set_input_preset payload: {}", e))?;
 
     let preset = InputPreset::from_str(&data.preset)
-        .ok_or_else(|| format!("Unknown input preset: {}. Valid: fps, platformer, topdown, racing", data.preset))?;
+        .ok_or_else(|| format!("Unknown input preset: {}. Valid: fps, platformer, topdown, racing, platformer_touch, topdown_touch", data.preset))?;
 
     let request = InputPresetRequest { preset };
 
@@ -761,7 +1527,7 @@ mod tests {
     fn spawn_entity_accepts_all_valid_entity_types() {
         let valid_types = [
             "cube", "sphere", "plane", "cylinder", "cone", "torus", "capsule",
-            "point_light", "directional_light", "spot_light",
+            "point_light", "directional_light", "spot_light", "light_probe_volume", "empty",
         ];
         for entity_type in &valid_types {
             let result = run("spawn_entity", json!({"entityType": entity_type}));
@@ -831,6 +1597,47 @@ mod tests {
         );
     }
 
+    // === update_transforms ===
+
+    #[test]
+    fn update_transforms_accepts_multiple_updates() {
+        let result = run("update_transforms", json!({
+            "updates": [
+                {"entityId": "entity-1", "position": [1.0, 0.0, 0.0]},
+                {"entityId": "entity-2", "rotation": [0.0, 1.57, 0.0], "scale": [2.0, 2.0, 2.0]}
+            ]
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn update_transforms_with_empty_list_is_no_op() {
+        let result = run("update_transforms", json!({"updates": []}));
+        assert!(result.is_ok(), "Empty update list should be no-op");
+    }
+
+    #[test]
+    fn update_transforms_rejects_update_missing_entity_id() {
+        let result = run("update_transforms", json!({
+            "updates": [{"position": [0.0, 0.0, 0.0]}]
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid update_transforms payload"));
+    }
+
+    #[test]
+    fn update_transforms_rejects_zero_scale_in_any_update() {
+        let result = run("update_transforms", json!({
+            "updates": [
+                {"entityId": "entity-1", "position": [1.0, 0.0, 0.0]},
+                {"entityId": "entity-2", "scale": [0.0, 1.0, 1.0]}
+            ]
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("update_transforms: scale components must be non-zero"));
+    }
+
     // === rename_entity ===
 
     #[test]
@@ -939,6 +1746,29 @@ mod tests {
         );
     }
 
+    // === copy_entities / paste_entities ===
+
+    #[test]
+    fn copy_entities_requires_entity_ids() {
+        let err = run("copy_entities", json!({"entityIds": []})).unwrap_err();
+        assert!(err.contains("must not be empty"), "got: {}", err);
+
+        let err = run("copy_entities", json!({"entityIds": ["entity-1"]})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn paste_entities_accepts_optional_json_and_offset() {
+        let err = run("paste_entities", json!({})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+
+        let err = run("paste_entities", json!({"json": "{}", "offset": [1, 0, 0]})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+
+        let err = run("paste_entities", json!({"offset": "up"})).unwrap_err();
+        assert!(err.contains("Invalid paste_entities payload"), "got: {}", err);
+    }
+
     // === select_entity ===
 
     #[test]
@@ -973,6 +1803,127 @@ mod tests {
         );
     }
 
+    // === select_entities / select_all_by_type / invert_selection ===
+
+    #[test]
+    fn select_entities_queues_single_bulk_request() {
+        let result = run("select_entities", json!({
+            "entityIds": ["entity-1", "entity-2"],
+            "mode": "add"
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn select_all_by_type_accepts_known_type() {
+        let result = run("select_all_by_type", json!({"entityType": "point_light"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn select_all_by_type_rejects_unknown_type() {
+        let result = run("select_all_by_type", json!({"entityType": "dragon"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Unknown entity type"));
+    }
+
+    #[test]
+    fn select_all_by_type_rejects_missing_type() {
+        let result = run("select_all_by_type", json!({}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Missing entityType"));
+    }
+
+    #[test]
+    fn invert_selection_accepts_empty_payload() {
+        let result = run("invert_selection", json!({}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    // === box_select ===
+
+    #[test]
+    fn box_select_accepts_rectangle_with_default_mode() {
+        let result = run("box_select", json!({"min": [10.0, 20.0], "max": [200.0, 150.0]}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn box_select_accepts_add_mode_and_swapped_corners() {
+        let result = run("box_select", json!({
+            "min": [200.0, 150.0],
+            "max": [10.0, 20.0],
+            "mode": "add"
+        }));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not initialized"));
+    }
+
+    #[test]
+    fn box_select_rejects_unknown_mode() {
+        let result = run("box_select", json!({"min": [0.0, 0.0], "max": [1.0, 1.0], "mode": "toggle"}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid box_select mode"));
+    }
+
+    #[test]
+    fn box_select_rejects_missing_corner() {
+        let result = run("box_select", json!({"min": [0.0, 0.0]}));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid box_select payload"));
+    }
+
+    // === set_entity_locked ===
+
+    #[test]
+    fn set_entity_locked_requires_boolean_flag() {
+        let err = run("set_entity_locked", json!({"entityId": "entity-1", "locked": "yes"})).unwrap_err();
+        assert!(err.contains("Missing locked"), "got: {}", err);
+
+        let err = run("set_entity_locked", json!({"entityId": "entity-1", "locked": true})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    // === add_entity_tag / remove_entity_tag ===
+
+    #[test]
+    fn entity_tag_commands_reject_blank_tags() {
+        let err = run("add_entity_tag", json!({"entityId": "entity-1", "tag": "  "})).unwrap_err();
+        assert!(err.contains("Missing tag"), "got: {}", err);
+
+        let err = run("remove_entity_tag", json!({"entityId": "entity-1", "tag": "enemy"})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    // === align_entities / distribute_entities ===
+
+    #[test]
+    fn align_entities_validates_axis_and_mode() {
+        let err = run("align_entities", json!({"axis": "w", "mode": "center"})).unwrap_err();
+        assert!(err.contains("Invalid align_entities payload"), "got: {}", err);
+
+        let err = run("align_entities", json!({"axis": "x", "mode": "center"})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+
+        let err = run("distribute_entities", json!({"axis": "y"})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    // === drop_to_ground ===
+
+    #[test]
+    fn drop_to_ground_requires_entities() {
+        let err = run("drop_to_ground", json!({"entityIds": []})).unwrap_err();
+        assert!(err.contains("must not be empty"), "got: {}", err);
+
+        let err = run("drop_to_ground", json!({"entityIds": ["rock"], "offset": 0.1})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     // === set_visibility ===
 
     #[test]
@@ -1051,6 +2002,24 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    #[test]
+    fn set_snap_settings_only_accepts_rotation_presets() {
+        let result = run("set_snap_settings", json!({"translationSnapX": 1.0, "rotationSnapPreset": 45.0}));
+        assert!(result.unwrap_err().contains("not initialized"));
+        let result = run("set_snap_settings", json!({"rotationSnapPreset": 30.0}));
+        assert!(result.unwrap_err().contains("rotationSnapPreset must be one of"));
+        let result = run("set_snap_settings", json!({"rotationSnapDegrees": 10.0, "rotationSnapPreset": 90.0}));
+        assert!(result.unwrap_err().contains("not both"));
+    }
+
+    #[test]
+    fn frame_selection_defaults_to_animated() {
+        let result = run("frame_selection", json!({}));
+        assert!(result.unwrap_err().contains("not initialized"));
+        let result = run("frame_selection", json!({"animate": "yes"}));
+        assert!(result.unwrap_err().contains("Invalid frame_selection payload"));
+    }
+
     #[test]
     fn focus_camera_rejects_missing_entity_id() {
         let result = run("focus_camera", json!({}));
@@ -1079,6 +2048,48 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    #[test]
+    fn set_camera_projection_validates_payload() {
+        let err = run("set_camera_projection", json!({ "mode": "isometric" })).unwrap_err();
+        assert!(err.contains("Invalid set_camera_projection payload"), "got: {}", err);
+
+        let err = run("set_camera_projection", json!({ "mode": "orthographic", "orthoScale": 0.0 })).unwrap_err();
+        assert!(err.contains("orthoScale"), "got: {}", err);
+
+        let err = run("set_camera_projection", json!({ "mode": "orthographic", "orthoScale": 12.0 })).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn set_viewport_layout_parses_layout_names() {
+        let err = run("set_viewport_layout", json!({ "layout": "triple" })).unwrap_err();
+        assert!(err.contains("Invalid set_viewport_layout payload"), "got: {}", err);
+
+        let err = run("set_viewport_layout", json!({ "layout": "split_horizontal" })).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn set_render_mode_parses_mode_names() {
+        let err = run("set_render_mode", json!({ "mode": "xray" })).unwrap_err();
+        assert!(err.contains("Invalid set_render_mode payload"), "got: {}", err);
+
+        let err = run("set_render_mode", json!({ "mode": "uv_checker" })).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn set_event_throttle_rejects_critical_events_and_bad_rates() {
+        let err = run("set_event_throttle", json!({ "eventType": "SCRIPT_ERROR", "maxHz": 10 })).unwrap_err();
+        assert!(err.contains("never throttled"), "got: {}", err);
+
+        let err = run("set_event_throttle", json!({ "eventType": "MATERIAL_CHANGED", "maxHz": -1 })).unwrap_err();
+        assert!(err.contains("maxHz must be positive"), "got: {}", err);
+
+        let err = run("set_event_throttle", json!({ "eventType": "MATERIAL_CHANGED", "maxHz": 30 })).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     #[test]
     fn orbit_camera_rejects_empty_payload() {
         let result = run("orbit_camera", json!({}));
@@ -1111,6 +2122,19 @@ mod tests {
         assert!(result.is_ok(), "redo should always queue successfully");
     }
 
+    #[test]
+    fn begin_history_group_requires_label() {
+        assert!(run("begin_history_group", json!({})).is_err());
+        let result = run("begin_history_group", json!({"label": "  "}));
+        assert!(result.unwrap_err().contains("label"));
+    }
+
+    #[test]
+    fn history_group_commands_always_succeed() {
+        assert!(run("begin_history_group", json!({"label": "Scatter rocks"})).is_ok());
+        assert!(run("end_history_group", json!({})).is_ok());
+    }
+
     // === update_scene (not implemented) ===
 
     #[test]
@@ -1134,6 +2158,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn push_input_context_requires_name() {
+        let err = run("push_input_context", json!({"name": ""})).unwrap_err();
+        assert!(err.contains("must not be empty"), "got: {}", err);
+
+        let err = run("push_input_context", json!({"name": "menu", "actions": ["confirm"]})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     #[test]
     fn set_input_preset_accepts_fps_preset() {
         let result = run("set_input_preset", json!({"preset": "fps"}));
@@ -1141,6 +2174,51 @@ mod tests {
         assert!(result.unwrap_err().contains("not initialized"));
     }
 
+    // === group_entities / ungroup_entity ===
+
+    #[test]
+    fn group_entities_accepts_empty_payload() {
+        let err = run("group_entities", json!({})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn ungroup_entity_requires_entity_id() {
+        let err = run("ungroup_entity", json!({})).unwrap_err();
+        assert!(err.contains("Invalid ungroup_entity payload"), "got: {}", err);
+    }
+
+    // === measure ===
+
+    #[test]
+    fn measure_rejects_wrong_point_count() {
+        let err = run("measure", json!({"mode": "angle", "points": [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]})).unwrap_err();
+        assert!(err.contains("needs 3 points"), "got: {}", err);
+
+        let err = run("measure", json!({"mode": "distance", "points": [[0.0, 0.0, 0.0], [3.0, 4.0, 0.0]]})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    #[test]
+    fn clear_measurement_accepts_empty_payload() {
+        let err = run("clear_measurement", json!({})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
+    // === set_outline_color ===
+
+    #[test]
+    fn set_outline_color_validates_payload() {
+        let err = run("set_outline_color", json!({"primaryColor": [1.0, 0.5]})).unwrap_err();
+        assert!(err.contains("Invalid set_outline_color payload"), "got: {}", err);
+
+        let err = run("set_outline_color", json!({"thickness": -1.0})).unwrap_err();
+        assert!(err.contains("thickness"), "got: {}", err);
+
+        let err = run("set_outline_color", json!({"primaryColor": [1.0, 0.5, 0.0, 1.0], "thickness": 4.0})).unwrap_err();
+        assert!(err.contains("not initialized"), "got: {}", err);
+    }
+
     // === dispatch returns None for unknown commands ===
 
     #[test]
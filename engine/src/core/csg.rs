@@ -22,6 +22,25 @@ pub struct CsgMeshData {
     pub indices: Vec<u32>,
 }
 
+impl CsgMeshData {
+    /// Extract mesh data from a Bevy Mesh (e.g. after baking a transform into it).
+    pub fn from_mesh(mesh: &Mesh) -> Option<Self> {
+        let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+            VertexAttributeValues::Float32x3(v) => v.clone(),
+            _ => return None,
+        };
+        let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL)? {
+            VertexAttributeValues::Float32x3(v) => v.clone(),
+            _ => return None,
+        };
+        let indices = match mesh.indices()? {
+            Indices::U32(v) => v.clone(),
+            Indices::U16(v) => v.iter().map(|i| *i as u32).collect(),
+        };
+        Some(Self { positions, normals, indices })
+    }
+}
+
 /// CSG operation type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -212,6 +231,17 @@ pub fn rebuild_mesh_from_data(data: &CsgMeshData) -> Mesh {
     mesh
 }
 
+/// Fold a sequence of CSG operations into a base mesh, left to right.
+/// Avoids creating an intermediate result entity for every step.
+pub fn perform_csg_chain(
+    base: CsgMesh<()>,
+    operands: impl IntoIterator<Item = (CsgOperation, CsgMesh<()>)>,
+) -> CsgMesh<()> {
+    operands
+        .into_iter()
+        .fold(base, |acc, (operation, operand)| perform_csg(&acc, &operand, operation))
+}
+
 /// Perform a CSG boolean operation on two meshes.
 pub fn perform_csg(
     mesh_a: &CsgMesh<()>,
@@ -224,3 +254,39 @@ pub fn perform_csg(
         CsgOperation::Intersect => mesh_a.intersection(mesh_b),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube_at(size: Vec3, translation: Vec3) -> CsgMesh<()> {
+        let mesh = Mesh::from(Cuboid::from_size(size));
+        bevy_mesh_to_csg(&mesh, &Transform::from_translation(translation)).expect("cuboid converts")
+    }
+
+    #[test]
+    fn csg_chain_folds_left_to_right() {
+        let base = cube_at(Vec3::splat(2.0), Vec3::ZERO);
+        let operands = vec![
+            (CsgOperation::Union, cube_at(Vec3::splat(2.0), Vec3::new(1.5, 0.0, 0.0))),
+            (CsgOperation::Intersect, cube_at(Vec3::new(10.0, 1.0, 10.0), Vec3::ZERO)),
+        ];
+        let result = perform_csg_chain(base, operands);
+        let (_, data) = csg_to_bevy_mesh(&result).expect("chain produces a mesh");
+
+        let (lo, hi) = data.positions.iter().fold((Vec3::MAX, Vec3::MIN), |(lo, hi), p| {
+            (lo.min(Vec3::from(*p)), hi.max(Vec3::from(*p)))
+        });
+        // Union widened X to [-1, 2.5]; the final intersect clipped Y to [-0.5, 0.5].
+        assert!(lo.abs_diff_eq(Vec3::new(-1.0, -0.5, -1.0), 1e-4), "min {:?}", lo);
+        assert!(hi.abs_diff_eq(Vec3::new(2.5, 0.5, 1.0), 1e-4), "max {:?}", hi);
+    }
+
+    #[test]
+    fn csg_chain_without_operands_returns_base() {
+        let base = cube_at(Vec3::splat(1.0), Vec3::ZERO);
+        let polygon_count = base.polygons.len();
+        let result = perform_csg_chain(base, Vec::new());
+        assert_eq!(result.polygons.len(), polygon_count);
+    }
+}
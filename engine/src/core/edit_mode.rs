@@ -1,6 +1,7 @@
 //! Edit mode for polygon modeling (vertex/edge/face editing).
 
 use bevy::prelude::*;
+use bevy::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
 use serde::{Serialize, Deserialize};
 
 /// Marks an entity as being in edit mode (vertex/edge/face editing)
@@ -54,3 +55,93 @@ impl Default for EditModeData {
         }
     }
 }
+
+/// Return a copy of a triangle mesh with recomputed normals. Smooth normals
+/// average the faces around each shared vertex; flat normals give every
+/// triangle its own vertices so each face has a single normal.
+pub fn recalculate_normals(mesh: &Mesh, smooth: bool) -> Result<Mesh, String> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return Err("Only triangle-list meshes can have their normals recalculated".to_string());
+    }
+    let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+        return Err("Mesh has no positions".to_string());
+    };
+    let vertex_count = positions.len() as u32;
+
+    let mut result = mesh.clone();
+    if smooth {
+        if result.indices().is_none() {
+            result.insert_indices(Indices::U32((0..vertex_count).collect()));
+        }
+        result.compute_smooth_normals();
+    } else {
+        result.duplicate_vertices();
+        result.compute_flat_normals();
+        // Stay indexed so procedural and CSG mesh data can store the result.
+        let corner_count = result.count_vertices() as u32;
+        result.insert_indices(Indices::U32((0..corner_count).collect()));
+    }
+    Ok(result)
+}
+
+/// Return a copy of a triangle mesh turned inside out: triangle winding is
+/// reversed and normals are negated.
+pub fn flip_normals(mesh: &Mesh) -> Result<Mesh, String> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return Err("Only triangle-list meshes can have their normals flipped".to_string());
+    }
+    let mut result = mesh.clone();
+    result.invert_winding().map_err(|e| format!("Cannot flip normals: {}", e))?;
+    if let Some(VertexAttributeValues::Float32x3(normals)) = result.attribute_mut(Mesh::ATTRIBUTE_NORMAL) {
+        for normal in normals.iter_mut() {
+            *normal = [-normal[0], -normal[1], -normal[2]];
+        }
+    }
+    // The tangent stays put, so flip handedness to keep the bitangent.
+    if let Some(VertexAttributeValues::Float32x4(tangents)) = result.attribute_mut(Mesh::ATTRIBUTE_TANGENT) {
+        for tangent in tangents.iter_mut() {
+            tangent[3] = -tangent[3];
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normals(mesh: &Mesh) -> Vec<Vec3> {
+        let Some(VertexAttributeValues::Float32x3(normals)) = mesh.attribute(Mesh::ATTRIBUTE_NORMAL) else {
+            panic!("missing normals");
+        };
+        normals.iter().map(|n| Vec3::from(*n)).collect()
+    }
+
+    #[test]
+    fn flipping_normals_twice_restores_them() {
+        let sphere = Mesh::from(Sphere::new(1.0));
+        let flipped = flip_normals(&sphere).expect("sphere should flip");
+        for (original, flipped) in normals(&sphere).iter().zip(normals(&flipped)) {
+            assert!(original.abs_diff_eq(-flipped, 1e-6));
+        }
+
+        let restored = flip_normals(&flipped).expect("sphere should flip back");
+        assert_eq!(normals(&restored), normals(&sphere));
+        assert_eq!(restored.indices().map(|i| i.iter().collect::<Vec<_>>()), sphere.indices().map(|i| i.iter().collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn recalculating_cleared_normals_gives_unit_normals() {
+        for smooth in [true, false] {
+            let mut cube = Mesh::from(Cuboid::new(1.0, 2.0, 3.0));
+            cube.remove_attribute(Mesh::ATTRIBUTE_NORMAL);
+            let result = recalculate_normals(&cube, smooth).expect("cube should get normals");
+
+            let normals = normals(&result);
+            assert_eq!(normals.len(), result.count_vertices());
+            for normal in normals {
+                assert!((normal.length() - 1.0).abs() < 1e-5, "smooth {}: {:?}", smooth, normal);
+            }
+        }
+    }
+}
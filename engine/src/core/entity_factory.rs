@@ -1,5 +1,6 @@
 //! Entity factory - systems for spawning, deleting, and duplicating entities.
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -7,20 +8,23 @@ use super::animation_clip::AnimationClipData;
 use super::asset_manager::AssetRef;
 use super::audio::{AudioData, AudioEnabled};
 use super::csg;
-use super::entity_id::{EntityId, EntityName, EntityVisible};
+use super::entity_id::{EntityId, EntityName, EntityTags, EntityVisible};
 use super::game_camera::{GameCameraData, ActiveGameCamera};
+use super::gizmo::LastMoveDelta;
+use super::instancing::{self, InstanceData};
 use super::terrain::{self, TerrainEnabled};
 use super::lod::LodData;
 use super::physics_2d::{Physics2dData, Physics2dEnabled};
 use super::skeleton2d::{SkeletonData2d, SkeletonEnabled2d};
 use super::tilemap::{TilemapData, TilemapEnabled};
 // Re-export history types for backward compatibility (bridge/mod.rs accesses these via entity_factory::)
-pub use super::history::{EntitySnapshot, HistoryStack, TransformSnapshot, UndoableAction};
-use super::lighting::LightData;
+pub use super::history::{EntitySnapshot, GroupedChild, HistoryStack, TransformSnapshot, UndoableAction};
+use super::lighting::{LightData, LightProbeVolume};
 use super::material::MaterialData;
-use super::particles::{ParticleData, ParticleEnabled};
+use super::particles::{ParticleData, ParticleEnabled, ParticleForceField};
 use super::pending_commands::{EntityType, PendingCommands};
 use super::physics::{JointData, PhysicsData, PhysicsEnabled};
+use super::prefab::PrefabInstance;
 use super::scripting::ScriptData;
 use super::selection::{Selection, SelectionChangedEvent};
 use super::shader_effects::ShaderEffectData;
@@ -29,6 +33,11 @@ use super::shader_effects::ShaderEffectData;
 #[derive(Component)]
 pub struct Undeletable;
 
+/// Marker component for entities the user has locked: they are skipped by
+/// picking, transform edits, the gizmo and delete until unlocked.
+#[derive(Component)]
+pub struct Locked;
+
 /// Counter for generating unique entity names.
 #[derive(Default)]
 pub struct EntityNameCounter {
@@ -74,7 +83,13 @@ pub fn apply_spawn_requests(
             EntityType::PointLight => spawn_point_light_with_id(&mut commands, &name, request.position),
             EntityType::DirectionalLight => spawn_directional_light_with_id(&mut commands, &name),
             EntityType::SpotLight => spawn_spot_light_with_id(&mut commands, &name, request.position),
+            EntityType::LightProbeVolume => spawn_light_probe_volume_with_id(&mut commands, &name, request.position),
+            EntityType::Empty => spawn_empty_with_id(&mut commands, &name, request.position),
             EntityType::Sprite => continue,
+            EntityType::Path => {
+                // Paths are created by create_path, which carries their control points.
+                continue;
+            }
             EntityType::GltfModel | EntityType::GltfMesh => {
                 // GltfModel/GltfMesh are spawned through the asset pipeline, not through spawn requests.
                 // Skip these if they somehow end up in the spawn queue.
@@ -94,9 +109,19 @@ pub fn apply_spawn_requests(
             }
         };
 
+        // Recorded spawns bring their ID, so replayed commands can find the entity
+        let entity_id = match request.entity_id {
+            Some(id) => {
+                commands.entity(entity).insert(EntityId(id.clone()));
+                id
+            }
+            None => entity_id,
+        };
+
         // Material data for mesh entities, light data for light entities
         let material_data = match request.entity_type {
-            EntityType::PointLight | EntityType::DirectionalLight | EntityType::SpotLight => None,
+            EntityType::PointLight | EntityType::DirectionalLight | EntityType::SpotLight
+            | EntityType::LightProbeVolume | EntityType::Empty => None,
             _ => Some(MaterialData::default()),
         };
         let light_data = match request.entity_type {
@@ -119,30 +144,63 @@ pub fn apply_spawn_requests(
         );
         snapshot.material_data = material_data;
         snapshot.light_data = light_data;
+        if request.entity_type == EntityType::LightProbeVolume {
+            snapshot.light_probe_volume = Some(LightProbeVolume::default());
+        }
         history.push(UndoableAction::Spawn { snapshot });
 
         let _ = entity; // Entity handle available for future use
     }
 }
 
+/// System that processes spawn_configured requests: each entity is spawned
+/// with all of its components in one go and recorded as one undo step.
+pub fn apply_spawn_configured_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut history: ResMut<HistoryStack>,
+    entities: Query<(Entity, &EntityId)>,
+) {
+    for request in pending.spawn_configured_requests.drain(..) {
+        let mut snapshot = request.snapshot;
+        let parent = snapshot.parent_id.as_ref().map(|parent_id| entities.iter().find(|(_, eid)| eid.0 == *parent_id));
+        if let Some(None) = parent {
+            tracing::warn!("spawn_configured: parent {:?} not found, spawning at the root", snapshot.parent_id);
+            snapshot.parent_id = None;
+        }
+
+        let entity = spawn_from_snapshot(&mut commands, &mut meshes, &mut materials, &snapshot);
+        if let Some(Some((parent, _))) = parent {
+            commands.entity(entity).insert(ChildOf(parent));
+        }
+        history.push(UndoableAction::Spawn { snapshot });
+    }
+}
+
 // ---------------------------------------------------------------------------
-// Shared helpers for delete & duplicate — pre-indexed O(1) lookups
+// Shared helpers for delete, duplicate & copy — pre-indexed O(1) lookups
 // ---------------------------------------------------------------------------
 
 /// Auxiliary component data collected from secondary queries, keyed by entity ID.
 /// Used by both delete and duplicate to avoid redundant O(n) scans.
-struct AuxComponentData {
+pub(crate) struct AuxComponentData {
     script_data: Option<ScriptData>,
     audio_data: Option<AudioData>,
+    light_probe_volume: Option<LightProbeVolume>,
     reverb_zone_data: Option<super::reverb_zone::ReverbZoneData>,
     reverb_zone_enabled: bool,
     particle_data: Option<ParticleData>,
     particle_enabled: bool,
+    particle_force_field: Option<ParticleForceField>,
     shader_effect_data: Option<ShaderEffectData>,
     csg_mesh_data: Option<csg::CsgMeshData>,
     procedural_mesh_data: Option<super::procedural_mesh::ProceduralMeshData>,
     joint_data: Option<JointData>,
     game_components: Option<super::game_components::GameComponents>,
+    look_at_constraint: Option<super::game_components::LookAtConstraint>,
+    path_data: Option<super::path::PathData>,
     animation_clip_data: Option<AnimationClipData>,
     game_camera_data: Option<GameCameraData>,
     active_game_camera: bool,
@@ -154,6 +212,8 @@ struct AuxComponentData {
     skeleton2d_data: Option<SkeletonData2d>,
     skeleton2d_enabled: bool,
     lod_data: Option<LodData>,
+    instance_data: Option<InstanceData>,
+    tags: Option<EntityTags>,
 }
 
 impl Default for AuxComponentData {
@@ -161,15 +221,19 @@ impl Default for AuxComponentData {
         Self {
             script_data: None,
             audio_data: None,
+            light_probe_volume: None,
             reverb_zone_data: None,
             reverb_zone_enabled: false,
             particle_data: None,
             particle_enabled: false,
+            particle_force_field: None,
             shader_effect_data: None,
             csg_mesh_data: None,
             procedural_mesh_data: None,
             joint_data: None,
             game_components: None,
+            look_at_constraint: None,
+            path_data: None,
             animation_clip_data: None,
             game_camera_data: None,
             active_game_camera: false,
@@ -181,113 +245,179 @@ impl Default for AuxComponentData {
             skeleton2d_data: None,
             skeleton2d_enabled: false,
             lod_data: None,
+            instance_data: None,
+            tags: None,
         }
     }
 }
 
-/// Build a HashMap of auxiliary component data from the secondary queries.
-/// This converts 7 separate O(n) linear scans per entity into a single O(n) pass.
-fn build_aux_index(
-    script_audio_query: &Query<(&EntityId, Option<&ScriptData>, Option<&AudioData>)>,
-    reverb_particle_query: &Query<(
-        &EntityId,
-        Option<&super::reverb_zone::ReverbZoneData>,
-        Option<&super::reverb_zone::ReverbZoneEnabled>,
-        Option<&ParticleData>,
-        Option<&ParticleEnabled>,
-    )>,
-    shader_csg_query: &Query<(&EntityId, Option<&ShaderEffectData>, Option<&csg::CsgMeshData>)>,
-    procedural_joint_query: &Query<(
-        &EntityId,
-        Option<&super::procedural_mesh::ProceduralMeshData>,
-        Option<&JointData>,
-    )>,
-    game_anim_query: &Query<(
-        &EntityId,
-        Option<&super::game_components::GameComponents>,
-        Option<&AnimationClipData>,
-        Option<&GameCameraData>,
-        Option<&ActiveGameCamera>,
-    )>,
-    sprite_query: &Query<(&EntityId, Option<&super::sprite::SpriteData>)>,
-    physics2d_tilemap_skeleton_lod_query: &Query<(
-        &EntityId,
-        Option<&Physics2dData>,
-        Option<&Physics2dEnabled>,
-        Option<&TilemapData>,
-        Option<&TilemapEnabled>,
-        Option<&SkeletonData2d>,
-        Option<&SkeletonEnabled2d>,
-        Option<&LodData>,
-    )>,
-) -> HashMap<String, AuxComponentData> {
-    let mut index: HashMap<String, AuxComponentData> = HashMap::new();
-
-    for (eid, sd, ad) in script_audio_query.iter() {
-        let entry = index.entry(eid.0.clone()).or_default();
-        entry.script_data = sd.cloned();
-        entry.audio_data = ad.cloned();
-    }
-
-    for (eid, rzd, rze, pd, pe) in reverb_particle_query.iter() {
-        let entry = index.entry(eid.0.clone()).or_default();
-        entry.reverb_zone_data = rzd.cloned();
-        entry.reverb_zone_enabled = rze.is_some();
-        entry.particle_data = pd.cloned();
-        entry.particle_enabled = pe.is_some();
-    }
-
-    for (eid, sed, cmd) in shader_csg_query.iter() {
-        let entry = index.entry(eid.0.clone()).or_default();
-        entry.shader_effect_data = sed.cloned();
-        entry.csg_mesh_data = cmd.cloned();
-    }
-
-    for (eid, pmd, jd) in procedural_joint_query.iter() {
-        let entry = index.entry(eid.0.clone()).or_default();
-        entry.procedural_mesh_data = pmd.cloned();
-        entry.joint_data = jd.cloned();
-    }
-
-    for (eid, gc, acd, gcd, agc) in game_anim_query.iter() {
-        let entry = index.entry(eid.0.clone()).or_default();
-        entry.game_components = gc.cloned();
-        entry.animation_clip_data = acd.cloned();
-        entry.game_camera_data = gcd.cloned();
-        entry.active_game_camera = agc.is_some();
-    }
-
-    for (eid, sd) in sprite_query.iter() {
-        let entry = index.entry(eid.0.clone()).or_default();
-        entry.sprite_data = sd.cloned();
-    }
-
-    for (eid, p2d, p2de, tmd, tme, sk, ske, ld) in physics2d_tilemap_skeleton_lod_query.iter() {
-        let entry = index.entry(eid.0.clone()).or_default();
-        entry.physics2d_data = p2d.cloned();
-        entry.physics2d_enabled = p2de.is_some();
-        entry.tilemap_data = tmd.cloned();
-        entry.tilemap_enabled = tme.is_some();
-        entry.skeleton2d_data = sk.cloned();
-        entry.skeleton2d_enabled = ske.is_some();
-        entry.lod_data = ld.cloned();
-    }
-
-    index
+type ScriptAudioProbeQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static ScriptData>,
+    Option<&'static AudioData>,
+    Option<&'static LightProbeVolume>,
+)>;
+
+type ReverbParticleQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static super::reverb_zone::ReverbZoneData>,
+    Option<&'static super::reverb_zone::ReverbZoneEnabled>,
+    Option<&'static ParticleData>,
+    Option<&'static ParticleEnabled>,
+)>;
+
+type ShaderCsgQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static ShaderEffectData>,
+    Option<&'static csg::CsgMeshData>,
+)>;
+
+type ProceduralJointQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static super::procedural_mesh::ProceduralMeshData>,
+    Option<&'static JointData>,
+)>;
+
+type GameAnimQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static super::game_components::GameComponents>,
+    Option<&'static AnimationClipData>,
+    Option<&'static GameCameraData>,
+    Option<&'static ActiveGameCamera>,
+    Option<&'static super::game_components::LookAtConstraint>,
+    Option<&'static super::path::PathData>,
+)>;
+
+type SpriteForceFieldQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static super::sprite::SpriteData>,
+    Option<&'static ParticleForceField>,
+    Option<&'static EntityTags>,
+    Option<&'static InstanceData>,
+)>;
+
+type Physics2dTilemapSkeletonLodQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    Option<&'static Physics2dData>,
+    Option<&'static Physics2dEnabled>,
+    Option<&'static TilemapData>,
+    Option<&'static TilemapEnabled>,
+    Option<&'static SkeletonData2d>,
+    Option<&'static SkeletonEnabled2d>,
+    Option<&'static LodData>,
+)>;
+
+/// The secondary queries that feed `AuxComponentData`, shared by delete,
+/// duplicate and copy.
+#[derive(SystemParam)]
+pub struct AuxQueries<'w, 's> {
+    script_audio_probe: ScriptAudioProbeQuery<'w, 's>,
+    reverb_particle: ReverbParticleQuery<'w, 's>,
+    shader_csg: ShaderCsgQuery<'w, 's>,
+    procedural_joint: ProceduralJointQuery<'w, 's>,
+    game_anim: GameAnimQuery<'w, 's>,
+    sprite_force_field: SpriteForceFieldQuery<'w, 's>,
+    physics2d_tilemap_skeleton_lod: Physics2dTilemapSkeletonLodQuery<'w, 's>,
+}
+
+/// Base components the delete and copy systems snapshot for each entity.
+pub(crate) type SnapshotData = (
+    Entity,
+    &'static EntityId,
+    &'static EntityName,
+    &'static Transform,
+    &'static EntityVisible,
+    Option<&'static EntityType>,
+    Option<&'static MaterialData>,
+    Option<&'static LightData>,
+    Option<&'static PhysicsData>,
+    Option<&'static PhysicsEnabled>,
+    Option<&'static AssetRef>,
+);
+
+/// Base-query components copied into a snapshot alongside the auxiliary data.
+pub(crate) struct SnapshotBase<'a> {
+    pub mat_data: Option<&'a MaterialData>,
+    pub light_data: Option<&'a LightData>,
+    pub phys_data: Option<&'a PhysicsData>,
+    pub phys_enabled: bool,
+    pub asset_ref: Option<&'a AssetRef>,
+}
+
+impl AuxQueries<'_, '_> {
+    /// Build a HashMap of auxiliary component data from the secondary queries.
+    /// This converts 7 separate O(n) linear scans per entity into a single O(n) pass.
+    pub(crate) fn build_index(&self) -> HashMap<String, AuxComponentData> {
+        let mut index: HashMap<String, AuxComponentData> = HashMap::new();
+
+        for (eid, sd, ad, lpv) in self.script_audio_probe.iter() {
+            let entry = index.entry(eid.0.clone()).or_default();
+            entry.script_data = sd.cloned();
+            entry.audio_data = ad.cloned();
+            entry.light_probe_volume = lpv.cloned();
+        }
+
+        for (eid, rzd, rze, pd, pe) in self.reverb_particle.iter() {
+            let entry = index.entry(eid.0.clone()).or_default();
+            entry.reverb_zone_data = rzd.cloned();
+            entry.reverb_zone_enabled = rze.is_some();
+            entry.particle_data = pd.cloned();
+            entry.particle_enabled = pe.is_some();
+        }
+
+        for (eid, sed, cmd) in self.shader_csg.iter() {
+            let entry = index.entry(eid.0.clone()).or_default();
+            entry.shader_effect_data = sed.cloned();
+            entry.csg_mesh_data = cmd.cloned();
+        }
+
+        for (eid, pmd, jd) in self.procedural_joint.iter() {
+            let entry = index.entry(eid.0.clone()).or_default();
+            entry.procedural_mesh_data = pmd.cloned();
+            entry.joint_data = jd.cloned();
+        }
+
+        for (eid, gc, acd, gcd, agc, lac, path) in self.game_anim.iter() {
+            let entry = index.entry(eid.0.clone()).or_default();
+            entry.game_components = gc.cloned();
+            entry.look_at_constraint = lac.cloned();
+            entry.path_data = path.cloned();
+            entry.animation_clip_data = acd.cloned();
+            entry.game_camera_data = gcd.cloned();
+            entry.active_game_camera = agc.is_some();
+        }
+
+        for (eid, sd, pff, tags, inst) in self.sprite_force_field.iter() {
+            let entry = index.entry(eid.0.clone()).or_default();
+            entry.sprite_data = sd.cloned();
+            entry.particle_force_field = pff.copied();
+            entry.tags = tags.cloned();
+            entry.instance_data = inst.cloned();
+        }
+
+        for (eid, p2d, p2de, tmd, tme, sk, ske, ld) in self.physics2d_tilemap_skeleton_lod.iter() {
+            let entry = index.entry(eid.0.clone()).or_default();
+            entry.physics2d_data = p2d.cloned();
+            entry.physics2d_enabled = p2de.is_some();
+            entry.tilemap_data = tmd.cloned();
+            entry.tilemap_enabled = tme.is_some();
+            entry.skeleton2d_data = sk.cloned();
+            entry.skeleton2d_enabled = ske.is_some();
+            entry.lod_data = ld.cloned();
+        }
+
+        index
+    }
 }
 
 /// Build a complete EntitySnapshot from base query data and pre-indexed auxiliary data.
-fn snapshot_entity(
+pub(crate) fn snapshot_entity(
     entity_id: &str,
     entity_type: EntityType,
     name: &str,
     transform: &Transform,
     visible: bool,
-    mat_data: Option<&MaterialData>,
-    light_data: Option<&LightData>,
-    phys_data: Option<&PhysicsData>,
-    phys_enabled: bool,
-    asset_ref: Option<&AssetRef>,
+    base: SnapshotBase,
     aux: &AuxComponentData,
 ) -> EntitySnapshot {
     let mut snapshot = EntitySnapshot::new(
@@ -297,22 +427,26 @@ fn snapshot_entity(
         TransformSnapshot::from(transform),
     );
     snapshot.visible = visible;
-    snapshot.material_data = mat_data.cloned();
-    snapshot.light_data = light_data.cloned();
-    snapshot.physics_data = phys_data.cloned();
-    snapshot.physics_enabled = phys_enabled;
-    snapshot.asset_ref = asset_ref.cloned();
+    snapshot.material_data = base.mat_data.cloned();
+    snapshot.light_data = base.light_data.cloned();
+    snapshot.physics_data = base.phys_data.cloned();
+    snapshot.physics_enabled = base.phys_enabled;
+    snapshot.asset_ref = base.asset_ref.cloned();
     snapshot.script_data = aux.script_data.clone();
     snapshot.audio_data = aux.audio_data.clone();
+    snapshot.light_probe_volume = aux.light_probe_volume.clone();
     snapshot.reverb_zone_data = aux.reverb_zone_data.clone();
     snapshot.reverb_zone_enabled = aux.reverb_zone_enabled;
     snapshot.particle_data = aux.particle_data.clone();
     snapshot.particle_enabled = aux.particle_enabled;
+    snapshot.particle_force_field = aux.particle_force_field;
     snapshot.shader_effect_data = aux.shader_effect_data.clone();
     snapshot.csg_mesh_data = aux.csg_mesh_data.clone();
     snapshot.procedural_mesh_data = aux.procedural_mesh_data.clone();
     snapshot.joint_data = aux.joint_data.clone();
     snapshot.game_components = aux.game_components.clone();
+    snapshot.look_at_constraint = aux.look_at_constraint.clone();
+    snapshot.path_data = aux.path_data.clone();
     snapshot.animation_clip_data = aux.animation_clip_data.clone();
     snapshot.game_camera_data = aux.game_camera_data.clone();
     snapshot.active_game_camera = aux.active_game_camera;
@@ -324,6 +458,8 @@ fn snapshot_entity(
     snapshot.skeleton2d_data = aux.skeleton2d_data.clone();
     snapshot.skeleton2d_enabled = aux.skeleton2d_enabled;
     snapshot.lod_data = aux.lod_data.clone();
+    snapshot.instance_transforms = aux.instance_data.as_ref().map(InstanceData::to_snapshots);
+    snapshot.tags = aux.tags.as_ref().map(EntityTags::sorted).unwrap_or_default();
     snapshot
 }
 
@@ -336,6 +472,9 @@ fn insert_aux_components(entity_commands: &mut bevy::ecs::system::EntityCommands
         entity_commands.insert(ad.clone());
         entity_commands.insert(AudioEnabled);
     }
+    if let Some(ref lpv) = aux.light_probe_volume {
+        entity_commands.insert((lpv.clone(), Visibility::default()));
+    }
     if let Some(ref pd) = aux.particle_data {
         entity_commands.insert(pd.clone());
     }
@@ -387,6 +526,12 @@ fn insert_aux_components(entity_commands: &mut bevy::ecs::system::EntityCommands
     if let Some(ref ld) = aux.lod_data {
         entity_commands.insert(ld.clone());
     }
+    if let Some(ref inst) = aux.instance_data {
+        instancing::insert_instances(entity_commands, inst.clone());
+    }
+    if let Some(ref tags) = aux.tags {
+        entity_commands.insert(tags.clone());
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -398,14 +543,8 @@ fn insert_aux_components(entity_commands: &mut bevy::ecs::system::EntityCommands
 pub fn apply_delete_requests(
     mut pending: ResMut<PendingCommands>,
     mut commands: Commands,
-    query: Query<(Entity, &EntityId, &EntityName, &Transform, &EntityVisible, Option<&EntityType>, Option<&MaterialData>, Option<&LightData>, Option<&PhysicsData>, Option<&PhysicsEnabled>, Option<&AssetRef>), Without<Undeletable>>,
-    script_audio_query: Query<(&EntityId, Option<&ScriptData>, Option<&AudioData>)>,
-    reverb_particle_query: Query<(&EntityId, Option<&super::reverb_zone::ReverbZoneData>, Option<&super::reverb_zone::ReverbZoneEnabled>, Option<&ParticleData>, Option<&ParticleEnabled>)>,
-    shader_csg_query: Query<(&EntityId, Option<&ShaderEffectData>, Option<&csg::CsgMeshData>)>,
-    procedural_joint_query: Query<(&EntityId, Option<&super::procedural_mesh::ProceduralMeshData>, Option<&JointData>)>,
-    game_anim_query: Query<(&EntityId, Option<&super::game_components::GameComponents>, Option<&AnimationClipData>, Option<&GameCameraData>, Option<&ActiveGameCamera>)>,
-    sprite_query: Query<(&EntityId, Option<&super::sprite::SpriteData>)>,
-    physics2d_tilemap_skeleton_lod_query: Query<(&EntityId, Option<&Physics2dData>, Option<&Physics2dEnabled>, Option<&TilemapData>, Option<&TilemapEnabled>, Option<&SkeletonData2d>, Option<&SkeletonEnabled2d>, Option<&LodData>)>,
+    query: Query<SnapshotData, (Without<Undeletable>, Without<Locked>)>,
+    aux_queries: AuxQueries,
     mut selection: ResMut<Selection>,
     mut selection_events: MessageWriter<SelectionChangedEvent>,
     mut history: ResMut<HistoryStack>,
@@ -415,19 +554,10 @@ pub fn apply_delete_requests(
     }
 
     // Pre-index: entity ID string -> (Entity, base query data) for O(1) lookup
-    let entity_index: HashMap<String, (Entity, &EntityId, &EntityName, &Transform, &EntityVisible, Option<&EntityType>, Option<&MaterialData>, Option<&LightData>, Option<&PhysicsData>, Option<&PhysicsEnabled>, Option<&AssetRef>)> =
-        query.iter().map(|row| (row.1 .0.clone(), row)).collect();
+    let entity_index: HashMap<String, _> = query.iter().map(|row| (row.1 .0.clone(), row)).collect();
 
     // Pre-index auxiliary component data (single O(n) pass over 7 queries)
-    let aux_index = build_aux_index(
-        &script_audio_query,
-        &reverb_particle_query,
-        &shader_csg_query,
-        &procedural_joint_query,
-        &game_anim_query,
-        &sprite_query,
-        &physics2d_tilemap_skeleton_lod_query,
-    );
+    let aux_index = aux_queries.build_index();
 
     let empty_aux = AuxComponentData::default();
     let mut deleted_any = false;
@@ -441,10 +571,10 @@ pub fn apply_delete_requests(
                 let entity_type = ent_type.copied().unwrap_or(EntityType::Cube);
                 let aux = aux_index.get(&eid.0).unwrap_or(&empty_aux);
 
-                let snapshot = snapshot_entity(
-                    &eid.0, entity_type, &name.0, transform, visible.0,
-                    mat_data, light_data, phys_data, phys_enabled.is_some(), asset_ref, aux,
-                );
+                let base = SnapshotBase {
+                    mat_data, light_data, phys_data, phys_enabled: phys_enabled.is_some(), asset_ref,
+                };
+                let snapshot = snapshot_entity(&eid.0, entity_type, &name.0, transform, visible.0, base, aux);
                 history.push(UndoableAction::Delete { snapshot });
 
                 commands.entity(entity).despawn();
@@ -482,87 +612,100 @@ pub fn apply_delete_requests(
 // Duplicate system
 // ---------------------------------------------------------------------------
 
+/// Offset of a duplicate from its source when there is no move to repeat.
+const DUPLICATE_OFFSET: Vec3 = Vec3::X;
+
+/// Source rows read when duplicating an entity.
+type DuplicateSourceQuery<'w, 's> = Query<'w, 's, (
+    Entity,
+    &'static EntityId,
+    &'static EntityName,
+    &'static Transform,
+    &'static EntityVisible,
+    Option<&'static EntityType>,
+    Option<&'static Mesh3d>,
+    Option<&'static MeshMaterial3d<StandardMaterial>>,
+    Option<&'static PointLight>,
+    Option<&'static DirectionalLight>,
+    Option<&'static SpotLight>,
+    Option<&'static MaterialData>,
+    Option<&'static LightData>,
+    Option<&'static PhysicsData>,
+    Option<&'static PhysicsEnabled>,
+)>;
+
+/// Everything the duplicate system reads from the source entities.
+#[derive(SystemParam)]
+pub struct DuplicateSources<'w, 's> {
+    query: DuplicateSourceQuery<'w, 's>,
+    asset_refs: Query<'w, 's, (&'static EntityId, Option<&'static AssetRef>)>,
+    aux: AuxQueries<'w, 's>,
+}
+
 /// System that processes pending duplicate requests.
 /// Uses pre-indexed HashMaps for O(n) batch performance instead of O(n^2) nested loops.
+///
+/// Smart duplicates copy the whole selection, offset by the last move, and
+/// select the copies; several in one frame build the row in one go.
 pub fn apply_duplicate_requests(
     mut pending: ResMut<PendingCommands>,
     mut commands: Commands,
-    query: Query<(
-        Entity,
-        &EntityId,
-        &EntityName,
-        &Transform,
-        &EntityVisible,
-        Option<&EntityType>,
-        Option<&Mesh3d>,
-        Option<&MeshMaterial3d<StandardMaterial>>,
-        Option<&PointLight>,
-        Option<&DirectionalLight>,
-        Option<&SpotLight>,
-        Option<&MaterialData>,
-        Option<&LightData>,
-        Option<&PhysicsData>,
-        Option<&PhysicsEnabled>,
-    )>,
-    asset_ref_query: Query<(&EntityId, Option<&AssetRef>)>,
-    script_audio_query: Query<(&EntityId, Option<&ScriptData>, Option<&AudioData>)>,
-    reverb_particle_query: Query<(&EntityId, Option<&super::reverb_zone::ReverbZoneData>, Option<&super::reverb_zone::ReverbZoneEnabled>, Option<&ParticleData>, Option<&ParticleEnabled>)>,
-    shader_csg_query: Query<(&EntityId, Option<&ShaderEffectData>, Option<&csg::CsgMeshData>)>,
-    procedural_joint_query: Query<(&EntityId, Option<&super::procedural_mesh::ProceduralMeshData>, Option<&JointData>)>,
-    game_anim_query: Query<(&EntityId, Option<&super::game_components::GameComponents>, Option<&AnimationClipData>, Option<&GameCameraData>, Option<&ActiveGameCamera>)>,
-    sprite_query: Query<(&EntityId, Option<&super::sprite::SpriteData>)>,
-    physics2d_tilemap_skeleton_lod_query: Query<(&EntityId, Option<&Physics2dData>, Option<&Physics2dEnabled>, Option<&TilemapData>, Option<&TilemapEnabled>, Option<&SkeletonData2d>, Option<&SkeletonEnabled2d>, Option<&LodData>)>,
+    sources: DuplicateSources,
     mut history: ResMut<HistoryStack>,
+    last_move: Res<LastMoveDelta>,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
 ) {
-    if pending.duplicate_requests.is_empty() {
+    if pending.duplicate_requests.is_empty() && pending.smart_duplicate_requests.is_empty() {
         return;
     }
 
+    // (source id, offset, select the copy)
+    let mut jobs: Vec<(String, Vec3, bool)> = pending.duplicate_requests
+        .drain(..)
+        .map(|request| (request.entity_id, DUPLICATE_OFFSET, false))
+        .collect();
+    let smart_count = pending.smart_duplicate_requests.drain(..).count();
+    if smart_count > 0 {
+        let step = last_move.0.unwrap_or(DUPLICATE_OFFSET);
+        let mut sources: Vec<String> = selection.entity_ids.iter().cloned().collect();
+        sources.sort();
+        for n in 1..=smart_count {
+            for source in &sources {
+                jobs.push((source.clone(), step * n as f32, n == smart_count));
+            }
+        }
+    }
+
     // Pre-index: entity ID string -> query row for O(1) lookup
-    let entity_index: HashMap<
-        String,
-        (
-            Entity, &EntityId, &EntityName, &Transform, &EntityVisible,
-            Option<&EntityType>, Option<&Mesh3d>, Option<&MeshMaterial3d<StandardMaterial>>,
-            Option<&PointLight>, Option<&DirectionalLight>, Option<&SpotLight>,
-            Option<&MaterialData>, Option<&LightData>, Option<&PhysicsData>,
-            Option<&PhysicsEnabled>,
-        ),
-    > = query.iter().map(|row| (row.1 .0.clone(), row)).collect();
+    let entity_index: HashMap<String, _> = sources.query.iter().map(|row| (row.1 .0.clone(), row)).collect();
 
     // Pre-index: entity ID string -> AssetRef for O(1) lookup
-    let asset_ref_index: HashMap<String, Option<AssetRef>> = asset_ref_query
+    let asset_ref_index: HashMap<String, Option<AssetRef>> = sources.asset_refs
         .iter()
         .map(|(eid, ar)| (eid.0.clone(), ar.cloned()))
         .collect();
 
     // Pre-index auxiliary component data (single O(n) pass over 7 queries)
-    let aux_index = build_aux_index(
-        &script_audio_query,
-        &reverb_particle_query,
-        &shader_csg_query,
-        &procedural_joint_query,
-        &game_anim_query,
-        &sprite_query,
-        &physics2d_tilemap_skeleton_lod_query,
-    );
+    let aux_index = sources.aux.build_index();
 
     let empty_aux = AuxComponentData::default();
+    let mut selected_copies: Vec<(Entity, String)> = Vec::new();
 
-    for request in pending.duplicate_requests.drain(..) {
+    for (source_id, offset, select) in jobs {
         // O(1) lookup instead of O(n) linear scan
         if let Some(&(
             _entity, source_eid, name, transform, visible,
             src_entity_type, mesh_handle, material_handle,
             point_light, dir_light, spot_light,
             src_mat_data, src_light_data, src_phys_data, src_phys_enabled,
-        )) = entity_index.get(&request.entity_id)
+        )) = entity_index.get(&source_id)
         {
             let src_asset_ref = asset_ref_index.get(&source_eid.0).and_then(|ar| ar.as_ref());
             let aux = aux_index.get(&source_eid.0).unwrap_or(&empty_aux);
 
             // Clone with offset
-            let new_pos = transform.translation + Vec3::new(1.0, 0.0, 0.0);
+            let new_pos = transform.translation + offset;
             let new_name = format!("{} (Copy)", name.0);
 
             // Create a new EntityId for the duplicate
@@ -640,12 +783,19 @@ pub fn apply_duplicate_requests(
             // Clone auxiliary component data
             insert_aux_components(&mut entity_commands, aux);
 
+            if select {
+                selected_copies.push((entity_commands.id(), new_entity_id_str.clone()));
+            }
+
             // Build snapshot using shared helper
-            let mut snapshot = snapshot_entity(
-                &source_eid.0, entity_type, &name.0, transform, visible.0,
-                src_mat_data, src_light_data, src_phys_data, src_phys_enabled.is_some(),
-                src_asset_ref, aux,
-            );
+            let base = SnapshotBase {
+                mat_data: src_mat_data,
+                light_data: src_light_data,
+                phys_data: src_phys_data,
+                phys_enabled: src_phys_enabled.is_some(),
+                asset_ref: src_asset_ref,
+            };
+            let mut snapshot = snapshot_entity(&source_eid.0, entity_type, &name.0, transform, visible.0, base, aux);
             // Override snapshot fields for the NEW duplicate entity
             snapshot.entity_id = new_entity_id_str;
             snapshot.entity_type = entity_type;
@@ -671,6 +821,18 @@ pub fn apply_duplicate_requests(
             });
         }
     }
+
+    if !selected_copies.is_empty() {
+        selection.clear();
+        for (entity, id) in selected_copies {
+            selection.add(entity, id);
+        }
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: selection.selected_ids(),
+            primary_id: selection.primary_id.clone(),
+            primary_name: None,
+        });
+    }
 }
 
 // Helper functions for spawning each entity type (return entity, entity_id, position)
@@ -956,6 +1118,51 @@ fn spawn_spot_light_with_id(
     (entity, entity_id_str, pos)
 }
 
+fn spawn_light_probe_volume_with_id(
+    commands: &mut Commands,
+    name: &str,
+    position: Option<Vec3>,
+) -> (Entity, String, Vec3) {
+    let pos = position.unwrap_or(Vec3::new(0.0, 2.5, 0.0));
+    let entity_id = EntityId::default();
+    let entity_id_str = entity_id.0.clone();
+
+    let entity = commands.spawn((
+        EntityType::LightProbeVolume,
+        entity_id,
+        EntityName::new(name),
+        EntityVisible::default(),
+        LightProbeVolume::default(),
+        Transform::from_translation(pos),
+        Visibility::default(),
+    )).id();
+
+    (entity, entity_id_str, pos)
+}
+
+/// Spawn a transform-only node. Also used by grouping, which places it at the
+/// selection's centroid.
+pub fn spawn_empty_with_id(
+    commands: &mut Commands,
+    name: &str,
+    position: Option<Vec3>,
+) -> (Entity, String, Vec3) {
+    let pos = position.unwrap_or(Vec3::ZERO);
+    let entity_id = EntityId::default();
+    let entity_id_str = entity_id.0.clone();
+
+    let entity = commands.spawn((
+        EntityType::Empty,
+        entity_id,
+        EntityName::new(name),
+        EntityVisible::default(),
+        Transform::from_translation(pos),
+        Visibility::default(),
+    )).id();
+
+    (entity, entity_id_str, pos)
+}
+
 /// Spawn an entity from a snapshot (for undo/redo).
 pub fn spawn_from_snapshot(
     commands: &mut Commands,
@@ -1131,7 +1338,7 @@ pub fn spawn_from_snapshot(
             // Rebuild the mesh from stored heightmap data
             if let Some(ref mesh_data) = snapshot.terrain_mesh_data {
                 let terrain_data = snapshot.terrain_data.clone().unwrap_or_default();
-                let mesh = terrain::rebuild_terrain_mesh(mesh_data);
+                let mesh = terrain::rebuild_terrain_mesh(mesh_data, &terrain_data.holes);
                 let mut entity_commands = commands.spawn((
                     snapshot.entity_type,
                     entity_id,
@@ -1268,6 +1475,39 @@ pub fn spawn_from_snapshot(
                 transform,
             )).id()
         }
+        EntityType::LightProbeVolume => {
+            let volume = snapshot.light_probe_volume.clone().unwrap_or_default();
+            commands.spawn((
+                snapshot.entity_type,
+                entity_id,
+                EntityName::new(&snapshot.name),
+                EntityVisible(snapshot.visible),
+                volume,
+                transform,
+                Visibility::default(),
+            )).id()
+        }
+        EntityType::Empty => {
+            commands.spawn((
+                snapshot.entity_type,
+                entity_id,
+                EntityName::new(&snapshot.name),
+                EntityVisible(snapshot.visible),
+                transform,
+                Visibility::default(),
+            )).id()
+        }
+        EntityType::Path => {
+            commands.spawn((
+                snapshot.entity_type,
+                entity_id,
+                EntityName::new(&snapshot.name),
+                EntityVisible(snapshot.visible),
+                snapshot.path_data.clone().unwrap_or_default(),
+                transform,
+                Visibility::default(),
+            )).id()
+        }
         EntityType::Sprite => {
             // Sprite entities spawn with just metadata - actual rendering handled by sprite system
             let sprite_data = snapshot.sprite_data.clone().unwrap_or_default();
@@ -1317,6 +1557,9 @@ pub fn spawn_from_snapshot(
     if snapshot.particle_enabled {
         commands.entity(entity).insert(ParticleEnabled);
     }
+    if let Some(field) = snapshot.particle_force_field {
+        commands.entity(entity).insert(field);
+    }
 
     // Restore shader data if present
     if let Some(sed) = &snapshot.shader_effect_data {
@@ -1332,6 +1575,9 @@ pub fn spawn_from_snapshot(
     if let Some(gc) = &snapshot.game_components {
         commands.entity(entity).insert(gc.clone());
     }
+    if let Some(constraint) = &snapshot.look_at_constraint {
+        commands.entity(entity).insert(constraint.clone());
+    }
 
     // Restore animation clip data if present
     if let Some(acd) = &snapshot.animation_clip_data {
@@ -1372,12 +1618,29 @@ pub fn spawn_from_snapshot(
     if snapshot.tilemap_enabled {
         commands.entity(entity).insert(TilemapEnabled);
     }
+    if let Some(tsd) = &snapshot.tileset_data {
+        commands.entity(entity).insert(tsd.clone());
+    }
 
     // Restore LOD data if present
     if let Some(ld) = &snapshot.lod_data {
         commands.entity(entity).insert(ld.clone());
     }
 
+    // Restore instanced array if present
+    if let Some(transforms) = &snapshot.instance_transforms {
+        instancing::insert_instances(&mut commands.entity(entity), InstanceData::from_snapshots(transforms));
+    }
+
+    // Restore prefab link if present
+    if let Some(instance) = &snapshot.prefab_instance {
+        commands.entity(entity).insert(instance.clone());
+    }
+
+    if !snapshot.tags.is_empty() {
+        commands.entity(entity).insert(EntityTags(snapshot.tags.iter().cloned().collect()));
+    }
+
     entity
 }
 
@@ -1437,11 +1700,11 @@ pub fn apply_redo_requests(
 /// System that applies pending material updates from the bridge.
 pub fn apply_material_updates(
     mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut MaterialData)>,
+    mut query: Query<(&EntityId, &mut MaterialData, Option<&mut PrefabInstance>)>,
     mut history: ResMut<HistoryStack>,
 ) {
     for update in pending.material_updates.drain(..) {
-        for (entity_id, mut current_mat) in query.iter_mut() {
+        for (entity_id, mut current_mat, prefab_instance) in query.iter_mut() {
             if entity_id.0 == update.entity_id {
                 let old_material = current_mat.clone();
                 // Merge: start from incoming update but preserve existing texture IDs
@@ -1456,7 +1719,11 @@ pub fn apply_material_updates(
                 if new_mat.clearcoat_texture.is_none() { new_mat.clearcoat_texture = old_material.clearcoat_texture.clone(); }
                 if new_mat.clearcoat_roughness_texture.is_none() { new_mat.clearcoat_roughness_texture = old_material.clearcoat_roughness_texture.clone(); }
                 if new_mat.clearcoat_normal_texture.is_none() { new_mat.clearcoat_normal_texture = old_material.clearcoat_normal_texture.clone(); }
+                if new_mat.anisotropy_texture.is_none() { new_mat.anisotropy_texture = old_material.anisotropy_texture.clone(); }
                 *current_mat = new_mat.clone();
+                if let Some(mut instance) = prefab_instance {
+                    instance.record_overrides("material", &old_material, &new_mat);
+                }
 
                 // Record for undo
                 history.push(UndoableAction::MaterialChange {
@@ -1473,17 +1740,20 @@ pub fn apply_material_updates(
 /// System that applies pending light updates from the bridge.
 pub fn apply_light_updates(
     mut pending: ResMut<PendingCommands>,
-    mut query: Query<(&EntityId, &mut LightData)>,
+    mut query: Query<(&EntityId, &mut LightData, Option<&mut PrefabInstance>)>,
     mut history: ResMut<HistoryStack>,
 ) {
     for update in pending.light_updates.drain(..) {
-        for (entity_id, mut current_light) in query.iter_mut() {
+        for (entity_id, mut current_light, prefab_instance) in query.iter_mut() {
             if entity_id.0 == update.entity_id {
                 let old_light = current_light.clone();
                 // Preserve the light_type from the existing component
                 let mut new_light = update.light_data.clone();
                 new_light.light_type = old_light.light_type.clone();
                 *current_light = new_light.clone();
+                if let Some(mut instance) = prefab_instance {
+                    instance.record_overrides("light", &old_light, &new_light);
+                }
 
                 // Record for undo
                 history.push(UndoableAction::LightChange {
@@ -1513,6 +1783,154 @@ pub fn apply_ambient_light_updates(
 }
 
 /// Execute undo for an action.
+/// Set a terrain's hole flags and rebuild its mesh (shared by undo and redo).
+fn restore_terrain_holes(
+    commands: &mut Commands,
+    query: &Query<(Entity, &EntityId, &mut Transform, &mut EntityName, &mut EntityVisible)>,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    entity_id: &str,
+    holes: &[bool],
+    mesh_data: &terrain::TerrainMeshData,
+) {
+    let Some((entity, ..)) = query.iter().find(|(_, eid, ..)| eid.0 == entity_id) else {
+        return;
+    };
+    let mesh = terrain::rebuild_terrain_mesh(mesh_data, holes);
+    let holes = holes.to_vec();
+    commands.entity(entity)
+        .insert(Mesh3d(meshes.add(mesh)))
+        .entry::<terrain::TerrainData>()
+        .and_modify(move |mut data| data.holes = holes);
+}
+
+/// Put an entity's mesh and stored mesh data back, along with its type and
+/// transform when the edit changed them (apply transform and mesh edit undo/redo).
+#[allow(clippy::too_many_arguments)]
+fn restore_mesh_state(
+    commands: &mut Commands,
+    query: &mut Query<(Entity, &EntityId, &mut Transform, &mut EntityName, &mut EntityVisible)>,
+    entity_id: &str,
+    entity_type: Option<EntityType>,
+    transform: Option<&TransformSnapshot>,
+    mesh: &Handle<Mesh>,
+    mesh_data: &Option<super::procedural_mesh::ProceduralMeshData>,
+    csg_data: &Option<csg::CsgMeshData>,
+) {
+    let Some((entity, _, mut entity_transform, _, _)) = query.iter_mut().find(|(_, eid, ..)| eid.0 == entity_id) else {
+        return;
+    };
+    if let Some(transform) = transform {
+        *entity_transform = transform.to_transform();
+    }
+    let mut entity_commands = commands.entity(entity);
+    entity_commands.insert(Mesh3d(mesh.clone()));
+    if let Some(entity_type) = entity_type {
+        entity_commands.insert(entity_type);
+    }
+    match mesh_data {
+        Some(data) => entity_commands.insert(data.clone()),
+        None => entity_commands.remove::<super::procedural_mesh::ProceduralMeshData>(),
+    };
+    match csg_data {
+        Some(data) => entity_commands.insert(data.clone()),
+        None => entity_commands.remove::<csg::CsgMeshData>(),
+    };
+}
+
+/// Write a tile region back into a tilemap layer (for tile paint undo/redo).
+fn restore_tile_region(
+    commands: &mut Commands,
+    query: &Query<(Entity, &EntityId, &mut Transform, &mut EntityName, &mut EntityVisible)>,
+    entity_id: &str,
+    layer: usize,
+    rect: super::tilemap::TileRect,
+    tiles: &[Option<u32>],
+) {
+    let Some((entity, ..)) = query.iter().find(|(_, eid, ..)| eid.0 == entity_id) else {
+        return;
+    };
+    let tiles = tiles.to_vec();
+    commands.entity(entity)
+        .entry::<super::tilemap::TilemapData>()
+        .and_modify(move |mut data| data.write_region(layer, rect, &tiles));
+}
+
+/// Move grouped children to their old (`use_new == false`) or new parents and
+/// transforms, respawning the group empty first when it must exist afterwards
+/// and despawning it last when it must not.
+#[allow(clippy::too_many_arguments)]
+fn restore_grouping(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    query: &Query<(Entity, &EntityId, &mut Transform, &mut EntityName, &mut EntityVisible)>,
+    group: &EntitySnapshot,
+    children: &[GroupedChild],
+    use_new: bool,
+    group_exists: bool,
+) {
+    let mut id_to_entity: HashMap<&str, Entity> = query.iter()
+        .map(|(entity, eid, _, _, _)| (eid.0.as_str(), entity))
+        .collect();
+
+    if group_exists {
+        let group_entity = spawn_from_snapshot(commands, meshes, materials, group);
+        if let Some(&parent) = group.parent_id.as_deref().and_then(|pid| id_to_entity.get(pid)) {
+            commands.entity(group_entity).insert(ChildOf(parent));
+        }
+        id_to_entity.insert(group.entity_id.as_str(), group_entity);
+    }
+
+    for child in children {
+        let Some(&entity) = id_to_entity.get(child.entity_id.as_str()) else {
+            continue;
+        };
+        let (parent_id, transform) = if use_new {
+            (&child.new_parent_id, &child.new_transform)
+        } else {
+            (&child.old_parent_id, &child.old_transform)
+        };
+        let mut ec = commands.entity(entity);
+        ec.insert(transform.to_transform());
+        match parent_id.as_deref().and_then(|pid| id_to_entity.get(pid)) {
+            Some(&parent) => { ec.insert(ChildOf(parent)); }
+            None => { ec.remove::<ChildOf>(); }
+        }
+    }
+
+    if !group_exists {
+        if let Some(&group_entity) = id_to_entity.get(group.entity_id.as_str()) {
+            commands.entity(group_entity).despawn();
+        }
+    }
+}
+
+/// Move an entity under `parent_id` at sibling position `index` (appended when
+/// `None`), or to the root when `parent_id` is `None`.
+fn restore_parent(
+    commands: &mut Commands,
+    query: &Query<(Entity, &EntityId, &mut Transform, &mut EntityName, &mut EntityVisible)>,
+    entity_id: &str,
+    parent_id: Option<&str>,
+    index: Option<usize>,
+) {
+    let find = |id: &str| query.iter().find(|(_, eid, _, _, _)| eid.0 == id).map(|(e, ..)| e);
+    let Some(entity) = find(entity_id) else {
+        return;
+    };
+
+    match parent_id.and_then(find) {
+        Some(parent) => {
+            commands.entity(entity).remove::<ChildOf>();
+            match index {
+                Some(index) => super::reparent::place_child(commands, parent, entity, index),
+                None => { commands.entity(parent).add_child(entity); }
+            }
+        }
+        None => { commands.entity(entity).remove::<ChildOf>(); }
+    }
+}
+
 fn execute_undo(
     action: &UndoableAction,
     commands: &mut Commands,
@@ -1650,6 +2068,17 @@ fn execute_undo(
                 }
             }
         }
+        UndoableAction::ParticleForceFieldChange { entity_id, old_field, .. } => {
+            for (entity, eid, _, _, _) in query.iter() {
+                if &eid.0 == entity_id {
+                    match old_field {
+                        Some(field) => commands.entity(entity).insert(*field),
+                        None => commands.entity(entity).remove::<ParticleForceField>(),
+                    };
+                    break;
+                }
+            }
+        }
         UndoableAction::ShaderChange { entity_id, old_shader, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -1686,6 +2115,44 @@ fn execute_undo(
                 }
             }
         }
+        UndoableAction::CsgChain { source_snapshots, result_snapshot, sources_deleted } => {
+            // 1. Delete the result entity
+            for (entity, eid, _, _, _) in query.iter() {
+                if eid.0 == result_snapshot.entity_id {
+                    commands.entity(entity).despawn();
+                    break;
+                }
+            }
+
+            // 2. Restore every source entity if they were deleted
+            if *sources_deleted {
+                for snap in source_snapshots {
+                    spawn_from_snapshot(commands, meshes, materials, snap);
+                }
+            }
+        }
+        UndoableAction::SubdivideMesh { entity_id, old_entity_type, old_mesh, old_mesh_data, .. } => {
+            // Restore the pre-subdivision mesh and procedural data
+            for (entity, eid, _, _, _) in query.iter() {
+                if &eid.0 == entity_id {
+                    let mut entity_commands = commands.entity(entity);
+                    entity_commands.insert((*old_entity_type, Mesh3d(old_mesh.clone())));
+                    match old_mesh_data {
+                        Some(data) => entity_commands.insert(data.clone()),
+                        None => entity_commands.remove::<super::procedural_mesh::ProceduralMeshData>(),
+                    };
+                    break;
+                }
+            }
+        }
+        UndoableAction::ApplyTransform { entity_id, old_entity_type, old_transform, old_mesh, old_mesh_data, old_csg_data, .. } => {
+            // Restore the pre-freeze transform and mesh
+            restore_mesh_state(commands, query, entity_id, Some(*old_entity_type), Some(old_transform), old_mesh, old_mesh_data, old_csg_data);
+        }
+        UndoableAction::MeshEdit { entity_id, old_mesh, old_mesh_data, old_csg_data, .. } => {
+            // Restore the mesh as it was before the edit
+            restore_mesh_state(commands, query, entity_id, None, None, old_mesh, old_mesh_data, old_csg_data);
+        }
         UndoableAction::TerrainChange { entity_id, old_terrain, old_mesh_data, .. } => {
             // Restore old terrain data and rebuild mesh
             for (entity, eid, _, _, _) in query.iter() {
@@ -1694,12 +2161,15 @@ fn execute_undo(
                     commands.entity(entity).insert(old_terrain.clone());
                     commands.entity(entity).insert(old_mesh_data.clone());
                     // Rebuild mesh from old heightmap
-                    let mesh = terrain::rebuild_terrain_mesh(old_mesh_data);
+                    let mesh = terrain::rebuild_terrain_mesh(old_mesh_data, &old_terrain.holes);
                     commands.entity(entity).insert(Mesh3d(meshes.add(mesh)));
                     break;
                 }
             }
         }
+        UndoableAction::TerrainHoleEdit { entity_id, old_holes, mesh_data, .. } => {
+            restore_terrain_holes(commands, query, meshes, entity_id, old_holes, mesh_data);
+        }
         UndoableAction::ExtrudeShape { snapshot } => {
             // Delete the extruded entity
             for (entity, eid, _, _, _) in query.iter() {
@@ -1709,8 +2179,11 @@ fn execute_undo(
                 }
             }
         }
-        UndoableAction::LatheShape { snapshot } => {
-            // Delete the lathed entity
+        UndoableAction::LatheShape { snapshot }
+        | UndoableAction::LoftShape { snapshot }
+        | UndoableAction::SweepShape { snapshot }
+        | UndoableAction::InstanceArray { snapshot, .. } => {
+            // Delete the lathed, lofted, swept, or instanced entity
             for (entity, eid, _, _, _) in query.iter() {
                 if eid.0 == snapshot.entity_id {
                     commands.entity(entity).despawn();
@@ -1742,6 +2215,27 @@ fn execute_undo(
                 spawn_from_snapshot(commands, meshes, materials, snap);
             }
         }
+        UndoableAction::MergeScene { created_snapshots }
+        | UndoableAction::PasteEntities { created_snapshots } => {
+            // Despawn merged roots; their merged children go with them
+            for snap in created_snapshots.iter().filter(|s| s.parent_id.is_none()) {
+                for (entity, eid, _, _, _) in query.iter() {
+                    if eid.0 == snap.entity_id {
+                        commands.entity(entity).despawn();
+                        break;
+                    }
+                }
+            }
+        }
+        UndoableAction::GroupEntities { group, children } => {
+            restore_grouping(commands, meshes, materials, query, group, children, false, false);
+        }
+        UndoableAction::UngroupEntities { group, children } => {
+            restore_grouping(commands, meshes, materials, query, group, children, false, true);
+        }
+        UndoableAction::Reparent { entity_id, old_parent_id, old_index, .. } => {
+            restore_parent(commands, query, entity_id, old_parent_id.as_deref(), *old_index);
+        }
         UndoableAction::JointChange { entity_id, old_joint, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -1766,6 +2260,19 @@ fn execute_undo(
                 }
             }
         }
+        UndoableAction::LookAtConstraintChange { entity_id, old_constraint, .. } => {
+            if let Some((entity, ..)) = query.iter().find(|(_, eid, ..)| &eid.0 == entity_id) {
+                match old_constraint {
+                    Some(constraint) => commands.entity(entity).insert(constraint.clone()),
+                    None => commands.entity(entity).remove::<super::game_components::LookAtConstraint>(),
+                };
+            }
+        }
+        UndoableAction::PathChange { entity_id, old_path, .. } => {
+            if let Some((entity, ..)) = query.iter().find(|(_, eid, ..)| &eid.0 == entity_id) {
+                commands.entity(entity).insert(old_path.clone());
+            }
+        }
         UndoableAction::AnimationClipChange { entity_id, old_clip, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -1830,6 +2337,9 @@ fn execute_undo(
                 }
             }
         }
+        UndoableAction::TilemapTilesEdit { entity_id, layer, rect, old_tiles, .. } => {
+            restore_tile_region(commands, query, entity_id, *layer, *rect, old_tiles);
+        }
         UndoableAction::TilemapChange { entity_id, old_tilemap, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -1858,6 +2368,11 @@ fn execute_undo(
                 }
             }
         }
+        UndoableAction::Transaction { actions, .. } => {
+            for action in actions.iter().rev() {
+                execute_undo(action, commands, query, mat_query, light_query, physics_query, script_query, audio_query, particle_query, meshes, materials);
+            }
+        }
     }
 }
 
@@ -1993,6 +2508,17 @@ fn execute_redo(
                 }
             }
         }
+        UndoableAction::ParticleForceFieldChange { entity_id, new_field, .. } => {
+            for (entity, eid, _, _, _) in query.iter() {
+                if &eid.0 == entity_id {
+                    match new_field {
+                        Some(field) => commands.entity(entity).insert(*field),
+                        None => commands.entity(entity).remove::<ParticleForceField>(),
+                    };
+                    break;
+                }
+            }
+        }
         UndoableAction::ShaderChange { entity_id, new_shader, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -2034,6 +2560,43 @@ fn execute_redo(
             // 2. Restore the result entity from snapshot
             spawn_from_snapshot(commands, meshes, materials, result_snapshot);
         }
+        UndoableAction::CsgChain { source_snapshots, result_snapshot, sources_deleted } => {
+            // 1. Delete source entities if they were originally deleted
+            if *sources_deleted {
+                for snap in source_snapshots {
+                    for (entity, eid, _, _, _) in query.iter() {
+                        if eid.0 == snap.entity_id {
+                            commands.entity(entity).despawn();
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // 2. Restore the result entity from snapshot
+            spawn_from_snapshot(commands, meshes, materials, result_snapshot);
+        }
+        UndoableAction::SubdivideMesh { entity_id, new_mesh, new_mesh_data, .. } => {
+            // Re-apply the subdivided mesh
+            for (entity, eid, _, _, _) in query.iter() {
+                if &eid.0 == entity_id {
+                    commands.entity(entity).insert((
+                        EntityType::ProceduralMesh,
+                        Mesh3d(new_mesh.clone()),
+                        new_mesh_data.clone(),
+                    ));
+                    break;
+                }
+            }
+        }
+        UndoableAction::ApplyTransform { entity_id, new_entity_type, new_transform, new_mesh, new_mesh_data, new_csg_data, .. } => {
+            // Re-apply the frozen transform and baked mesh
+            restore_mesh_state(commands, query, entity_id, Some(*new_entity_type), Some(new_transform), new_mesh, new_mesh_data, new_csg_data);
+        }
+        UndoableAction::MeshEdit { entity_id, new_mesh, new_mesh_data, new_csg_data, .. } => {
+            // Re-apply the edited mesh
+            restore_mesh_state(commands, query, entity_id, None, None, new_mesh, new_mesh_data, new_csg_data);
+        }
         UndoableAction::TerrainChange { entity_id, new_terrain, new_mesh_data, .. } => {
             // Apply new terrain data and rebuild mesh
             for (entity, eid, _, _, _) in query.iter() {
@@ -2042,18 +2605,24 @@ fn execute_redo(
                     commands.entity(entity).insert(new_terrain.clone());
                     commands.entity(entity).insert(new_mesh_data.clone());
                     // Rebuild mesh from new heightmap
-                    let mesh = terrain::rebuild_terrain_mesh(new_mesh_data);
+                    let mesh = terrain::rebuild_terrain_mesh(new_mesh_data, &new_terrain.holes);
                     commands.entity(entity).insert(Mesh3d(meshes.add(mesh)));
                     break;
                 }
             }
         }
+        UndoableAction::TerrainHoleEdit { entity_id, new_holes, mesh_data, .. } => {
+            restore_terrain_holes(commands, query, meshes, entity_id, new_holes, mesh_data);
+        }
         UndoableAction::ExtrudeShape { snapshot } => {
             // Re-create the extruded entity
             spawn_from_snapshot(commands, meshes, materials, snapshot);
         }
-        UndoableAction::LatheShape { snapshot } => {
-            // Re-create the lathed entity
+        UndoableAction::LatheShape { snapshot }
+        | UndoableAction::LoftShape { snapshot }
+        | UndoableAction::SweepShape { snapshot }
+        | UndoableAction::InstanceArray { snapshot, .. } => {
+            // Re-create the lathed, lofted, swept, or instanced entity
             spawn_from_snapshot(commands, meshes, materials, snapshot);
         }
         UndoableAction::ArrayEntity { created_snapshots, .. } => {
@@ -2075,6 +2644,33 @@ fn execute_redo(
             // Re-create the combined result entity
             spawn_from_snapshot(commands, meshes, materials, result_snapshot);
         }
+        UndoableAction::MergeScene { created_snapshots }
+        | UndoableAction::PasteEntities { created_snapshots } => {
+            // Re-create all merged entities, then restore their hierarchy
+            let mut id_to_entity: HashMap<&str, Entity> = HashMap::new();
+            for snap in created_snapshots {
+                let entity = spawn_from_snapshot(commands, meshes, materials, snap);
+                id_to_entity.insert(snap.entity_id.as_str(), entity);
+            }
+            for snap in created_snapshots {
+                if let Some(ref parent_id) = snap.parent_id {
+                    if let (Some(&child), Some(&parent)) =
+                        (id_to_entity.get(snap.entity_id.as_str()), id_to_entity.get(parent_id.as_str()))
+                    {
+                        commands.entity(child).insert(ChildOf(parent));
+                    }
+                }
+            }
+        }
+        UndoableAction::GroupEntities { group, children } => {
+            restore_grouping(commands, meshes, materials, query, group, children, true, true);
+        }
+        UndoableAction::UngroupEntities { group, children } => {
+            restore_grouping(commands, meshes, materials, query, group, children, true, false);
+        }
+        UndoableAction::Reparent { entity_id, new_parent_id, new_index, .. } => {
+            restore_parent(commands, query, entity_id, new_parent_id.as_deref(), *new_index);
+        }
         UndoableAction::JointChange { entity_id, new_joint, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -2099,6 +2695,19 @@ fn execute_redo(
                 }
             }
         }
+        UndoableAction::LookAtConstraintChange { entity_id, new_constraint, .. } => {
+            if let Some((entity, ..)) = query.iter().find(|(_, eid, ..)| &eid.0 == entity_id) {
+                match new_constraint {
+                    Some(constraint) => commands.entity(entity).insert(constraint.clone()),
+                    None => commands.entity(entity).remove::<super::game_components::LookAtConstraint>(),
+                };
+            }
+        }
+        UndoableAction::PathChange { entity_id, new_path, .. } => {
+            if let Some((entity, ..)) = query.iter().find(|(_, eid, ..)| &eid.0 == entity_id) {
+                commands.entity(entity).insert(new_path.clone());
+            }
+        }
         UndoableAction::AnimationClipChange { entity_id, new_clip, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -2163,6 +2772,9 @@ fn execute_redo(
                 }
             }
         }
+        UndoableAction::TilemapTilesEdit { entity_id, layer, rect, new_tiles, .. } => {
+            restore_tile_region(commands, query, entity_id, *layer, *rect, new_tiles);
+        }
         UndoableAction::TilemapChange { entity_id, new_tilemap, .. } => {
             for (entity, eid, _, _, _) in query.iter() {
                 if &eid.0 == entity_id {
@@ -2191,5 +2803,97 @@ fn execute_redo(
                 }
             }
         }
+        UndoableAction::Transaction { actions, .. } => {
+            // Entities respawned by an earlier action aren't visible to `query`
+            // until commands are applied, so later actions in the group that
+            // look them up by ID are skipped.
+            for action in actions {
+                execute_redo(action, commands, query, mat_query, light_query, physics_query, script_query, audio_query, particle_query, meshes, materials);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::commands::dispatch;
+    use serde_json::json;
+
+    #[test]
+    fn spawn_configured_builds_the_whole_entity_in_one_frame() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, apply_spawn_configured_requests);
+        let pending = app.world_mut().resource_mut::<PendingCommands>().into_inner() as *mut PendingCommands;
+        crate::core::pending_commands::register_pending_commands(pending);
+        app.world_mut().spawn(EntityId("crates".to_string()));
+
+        let material = MaterialData { base_color: [1.0, 0.2, 0.1, 1.0], ..Default::default() };
+        dispatch("spawn_configured", json!({
+            "entityType": "cube",
+            "name": "Crate",
+            "parentId": "crates",
+            "materialData": material,
+            "physicsData": {"bodyType": "dynamic", "colliderShape": "cuboid", "restitution": 0.1, "friction": 0.8,
+                "density": 2.0, "gravityScale": 1.0, "lockTranslationX": false, "lockTranslationY": false,
+                "lockTranslationZ": false, "lockRotationX": false, "lockRotationY": false, "lockRotationZ": false,
+                "isSensor": false},
+            "tags": ["loot", "breakable"],
+        })).expect("pending commands are registered");
+        app.update();
+
+        let mut spawned = app.world_mut().query_filtered::<(&EntityName, &MaterialData, &PhysicsData, &EntityTags, &ChildOf), With<PhysicsEnabled>>();
+        let (name, material, physics, tags, child_of) = spawned.single(app.world()).expect("one configured entity");
+        assert_eq!(name.0, "Crate");
+        assert_eq!(material.base_color, [1.0, 0.2, 0.1, 1.0]);
+        assert_eq!(physics.density, 2.0);
+        assert!(tags.0.contains("loot") && tags.0.contains("breakable"));
+        assert_eq!(app.world().get::<EntityId>(child_of.parent()).map(|id| id.0.as_str()), Some("crates"));
+        let mut history = app.world_mut().resource_mut::<HistoryStack>();
+        assert!(matches!(history.pop_undo(), Some(UndoableAction::Spawn { .. })));
+        assert!(!history.can_undo(), "the whole spawn is one undo step");
+    }
+
+    #[test]
+    fn instanced_snapshot_restores_as_instanced_array() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>();
+
+        let mut snapshot = EntitySnapshot::new("grid".to_string(), EntityType::Cube, "Grid (Instanced)".to_string(), TransformSnapshot::from(&Transform::IDENTITY));
+        let offsets = [Transform::IDENTITY, Transform::from_xyz(2.0, 0.0, 0.0), Transform::from_xyz(4.0, 0.0, 0.0)];
+        snapshot.instance_transforms = Some(offsets.iter().map(TransformSnapshot::from).collect());
+
+        let entity = app.world_mut()
+            .run_system_once(move |mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<StandardMaterial>>| {
+                spawn_from_snapshot(&mut commands, &mut meshes, &mut materials, &snapshot)
+            })
+            .expect("restore system should run");
+
+        let world = app.world();
+        let instances = world.get::<InstanceData>(entity).expect("instances restored");
+        assert_eq!(instances.transforms, offsets);
+        assert!(world.get::<Mesh3d>(entity).is_some());
+        assert!(world.get::<MeshMaterial3d<StandardMaterial>>(entity).is_none(), "only the instancing pass draws the array");
+    }
+
+    #[test]
+    fn spawn_configured_rejects_bad_payloads() {
+        let err = dispatch("spawn_configured", json!({"entityType": "cube", "colour": "red"})).unwrap_err();
+        assert!(err.contains("unknown field `colour`"), "{err}");
+        let err = dispatch("spawn_configured", json!({"entityType": "dragon"})).unwrap_err();
+        assert!(err.contains("Unknown entity type"), "{err}");
+        let err = dispatch("spawn_configured", json!({"entityType": "terrain"})).unwrap_err();
+        assert!(err.contains("cannot create"), "{err}");
+        let err = dispatch("spawn_configured", json!({"entityType": "point_light", "materialData": MaterialData::default()})).unwrap_err();
+        assert!(err.contains("materialData"), "{err}");
     }
 }
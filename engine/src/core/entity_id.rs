@@ -4,6 +4,7 @@
 //! and can be serialized to JSON for React communication.
 
 use bevy::prelude::*;
+use std::collections::HashSet;
 use uuid::Uuid;
 
 /// Stable identifier for entities, used in bridge communication.
@@ -77,3 +78,21 @@ impl EntityVisible {
         self.0 = !self.0;
     }
 }
+
+/// Free-form labels ("enemy", "pickup") used to group and look up entities.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct EntityTags(pub HashSet<String>);
+
+impl EntityTags {
+    /// Check whether the entity carries `tag`.
+    pub fn contains(&self, tag: &str) -> bool {
+        self.0.contains(tag)
+    }
+
+    /// Tags in sorted order, for stable output.
+    pub fn sorted(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.0.iter().cloned().collect();
+        tags.sort();
+        tags
+    }
+}
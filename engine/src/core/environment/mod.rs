@@ -0,0 +1,773 @@
+//! Environment settings - skybox, clear color, fog.
+//!
+//! Provides:
+//! - Configurable ClearColor background
+//! - Distance fog (linear, exponential or exponential-squared), optionally
+//!   thinning with height
+//! - Skybox cubemap rendering with 5 built-in presets
+//! - Custom skyboxes from an equirectangular image or six face images
+//! - Procedural (Preetham) sky driven by a sun position, optionally aiming a
+//!   directional light along the sunlight
+//! - Image-based lighting (IBL)
+
+use bevy::prelude::*;
+use bevy::pbr::{DistanceFog, FogFalloff};
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use serde::{Serialize, Deserialize};
+
+use super::camera::EditorCamera;
+use super::entity_id::EntityId;
+use super::pending_commands::SetCustomSkyboxRequest;
+
+mod procedural_sky;
+
+pub use procedural_sky::*;
+
+/// User-editable environment settings, serializable for the bridge.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentSettings {
+    pub skybox_brightness: f32,
+    pub ibl_intensity: f32,
+    pub ibl_rotation_degrees: f32,
+    pub clear_color: [f32; 3],
+    pub fog_enabled: bool,
+    pub fog_color: [f32; 3],
+    pub fog_start: f32,
+    pub fog_end: f32,
+    #[serde(default)]
+    pub fog_mode: FogMode,
+    /// Density for the exponential fog modes.
+    #[serde(default = "default_fog_density")]
+    pub fog_density: f32,
+    /// How fast fog thins above `fog_base_height`; 0 disables height fog.
+    #[serde(default)]
+    pub fog_height_falloff: f32,
+    #[serde(default)]
+    pub fog_base_height: f32,
+    pub skybox_preset: Option<String>,
+    pub skybox_asset_id: Option<String>,
+    #[serde(default)]
+    pub procedural_sky: Option<ProceduralSky>,
+}
+
+impl Default for EnvironmentSettings {
+    fn default() -> Self {
+        Self {
+            skybox_brightness: 1000.0,
+            ibl_intensity: 900.0,
+            ibl_rotation_degrees: 0.0,
+            clear_color: [0.1, 0.1, 0.12],    // Dark grey-blue
+            fog_enabled: false,
+            fog_color: [0.5, 0.5, 0.55],
+            fog_start: 30.0,
+            fog_end: 100.0,
+            fog_mode: FogMode::Linear,
+            fog_density: default_fog_density(),
+            fog_height_falloff: 0.0,
+            fog_base_height: 0.0,
+            skybox_preset: None,
+            skybox_asset_id: None,
+            procedural_sky: None,
+        }
+    }
+}
+
+fn default_fog_density() -> f32 {
+    0.05
+}
+
+/// How fog thickens with distance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FogMode {
+    /// Ramps from `fog_start` to `fog_end`.
+    #[default]
+    Linear,
+    /// Exponential in distance, scaled by `fog_density`.
+    Exp,
+    /// Exponential in squared distance: clear nearby, then closes in fast.
+    Exp2,
+}
+
+impl EnvironmentSettings {
+    /// Fog falloff seen from a camera at `camera_height`.
+    ///
+    /// With a height falloff the fog thins exponentially above
+    /// `fog_base_height`. Bevy's distance fog is uniform, so the thinning is
+    /// evaluated once at the camera: ground mist clears as the camera rises.
+    pub fn fog_falloff(&self, camera_height: f32) -> FogFalloff {
+        let height = (camera_height - self.fog_base_height).max(0.0);
+        let thinning = (-self.fog_height_falloff.max(0.0) * height).exp();
+        match self.fog_mode {
+            FogMode::Linear => {
+                let stretch = 1.0 / thinning.max(1e-4);
+                FogFalloff::Linear {
+                    start: self.fog_start * stretch,
+                    end: self.fog_end * stretch,
+                }
+            }
+            FogMode::Exp => FogFalloff::Exponential { density: self.fog_density * thinning },
+            FogMode::Exp2 => FogFalloff::ExponentialSquared { density: self.fog_density * thinning },
+        }
+    }
+}
+
+/// Parameters of the procedural sky. Changing them re-renders the sky, so
+/// animating the sun gives a day/night cycle.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProceduralSky {
+    /// Sun heading in degrees around +Y: 0 puts the sun toward +Z, 90 toward +X.
+    pub sun_azimuth: f32,
+    /// Sun height above the horizon in degrees; below zero is night.
+    pub sun_elevation: f32,
+    /// Atmospheric haze, from about 2 (clear) to 10 (hazy).
+    pub turbidity: f32,
+    /// Ground reflectance in `[0, 1]`, lighting the lower hemisphere.
+    pub ground_albedo: f32,
+    /// Directional light kept pointing along the sunlight.
+    #[serde(default)]
+    pub sun_entity_id: Option<String>,
+}
+
+impl Default for ProceduralSky {
+    fn default() -> Self {
+        Self {
+            sun_azimuth: 0.0,
+            sun_elevation: 45.0,
+            turbidity: 3.0,
+            ground_albedo: 0.3,
+            sun_entity_id: None,
+        }
+    }
+}
+
+impl ProceduralSky {
+    /// Rotation that points a light's forward axis (-Z) along the sunlight.
+    pub fn sun_rotation(&self) -> Quat {
+        Quat::from_euler(
+            EulerRot::YXZ,
+            self.sun_azimuth.to_radians(),
+            -self.sun_elevation.to_radians(),
+            0.0,
+        )
+    }
+
+    /// Unit vector from the scene toward the sun.
+    pub fn sun_direction(&self) -> Vec3 {
+        self.sun_rotation() * Vec3::Z
+    }
+}
+
+/// Resource for caching generated preset cubemap handles.
+#[derive(Resource, Default)]
+pub struct SkyboxHandles {
+    pub handles: std::collections::HashMap<String, Handle<Image>>,
+    /// Cubemap re-rendered in place whenever the procedural sky changes.
+    pub procedural: Option<Handle<Image>>,
+}
+
+/// Generate a procedural cubemap for a built-in preset.
+/// Returns a cubemap Image with 6 faces (64x64 each).
+pub fn generate_preset_cubemap(preset: &str) -> Image {
+    let size = 64;
+    let face_size = size * size * 4; // RGBA
+
+    // Define color schemes for each preset
+    let colors = match preset {
+        "studio" => {
+            // Neutral gray studio
+            let gray = [0x88, 0x88, 0x88, 0xFF];
+            [gray; 6]
+        }
+        "sunset" => {
+            // Warm orange/purple gradient
+            let orange = [0xFF, 0x66, 0x33, 0xFF];
+            let purple = [0x66, 0x33, 0xAA, 0xFF];
+            [orange, orange, purple, orange, orange, orange] // Bottom faces orange, top purple
+        }
+        "overcast" => {
+            // Cool gray-blue
+            let cool = [0x77, 0x88, 0xAA, 0xFF];
+            [cool; 6]
+        }
+        "night" => {
+            // Dark navy
+            let navy = [0x11, 0x11, 0x33, 0xFF];
+            [navy; 6]
+        }
+        "bright_day" => {
+            // Blue top, white bottom
+            let blue = [0x88, 0xBB, 0xFF, 0xFF];
+            let white = [0xEE, 0xEE, 0xEE, 0xFF];
+            [white, white, blue, white, white, white] // Bottom white, top blue
+        }
+        _ => {
+            // Default fallback
+            let gray = [0x88, 0x88, 0x88, 0xFF];
+            [gray; 6]
+        }
+    };
+
+    // Create pixel data for all 6 faces
+    let mut data = Vec::with_capacity(face_size * 6);
+    for face_color in &colors {
+        for _ in 0..(size * size) {
+            data.extend_from_slice(face_color);
+        }
+    }
+
+    // Create the cubemap image
+    let mut image = Image::new(
+        Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 6,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::asset::RenderAssetUsages::RENDER_WORLD,
+    );
+
+    // Mark as cubemap — image is already created with depth_or_array_layers: 6,
+    // so no reinterpret_stacked_2d_as_array call is needed (that's for stacked 2D layouts).
+    image.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
+        dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
+        ..default()
+    });
+
+    image
+}
+
+/// Unit direction through the center of texel (`x`, `y`) on cubemap `face`.
+///
+/// Face order: +X, -X, +Y, -Y, +Z, -Z (standard cubemap convention).
+fn cube_texel_direction(face: u32, x: u32, y: u32, face_size: u32) -> Vec3 {
+    // Normalized coordinates in [-1, 1]
+    let u = (x as f32 + 0.5) / face_size as f32 * 2.0 - 1.0;
+    let v = (y as f32 + 0.5) / face_size as f32 * 2.0 - 1.0;
+
+    let direction = match face {
+        0 => Vec3::new( 1.0, -v,   -u),   // +X
+        1 => Vec3::new(-1.0, -v,    u),   // -X
+        2 => Vec3::new( u,    1.0,  v),   // +Y
+        3 => Vec3::new( u,   -1.0, -v),   // -Y
+        4 => Vec3::new( u,   -v,    1.0), // +Z
+        5 => Vec3::new(-u,   -v,   -1.0), // -Z
+        _ => unreachable!(),
+    };
+    direction.normalize()
+}
+
+/// Convert an equirectangular (2:1 aspect ratio) image into a cubemap with 6 faces.
+///
+/// The input image is a standard equirectangular projection (latitude/longitude mapped to x/y).
+/// The output is a Bevy `Image` with `depth_or_array_layers: 6` and `TextureViewDimension::Cube`.
+///
+/// Face order: +X, -X, +Y, -Y, +Z, -Z (standard cubemap convention).
+pub fn equirectangular_to_cubemap(source: &Image, face_size: u32) -> Image {
+    let src_width = source.width();
+    let src_height = source.height();
+    let src_data = match source.data.as_ref() {
+        Some(data) => data,
+        None => {
+            bevy::log::error!("equirectangular_to_cubemap: Image has no CPU pixel data, returning blank cubemap");
+            // Return a blank black cubemap instead of panicking
+            let face_pixels = (face_size * face_size) as usize;
+            let data = vec![0u8; face_pixels * 6 * 4];
+            let mut img = Image::new(
+                Extent3d { width: face_size, height: face_size, depth_or_array_layers: 6 },
+                TextureDimension::D2,
+                data,
+                TextureFormat::Rgba8UnormSrgb,
+                bevy::asset::RenderAssetUsages::all(),
+            );
+            img.texture_descriptor.view_formats = &[];
+            img.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
+                dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
+                ..Default::default()
+            });
+            return img;
+        }
+    };
+
+    // Determine bytes per pixel from the source format
+    let bpp: usize = match source.texture_descriptor.format {
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => 4,
+        TextureFormat::Rgba16Float => 8,
+        _ => 4, // Fallback — most decoded PNGs are RGBA8
+    };
+    let out_bpp: usize = 4; // Output is always RGBA8
+
+    let face_pixels = (face_size * face_size) as usize;
+    let mut data = vec![0u8; face_pixels * 6 * out_bpp];
+
+    // For each of the 6 faces, compute the direction vector for each texel,
+    // then sample from the equirectangular source.
+    for face in 0u32..6 {
+        for y in 0..face_size {
+            for x in 0..face_size {
+                // Direction vector for this texel on this face
+                let Vec3 { x: nx, y: ny, z: nz } = cube_texel_direction(face, x, y, face_size);
+
+                // theta: azimuth angle [-pi, pi], phi: elevation [-pi/2, pi/2]
+                let theta = nz.atan2(nx);
+                let phi = ny.asin();
+
+                // Map to equirectangular UV coordinates [0, 1]
+                let eq_u = (theta / std::f32::consts::PI + 1.0) * 0.5;
+                let eq_v = (-phi / std::f32::consts::FRAC_PI_2 + 1.0) * 0.5;
+
+                // Sample source pixel (nearest neighbor)
+                let sx = ((eq_u * src_width as f32) as u32).min(src_width - 1);
+                let sy = ((eq_v * src_height as f32) as u32).min(src_height - 1);
+
+                let src_idx = (sy as usize * src_width as usize + sx as usize) * bpp;
+                let dst_idx = (face as usize * face_pixels + y as usize * face_size as usize + x as usize) * out_bpp;
+
+                if src_idx + bpp <= src_data.len() && dst_idx + out_bpp <= data.len() {
+                    if bpp == 8 {
+                        // Rgba16Float -> Rgba8: read f16 values, convert to u8
+                        // For simplicity, use a basic conversion (clamp to [0,1])
+                        for c in 0..4 {
+                            let half_idx = src_idx + c * 2;
+                            let half_bits = u16::from_le_bytes([
+                                src_data[half_idx],
+                                src_data[half_idx + 1],
+                            ]);
+                            let f = half_to_f32(half_bits);
+                            data[dst_idx + c] = (f.clamp(0.0, 1.0) * 255.0) as u8;
+                        }
+                    } else {
+                        data[dst_idx]     = src_data[src_idx];
+                        data[dst_idx + 1] = src_data[src_idx + 1];
+                        data[dst_idx + 2] = src_data[src_idx + 2];
+                        data[dst_idx + 3] = src_data[src_idx + 3];
+                    }
+                }
+            }
+        }
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: face_size,
+            height: face_size,
+            depth_or_array_layers: 6,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::asset::RenderAssetUsages::RENDER_WORLD,
+    );
+
+    image.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
+        dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
+        ..default()
+    });
+
+    image
+}
+
+/// Decode an image from a data URL ("data:image/png;base64,...") or raw
+/// base64, which is assumed to be PNG.
+pub fn decode_image_data_url(data: &str) -> Result<Image, String> {
+    use base64::Engine as _;
+    use bevy::image::{CompressedImageFormats, ImageSampler, ImageType};
+
+    let (mime_type, raw_base64) = if let Some(comma_pos) = data.find(',') {
+        let mime = data[..comma_pos]
+            .strip_prefix("data:")
+            .and_then(|s| s.strip_suffix(";base64"))
+            .unwrap_or("image/png");
+        (mime, &data[comma_pos + 1..])
+    } else {
+        ("image/png", data)
+    };
+
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(raw_base64)
+        .map_err(|e| format!("Failed to decode base64 image: {}", e))?;
+    Image::from_buffer(
+        &bytes,
+        ImageType::MimeType(mime_type),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        bevy::asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .map_err(|e| format!("Failed to create image: {}", e))
+}
+
+/// Stack six square face images into a cubemap.
+///
+/// Faces are in cubemap order (+X, -X, +Y, -Y, +Z, -Z), must all be the same
+/// size, and are converted to RGBA8 when needed.
+pub fn cubemap_from_faces(faces: &[Image]) -> Result<Image, String> {
+    if faces.len() != 6 {
+        return Err(format!("A cubemap needs 6 faces, got {}", faces.len()));
+    }
+    let size = faces[0].width();
+    if size == 0 {
+        return Err("Skybox faces are empty".to_string());
+    }
+
+    let mut data = Vec::with_capacity((size * size) as usize * 6 * 4);
+    for (index, face) in faces.iter().enumerate() {
+        if face.width() != size || face.height() != size {
+            return Err(format!(
+                "Skybox face {} is {}x{}, expected {}x{}",
+                index, face.width(), face.height(), size, size,
+            ));
+        }
+        let converted;
+        let face = match face.texture_descriptor.format {
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => face,
+            format => {
+                converted = face
+                    .convert(TextureFormat::Rgba8UnormSrgb)
+                    .ok_or_else(|| format!("Skybox face {} has unsupported format {:?}", index, format))?;
+                &converted
+            }
+        };
+        let pixels = face.data.as_ref().ok_or_else(|| format!("Skybox face {} has no pixel data", index))?;
+        data.extend_from_slice(pixels);
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 6,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::asset::RenderAssetUsages::RENDER_WORLD,
+    );
+    image.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
+        dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
+        ..default()
+    });
+    Ok(image)
+}
+
+/// Build the cubemap for a set_custom_skybox request.
+///
+/// Six faces are stacked as given. A single image that is already a 6-layer
+/// cubemap is used as-is; otherwise it is treated as equirectangular and
+/// resampled to faces half its height (64–256 px).
+pub fn custom_skybox_cubemap(request: &SetCustomSkyboxRequest) -> Result<Image, String> {
+    if !request.faces.is_empty() {
+        let faces = request.faces.iter()
+            .map(|face| decode_image_data_url(face))
+            .collect::<Result<Vec<_>, _>>()?;
+        return cubemap_from_faces(&faces);
+    }
+
+    let source = decode_image_data_url(&request.data_base64)?;
+    if source.texture_descriptor.size.depth_or_array_layers == 6 {
+        let mut image = source;
+        image.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
+            dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
+            ..default()
+        });
+        return Ok(image);
+    }
+
+    let face_size = (source.height() / 2).clamp(64, 256);
+    tracing::info!(
+        "Converting equirectangular image ({}x{}) to cubemap ({}x{} per face)",
+        source.width(), source.height(), face_size, face_size,
+    );
+    Ok(equirectangular_to_cubemap(&source, face_size))
+}
+
+/// Convert IEEE 754 half-precision float (f16) to f32.
+fn half_to_f32(h: u16) -> f32 {
+    let sign = ((h >> 15) & 1) as u32;
+    let exp = ((h >> 10) & 0x1F) as u32;
+    let mant = (h & 0x3FF) as u32;
+
+    if exp == 0 {
+        // Subnormal or zero
+        if mant == 0 {
+            return f32::from_bits(sign << 31);
+        }
+        // Subnormal: normalize
+        let mut e = 0i32;
+        let mut m = mant;
+        while m & 0x400 == 0 {
+            m <<= 1;
+            e -= 1;
+        }
+        m &= 0x3FF;
+        let f_exp = (127 - 15 + 1 + e) as u32;
+        return f32::from_bits((sign << 31) | (f_exp << 23) | (m << 13));
+    }
+    if exp == 31 {
+        // Inf or NaN
+        return f32::from_bits((sign << 31) | (0xFF << 23) | (mant << 13));
+    }
+    let f_exp = exp + (127 - 15);
+    f32::from_bits((sign << 31) | (f_exp << 23) | (mant << 13))
+}
+
+pub struct EnvironmentPlugin;
+
+impl Plugin for EnvironmentPlugin {
+    fn build(&self, app: &mut App) {
+        let settings = EnvironmentSettings::default();
+        let clear = ClearColor(Color::linear_rgb(
+            settings.clear_color[0],
+            settings.clear_color[1],
+            settings.clear_color[2],
+        ));
+        app.insert_resource(settings)
+           .insert_resource(clear)
+           .init_resource::<SkyboxHandles>()
+           .add_systems(Update, (sync_environment_settings, sync_procedural_sky));
+    }
+}
+
+/// Watches EnvironmentSettings resource and syncs to Bevy components (ClearColor, FogSettings).
+/// Height fog also follows the camera, so it re-syncs when the camera moves.
+pub fn sync_environment_settings(
+    settings: Res<EnvironmentSettings>,
+    mut clear_color: ResMut<ClearColor>,
+    mut fog_query: Query<(Entity, &mut DistanceFog), With<EditorCamera>>,
+    camera_no_fog: Query<Entity, (With<EditorCamera>, Without<DistanceFog>)>,
+    camera_transform: Query<Ref<GlobalTransform>, With<EditorCamera>>,
+    mut commands: Commands,
+) {
+    let camera_transform = camera_transform.single().ok();
+    let camera_moved = camera_transform.as_ref().is_some_and(|t| t.is_changed());
+    let height_fog = settings.fog_enabled && settings.fog_height_falloff > 0.0;
+    if !(settings.is_changed() || height_fog && camera_moved) {
+        return;
+    }
+
+    // Update clear color
+    clear_color.0 = Color::linear_rgb(
+        settings.clear_color[0],
+        settings.clear_color[1],
+        settings.clear_color[2],
+    );
+
+    // Handle fog
+    if settings.fog_enabled {
+        let fog_color = Color::linear_rgb(
+            settings.fog_color[0],
+            settings.fog_color[1],
+            settings.fog_color[2],
+        );
+        let camera_height = camera_transform.map_or(0.0, |t| t.translation().y);
+        let falloff = settings.fog_falloff(camera_height);
+
+        if let Ok((_entity, mut fog)) = fog_query.single_mut() {
+            // Update existing fog
+            fog.color = fog_color;
+            fog.falloff = falloff;
+        } else if let Ok(camera_entity) = camera_no_fog.single() {
+            // Add fog to camera
+            commands.entity(camera_entity).insert(DistanceFog {
+                color: fog_color,
+                falloff,
+                ..default()
+            });
+        }
+    } else {
+        // Remove fog if present
+        if let Ok((entity, _)) = fog_query.single_mut() {
+            commands.entity(entity).remove::<DistanceFog>();
+        }
+    }
+}
+
+/// Re-renders the procedural sky onto the editor camera and aims the linked
+/// sun light whenever the sky parameters change.
+pub fn sync_procedural_sky(
+    settings: Res<EnvironmentSettings>,
+    mut skybox_handles: ResMut<SkyboxHandles>,
+    mut images: ResMut<Assets<Image>>,
+    camera_query: Query<Entity, With<EditorCamera>>,
+    mut lights: Query<(&EntityId, &mut Transform), With<DirectionalLight>>,
+    mut rendered: Local<Option<ProceduralSky>>,
+    mut commands: Commands,
+) {
+    let Some(sky) = settings.procedural_sky.as_ref() else {
+        *rendered = None;
+        return;
+    };
+    if rendered.as_ref() == Some(sky) {
+        return;
+    }
+    *rendered = Some(sky.clone());
+
+    let image = generate_procedural_sky_cubemap(sky);
+    let handle = match skybox_handles.procedural.clone() {
+        Some(handle) if images.contains(&handle) => {
+            images.insert(&handle, image).ok();
+            handle
+        }
+        _ => {
+            let handle = images.add(image);
+            skybox_handles.procedural = Some(handle.clone());
+            handle
+        }
+    };
+    if let Ok(camera_entity) = camera_query.single() {
+        commands.entity(camera_entity).insert(bevy::core_pipeline::Skybox {
+            image: handle,
+            brightness: settings.skybox_brightness,
+            ..Default::default()
+        });
+    }
+
+    if let Some(sun_id) = sky.sun_entity_id.as_deref() {
+        if let Some((_, mut transform)) = lights.iter_mut().find(|(id, _)| id.0 == sun_id) {
+            transform.rotation = sky.sun_rotation();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::render_resource::TextureViewDimension;
+
+    fn face(size: u32, value: u8) -> Image {
+        Image::new_fill(
+            Extent3d { width: size, height: size, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            &[value, value, value, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            bevy::asset::RenderAssetUsages::RENDER_WORLD,
+        )
+    }
+
+    #[test]
+    fn six_faces_stack_into_a_cube_image() {
+        let faces: Vec<Image> = (0..6).map(|i| face(4, i * 40)).collect();
+        let cubemap = cubemap_from_faces(&faces).expect("faces should assemble");
+
+        assert_eq!(cubemap.texture_descriptor.size, Extent3d { width: 4, height: 4, depth_or_array_layers: 6 });
+        let view = cubemap.texture_view_descriptor.as_ref().and_then(|v| v.dimension);
+        assert_eq!(view, Some(TextureViewDimension::Cube));
+        let data = cubemap.data.as_ref().unwrap();
+        let layer_bytes = 4 * 4 * 4;
+        for layer in 0..6 {
+            assert_eq!(data[layer * layer_bytes], layer as u8 * 40, "layer {layer}");
+        }
+    }
+
+    #[test]
+    fn sun_elevation_change_rerenders_sky_and_aims_sun_light() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_resource::<EnvironmentSettings>()
+            .init_resource::<SkyboxHandles>()
+            .add_systems(Update, sync_procedural_sky);
+        let camera = app.world_mut().spawn(EditorCamera).id();
+        let sun = app.world_mut().spawn((EntityId("sun".to_string()), Transform::default(), DirectionalLight::default())).id();
+
+        let set_elevation = |app: &mut App, elevation: f32| {
+            app.world_mut().resource_mut::<EnvironmentSettings>().procedural_sky = Some(ProceduralSky {
+                sun_elevation: elevation,
+                sun_entity_id: Some("sun".to_string()),
+                ..Default::default()
+            });
+            app.update();
+        };
+        let sky_pixels = |app: &App| {
+            let skybox = app.world().get::<bevy::core_pipeline::Skybox>(camera).expect("camera should get a skybox");
+            let image = app.world().resource::<Assets<Image>>().get(&skybox.image).unwrap();
+            assert_eq!(image.texture_descriptor.size.depth_or_array_layers, 6);
+            image.data.clone().unwrap()
+        };
+
+        set_elevation(&mut app, 60.0);
+        let noon = sky_pixels(&app);
+        let forward = app.world().get::<Transform>(sun).unwrap().forward();
+        assert!((forward.y + 60f32.to_radians().sin()).abs() < 1e-4, "forward {forward:?}");
+
+        set_elevation(&mut app, 5.0);
+        let dusk = sky_pixels(&app);
+        let forward = app.world().get::<Transform>(sun).unwrap().forward();
+        assert!((forward.y + 5f32.to_radians().sin()).abs() < 1e-4, "forward {forward:?}");
+        assert_ne!(noon, dusk);
+    }
+
+    #[test]
+    fn exp2_fog_mode_sets_squared_falloff_on_camera() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<EnvironmentSettings>()
+            .init_resource::<ClearColor>()
+            .add_systems(Update, sync_environment_settings);
+        let camera = app.world_mut().spawn((EditorCamera, GlobalTransform::default())).id();
+
+        {
+            let mut settings = app.world_mut().resource_mut::<EnvironmentSettings>();
+            settings.fog_enabled = true;
+            settings.fog_mode = FogMode::Exp2;
+            settings.fog_density = 0.2;
+        }
+        app.update();
+
+        let fog = app.world().get::<DistanceFog>(camera).expect("camera should have fog");
+        assert!(matches!(fog.falloff, FogFalloff::ExponentialSquared { density } if (density - 0.2).abs() < 1e-6));
+    }
+
+    #[test]
+    fn height_falloff_thins_fog_above_base_height() {
+        let settings = EnvironmentSettings {
+            fog_mode: FogMode::Exp,
+            fog_density: 0.1,
+            fog_height_falloff: 0.5,
+            fog_base_height: 2.0,
+            ..Default::default()
+        };
+        let density = |height| match settings.fog_falloff(height) {
+            FogFalloff::Exponential { density } => density,
+            other => panic!("unexpected falloff {other:?}"),
+        };
+        assert_eq!(density(0.0), 0.1);
+        assert!((density(4.0) - 0.1 * (-1.0f32).exp()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn fog_fields_round_trip_and_default_for_older_scenes() {
+        let settings = EnvironmentSettings {
+            fog_mode: FogMode::Exp2,
+            fog_density: 0.3,
+            fog_height_falloff: 0.2,
+            fog_base_height: -1.0,
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&settings).unwrap();
+        assert_eq!(json["fogMode"], "exp2");
+        let restored: EnvironmentSettings = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(restored.fog_mode, FogMode::Exp2);
+        assert_eq!(restored.fog_base_height, -1.0);
+
+        let mut old = json;
+        for key in ["fogMode", "fogDensity", "fogHeightFalloff", "fogBaseHeight"] {
+            old.as_object_mut().unwrap().remove(key);
+        }
+        let restored: EnvironmentSettings = serde_json::from_value(old).unwrap();
+        assert_eq!(restored.fog_mode, FogMode::Linear);
+        assert_eq!(restored.fog_density, default_fog_density());
+    }
+
+    #[test]
+    fn mismatched_or_missing_faces_are_rejected() {
+        let mut faces: Vec<Image> = (0..6).map(|_| face(4, 0)).collect();
+        faces[3] = face(8, 0);
+        assert!(cubemap_from_faces(&faces).unwrap_err().contains("face 3"));
+        assert!(cubemap_from_faces(&faces[..5]).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//! Procedural (Preetham) sky rendered into a cubemap.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use super::{cube_texel_direction, ProceduralSky};
+
+/// Face size of the procedural sky cubemap.
+const PROCEDURAL_SKY_FACE_SIZE: u32 = 64;
+
+/// Scales Preetham luminance (kcd/m²) into display range before tone mapping.
+const PROCEDURAL_SKY_EXPOSURE: f32 = 0.05;
+
+/// Perez sky distribution coefficients A–E for one channel.
+struct Perez([f32; 5]);
+
+impl Perez {
+    fn eval(&self, cos_theta: f32, gamma: f32, cos_gamma: f32) -> f32 {
+        let [a, b, c, d, e] = self.0;
+        (1.0 + a * (b / cos_theta).exp()) * (1.0 + c * (d * gamma).exp() + e * cos_gamma * cos_gamma)
+    }
+}
+
+/// Preetham analytic daylight model, evaluated in CIE xyY.
+struct PreethamSky {
+    sun: Vec3,
+    /// Distributions for luminance Y and chromaticities x, y.
+    perez: [Perez; 3],
+    /// Zenith values divided by the distribution at the zenith, per channel.
+    scale: [f32; 3],
+    /// Fades the sky out as the sun sets.
+    daylight: f32,
+    ground_albedo: f32,
+}
+
+impl PreethamSky {
+    fn new(sky: &ProceduralSky) -> Self {
+        let t = sky.turbidity.clamp(1.7, 10.0);
+        let sun = sky.sun_direction();
+        // The model is only defined for a sun above the horizon; below it the
+        // sunset sky is kept and faded out.
+        let theta_s = sun.y.max(0.0).acos().min(1.55);
+
+        let perez = [
+            Perez([0.1787 * t - 1.4630, -0.3554 * t + 0.4275, -0.0227 * t + 5.3251, 0.1206 * t - 2.5771, -0.0670 * t + 0.3703]),
+            Perez([-0.0193 * t - 0.2592, -0.0665 * t + 0.0008, -0.0004 * t + 0.2125, -0.0641 * t - 0.8989, -0.0033 * t + 0.0452]),
+            Perez([-0.0167 * t - 0.2608, -0.0950 * t + 0.0092, -0.0079 * t + 0.2102, -0.0441 * t - 1.6537, -0.0109 * t + 0.0529]),
+        ];
+
+        let chi = (4.0 / 9.0 - t / 120.0) * (std::f32::consts::PI - 2.0 * theta_s);
+        let zenith_luminance = (4.0453 * t - 4.9710) * chi.tan() - 0.2155 * t + 2.4192;
+        let (t2, th, th2, th3) = (t * t, theta_s, theta_s * theta_s, theta_s * theta_s * theta_s);
+        let zenith_x = t2 * (0.00166 * th3 - 0.00375 * th2 + 0.00209 * th)
+            + t * (-0.02903 * th3 + 0.06377 * th2 - 0.03202 * th + 0.00394)
+            + (0.11693 * th3 - 0.21196 * th2 + 0.06052 * th + 0.25886);
+        let zenith_y = t2 * (0.00275 * th3 - 0.00610 * th2 + 0.00317 * th)
+            + t * (-0.04214 * th3 + 0.08970 * th2 - 0.04153 * th + 0.00516)
+            + (0.15346 * th3 - 0.26756 * th2 + 0.06670 * th + 0.26688);
+
+        let zenith = [zenith_luminance, zenith_x, zenith_y];
+        let scale = std::array::from_fn(|i| zenith[i] / perez[i].eval(1.0, theta_s, theta_s.cos()));
+
+        Self {
+            sun,
+            perez,
+            scale,
+            daylight: smoothstep(-0.1, 0.05, sun.y),
+            ground_albedo: sky.ground_albedo.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Linear RGB sky color looking along unit vector `view`.
+    fn radiance(&self, view: Vec3) -> Vec3 {
+        if view.y < 0.0 {
+            // Ground: lit by the sky overhead and the sun.
+            return self.radiance(Vec3::Y) * self.ground_albedo * (1.0 + 2.0 * self.sun.y.max(0.0));
+        }
+
+        let cos_theta = view.y.max(0.01);
+        let cos_gamma = view.dot(self.sun).clamp(-1.0, 1.0);
+        let gamma = cos_gamma.acos();
+        let [luminance, x, y]: [f32; 3] =
+            std::array::from_fn(|i| self.scale[i] * self.perez[i].eval(cos_theta, gamma, cos_gamma));
+
+        let luminance = luminance.max(0.0) * PROCEDURAL_SKY_EXPOSURE;
+        let xyz = Xyza::xyz(x / y * luminance, luminance, (1.0 - x - y) / y * luminance);
+        let linear = LinearRgba::from(xyz);
+        let mut color = Vec3::new(linear.red, linear.green, linear.blue).max(Vec3::ZERO);
+
+        // Sun glow, wider than the real disk so it survives the coarse faces.
+        color += Vec3::splat(4.0 * ((cos_gamma - 1.0) * 800.0).exp());
+
+        let night = Vec3::new(0.005, 0.008, 0.02);
+        night.lerp(color, self.daylight)
+    }
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Render the procedural sky into a cubemap.
+pub fn generate_procedural_sky_cubemap(sky: &ProceduralSky) -> Image {
+    let model = PreethamSky::new(sky);
+    let size = PROCEDURAL_SKY_FACE_SIZE;
+    let mut data = Vec::with_capacity((size * size) as usize * 6 * 4);
+    for face in 0u32..6 {
+        for y in 0..size {
+            for x in 0..size {
+                let color = model.radiance(cube_texel_direction(face, x, y, size));
+                // Reinhard tone mapping keeps the sun glow from clipping the sky.
+                let mapped = color / (Vec3::ONE + color);
+                data.extend_from_slice(&Srgba::from(LinearRgba::rgb(mapped.x, mapped.y, mapped.z)).to_u8_array());
+            }
+        }
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 6,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::asset::RenderAssetUsages::RENDER_WORLD,
+    );
+    image.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
+        dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
+        ..default()
+    });
+    image
+}
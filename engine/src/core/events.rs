@@ -0,0 +1,110 @@
+//! Event throttling: coalesce rapidly repeated engine → editor events.
+//!
+//! An event type given a maximum rate is not sent when emitted. Instead the
+//! latest payload per entity (the payload's `entityId`, if any) is held and
+//! sent when flushed at the end of the frame, at most `max_hz` times a second
+//! per entity. Dragging a slider then sends one `MATERIAL_CHANGED` per
+//! interval instead of one per change. Errors and mode changes are never
+//! throttled.
+
+use std::collections::HashMap;
+
+/// Whether `event_type` must always be sent immediately.
+pub fn is_critical(event_type: &str) -> bool {
+    event_type.contains("ERROR") || event_type.contains("FAILED") || event_type.ends_with("MODE_CHANGED")
+}
+
+/// Check a rate limit before applying it with `EventThrottle::set_rate`.
+pub fn validate_rate(event_type: &str, max_hz: Option<f64>) -> Result<(), String> {
+    if is_critical(event_type) {
+        return Err(format!("{} is never throttled", event_type));
+    }
+    match max_hz {
+        Some(hz) if !(hz.is_finite() && hz > 0.0) => Err(format!("maxHz must be positive, got {}", hz)),
+        _ => Ok(()),
+    }
+}
+
+/// Per-type rate limits and the events they are holding back.
+#[derive(Debug, Default)]
+pub struct EventThrottle {
+    /// Maximum events per second, per entity, by event type.
+    rates: HashMap<String, f64>,
+    /// Latest held payload per (event type, entity), in first-held order.
+    pending: Vec<(String, String, serde_json::Value)>,
+    /// When each (event type, entity) was last sent, in seconds.
+    last_sent: HashMap<(String, String), f64>,
+}
+
+impl EventThrottle {
+    /// Limit `event_type` to `max_hz` events a second per entity, or remove
+    /// its limit with `None`. Held events of a type that is no longer limited
+    /// go out on the next flush.
+    pub fn set_rate(&mut self, event_type: &str, max_hz: Option<f64>) -> Result<(), String> {
+        validate_rate(event_type, max_hz)?;
+        match max_hz {
+            Some(hz) => self.rates.insert(event_type.to_string(), hz),
+            None => self.rates.remove(event_type),
+        };
+        Ok(())
+    }
+
+    pub fn is_throttled(&self, event_type: &str) -> bool {
+        self.rates.contains_key(event_type)
+    }
+
+    /// Hold `payload`, replacing any held event of the same type and entity.
+    pub fn hold(&mut self, event_type: &str, payload: serde_json::Value) {
+        let key = payload.get("entityId").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        match self.pending.iter_mut().find(|(t, k, _)| t == event_type && *k == key) {
+            Some(held) => held.2 = payload,
+            None => self.pending.push((event_type.to_string(), key, payload)),
+        }
+    }
+
+    /// Take the held events that may be sent at time `now` (seconds).
+    pub fn drain_due(&mut self, now: f64) -> Vec<(String, serde_json::Value)> {
+        let mut due = Vec::new();
+        let mut held = Vec::new();
+        for (event_type, key, payload) in self.pending.drain(..) {
+            let interval = self.rates.get(&event_type).map_or(0.0, |hz| 1.0 / hz);
+            let slot = (event_type, key);
+            let ready = self.last_sent.get(&slot).is_none_or(|&last| now - last >= interval);
+            if ready {
+                self.last_sent.insert(slot.clone(), now);
+                due.push((slot.0, payload));
+            } else {
+                held.push((slot.0, slot.1, payload));
+            }
+        }
+        self.pending = held;
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn held_events_are_sent_at_most_once_per_interval() {
+        let mut throttle = EventThrottle::default();
+        throttle.set_rate("TRANSFORM_CHANGED", Some(10.0)).unwrap();
+        assert!(throttle.set_rate("SCRIPT_ERROR", Some(10.0)).is_err());
+        assert!(throttle.set_rate("TRANSFORM_CHANGED", Some(0.0)).is_err());
+
+        throttle.hold("TRANSFORM_CHANGED", json!({ "entityId": "a", "x": 1 }));
+        throttle.hold("TRANSFORM_CHANGED", json!({ "entityId": "b", "x": 1 }));
+        assert_eq!(throttle.drain_due(0.0).len(), 2);
+
+        throttle.hold("TRANSFORM_CHANGED", json!({ "entityId": "a", "x": 2 }));
+        throttle.hold("TRANSFORM_CHANGED", json!({ "entityId": "a", "x": 3 }));
+        assert!(throttle.drain_due(0.05).is_empty(), "within 1/10 s of the last send");
+        assert_eq!(throttle.drain_due(0.1), vec![("TRANSFORM_CHANGED".to_string(), json!({ "entityId": "a", "x": 3 }))]);
+
+        throttle.hold("TRANSFORM_CHANGED", json!({ "entityId": "a", "x": 4 }));
+        throttle.set_rate("TRANSFORM_CHANGED", None).unwrap();
+        assert_eq!(throttle.drain_due(0.11).len(), 1, "unthrottled types flush right away");
+    }
+}
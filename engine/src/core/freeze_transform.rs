@@ -0,0 +1,138 @@
+//! Apply transform ("freeze"): bake parts of an entity's transform into its
+//! mesh vertices.
+//!
+//! Whatever the user keeps stays on the entity and the rest moves into the
+//! mesh, so the surface does not move in world space. Normals and tangents are
+//! re-derived through the inverse-transpose of the baked matrix, and triangle
+//! winding is flipped when the baked part mirrors the mesh.
+
+use bevy::prelude::*;
+use bevy::math::Affine3A;
+use bevy::mesh::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues};
+
+/// Which transform components to bake into the mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreezeComponents {
+    pub position: bool,
+    pub rotation: bool,
+    pub scale: bool,
+}
+
+impl FreezeComponents {
+    pub fn any(&self) -> bool {
+        self.position || self.rotation || self.scale
+    }
+}
+
+/// Split `transform` into the transform left on the entity (frozen components
+/// reset to identity) and the affine to bake into the mesh, such that
+/// `remaining * baked == transform`.
+pub fn split_transform(transform: &Transform, freeze: FreezeComponents) -> (Transform, Affine3A) {
+    let remaining = Transform {
+        translation: if freeze.position { Vec3::ZERO } else { transform.translation },
+        rotation: if freeze.rotation { Quat::IDENTITY } else { transform.rotation },
+        scale: if freeze.scale { Vec3::ONE } else { transform.scale },
+    };
+    let baked = remaining.compute_affine().inverse() * transform.compute_affine();
+    (remaining, baked)
+}
+
+/// Return a copy of `mesh` with `baked` applied to its positions, normals and
+/// tangents.
+pub fn bake_mesh(mesh: &Mesh, baked: Affine3A) -> Result<Mesh, String> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return Err("Only triangle-list meshes can have their transform applied".to_string());
+    }
+    let positions: Vec<[f32; 3]> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(v)) => v.iter()
+            .map(|p| baked.transform_point3(Vec3::from(*p)).to_array())
+            .collect(),
+        _ => return Err("Mesh has no positions".to_string()),
+    };
+    let linear = Mat3::from(baked.matrix3);
+    if linear.determinant().abs() < 1e-8 {
+        return Err("Cannot apply a transform with zero scale".to_string());
+    }
+    let normal_matrix = linear.inverse().transpose();
+    let mirrored = linear.determinant() < 0.0;
+
+    let mut result = mesh.clone();
+    result.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    if let Some(VertexAttributeValues::Float32x3(normals)) = mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        let normals: Vec<[f32; 3]> = normals.iter()
+            .map(|n| (normal_matrix * Vec3::from(*n)).normalize_or_zero().to_array())
+            .collect();
+        result.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    }
+    if let Some(VertexAttributeValues::Float32x4(tangents)) = mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+        let handedness = if mirrored { -1.0 } else { 1.0 };
+        let tangents: Vec<[f32; 4]> = tangents.iter()
+            .map(|t| (linear * Vec3::new(t[0], t[1], t[2])).normalize_or_zero().extend(t[3] * handedness).to_array())
+            .collect();
+        result.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+    }
+    if mirrored {
+        // A mirroring bake turns every face inside out; swap two corners back.
+        match result.indices_mut() {
+            Some(Indices::U32(indices)) => indices.chunks_exact_mut(3).for_each(|tri| tri.swap(1, 2)),
+            Some(Indices::U16(indices)) => indices.chunks_exact_mut(3).for_each(|tri| tri.swap(1, 2)),
+            None => return Err("Mesh has no indices".to_string()),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: FreezeComponents = FreezeComponents { position: true, rotation: true, scale: true };
+
+    fn positions(mesh: &Mesh) -> Vec<Vec3> {
+        let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            panic!("missing positions");
+        };
+        positions.iter().map(|p| Vec3::from(*p)).collect()
+    }
+
+    #[test]
+    fn freezing_scaled_cube_doubles_its_bounds() {
+        let cube = Mesh::from(Cuboid::new(1.0, 1.0, 1.0));
+        let transform = Transform::from_xyz(3.0, 0.0, 0.0).with_scale(Vec3::splat(2.0));
+        let (remaining, baked) = split_transform(&transform, FreezeComponents { position: false, rotation: false, scale: true });
+        let frozen = bake_mesh(&cube, baked).expect("cube should bake");
+
+        assert_eq!(remaining.scale, Vec3::ONE);
+        assert_eq!(remaining.translation, transform.translation);
+        let (lo, hi) = positions(&frozen).iter().fold((Vec3::MAX, Vec3::MIN), |(lo, hi), p| (lo.min(*p), hi.max(*p)));
+        assert!(lo.abs_diff_eq(Vec3::splat(-1.0), 1e-5), "min {:?}", lo);
+        assert!(hi.abs_diff_eq(Vec3::splat(1.0), 1e-5), "max {:?}", hi);
+    }
+
+    #[test]
+    fn freezing_keeps_world_positions_and_outward_normals() {
+        let cube = Mesh::from(Cuboid::new(1.0, 1.0, 1.0));
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0)
+            .with_rotation(Quat::from_rotation_y(0.7))
+            .with_scale(Vec3::new(2.0, -1.0, 0.5));
+        let (remaining, baked) = split_transform(&transform, ALL);
+        let frozen = bake_mesh(&cube, baked).expect("cube should bake");
+
+        assert_eq!(remaining, Transform::IDENTITY);
+        for (before, after) in positions(&cube).iter().zip(positions(&frozen)) {
+            assert!(transform.transform_point(*before).abs_diff_eq(after, 1e-4));
+        }
+
+        // Mirrored on Y: winding flips so geometric normals still face outward.
+        let center = transform.translation;
+        let points = positions(&frozen);
+        let Some(Indices::U32(indices)) = frozen.indices() else {
+            panic!("missing indices");
+        };
+        for tri in indices.chunks_exact(3) {
+            let [a, b, c] = [points[tri[0] as usize], points[tri[1] as usize], points[tri[2] as usize]];
+            let face_normal = (b - a).cross(c - a);
+            assert!(face_normal.dot((a + b + c) / 3.0 - center) > 0.0);
+        }
+    }
+}
@@ -7,7 +7,9 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use super::engine_mode::PlaySystemSet;
+use noise::{NoiseFn, Perlin};
+
+use super::engine_mode::{EngineMode, PlaySystemSet};
 use super::entity_id::EntityId;
 use super::pending_commands::PendingCommands;
 
@@ -25,13 +27,20 @@ pub struct GameCameraData {
     pub mode: GameCameraMode,
     /// EntityId of the entity to follow/look at (if applicable).
     pub target_entity: Option<String>,
-    /// Camera shake state (runtime-only, not serialized in EntitySnapshot).
-    #[serde(skip)]
-    pub shake_intensity: f32,
-    #[serde(skip)]
-    pub shake_duration: f32,
-    #[serde(skip)]
-    pub shake_timer: f32,
+    /// EntityId the camera tracks with smoothing and a deadzone. When set,
+    /// this replaces the mode's own positioning.
+    #[serde(default)]
+    pub follow_target_id: Option<String>,
+    /// Camera position relative to the followed target (world space).
+    #[serde(default = "default_follow_offset")]
+    pub follow_offset: Vec3,
+    /// Smoothing time constant in seconds (0 = snap to the target).
+    #[serde(default = "default_follow_smoothing")]
+    pub follow_smoothing: f32,
+    /// Half-extents of the box, around the camera's resting spot, inside
+    /// which target motion is ignored.
+    #[serde(default)]
+    pub deadzone: Vec3,
 }
 
 impl Default for GameCameraData {
@@ -46,13 +55,35 @@ impl Default for GameCameraData {
                 collision_avoidance: true,
             },
             target_entity: None,
-            shake_intensity: 0.0,
-            shake_duration: 0.0,
-            shake_timer: 0.0,
+            follow_target_id: None,
+            follow_offset: default_follow_offset(),
+            follow_smoothing: default_follow_smoothing(),
+            deadzone: Vec3::ZERO,
         }
     }
 }
 
+fn default_follow_offset() -> Vec3 {
+    Vec3::new(0.0, 3.0, 10.0)
+}
+
+fn default_follow_smoothing() -> f32 {
+    0.15
+}
+
+/// One step of follow-camera motion: chase `target + offset`, ignoring the
+/// part of the gap that lies within the deadzone, with exponential smoothing.
+pub fn follow_step(current: Vec3, target: Vec3, offset: Vec3, smoothing: f32, deadzone: Vec3, delta: f32) -> Vec3 {
+    let error = target + offset - current;
+    // Only the part of the gap outside the deadzone is chased, so the camera
+    // settles with the target at the deadzone's edge.
+    let excess = error.signum() * (error.abs() - deadzone.max(Vec3::ZERO)).max(Vec3::ZERO);
+    if smoothing <= 0.0 {
+        return current + excess;
+    }
+    current + excess * (1.0 - (-delta / smoothing).exp())
+}
+
 #[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
 pub enum GameCameraMode {
     /// Third-person follow camera with collision avoidance.
@@ -142,10 +173,14 @@ impl Plugin for GameCameraPlugin {
         app.register_type::<GameCameraData>()
             .register_type::<ActiveGameCamera>()
             .register_type::<GameCameraMode>()
+            .init_resource::<CameraShakeState>()
             .add_systems(Update, (
+                remove_camera_shake_offset,
                 first_person_mouse_look,
                 game_camera_system,
+                apply_camera_shake,
             ).chain().in_set(PlaySystemSet))
+            .add_systems(Update, reset_camera_shake)
             .add_systems(Update, update_orbital_angle.in_set(PlaySystemSet));
     }
 }
@@ -189,30 +224,22 @@ fn first_person_mouse_look(
 }
 
 /// Main game camera system — runs during Play mode only.
-fn game_camera_system(
+pub fn game_camera_system(
     time: Res<Time>,
     mut camera_query: Query<(
         &mut Transform,
-        &mut GameCameraData,
+        &GameCameraData,
         Option<&FirstPersonState>,
         Option<&OrbitalState>,
     ), With<ActiveGameCamera>>,
     target_query: Query<(&EntityId, &Transform), Without<ActiveGameCamera>>,
 ) {
-    let Ok((mut camera_transform, mut camera_data, first_person_state, orbital_state)) = camera_query.single_mut() else {
+    let Ok((mut camera_transform, camera_data, first_person_state, orbital_state)) = camera_query.single_mut() else {
         return; // No active game camera
     };
 
     let delta = time.delta_secs();
 
-    // Update shake timer
-    if camera_data.shake_timer > 0.0 {
-        camera_data.shake_timer -= delta;
-        if camera_data.shake_timer <= 0.0 {
-            camera_data.shake_intensity = 0.0;
-        }
-    }
-
     // Get target entity transform
     let target_transform = if let Some(target_id) = &camera_data.target_entity {
         target_query.iter()
@@ -222,93 +249,190 @@ fn game_camera_system(
         None
     };
 
-    // Clone mode to avoid borrow conflict
-    let mode = camera_data.mode.clone();
-
-    // Apply mode-specific logic
-    match &mode {
-        GameCameraMode::ThirdPersonFollow { offset, damping, min_distance, look_at_target, collision_avoidance, .. } => {
-            if let Some(target_t) = target_transform {
-                update_third_person(
-                    &mut camera_transform,
-                    &target_t,
-                    *offset,
-                    *damping,
-                    *min_distance,
-                    *look_at_target,
-                    *collision_avoidance,
-                    delta,
-                );
-            }
+    // Follow mode replaces the mode's positioning
+    if let Some(follow_id) = &camera_data.follow_target_id {
+        if let Some((_, target_t)) = target_query.iter().find(|(id, _)| id.0 == *follow_id) {
+            camera_transform.translation = follow_step(
+                camera_transform.translation,
+                target_t.translation,
+                camera_data.follow_offset,
+                camera_data.follow_smoothing,
+                camera_data.deadzone,
+                delta,
+            );
         }
-        GameCameraMode::FirstPerson { eye_height, .. } => {
-            if let Some(target_t) = target_transform {
-                if let Some(fp_state) = first_person_state {
-                    update_first_person(
+    } else {
+        // Clone mode to avoid borrow conflict
+        let mode = camera_data.mode.clone();
+
+        // Apply mode-specific logic
+        match &mode {
+            GameCameraMode::ThirdPersonFollow { offset, damping, min_distance, look_at_target, collision_avoidance, .. } => {
+                if let Some(target_t) = target_transform {
+                    update_third_person(
                         &mut camera_transform,
                         &target_t,
-                        *eye_height,
-                        fp_state.yaw,
-                        fp_state.pitch,
+                        *offset,
+                        *damping,
+                        *min_distance,
+                        *look_at_target,
+                        *collision_avoidance,
+                        delta,
                     );
                 }
             }
-        }
-        GameCameraMode::SideScroller { z_offset, follow_y, y_bounds, damping } => {
-            if let Some(target_t) = target_transform {
-                update_side_scroller(
-                    &mut camera_transform,
-                    &target_t,
-                    *z_offset,
-                    *follow_y,
-                    *y_bounds,
-                    *damping,
-                    delta,
-                );
-            }
-        }
-        GameCameraMode::TopDown { height, damping, follow_rotation } => {
-            if let Some(target_t) = target_transform {
-                update_top_down(
-                    &mut camera_transform,
-                    &target_t,
-                    *height,
-                    *damping,
-                    *follow_rotation,
-                    delta,
-                );
+            GameCameraMode::FirstPerson { eye_height, .. } => {
+                if let Some(target_t) = target_transform {
+                    if let Some(fp_state) = first_person_state {
+                        update_first_person(
+                            &mut camera_transform,
+                            &target_t,
+                            *eye_height,
+                            fp_state.yaw,
+                            fp_state.pitch,
+                        );
+                    }
+                }
             }
-        }
-        GameCameraMode::Fixed { look_at } => {
-            if let Some(look_at_pos) = look_at {
-                camera_transform.look_at(*look_at_pos, Vec3::Y);
+            GameCameraMode::SideScroller { z_offset, follow_y, y_bounds, damping } => {
+                if let Some(target_t) = target_transform {
+                    update_side_scroller(
+                        &mut camera_transform,
+                        &target_t,
+                        *z_offset,
+                        *follow_y,
+                        *y_bounds,
+                        *damping,
+                        delta,
+                    );
+                }
             }
-        }
-        GameCameraMode::Orbital { radius, .. } => {
-            if let Some(target_t) = target_transform {
-                if let Some(orbital_state) = orbital_state {
-                    update_orbital(
+            GameCameraMode::TopDown { height, damping, follow_rotation } => {
+                if let Some(target_t) = target_transform {
+                    update_top_down(
                         &mut camera_transform,
                         &target_t,
-                        *radius,
-                        orbital_state.angle,
+                        *height,
+                        *damping,
+                        *follow_rotation,
+                        delta,
                     );
                 }
             }
+            GameCameraMode::Fixed { look_at } => {
+                if let Some(look_at_pos) = look_at {
+                    camera_transform.look_at(*look_at_pos, Vec3::Y);
+                }
+            }
+            GameCameraMode::Orbital { radius, .. } => {
+                if let Some(target_t) = target_transform {
+                    if let Some(orbital_state) = orbital_state {
+                        update_orbital(
+                            &mut camera_transform,
+                            &target_t,
+                            *radius,
+                            orbital_state.angle,
+                        );
+                    }
+                }
+            }
         }
     }
+}
+
+/// Trauma-based shake of the active game camera.
+///
+/// Each `camera_shake` request stacks onto the current shake: amplitudes add
+/// up and trauma resets to 1, then decays linearly so it reaches zero when the
+/// longest pending shake ends. The offset is `amplitude * trauma²` scaled
+/// Perlin noise, added after the camera modes run and taken off again before
+/// they run next frame, so the camera comes back to exactly where it was.
+#[derive(Resource, Debug, Clone)]
+pub struct CameraShakeState {
+    /// Shake strength in `[0, 1]`.
+    pub trauma: f32,
+    /// Trauma lost per second.
+    pub decay: f32,
+    /// Noise samples per second; higher shakes faster.
+    pub frequency: f32,
+    /// Maximum offset at full trauma, in world units.
+    pub amplitude: f32,
+    /// Noise time, advanced while shaking.
+    pub time: f32,
+    /// Camera currently offset: (entity, base translation, shaken translation).
+    applied: Option<(Entity, Vec3, Vec3)>,
+}
 
-    // Apply camera shake (additive offset)
-    if camera_data.shake_intensity > 0.0 {
-        let elapsed = time.elapsed_secs();
-        let decay = camera_data.shake_timer / camera_data.shake_duration.max(0.001);
-        let intensity = camera_data.shake_intensity * decay;
-        let shake_offset = Vec3::new(
-            (elapsed * 20.0).sin() * intensity,
-            (elapsed * 25.0).cos() * intensity,
-            (elapsed * 30.0).sin() * intensity * 0.5,
-        );
-        camera_transform.translation += shake_offset;
+impl Default for CameraShakeState {
+    fn default() -> Self {
+        Self { trauma: 0.0, decay: 0.0, frequency: 15.0, amplitude: 0.0, time: 0.0, applied: None }
+    }
+}
+
+impl CameraShakeState {
+    /// Stack a shake of `intensity` world units lasting `duration` seconds.
+    pub fn add(&mut self, intensity: f32, duration: f32) {
+        let remaining = if self.decay > 0.0 { self.trauma / self.decay } else { 0.0 };
+        self.amplitude = self.amplitude * self.trauma + intensity.max(0.0);
+        self.trauma = 1.0;
+        self.decay = 1.0 / remaining.max(duration).max(0.001);
+    }
+
+    /// Offset for the current trauma and noise time.
+    fn offset(&self, noise: &Perlin) -> Vec3 {
+        let t = f64::from(self.time * self.frequency);
+        // Separate rows of the 2D noise field give three uncorrelated axes.
+        let sample = |row: f64| noise.get([t, row]) as f32;
+        Vec3::new(sample(0.5), sample(7.5), sample(13.5)) * self.amplitude * self.trauma * self.trauma
+    }
+}
+
+/// Take last frame's shake offset back off the camera, before camera modes run.
+fn remove_camera_shake_offset(
+    mut shake: ResMut<CameraShakeState>,
+    mut cameras: Query<&mut Transform>,
+) {
+    let Some((entity, base, shaken)) = shake.applied.take() else { return; };
+    if let Ok(mut transform) = cameras.get_mut(entity) {
+        // Restore exactly unless something else moved the camera meanwhile.
+        if transform.translation == shaken {
+            transform.translation = base;
+        } else {
+            transform.translation -= shaken - base;
+        }
+    }
+}
+
+/// Decay trauma and offset the active game camera by this frame's shake.
+pub fn apply_camera_shake(
+    time: Res<Time>,
+    mut shake: ResMut<CameraShakeState>,
+    mut cameras: Query<(Entity, &mut Transform), With<ActiveGameCamera>>,
+    noise: Local<Perlin>,
+) {
+    if shake.trauma <= 0.0 {
+        return;
+    }
+    let delta = time.delta_secs();
+    shake.time += delta;
+    shake.trauma = (shake.trauma - shake.decay * delta).max(0.0);
+    if shake.trauma <= 0.0 {
+        shake.amplitude = 0.0;
+        shake.decay = 0.0;
+        return;
+    }
+
+    let Ok((entity, mut transform)) = cameras.single_mut() else { return; };
+    let base = transform.translation;
+    transform.translation += shake.offset(&noise);
+    shake.applied = Some((entity, base, transform.translation));
+}
+
+/// Drop any shake when Play mode ends; the camera itself is restored from the
+/// Edit-mode snapshot.
+fn reset_camera_shake(mode: Res<EngineMode>, mut shake: ResMut<CameraShakeState>) {
+    if !mode.is_playing() && (shake.trauma > 0.0 || shake.applied.is_some()) {
+        *shake = CameraShakeState { frequency: shake.frequency, ..Default::default() };
     }
 }
 
@@ -436,3 +560,76 @@ fn update_orbital(
     camera_transform.translation = target_transform.translation + offset;
     camera_transform.look_at(target_transform.translation, Vec3::Y);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follow_ignores_small_moves_inside_deadzone_and_catches_up_on_large_ones() {
+        let offset = Vec3::new(0.0, 2.0, 8.0);
+        let deadzone = Vec3::new(1.0, 0.5, 1.0);
+        let start = offset;
+        let step = |current: Vec3, target: Vec3| follow_step(current, target, offset, 0.1, deadzone, 1.0 / 60.0);
+
+        // Small nudge stays inside the deadzone: the camera doesn't move.
+        let mut camera = start;
+        for _ in 0..60 {
+            camera = step(camera, Vec3::new(0.6, 0.2, 0.0));
+        }
+        assert_eq!(camera, start);
+
+        // Large move: the camera catches up until the target sits at the deadzone edge.
+        for _ in 0..120 {
+            camera = step(camera, Vec3::new(10.0, 0.0, 0.0));
+        }
+        assert!(camera.abs_diff_eq(Vec3::new(9.0, 2.0, 8.0), 1e-3), "camera at {:?}", camera);
+    }
+
+    #[test]
+    fn follow_without_smoothing_snaps() {
+        let camera = follow_step(Vec3::ZERO, Vec3::new(3.0, 0.0, 0.0), Vec3::Y, 0.0, Vec3::ZERO, 1.0 / 60.0);
+        assert_eq!(camera, Vec3::new(3.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn shake_decays_and_returns_camera_to_its_base() {
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(50)))
+            .init_resource::<CameraShakeState>()
+            .add_systems(Update, (remove_camera_shake_offset, apply_camera_shake).chain());
+        let base = Vec3::new(1.5, 4.0, -7.25);
+        let camera = app.world_mut().spawn((ActiveGameCamera, Transform::from_translation(base))).id();
+        app.world_mut().resource_mut::<CameraShakeState>().add(0.5, 0.5);
+
+        let mut moved = false;
+        for _ in 0..8 {
+            app.update();
+            moved |= app.world().get::<Transform>(camera).unwrap().translation != base;
+        }
+        assert!(moved, "camera never shook");
+
+        // 0.5s plus a few spare frames: trauma is spent and the camera is back.
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<CameraShakeState>().trauma, 0.0);
+        assert_eq!(app.world().get::<Transform>(camera).unwrap().translation, base);
+    }
+
+    #[test]
+    fn shakes_stack() {
+        let mut shake = CameraShakeState::default();
+        shake.add(0.3, 2.0);
+        shake.trauma = 0.5;
+        shake.add(0.2, 0.5);
+        assert_eq!(shake.trauma, 1.0);
+        assert!((shake.amplitude - 0.35).abs() < 1e-6);
+        // The earlier shake still has 1s left, longer than the new one.
+        assert!((shake.decay - 1.0).abs() < 1e-6);
+    }
+}
@@ -5,11 +5,15 @@
 //! to the script sandbox via `forge.components.*`.
 
 use bevy::prelude::*;
-use bevy_rapier3d::prelude::CollisionEvent;
+use bevy_rapier3d::prelude::{
+    CharacterAutostep, CharacterLength, CollisionEvent, DefaultRapierContext,
+    KinematicCharacterController, KinematicCharacterControllerOutput, RapierContextEntityLink,
+};
 use serde::{Deserialize, Serialize};
 
 use super::engine_mode::RuntimeEntity;
 use super::entity_id::EntityId;
+use super::pending_commands::TimerAction;
 
 /// A single pre-built game behavior.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -28,6 +32,8 @@ pub enum GameComponentData {
     Projectile(ProjectileData),
     WinCondition(WinConditionData),
     DialogueTrigger(DialogueTriggerData),
+    PathFollower(PathFollowerData),
+    Timer(TimerData),
 }
 
 impl GameComponentData {
@@ -47,6 +53,8 @@ impl GameComponentData {
             Self::Projectile(_) => "projectile",
             Self::WinCondition(_) => "win_condition",
             Self::DialogueTrigger(_) => "dialogue_trigger",
+            Self::PathFollower(_) => "path_follower",
+            Self::Timer(_) => "timer",
         }
     }
 }
@@ -60,11 +68,34 @@ pub struct CharacterControllerData {
     pub jump_height: f32,       // impulse magnitude, default 8.0
     pub gravity_scale: f32,     // multiplier, default 1.0
     pub can_double_jump: bool,  // default false
+    #[serde(default = "default_step_height")]
+    pub step_height: f32,       // max obstacle height climbed automatically, default 0.3
 }
 
+fn default_step_height() -> f32 { 0.3 }
+
 impl Default for CharacterControllerData {
     fn default() -> Self {
-        Self { speed: 5.0, jump_height: 8.0, gravity_scale: 1.0, can_double_jump: false }
+        Self { speed: 5.0, jump_height: 8.0, gravity_scale: 1.0, can_double_jump: false, step_height: 0.3 }
+    }
+}
+
+impl CharacterControllerData {
+    /// Build the Rapier kinematic controller used during Play mode.
+    /// The character shape is a capsule sized from the entity's scale.
+    pub fn kinematic_controller(&self, scale: Vec3) -> KinematicCharacterController {
+        let shape = super::physics::make_collider(&super::physics::ColliderShape::Capsule, scale);
+        KinematicCharacterController {
+            custom_shape: Some((shape, Vec3::ZERO, Quat::IDENTITY)),
+            offset: CharacterLength::Absolute(0.01),
+            autostep: (self.step_height > 0.0).then_some(CharacterAutostep {
+                max_height: CharacterLength::Absolute(self.step_height),
+                min_width: CharacterLength::Absolute(0.1),
+                include_dynamic_bodies: false,
+            }),
+            snap_to_ground: Some(CharacterLength::Absolute(0.2)),
+            ..Default::default()
+        }
     }
 }
 
@@ -227,6 +258,78 @@ impl Default for FollowerData {
     }
 }
 
+/// Moves the entity along a `Path` entity's spline.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PathFollowerData {
+    pub path_entity_id: Option<String>, // EntityId of the Path, default None
+    pub speed: f32,             // units/sec along the curve, default 2.0
+    pub loop_mode: PlatformLoopMode, // default Loop
+    pub t: f32,                 // starting position on the path (0..1), default 0.0
+    pub orient_to_path: bool,   // face along the tangent, default true
+}
+
+impl Default for PathFollowerData {
+    fn default() -> Self {
+        Self { path_entity_id: None, speed: 2.0, loop_mode: PlatformLoopMode::Loop, t: 0.0, orient_to_path: true }
+    }
+}
+
+/// Advance a path parameter by `delta` (already scaled by the follow
+/// direction), wrapping, bouncing or stopping at the ends per `loop_mode`.
+/// Returns the new parameter and direction.
+pub fn advance_path_parameter(t: f32, direction: f32, delta: f32, loop_mode: &PlatformLoopMode) -> (f32, f32) {
+    let next = t + delta * direction;
+    match loop_mode {
+        PlatformLoopMode::Loop => (next.rem_euclid(1.0), direction),
+        PlatformLoopMode::PingPong if next > 1.0 => ((2.0 - next).max(0.0), -1.0),
+        PlatformLoopMode::PingPong if next < 0.0 => ((-next).min(1.0), 1.0),
+        PlatformLoopMode::PingPong => (next, direction),
+        PlatformLoopMode::Once => (next.clamp(0.0, 1.0), direction),
+    }
+}
+
+/// Counts down in Play mode and fires a named event when done, for cooldowns
+/// and spawn waves driven by scripts.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TimerData {
+    pub duration: f32,          // seconds, default 1.0
+    pub elapsed: f32,           // runtime state, default 0.0
+    pub repeating: bool,        // restart on completion, default false
+    pub fire_event: String,     // event name sent with TIMER_FIRED, default "timer"
+    pub running: bool,          // counting when Play starts, default true
+}
+
+impl Default for TimerData {
+    fn default() -> Self {
+        Self { duration: 1.0, elapsed: 0.0, repeating: false, fire_event: "timer".to_string(), running: true }
+    }
+}
+
+impl TimerData {
+    /// Advance a running timer by `dt` and return how many times it fired.
+    /// A one-shot timer stops at its duration; a repeating one carries the
+    /// overshoot into the next cycle.
+    pub fn advance(&mut self, dt: f32) -> u32 {
+        if !self.running {
+            return 0;
+        }
+        self.elapsed += dt;
+        let mut fired = 0;
+        while self.running && self.elapsed >= self.duration {
+            fired += 1;
+            if self.repeating && self.duration > 0.0 {
+                self.elapsed -= self.duration;
+            } else {
+                self.elapsed = self.duration;
+                self.running = false;
+            }
+        }
+        fired
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectileData {
@@ -328,6 +431,39 @@ impl GameComponents {
     }
 }
 
+/// Keeps an entity turned toward another entity during Play mode
+/// (turrets, cameras, billboards). The entity's -Z axis faces the target.
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LookAtConstraint {
+    pub target_entity_id: String,
+    /// World-space up hint used to keep the entity level, default +Y.
+    #[serde(default = "default_look_at_up_axis")]
+    pub up_axis: [f32; 3],
+    /// Fraction of the remaining turn applied each frame (1.0 = snap).
+    #[serde(default = "default_look_at_weight")]
+    pub weight: f32,
+}
+
+fn default_look_at_up_axis() -> [f32; 3] {
+    [0.0, 1.0, 0.0]
+}
+
+fn default_look_at_weight() -> f32 {
+    1.0
+}
+
+impl LookAtConstraint {
+    /// World rotation that faces `target` from `source`, blended from
+    /// `current` by the weight. Returns `None` when the two points coincide.
+    pub fn rotation_toward(&self, source: Vec3, target: Vec3, current: Quat) -> Option<Quat> {
+        let direction = Dir3::new(target - source).ok()?;
+        let up = Dir3::new(Vec3::from(self.up_axis)).unwrap_or(Dir3::Y);
+        let desired = Transform::default().looking_to(direction, up).rotation;
+        Some(current.slerp(desired, self.weight.clamp(0.0, 1.0)))
+    }
+}
+
 /// Build a GameComponentData from a type name and JSON properties string.
 /// If properties_json is empty, uses defaults.
 pub fn build_game_component(component_type: &str, properties_json: &str) -> Result<GameComponentData, String> {
@@ -346,6 +482,8 @@ pub fn build_game_component(component_type: &str, properties_json: &str) -> Resu
             "projectile" => GameComponentData::Projectile(ProjectileData::default()),
             "win_condition" => GameComponentData::WinCondition(WinConditionData::default()),
             "dialogue_trigger" => GameComponentData::DialogueTrigger(DialogueTriggerData::default()),
+            "path_follower" => GameComponentData::PathFollower(PathFollowerData::default()),
+            "timer" => GameComponentData::Timer(TimerData::default()),
             other => return Err(format!("Unknown game component type: {}", other)),
         });
     }
@@ -390,6 +528,12 @@ pub fn build_game_component(component_type: &str, properties_json: &str) -> Resu
         "dialogue_trigger" => serde_json::from_str::<DialogueTriggerData>(properties_json)
             .map(GameComponentData::DialogueTrigger)
             .map_err(|e| format!("Invalid dialogue_trigger properties: {}", e)),
+        "path_follower" => serde_json::from_str::<PathFollowerData>(properties_json)
+            .map(GameComponentData::PathFollower)
+            .map_err(|e| format!("Invalid path_follower properties: {}", e)),
+        "timer" => serde_json::from_str::<TimerData>(properties_json)
+            .map(GameComponentData::Timer)
+            .map_err(|e| format!("Invalid timer properties: {}", e)),
         other => Err(format!("Unknown game component type: {}", other)),
     }
 }
@@ -413,12 +557,24 @@ pub struct GameComponentRuntime {
     pub spawner_states: std::collections::HashMap<String, SpawnerState>,
     /// Projectile state: entity_id -> (direction, remaining_lifetime)
     pub projectile_states: std::collections::HashMap<String, ProjectileState>,
+    /// Path follower state: entity_id -> (t, direction)
+    pub path_follower_states: std::collections::HashMap<String, PathFollowerState>,
     /// Trigger fired flags (for one_shot triggers): entity_id -> bool
     pub trigger_fired: std::collections::HashMap<String, bool>,
     /// Double jump tracking: entity_id -> jumps_remaining
     pub double_jump_states: std::collections::HashMap<String, u32>,
+    /// Character vertical velocity (gravity + jumps): entity_id -> m/s
+    pub character_vertical_velocities: std::collections::HashMap<String, f32>,
     /// Named game events emitted this frame (consumed by scripts)
     pub pending_events: Vec<GameEvent>,
+    /// Last entity to damage each entity via `apply_damage`: target_id -> source_id
+    pub damage_sources: std::collections::HashMap<String, String>,
+    /// Entities at zero HP whose death was already reported
+    pub dead_entities: std::collections::HashSet<String>,
+    /// Deaths not yet emitted to the editor (drained by the bridge)
+    pub deaths: Vec<EntityDeath>,
+    /// Timer completions not yet emitted: (entity_id, fire_event)
+    pub fired_timers: Vec<(String, String)>,
     /// Active collision pairs tracked per frame: (entity_a_id, entity_b_id)
     /// Used for DamageZone continuous damage and TriggerZone enter/exit detection
     pub active_collisions: std::collections::HashSet<(String, String)>,
@@ -440,12 +596,25 @@ pub struct SpawnerState {
     pub spawned_ids: Vec<String>,
 }
 
+#[derive(Clone, Debug)]
+pub struct PathFollowerState {
+    pub t: f32,
+    pub direction: f32,     // +1 or -1 for ping-pong
+}
+
 #[derive(Clone, Debug)]
 pub struct ProjectileState {
     pub direction: [f32; 3],
     pub remaining_lifetime: f32,
 }
 
+/// An entity whose health reached zero.
+#[derive(Clone, Debug)]
+pub struct EntityDeath {
+    pub entity_id: String,
+    pub source_entity_id: Option<String>,
+}
+
 /// A named game event for script communication.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -469,6 +638,11 @@ impl Plugin for GameComponentsPlugin {
             cleanup_game_component_runtime,
         ));
 
+        // Script-driven character moves are drained every frame (discarded outside Play)
+        app.add_systems(Update, apply_move_character_requests);
+        app.add_systems(Update, apply_damage_requests.before(system_health));
+        app.add_systems(Update, apply_timer_requests.before(system_timer));
+
         // Collision tracking must run first so game component systems see fresh data
         app.add_systems(Update, system_track_collisions.in_set(PlaySystemSet));
 
@@ -497,7 +671,17 @@ impl Plugin for GameComponentsPlugin {
 
         app.add_systems(Update, (
             system_dialogue_trigger,
+            system_path_follower,
+            system_timer,
         ).after(system_track_collisions).in_set(PlaySystemSet));
+
+        // Look-at runs after everything that moves entities so it aims at this frame's positions
+        app.add_systems(Update, system_look_at_constraint
+            .after(system_moving_platform)
+            .after(system_follower)
+            .after(system_projectile)
+            .after(system_path_follower)
+            .in_set(PlaySystemSet));
     }
 }
 
@@ -508,7 +692,8 @@ fn init_game_component_runtime(
     mut commands: Commands,
     mode: Res<super::engine_mode::EngineMode>,
     mut was_playing: Local<bool>,
-    entities: Query<(&EntityId, &GameComponents, &Transform)>,
+    entities: Query<(Entity, &EntityId, &GameComponents, &Transform)>,
+    rapier_context: Query<Entity, With<DefaultRapierContext>>,
 ) {
 
 
@@ -517,9 +702,18 @@ fn init_game_component_runtime(
         let mut runtime = GameComponentRuntime::default();
 
         // Count collectibles and init states
-        for (eid, gc, transform) in entities.iter() {
+        for (entity, eid, gc, transform) in entities.iter() {
             for comp in &gc.components {
                 match comp {
+                    GameComponentData::CharacterController(data) => {
+                        // The controller uses a custom shape, so Rapier won't link the
+                        // entity to a context on its own (it would for a collider).
+                        let mut ec = commands.entity(entity);
+                        ec.insert(data.kinematic_controller(transform.scale));
+                        if let Ok(context) = rapier_context.single() {
+                            ec.insert(RapierContextEntityLink(context));
+                        }
+                    }
                     GameComponentData::Collectible(_) => {
                         runtime.total_collectibles += 1;
                     }
@@ -532,6 +726,12 @@ fn init_game_component_runtime(
                             origin: [pos.x, pos.y, pos.z],
                         });
                     }
+                    GameComponentData::PathFollower(data) => {
+                        runtime.path_follower_states.insert(eid.0.clone(), PathFollowerState {
+                            t: data.t.clamp(0.0, 1.0),
+                            direction: 1.0,
+                        });
+                    }
                     GameComponentData::Spawner(_) => {
                         runtime.spawner_states.insert(eid.0.clone(), SpawnerState {
                             timer: 0.0,
@@ -554,32 +754,45 @@ fn cleanup_game_component_runtime(
     mode: Res<super::engine_mode::EngineMode>,
     mut was_playing: Local<bool>,
     runtime: Option<Res<GameComponentRuntime>>,
+    controllers: Query<Entity, With<KinematicCharacterController>>,
 ) {
     let is_playing = mode.is_playing();
     if !is_playing && *was_playing {
         if runtime.is_some() {
             commands.remove_resource::<GameComponentRuntime>();
         }
+        for entity in controllers.iter() {
+            commands.entity(entity)
+                .remove::<KinematicCharacterController>()
+                .remove::<KinematicCharacterControllerOutput>();
+        }
     }
     *was_playing = is_playing;
 }
 
 // ---- Game Component Systems ----
 
-/// Character controller: apply WASD movement and jump
+/// Character controller: turn WASD input, jumps, and gravity into a desired
+/// translation for the Rapier kinematic controller, which resolves it with
+/// collide-and-slide and writes back the transform.
 fn system_character_controller(
     time: Res<Time>,
     input: Option<Res<super::input::InputState>>,
-    runtime: Option<Res<GameComponentRuntime>>,
-    mut entities: Query<(&EntityId, &GameComponents, &mut Transform)>,
+    runtime: Option<ResMut<GameComponentRuntime>>,
+    mut entities: Query<(
+        &EntityId,
+        &GameComponents,
+        &mut KinematicCharacterController,
+        Option<&KinematicCharacterControllerOutput>,
+    )>,
 ) {
 
 
     let Some(input) = input else { return; };
-    let Some(_runtime) = runtime else { return; };
+    let Some(mut runtime) = runtime else { return; };
     let dt = time.delta_secs();
 
-    for (_eid, gc, mut transform) in entities.iter_mut() {
+    for (eid, gc, mut controller, output) in entities.iter_mut() {
         for comp in &gc.components {
             if let GameComponentData::CharacterController(data) = comp {
                 // Movement
@@ -618,21 +831,146 @@ fn system_character_controller(
 
                 if movement.length_squared() > 0.0 {
                     movement = movement.normalize() * data.speed * dt;
-                    transform.translation += movement;
                 }
 
-                // Jump
+                // Vertical: gravity, landing, and (double) jumps
+                let grounded = output.is_some_and(|o| o.grounded);
+                let mut velocity_y = runtime.character_vertical_velocities.get(&eid.0).copied().unwrap_or(0.0);
+                if grounded {
+                    velocity_y = velocity_y.max(0.0);
+                    runtime.double_jump_states.insert(eid.0.clone(), u32::from(data.can_double_jump));
+                }
                 if input.is_action_just_pressed("jump") {
-                    // Simple jump: apply instant upward movement
-                    transform.translation.y += data.jump_height * 0.5 * dt;
+                    let jumps_left = runtime.double_jump_states.get(&eid.0).copied().unwrap_or(0);
+                    if grounded {
+                        velocity_y = data.jump_height;
+                    } else if jumps_left > 0 {
+                        velocity_y = data.jump_height;
+                        runtime.double_jump_states.insert(eid.0.clone(), jumps_left - 1);
+                    }
                 }
+                velocity_y -= 9.81 * data.gravity_scale * dt;
+                runtime.character_vertical_velocities.insert(eid.0.clone(), velocity_y);
+                movement.y = velocity_y * dt;
+
+                // Accumulate with any script-driven move queued this frame
+                controller.translation = Some(controller.translation.unwrap_or(Vec3::ZERO) + movement);
             }
         }
     }
 }
 
+/// Apply `move_character` requests from scripts. The direction is scaled by the
+/// controller's speed and accumulated into the Rapier controller's translation,
+/// so walls stop the character instead of being teleported through.
+fn apply_move_character_requests(
+    mode: Res<super::engine_mode::EngineMode>,
+    mut pending: ResMut<super::pending_commands::PendingCommands>,
+    mut characters: Query<(&EntityId, &GameComponents, &mut KinematicCharacterController)>,
+) {
+    if !mode.is_playing() {
+        pending.move_character_requests.clear();
+        return;
+    }
+
+    for request in pending.move_character_requests.drain(..) {
+        let Some((_, gc, mut controller)) = characters.iter_mut().find(|(eid, _, _)| eid.0 == request.entity_id) else {
+            continue;
+        };
+        let Some(GameComponentData::CharacterController(data)) = gc.get("character_controller") else {
+            continue;
+        };
+        let direction = Vec3::from(request.direction).clamp_length_max(1.0);
+        let movement = direction * data.speed * request.dt;
+        controller.translation = Some(controller.translation.unwrap_or(Vec3::ZERO) + movement);
+    }
+}
+
+/// Apply `apply_damage` requests to `health` components (discarded outside Play).
+/// HP is clamped to `[0, max_hp]`, so negative damage heals; deaths are
+/// reported by `system_health`.
+fn apply_damage_requests(
+    mode: Res<super::engine_mode::EngineMode>,
+    mut pending: ResMut<super::pending_commands::PendingCommands>,
+    runtime: Option<ResMut<GameComponentRuntime>>,
+    mut entities: Query<(&EntityId, &mut GameComponents)>,
+) {
+    let (true, Some(mut runtime)) = (mode.is_playing(), runtime) else {
+        pending.apply_damage_requests.clear();
+        return;
+    };
+
+    for request in pending.apply_damage_requests.drain(..) {
+        let Some((_, mut gc)) = entities.iter_mut().find(|(eid, _)| eid.0 == request.entity_id) else {
+            continue;
+        };
+        let Some(GameComponentData::Health(health)) = gc.get_mut("health") else {
+            continue;
+        };
+        // No hits or heals on the dead until they respawn
+        if health.current_hp <= 0.0 {
+            continue;
+        }
+        health.current_hp = (health.current_hp - request.amount).clamp(0.0, health.max_hp);
+        if let Some(source) = request.source {
+            runtime.damage_sources.insert(request.entity_id, source);
+        }
+    }
+}
+
+/// Apply `start_timer` / `stop_timer` / `reset_timer` requests (discarded outside Play).
+fn apply_timer_requests(
+    mode: Res<super::engine_mode::EngineMode>,
+    mut pending: ResMut<super::pending_commands::PendingCommands>,
+    mut entities: Query<(&EntityId, &mut GameComponents)>,
+) {
+    if !mode.is_playing() {
+        pending.timer_requests.clear();
+        return;
+    }
+
+    for request in pending.timer_requests.drain(..) {
+        let Some((_, mut gc)) = entities.iter_mut().find(|(eid, _)| eid.0 == request.entity_id) else {
+            continue;
+        };
+        let Some(GameComponentData::Timer(timer)) = gc.get_mut("timer") else {
+            continue;
+        };
+        match request.action {
+            TimerAction::Start => timer.running = true,
+            TimerAction::Stop => timer.running = false,
+            TimerAction::Reset => timer.elapsed = 0.0,
+        }
+    }
+}
+
+/// Timer system: advance running timers and record completions. Paused Play
+/// mode doesn't run this system, so timers pause with the game.
+pub(crate) fn system_timer(
+    time: Res<Time>,
+    runtime: Option<ResMut<GameComponentRuntime>>,
+    mut entities: Query<(&EntityId, &mut GameComponents)>,
+) {
+    let Some(mut runtime) = runtime else { return; };
+    let dt = time.delta_secs();
+
+    for (eid, mut gc) in entities.iter_mut() {
+        let Some(GameComponentData::Timer(timer)) = gc.get_mut("timer") else {
+            continue;
+        };
+        for _ in 0..timer.advance(dt) {
+            runtime.pending_events.push(GameEvent {
+                event_name: timer.fire_event.clone(),
+                source_entity_id: Some(eid.0.clone()),
+                target_entity_id: None,
+            });
+            runtime.fired_timers.push((eid.0.clone(), timer.fire_event.clone()));
+        }
+    }
+}
+
 /// Health system: tick invincibility timers, handle death/despawn
-fn system_health(
+pub(crate) fn system_health(
     mut commands: Commands,
     time: Res<Time>,
     runtime: Option<ResMut<GameComponentRuntime>>,
@@ -661,18 +999,23 @@ fn system_health(
     // Check for death
     for (entity, eid, mut gc, mut transform, runtime_marker) in entities.iter_mut() {
         if let Some(GameComponentData::Health(health)) = gc.get_mut("health") {
-            if health.current_hp <= 0.0 {
-                // Emit death event regardless of respawn/despawn behavior
+            if health.current_hp > 0.0 {
+                runtime.dead_entities.remove(&eid.0);
+            } else if runtime.dead_entities.insert(eid.0.clone()) {
+                // Emit death event once, regardless of respawn/despawn behavior
                 runtime.pending_events.push(GameEvent {
                     event_name: "entity_death".to_string(),
                     source_entity_id: Some(eid.0.clone()),
                     target_entity_id: None,
                 });
+                let source_entity_id = runtime.damage_sources.remove(&eid.0);
+                runtime.deaths.push(EntityDeath { entity_id: eid.0.clone(), source_entity_id });
 
                 if health.respawn_on_death {
                     // Respawn at checkpoint
                     transform.translation = Vec3::from(health.respawn_point);
                     health.current_hp = health.max_hp;
+                    runtime.dead_entities.remove(&eid.0);
                 } else if health.despawn_on_death && runtime_marker.is_some() {
                     // Only despawn RuntimeEntity entities (spawned during play mode).
                     // Undeletable entities are already excluded via Without<Undeletable>.
@@ -1381,3 +1724,329 @@ fn system_dialogue_trigger(
         }
     }
 }
+
+/// Path follower: advance along the path's spline at constant average speed,
+/// optionally facing along the curve. Followers without a valid path stay put.
+fn system_path_follower(
+    time: Res<Time>,
+    runtime: Option<ResMut<GameComponentRuntime>>,
+    mut followers: Query<(&EntityId, &GameComponents, &mut Transform, &GlobalTransform)>,
+    paths: Query<(&EntityId, &super::path::PathData, &GlobalTransform)>,
+) {
+    let Some(mut runtime) = runtime else { return; };
+    let dt = time.delta_secs();
+
+    for (eid, gc, mut transform, global) in followers.iter_mut() {
+        let Some(GameComponentData::PathFollower(data)) = gc.get("path_follower") else { continue; };
+        let Some(path_id) = &data.path_entity_id else { continue; };
+        let Some((_, path, path_global)) = paths.iter().find(|(pid, _, _)| pid.0 == *path_id) else { continue; };
+        // Constant speed needs the curve length in world units.
+        let to_world = |p: Vec3| path_global.transform_point(p);
+        let length: f32 = path.polyline().windows(2).map(|pair| to_world(pair[0]).distance(to_world(pair[1]))).sum();
+        if length <= f32::EPSILON {
+            continue;
+        }
+
+        let state = runtime.path_follower_states.entry(eid.0.clone()).or_insert_with(|| PathFollowerState {
+            t: data.t.clamp(0.0, 1.0),
+            direction: 1.0,
+        });
+        let (t, direction) = advance_path_parameter(state.t, state.direction, data.speed * dt / length, &data.loop_mode);
+        state.t = t;
+        state.direction = direction;
+
+        let Some(position) = path.sample(t).map(to_world) else { continue; };
+        // Convert back to the local frame when the entity has a parent.
+        let parent = global.affine() * transform.compute_affine().inverse();
+        transform.translation = parent.inverse().transform_point3(position);
+        if data.orient_to_path {
+            let tangent = path.tangent(t).map(|v| path_global.affine().transform_vector3(v * direction));
+            if let Some(forward) = tangent.and_then(|v| Dir3::new(v).ok()) {
+                let (_, parent_rotation, _) = parent.to_scale_rotation_translation();
+                transform.rotation = parent_rotation.inverse() * Transform::default().looking_to(forward, Vec3::Y).rotation;
+            }
+        }
+    }
+}
+
+/// Turn every constrained entity toward its target's world position.
+/// Entities whose target is missing or coincides with them keep their rotation.
+fn system_look_at_constraint(
+    mut constrained: Query<(&LookAtConstraint, &mut Transform, &GlobalTransform)>,
+    targets: Query<(&EntityId, &GlobalTransform)>,
+) {
+    let positions: std::collections::HashMap<&str, Vec3> = targets.iter()
+        .map(|(eid, global)| (eid.0.as_str(), global.translation()))
+        .collect();
+
+    for (constraint, mut transform, global) in constrained.iter_mut() {
+        let Some(&target) = positions.get(constraint.target_entity_id.as_str()) else { continue; };
+        let (_, world_rotation, source) = global.to_scale_rotation_translation();
+        let Some(rotation) = constraint.rotation_toward(source, target, world_rotation) else { continue; };
+        // Convert back to the local frame when the entity has a rotated parent.
+        let parent_rotation = world_rotation * transform.rotation.inverse();
+        transform.rotation = parent_rotation.inverse() * rotation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::engine_mode::EngineMode;
+    use super::super::pending_commands::{MoveCharacterRequest, PendingCommands};
+    use bevy_rapier3d::prelude::{Collider, NoUserData, RapierPhysicsPlugin, RigidBody, TimestepMode};
+
+    /// Headless Play-mode app with Rapier and the character controller systems.
+    fn character_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), bevy::scene::ScenePlugin))
+            .init_asset::<Mesh>()
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+            .insert_resource(TimestepMode::Fixed { dt: 1.0 / 60.0, substeps: 1 })
+            .insert_resource(EngineMode::Play)
+            .init_resource::<PendingCommands>()
+            .add_systems(Update, (init_game_component_runtime, apply_move_character_requests).chain());
+        app
+    }
+
+    fn spawn_character(app: &mut App) -> Entity {
+        app.world_mut().spawn((
+            EntityId("player".to_string()),
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            GameComponents {
+                components: vec![GameComponentData::CharacterController(CharacterControllerData::default())],
+            },
+        )).id()
+    }
+
+    fn walk_right(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.world_mut().resource_mut::<PendingCommands>().queue_move_character(MoveCharacterRequest {
+                entity_id: "player".to_string(),
+                direction: [1.0, 0.0, 0.0],
+                dt: 1.0 / 60.0,
+            });
+            app.update();
+        }
+    }
+
+    #[test]
+    fn character_walking_into_wall_stops_at_wall() {
+        let mut app = character_app();
+        // Wall face at x = 2.9
+        app.world_mut().spawn((
+            Transform::from_xyz(3.0, 1.0, 0.0),
+            RigidBody::Fixed,
+            Collider::cuboid(0.1, 5.0, 5.0),
+        ));
+        let player = spawn_character(&mut app);
+
+        // 2 seconds at 5 m/s would carry the character 10 m without the wall.
+        walk_right(&mut app, 120);
+
+        let x = app.world().get::<Transform>(player).unwrap().translation.x;
+        assert!(x < 2.9, "character passed into the wall, x = {x}");
+        assert!(x > 2.0, "character should have walked up to the wall, x = {x}");
+    }
+
+    #[test]
+    fn character_moves_freely_without_obstacles() {
+        let mut app = character_app();
+        let player = spawn_character(&mut app);
+
+        walk_right(&mut app, 60);
+
+        let x = app.world().get::<Transform>(player).unwrap().translation.x;
+        assert!((x - 5.0).abs() < 0.2, "expected ~5 m after 1 s at 5 m/s, x = {x}");
+    }
+
+    #[test]
+    fn move_requests_are_discarded_outside_play() {
+        let mut app = character_app();
+        app.insert_resource(EngineMode::Edit);
+        let player = spawn_character(&mut app);
+
+        walk_right(&mut app, 10);
+
+        assert!(app.world().resource::<PendingCommands>().move_character_requests.is_empty());
+        assert_eq!(app.world().get::<Transform>(player).unwrap().translation.x, 0.0);
+        assert!(app.world().get::<KinematicCharacterController>(player).is_none());
+    }
+
+    #[test]
+    fn step_height_defaults_for_older_data() {
+        let data: CharacterControllerData = serde_json::from_str(
+            r#"{"speed":5.0,"jumpHeight":8.0,"gravityScale":1.0,"canDoubleJump":false}"#,
+        ).unwrap();
+        assert_eq!(data.step_height, 0.3);
+        assert!(data.kinematic_controller(Vec3::ONE).autostep.is_some());
+    }
+
+    #[test]
+    fn look_at_constraint_faces_target_along_x() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, system_look_at_constraint);
+        app.world_mut().spawn((
+            EntityId("target".to_string()),
+            GlobalTransform::from(Transform::from_xyz(5.0, 0.0, 0.0)),
+        ));
+        let turret = app.world_mut().spawn((
+            LookAtConstraint {
+                target_entity_id: "target".to_string(),
+                up_axis: default_look_at_up_axis(),
+                weight: 1.0,
+            },
+            Transform::default(),
+            GlobalTransform::default(),
+        )).id();
+        app.update();
+
+        let transform = app.world().get::<Transform>(turret).unwrap();
+        assert!(transform.forward().abs_diff_eq(Vec3::X, 1e-5), "forward {:?}", transform.forward());
+        assert!(transform.up().abs_diff_eq(Vec3::Y, 1e-5));
+    }
+
+    #[test]
+    fn look_at_constraint_keeps_rotation_when_target_coincides() {
+        let constraint = LookAtConstraint {
+            target_entity_id: "target".to_string(),
+            up_axis: default_look_at_up_axis(),
+            weight: 0.5,
+        };
+        let current = Quat::from_rotation_y(0.4);
+        assert_eq!(constraint.rotation_toward(Vec3::ONE, Vec3::ONE, current), None);
+
+        // Half weight turns halfway from facing -Z to facing +X.
+        let half = constraint.rotation_toward(Vec3::ZERO, Vec3::X, Quat::IDENTITY).unwrap();
+        assert!(half.abs_diff_eq(Quat::from_rotation_y(-std::f32::consts::FRAC_PI_4), 1e-5));
+    }
+
+    #[test]
+    fn path_follower_returns_to_start_after_one_loop_of_closed_path() {
+        use super::super::path::PathData;
+        use bevy::time::TimeUpdateStrategy;
+
+        let path = PathData {
+            points: vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [4.0, 0.0, 4.0], [0.0, 0.0, 4.0]],
+            closed: true,
+        };
+        // One loop every 120 frames at 60 fps.
+        let speed = path.length() / 2.0;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(std::time::Duration::from_secs_f64(1.0 / 60.0)))
+            .init_resource::<GameComponentRuntime>()
+            .add_systems(Update, system_path_follower);
+        app.world_mut().spawn((EntityId("path".to_string()), path, Transform::default()));
+        let follower = app.world_mut().spawn((
+            EntityId("follower".to_string()),
+            GameComponents {
+                components: vec![GameComponentData::PathFollower(PathFollowerData {
+                    path_entity_id: Some("path".to_string()),
+                    speed,
+                    ..Default::default()
+                })],
+            },
+            Transform::default(),
+        )).id();
+
+        // The first update has no elapsed time.
+        app.update();
+        let position = |app: &App| app.world().get::<Transform>(follower).unwrap().translation;
+        assert!(position(&app).abs_diff_eq(Vec3::ZERO, 1e-4));
+
+        for _ in 0..60 {
+            app.update();
+        }
+        assert!(position(&app).abs_diff_eq(Vec3::new(4.0, 0.0, 4.0), 1e-3), "halfway at {:?}", position(&app));
+
+        for _ in 0..60 {
+            app.update();
+        }
+        assert!(position(&app).abs_diff_eq(Vec3::ZERO, 1e-3), "after one loop at {:?}", position(&app));
+    }
+
+    #[test]
+    fn third_thirty_point_hit_kills_exactly_once() {
+        use super::super::engine_mode::EngineMode;
+        use super::super::pending_commands::{ApplyDamageRequest, PendingCommands};
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(EngineMode::Play)
+            .init_resource::<PendingCommands>()
+            .init_resource::<GameComponentRuntime>()
+            .add_systems(Update, (apply_damage_requests, system_health).chain());
+        // Already wounded: 80 of 100 HP, so three 30-point hits are fatal.
+        let target = app.world_mut().spawn((
+            EntityId("target".to_string()),
+            GameComponents {
+                components: vec![GameComponentData::Health(HealthData {
+                    current_hp: 80.0,
+                    respawn_on_death: false,
+                    despawn_on_death: false,
+                    ..Default::default()
+                })],
+            },
+            Transform::default(),
+        )).id();
+
+        let hp = |app: &App| match app.world().get::<GameComponents>(target).unwrap().get("health") {
+            Some(GameComponentData::Health(health)) => health.current_hp,
+            _ => panic!("missing health"),
+        };
+        let mut deaths = Vec::new();
+        for hit in 0..3 {
+            app.world_mut().resource_mut::<PendingCommands>().queue_apply_damage(ApplyDamageRequest {
+                entity_id: "target".to_string(),
+                amount: 30.0,
+                source: Some("spikes".to_string()),
+            });
+            app.update();
+            let new_deaths: Vec<_> = app.world_mut().resource_mut::<GameComponentRuntime>().deaths.drain(..).collect();
+            assert_eq!(new_deaths.len(), usize::from(hit == 2), "after hit {}", hit + 1);
+            deaths.extend(new_deaths);
+        }
+        assert_eq!(hp(&app), 0.0);
+        assert_eq!(deaths[0].source_entity_id.as_deref(), Some("spikes"));
+
+        // Staying dead does not report again, and the dead cannot be healed.
+        app.world_mut().resource_mut::<PendingCommands>().queue_apply_damage(ApplyDamageRequest {
+            entity_id: "target".to_string(),
+            amount: -50.0,
+            source: None,
+        });
+        app.update();
+        assert!(app.world().resource::<GameComponentRuntime>().deaths.is_empty());
+        assert_eq!(hp(&app), 0.0);
+    }
+
+    #[test]
+    fn repeating_timer_fires_twice_in_two_point_one_seconds() {
+        let mut timer = TimerData { repeating: true, ..Default::default() };
+        let fired: u32 = (0..21).map(|_| timer.advance(0.1)).sum();
+        assert_eq!(fired, 2);
+        assert!(timer.running);
+    }
+
+    #[test]
+    fn one_shot_timer_fires_once_and_stops() {
+        let mut timer = TimerData::default();
+        let fired: u32 = (0..30).map(|_| timer.advance(0.1)).sum();
+        assert_eq!(fired, 1);
+        assert!(!timer.running);
+        assert_eq!(timer.elapsed, timer.duration);
+    }
+
+    #[test]
+    fn path_parameter_bounces_and_stops_at_ends() {
+        let (t, direction) = advance_path_parameter(0.9, 1.0, 0.3, &PlatformLoopMode::PingPong);
+        assert!((t - 0.8).abs() < 1e-5);
+        assert_eq!(direction, -1.0);
+        assert_eq!(advance_path_parameter(0.9, 1.0, 0.3, &PlatformLoopMode::Once), (1.0, 1.0));
+        let (t, _) = advance_path_parameter(0.9, 1.0, 0.3, &PlatformLoopMode::Loop);
+        assert!((t - 0.2).abs() < 1e-5);
+    }
+}
@@ -25,6 +25,10 @@ pub fn build_game_component(component_type: &str, properties_json: &str) -> Resu
                 if v.is_finite() { data.gravity_scale = v.clamp(-10.0, 10.0); }
             }
             if let Some(v) = props.get("canDoubleJump").and_then(|v| v.as_bool()) { data.can_double_jump = v; }
+            if let Some(v) = props.get("stepHeight").and_then(|v| v.as_f64()) {
+                let v = v as f32;
+                if v.is_finite() { data.step_height = v.clamp(0.0, 10.0); }
+            }
             Ok(GameComponentData::CharacterController(data))
         }
         "health" => {
@@ -218,6 +222,38 @@ pub fn build_game_component(component_type: &str, properties_json: &str) -> Resu
             if let Some(v) = props.get("interactionKey").and_then(|v| v.as_str()) { data.interaction_key = v.to_string(); }
             Ok(GameComponentData::DialogueTrigger(data))
         }
+        "path_follower" => {
+            let mut data = PathFollowerData::default();
+            if let Some(v) = props.get("pathEntityId").and_then(|v| v.as_str()) { data.path_entity_id = Some(v.to_string()); }
+            if let Some(v) = props.get("speed").and_then(|v| v.as_f64()) {
+                let v = v as f32;
+                if v.is_finite() { data.speed = v.clamp(0.0, 1000.0); }
+            }
+            if let Some(v) = props.get("loopMode").and_then(|v| v.as_str()) {
+                data.loop_mode = match v {
+                    "pingPong" => PlatformLoopMode::PingPong,
+                    "once" => PlatformLoopMode::Once,
+                    _ => PlatformLoopMode::Loop,
+                };
+            }
+            if let Some(v) = props.get("t").and_then(|v| v.as_f64()) {
+                let v = v as f32;
+                if v.is_finite() { data.t = v.clamp(0.0, 1.0); }
+            }
+            if let Some(v) = props.get("orientToPath").and_then(|v| v.as_bool()) { data.orient_to_path = v; }
+            Ok(GameComponentData::PathFollower(data))
+        }
+        "timer" => {
+            let mut data = TimerData::default();
+            if let Some(v) = props.get("duration").and_then(|v| v.as_f64()) {
+                let v = v as f32;
+                if v.is_finite() { data.duration = v.clamp(0.0, 86_400.0); }
+            }
+            if let Some(v) = props.get("repeating").and_then(|v| v.as_bool()) { data.repeating = v; }
+            if let Some(v) = props.get("fireEvent").and_then(|v| v.as_str()) { data.fire_event = v.to_string(); }
+            if let Some(v) = props.get("running").and_then(|v| v.as_bool()) { data.running = v; }
+            Ok(GameComponentData::Timer(data))
+        }
         _ => Err(format!("Unknown component type: {}", component_type))
     }
 }
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use transform_gizmo_bevy::prelude::*;
 
+use super::entity_factory::Locked;
 use super::entity_id::EntityId;
 use super::history::{HistoryStack, TransformSnapshot, UndoableAction};
 use super::selection::Selection;
@@ -22,6 +23,7 @@ impl Plugin for ForgeGizmoPlugin {
             .init_resource::<ActiveGizmoMode>()
             .init_resource::<CoordinateMode>()
             .init_resource::<GizmoInteractionState>()
+            .init_resource::<LastMoveDelta>()
             .add_systems(Update, (
                 configure_multi_target_gizmo,
                 sync_gizmo_targets,
@@ -43,6 +45,27 @@ pub struct GizmoInteractionState {
     drag_start_transforms: Vec<(String, TransformSnapshot)>,
 }
 
+/// Translation of the most recent move (gizmo drag or transform update),
+/// reused as the offset of `smart_duplicate`.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct LastMoveDelta(pub Option<Vec3>);
+
+impl LastMoveDelta {
+    /// Record a move; pure rotations and scales (zero delta) are ignored.
+    pub fn record(&mut self, delta: Vec3) {
+        if delta != Vec3::ZERO {
+            self.0 = Some(delta);
+        }
+    }
+
+    /// Record the move of the first entity in a set of transform changes.
+    pub fn record_changes(&mut self, changes: &[(String, TransformSnapshot, TransformSnapshot)]) {
+        if let Some((_, old, new)) = changes.first() {
+            self.record(Vec3::from(new.position) - Vec3::from(old.position));
+        }
+    }
+}
+
 /// Current gizmo manipulation mode (exposed to React).
 #[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ActiveGizmoMode {
@@ -154,15 +177,16 @@ fn configure_multi_target_gizmo(
 
 /// System that syncs gizmo targets with ALL selected entities.
 /// Adds GizmoTarget to all selected entities, removes from unselected.
+/// Locked entities never get a gizmo.
 fn sync_gizmo_targets(
     selection: Res<Selection>,
     mut commands: Commands,
     gizmo_targets: Query<Entity, With<GizmoTarget>>,
-    transforms: Query<&Transform>,
+    transforms: Query<&Transform, Without<Locked>>,
 ) {
-    // Remove gizmo from entities that are no longer selected
+    // Remove gizmo from entities that are no longer selected or got locked
     for entity in gizmo_targets.iter() {
-        if !selection.entities.contains(&entity) {
+        if !selection.entities.contains(&entity) || !transforms.contains(entity) {
             commands.entity(entity).remove::<GizmoTarget>();
         }
     }
@@ -176,12 +200,13 @@ fn sync_gizmo_targets(
 }
 
 /// System that tracks gizmo interaction start/end for history recording.
-fn track_gizmo_interaction(
+pub(crate) fn track_gizmo_interaction(
     mouse_button: Res<ButtonInput<MouseButton>>,
     selection: Res<Selection>,
     query: Query<(&EntityId, &Transform), With<GizmoTarget>>,
     mut interaction_state: ResMut<GizmoInteractionState>,
     mut history: ResMut<HistoryStack>,
+    mut last_move: ResMut<LastMoveDelta>,
 ) {
     let left_pressed = mouse_button.pressed(MouseButton::Left);
 
@@ -226,6 +251,8 @@ fn track_gizmo_interaction(
                 }
             }
 
+            last_move.record_changes(&changes);
+
             // Push to history if any changes occurred
             if !changes.is_empty() {
                 if changes.len() == 1 {
@@ -0,0 +1,368 @@
+//! Binary glTF (.glb) export of static scene geometry.
+//!
+//! This module is pure data: the bridge system collects meshes, materials, and
+//! transforms from the ECS into `ExportNode`s, and `build_glb` writes them out.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use bevy::mesh::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues};
+use gltf::json;
+use json::validation::Checked::Valid;
+use json::validation::USize64;
+
+use super::csg::CsgMeshData;
+use super::material::{MaterialAlphaMode, MaterialData};
+use super::procedural_mesh::ProceduralMeshData;
+
+/// Triangle-list geometry ready to be written to a glTF buffer.
+#[derive(Debug, Clone, Default)]
+pub struct ExportMesh {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u32>,
+}
+
+impl ExportMesh {
+    /// Extract geometry from a Bevy mesh asset.
+    /// Returns `None` for non-triangle-list meshes or meshes without positions.
+    pub fn from_mesh(mesh: &Mesh) -> Option<Self> {
+        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+            return None;
+        }
+        let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float32x3(p)) => p.clone(),
+            _ => return None,
+        };
+        let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+            Some(VertexAttributeValues::Float32x3(n)) => n.clone(),
+            _ => Vec::new(),
+        };
+        let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float32x2(uv)) => uv.clone(),
+            _ => Vec::new(),
+        };
+        let indices = match mesh.indices() {
+            Some(Indices::U16(i)) => i.iter().map(|&v| v as u32).collect(),
+            Some(Indices::U32(i)) => i.clone(),
+            None => Vec::new(),
+        };
+        Some(Self { positions, normals, uvs, indices })
+    }
+
+    /// Use the serialized vertex data of a procedural mesh directly.
+    pub fn from_procedural(data: &ProceduralMeshData) -> Self {
+        Self {
+            positions: data.positions.clone(),
+            normals: data.normals.clone(),
+            uvs: data.uvs.clone(),
+            indices: data.indices.clone(),
+        }
+    }
+
+    /// Use the serialized vertex data of a CSG result directly.
+    pub fn from_csg(data: &CsgMeshData) -> Self {
+        Self {
+            positions: data.positions.clone(),
+            normals: data.normals.clone(),
+            uvs: Vec::new(),
+            indices: data.indices.clone(),
+        }
+    }
+}
+
+/// One node of the exported hierarchy.
+#[derive(Debug, Clone)]
+pub struct ExportNode {
+    pub name: String,
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+    pub mesh: Option<ExportMesh>,
+    pub material: Option<MaterialData>,
+    /// Index of the parent within the exported node list (`None` = scene root).
+    pub parent: Option<usize>,
+}
+
+/// Append `bytes` to the binary chunk as a new buffer view (4-byte aligned).
+fn push_view(
+    root: &mut json::Root,
+    bin: &mut Vec<u8>,
+    buffer: json::Index<json::Buffer>,
+    bytes: &[u8],
+    target: json::buffer::Target,
+) -> json::Index<json::buffer::View> {
+    let offset = bin.len();
+    bin.extend_from_slice(bytes);
+    while !bin.len().is_multiple_of(4) {
+        bin.push(0);
+    }
+    root.push(json::buffer::View {
+        buffer,
+        byte_length: USize64::from(bytes.len()),
+        byte_offset: Some(USize64::from(offset)),
+        byte_stride: None,
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        target: Some(Valid(target)),
+    })
+}
+
+fn push_accessor(
+    root: &mut json::Root,
+    view: json::Index<json::buffer::View>,
+    count: usize,
+    component: json::accessor::ComponentType,
+    type_: json::accessor::Type,
+    bounds: Option<([f32; 3], [f32; 3])>,
+) -> json::Index<json::Accessor> {
+    root.push(json::Accessor {
+        buffer_view: Some(view),
+        byte_offset: Some(USize64(0)),
+        count: USize64::from(count),
+        component_type: Valid(json::accessor::GenericComponentType(component)),
+        extensions: Default::default(),
+        extras: Default::default(),
+        type_: Valid(type_),
+        min: bounds.map(|(min, _)| json::Value::from(Vec::from(min))),
+        max: bounds.map(|(_, max)| json::Value::from(Vec::from(max))),
+        name: None,
+        normalized: false,
+        sparse: None,
+    })
+}
+
+fn f32_bytes<const N: usize>(values: &[[f32; N]]) -> Vec<u8> {
+    values.iter().flatten().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+/// Map the editor's material data onto glTF metallic-roughness PBR.
+fn to_gltf_material(name: &str, data: &MaterialData) -> json::Material {
+    let (alpha_mode, alpha_cutoff) = match data.alpha_mode {
+        MaterialAlphaMode::Opaque => (json::material::AlphaMode::Opaque, None),
+        MaterialAlphaMode::Blend => (json::material::AlphaMode::Blend, None),
+        MaterialAlphaMode::Mask => (
+            json::material::AlphaMode::Mask,
+            Some(json::material::AlphaCutoff(data.alpha_cutoff)),
+        ),
+    };
+    json::Material {
+        alpha_cutoff,
+        alpha_mode: Valid(alpha_mode),
+        double_sided: data.double_sided,
+        name: Some(name.to_string()),
+        pbr_metallic_roughness: json::material::PbrMetallicRoughness {
+            base_color_factor: json::material::PbrBaseColorFactor(data.base_color),
+            metallic_factor: json::material::StrengthFactor(data.metallic),
+            roughness_factor: json::material::StrengthFactor(data.perceptual_roughness),
+            ..Default::default()
+        },
+        // glTF core clamps emissive to [0, 1]; HDR emission needs an extension we don't write.
+        emissive_factor: json::material::EmissiveFactor([
+            data.emissive[0].clamp(0.0, 1.0),
+            data.emissive[1].clamp(0.0, 1.0),
+            data.emissive[2].clamp(0.0, 1.0),
+        ]),
+        ..Default::default()
+    }
+}
+
+/// Write the given nodes as a binary glTF file.
+pub fn build_glb(nodes: &[ExportNode]) -> Result<Vec<u8>, String> {
+    let mut root = json::Root::default();
+    root.asset.generator = Some("SpawnForge".to_string());
+
+    let mut bin: Vec<u8> = Vec::new();
+    // Single buffer; its byte length is patched once all views are written.
+    let buffer = root.push(json::Buffer {
+        byte_length: USize64(0),
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        uri: None,
+    });
+
+    let mut node_indices = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        let mesh_index = match &node.mesh {
+            Some(mesh) if !mesh.positions.is_empty() => {
+                let vertex_count = mesh.positions.len();
+                let mut attributes = BTreeMap::new();
+
+                let mut min = [f32::MAX; 3];
+                let mut max = [f32::MIN; 3];
+                for p in &mesh.positions {
+                    for i in 0..3 {
+                        min[i] = min[i].min(p[i]);
+                        max[i] = max[i].max(p[i]);
+                    }
+                }
+                let view = push_view(&mut root, &mut bin, buffer, &f32_bytes(&mesh.positions), json::buffer::Target::ArrayBuffer);
+                let accessor = push_accessor(&mut root, view, vertex_count, json::accessor::ComponentType::F32, json::accessor::Type::Vec3, Some((min, max)));
+                attributes.insert(Valid(json::mesh::Semantic::Positions), accessor);
+
+                if mesh.normals.len() == vertex_count {
+                    let view = push_view(&mut root, &mut bin, buffer, &f32_bytes(&mesh.normals), json::buffer::Target::ArrayBuffer);
+                    let accessor = push_accessor(&mut root, view, vertex_count, json::accessor::ComponentType::F32, json::accessor::Type::Vec3, None);
+                    attributes.insert(Valid(json::mesh::Semantic::Normals), accessor);
+                }
+                if mesh.uvs.len() == vertex_count {
+                    let view = push_view(&mut root, &mut bin, buffer, &f32_bytes(&mesh.uvs), json::buffer::Target::ArrayBuffer);
+                    let accessor = push_accessor(&mut root, view, vertex_count, json::accessor::ComponentType::F32, json::accessor::Type::Vec2, None);
+                    attributes.insert(Valid(json::mesh::Semantic::TexCoords(0)), accessor);
+                }
+
+                let indices = if mesh.indices.is_empty() {
+                    None
+                } else {
+                    let bytes: Vec<u8> = mesh.indices.iter().flat_map(|i| i.to_le_bytes()).collect();
+                    let view = push_view(&mut root, &mut bin, buffer, &bytes, json::buffer::Target::ElementArrayBuffer);
+                    Some(push_accessor(&mut root, view, mesh.indices.len(), json::accessor::ComponentType::U32, json::accessor::Type::Scalar, None))
+                };
+
+                let material = node.material.as_ref()
+                    .map(|m| root.push(to_gltf_material(&node.name, m)));
+
+                Some(root.push(json::Mesh {
+                    extensions: Default::default(),
+                    extras: Default::default(),
+                    name: Some(node.name.clone()),
+                    primitives: vec![json::mesh::Primitive {
+                        attributes,
+                        extensions: Default::default(),
+                        extras: Default::default(),
+                        indices,
+                        material,
+                        mode: Valid(json::mesh::Mode::Triangles),
+                        targets: None,
+                    }],
+                    weights: None,
+                }))
+            }
+            _ => None,
+        };
+
+        node_indices.push(root.push(json::Node {
+            mesh: mesh_index,
+            name: Some(node.name.clone()),
+            translation: Some(node.translation),
+            rotation: Some(json::scene::UnitQuaternion(node.rotation)),
+            scale: Some(node.scale),
+            ..Default::default()
+        }));
+    }
+
+    // Wire up the hierarchy; anything without a (valid) parent is a scene root.
+    let mut scene_roots = Vec::new();
+    for (i, node) in nodes.iter().enumerate() {
+        match node.parent.filter(|&p| p < nodes.len() && p != i) {
+            Some(parent) => {
+                let children = root.nodes[parent].children.get_or_insert_with(Vec::new);
+                children.push(node_indices[i]);
+            }
+            None => scene_roots.push(node_indices[i]),
+        }
+    }
+
+    let scene = root.push(json::Scene {
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        nodes: scene_roots,
+    });
+    root.scene = Some(scene);
+
+    if bin.is_empty() {
+        root.buffers.clear();
+    } else {
+        root.buffers[0].byte_length = USize64::from(bin.len());
+    }
+
+    let json_bytes = json::serialize::to_vec(&root)
+        .map_err(|e| format!("Failed to serialize glTF JSON: {}", e))?;
+    let align = |n: usize| (n + 3) & !3;
+    let length = 12 + 8 + align(json_bytes.len()) + if bin.is_empty() { 0 } else { 8 + align(bin.len()) };
+
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: u32::try_from(length).map_err(|_| "glTF export exceeds the 4 GB GLB limit".to_string())?,
+        },
+        json: Cow::Owned(json_bytes),
+        bin: if bin.is_empty() { None } else { Some(Cow::Owned(bin)) },
+    };
+    glb.to_vec().map_err(|e| format!("Failed to write GLB: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::{Cuboid, Mesh};
+
+    fn cube_node(name: &str, x: f32) -> ExportNode {
+        ExportNode {
+            name: name.to_string(),
+            translation: [x, 0.0, 0.0],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [1.0, 1.0, 1.0],
+            mesh: ExportMesh::from_mesh(&Mesh::from(Cuboid::default())),
+            material: Some(MaterialData::default()),
+            parent: None,
+        }
+    }
+
+    #[test]
+    fn two_cube_scene_round_trips_through_glb() {
+        let bytes = build_glb(&[cube_node("CubeA", -1.0), cube_node("CubeB", 1.0)])
+            .expect("export succeeds");
+
+        let document = gltf::Gltf::from_slice(&bytes).expect("valid glb");
+        let blob = document.blob.as_deref().expect("binary chunk present");
+
+        let mesh_nodes: Vec<_> = document.nodes().filter(|n| n.mesh().is_some()).collect();
+        assert_eq!(mesh_nodes.len(), 2);
+        assert_eq!(document.materials().len(), 2);
+
+        for node in mesh_nodes {
+            let mesh = node.mesh().expect("mesh");
+            let primitive = mesh.primitives().next().expect("primitive");
+            let reader = primitive.reader(|_| Some(blob));
+            assert_eq!(reader.read_positions().expect("positions").count(), 24);
+            assert_eq!(reader.read_indices().expect("indices").into_u32().count(), 36);
+        }
+    }
+
+    #[test]
+    fn child_nodes_are_nested_under_their_parent() {
+        let mut child = cube_node("Child", 0.5);
+        child.parent = Some(0);
+        let bytes = build_glb(&[cube_node("Parent", 0.0), child]).expect("export succeeds");
+
+        let document = gltf::Gltf::from_slice(&bytes).expect("valid glb");
+        let scene = document.default_scene().expect("default scene");
+        let roots: Vec<_> = scene.nodes().collect();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].name(), Some("Parent"));
+        assert_eq!(roots[0].children().map(|c| c.name()).collect::<Vec<_>>(), vec![Some("Child")]);
+    }
+
+    #[test]
+    fn procedural_mesh_data_exports_directly() {
+        let mesh = ExportMesh::from_csg(&CsgMeshData {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            normals: vec![[0.0, 0.0, 1.0]; 3],
+            indices: vec![0, 1, 2],
+        });
+        let node = ExportNode { mesh: Some(mesh), material: None, ..cube_node("Tri", 0.0) };
+        let bytes = build_glb(&[node]).expect("export succeeds");
+
+        let document = gltf::Gltf::from_slice(&bytes).expect("valid glb");
+        let mesh = document.meshes().next().expect("mesh");
+        let primitive = mesh.primitives().next().expect("primitive");
+        assert!(primitive.get(&gltf::Semantic::TexCoords(0)).is_none());
+        assert_eq!(primitive.indices().expect("indices").count(), 3);
+    }
+}
@@ -4,220 +4,32 @@
 //! All undoable actions are stored in a stack with their reverse operations.
 
 use bevy::prelude::*;
-use serde::{Deserialize, Serialize};
 
 use super::animation_clip::AnimationClipData;
-use super::asset_manager::AssetRef;
 use super::audio::AudioData;
 use super::csg::CsgMeshData;
-use super::game_camera::GameCameraData;
-use super::game_components::GameComponents;
+use super::game_components::{GameComponents, LookAtConstraint};
+use super::path::PathData;
 use super::lighting::LightData;
 use super::material::MaterialData;
-use super::particles::ParticleData;
+use super::particles::{ParticleData, ParticleForceField};
 use super::pending_commands::EntityType;
 use super::physics::{JointData, PhysicsData};
 use super::physics_2d::{PhysicsJoint2d, Physics2dData};
 use super::scripting::ScriptData;
 use super::shader_effects::ShaderEffectData;
-use super::lod::LodData;
-use super::skeletal_animation2d::SkeletalAnimation2d;
 use super::skeleton2d::SkeletonData2d;
 use super::sprite::SpriteData;
 use super::terrain::{TerrainData, TerrainMeshData};
-use super::tilemap::TilemapData;
-
-/// Snapshot of transform data for undo/redo.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TransformSnapshot {
-    pub position: [f32; 3],
-    pub rotation: [f32; 4], // Quaternion
-    pub scale: [f32; 3],
-}
+use super::tilemap::{TileRect, TilemapData};
 
-impl From<&Transform> for TransformSnapshot {
-    fn from(t: &Transform) -> Self {
-        Self {
-            position: [t.translation.x, t.translation.y, t.translation.z],
-            rotation: [t.rotation.x, t.rotation.y, t.rotation.z, t.rotation.w],
-            scale: [t.scale.x, t.scale.y, t.scale.z],
-        }
-    }
-}
+mod queue;
+mod snapshot;
+mod transform_batch;
 
-impl TransformSnapshot {
-    pub fn to_transform(&self) -> Transform {
-        Transform {
-            translation: Vec3::new(self.position[0], self.position[1], self.position[2]),
-            rotation: Quat::from_xyzw(
-                self.rotation[0],
-                self.rotation[1],
-                self.rotation[2],
-                self.rotation[3],
-            ),
-            scale: Vec3::new(self.scale[0], self.scale[1], self.scale[2]),
-        }
-    }
-}
-
-/// Complete snapshot of an entity for perfect restoration.
-/// Stores the original entity_id so it can be reused on restore.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct EntitySnapshot {
-    /// Original entity ID - reused when restoring to preserve references
-    pub entity_id: String,
-    /// Type of entity (cube, sphere, light, etc.)
-    pub entity_type: EntityType,
-    /// Display name
-    pub name: String,
-    /// Transform state
-    pub transform: TransformSnapshot,
-    /// Parent entity ID (for hierarchy preservation)
-    pub parent_id: Option<String>,
-    /// Visibility state
-    pub visible: bool,
-    /// Material data (for mesh entities)
-    pub material_data: Option<MaterialData>,
-    /// Light data (for light entities)
-    pub light_data: Option<LightData>,
-    /// Physics data (if entity has physics configured)
-    pub physics_data: Option<PhysicsData>,
-    /// Whether physics is enabled on this entity
-    pub physics_enabled: bool,
-    /// Asset reference (for imported glTF models)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub asset_ref: Option<AssetRef>,
-    /// Script data (if entity has a script)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub script_data: Option<ScriptData>,
-    /// Audio data (if entity has audio)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub audio_data: Option<AudioData>,
-    /// Reverb zone data (if entity has reverb zone)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub reverb_zone_data: Option<super::reverb_zone::ReverbZoneData>,
-    /// Whether reverb zone is enabled on this entity
-    #[serde(default)]
-    pub reverb_zone_enabled: bool,
-    /// Particle data (if entity has particles configured)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub particle_data: Option<ParticleData>,
-    /// Whether particle emission is enabled on this entity
-    #[serde(default)]
-    pub particle_enabled: bool,
-    /// Shader effect data (if entity has custom shader)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub shader_effect_data: Option<ShaderEffectData>,
-    /// CSG mesh vertex/index data (for CsgResult entities, needed for undo restore)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub csg_mesh_data: Option<CsgMeshData>,
-    /// Terrain data (if entity is a terrain)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub terrain_data: Option<TerrainData>,
-    /// Terrain mesh (heightmap) data for reconstruction
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub terrain_mesh_data: Option<TerrainMeshData>,
-    /// Procedural mesh data (for extrude/lathe/combine results)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub procedural_mesh_data: Option<super::procedural_mesh::ProceduralMeshData>,
-    /// Joint data (if entity has a physics joint)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub joint_data: Option<JointData>,
-    /// Game components (pre-built behaviors)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub game_components: Option<GameComponents>,
-    /// Animation clip data (keyframe property animation)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub animation_clip_data: Option<AnimationClipData>,
-    /// Game camera configuration (if entity has a game camera)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub game_camera_data: Option<GameCameraData>,
-    /// Whether this entity is the active game camera
-    #[serde(default)]
-    pub active_game_camera: bool,
-    /// Sprite data (if entity is a 2D sprite)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sprite_data: Option<SpriteData>,
-    /// 2D physics data (if entity has 2D physics configured)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub physics2d_data: Option<Physics2dData>,
-    /// Whether 2D physics is enabled on this entity
-    #[serde(default)]
-    pub physics2d_enabled: bool,
-    /// 2D joint data (if entity has a 2D physics joint)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub joint2d_data: Option<PhysicsJoint2d>,
-    /// Tilemap data (if entity is a tilemap)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tilemap_data: Option<TilemapData>,
-    /// Whether tilemap rendering is enabled
-    #[serde(default)]
-    pub tilemap_enabled: bool,
-    /// Skeleton 2D data (if entity has a skeleton)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub skeleton2d_data: Option<SkeletonData2d>,
-    /// Whether skeleton 2D is enabled
-    #[serde(default)]
-    pub skeleton2d_enabled: bool,
-    /// Skeletal animations (if entity has skeletal animations)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub skeletal_animations: Option<Vec<SkeletalAnimation2d>>,
-    /// LOD configuration (if entity has LOD data)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub lod_data: Option<LodData>,
-}
-
-impl EntitySnapshot {
-    /// Create a new snapshot with required fields; all optional fields default to None/false.
-    pub fn new(
-        entity_id: String,
-        entity_type: EntityType,
-        name: String,
-        transform: TransformSnapshot,
-    ) -> Self {
-        Self {
-            entity_id,
-            entity_type,
-            name,
-            transform,
-            parent_id: None,
-            visible: true,
-            material_data: None,
-            light_data: None,
-            physics_data: None,
-            physics_enabled: false,
-            asset_ref: None,
-            script_data: None,
-            audio_data: None,
-            reverb_zone_data: None,
-            reverb_zone_enabled: false,
-            particle_data: None,
-            particle_enabled: false,
-            shader_effect_data: None,
-            csg_mesh_data: None,
-            terrain_data: None,
-            terrain_mesh_data: None,
-            procedural_mesh_data: None,
-            joint_data: None,
-            game_components: None,
-            animation_clip_data: None,
-            game_camera_data: None,
-            active_game_camera: false,
-            sprite_data: None,
-            physics2d_data: None,
-            physics2d_enabled: false,
-            joint2d_data: None,
-            tilemap_data: None,
-            tilemap_enabled: false,
-            skeleton2d_data: None,
-            skeleton2d_enabled: false,
-            skeletal_animations: None,
-            lod_data: None,
-        }
-    }
-}
+pub use queue::*;
+pub use snapshot::*;
+pub use transform_batch::*;
 
 /// An action that can be undone/redone.
 #[derive(Debug, Clone)]
@@ -314,6 +126,13 @@ pub enum UndoableAction {
         new_particle: Option<ParticleData>,
     },
 
+    /// Particle force field changed
+    ParticleForceFieldChange {
+        entity_id: String,
+        old_field: Option<ParticleForceField>,
+        new_field: Option<ParticleForceField>,
+    },
+
     /// Shader effect configuration changed
     ShaderChange {
         entity_id: String,
@@ -333,6 +152,16 @@ pub enum UndoableAction {
         sources_deleted: bool,
     },
 
+    /// Multi-operand CSG chain performed
+    CsgChain {
+        /// Snapshots of the base and every operand (for restore on undo if deleted)
+        source_snapshots: Vec<EntitySnapshot>,
+        /// Snapshot of the result entity (for delete on undo)
+        result_snapshot: EntitySnapshot,
+        /// Whether source entities were deleted
+        sources_deleted: bool,
+    },
+
     /// Terrain noise parameters or sculpt operation changed
     TerrainChange {
         entity_id: String,
@@ -342,6 +171,15 @@ pub enum UndoableAction {
         new_mesh_data: TerrainMeshData,
     },
 
+    /// Terrain holes painted or filled
+    TerrainHoleEdit {
+        entity_id: String,
+        old_holes: Vec<bool>,
+        new_holes: Vec<bool>,
+        /// Heightmap at the time of the edit, used to rebuild the mesh
+        mesh_data: TerrainMeshData,
+    },
+
     /// Extrude operation performed
     ExtrudeShape {
         snapshot: EntitySnapshot,
@@ -352,18 +190,105 @@ pub enum UndoableAction {
         snapshot: EntitySnapshot,
     },
 
+    /// Loft operation performed
+    LoftShape {
+        snapshot: EntitySnapshot,
+    },
+
+    /// Sweep operation performed
+    SweepShape {
+        snapshot: EntitySnapshot,
+    },
+
+    /// Mesh subdivided in place
+    SubdivideMesh {
+        entity_id: String,
+        old_entity_type: EntityType,
+        /// Mesh before subdivision (restored on undo)
+        old_mesh: Handle<Mesh>,
+        new_mesh: Handle<Mesh>,
+        old_mesh_data: Option<super::procedural_mesh::ProceduralMeshData>,
+        new_mesh_data: super::procedural_mesh::ProceduralMeshData,
+    },
+
+    /// Transform components baked into the mesh (apply transform)
+    ApplyTransform {
+        entity_id: String,
+        old_entity_type: EntityType,
+        new_entity_type: EntityType,
+        old_transform: TransformSnapshot,
+        new_transform: TransformSnapshot,
+        old_mesh: Handle<Mesh>,
+        new_mesh: Handle<Mesh>,
+        old_mesh_data: Option<super::procedural_mesh::ProceduralMeshData>,
+        new_mesh_data: Option<super::procedural_mesh::ProceduralMeshData>,
+        old_csg_data: Option<CsgMeshData>,
+        new_csg_data: Option<CsgMeshData>,
+    },
+
+    /// Mesh geometry edited in place (recalculate or flip normals)
+    MeshEdit {
+        entity_id: String,
+        /// Display label, e.g. "Flip Normals"
+        operation: String,
+        old_mesh: Handle<Mesh>,
+        new_mesh: Handle<Mesh>,
+        old_mesh_data: Option<super::procedural_mesh::ProceduralMeshData>,
+        new_mesh_data: Option<super::procedural_mesh::ProceduralMeshData>,
+        old_csg_data: Option<CsgMeshData>,
+        new_csg_data: Option<CsgMeshData>,
+    },
+
     /// Array operation performed
     ArrayEntity {
         source_id: String,
         created_snapshots: Vec<EntitySnapshot>,
     },
 
+    /// Instanced array created from a mesh entity
+    InstanceArray {
+        source_id: String,
+        snapshot: EntitySnapshot,
+    },
+
     /// Combine operation performed
     CombineMeshes {
         source_snapshots: Vec<EntitySnapshot>,
         result_snapshot: EntitySnapshot,
     },
 
+    /// Scene file merged into the current world
+    MergeScene {
+        created_snapshots: Vec<EntitySnapshot>,
+    },
+
+    /// Clipboard entities pasted into the current world
+    PasteEntities {
+        created_snapshots: Vec<EntitySnapshot>,
+    },
+
+    /// Entities grouped under a new empty (stores the empty and each child's prior parent)
+    GroupEntities {
+        group: EntitySnapshot,
+        children: Vec<GroupedChild>,
+    },
+
+    /// Empty dissolved, its children moved to its parent
+    UngroupEntities {
+        group: EntitySnapshot,
+        children: Vec<GroupedChild>,
+    },
+
+    /// Entity moved in the hierarchy. Indices are positions among the parent's
+    /// children; `None` parent means root (roots are unordered, so index is `None` too).
+    Reparent {
+        entity_id: String,
+        old_parent_id: Option<String>,
+        new_parent_id: Option<String>,
+        old_index: Option<usize>,
+        new_index: Option<usize>,
+    },
+
     /// Joint configuration changed
     JointChange {
         entity_id: String,
@@ -378,6 +303,20 @@ pub enum UndoableAction {
         new_components: Option<GameComponents>,  // None = GameComponents removed
     },
 
+    /// Look-at constraint set, changed, or removed
+    LookAtConstraintChange {
+        entity_id: String,
+        old_constraint: Option<LookAtConstraint>,
+        new_constraint: Option<LookAtConstraint>,
+    },
+
+    /// Path control points changed
+    PathChange {
+        entity_id: String,
+        old_path: PathData,
+        new_path: PathData,
+    },
+
     /// Animation clip configuration changed (keyframe animation)
     AnimationClipChange {
         entity_id: String,
@@ -413,12 +352,28 @@ pub enum UndoableAction {
         new_tilemap: Option<TilemapData>,
     },
 
+    /// Tiles painted, erased or filled in one tilemap layer (stores the dirty region only)
+    TilemapTilesEdit {
+        entity_id: String,
+        layer: usize,
+        rect: TileRect,
+        old_tiles: Vec<Option<u32>>,
+        new_tiles: Vec<Option<u32>>,
+    },
+
     /// Skeleton 2D configuration changed
     SkeletonChange {
         entity_id: String,
         old_skeleton: Option<SkeletonData2d>,
         new_skeleton: Option<SkeletonData2d>,
     },
+
+    /// Several actions recorded as one named step (see `HistoryStack::begin_transaction`).
+    /// Undone in reverse order and redone in original order.
+    Transaction {
+        label: String,
+        actions: Vec<UndoableAction>,
+    },
 }
 
 impl UndoableAction {
@@ -446,32 +401,76 @@ impl UndoableAction {
             UndoableAction::ScriptChange { .. } => "Script Change".to_string(),
             UndoableAction::AudioChange { .. } => "Audio Change".to_string(),
             UndoableAction::ParticleChange { .. } => "Particle Change".to_string(),
+            UndoableAction::ParticleForceFieldChange { .. } => "Particle Force Field Change".to_string(),
             UndoableAction::ShaderChange { .. } => "Shader Effect Change".to_string(),
             UndoableAction::CsgOperation { result_snapshot, .. } => {
                 format!("CSG '{}'", result_snapshot.name)
             }
+            UndoableAction::CsgChain { result_snapshot, .. } => {
+                format!("CSG Chain '{}'", result_snapshot.name)
+            }
             UndoableAction::TerrainChange { .. } => "Terrain Change".to_string(),
+            UndoableAction::TerrainHoleEdit { .. } => "Paint Terrain Holes".to_string(),
             UndoableAction::ExtrudeShape { snapshot } => {
                 format!("Extrude '{}'", snapshot.name)
             }
             UndoableAction::LatheShape { snapshot } => {
                 format!("Lathe '{}'", snapshot.name)
             }
+            UndoableAction::LoftShape { snapshot } => {
+                format!("Loft '{}'", snapshot.name)
+            }
+            UndoableAction::SweepShape { snapshot } => {
+                format!("Sweep '{}'", snapshot.name)
+            }
+            UndoableAction::SubdivideMesh { .. } => "Subdivide Mesh".to_string(),
+            UndoableAction::ApplyTransform { .. } => "Apply Transform".to_string(),
+            UndoableAction::MeshEdit { operation, .. } => operation.clone(),
             UndoableAction::ArrayEntity { created_snapshots, .. } => {
                 format!("Array {} copies", created_snapshots.len())
             }
+            UndoableAction::InstanceArray { snapshot, .. } => {
+                format!("Instance Array '{}'", snapshot.name)
+            }
             UndoableAction::CombineMeshes { result_snapshot, .. } => {
                 format!("Combine '{}'", result_snapshot.name)
             }
+            UndoableAction::MergeScene { created_snapshots } => {
+                format!("Merge Scene ({} entities)", created_snapshots.len())
+            }
+            UndoableAction::PasteEntities { created_snapshots } => {
+                format!("Paste {} entities", created_snapshots.len())
+            }
+            UndoableAction::GroupEntities { children, .. } => {
+                format!("Group {} objects", children.len())
+            }
+            UndoableAction::UngroupEntities { group, .. } => format!("Ungroup '{}'", group.name),
+            UndoableAction::Reparent { .. } => "Reparent".to_string(),
             UndoableAction::JointChange { .. } => "Joint Change".to_string(),
             UndoableAction::GameComponentChange { .. } => "Game Component Change".to_string(),
+            UndoableAction::LookAtConstraintChange { new_constraint, .. } => {
+                if new_constraint.is_some() {
+                    "Set Look At Constraint".to_string()
+                } else {
+                    "Remove Look At Constraint".to_string()
+                }
+            }
+            UndoableAction::PathChange { .. } => "Path Change".to_string(),
             UndoableAction::AnimationClipChange { .. } => "Animation Clip Change".to_string(),
             UndoableAction::ReverbZoneChange { .. } => "Reverb Zone Change".to_string(),
             UndoableAction::SpriteChange { .. } => "Sprite Change".to_string(),
             UndoableAction::Physics2dChange { .. } => "2D Physics Change".to_string(),
             UndoableAction::Joint2dChange { .. } => "2D Joint Change".to_string(),
             UndoableAction::TilemapChange { .. } => "Tilemap Change".to_string(),
+            UndoableAction::TilemapTilesEdit { new_tiles, .. } => {
+                if new_tiles.iter().all(Option::is_none) {
+                    "Erase Tiles".to_string()
+                } else {
+                    "Paint Tiles".to_string()
+                }
+            }
             UndoableAction::SkeletonChange { .. } => "Skeleton 2D Change".to_string(),
+            UndoableAction::Transaction { label, .. } => label.clone(),
         }
     }
 }
@@ -487,6 +486,16 @@ pub struct HistoryStack {
     max_size: usize,
     /// Flag to indicate history changed (for UI update)
     pub dirty: bool,
+    /// Open transaction collecting pushed actions, if any
+    transaction: Option<OpenTransaction>,
+}
+
+/// Actions collected between `begin_transaction` and the matching `end_transaction`.
+struct OpenTransaction {
+    label: String,
+    actions: Vec<UndoableAction>,
+    /// Nesting depth; inner begin/end pairs fold into the outermost transaction.
+    depth: u32,
 }
 
 impl Default for HistoryStack {
@@ -496,6 +505,7 @@ impl Default for HistoryStack {
             redo_stack: Vec::new(),
             max_size: 100,
             dirty: false,
+            transaction: None,
         }
     }
 }
@@ -503,19 +513,69 @@ impl Default for HistoryStack {
 impl HistoryStack {
     /// Push a new action onto the undo stack.
     /// Clears the redo stack (you can't redo after a new action).
+    /// While a transaction is open the action is collected into it instead.
     pub fn push(&mut self, action: UndoableAction) {
-        self.undo_stack.push(action);
         self.redo_stack.clear();
         self.dirty = true;
 
+        if let Some(transaction) = self.transaction.as_mut() {
+            transaction.actions.push(action);
+            return;
+        }
+        self.undo_stack.push(action);
+
         // Enforce max size
         while self.undo_stack.len() > self.max_size {
             self.undo_stack.remove(0);
         }
     }
 
+    /// Start collecting pushed actions into one undo step named `label`.
+    /// Nested calls are folded into the outermost transaction.
+    pub fn begin_transaction(&mut self, label: impl Into<String>) {
+        match self.transaction.as_mut() {
+            Some(transaction) => transaction.depth += 1,
+            None => {
+                self.transaction = Some(OpenTransaction {
+                    label: label.into(),
+                    actions: Vec::new(),
+                    depth: 1,
+                });
+            }
+        }
+    }
+
+    /// Close the innermost open transaction. When the outermost one closes,
+    /// its actions are pushed as a single `Transaction` (nothing if empty).
+    /// Returns false if no transaction was open.
+    pub fn end_transaction(&mut self) -> bool {
+        let Some(transaction) = self.transaction.as_mut() else {
+            return false;
+        };
+        transaction.depth -= 1;
+        if transaction.depth > 0 {
+            return true;
+        }
+
+        let OpenTransaction { label, actions, .. } = self.transaction.take().expect("open transaction");
+        if !actions.is_empty() {
+            self.push(UndoableAction::Transaction { label, actions });
+        }
+        true
+    }
+
+    /// Check if a transaction is currently open.
+    pub fn in_transaction(&self) -> bool {
+        self.transaction.is_some()
+    }
+
     /// Pop the most recent action for undo.
+    /// An open transaction is closed first so its actions are undone together.
     pub fn pop_undo(&mut self) -> Option<UndoableAction> {
+        if let Some(transaction) = self.transaction.as_mut() {
+            transaction.depth = 1;
+            self.end_transaction();
+        }
         let action = self.undo_stack.pop();
         if action.is_some() {
             self.dirty = true;
@@ -579,77 +639,6 @@ impl HistoryStack {
     }
 }
 
-/// Pending undo/redo requests from the bridge.
-#[derive(Default)]
-pub struct PendingHistoryCommands {
-    pub undo_requested: bool,
-    pub redo_requested: bool,
-}
-
-// Global instance for bridge access (WASM is single-threaded)
-use std::cell::RefCell;
-
-thread_local! {
-    static HISTORY_STACK: RefCell<Option<*mut HistoryStack>> = const { RefCell::new(None) };
-    static PENDING_HISTORY: RefCell<PendingHistoryCommands> = const { RefCell::new(PendingHistoryCommands { undo_requested: false, redo_requested: false }) };
-}
-
-/// Register the HistoryStack resource pointer for bridge access.
-pub fn register_history_stack(history: *mut HistoryStack) {
-    HISTORY_STACK.with(|h| {
-        *h.borrow_mut() = Some(history);
-    });
-}
-
-/// Queue an undo request from the bridge layer.
-pub fn queue_undo_from_bridge() -> bool {
-    PENDING_HISTORY.with(|ph| {
-        ph.borrow_mut().undo_requested = true;
-    });
-    true
-}
-
-/// Queue a redo request from the bridge layer.
-pub fn queue_redo_from_bridge() -> bool {
-    PENDING_HISTORY.with(|ph| {
-        ph.borrow_mut().redo_requested = true;
-    });
-    true
-}
-
-/// Take pending undo request (clears it).
-pub fn take_undo_request() -> bool {
-    PENDING_HISTORY.with(|ph| {
-        let requested = ph.borrow().undo_requested;
-        ph.borrow_mut().undo_requested = false;
-        requested
-    })
-}
-
-/// Take pending redo request (clears it).
-pub fn take_redo_request() -> bool {
-    PENDING_HISTORY.with(|ph| {
-        let requested = ph.borrow().redo_requested;
-        ph.borrow_mut().redo_requested = false;
-        requested
-    })
-}
-
-/// Push an action to history from the bridge layer.
-pub fn push_action_from_bridge(action: UndoableAction) -> bool {
-    HISTORY_STACK.with(|h| {
-        if let Some(ptr) = *h.borrow() {
-            // SAFETY: WASM is single-threaded and we control the lifetime
-            unsafe {
-                (*ptr).push(action);
-            }
-            true
-        } else {
-            false
-        }
-    })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -703,4 +692,85 @@ mod tests {
         assert!(history.redo_stack.is_empty(), "push() must clear redo_stack");
         assert_eq!(history.undo_stack.len(), 1);
     }
+
+    fn spawn_action(id: &str) -> UndoableAction {
+        UndoableAction::Spawn {
+            snapshot: EntitySnapshot::new(
+                id.to_string(),
+                EntityType::Cube,
+                id.to_string(),
+                TransformSnapshot::from(&Transform::IDENTITY),
+            ),
+        }
+    }
+
+    #[test]
+    fn transaction_collects_actions_into_one_step() {
+        let mut history = HistoryStack::default();
+        history.push(rename_action(0));
+        history.begin_transaction("Scatter rocks");
+        history.begin_transaction("inner");
+        history.push(spawn_action("a"));
+        history.push(spawn_action("b"));
+        assert!(history.end_transaction());
+        assert!(history.in_transaction());
+        history.push(spawn_action("c"));
+        assert!(history.end_transaction());
+        assert!(!history.in_transaction());
+        assert!(!history.end_transaction());
+
+        assert_eq!(history.undo_description().as_deref(), Some("Scatter rocks"));
+        match history.pop_undo() {
+            Some(UndoableAction::Transaction { label, actions }) => {
+                assert_eq!(label, "Scatter rocks");
+                assert_eq!(actions.len(), 3);
+            }
+            other => panic!("expected Transaction, got {other:?}"),
+        }
+        assert_eq!(history.undo_description().as_deref(), Some("Rename to 'new0'"));
+    }
+
+    #[test]
+    fn empty_transaction_records_nothing() {
+        let mut history = HistoryStack::default();
+        history.begin_transaction("Nothing");
+        assert!(history.end_transaction());
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn undoing_a_transaction_removes_all_spawned_entities() {
+        use crate::core::entity_factory::apply_undo_requests;
+        use crate::core::entity_id::{EntityId, EntityName, EntityVisible};
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HistoryStack>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, apply_undo_requests);
+
+        let mut history = HistoryStack::default();
+        history.begin_transaction("Spawn three");
+        for id in ["a", "b", "c"] {
+            app.world_mut().spawn((
+                EntityId(id.to_string()),
+                EntityName::new(id),
+                EntityVisible::default(),
+                Transform::IDENTITY,
+            ));
+            history.push(spawn_action(id));
+        }
+        history.end_transaction();
+        app.insert_resource(history);
+
+        queue_undo_from_bridge();
+        app.update();
+
+        let remaining = app.world_mut().query::<&EntityId>().iter(app.world()).count();
+        assert_eq!(remaining, 0);
+        let history = app.world().resource::<HistoryStack>();
+        assert!(!history.can_undo());
+        assert_eq!(history.redo_description().as_deref(), Some("Spawn three"));
+    }
 }
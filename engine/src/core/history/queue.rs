@@ -0,0 +1,183 @@
+//! Undo, redo and history group requests queued by the bridge layer.
+
+use bevy::prelude::*;
+use std::cell::RefCell;
+
+use super::{HistoryStack, UndoableAction};
+
+/// A queued `begin_history_group` or `end_history_group` request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryGroupOp {
+    Begin(String),
+    End,
+}
+
+/// Pending undo/redo requests from the bridge.
+#[derive(Default)]
+pub struct PendingHistoryCommands {
+    pub undo_requested: bool,
+    pub redo_requested: bool,
+    /// History group begins and ends, in the order they were sent
+    pub group_ops: Vec<HistoryGroupOp>,
+}
+
+// Global instance for bridge access (WASM is single-threaded)
+
+thread_local! {
+    static HISTORY_STACK: RefCell<Option<*mut HistoryStack>> = const { RefCell::new(None) };
+    static PENDING_HISTORY: RefCell<PendingHistoryCommands> = const { RefCell::new(PendingHistoryCommands { undo_requested: false, redo_requested: false, group_ops: Vec::new() }) };
+}
+
+/// Register the HistoryStack resource pointer for bridge access.
+pub fn register_history_stack(history: *mut HistoryStack) {
+    HISTORY_STACK.with(|h| {
+        *h.borrow_mut() = Some(history);
+    });
+}
+
+/// Queue an undo request from the bridge layer.
+pub fn queue_undo_from_bridge() -> bool {
+    PENDING_HISTORY.with(|ph| {
+        ph.borrow_mut().undo_requested = true;
+    });
+    true
+}
+
+/// Queue a redo request from the bridge layer.
+pub fn queue_redo_from_bridge() -> bool {
+    PENDING_HISTORY.with(|ph| {
+        ph.borrow_mut().redo_requested = true;
+    });
+    true
+}
+
+/// Take pending undo request (clears it).
+pub fn take_undo_request() -> bool {
+    PENDING_HISTORY.with(|ph| {
+        let requested = ph.borrow().undo_requested;
+        ph.borrow_mut().undo_requested = false;
+        requested
+    })
+}
+
+/// Take pending redo request (clears it).
+pub fn take_redo_request() -> bool {
+    PENDING_HISTORY.with(|ph| {
+        let requested = ph.borrow().redo_requested;
+        ph.borrow_mut().redo_requested = false;
+        requested
+    })
+}
+
+/// Queue the start of a named history group from the bridge layer.
+pub fn queue_begin_history_group_from_bridge(label: String) -> bool {
+    PENDING_HISTORY.with(|ph| {
+        ph.borrow_mut().group_ops.push(HistoryGroupOp::Begin(label));
+    });
+    true
+}
+
+/// Queue the end of the current history group from the bridge layer.
+pub fn queue_end_history_group_from_bridge() -> bool {
+    PENDING_HISTORY.with(|ph| {
+        ph.borrow_mut().group_ops.push(HistoryGroupOp::End);
+    });
+    true
+}
+
+/// Number of leading ops to apply at the start of the frame: everything up to
+/// and including the last `Begin`. The ends after it wait for the end of the
+/// frame so actions recorded in between land inside the group.
+fn history_group_split(ops: &[HistoryGroupOp]) -> usize {
+    ops.iter()
+        .rposition(|op| matches!(op, HistoryGroupOp::Begin(_)))
+        .map_or(0, |i| i + 1)
+}
+
+fn apply_history_group_ops(history: &mut HistoryStack, ops: impl IntoIterator<Item = HistoryGroupOp>) {
+    for op in ops {
+        match op {
+            HistoryGroupOp::Begin(label) => history.begin_transaction(label),
+            HistoryGroupOp::End => {
+                if !history.end_transaction() {
+                    tracing::warn!("end_history_group without a matching begin_history_group");
+                }
+            }
+        }
+    }
+}
+
+/// System that applies queued history group ops, in order, up to the last
+/// begin. Runs in `First` so actions recorded later in the same frame land
+/// inside the group.
+pub fn apply_begin_history_groups(mut history: ResMut<HistoryStack>) {
+    let ops: Vec<HistoryGroupOp> = PENDING_HISTORY.with(|ph| {
+        let mut ph = ph.borrow_mut();
+        let split = history_group_split(&ph.group_ops);
+        ph.group_ops.drain(..split).collect()
+    });
+    apply_history_group_ops(&mut history, ops);
+}
+
+/// System that applies the remaining queued history group ends. Runs in `Last`
+/// so actions recorded earlier in the same frame are included.
+pub fn apply_end_history_groups(mut history: ResMut<HistoryStack>) {
+    let ops = PENDING_HISTORY.with(|ph| std::mem::take(&mut ph.borrow_mut().group_ops));
+    apply_history_group_ops(&mut history, ops);
+}
+
+/// Push an action to history from the bridge layer.
+pub fn push_action_from_bridge(action: UndoableAction) -> bool {
+    HISTORY_STACK.with(|h| {
+        if let Some(ptr) = *h.borrow() {
+            // SAFETY: WASM is single-threaded and we control the lifetime
+            unsafe {
+                (*ptr).push(action);
+            }
+            true
+        } else {
+            false
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename_action() -> UndoableAction {
+        UndoableAction::Rename { entity_id: "e".into(), old_name: "old".into(), new_name: "new".into() }
+    }
+
+    #[test]
+    fn history_group_ops_apply_in_send_order() {
+        use HistoryGroupOp::{Begin, End};
+
+        // Close last frame's group, then open a new one for this frame
+        let ops = vec![End, Begin("Scatter rocks".into()), End];
+        let split = history_group_split(&ops);
+        assert_eq!(split, 2);
+
+        let mut history = HistoryStack::default();
+        history.begin_transaction("Previous");
+        history.push(rename_action());
+        apply_history_group_ops(&mut history, ops[..split].to_vec());
+        history.push(rename_action());
+        history.push(rename_action());
+        apply_history_group_ops(&mut history, ops[split..].to_vec());
+
+        assert!(!history.in_transaction());
+        assert_eq!(history.undo_description().as_deref(), Some("Scatter rocks"));
+        history.pop_undo();
+        assert_eq!(history.undo_description().as_deref(), Some("Previous"));
+    }
+
+    #[test]
+    fn history_group_split_leaves_trailing_ends_for_the_end_of_frame() {
+        use HistoryGroupOp::{Begin, End};
+
+        assert_eq!(history_group_split(&[]), 0);
+        assert_eq!(history_group_split(&[End, End]), 0);
+        assert_eq!(history_group_split(&[Begin("a".into()), End, Begin("b".into()), End]), 3);
+    }
+}
@@ -0,0 +1,262 @@
+//! Entity and transform snapshots recorded by undoable actions.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::animation_clip::AnimationClipData;
+use crate::core::asset_manager::AssetRef;
+use crate::core::audio::AudioData;
+use crate::core::csg::CsgMeshData;
+use crate::core::game_camera::GameCameraData;
+use crate::core::game_components::{GameComponents, LookAtConstraint};
+use crate::core::path::PathData;
+use crate::core::lighting::{LightData, LightProbeVolume};
+use crate::core::material::MaterialData;
+use crate::core::particles::{ParticleData, ParticleForceField};
+use crate::core::pending_commands::EntityType;
+use crate::core::physics::{JointData, PhysicsData};
+use crate::core::physics_2d::{PhysicsJoint2d, Physics2dData};
+use crate::core::scripting::ScriptData;
+use crate::core::shader_effects::ShaderEffectData;
+use crate::core::lod::LodData;
+use crate::core::prefab::PrefabInstance;
+use crate::core::skeletal_animation2d::SkeletalAnimation2d;
+use crate::core::skeleton2d::SkeletonData2d;
+use crate::core::sprite::SpriteData;
+use crate::core::terrain::{TerrainData, TerrainMeshData};
+use crate::core::tilemap::TilemapData;
+use crate::core::tileset::TilesetData;
+
+/// Snapshot of transform data for undo/redo.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformSnapshot {
+    pub position: [f32; 3],
+    pub rotation: [f32; 4], // Quaternion
+    pub scale: [f32; 3],
+}
+
+impl From<&Transform> for TransformSnapshot {
+    fn from(t: &Transform) -> Self {
+        Self {
+            position: [t.translation.x, t.translation.y, t.translation.z],
+            rotation: [t.rotation.x, t.rotation.y, t.rotation.z, t.rotation.w],
+            scale: [t.scale.x, t.scale.y, t.scale.z],
+        }
+    }
+}
+
+impl TransformSnapshot {
+    pub fn to_transform(&self) -> Transform {
+        Transform {
+            translation: Vec3::new(self.position[0], self.position[1], self.position[2]),
+            rotation: Quat::from_xyzw(
+                self.rotation[0],
+                self.rotation[1],
+                self.rotation[2],
+                self.rotation[3],
+            ),
+            scale: Vec3::new(self.scale[0], self.scale[1], self.scale[2]),
+        }
+    }
+}
+
+/// Complete snapshot of an entity for perfect restoration.
+/// Stores the original entity_id so it can be reused on restore.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntitySnapshot {
+    /// Original entity ID - reused when restoring to preserve references
+    pub entity_id: String,
+    /// Type of entity (cube, sphere, light, etc.)
+    pub entity_type: EntityType,
+    /// Display name
+    pub name: String,
+    /// Transform state
+    pub transform: TransformSnapshot,
+    /// Parent entity ID (for hierarchy preservation)
+    pub parent_id: Option<String>,
+    /// Visibility state
+    pub visible: bool,
+    /// Material data (for mesh entities)
+    pub material_data: Option<MaterialData>,
+    /// Light data (for light entities)
+    pub light_data: Option<LightData>,
+    /// Light probe volume settings and baked probes (for light probe volumes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub light_probe_volume: Option<LightProbeVolume>,
+    /// Physics data (if entity has physics configured)
+    pub physics_data: Option<PhysicsData>,
+    /// Whether physics is enabled on this entity
+    pub physics_enabled: bool,
+    /// Asset reference (for imported glTF models)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_ref: Option<AssetRef>,
+    /// Script data (if entity has a script)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script_data: Option<ScriptData>,
+    /// Audio data (if entity has audio)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_data: Option<AudioData>,
+    /// Reverb zone data (if entity has reverb zone)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverb_zone_data: Option<crate::core::reverb_zone::ReverbZoneData>,
+    /// Whether reverb zone is enabled on this entity
+    #[serde(default)]
+    pub reverb_zone_enabled: bool,
+    /// Particle data (if entity has particles configured)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub particle_data: Option<ParticleData>,
+    /// Whether particle emission is enabled on this entity
+    #[serde(default)]
+    pub particle_enabled: bool,
+    /// Particle force field (if entity has one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub particle_force_field: Option<ParticleForceField>,
+    /// Shader effect data (if entity has custom shader)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shader_effect_data: Option<ShaderEffectData>,
+    /// CSG mesh vertex/index data (for CsgResult entities, needed for undo restore)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub csg_mesh_data: Option<CsgMeshData>,
+    /// Terrain data (if entity is a terrain)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terrain_data: Option<TerrainData>,
+    /// Terrain mesh (heightmap) data for reconstruction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terrain_mesh_data: Option<TerrainMeshData>,
+    /// Procedural mesh data (for extrude/lathe/combine results)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub procedural_mesh_data: Option<crate::core::procedural_mesh::ProceduralMeshData>,
+    /// Joint data (if entity has a physics joint)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joint_data: Option<JointData>,
+    /// Game components (pre-built behaviors)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_components: Option<GameComponents>,
+    /// Look-at constraint (if entity continuously faces a target)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub look_at_constraint: Option<LookAtConstraint>,
+    /// Path control points (for Path entities)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_data: Option<PathData>,
+    /// Animation clip data (keyframe property animation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation_clip_data: Option<AnimationClipData>,
+    /// Game camera configuration (if entity has a game camera)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_camera_data: Option<GameCameraData>,
+    /// Whether this entity is the active game camera
+    #[serde(default)]
+    pub active_game_camera: bool,
+    /// Sprite data (if entity is a 2D sprite)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprite_data: Option<SpriteData>,
+    /// 2D physics data (if entity has 2D physics configured)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physics2d_data: Option<Physics2dData>,
+    /// Whether 2D physics is enabled on this entity
+    #[serde(default)]
+    pub physics2d_enabled: bool,
+    /// 2D joint data (if entity has a 2D physics joint)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joint2d_data: Option<PhysicsJoint2d>,
+    /// Tilemap data (if entity is a tilemap)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tilemap_data: Option<TilemapData>,
+    /// Whether tilemap rendering is enabled
+    #[serde(default)]
+    pub tilemap_enabled: bool,
+    /// Tileset data, including auto-tile rules (if entity defines a tileset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tileset_data: Option<TilesetData>,
+    /// Skeleton 2D data (if entity has a skeleton)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skeleton2d_data: Option<SkeletonData2d>,
+    /// Whether skeleton 2D is enabled
+    #[serde(default)]
+    pub skeleton2d_enabled: bool,
+    /// Skeletal animations (if entity has skeletal animations)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skeletal_animations: Option<Vec<SkeletalAnimation2d>>,
+    /// LOD configuration (if entity has LOD data)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lod_data: Option<LodData>,
+    /// Per-instance transforms (if entity is an instanced array)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance_transforms: Option<Vec<TransformSnapshot>>,
+    /// Link to the prefab this entity was instantiated from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefab_instance: Option<PrefabInstance>,
+    /// Entity tags, sorted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl EntitySnapshot {
+    /// Create a new snapshot with required fields; all optional fields default to None/false.
+    pub fn new(
+        entity_id: String,
+        entity_type: EntityType,
+        name: String,
+        transform: TransformSnapshot,
+    ) -> Self {
+        Self {
+            entity_id,
+            entity_type,
+            name,
+            transform,
+            parent_id: None,
+            visible: true,
+            material_data: None,
+            light_data: None,
+            light_probe_volume: None,
+            physics_data: None,
+            physics_enabled: false,
+            asset_ref: None,
+            script_data: None,
+            audio_data: None,
+            reverb_zone_data: None,
+            reverb_zone_enabled: false,
+            particle_data: None,
+            particle_enabled: false,
+            particle_force_field: None,
+            shader_effect_data: None,
+            csg_mesh_data: None,
+            terrain_data: None,
+            terrain_mesh_data: None,
+            procedural_mesh_data: None,
+            joint_data: None,
+            game_components: None,
+            look_at_constraint: None,
+            path_data: None,
+            animation_clip_data: None,
+            game_camera_data: None,
+            active_game_camera: false,
+            sprite_data: None,
+            physics2d_data: None,
+            physics2d_enabled: false,
+            joint2d_data: None,
+            tilemap_data: None,
+            tilemap_enabled: false,
+            tileset_data: None,
+            skeleton2d_data: None,
+            skeleton2d_enabled: false,
+            skeletal_animations: None,
+            lod_data: None,
+            instance_transforms: None,
+            prefab_instance: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Hierarchy move of one entity during a group or ungroup.
+#[derive(Debug, Clone)]
+pub struct GroupedChild {
+    pub entity_id: String,
+    pub old_parent_id: Option<String>,
+    pub old_transform: TransformSnapshot,
+    pub new_parent_id: Option<String>,
+    pub new_transform: TransformSnapshot,
+}
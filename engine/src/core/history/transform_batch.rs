@@ -0,0 +1,102 @@
+//! Batched transform updates recorded as a single undo step.
+
+use bevy::prelude::*;
+
+use super::TransformSnapshot;
+use crate::core::entity_id::EntityId;
+use crate::core::pending_commands::TransformUpdate;
+
+/// Apply a batch of transform updates in a single pass over `targets`.
+///
+/// Returns `(entity_id, old, new)` for every entity that was touched, ready to be
+/// recorded as one `MultiTransformChange`. If an entity appears more than once
+/// in the batch, the last update wins.
+pub fn apply_transform_batch<'a>(
+    updates: &[TransformUpdate],
+    targets: impl Iterator<Item = (&'a EntityId, Mut<'a, Transform>)>,
+) -> Vec<(String, TransformSnapshot, TransformSnapshot)> {
+    let by_id: std::collections::HashMap<&str, &TransformUpdate> = updates
+        .iter()
+        .map(|u| (u.entity_id.as_str(), u))
+        .collect();
+
+    let mut changes = Vec::with_capacity(by_id.len());
+    for (entity_id, mut transform) in targets {
+        let Some(update) = by_id.get(entity_id.0.as_str()) else {
+            continue;
+        };
+        let old = TransformSnapshot::from(&*transform);
+        if let Some(pos) = update.position {
+            transform.translation = pos;
+        }
+        if let Some(rot) = update.rotation {
+            transform.rotation = rot;
+        }
+        if let Some(scale) = update.scale {
+            transform.scale = scale;
+        }
+        changes.push((entity_id.0.clone(), old, TransformSnapshot::from(&*transform)));
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::history::{HistoryStack, UndoableAction};
+
+    #[test]
+    fn transform_batch_moves_all_entities_with_one_history_entry() {
+        let mut world = World::new();
+        for i in 0..100 {
+            world.spawn((EntityId::new(format!("e{i}")), Transform::default()));
+        }
+        let updates: Vec<TransformUpdate> = (0..100)
+            .map(|i| TransformUpdate {
+                entity_id: format!("e{i}"),
+                position: Some(Vec3::new(i as f32, 1.0, 0.0)),
+                rotation: None,
+                scale: None,
+            })
+            .collect();
+
+        let mut history = HistoryStack::default();
+        let mut query = world.query::<(&EntityId, &mut Transform)>();
+        let transforms = apply_transform_batch(&updates, query.iter_mut(&mut world));
+        history.push(UndoableAction::MultiTransformChange { transforms });
+
+        for (eid, transform) in world.query::<(&EntityId, &Transform)>().iter(&world) {
+            let i: f32 = eid.0[1..].parse().expect("numeric suffix");
+            assert_eq!(transform.translation, Vec3::new(i, 1.0, 0.0));
+        }
+        assert_eq!(history.undo_stack.len(), 1, "whole batch must be one history entry");
+        match &history.undo_stack[0] {
+            UndoableAction::MultiTransformChange { transforms } => assert_eq!(transforms.len(), 100),
+            other => panic!("unexpected action: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn transform_batch_leaves_unlisted_fields_and_entities_untouched() {
+        let mut world = World::new();
+        let scaled = Transform::from_scale(Vec3::splat(2.0));
+        world.spawn((EntityId::new("moved"), scaled));
+        world.spawn((EntityId::new("other"), scaled));
+        let updates = vec![TransformUpdate {
+            entity_id: "moved".into(),
+            position: Some(Vec3::X),
+            rotation: None,
+            scale: None,
+        }];
+
+        let mut query = world.query::<(&EntityId, &mut Transform)>();
+        let transforms = apply_transform_batch(&updates, query.iter_mut(&mut world));
+
+        assert_eq!(transforms.len(), 1);
+        let (id, old, new) = &transforms[0];
+        assert_eq!(id, "moved");
+        assert_eq!(old.position, [0.0, 0.0, 0.0]);
+        assert_eq!(new.position, [1.0, 0.0, 0.0]);
+        assert_eq!(new.scale, [2.0, 2.0, 2.0]);
+    }
+}
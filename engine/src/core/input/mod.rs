@@ -0,0 +1,622 @@
+//! Input mapping system for Play mode.
+//!
+//! Maps browser keyboard/mouse events (via Bevy's ButtonInput), connected
+//! gamepads, and on-screen touch zones to named game actions. Provides
+//! configurable presets (FPS, Platformer, TopDown, Racing, plus touch variants),
+//! a stack of input contexts that scopes which actions are live (e.g. menu vs.
+//! gameplay), and per-frame `InputState` that future scripting systems can query.
+
+use bevy::input::touch::{Touch, Touches};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use super::engine_mode::{PlaySystemSet, in_play_mode};
+
+mod presets;
+
+pub use presets::*;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A screen region in normalized 0..1 coordinates (origin top-left, y down),
+/// so touch layouts scale to any canvas size.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScreenRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ScreenRect {
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Whether a normalized screen point lies inside the rect.
+    pub fn contains(&self, point: Vec2) -> bool {
+        point.x >= self.x && point.x <= self.x + self.width
+            && point.y >= self.y && point.y <= self.y + self.height
+    }
+
+    /// Sub-region given as fractions of this rect's width and height.
+    pub fn sub(&self, x0: f32, y0: f32, x1: f32, y1: f32) -> Self {
+        Self::new(
+            self.x + self.width * x0,
+            self.y + self.height * y0,
+            self.width * (x1 - x0),
+            self.height * (y1 - y0),
+        )
+    }
+}
+
+// Regions come from JSON and are never NaN, so bitwise equality is total.
+impl Eq for ScreenRect {}
+
+impl Hash for ScreenRect {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for v in [self.x, self.y, self.width, self.height] {
+            v.to_bits().hash(state);
+        }
+    }
+}
+
+/// An input source: a keyboard key or mouse button identified by browser event.code string,
+/// a gamepad button/axis identified by its Bevy name (e.g. "South", "LeftStickX"), or an
+/// on-screen touch zone that counts as pressed while any touch is inside it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum InputSource {
+    Key(String),
+    MouseButton(String),
+    GamepadButton(String),
+    GamepadAxis(String),
+    TouchZone { region: ScreenRect },
+}
+
+impl InputSource {
+    /// Parse a binding string: "Left"/"Right"/"Middle" are mouse buttons,
+    /// "Gamepad:<button>" and "GamepadAxis:<axis>" are gamepad inputs, and
+    /// anything else is a key code.
+    pub fn parse(source: &str) -> Self {
+        if let Some(axis) = source.strip_prefix("GamepadAxis:") {
+            Self::GamepadAxis(axis.to_string())
+        } else if let Some(button) = source.strip_prefix("Gamepad:") {
+            Self::GamepadButton(button.to_string())
+        } else if mouse_button_from_str(source).is_some() {
+            Self::MouseButton(source.to_string())
+        } else {
+            Self::Key(source.to_string())
+        }
+    }
+}
+
+/// Whether an action is a simple digital button or a composite axis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ActionType {
+    Digital,
+    Axis {
+        positive: Vec<InputSource>,
+        negative: Vec<InputSource>,
+    },
+}
+
+/// Definition of a single named action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionDef {
+    pub name: String,
+    pub action_type: ActionType,
+    /// Sources for Digital actions (ignored for Axis).
+    #[serde(default)]
+    pub sources: Vec<InputSource>,
+    /// Dead-zone for axis values (values below this snap to 0).
+    #[serde(default = "default_dead_zone")]
+    pub dead_zone: f32,
+}
+
+fn default_dead_zone() -> f32 { 0.1 }
+
+/// Per-frame state for one action.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionValue {
+    pub pressed: bool,
+    pub just_pressed: bool,
+    pub just_released: bool,
+    pub axis_value: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// The input binding configuration. Maps action name -> definition.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputMap {
+    pub actions: HashMap<String, ActionDef>,
+    /// Which preset produced the current bindings (None = custom).
+    pub preset: Option<String>,
+}
+
+/// Per-frame evaluated input state. Updated by `capture_input` in PlaySystemSet.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InputState {
+    pub actions: HashMap<String, ActionValue>,
+}
+
+/// A named set of actions that are only live while the context is active.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputContext {
+    pub actions: HashSet<String>,
+    /// Active regardless of its position on the stack.
+    #[serde(default)]
+    pub always_active: bool,
+}
+
+/// Stack of input contexts. An action assigned to one or more contexts is
+/// only reported while one of them is on top of the stack or always active;
+/// actions outside every context are always reported.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputContextStack {
+    pub contexts: HashMap<String, InputContext>,
+    pub stack: Vec<String>,
+}
+
+impl InputContextStack {
+    /// Define (or redefine) a context's action set.
+    pub fn define_context(&mut self, name: &str, context: InputContext) {
+        self.contexts.insert(name.to_string(), context);
+    }
+
+    pub fn push_context(&mut self, name: &str) {
+        self.stack.push(name.to_string());
+    }
+
+    pub fn pop_context(&mut self) -> Option<String> {
+        self.stack.pop()
+    }
+
+    /// The context on top of the stack, if any.
+    pub fn active(&self) -> Option<&str> {
+        self.stack.last().map(String::as_str)
+    }
+
+    pub fn is_action_enabled(&self, action: &str) -> bool {
+        let mut assigned = false;
+        for (name, context) in &self.contexts {
+            if context.actions.contains(action) {
+                if context.always_active || self.active() == Some(name.as_str()) {
+                    return true;
+                }
+                assigned = true;
+            }
+        }
+        !assigned
+    }
+}
+
+impl InputState {
+    pub fn is_action_active(&self, name: &str) -> bool {
+        self.actions.get(name).map_or(false, |v| v.pressed)
+    }
+
+    pub fn is_action_just_pressed(&self, name: &str) -> bool {
+        self.actions.get(name).map_or(false, |v| v.just_pressed)
+    }
+
+    pub fn get_axis(&self, name: &str) -> f32 {
+        self.actions.get(name).map_or(0.0, |v| v.axis_value)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Key mapping
+// ---------------------------------------------------------------------------
+
+/// Map a browser `event.code` string to a Bevy `KeyCode`.
+pub fn keycode_from_str(code: &str) -> Option<KeyCode> {
+    Some(match code {
+        // Letters
+        "KeyA" => KeyCode::KeyA,
+        "KeyB" => KeyCode::KeyB,
+        "KeyC" => KeyCode::KeyC,
+        "KeyD" => KeyCode::KeyD,
+        "KeyE" => KeyCode::KeyE,
+        "KeyF" => KeyCode::KeyF,
+        "KeyG" => KeyCode::KeyG,
+        "KeyH" => KeyCode::KeyH,
+        "KeyI" => KeyCode::KeyI,
+        "KeyJ" => KeyCode::KeyJ,
+        "KeyK" => KeyCode::KeyK,
+        "KeyL" => KeyCode::KeyL,
+        "KeyM" => KeyCode::KeyM,
+        "KeyN" => KeyCode::KeyN,
+        "KeyO" => KeyCode::KeyO,
+        "KeyP" => KeyCode::KeyP,
+        "KeyQ" => KeyCode::KeyQ,
+        "KeyR" => KeyCode::KeyR,
+        "KeyS" => KeyCode::KeyS,
+        "KeyT" => KeyCode::KeyT,
+        "KeyU" => KeyCode::KeyU,
+        "KeyV" => KeyCode::KeyV,
+        "KeyW" => KeyCode::KeyW,
+        "KeyX" => KeyCode::KeyX,
+        "KeyY" => KeyCode::KeyY,
+        "KeyZ" => KeyCode::KeyZ,
+        // Numbers
+        "Digit0" => KeyCode::Digit0,
+        "Digit1" => KeyCode::Digit1,
+        "Digit2" => KeyCode::Digit2,
+        "Digit3" => KeyCode::Digit3,
+        "Digit4" => KeyCode::Digit4,
+        "Digit5" => KeyCode::Digit5,
+        "Digit6" => KeyCode::Digit6,
+        "Digit7" => KeyCode::Digit7,
+        "Digit8" => KeyCode::Digit8,
+        "Digit9" => KeyCode::Digit9,
+        // Arrows
+        "ArrowUp" => KeyCode::ArrowUp,
+        "ArrowDown" => KeyCode::ArrowDown,
+        "ArrowLeft" => KeyCode::ArrowLeft,
+        "ArrowRight" => KeyCode::ArrowRight,
+        // Modifiers / common
+        "Space" => KeyCode::Space,
+        "ShiftLeft" => KeyCode::ShiftLeft,
+        "ShiftRight" => KeyCode::ShiftRight,
+        "ControlLeft" => KeyCode::ControlLeft,
+        "ControlRight" => KeyCode::ControlRight,
+        "AltLeft" => KeyCode::AltLeft,
+        "AltRight" => KeyCode::AltRight,
+        "Tab" => KeyCode::Tab,
+        "Escape" => KeyCode::Escape,
+        "Enter" => KeyCode::Enter,
+        "Backspace" => KeyCode::Backspace,
+        _ => return None,
+    })
+}
+
+/// Map a browser mouse button name to a Bevy `MouseButton`.
+fn mouse_button_from_str(name: &str) -> Option<MouseButton> {
+    match name {
+        "Left" => Some(MouseButton::Left),
+        "Right" => Some(MouseButton::Right),
+        "Middle" => Some(MouseButton::Middle),
+        _ => None,
+    }
+}
+
+/// Map a gamepad button name to a Bevy `GamepadButton`.
+fn gamepad_button_from_str(name: &str) -> Option<GamepadButton> {
+    Some(match name {
+        "South" => GamepadButton::South,
+        "East" => GamepadButton::East,
+        "North" => GamepadButton::North,
+        "West" => GamepadButton::West,
+        "C" => GamepadButton::C,
+        "Z" => GamepadButton::Z,
+        "LeftTrigger" => GamepadButton::LeftTrigger,
+        "LeftTrigger2" => GamepadButton::LeftTrigger2,
+        "RightTrigger" => GamepadButton::RightTrigger,
+        "RightTrigger2" => GamepadButton::RightTrigger2,
+        "Select" => GamepadButton::Select,
+        "Start" => GamepadButton::Start,
+        "Mode" => GamepadButton::Mode,
+        "LeftThumb" => GamepadButton::LeftThumb,
+        "RightThumb" => GamepadButton::RightThumb,
+        "DPadUp" => GamepadButton::DPadUp,
+        "DPadDown" => GamepadButton::DPadDown,
+        "DPadLeft" => GamepadButton::DPadLeft,
+        "DPadRight" => GamepadButton::DPadRight,
+        _ => return None,
+    })
+}
+
+/// Map a gamepad axis name to a Bevy `GamepadAxis`.
+fn gamepad_axis_from_str(name: &str) -> Option<GamepadAxis> {
+    Some(match name {
+        "LeftStickX" => GamepadAxis::LeftStickX,
+        "LeftStickY" => GamepadAxis::LeftStickY,
+        "LeftZ" => GamepadAxis::LeftZ,
+        "RightStickX" => GamepadAxis::RightStickX,
+        "RightStickY" => GamepadAxis::RightStickY,
+        "RightZ" => GamepadAxis::RightZ,
+        _ => return None,
+    })
+}
+
+/// Gamepad axes count as pressed (for Digital actions) past this magnitude.
+const GAMEPAD_AXIS_PRESS_THRESHOLD: f32 = 0.5;
+
+/// Snapshot of every input device `capture_input` reads from.
+struct InputDevices<'a> {
+    keys: &'a ButtonInput<KeyCode>,
+    mouse: &'a ButtonInput<MouseButton>,
+    gamepads: Vec<&'a Gamepad>,
+    /// Normalized positions of active, just-started, and just-ended touches.
+    touches: Vec<Vec2>,
+    touches_started: Vec<Vec2>,
+    touches_ended: Vec<Vec2>,
+}
+
+impl InputDevices<'_> {
+    /// Signed analog value of a gamepad axis: the largest deflection across gamepads.
+    fn gamepad_axis(&self, axis: GamepadAxis) -> f32 {
+        self.gamepads
+            .iter()
+            .filter_map(|pad| pad.get(axis))
+            .fold(0.0, |best: f32, v| if v.abs() > best.abs() { v } else { best })
+    }
+
+    /// Check if an `InputSource` is currently pressed.
+    fn pressed(&self, source: &InputSource) -> bool {
+        match source {
+            InputSource::Key(code) => {
+                keycode_from_str(code).is_some_and(|kc| self.keys.pressed(kc))
+            }
+            InputSource::MouseButton(name) => {
+                mouse_button_from_str(name).is_some_and(|mb| self.mouse.pressed(mb))
+            }
+            InputSource::GamepadButton(name) => gamepad_button_from_str(name)
+                .is_some_and(|b| self.gamepads.iter().any(|pad| pad.pressed(b))),
+            InputSource::GamepadAxis(name) => gamepad_axis_from_str(name)
+                .is_some_and(|a| self.gamepad_axis(a).abs() > GAMEPAD_AXIS_PRESS_THRESHOLD),
+            InputSource::TouchZone { region } => self.touches.iter().any(|&t| region.contains(t)),
+        }
+    }
+
+    /// Check if an `InputSource` was just pressed this frame. Gamepad axes
+    /// have no press history and never report just-pressed.
+    fn just_pressed(&self, source: &InputSource) -> bool {
+        match source {
+            InputSource::Key(code) => {
+                keycode_from_str(code).is_some_and(|kc| self.keys.just_pressed(kc))
+            }
+            InputSource::MouseButton(name) => {
+                mouse_button_from_str(name).is_some_and(|mb| self.mouse.just_pressed(mb))
+            }
+            InputSource::GamepadButton(name) => gamepad_button_from_str(name)
+                .is_some_and(|b| self.gamepads.iter().any(|pad| pad.just_pressed(b))),
+            InputSource::GamepadAxis(_) => false,
+            InputSource::TouchZone { region } => self.touches_started.iter().any(|&t| region.contains(t)),
+        }
+    }
+
+    /// Check if an `InputSource` was just released this frame.
+    fn just_released(&self, source: &InputSource) -> bool {
+        match source {
+            InputSource::Key(code) => {
+                keycode_from_str(code).is_some_and(|kc| self.keys.just_released(kc))
+            }
+            InputSource::MouseButton(name) => {
+                mouse_button_from_str(name).is_some_and(|mb| self.mouse.just_released(mb))
+            }
+            InputSource::GamepadButton(name) => gamepad_button_from_str(name)
+                .is_some_and(|b| self.gamepads.iter().any(|pad| pad.just_released(b))),
+            InputSource::GamepadAxis(_) => false,
+            InputSource::TouchZone { region } => self.touches_ended.iter().any(|&t| region.contains(t)),
+        }
+    }
+
+    /// Contribution of a list of axis sources in [-1, 1]: 1.0 when any key or
+    /// button is held, or the strongest analog reading if that is larger.
+    /// Analog triggers report their partial pull.
+    fn axis_contribution(&self, sources: &[InputSource]) -> f32 {
+        sources.iter().fold(0.0, |best: f32, source| {
+            let value = match source {
+                InputSource::GamepadAxis(name) => {
+                    gamepad_axis_from_str(name).map_or(0.0, |a| self.gamepad_axis(a))
+                }
+                InputSource::GamepadButton(name) => gamepad_button_from_str(name).map_or(0.0, |b| {
+                    let analog = self.gamepads.iter().filter_map(|pad| pad.get(b)).fold(0.0, f32::max);
+                    if self.pressed(source) { 1.0 } else { analog }
+                }),
+                _ => if self.pressed(source) { 1.0 } else { 0.0 },
+            };
+            if value.abs() > best.abs() { value } else { best }
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Bevy system that reads keyboard/mouse/gamepad/touch state and evaluates InputMap → InputState.
+/// Touch positions are normalized against the primary window so zones scale with the canvas.
+/// Runs in PlaySystemSet (only during active Play mode).
+pub fn capture_input(
+    input_map: Res<InputMap>,
+    mut input_state: ResMut<InputState>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    touches: Res<Touches>,
+    window: Query<&Window, With<PrimaryWindow>>,
+) {
+    input_state.actions.clear();
+    let size = window.single().map(|w| Vec2::new(w.width(), w.height())).ok().filter(|s| s.min_element() > 0.0);
+    let normalize = |touch: &Touch| size.map(|size| touch.position() / size);
+    let devices = InputDevices {
+        keys: &keys,
+        mouse: &mouse,
+        gamepads: gamepads.iter().collect(),
+        touches: touches.iter().filter_map(normalize).collect(),
+        touches_started: touches.iter_just_pressed().filter_map(normalize).collect(),
+        touches_ended: touches.iter_just_released().filter_map(normalize).collect(),
+    };
+
+    for (action_name, def) in &input_map.actions {
+        let value = match &def.action_type {
+            ActionType::Digital => {
+                let pressed = def.sources.iter().any(|s| devices.pressed(s));
+                let just_pressed = def.sources.iter().any(|s| devices.just_pressed(s));
+                let just_released = def.sources.iter().any(|s| devices.just_released(s));
+                ActionValue {
+                    pressed,
+                    just_pressed,
+                    just_released,
+                    axis_value: if pressed { 1.0 } else { 0.0 },
+                }
+            }
+            ActionType::Axis { positive, negative } => {
+                // Opposing digital inputs cancel out; analog sources add their deflection.
+                let raw = (devices.axis_contribution(positive) - devices.axis_contribution(negative))
+                    .clamp(-1.0, 1.0);
+                let axis_value: f32 = if raw.abs() < def.dead_zone { 0.0 } else { raw };
+                let pressed = axis_value.abs() > 0.0;
+                let just_pressed = positive.iter().chain(negative).any(|s| devices.just_pressed(s));
+                let just_released = positive.iter().chain(negative).any(|s| devices.just_released(s));
+                ActionValue { pressed, just_pressed, just_released, axis_value }
+            }
+        };
+
+        input_state.actions.insert(action_name.clone(), value);
+    }
+}
+
+/// Bevy system that drops actions whose input context is not active.
+/// Runs right after `capture_input`.
+pub fn apply_input_contexts(
+    contexts: Res<InputContextStack>,
+    mut input_state: ResMut<InputState>,
+) {
+    if contexts.contexts.is_empty() {
+        return;
+    }
+    input_state.actions.retain(|name, _| contexts.is_action_enabled(name));
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Plugin that registers input resources and the capture system.
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputMap>()
+            .init_resource::<InputState>()
+            .init_resource::<InputContextStack>()
+            .configure_sets(Update, PlaySystemSet.run_if(in_play_mode))
+            .add_systems(Update, (capture_input, apply_input_contexts).chain().in_set(PlaySystemSet));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_app(input_map: InputMap) -> App {
+        let mut app = App::new();
+        app.insert_resource(input_map)
+            .init_resource::<InputState>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Touches>()
+            .init_resource::<InputContextStack>()
+            .add_message::<TouchInput>()
+            .add_systems(Update, (bevy::input::touch::touch_screen_input_system, capture_input, apply_input_contexts).chain());
+        app
+    }
+
+    #[test]
+    fn parse_maps_gamepad_sources() {
+        assert_eq!(InputSource::parse("Gamepad:South"), InputSource::GamepadButton("South".into()));
+        assert_eq!(InputSource::parse("GamepadAxis:LeftStickX"), InputSource::GamepadAxis("LeftStickX".into()));
+        assert_eq!(InputSource::parse("Left"), InputSource::MouseButton("Left".into()));
+        assert_eq!(InputSource::parse("KeyW"), InputSource::Key("KeyW".into()));
+    }
+
+    #[test]
+    fn gamepad_stick_drives_axis_past_dead_zone() {
+        let mut input_map = InputMap::default();
+        input_map.actions.insert("move_right".into(), ActionDef {
+            name: "move_right".into(),
+            action_type: ActionType::Axis {
+                positive: vec![InputSource::GamepadAxis("LeftStickX".into())],
+                negative: vec![InputSource::Key("KeyA".into())],
+            },
+            sources: vec![],
+            dead_zone: 0.2,
+        });
+        let mut app = input_app(input_map);
+        let mut gamepad = Gamepad::default();
+        gamepad.analog_mut().set(GamepadAxis::LeftStickX, 0.1);
+        let pad = app.world_mut().spawn(gamepad).id();
+
+        app.update();
+        assert_eq!(app.world().resource::<InputState>().get_axis("move_right"), 0.0);
+
+        app.world_mut().get_mut::<Gamepad>(pad).expect("gamepad").analog_mut().set(GamepadAxis::LeftStickX, -0.6);
+        app.update();
+        let state = app.world().resource::<InputState>();
+        assert_eq!(state.get_axis("move_right"), -0.6);
+        assert!(state.is_action_active("move_right"));
+    }
+
+    #[test]
+    fn touch_in_left_half_of_stick_steers_left() {
+        let mut app = input_app(InputPreset::PlatformerTouch.default_bindings());
+        let window = app.world_mut().spawn((
+            Window { resolution: bevy::window::WindowResolution::new(800, 600), ..default() },
+            PrimaryWindow,
+        )).id();
+
+        // (80, 500) is (0.1, 0.83) normalized: the left half of the stick.
+        app.world_mut().write_message(TouchInput {
+            phase: bevy::input::touch::TouchPhase::Started,
+            position: Vec2::new(80.0, 500.0),
+            window,
+            force: None,
+            id: 0,
+        });
+        app.update();
+
+        let state = app.world().resource::<InputState>();
+        assert_eq!(state.get_axis("move_horizontal"), -1.0);
+        assert!(state.is_action_just_pressed("move_horizontal"));
+        assert!(!state.is_action_active("jump"));
+    }
+
+    #[test]
+    fn action_in_popped_context_stops_producing_input() {
+        let mut app = input_app(InputPreset::Platformer.default_bindings());
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        {
+            let mut contexts = app.world_mut().resource_mut::<InputContextStack>();
+            contexts.define_context("gameplay", InputContext {
+                actions: ["jump".to_string()].into(),
+                always_active: false,
+            });
+            contexts.define_context("menu", InputContext::default());
+            contexts.push_context("gameplay");
+        }
+        app.update();
+        assert!(app.world().resource::<InputState>().is_action_active("jump"));
+
+        // A menu on top silences gameplay actions; unassigned actions still report.
+        app.world_mut().resource_mut::<InputContextStack>().push_context("menu");
+        app.update();
+        let state = app.world().resource::<InputState>();
+        assert!(!state.is_action_active("jump"));
+        assert!(state.actions.contains_key("attack"));
+
+        // Popping back past gameplay leaves jump inactive too.
+        let mut contexts = app.world_mut().resource_mut::<InputContextStack>();
+        contexts.pop_context();
+        contexts.pop_context();
+        app.update();
+        assert!(!app.world().resource::<InputState>().is_action_active("jump"));
+    }
+}
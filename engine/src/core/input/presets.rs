@@ -0,0 +1,199 @@
+//! Built-in input presets and their touch layouts.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{ActionDef, ActionType, InputMap, InputSource, ScreenRect};
+
+/// Built-in input preset names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputPreset {
+    FPS,
+    Platformer,
+    TopDown,
+    Racing,
+    /// Platformer bindings plus an on-screen stick and buttons.
+    PlatformerTouch,
+    /// Top-down bindings plus an on-screen 8-way stick and buttons.
+    TopDownTouch,
+}
+
+/// Touch layout shared by the touch presets: a stick in the lower-left
+/// corner and two buttons in the lower-right.
+const TOUCH_STICK: ScreenRect = ScreenRect::new(0.0, 0.5, 0.4, 0.5);
+const TOUCH_BUTTON_PRIMARY: ScreenRect = ScreenRect::new(0.8, 0.7, 0.2, 0.3);
+const TOUCH_BUTTON_SECONDARY: ScreenRect = ScreenRect::new(0.6, 0.7, 0.2, 0.3);
+
+impl InputPreset {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "fps" => Some(Self::FPS),
+            "platformer" => Some(Self::Platformer),
+            "topdown" | "top_down" => Some(Self::TopDown),
+            "racing" => Some(Self::Racing),
+            "platformer_touch" => Some(Self::PlatformerTouch),
+            "topdown_touch" | "top_down_touch" => Some(Self::TopDownTouch),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FPS => "fps",
+            Self::Platformer => "platformer",
+            Self::TopDown => "topdown",
+            Self::Racing => "racing",
+            Self::PlatformerTouch => "platformer_touch",
+            Self::TopDownTouch => "topdown_touch",
+        }
+    }
+
+    pub fn default_bindings(&self) -> InputMap {
+        let mut map = InputMap {
+            actions: HashMap::new(),
+            preset: Some(self.as_str().to_string()),
+        };
+
+        match self {
+            Self::FPS => {
+                // Movement axes
+                map.actions.insert("move_forward".into(), ActionDef {
+                    name: "move_forward".into(),
+                    action_type: ActionType::Axis {
+                        positive: vec![InputSource::Key("KeyW".into())],
+                        negative: vec![InputSource::Key("KeyS".into())],
+                    },
+                    sources: vec![],
+                    dead_zone: 0.1,
+                });
+                map.actions.insert("move_right".into(), ActionDef {
+                    name: "move_right".into(),
+                    action_type: ActionType::Axis {
+                        positive: vec![InputSource::Key("KeyD".into())],
+                        negative: vec![InputSource::Key("KeyA".into())],
+                    },
+                    sources: vec![],
+                    dead_zone: 0.1,
+                });
+                // Digital actions
+                map.actions.insert("jump".into(), digital("jump", vec!["Space"]));
+                map.actions.insert("sprint".into(), digital("sprint", vec!["ShiftLeft"]));
+                map.actions.insert("crouch".into(), digital("crouch", vec!["ControlLeft"]));
+                map.actions.insert("interact".into(), digital("interact", vec!["KeyE"]));
+                map.actions.insert("fire".into(), ActionDef {
+                    name: "fire".into(),
+                    action_type: ActionType::Digital,
+                    sources: vec![InputSource::MouseButton("Left".into())],
+                    dead_zone: 0.1,
+                });
+                map.actions.insert("aim".into(), ActionDef {
+                    name: "aim".into(),
+                    action_type: ActionType::Digital,
+                    sources: vec![InputSource::MouseButton("Right".into())],
+                    dead_zone: 0.1,
+                });
+            }
+            Self::Platformer => {
+                map.actions.insert("move_horizontal".into(), ActionDef {
+                    name: "move_horizontal".into(),
+                    action_type: ActionType::Axis {
+                        positive: vec![InputSource::Key("KeyD".into()), InputSource::Key("ArrowRight".into())],
+                        negative: vec![InputSource::Key("KeyA".into()), InputSource::Key("ArrowLeft".into())],
+                    },
+                    sources: vec![],
+                    dead_zone: 0.1,
+                });
+                map.actions.insert("jump".into(), digital("jump", vec!["Space", "ArrowUp", "KeyW"]));
+                map.actions.insert("crouch".into(), digital("crouch", vec!["ArrowDown", "KeyS"]));
+                map.actions.insert("attack".into(), digital("attack", vec!["KeyZ", "KeyJ"]));
+                map.actions.insert("special".into(), digital("special", vec!["KeyX", "KeyK"]));
+            }
+            Self::TopDown => {
+                map.actions.insert("move_vertical".into(), ActionDef {
+                    name: "move_vertical".into(),
+                    action_type: ActionType::Axis {
+                        positive: vec![InputSource::Key("KeyW".into()), InputSource::Key("ArrowUp".into())],
+                        negative: vec![InputSource::Key("KeyS".into()), InputSource::Key("ArrowDown".into())],
+                    },
+                    sources: vec![],
+                    dead_zone: 0.1,
+                });
+                map.actions.insert("move_horizontal".into(), ActionDef {
+                    name: "move_horizontal".into(),
+                    action_type: ActionType::Axis {
+                        positive: vec![InputSource::Key("KeyD".into()), InputSource::Key("ArrowRight".into())],
+                        negative: vec![InputSource::Key("KeyA".into()), InputSource::Key("ArrowLeft".into())],
+                    },
+                    sources: vec![],
+                    dead_zone: 0.1,
+                });
+                map.actions.insert("action1".into(), digital("action1", vec!["Space"]));
+                map.actions.insert("action2".into(), digital("action2", vec!["KeyE"]));
+                map.actions.insert("fire".into(), ActionDef {
+                    name: "fire".into(),
+                    action_type: ActionType::Digital,
+                    sources: vec![InputSource::MouseButton("Left".into())],
+                    dead_zone: 0.1,
+                });
+            }
+            Self::Racing => {
+                map.actions.insert("throttle".into(), digital("throttle", vec!["KeyW", "ArrowUp"]));
+                map.actions.insert("brake".into(), digital("brake", vec!["KeyS", "ArrowDown"]));
+                map.actions.insert("steer".into(), ActionDef {
+                    name: "steer".into(),
+                    action_type: ActionType::Axis {
+                        positive: vec![InputSource::Key("KeyD".into()), InputSource::Key("ArrowRight".into())],
+                        negative: vec![InputSource::Key("KeyA".into()), InputSource::Key("ArrowLeft".into())],
+                    },
+                    sources: vec![],
+                    dead_zone: 0.1,
+                });
+                map.actions.insert("nitro".into(), digital("nitro", vec!["ShiftLeft", "Space"]));
+                map.actions.insert("reset".into(), digital("reset", vec!["KeyR"]));
+            }
+            Self::PlatformerTouch => {
+                map.actions = Self::Platformer.default_bindings().actions;
+                // Stick halves steer left/right.
+                add_touch_axis(&mut map, "move_horizontal", TOUCH_STICK.sub(0.5, 0.0, 1.0, 1.0), TOUCH_STICK.sub(0.0, 0.0, 0.5, 1.0));
+                add_touch_button(&mut map, "jump", TOUCH_BUTTON_PRIMARY);
+                add_touch_button(&mut map, "attack", TOUCH_BUTTON_SECONDARY);
+            }
+            Self::TopDownTouch => {
+                map.actions = Self::TopDown.default_bindings().actions;
+                // Outer thirds of the stick act as an 8-way pad; the center is neutral.
+                let third = 1.0 / 3.0;
+                add_touch_axis(&mut map, "move_horizontal", TOUCH_STICK.sub(1.0 - third, 0.0, 1.0, 1.0), TOUCH_STICK.sub(0.0, 0.0, third, 1.0));
+                add_touch_axis(&mut map, "move_vertical", TOUCH_STICK.sub(0.0, 0.0, 1.0, third), TOUCH_STICK.sub(0.0, 1.0 - third, 1.0, 1.0));
+                add_touch_button(&mut map, "action1", TOUCH_BUTTON_PRIMARY);
+                add_touch_button(&mut map, "action2", TOUCH_BUTTON_SECONDARY);
+            }
+        }
+
+        map
+    }
+}
+
+/// Add touch zones to an existing Axis action.
+fn add_touch_axis(map: &mut InputMap, action: &str, positive_zone: ScreenRect, negative_zone: ScreenRect) {
+    if let Some(ActionDef { action_type: ActionType::Axis { positive, negative }, .. }) = map.actions.get_mut(action) {
+        positive.push(InputSource::TouchZone { region: positive_zone });
+        negative.push(InputSource::TouchZone { region: negative_zone });
+    }
+}
+
+/// Add a touch zone to an existing Digital action.
+fn add_touch_button(map: &mut InputMap, action: &str, region: ScreenRect) {
+    if let Some(def) = map.actions.get_mut(action) {
+        def.sources.push(InputSource::TouchZone { region });
+    }
+}
+
+/// Helper to make a Digital ActionDef from key code strings.
+fn digital(name: &str, keys: Vec<&str>) -> ActionDef {
+    ActionDef {
+        name: name.to_string(),
+        action_type: ActionType::Digital,
+        sources: keys.into_iter().map(|k| InputSource::Key(k.to_string())).collect(),
+        dead_zone: 0.1,
+    }
+}
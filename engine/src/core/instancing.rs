@@ -0,0 +1,399 @@
+//! GPU instancing for arrayed copies of a single mesh.
+//!
+//! `instance_array` spawns one entity carrying the source `Mesh3d`, its
+//! `MaterialData`, and an `InstanceData` list of per-instance transforms,
+//! instead of N independent entities. `InstancingPlugin` extracts the instances
+//! into a per-instance vertex buffer and draws every copy with a single
+//! instanced draw call.
+//!
+//! The instanced entity deliberately has no `MeshMaterial3d`, so the standard
+//! PBR pipeline does not draw it a second time. Shading is a fixed key light
+//! plus ambient over the material's base color; use `array_entity` when the
+//! copies need full PBR or per-instance editing.
+
+use bevy::prelude::*;
+use bevy::asset::uuid_handle;
+use bevy::core_pipeline::core_3d::Transparent3d;
+use bevy::ecs::{
+    query::QueryItem,
+    system::{lifetimeless::*, SystemParamItem},
+};
+use bevy::mesh::{MeshVertexBufferLayoutRef, VertexBufferLayout};
+use bevy::pbr::{
+    MeshPipeline, MeshPipelineKey, RenderMeshInstances, SetMeshViewBindGroup,
+    SetMeshViewBindingArrayBindGroup,
+};
+use bevy::render::{
+    extract_component::{ExtractComponent, ExtractComponentPlugin},
+    mesh::{allocator::MeshAllocator, RenderMesh, RenderMeshBufferInfo},
+    render_asset::RenderAssets,
+    render_phase::{
+        AddRenderCommand, DrawFunctionId, DrawFunctions, PhaseItem, PhaseItemExtraIndex, RenderCommand,
+        RenderCommandResult, SetItemPipeline, TrackedRenderPass, ViewSortedRenderPhases,
+    },
+    render_resource::*,
+    renderer::RenderDevice,
+    sync_world::MainEntity,
+    view::ExtractedView,
+    Render, RenderApp, RenderStartup, RenderSystems,
+};
+use bevy::camera::visibility::NoFrustumCulling;
+use bevy::shader::Shader;
+use bytemuck::{Pod, Zeroable};
+use crate::core::history::TransformSnapshot;
+use crate::core::material::MaterialData;
+
+/// Stable handle for the instancing shader, registered via include_str! like
+/// the forge effects shader.
+const FORGE_INSTANCING_SHADER_HANDLE: Handle<Shader> = uuid_handle!("f09eeffc-e750-4001-a000-000000000002");
+
+/// First vertex location used for per-instance attributes. Locations below
+/// this are reserved for mesh attributes (position, normal, UVs, tangent, ...).
+const INSTANCE_ATTRIBUTE_LOCATION: u32 = 8;
+
+/// Per-instance transforms for an instanced array entity, relative to the
+/// entity's own transform.
+#[derive(Component, Clone, Debug, Default)]
+pub struct InstanceData {
+    pub transforms: Vec<Transform>,
+}
+
+impl InstanceData {
+    /// Rebuild instance data from its serialized form in an `EntitySnapshot`.
+    pub fn from_snapshots(transforms: &[TransformSnapshot]) -> Self {
+        Self { transforms: transforms.iter().map(TransformSnapshot::to_transform).collect() }
+    }
+
+    pub fn to_snapshots(&self) -> Vec<TransformSnapshot> {
+        self.transforms.iter().map(TransformSnapshot::from).collect()
+    }
+}
+
+/// Turn a spawned mesh entity into an instanced array: attach the instances
+/// and drop the PBR material so the mesh is only drawn by `InstancingPlugin`.
+pub fn insert_instances(entity_commands: &mut bevy::ecs::system::EntityCommands, data: InstanceData) {
+    entity_commands
+        .insert((
+            data,
+            // Built-in culling only sees the base mesh AABB, not the instances.
+            NoFrustumCulling,
+        ))
+        .remove::<MeshMaterial3d<StandardMaterial>>();
+}
+
+/// GPU layout of one instance: world matrix columns followed by the linear base color.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct InstanceRaw {
+    pub world_from_local: [[f32; 4]; 4],
+    pub color: [f32; 4],
+}
+
+/// Render-world copy of an instanced entity, ready to upload as one buffer.
+#[derive(Component, Clone, Debug)]
+pub struct ExtractedInstances(pub Vec<InstanceRaw>);
+
+/// Build the GPU instance records for an instanced entity.
+pub fn build_instance_raw(global: &GlobalTransform, data: &InstanceData, color: [f32; 4]) -> Vec<InstanceRaw> {
+    let world_from_entity = global.to_matrix();
+    data.transforms
+        .iter()
+        .map(|t| InstanceRaw {
+            world_from_local: (world_from_entity * t.to_matrix()).to_cols_array_2d(),
+            color,
+        })
+        .collect()
+}
+
+impl ExtractComponent for InstanceData {
+    type QueryData = (&'static InstanceData, &'static GlobalTransform, Option<&'static MaterialData>);
+    type QueryFilter = ();
+    type Out = ExtractedInstances;
+
+    fn extract_component((data, global, material): QueryItem<'_, '_, Self::QueryData>) -> Option<ExtractedInstances> {
+        let color = material.map(|m| m.base_color).unwrap_or([1.0, 1.0, 1.0, 1.0]);
+        Some(ExtractedInstances(build_instance_raw(global, data, color)))
+    }
+}
+
+/// Plugin that renders `InstanceData` entities with one instanced draw each.
+pub struct InstancingPlugin;
+
+impl Plugin for InstancingPlugin {
+    fn build(&self, app: &mut App) {
+        if let Err(err) = app.world_mut()
+            .resource_mut::<Assets<Shader>>()
+            .insert(
+                FORGE_INSTANCING_SHADER_HANDLE.id(),
+                Shader::from_wgsl(
+                    include_str!("../shaders/forge_instancing.wgsl"),
+                    "shaders/forge_instancing.wgsl",
+                ),
+            )
+        {
+            tracing::warn!("Failed to register forge instancing shader: {err}");
+        }
+
+        app.add_plugins(ExtractComponentPlugin::<InstanceData>::default());
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .add_render_command::<Transparent3d, DrawInstanced>()
+            .init_resource::<SpecializedMeshPipelines<InstancingPipeline>>()
+            .add_systems(RenderStartup, init_instancing_pipeline)
+            .add_systems(
+                Render,
+                (
+                    queue_instanced.in_set(RenderSystems::QueueMeshes),
+                    prepare_instance_buffers.in_set(RenderSystems::PrepareResources),
+                ),
+            );
+    }
+}
+
+fn queue_instanced(
+    instancing_pipeline: Res<InstancingPipeline>,
+    (mut pipelines, pipeline_cache): (ResMut<SpecializedMeshPipelines<InstancingPipeline>>, Res<PipelineCache>),
+    meshes: Res<RenderAssets<RenderMesh>>,
+    render_mesh_instances: Res<RenderMeshInstances>,
+    instanced_meshes: Query<(Entity, &MainEntity), With<ExtractedInstances>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent3d>>,
+    views: Query<(&ExtractedView, &Msaa)>,
+) {
+    for (view, msaa) in &views {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view.retained_view_entity) else {
+            continue;
+        };
+
+        let view_key = MeshPipelineKey::from_msaa_samples(msaa.samples()) | MeshPipelineKey::from_hdr(view.hdr);
+        let rangefinder = view.rangefinder3d();
+        for (entity, main_entity) in &instanced_meshes {
+            let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(*main_entity) else {
+                continue;
+            };
+            let Some(mesh) = meshes.get(mesh_instance.mesh_asset_id) else {
+                continue;
+            };
+            let key = view_key | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology());
+            let pipeline = match pipelines.specialize(&pipeline_cache, &instancing_pipeline, key, &mesh.layout) {
+                Ok(pipeline) => pipeline,
+                Err(err) => {
+                    tracing::error!("Failed to specialize instancing pipeline: {err}");
+                    continue;
+                }
+            };
+            transparent_phase.add(Transparent3d {
+                entity: (entity, *main_entity),
+                pipeline,
+                draw_function: instancing_pipeline.draw_function,
+                distance: rangefinder.distance(&mesh_instance.center),
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::None,
+                indexed: mesh.indexed(),
+            });
+        }
+    }
+}
+
+/// Per-instance vertex buffer for an instanced entity (render world).
+#[derive(Component)]
+struct InstanceBuffer {
+    buffer: Buffer,
+    length: usize,
+}
+
+fn prepare_instance_buffers(
+    mut commands: Commands,
+    query: Query<(Entity, &ExtractedInstances)>,
+    render_device: Res<RenderDevice>,
+) {
+    for (entity, instances) in &query {
+        if instances.0.is_empty() {
+            commands.entity(entity).remove::<InstanceBuffer>();
+            continue;
+        }
+        let buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some("forge instance buffer"),
+            contents: bytemuck::cast_slice(instances.0.as_slice()),
+            usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+        });
+        commands.entity(entity).insert(InstanceBuffer {
+            buffer,
+            length: instances.0.len(),
+        });
+    }
+}
+
+#[derive(Resource)]
+struct InstancingPipeline {
+    mesh_pipeline: MeshPipeline,
+    draw_function: DrawFunctionId,
+}
+
+fn init_instancing_pipeline(
+    mut commands: Commands,
+    mesh_pipeline: Res<MeshPipeline>,
+    draw_functions: Res<DrawFunctions<Transparent3d>>,
+) {
+    commands.insert_resource(InstancingPipeline {
+        mesh_pipeline: mesh_pipeline.clone(),
+        draw_function: draw_functions.read().id::<DrawInstanced>(),
+    });
+}
+
+impl SpecializedMeshPipeline for InstancingPipeline {
+    type Key = MeshPipelineKey;
+
+    fn specialize(
+        &self,
+        key: Self::Key,
+        layout: &MeshVertexBufferLayoutRef,
+    ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
+        let mut descriptor = self.mesh_pipeline.specialize(key, layout)?;
+
+        // Only the view bind groups are used: world matrices come from the
+        // instance buffer, so the per-mesh group is dropped from the layout.
+        descriptor.layout.truncate(2);
+        descriptor.label = Some("forge_instancing_pipeline".into());
+        descriptor.vertex.shader = FORGE_INSTANCING_SHADER_HANDLE;
+        let vec4_size = VertexFormat::Float32x4.size();
+        descriptor.vertex.buffers.push(VertexBufferLayout {
+            array_stride: size_of::<InstanceRaw>() as u64,
+            step_mode: VertexStepMode::Instance,
+            attributes: (0..5)
+                .map(|i| VertexAttribute {
+                    format: VertexFormat::Float32x4,
+                    offset: i as u64 * vec4_size,
+                    shader_location: INSTANCE_ATTRIBUTE_LOCATION + i,
+                })
+                .collect(),
+        });
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            fragment.shader = FORGE_INSTANCING_SHADER_HANDLE;
+        }
+        Ok(descriptor)
+    }
+}
+
+type DrawInstanced = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetMeshViewBindingArrayBindGroup<1>,
+    DrawMeshInstanced,
+);
+
+struct DrawMeshInstanced;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawMeshInstanced {
+    type Param = (
+        SRes<RenderAssets<RenderMesh>>,
+        SRes<RenderMeshInstances>,
+        SRes<MeshAllocator>,
+    );
+    type ViewQuery = ();
+    type ItemQuery = Read<InstanceBuffer>;
+
+    #[inline]
+    fn render<'w>(
+        item: &P,
+        _view: (),
+        instance_buffer: Option<&'w InstanceBuffer>,
+        (meshes, render_mesh_instances, mesh_allocator): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let mesh_allocator = mesh_allocator.into_inner();
+
+        let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(item.main_entity()) else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(gpu_mesh) = meshes.into_inner().get(mesh_instance.mesh_asset_id) else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(instance_buffer) = instance_buffer else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(vertex_buffer_slice) = mesh_allocator.mesh_vertex_slice(&mesh_instance.mesh_asset_id) else {
+            return RenderCommandResult::Skip;
+        };
+
+        pass.set_vertex_buffer(0, vertex_buffer_slice.buffer.slice(..));
+        pass.set_vertex_buffer(1, instance_buffer.buffer.slice(..));
+
+        let instances = 0..instance_buffer.length as u32;
+        match &gpu_mesh.buffer_info {
+            RenderMeshBufferInfo::Indexed { index_format, count } => {
+                let Some(index_buffer_slice) = mesh_allocator.mesh_index_slice(&mesh_instance.mesh_asset_id) else {
+                    return RenderCommandResult::Skip;
+                };
+                pass.set_index_buffer(index_buffer_slice.buffer.slice(..), *index_format);
+                pass.draw_indexed(
+                    index_buffer_slice.range.start..(index_buffer_slice.range.start + count),
+                    vertex_buffer_slice.range.start as i32,
+                    instances,
+                );
+            }
+            RenderMeshBufferInfo::NonIndexed => {
+                pass.draw(vertex_buffer_slice.range, instances);
+            }
+        }
+        RenderCommandResult::Success
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending::ArrayRequest;
+    use crate::core::procedural_mesh::array_offsets;
+
+    fn grid_request(n: u32) -> ArrayRequest {
+        ArrayRequest {
+            entity_id: "src".to_string(),
+            pattern: "grid".to_string(),
+            count_x: Some(n),
+            count_y: Some(n),
+            count_z: Some(n),
+            spacing_x: Some(2.0),
+            spacing_y: Some(2.0),
+            spacing_z: Some(2.0),
+            circle_count: None,
+            circle_radius: None,
+        }
+    }
+
+    #[test]
+    fn instance_raw_composes_entity_and_instance_transforms() {
+        let global = GlobalTransform::from(Transform::from_xyz(1.0, 2.0, 3.0));
+        let data = InstanceData { transforms: vec![Transform::from_xyz(4.0, 0.0, 0.0)] };
+        let raw = build_instance_raw(&global, &data, [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(raw.len(), 1);
+        assert_eq!(raw[0].world_from_local[3], [5.0, 2.0, 3.0, 1.0]);
+        assert_eq!(raw[0].color, [1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn thousand_instance_grid_fits_one_draw_buffer() {
+        // Full 10x10x10 grid: the 999 array offsets plus the origin.
+        let offsets = array_offsets(&grid_request(10)).unwrap();
+        assert_eq!(offsets.len(), 999);
+        let mut transforms: Vec<Transform> = vec![Transform::IDENTITY];
+        transforms.extend(offsets.into_iter().map(Transform::from_translation));
+        let data = InstanceData { transforms };
+
+        let raw = build_instance_raw(&GlobalTransform::IDENTITY, &data, [1.0; 4]);
+        assert_eq!(raw.len(), 1000);
+        // One contiguous vertex buffer = one instanced draw call.
+        let bytes: &[u8] = bytemuck::cast_slice(raw.as_slice());
+        assert_eq!(bytes.len(), 1000 * size_of::<InstanceRaw>());
+        assert_eq!(size_of::<InstanceRaw>(), 5 * 16);
+    }
+
+    #[test]
+    fn array_offsets_reject_unknown_pattern() {
+        let mut request = grid_request(2);
+        request.pattern = "spiral".to_string();
+        assert_eq!(array_offsets(&request).unwrap_err(), "Unknown array pattern: spiral");
+    }
+}
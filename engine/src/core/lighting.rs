@@ -2,11 +2,20 @@
 //!
 //! Provides `LightData` — a serializable component covering PointLight,
 //! DirectionalLight, and SpotLight properties that can be edited via the
-//! bridge and synced back to the actual Bevy light components.
+//! bridge and synced back to the actual Bevy light components, including
+//! projected cookie textures on spot and directional lights.
+//!
+//! Also provides `LightProbeVolume` — a grid of baked irradiance probes that
+//! is applied to meshes inside its bounds through Bevy's `IrradianceVolume`.
 
+use bevy::asset::RenderAssetUsages;
+use bevy::light::{DirectionalLightTexture, IrradianceVolume, SpotLightTexture};
 use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use serde::{Serialize, Deserialize};
 
+use crate::core::asset_manager::TextureHandleMap;
+
 /// Light type discriminator.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -35,6 +44,11 @@ pub struct LightData {
     // Spot specific
     pub inner_angle: f32,
     pub outer_angle: f32,
+
+    /// Asset ID of a cookie (gobo) texture projected by spot and directional
+    /// lights. Only the red channel is used. Ignored on point lights.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_texture: Option<String>,
 }
 
 impl LightData {
@@ -51,6 +65,7 @@ impl LightData {
             radius: 0.0,
             inner_angle: 0.0,
             outer_angle: std::f32::consts::FRAC_PI_4,
+            cookie_texture: None,
         }
     }
 
@@ -67,6 +82,7 @@ impl LightData {
             radius: 0.0,
             inner_angle: 0.0,
             outer_angle: std::f32::consts::FRAC_PI_4,
+            cookie_texture: None,
         }
     }
 
@@ -83,16 +99,188 @@ impl LightData {
             radius: 0.0,
             inner_angle: 0.0,
             outer_angle: std::f32::consts::FRAC_PI_4,
+            cookie_texture: None,
+        }
+    }
+
+    /// Unshadowed illuminance this light casts on a surface at `point` facing
+    /// `normal`, per color channel, in lux.
+    pub fn irradiance_at(&self, transform: &GlobalTransform, point: Vec3, normal: Vec3) -> [f32; 3] {
+        let illuminance = match self.light_type {
+            LightType::Directional => self.intensity * normal.dot(-transform.forward().as_vec3()).max(0.0),
+            LightType::Point | LightType::Spot => {
+                let to_light = transform.translation() - point;
+                let distance = to_light.length();
+                if distance <= f32::EPSILON || distance > self.range {
+                    return [0.0; 3];
+                }
+                let direction = to_light / distance;
+                // Luminous power in lumens to luminous intensity in candela.
+                let candela = self.intensity / (4.0 * std::f32::consts::PI);
+                let mut illuminance = candela / (distance * distance) * normal.dot(direction).max(0.0);
+                if self.light_type == LightType::Spot {
+                    let angle = transform.forward().dot(-direction).clamp(-1.0, 1.0).acos();
+                    let falloff = (self.outer_angle - self.inner_angle).max(f32::EPSILON);
+                    illuminance *= ((self.outer_angle - angle) / falloff).clamp(0.0, 1.0);
+                }
+                illuminance
+            }
+        };
+        self.color.map(|c| c * illuminance)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Light probe volumes
+// ---------------------------------------------------------------------------
+
+/// Baked irradiance at one probe as RGB level-1 spherical harmonics:
+/// the L0 band followed by the L1 bands along Y, Z, and X.
+pub type ProbeSh = [[f32; 3]; 4];
+
+/// SH basis constant for the L0 band.
+const SH_Y0: f32 = 0.282_095;
+/// SH basis constant for the L1 bands.
+const SH_Y1: f32 = 0.488_603;
+
+/// A box of evenly spaced light probes centered on the entity.
+///
+/// Probes sit at the centers of a `resolution` grid spanning `size`. Once
+/// baked, the irradiance is applied to meshes inside the box; the baked data
+/// is saved with the scene so loading never needs to re-bake.
+#[derive(Component, Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LightProbeVolume {
+    /// Full extents of the box in local space.
+    pub size: [f32; 3],
+    /// Probe count along each axis.
+    pub resolution: [u32; 3],
+    /// Multiplier applied to the baked irradiance when rendering.
+    pub intensity: f32,
+    /// Baked probes in X-fastest, then Y, then Z order. `None` until baked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baked: Option<Vec<ProbeSh>>,
+}
+
+impl Default for LightProbeVolume {
+    fn default() -> Self {
+        Self {
+            size: [10.0, 5.0, 10.0],
+            resolution: [3, 3, 3],
+            intensity: 1.0,
+            baked: None,
         }
     }
 }
 
+impl LightProbeVolume {
+    /// Total number of probes in the grid.
+    pub fn probe_count(&self) -> usize {
+        self.resolution.iter().map(|&r| r.max(1) as usize).product()
+    }
+
+    /// Local-space position of probe `index`, at the center of its grid cell.
+    pub fn probe_position(&self, index: usize) -> Vec3 {
+        let [rx, ry, _] = self.resolution.map(|r| r.max(1) as usize);
+        let cell = Vec3::new(
+            (index % rx) as f32,
+            (index / rx % ry) as f32,
+            (index / (rx * ry)) as f32,
+        );
+        let resolution = Vec3::from_array(self.resolution.map(|r| r.max(1) as f32));
+        ((cell + 0.5) / resolution - 0.5) * Vec3::from_array(self.size)
+    }
+
+    /// Baked probes, if a complete bake matching the current resolution exists.
+    pub fn baked_probes(&self) -> Option<&[ProbeSh]> {
+        self.baked.as_deref().filter(|probes| probes.len() == self.probe_count())
+    }
+
+    /// Build the ambient-cube voxel texture sampled by Bevy's `IrradianceVolume`.
+    ///
+    /// A volume of resolution (Rx, Ry, Rz) becomes an (Rx, 2Ry, 3Rz) 3D texture:
+    /// the +X/+Y/+Z faces of every voxel in the lower half of each slab and the
+    /// negative faces in the upper half, with one slab per axis.
+    pub fn irradiance_voxels(&self) -> Option<Image> {
+        let probes = self.baked_probes()?;
+        let [rx, ry, rz] = self.resolution.map(|r| r.max(1));
+        let mut image = Image::new_fill(
+            Extent3d { width: rx, height: ry * 2, depth_or_array_layers: rz * 3 },
+            TextureDimension::D3,
+            &[0; 8],
+            TextureFormat::Rgba16Float,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        for (index, sh) in probes.iter().enumerate() {
+            let index = index as u32;
+            let (x, y, z) = (index % rx, index / rx % ry, index / (rx * ry));
+            for (axis, direction) in [Vec3::X, Vec3::Y, Vec3::Z].into_iter().enumerate() {
+                for (half, normal) in [direction, -direction].into_iter().enumerate() {
+                    let [r, g, b] = probe_sh_irradiance(sh, normal);
+                    image
+                        .set_color_at_3d(x, y + half as u32 * ry, z + axis as u32 * rz, Color::linear_rgb(r, g, b))
+                        .ok()?;
+                }
+            }
+        }
+        Some(image)
+    }
+}
+
+/// Marker on the child entity that carries a volume's `IrradianceVolume`.
+#[derive(Component)]
+pub struct LightProbeVoxels;
+
+/// Uniformly distributed unit directions (Fibonacci sphere).
+pub fn probe_sample_directions(count: usize) -> Vec<Vec3> {
+    let golden_angle = std::f32::consts::PI * (3.0 - 5.0_f32.sqrt());
+    (0..count)
+        .map(|i| {
+            let y = 1.0 - 2.0 * (i as f32 + 0.5) / count as f32;
+            let radius = (1.0 - y * y).sqrt();
+            let theta = golden_angle * i as f32;
+            Vec3::new(theta.cos() * radius, y, theta.sin() * radius)
+        })
+        .collect()
+}
+
+/// Project radiance sampled along uniformly distributed directions onto L1 SH.
+pub fn project_probe_sh(samples: &[(Vec3, [f32; 3])]) -> ProbeSh {
+    let mut sh = [[0.0; 3]; 4];
+    if samples.is_empty() {
+        return sh;
+    }
+    let weight = 4.0 * std::f32::consts::PI / samples.len() as f32;
+    for (direction, radiance) in samples {
+        let basis = [SH_Y0, SH_Y1 * direction.y, SH_Y1 * direction.z, SH_Y1 * direction.x];
+        for (coefficient, b) in sh.iter_mut().zip(basis) {
+            for (c, r) in coefficient.iter_mut().zip(radiance) {
+                *c += r * b * weight;
+            }
+        }
+    }
+    sh
+}
+
+/// Cosine-weighted radiance (irradiance / π) reaching a surface facing `normal`.
+pub fn probe_sh_irradiance(sh: &ProbeSh, normal: Vec3) -> [f32; 3] {
+    // Lambertian convolution weights the L1 bands by 2/3 relative to L0.
+    let basis = [SH_Y0, SH_Y1 * normal.y * 2.0 / 3.0, SH_Y1 * normal.z * 2.0 / 3.0, SH_Y1 * normal.x * 2.0 / 3.0];
+    let mut irradiance = [0.0; 3];
+    for (coefficient, b) in sh.iter().zip(basis) {
+        for (e, c) in irradiance.iter_mut().zip(coefficient) {
+            *e += c * b;
+        }
+    }
+    irradiance.map(|c| c.max(0.0))
+}
+
 /// Plugin that registers the light sync system.
 pub struct LightingPlugin;
 
 impl Plugin for LightingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, sync_light_data);
+        app.add_systems(Update, (sync_light_data, sync_light_cookies, sync_light_probe_volumes));
     }
 }
 
@@ -132,3 +320,141 @@ fn sync_light_data(
         light.outer_angle = data.outer_angle;
     }
 }
+
+/// System that attaches each light's cookie texture as Bevy's
+/// `SpotLightTexture` / `DirectionalLightTexture`, and re-resolves when
+/// textures finish loading.
+///
+/// Bevy only renders light textures with the `pbr_light_textures` feature,
+/// which relies on clustered decals and therefore bindless textures. WebGL2
+/// and WebGPU have neither, so browser builds keep and round-trip the cookie
+/// but light without it; native builds with the feature project it.
+fn sync_light_cookies(
+    mut commands: Commands,
+    texture_handles: Res<TextureHandleMap>,
+    query: Query<(Entity, Ref<LightData>)>,
+) {
+    let textures_changed = texture_handles.is_changed();
+    for (entity, data) in query.iter() {
+        if !data.is_changed() && !textures_changed {
+            continue;
+        }
+        let image = data.cookie_texture.as_ref().and_then(|id| texture_handles.0.get(id)).cloned();
+        let mut entity = commands.entity(entity);
+        match (image, &data.light_type) {
+            (Some(image), LightType::Spot) => {
+                entity.remove::<DirectionalLightTexture>().insert(SpotLightTexture { image });
+            }
+            (Some(image), LightType::Directional) => {
+                entity.remove::<SpotLightTexture>().insert(DirectionalLightTexture { image, tiled: false });
+            }
+            _ => {
+                entity.remove::<(SpotLightTexture, DirectionalLightTexture)>();
+            }
+        }
+    }
+}
+
+/// System that rebuilds the irradiance texture of each changed volume.
+///
+/// The `IrradianceVolume` lives on a `LightProbeVoxels` child scaled to the
+/// volume's size, since Bevy sizes light probes by their transform. Bevy only
+/// renders irradiance volumes on native targets; browser builds keep and
+/// round-trip the baked data but do not apply it.
+fn sync_light_probe_volumes(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    volumes: Query<(Entity, &LightProbeVolume), Changed<LightProbeVolume>>,
+    voxels: Query<(Entity, &ChildOf), With<LightProbeVoxels>>,
+) {
+    for (entity, volume) in volumes.iter() {
+        for (child, child_of) in voxels.iter() {
+            if child_of.parent() == entity {
+                commands.entity(child).despawn();
+            }
+        }
+
+        let Some(image) = volume.irradiance_voxels() else {
+            continue;
+        };
+        commands.spawn((
+            LightProbeVoxels,
+            IrradianceVolume {
+                voxels: images.add(image),
+                intensity: volume.intensity,
+                ..default()
+            },
+            Transform::from_scale(Vec3::from_array(volume.size)),
+            ChildOf(entity),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spot_light_cookie_is_applied_once_texture_loads() {
+        let mut app = App::new();
+        app.init_resource::<TextureHandleMap>()
+            .add_systems(Update, sync_light_cookies);
+        let light = app.world_mut().spawn(LightData {
+            cookie_texture: Some("window".to_string()),
+            ..LightData::spot()
+        }).id();
+
+        // Not loaded yet: no cookie.
+        app.update();
+        assert!(app.world().get::<SpotLightTexture>(light).is_none());
+
+        app.world_mut().resource_mut::<TextureHandleMap>().0.insert("window".to_string(), Handle::default());
+        app.update();
+        assert!(app.world().get::<SpotLightTexture>(light).is_some());
+
+        app.world_mut().get_mut::<LightData>(light).expect("light").cookie_texture = None;
+        app.update();
+        assert!(app.world().get::<SpotLightTexture>(light).is_none());
+    }
+
+    #[test]
+    fn constant_radiance_projects_to_uniform_irradiance() {
+        let samples: Vec<_> = probe_sample_directions(256)
+            .into_iter()
+            .map(|dir| (dir, [0.5, 1.0, 2.0]))
+            .collect();
+        let sh = project_probe_sh(&samples);
+
+        for normal in [Vec3::X, Vec3::NEG_Y, Vec3::Z] {
+            let irradiance = probe_sh_irradiance(&sh, normal);
+            for (got, want) in irradiance.iter().zip([0.5, 1.0, 2.0]) {
+                assert!((got - want).abs() < 0.02, "{got} vs {want}");
+            }
+        }
+    }
+
+    #[test]
+    fn baked_volume_builds_irradiance_without_rebaking() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Image>>()
+            .add_systems(Update, sync_light_probe_volumes);
+
+        let unbaked = app.world_mut().spawn(LightProbeVolume::default()).id();
+        let volume = LightProbeVolume {
+            baked: Some(vec![[[1.0; 3], [0.0; 3], [0.0; 3], [0.0; 3]]; 27]),
+            ..LightProbeVolume::default()
+        };
+        let baked = app.world_mut().spawn(volume).id();
+        app.update();
+
+        let mut voxels = app.world_mut().query_filtered::<(&ChildOf, &IrradianceVolume), With<LightProbeVoxels>>();
+        let found: Vec<_> = voxels.iter(app.world()).map(|(child_of, irradiance)| (child_of.parent(), irradiance.voxels.clone())).collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, baked);
+        assert_ne!(found[0].0, unbaked);
+
+        let images = app.world().resource::<Assets<Image>>();
+        let size = images.get(&found[0].1).expect("voxel image").texture_descriptor.size;
+        assert_eq!((size.width, size.height, size.depth_or_array_layers), (3, 6, 9));
+    }
+}
@@ -3,7 +3,8 @@
 //! `LodData` stores per-entity distance thresholds and triangle reduction ratios.
 //! The `update_lod_levels` system computes camera distance each frame and emits
 //! `LOD_CHANGED` events when the active level changes. Mesh simplification uses
-//! QEM (Quadric Error Metric) or Fast (position-only) backends from `mesh_simplify`.
+//! QEM (Quadric Error Metric) or Fast (position-only) backends from `mesh_simplify`;
+//! `generate_lods` produces the three simplified levels stored in `LodMeshes`.
 
 use bevy::prelude::*;
 use bevy::mesh::Mesh;
@@ -29,6 +30,14 @@ impl Default for LodData {
     }
 }
 
+impl LodData {
+    /// LOD level for a camera distance: 0 closer than the first threshold,
+    /// 3 at or past the last one.
+    pub fn level_for_distance(&self, distance: f32) -> u8 {
+        self.lod_distances.iter().take_while(|&&threshold| distance >= threshold).count() as u8
+    }
+}
+
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceBudget {
     pub max_triangles: u32,       // Max total triangles
@@ -63,6 +72,19 @@ impl Default for LodMeshes {
     }
 }
 
+/// Simplify `mesh` once per ratio, producing LOD levels 1-3.
+pub fn generate_lods(mesh: &Mesh, ratios: &[f32; 3], simplifier: &dyn MeshSimplifier) -> [Mesh; 3] {
+    ratios.map(|ratio| simplifier.simplify(mesh, ratio))
+}
+
+/// Triangle count of a mesh; non-indexed meshes count every three vertices.
+pub fn triangle_count(mesh: &Mesh) -> u32 {
+    match mesh.indices() {
+        Some(indices) => indices.len() as u32 / 3,
+        None => mesh.count_vertices() as u32 / 3,
+    }
+}
+
 /// Bevy resource that holds the active mesh simplification backend.
 ///
 /// Defaults to `QemSimplifier` (attribute-preserving QEM). Can be switched to
@@ -110,6 +132,158 @@ pub struct PerformanceMetrics {
     pub draw_call_estimate: u32,
     pub wasm_heap_bytes: u64,
     pub mesh_memory_bytes: u64,
+    /// Mesh entities that passed frustum culling last frame.
+    pub visible_count: u32,
+    /// Mesh entities hidden from every view last frame (culled or invisible).
+    pub culled_count: u32,
+    /// Total mesh entities considered for visibility.
+    pub total_count: u32,
+    /// Mesh entities hidden by occlusion culling (WebGPU builds only).
+    pub occluded_count: u32,
     /// Frame counter for throttling metrics collection.
     pub frame_counter: u32,
 }
+
+/// Whether `STATS_UPDATE` events are streaming, toggled by the
+/// `start_stats_stream` and `stop_stats_stream` commands.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct StatsStream {
+    pub active: bool,
+}
+
+/// One sample of the live stats stream.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngineStats {
+    pub fps: f32,
+    pub frame_time_ms: f32,
+    pub draw_call_estimate: u32,
+    pub entity_count: u32,
+    /// Triangles across all mesh entities, visible or not.
+    pub triangle_count: u32,
+    pub mesh_asset_count: u32,
+    pub material_asset_count: u32,
+}
+
+/// Count visible vs. culled mesh entities from last frame's `ViewVisibility`.
+/// Cheap enough to run every frame, so the counts are never stale.
+pub fn collect_visibility_stats(
+    mut metrics: ResMut<PerformanceMetrics>,
+    query: Query<&ViewVisibility, With<Mesh3d>>,
+) {
+    let mut visible = 0u32;
+    let mut total = 0u32;
+    for view_visibility in query.iter() {
+        total += 1;
+        if view_visibility.get() {
+            visible += 1;
+        }
+    }
+    metrics.visible_count = visible;
+    metrics.culled_count = total - visible;
+    metrics.total_count = total;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::camera::{
+        primitives::Frustum,
+        visibility::{NoFrustumCulling, VisibilityPlugin},
+        CameraProjection,
+    };
+
+    fn frustum_for(transform: &Transform) -> Frustum {
+        let clip_from_view = PerspectiveProjection::default().get_clip_from_view();
+        let world_from_view = transform.to_matrix();
+        Frustum::from_clip_from_world(&(clip_from_view * world_from_view.inverse()))
+    }
+
+    /// Headless app with a camera at (0, 0, 10) looking at a row of cubes at the origin.
+    /// Frusta are set by hand since there is no render target to size the projection.
+    fn culling_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), VisibilityPlugin))
+            .init_asset::<Mesh>()
+            .init_resource::<PerformanceMetrics>()
+            .add_systems(Update, collect_visibility_stats);
+
+        let cube = app.world_mut().resource_mut::<Assets<Mesh>>().add(Cuboid::default());
+        for i in 0..5 {
+            app.world_mut().spawn((Mesh3d(cube.clone()), Transform::from_xyz(i as f32 * 2.0 - 4.0, 0.0, 0.0)));
+        }
+
+        let camera_transform = Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y);
+        let camera = app.world_mut()
+            .spawn((Camera::default(), camera_transform, frustum_for(&camera_transform)))
+            .id();
+        (app, camera)
+    }
+
+    fn look(app: &mut App, camera: Entity, transform: Transform) {
+        let frustum = frustum_for(&transform);
+        app.world_mut().entity_mut(camera).insert((transform, frustum));
+    }
+
+    fn stats_after_frames(app: &mut App) -> (u32, u32, u32) {
+        // One frame computes ViewVisibility in PostUpdate; the next frame counts it.
+        app.update();
+        app.update();
+        let m = app.world().resource::<PerformanceMetrics>();
+        (m.visible_count, m.culled_count, m.total_count)
+    }
+
+    #[test]
+    fn culled_count_rises_when_camera_looks_away() {
+        let (mut app, camera) = culling_app();
+        let (visible, culled, total) = stats_after_frames(&mut app);
+        assert_eq!(total, 5);
+        assert_eq!(visible, 5, "all cubes should be in view");
+        assert_eq!(culled, 0);
+
+        look(&mut app, camera, Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::new(0.0, 0.0, 20.0), Vec3::Y));
+        let (visible, culled, total) = stats_after_frames(&mut app);
+        assert_eq!(total, 5);
+        assert_eq!(visible, 0, "camera faces away from every cube");
+        assert_eq!(culled, 5);
+    }
+
+    #[test]
+    fn no_frustum_culling_keeps_entities_visible() {
+        let (mut app, camera) = culling_app();
+        let meshes: Vec<Entity> = app.world_mut()
+            .query_filtered::<Entity, With<Mesh3d>>()
+            .iter(app.world())
+            .collect();
+        for entity in meshes {
+            app.world_mut().entity_mut(entity).insert(NoFrustumCulling);
+        }
+
+        look(&mut app, camera, Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::new(0.0, 0.0, 20.0), Vec3::Y));
+        let (visible, culled, _) = stats_after_frames(&mut app);
+        assert_eq!(visible, 5);
+        assert_eq!(culled, 0);
+    }
+
+    #[test]
+    fn generated_lods_lose_triangles_at_every_level() {
+        let sphere = Sphere::new(1.0).mesh().uv(48, 32);
+        let levels = generate_lods(&sphere, &[0.5, 0.25, 0.1], &QemSimplifier);
+        let counts: Vec<u32> = std::iter::once(&sphere).chain(levels.iter()).map(triangle_count).collect();
+        for pair in counts.windows(2) {
+            assert!(pair[1] < pair[0], "triangle counts should decrease per level: {:?}", counts);
+        }
+    }
+
+    #[test]
+    fn lod_level_switches_at_configured_distances() {
+        let lod = LodData { lod_distances: [10.0, 25.0, 50.0], ..default() };
+        assert_eq!(lod.level_for_distance(0.0), 0);
+        assert_eq!(lod.level_for_distance(9.9), 0);
+        assert_eq!(lod.level_for_distance(10.0), 1);
+        assert_eq!(lod.level_for_distance(24.9), 1);
+        assert_eq!(lod.level_for_distance(25.0), 2);
+        assert_eq!(lod.level_for_distance(50.0), 3);
+        assert_eq!(lod.level_for_distance(1000.0), 3);
+    }
+}
@@ -18,7 +18,14 @@ fn default_parallax_relief_steps() -> u32 { 5 }
 fn default_clearcoat_roughness() -> f32 { 0.5 }
 fn default_ior() -> f32 { 1.5 }
 fn default_attenuation_distance() -> f32 { f32::INFINITY }
+
+/// JSON has no infinity: serde_json writes the default attenuation distance as
+/// `null`, so read `null` back as infinity.
+fn deserialize_attenuation_distance<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    Ok(Option::<f32>::deserialize(deserializer)?.unwrap_or_else(default_attenuation_distance))
+}
 fn default_attenuation_color() -> [f32; 3] { [1.0, 1.0, 1.0] }
+fn default_triplanar_scale() -> f32 { 1.0 }
 
 /// Serializable parallax mapping method (mirror of Bevy's `ParallaxMappingMethod`).
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -110,10 +117,38 @@ pub struct MaterialData {
     pub ior: f32,
     #[serde(default)]
     pub thickness: f32,
-    #[serde(default = "default_attenuation_distance")]
+    #[serde(default = "default_attenuation_distance", deserialize_with = "deserialize_attenuation_distance")]
     pub attenuation_distance: f32,
     #[serde(default = "default_attenuation_color")]
     pub attenuation_color: [f32; 3],
+
+    // --- Anisotropy ---
+    /// Stretches specular highlights along the surface, as on brushed metal.
+    #[serde(default)]
+    pub anisotropy_strength: f32,
+    /// Direction of the stretch in radians, counter-clockwise from the tangent.
+    #[serde(default)]
+    pub anisotropy_rotation: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anisotropy_texture: Option<String>,
+
+    // --- Sheen ---
+    /// Linear RGB tint of the cloth-like sheen layer; black disables it.
+    /// Stored so scenes keep it, but not rendered: StandardMaterial has no
+    /// sheen layer.
+    #[serde(default)]
+    pub sheen_color: [f32; 3],
+    #[serde(default)]
+    pub sheen_roughness: f32,
+
+    // --- Triplanar projection ---
+    /// Sample the base color texture by world-space triplanar projection
+    /// instead of mesh UVs. Requires the entity to render with ForgeMaterial.
+    #[serde(default)]
+    pub triplanar: bool,
+    /// Texture repeats per world unit when triplanar is enabled.
+    #[serde(default = "default_triplanar_scale")]
+    pub triplanar_scale: f32,
 }
 
 /// Alpha blending mode (serializable mirror of Bevy's AlphaMode).
@@ -166,6 +201,128 @@ impl Default for MaterialData {
             thickness: 0.0,
             attenuation_distance: default_attenuation_distance(),
             attenuation_color: default_attenuation_color(),
+            // Anisotropy and sheen defaults (disabled)
+            anisotropy_strength: 0.0,
+            anisotropy_rotation: 0.0,
+            anisotropy_texture: None,
+            sheen_color: [0.0, 0.0, 0.0],
+            sheen_roughness: 0.0,
+            // Triplanar defaults (mesh UVs)
+            triplanar: false,
+            triplanar_scale: default_triplanar_scale(),
+        }
+    }
+}
+
+/// Named one-click PBR material presets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaterialPreset {
+    Gold,
+    Silver,
+    Copper,
+    Chrome,
+    Plastic,
+    Rubber,
+    Glass,
+}
+
+impl MaterialPreset {
+    /// All presets, in the order they are listed to the UI.
+    pub const ALL: [MaterialPreset; 7] = [
+        Self::Gold,
+        Self::Silver,
+        Self::Copper,
+        Self::Chrome,
+        Self::Plastic,
+        Self::Rubber,
+        Self::Glass,
+    ];
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s {
+            "gold" => Some(Self::Gold),
+            "silver" => Some(Self::Silver),
+            "copper" => Some(Self::Copper),
+            "chrome" => Some(Self::Chrome),
+            "plastic" => Some(Self::Plastic),
+            "rubber" => Some(Self::Rubber),
+            "glass" => Some(Self::Glass),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gold => "gold",
+            Self::Silver => "silver",
+            Self::Copper => "copper",
+            Self::Chrome => "chrome",
+            Self::Plastic => "plastic",
+            Self::Rubber => "rubber",
+            Self::Glass => "glass",
+        }
+    }
+}
+
+impl MaterialData {
+    /// Build a fully configured material for a preset.
+    /// Metal base colors are measured F0 reflectances (linear).
+    pub fn from_preset(preset: MaterialPreset) -> Self {
+        let base = Self::default();
+        match preset {
+            MaterialPreset::Gold => Self {
+                base_color: [1.0, 0.766, 0.336, 1.0],
+                metallic: 1.0,
+                perceptual_roughness: 0.25,
+                ..base
+            },
+            MaterialPreset::Silver => Self {
+                base_color: [0.972, 0.960, 0.915, 1.0],
+                metallic: 1.0,
+                perceptual_roughness: 0.2,
+                ..base
+            },
+            MaterialPreset::Copper => Self {
+                base_color: [0.955, 0.638, 0.538, 1.0],
+                metallic: 1.0,
+                perceptual_roughness: 0.3,
+                ..base
+            },
+            MaterialPreset::Chrome => Self {
+                base_color: [0.550, 0.556, 0.554, 1.0],
+                metallic: 1.0,
+                perceptual_roughness: 0.05,
+                ..base
+            },
+            MaterialPreset::Plastic => Self {
+                base_color: [0.8, 0.8, 0.8, 1.0],
+                metallic: 0.0,
+                perceptual_roughness: 0.4,
+                reflectance: 0.5,
+                clearcoat: 0.3,
+                clearcoat_perceptual_roughness: 0.2,
+                ..base
+            },
+            MaterialPreset::Rubber => Self {
+                base_color: [0.05, 0.05, 0.05, 1.0],
+                metallic: 0.0,
+                perceptual_roughness: 0.9,
+                reflectance: 0.35,
+                ..base
+            },
+            // Refractive: full specular transmission with a real glass IOR.
+            // reflectance 0.5 corresponds to the same 4% F0 as ior 1.5.
+            MaterialPreset::Glass => Self {
+                base_color: [1.0, 1.0, 1.0, 1.0],
+                metallic: 0.0,
+                perceptual_roughness: 0.05,
+                reflectance: 0.5,
+                specular_transmission: 1.0,
+                ior: 1.5,
+                thickness: 0.5,
+                ..base
+            },
         }
     }
 }
@@ -265,6 +422,46 @@ pub fn apply_material_data_to_standard(
         data.attenuation_color[1],
         data.attenuation_color[2],
     );
+
+    // --- Anisotropy ---
+    material.anisotropy_strength = data.anisotropy_strength;
+    material.anisotropy_rotation = data.anisotropy_rotation;
+    material.anisotropy_texture = data.anisotropy_texture.as_ref()
+        .and_then(|id| texture_handles.0.get(id))
+        .cloned();
+
+    // --- Sheen ---
+    // Note: Bevy's StandardMaterial has no sheen layer. Sheen color and roughness
+    // are stored in MaterialData so they survive save and load.
+}
+
+/// Point texture `slot` of `data` at `asset_id`, or clear it with `None`.
+/// Returns false if `slot` is not a known texture slot.
+pub fn set_texture_slot(data: &mut MaterialData, slot: &str, asset_id: Option<String>) -> bool {
+    let texture = match slot {
+        "base_color" => &mut data.base_color_texture,
+        "normal_map" => &mut data.normal_map_texture,
+        "metallic_roughness" => &mut data.metallic_roughness_texture,
+        "emissive" => &mut data.emissive_texture,
+        "occlusion" => &mut data.occlusion_texture,
+        "depth_map" => &mut data.depth_map_texture,
+        "clearcoat" => &mut data.clearcoat_texture,
+        "clearcoat_roughness" => &mut data.clearcoat_roughness_texture,
+        "clearcoat_normal" => &mut data.clearcoat_normal_texture,
+        "anisotropy" => &mut data.anisotropy_texture,
+        _ => return false,
+    };
+    *texture = asset_id;
+    true
+}
+
+/// Whether a texture loaded into material `slot` holds color (sRGB) rather
+/// than data such as normals, depth or roughness (linear).
+pub fn is_srgb_texture_slot(slot: &str) -> bool {
+    !matches!(
+        slot,
+        "normal_map" | "depth_map" | "clearcoat_normal" | "clearcoat_roughness" | "metallic_roughness" | "anisotropy"
+    )
 }
 
 /// System that applies MaterialData changes to the actual StandardMaterial asset.
@@ -279,3 +476,108 @@ fn sync_material_data(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gold_preset_is_fully_metallic_gold() {
+        let gold = MaterialData::from_preset(MaterialPreset::Gold);
+        assert!((gold.metallic - 1.0).abs() < 1e-6);
+        assert_eq!(gold.base_color, [1.0, 0.766, 0.336, 1.0]);
+        assert_eq!(gold.specular_transmission, 0.0);
+    }
+
+    #[test]
+    fn glass_preset_refracts() {
+        let glass = MaterialData::from_preset(MaterialPreset::Glass);
+        assert_eq!(glass.specular_transmission, 1.0);
+        assert!((glass.ior - 1.5).abs() < 1e-6);
+        assert!(glass.thickness > 0.0);
+        assert_eq!(glass.metallic, 0.0);
+    }
+
+    #[test]
+    fn anisotropy_strength_reaches_standard_material() {
+        let data = MaterialData { anisotropy_strength: 0.8, anisotropy_rotation: 1.2, ..Default::default() };
+        let mut material = StandardMaterial::default();
+        apply_material_data_to_standard(&mut material, &data, &TextureHandleMap::default());
+        assert_eq!(material.anisotropy_strength, 0.8);
+        assert_eq!(material.anisotropy_rotation, 1.2);
+    }
+
+    #[test]
+    fn infinite_attenuation_distance_round_trips_through_json() {
+        let json = serde_json::to_string(&MaterialData::default()).unwrap();
+        let loaded: MaterialData = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.attenuation_distance, f32::INFINITY);
+
+        let finite = MaterialData { attenuation_distance: 10.0, ..Default::default() };
+        let loaded: MaterialData = serde_json::from_str(&serde_json::to_string(&finite).unwrap()).unwrap();
+        assert_eq!(loaded.attenuation_distance, 10.0);
+    }
+
+    #[test]
+    fn anisotropy_texture_slot_is_linear() {
+        assert!(!is_srgb_texture_slot("anisotropy"));
+        assert!(!is_srgb_texture_slot("normal_map"));
+        assert!(is_srgb_texture_slot("base_color"));
+    }
+
+    #[test]
+    fn anisotropy_texture_reaches_standard_material() {
+        let mut images = Assets::<Image>::default();
+        let handle = images.add(Image::default());
+        let mut texture_handles = TextureHandleMap::default();
+        texture_handles.0.insert("brushed".to_string(), handle.clone());
+
+        let mut data = MaterialData::default();
+        assert!(set_texture_slot(&mut data, "anisotropy", Some("brushed".to_string())));
+        let mut material = StandardMaterial::default();
+        apply_material_data_to_standard(&mut material, &data, &texture_handles);
+        assert_eq!(material.anisotropy_texture, Some(handle));
+
+        assert!(set_texture_slot(&mut data, "anisotropy", None));
+        apply_material_data_to_standard(&mut material, &data, &texture_handles);
+        assert_eq!(material.anisotropy_texture, None);
+
+        assert!(!set_texture_slot(&mut data, "sheen", Some("brushed".to_string())));
+    }
+
+    #[test]
+    fn anisotropy_and_sheen_round_trip_through_json() {
+        let data = MaterialData {
+            anisotropy_strength: 0.5,
+            anisotropy_rotation: 1.2,
+            anisotropy_texture: Some("brushed".to_string()),
+            sheen_color: [0.9, 0.2, 0.4],
+            sheen_roughness: 0.7,
+            ..Default::default()
+        };
+        let loaded: MaterialData = serde_json::from_str(&serde_json::to_string(&data).unwrap()).unwrap();
+        assert_eq!(loaded.anisotropy_strength, 0.5);
+        assert_eq!(loaded.anisotropy_rotation, 1.2);
+        assert_eq!(loaded.anisotropy_texture.as_deref(), Some("brushed"));
+        assert_eq!(loaded.sheen_color, [0.9, 0.2, 0.4]);
+        assert_eq!(loaded.sheen_roughness, 0.7);
+
+        // Scenes saved before these fields existed load with them disabled
+        let mut old = serde_json::to_value(MaterialData::default()).unwrap();
+        for key in ["anisotropyStrength", "anisotropyRotation", "sheenColor", "sheenRoughness"] {
+            old.as_object_mut().unwrap().remove(key);
+        }
+        let loaded: MaterialData = serde_json::from_value(old).unwrap();
+        assert_eq!(loaded.anisotropy_strength, 0.0);
+        assert_eq!(loaded.anisotropy_texture, None);
+        assert_eq!(loaded.sheen_color, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn preset_names_round_trip() {
+        for preset in MaterialPreset::ALL {
+            assert_eq!(MaterialPreset::from_name(preset.as_str()), Some(preset));
+        }
+        assert_eq!(MaterialPreset::from_name("unobtainium"), None);
+    }
+}
@@ -0,0 +1,140 @@
+//! Measurement (ruler) tool for the editor.
+//!
+//! Holds the active measurement — the distance between two points or the
+//! angle at the middle of three — and draws it with gizmos. The numeric label
+//! is drawn by the editor UI from the `MEASUREMENT_RESULT` event, anchored at
+//! `MeasurementState::label_anchor`, since the engine has no text rendering.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// What a measurement reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeasureMode {
+    /// Length between two points, in world units.
+    #[default]
+    Distance,
+    /// Angle at the second of three points, in degrees.
+    Angle,
+}
+
+impl MeasureMode {
+    /// Number of points this mode needs.
+    pub fn point_count(self) -> usize {
+        match self {
+            MeasureMode::Distance => 2,
+            MeasureMode::Angle => 3,
+        }
+    }
+}
+
+/// Resource holding the active measurement. Empty `points` means none.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MeasurementState {
+    pub mode: MeasureMode,
+    pub points: Vec<Vec3>,
+}
+
+impl MeasurementState {
+    /// Measured value, or `None` when the points don't match the mode or an
+    /// angle arm has zero length.
+    pub fn value(&self) -> Option<f32> {
+        match (self.mode, self.points.as_slice()) {
+            (MeasureMode::Distance, [a, b]) => Some(a.distance(*b)),
+            (MeasureMode::Angle, [a, vertex, b]) => {
+                let (arm_a, arm_b) = (*a - *vertex, *b - *vertex);
+                if arm_a.length_squared() <= f32::EPSILON || arm_b.length_squared() <= f32::EPSILON
+                {
+                    return None;
+                }
+                Some(arm_a.angle_between(arm_b).to_degrees())
+            }
+            _ => None,
+        }
+    }
+
+    /// World position the UI should anchor the value label at: the midpoint
+    /// of a distance, or the vertex of an angle.
+    pub fn label_anchor(&self) -> Option<Vec3> {
+        match (self.mode, self.points.as_slice()) {
+            (MeasureMode::Distance, [a, b]) => Some(a.midpoint(*b)),
+            (MeasureMode::Angle, [_, vertex, _]) => Some(*vertex),
+            _ => None,
+        }
+    }
+}
+
+/// Plugin that holds the measurement state and draws it.
+pub struct MeasurementPlugin;
+
+impl Plugin for MeasurementPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MeasurementState>()
+            .add_systems(Update, render_measurement);
+    }
+}
+
+/// System that draws the active measurement using Bevy gizmos.
+fn render_measurement(state: Res<MeasurementState>, mut gizmos: Gizmos) {
+    if state.value().is_none() {
+        return;
+    }
+
+    let line_color = Color::srgb(1.0, 0.85, 0.1);
+    gizmos.linestrip(state.points.iter().copied(), line_color);
+    for point in &state.points {
+        gizmos.sphere(Isometry3d::from_translation(*point), 0.05, line_color);
+    }
+
+    // Angle arc at the vertex, a quarter of the shorter arm long.
+    if let (MeasureMode::Angle, [a, vertex, b]) = (state.mode, state.points.as_slice()) {
+        let radius = 0.25 * (*a - *vertex).length().min((*b - *vertex).length());
+        let (from, to) = ((*a - *vertex).normalize(), (*b - *vertex).normalize());
+        let rotation = Quat::from_rotation_arc(from, to);
+        let arc = (0..=16)
+            .map(|i| *vertex + Quat::IDENTITY.slerp(rotation, i as f32 / 16.0) * from * radius);
+        gizmos.linestrip(arc, Color::srgb(1.0, 0.6, 0.1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_between_two_points() {
+        let state = MeasurementState {
+            mode: MeasureMode::Distance,
+            points: vec![Vec3::ZERO, Vec3::new(3.0, 4.0, 0.0)],
+        };
+        assert_eq!(state.value(), Some(5.0));
+        assert_eq!(state.label_anchor(), Some(Vec3::new(1.5, 2.0, 0.0)));
+    }
+
+    #[test]
+    fn angle_at_middle_point() {
+        let state = MeasurementState {
+            mode: MeasureMode::Angle,
+            points: vec![Vec3::X, Vec3::ZERO, Vec3::new(1.0, 1.0, 0.0)],
+        };
+        let angle = state.value().expect("angle");
+        assert!((angle - 45.0).abs() < 1e-4, "got {angle}");
+        assert_eq!(state.label_anchor(), Some(Vec3::ZERO));
+    }
+
+    #[test]
+    fn mismatched_or_degenerate_points_have_no_value() {
+        let mut state = MeasurementState {
+            mode: MeasureMode::Angle,
+            points: vec![Vec3::ZERO, Vec3::X],
+        };
+        assert_eq!(state.value(), None);
+
+        state.points = vec![Vec3::ZERO, Vec3::ZERO, Vec3::X];
+        assert_eq!(state.value(), None);
+
+        state.points.clear();
+        assert_eq!(state.label_anchor(), None);
+    }
+}
@@ -265,6 +265,28 @@ impl VertexAttributes {
         }
     }
 
+    /// Mark UV seam vertices: vertices sharing a position with another vertex
+    /// that has a different UV. Seams stay pinned during simplification so
+    /// the two sides of the seam can't drift apart and open a crack.
+    fn seam_vertices(&self, positions: &[[f32; 3]]) -> Vec<bool> {
+        let mut seam = vec![false; positions.len()];
+        let Some(ref uvs) = self.uvs else {
+            return seam;
+        };
+        let mut coincident: std::collections::HashMap<[i64; 3], Vec<usize>> = std::collections::HashMap::new();
+        for (i, p) in positions.iter().enumerate() {
+            coincident.entry(p.map(|c| (c * 1e5).round() as i64)).or_default().push(i);
+        }
+        for group in coincident.values() {
+            if group.iter().any(|&i| uvs[i] != uvs[group[0]]) {
+                for &i in group {
+                    seam[i] = true;
+                }
+            }
+        }
+        seam
+    }
+
     /// Compact the attribute arrays to only include vertices marked as used.
     fn compact(&self, vertex_used: &[bool]) -> CompactedAttributes {
         let uvs = self.uvs.as_ref().map(|arr| {
@@ -407,6 +429,7 @@ fn qem_simplify_with_attrs(
     let n = positions.len();
     let mut pos = positions.to_vec();
     let mut remap: Vec<usize> = (0..n).collect();
+    let mut seam = attrs.seam_vertices(positions);
 
     let mut quadrics = vec![Quadric::zero(); n];
     let tri_count_initial = indices.len() / 3;
@@ -486,8 +509,16 @@ fn qem_simplify_with_attrs(
         // from where they were when this entry was pushed onto the heap.
         let combined_q = quadrics[actual_v1].add(&quadrics[actual_v2]);
         let fresh_t = combined_q.optimal_t(pos[actual_v1], pos[actual_v2]);
-        pos[actual_v1] = collapse.optimal;
-        attrs.interpolate(actual_v1, actual_v2, fresh_t);
+        // Seam vertices never move; an edge can only collapse onto them.
+        let (optimal, t) = match (seam[actual_v1], seam[actual_v2]) {
+            (true, true) => continue,
+            (true, false) => (pos[actual_v1], 0.0),
+            (false, true) => (pos[actual_v2], 1.0),
+            (false, false) => (collapse.optimal, fresh_t),
+        };
+        pos[actual_v1] = optimal;
+        attrs.interpolate(actual_v1, actual_v2, t);
+        seam[actual_v1] |= seam[actual_v2];
 
         remap[actual_v2] = actual_v1;
         quadrics[actual_v1] = quadrics[actual_v1].add(&quadrics[actual_v2]);
@@ -900,6 +931,37 @@ mod tests {
         assert!(result_tris < orig_tris, "Expected fewer triangles");
     }
 
+    #[test]
+    fn qem_keeps_uv_seam_vertices_in_place() {
+        use bevy::math::primitives::Sphere;
+        use bevy::mesh::Meshable;
+
+        let sphere = Sphere::new(1.0).mesh().uv(24, 16);
+        let Some(VertexAttributeValues::Float32x2(uvs)) = sphere.attribute(Mesh::ATTRIBUTE_UV_0) else {
+            panic!("sphere should have UVs");
+        };
+        let Some(VertexAttributeValues::Float32x3(positions)) = sphere.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            panic!("sphere should have positions");
+        };
+        // The u = 0 / u = 1 meridian is duplicated with different UVs.
+        let seam: Vec<[f32; 3]> = positions.iter().zip(uvs)
+            .filter(|(_, uv)| uv[0] == 0.0)
+            .map(|(p, _)| *p)
+            .collect();
+
+        let result = QemSimplifier.simplify(&sphere, 0.25);
+        let Some(VertexAttributeValues::Float32x3(simplified)) = result.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            panic!("simplified mesh should have positions");
+        };
+        for p in seam {
+            assert!(
+                simplified.iter().any(|q| (0..3).all(|k| (p[k] - q[k]).abs() < 1e-5)),
+                "seam vertex {:?} moved or was removed",
+                p
+            );
+        }
+    }
+
     #[test]
     fn fast_simplifier_trait_reduces_triangles() {
         let mesh = make_grid(10);
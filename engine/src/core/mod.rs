@@ -3,6 +3,7 @@
 //! This module contains all game engine logic that is platform-agnostic.
 //! All browser/JS interop must go through the bridge module.
 
+pub mod align;
 pub mod animation;
 pub mod animation_clip;
 pub mod asset_manager;
@@ -11,6 +12,8 @@ pub mod blend_tree2d;
 pub mod camera;
 pub mod camera_2d;
 pub mod camera_presets;
+pub mod clipboard;
+pub mod command_log;
 pub mod commands;
 pub mod csg;
 pub mod custom_wgsl;
@@ -19,18 +22,26 @@ pub mod engine_mode;
 pub mod entity_factory;
 pub mod entity_id;
 pub mod environment;
+pub mod events;
+pub mod freeze_transform;
 pub mod game_camera;
 pub mod game_components;
 pub mod game_components_helpers;
 pub mod gizmo;
+pub mod gltf_export;
 pub mod history;
 pub mod input;
+pub mod instancing;
 pub mod lighting;
 pub mod lod;
 pub mod material;
+pub mod measurement;
 pub mod mesh_simplify;
+#[cfg(feature = "webgpu")]
+pub mod occlusion;
 pub mod observability;
 pub mod particles;
+pub mod path;
 pub mod pending;
 /// Backward-compatible alias for the split pending_commands module.
 pub mod pending_commands {
@@ -39,10 +50,13 @@ pub mod pending_commands {
 pub mod physics;
 pub mod physics_2d;
 pub mod physics_2d_sim;
+pub mod physics_bake;
 pub mod post_processing;
+pub mod prefab;
 pub mod procedural_mesh;
 pub mod project_type;
 pub mod quality;
+pub mod render_mode;
 pub mod reparent;
 pub mod reverb_zone;
 pub mod shader_effects;
@@ -51,11 +65,19 @@ pub mod scene_file;
 pub mod scene_graph;
 pub mod scripting;
 pub mod selection;
+pub mod selection_outline;
 pub mod skeletal_animation2d;
 pub mod skeleton2d;
 pub mod snap;
+pub mod spatial_query;
 pub mod sprite;
+pub mod subdivision;
 pub mod terrain;
+#[cfg(test)]
+pub mod test_app;
+#[cfg(test)]
+pub use test_app::{test_app, TestApp};
+pub mod terrain_material;
 pub mod tilemap;
 pub mod tileset;
 pub mod viewport;
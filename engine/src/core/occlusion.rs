@@ -0,0 +1,328 @@
+//! Coarse occlusion culling for dense scenes (WebGPU builds only).
+//!
+//! Every frame the bounding boxes of visible meshes are projected through the
+//! active camera into a small depth grid covering the screen; each occluder
+//! writes its farthest view depth into the cells its projected silhouette (the
+//! convex hull of the box corners) fully covers. A mesh whose
+//! whole screen rectangle lies behind the grid is tagged `OcclusionCulled` and
+//! set to `Visibility::Hidden`, which takes effect on the next frame. This
+//! one-frame-latency CPU depth test stands in for a GPU depth readback, which
+//! would be asynchronous anyway.
+
+use bevy::prelude::*;
+use bevy::camera::primitives::Aabb;
+use bevy::camera::visibility::{NoFrustumCulling, VisibilitySystems};
+
+use super::camera::EditorCamera;
+use super::entity_id::EntityVisible;
+use super::game_camera::ActiveGameCamera;
+use super::lod::PerformanceMetrics;
+use super::pending_commands::PendingCommands;
+use super::selection_outline::OutlineShell;
+
+/// Depth grid resolution (cells across and down the screen).
+const OCCLUSION_GRID_SIZE: usize = 64;
+
+/// Occlusion culling toggle, set by the `set_occlusion_culling` command.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct OcclusionCullingSettings {
+    pub enabled: bool,
+}
+
+/// Marks an entity hidden by occlusion culling rather than by the user.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OcclusionCulled;
+
+/// Screen rectangle (NDC), projected corners and view-depth range of a
+/// projected bounding box.
+#[derive(Debug, Clone, Copy)]
+struct ScreenBounds {
+    min: Vec2,
+    max: Vec2,
+    corners: [Vec2; 8],
+    near: f32,
+    far: f32,
+}
+
+/// Project an entity's bounding box. Returns `None` when any corner is
+/// behind the camera, so the entity is neither occluder nor occludee.
+fn project_bounds(aabb: &Aabb, transform: &GlobalTransform, view_from_world: Mat4, clip_from_view: Mat4) -> Option<ScreenBounds> {
+    let center = Vec3::from(aabb.center);
+    let half = Vec3::from(aabb.half_extents);
+    let mut bounds = ScreenBounds {
+        min: Vec2::splat(f32::INFINITY),
+        max: Vec2::splat(f32::NEG_INFINITY),
+        corners: [Vec2::ZERO; 8],
+        near: f32::INFINITY,
+        far: f32::NEG_INFINITY,
+    };
+    for i in 0..8 {
+        let sign = Vec3::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 },
+        );
+        let world = transform.transform_point(center + half * sign);
+        let view = view_from_world.transform_point3(world);
+        let clip = clip_from_view * view.extend(1.0);
+        if clip.w <= 1e-5 {
+            return None;
+        }
+        let ndc = clip.truncate().truncate() / clip.w;
+        bounds.corners[i] = ndc;
+        bounds.min = bounds.min.min(ndc);
+        bounds.max = bounds.max.max(ndc);
+        bounds.near = bounds.near.min(-view.z);
+        bounds.far = bounds.far.max(-view.z);
+    }
+    Some(bounds)
+}
+
+/// Counter-clockwise convex hull of a point set (Andrew's monotone chain).
+fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    // Append `p`, first dropping points that would make a clockwise turn,
+    // but never shrinking the chain below `min_len`.
+    fn push_turning(hull: &mut Vec<Vec2>, min_len: usize, p: Vec2) {
+        while hull.len() >= min_len && (hull[hull.len() - 1] - hull[hull.len() - 2]).perp_dot(p - hull[hull.len() - 2]) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    let mut hull = Vec::with_capacity(sorted.len() + 1);
+    for &p in &sorted {
+        push_turning(&mut hull, 2, p);
+    }
+    let lower_len = hull.len() + 1;
+    for &p in sorted.iter().rev().skip(1) {
+        push_turning(&mut hull, lower_len, p);
+    }
+    // The upper chain ends back at the first point
+    hull.pop();
+    hull
+}
+
+/// True when `point` lies inside (or on) a counter-clockwise convex polygon.
+fn hull_contains(hull: &[Vec2], point: Vec2) -> bool {
+    hull.len() >= 3
+        && hull.iter().zip(hull.iter().cycle().skip(1)).all(|(&a, &b)| (b - a).perp_dot(point - a) >= 0.0)
+}
+
+/// Coarse screen-space depth grid; every cell holds the nearest occluder depth.
+struct DepthGrid {
+    cells: Vec<f32>,
+}
+
+impl DepthGrid {
+    fn new() -> Self {
+        Self { cells: vec![f32::INFINITY; OCCLUSION_GRID_SIZE * OCCLUSION_GRID_SIZE] }
+    }
+
+    /// Cell range overlapped by an NDC span, clamped to the screen.
+    /// `inner` keeps only cells the span covers completely.
+    fn cell_range(min: f32, max: f32, inner: bool) -> std::ops::Range<usize> {
+        let size = OCCLUSION_GRID_SIZE as f32;
+        let to_cell = |ndc: f32| (ndc * 0.5 + 0.5) * size;
+        let (start, end) = if inner {
+            (to_cell(min).ceil(), to_cell(max).floor())
+        } else {
+            (to_cell(min).floor(), to_cell(max).ceil())
+        };
+        (start.clamp(0.0, size) as usize)..(end.clamp(0.0, size) as usize)
+    }
+
+    /// Write the occluder into the cells that lie entirely inside its
+    /// silhouette. A cell is convex, so it is inside when its four corners are.
+    fn write_occluder(&mut self, bounds: &ScreenBounds) {
+        let hull = convex_hull(&bounds.corners);
+        let to_ndc = |cell: usize| cell as f32 / OCCLUSION_GRID_SIZE as f32 * 2.0 - 1.0;
+        for y in Self::cell_range(bounds.min.y, bounds.max.y, true) {
+            for x in Self::cell_range(bounds.min.x, bounds.max.x, true) {
+                let (x0, x1, y0, y1) = (to_ndc(x), to_ndc(x + 1), to_ndc(y), to_ndc(y + 1));
+                let covered = [Vec2::new(x0, y0), Vec2::new(x1, y0), Vec2::new(x0, y1), Vec2::new(x1, y1)]
+                    .into_iter()
+                    .all(|corner| hull_contains(&hull, corner));
+                if covered {
+                    let cell = &mut self.cells[y * OCCLUSION_GRID_SIZE + x];
+                    *cell = cell.min(bounds.far);
+                }
+            }
+        }
+    }
+
+    /// True when every cell under the rectangle has an occluder nearer than
+    /// the entity's nearest point. Off-screen entities are left to frustum culling.
+    fn is_occluded(&self, bounds: &ScreenBounds) -> bool {
+        let rows = Self::cell_range(bounds.min.y, bounds.max.y, false);
+        let columns = Self::cell_range(bounds.min.x, bounds.max.x, false);
+        if rows.is_empty() || columns.is_empty() {
+            return false;
+        }
+        rows.into_iter().all(|y| {
+            columns.clone().all(|x| self.cells[y * OCCLUSION_GRID_SIZE + x] < bounds.near)
+        })
+    }
+}
+
+/// Plugin that registers occlusion culling.
+pub struct OcclusionCullingPlugin;
+
+impl Plugin for OcclusionCullingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OcclusionCullingSettings>()
+            .add_systems(Update, apply_occlusion_culling_requests)
+            .add_systems(PostUpdate, update_occlusion_culling.after(VisibilitySystems::CheckVisibility));
+    }
+}
+
+/// System that applies pending set_occlusion_culling requests.
+pub fn apply_occlusion_culling_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<OcclusionCullingSettings>,
+) {
+    for request in pending.set_occlusion_culling_requests.drain(..) {
+        settings.enabled = request.enabled;
+        tracing::info!("Occlusion culling {}", if request.enabled { "enabled" } else { "disabled" });
+    }
+}
+
+/// System that tests every mesh against the depth grid built from this
+/// frame's occluders and hides the ones fully behind it.
+#[allow(clippy::type_complexity)]
+pub fn update_occlusion_culling(
+    mut commands: Commands,
+    settings: Res<OcclusionCullingSettings>,
+    game_cameras: Query<(&GlobalTransform, &Projection), With<ActiveGameCamera>>,
+    editor_cameras: Query<(&GlobalTransform, &Projection), With<EditorCamera>>,
+    mut meshes: Query<
+        (Entity, &Aabb, &GlobalTransform, &mut Visibility, Option<&EntityVisible>, Has<OcclusionCulled>),
+        (With<Mesh3d>, Without<NoFrustumCulling>, Without<OutlineShell>),
+    >,
+    metrics: Option<ResMut<PerformanceMetrics>>,
+) {
+    let camera = game_cameras.iter().next().or_else(|| editor_cameras.iter().next());
+    let (Some((camera_transform, projection)), true) = (camera, settings.enabled) else {
+        // Disabled (or no camera): give back everything we hid.
+        for (entity, _, _, mut visibility, _, culled) in meshes.iter_mut() {
+            if culled {
+                *visibility = Visibility::Inherited;
+                commands.entity(entity).remove::<OcclusionCulled>();
+            }
+        }
+        if let Some(mut metrics) = metrics {
+            metrics.occluded_count = 0;
+        }
+        return;
+    };
+
+    let view_from_world = Mat4::from(camera_transform.affine().inverse());
+    let clip_from_view = projection.get_clip_from_view();
+
+    // User-hidden meshes neither occlude nor get touched. Culled meshes still
+    // occlude, so the result doesn't flicker as they toggle.
+    let projected: Vec<(Entity, ScreenBounds, bool)> = meshes.iter()
+        .filter(|(_, _, _, _, entity_visible, _)| entity_visible.is_none_or(|v| v.0))
+        .filter_map(|(entity, aabb, transform, _, _, culled)| {
+            project_bounds(aabb, transform, view_from_world, clip_from_view).map(|bounds| (entity, bounds, culled))
+        })
+        .collect();
+
+    let mut grid = DepthGrid::new();
+    for (_, bounds, _) in &projected {
+        grid.write_occluder(bounds);
+    }
+
+    let mut occluded_count = 0u32;
+    for (entity, bounds, culled) in projected {
+        let Ok((_, _, _, mut visibility, _, _)) = meshes.get_mut(entity) else {
+            continue;
+        };
+        if grid.is_occluded(&bounds) {
+            occluded_count += 1;
+            // Re-hide even when already marked: sync_visibility may have
+            // reset Visibility after the user toggled the entity.
+            visibility.set_if_neq(Visibility::Hidden);
+            if !culled {
+                commands.entity(entity).insert(OcclusionCulled);
+            }
+        } else if culled {
+            *visibility = Visibility::Inherited;
+            commands.entity(entity).remove::<OcclusionCulled>();
+        }
+    }
+
+    // The user hid these meanwhile; their visibility is theirs again.
+    for (entity, _, _, _, entity_visible, culled) in meshes.iter() {
+        if culled && entity_visible.is_some_and(|v| !v.0) {
+            commands.entity(entity).remove::<OcclusionCulled>();
+        }
+    }
+
+    if let Some(mut metrics) = metrics {
+        metrics.occluded_count = occluded_count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_box(app: &mut App, position: Vec3, half_extents: Vec3) -> Entity {
+        app.world_mut().spawn((
+            Mesh3d(Handle::default()),
+            Aabb::from_min_max(-half_extents, half_extents),
+            GlobalTransform::from(Transform::from_translation(position)),
+            Visibility::default(),
+        )).id()
+    }
+
+    fn visibility(app: &App, entity: Entity) -> Visibility {
+        *app.world().get::<Visibility>(entity).unwrap()
+    }
+
+    #[test]
+    fn object_behind_wall_is_hidden_and_restored() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(OcclusionCullingSettings { enabled: true })
+            .add_systems(Update, update_occlusion_culling);
+        // Looks down -Z from the origin.
+        app.world_mut().spawn((EditorCamera, Projection::default(), GlobalTransform::default()));
+
+        let wall = spawn_box(&mut app, Vec3::new(0.0, 0.0, -10.0), Vec3::new(20.0, 20.0, 0.5));
+        let hidden = spawn_box(&mut app, Vec3::new(0.0, 0.0, -20.0), Vec3::ONE);
+        let in_front = spawn_box(&mut app, Vec3::new(1.0, 0.0, -5.0), Vec3::splat(0.5));
+        app.update();
+
+        assert_eq!(visibility(&app, hidden), Visibility::Hidden);
+        assert!(app.world().get::<OcclusionCulled>(hidden).is_some());
+        assert_eq!(visibility(&app, in_front), Visibility::Inherited);
+        assert_eq!(visibility(&app, wall), Visibility::Inherited);
+
+        app.world_mut().despawn(wall);
+        app.update();
+        assert_eq!(visibility(&app, hidden), Visibility::Inherited);
+        assert!(app.world().get::<OcclusionCulled>(hidden).is_none());
+    }
+
+    #[test]
+    fn object_beside_a_ground_plane_is_not_hidden_by_its_screen_rectangle() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(OcclusionCullingSettings { enabled: true })
+            .add_systems(Update, update_occlusion_culling);
+        // A banked camera turns the plane's trapezoid on screen, so its
+        // bounding rectangle covers open ground beside the far edge.
+        let banked = Transform::from_rotation(Quat::from_rotation_z(30f32.to_radians()));
+        app.world_mut().spawn((EditorCamera, Projection::default(), GlobalTransform::from(banked)));
+
+        spawn_box(&mut app, Vec3::new(0.0, -2.0, -30.0), Vec3::new(10.0, 0.05, 20.0));
+        let beside_far_edge = spawn_box(&mut app, Vec3::new(15.0, -1.5, -52.0), Vec3::splat(0.5));
+        app.update();
+
+        assert_eq!(visibility(&app, beside_far_edge), Visibility::Inherited);
+        assert!(app.world().get::<OcclusionCulled>(beside_far_edge).is_none());
+    }
+}
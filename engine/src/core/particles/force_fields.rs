@@ -0,0 +1,155 @@
+//! Force fields that push particles of every nearby emitter.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ParticleData;
+
+/// How a force field pushes particles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForceFieldKind {
+    /// Pull particles toward the field center.
+    #[default]
+    Attractor,
+    /// Push particles away from the field center.
+    Repulsor,
+    /// Swirl particles around the field's vertical axis.
+    Vortex,
+}
+
+/// Force field that affects every particle system within `radius` of the
+/// entity, not only particles emitted by the entity itself.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticleForceField {
+    pub kind: ForceFieldKind,
+    /// Acceleration in units/s² applied to particles inside the field.
+    pub strength: f32,
+    pub radius: f32,
+}
+
+impl Default for ParticleForceField {
+    fn default() -> Self {
+        Self {
+            kind: ForceFieldKind::Attractor,
+            strength: 5.0,
+            radius: 5.0,
+        }
+    }
+}
+
+/// A force field as seen by one emitter: the center is expressed in the
+/// emitter's simulation space (world, or emitter-local).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AppliedForceField {
+    pub kind: ForceFieldKind,
+    pub strength: f32,
+    pub radius: f32,
+    pub center: Vec3,
+}
+
+/// Force fields whose radius reaches a particle emitter. Maintained by
+/// `sync_particle_force_fields` and only rewritten when the set changes, so
+/// GPU effects rebuild when a nearby field is added, edited, moved, or removed.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ParticleForceFieldsInRange(pub Vec<AppliedForceField>);
+
+/// Collect the force fields reaching an emitter at `emitter`.
+pub fn force_fields_in_range(
+    emitter: &GlobalTransform,
+    world_space: bool,
+    fields: impl IntoIterator<Item = (ParticleForceField, Vec3)>,
+) -> Vec<AppliedForceField> {
+    let emitter_pos = emitter.translation();
+    let to_local = emitter.affine().inverse();
+    fields.into_iter()
+        .filter(|(field, center)| center.distance(emitter_pos) <= field.radius)
+        .map(|(field, center)| AppliedForceField {
+            kind: field.kind,
+            strength: field.strength,
+            radius: field.radius,
+            center: if world_space { center } else { to_local.transform_point3(center) },
+        })
+        .collect()
+}
+
+/// System that keeps `ParticleForceFieldsInRange` up to date on every
+/// particle emitter.
+pub fn sync_particle_force_fields(
+    mut commands: Commands,
+    fields: Query<(&ParticleForceField, &GlobalTransform)>,
+    mut emitters: Query<(Entity, &ParticleData, &GlobalTransform, Option<&mut ParticleForceFieldsInRange>)>,
+) {
+    for (entity, data, transform, in_range) in emitters.iter_mut() {
+        let applied = force_fields_in_range(
+            transform,
+            data.world_space,
+            fields.iter().map(|(field, gt)| (*field, gt.translation())),
+        );
+        match in_range {
+            Some(mut in_range) => {
+                in_range.set_if_neq(ParticleForceFieldsInRange(applied));
+            }
+            None if !applied.is_empty() => {
+                commands.entity(entity).insert(ParticleForceFieldsInRange(applied));
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn force_field_round_trips_through_json() {
+        let field = ParticleForceField { kind: ForceFieldKind::Vortex, strength: 12.5, radius: 3.0 };
+        let json = serde_json::to_value(field).expect("serialize");
+        assert_eq!(json["kind"], "vortex");
+        let back: ParticleForceField = serde_json::from_value(json).expect("deserialize");
+        assert_eq!(back, field);
+    }
+
+    #[test]
+    fn emitters_track_nearby_force_field_changes() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_particle_force_fields);
+        let near = app.world_mut().spawn((ParticleData::default(), GlobalTransform::default())).id();
+        let far = app.world_mut()
+            .spawn((ParticleData::default(), GlobalTransform::from_translation(Vec3::X * 50.0)))
+            .id();
+        let field = app.world_mut()
+            .spawn((ParticleForceField::default(), GlobalTransform::from_translation(Vec3::Y * 2.0)))
+            .id();
+
+        app.update();
+        let applied = &app.world().get::<ParticleForceFieldsInRange>(near).expect("near emitter").0;
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].kind, ForceFieldKind::Attractor);
+        // Local-space emitters see the center relative to themselves.
+        assert_eq!(applied[0].center, Vec3::Y * 2.0);
+        assert!(app.world().get::<ParticleForceFieldsInRange>(far).is_none());
+
+        // An unchanged field leaves the component untouched (no effect rebuild).
+        let last_changed = |app: &App| {
+            app.world().entity(near).get_ref::<ParticleForceFieldsInRange>().expect("in range").last_changed()
+        };
+        let before = last_changed(&app);
+        app.update();
+        assert_eq!(last_changed(&app), before);
+
+        // Editing the field marks the nearby emitter for a rebuild.
+        app.world_mut().get_mut::<ParticleForceField>(field).expect("field").kind = ForceFieldKind::Vortex;
+        app.update();
+        assert_ne!(last_changed(&app), before);
+        let applied = &app.world().get::<ParticleForceFieldsInRange>(near).expect("in range").0;
+        assert_eq!(applied[0].kind, ForceFieldKind::Vortex);
+
+        // Removing it clears the emitter's field list.
+        app.world_mut().despawn(field);
+        app.update();
+        assert!(app.world().get::<ParticleForceFieldsInRange>(near).expect("in range").0.is_empty());
+    }
+}
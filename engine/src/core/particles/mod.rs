@@ -8,6 +8,10 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+mod force_fields;
+
+pub use force_fields::*;
+
 /// Blend mode for particle rendering.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -73,6 +77,18 @@ impl Default for ParticleOrientation {
     }
 }
 
+/// Event on a parent particle that fires its sub-emitter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubEmitterTrigger {
+    /// Spawn when a parent particle reaches the end of its lifetime.
+    #[default]
+    OnDeath,
+    /// Spawn when a parent particle hits something. GPU particles have no
+    /// collision events yet, so the WebGPU path treats this like `OnDeath`.
+    OnCollision,
+}
+
 /// Named preset identifier.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -145,6 +161,10 @@ pub struct SizeKeyframe {
 }
 
 /// Particle effect configuration component.
+fn default_flipbook_cells() -> u32 {
+    1
+}
+
 /// Serializable, bridge-friendly representation of a particle system.
 /// Always compiled on both WebGL2 and WebGPU.
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
@@ -186,8 +206,30 @@ pub struct ParticleData {
     pub blend_mode: ParticleBlendMode,
     pub orientation: ParticleOrientation,
 
+    // -- Texture / flipbook --
+    /// Texture asset sampled by each particle (None = flat gradient color).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub texture_asset_id: Option<String>,
+    /// Sprite sheet grid; 1x1 uses the whole texture.
+    #[serde(default = "default_flipbook_cells")]
+    pub flipbook_cols: u32,
+    #[serde(default = "default_flipbook_cells")]
+    pub flipbook_rows: u32,
+    /// Frames per second, looping. 0 plays the sheet once over each
+    /// particle's lifetime.
+    #[serde(default)]
+    pub flipbook_fps: f32,
+
     // -- Misc --
     pub world_space: bool,
+
+    // -- Sub-emitter --
+    /// Effect spawned at each parent particle when `sub_emitter_trigger` fires.
+    /// Sub-emitters cannot have sub-emitters of their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_emitter: Option<Box<ParticleData>>,
+    #[serde(default)]
+    pub sub_emitter_trigger: SubEmitterTrigger,
 }
 
 impl Default for ParticleData {
@@ -218,7 +260,13 @@ impl Default for ParticleData {
             ],
             blend_mode: ParticleBlendMode::AlphaBlend,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 }
@@ -269,7 +317,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::Additive,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -305,7 +359,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::AlphaBlend,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -341,7 +401,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::Additive,
             orientation: ParticleOrientation::VelocityAligned,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -375,7 +441,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::AlphaBlend,
             orientation: ParticleOrientation::VelocityAligned,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -413,7 +485,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::AlphaBlend,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -466,7 +544,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::Additive,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -519,7 +603,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::Additive,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: false,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -561,7 +651,13 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::AlphaBlend,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: true,
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
         }
     }
 
@@ -597,7 +693,27 @@ impl ParticleData {
             ],
             blend_mode: ParticleBlendMode::Additive,
             orientation: ParticleOrientation::Billboard,
+            texture_asset_id: None,
+            flipbook_cols: 1,
+            flipbook_rows: 1,
+            flipbook_fps: 0.0,
             world_space: false, // local space: particles inherit entity transform, creating trail effect
+            sub_emitter: None,
+            sub_emitter_trigger: SubEmitterTrigger::OnDeath,
+        }
+    }
+
+    /// Number of frames in the flipbook sprite sheet.
+    pub fn flipbook_frame_count(&self) -> u32 {
+        self.flipbook_cols.max(1) * self.flipbook_rows.max(1)
+    }
+
+    /// Particles a sub-emitter spawns per trigger: the burst count for
+    /// burst/once spawners, or the per-second rate for continuous ones.
+    pub fn sub_emitter_spawn_count(&self) -> u32 {
+        match &self.spawner_mode {
+            SpawnerMode::Burst { count } | SpawnerMode::Once { count } => (*count).max(1),
+            SpawnerMode::Continuous { rate } => (rate.round() as u32).max(1),
         }
     }
 
@@ -617,3 +733,51 @@ impl ParticleData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_sub_emitter_round_trips_through_json() {
+        let mut sparks = ParticleData::sparks();
+        sparks.sub_emitter = Some(Box::new(ParticleData::smoke()));
+        sparks.sub_emitter_trigger = SubEmitterTrigger::OnCollision;
+
+        let json = serde_json::to_value(&sparks).expect("serialize");
+        assert_eq!(json["subEmitterTrigger"], "on_collision");
+        assert_eq!(json["subEmitter"]["preset"], "smoke");
+
+        let back: ParticleData = serde_json::from_value(json).expect("deserialize");
+        let sub = back.sub_emitter.expect("sub-emitter survives the round trip");
+        assert_eq!(sub.preset, ParticlePreset::Smoke);
+        assert_eq!(sub.color_gradient, ParticleData::smoke().color_gradient);
+        assert_eq!(back.sub_emitter_trigger, SubEmitterTrigger::OnCollision);
+    }
+
+    #[test]
+    fn flipbook_fields_default_when_missing() {
+        let mut json = serde_json::to_value(ParticleData::smoke()).expect("serialize");
+        let obj = json.as_object_mut().expect("object");
+        for key in ["flipbookCols", "flipbookRows", "flipbookFps"] {
+            obj.remove(key);
+        }
+
+        let data: ParticleData = serde_json::from_value(json).expect("deserialize");
+        assert!(data.texture_asset_id.is_none());
+        assert_eq!((data.flipbook_cols, data.flipbook_rows), (1, 1));
+        assert_eq!(data.flipbook_frame_count(), 1);
+    }
+
+    #[test]
+    fn particle_data_without_sub_emitter_fields_still_loads() {
+        let mut json = serde_json::to_value(ParticleData::fire()).expect("serialize");
+        assert!(json.get("subEmitter").is_none());
+        json.as_object_mut().expect("object").remove("subEmitterTrigger");
+
+        let data: ParticleData = serde_json::from_value(json).expect("deserialize");
+        assert!(data.sub_emitter.is_none());
+        assert_eq!(data.sub_emitter_trigger, SubEmitterTrigger::OnDeath);
+    }
+
+}
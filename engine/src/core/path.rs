@@ -0,0 +1,173 @@
+//! Spline paths for cutscenes and patrols.
+//!
+//! A `Path` entity stores control points in its local space. The curve is a
+//! uniform Catmull-Rom spline through every point, parameterized by `t` in
+//! `[0, 1]` over the whole path; closed paths wrap back to the first point.
+//! Entities travel along it with the `path_follower` game component. In Edit
+//! mode the curve is drawn with gizmos.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::engine_mode::EngineMode;
+use super::entity_id::EntityVisible;
+
+/// Samples per segment used for length estimates and drawing.
+const SAMPLES_PER_SEGMENT: usize = 16;
+
+/// Control points of a `Path` entity.
+#[derive(Component, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathData {
+    /// Control points in the path entity's local space.
+    pub points: Vec<[f32; 3]>,
+    /// Whether the curve wraps from the last point back to the first.
+    #[serde(default)]
+    pub closed: bool,
+}
+
+impl PathData {
+    /// Number of curve segments; zero when there are fewer than two points.
+    pub fn segment_count(&self) -> usize {
+        match self.points.len() {
+            0 | 1 => 0,
+            n if self.closed => n,
+            n => n - 1,
+        }
+    }
+
+    /// Control point `i`, wrapped on closed paths and clamped on open ones
+    /// (so the end segments get a duplicated neighbour).
+    fn point(&self, i: isize) -> Vec3 {
+        let n = self.points.len() as isize;
+        let i = if self.closed { i.rem_euclid(n) } else { i.clamp(0, n - 1) };
+        Vec3::from(self.points[i as usize])
+    }
+
+    /// Segment index and local parameter for a path parameter.
+    fn segment_at(&self, t: f32) -> (usize, f32) {
+        let segments = self.segment_count();
+        let scaled = t.clamp(0.0, 1.0) * segments as f32;
+        let index = (scaled.floor() as usize).min(segments - 1);
+        (index, scaled - index as f32)
+    }
+
+    /// The four control points around segment `index`.
+    fn segment_points(&self, index: usize) -> [Vec3; 4] {
+        let i = index as isize;
+        [self.point(i - 1), self.point(i), self.point(i + 1), self.point(i + 2)]
+    }
+
+    /// Local-space position at `t` in `[0, 1]`, or `None` without a segment.
+    pub fn sample(&self, t: f32) -> Option<Vec3> {
+        if self.segment_count() == 0 {
+            return None;
+        }
+        let (index, u) = self.segment_at(t);
+        let [p0, p1, p2, p3] = self.segment_points(index);
+        Some(0.5 * (
+            2.0 * p1
+            + (p2 - p0) * u
+            + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u * u
+            + (3.0 * p1 - p0 - 3.0 * p2 + p3) * u * u * u
+        ))
+    }
+
+    /// Local-space direction of travel at `t`, or `None` where the curve is
+    /// degenerate.
+    pub fn tangent(&self, t: f32) -> Option<Vec3> {
+        if self.segment_count() == 0 {
+            return None;
+        }
+        let (index, u) = self.segment_at(t);
+        let [p0, p1, p2, p3] = self.segment_points(index);
+        let derivative = 0.5 * (
+            (p2 - p0)
+            + 2.0 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u
+            + 3.0 * (3.0 * p1 - p0 - 3.0 * p2 + p3) * u * u
+        );
+        derivative.try_normalize()
+    }
+
+    /// Evenly spaced (in `t`) points along the curve, ending on its last point.
+    pub fn polyline(&self) -> Vec<Vec3> {
+        let samples = self.segment_count() * SAMPLES_PER_SEGMENT;
+        if samples == 0 {
+            return Vec::new();
+        }
+        (0..=samples)
+            .filter_map(|i| self.sample(i as f32 / samples as f32))
+            .collect()
+    }
+
+    /// Curve length in local units, measured along the polyline.
+    pub fn length(&self) -> f32 {
+        self.polyline().windows(2).map(|pair| pair[0].distance(pair[1])).sum()
+    }
+}
+
+/// Editor plugin that draws paths.
+pub struct PathGizmoPlugin;
+
+impl Plugin for PathGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, render_paths);
+    }
+}
+
+/// System that draws every visible path and its control points in Edit mode.
+fn render_paths(
+    mode: Res<EngineMode>,
+    paths: Query<(&PathData, &GlobalTransform, Option<&EntityVisible>)>,
+    mut gizmos: Gizmos,
+) {
+    if !mode.is_edit() {
+        return;
+    }
+
+    let curve_color = Color::srgb(0.2, 0.85, 0.9);
+    for (path, global, visible) in paths.iter() {
+        if visible.is_some_and(|v| !v.0) {
+            continue;
+        }
+        gizmos.linestrip(path.polyline().into_iter().map(|p| global.transform_point(p)), curve_color);
+        for point in &path.points {
+            gizmos.sphere(Isometry3d::from_translation(global.transform_point(Vec3::from(*point))), 0.08, curve_color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(closed: bool) -> PathData {
+        PathData {
+            points: vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [4.0, 0.0, 4.0], [0.0, 0.0, 4.0]],
+            closed,
+        }
+    }
+
+    #[test]
+    fn curve_passes_through_control_points() {
+        let open = square(false);
+        assert_eq!(open.segment_count(), 3);
+        for (i, point) in open.points.iter().enumerate() {
+            let sample = open.sample(i as f32 / 3.0).unwrap();
+            assert!(sample.abs_diff_eq(Vec3::from(*point), 1e-5), "point {i}: {sample:?}");
+        }
+
+        let closed = square(true);
+        assert_eq!(closed.segment_count(), 4);
+        assert!(closed.sample(1.0).unwrap().abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(closed.tangent(0.0).unwrap().x > 0.0);
+    }
+
+    #[test]
+    fn degenerate_paths_have_no_curve() {
+        let single = PathData { points: vec![[1.0, 2.0, 3.0]], closed: true };
+        assert!(single.sample(0.5).is_none());
+        assert!(single.polyline().is_empty());
+        assert_eq!(single.length(), 0.0);
+    }
+}
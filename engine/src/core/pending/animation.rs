@@ -16,6 +16,8 @@ pub enum AnimationAction {
     Play {
         clip_name: String,
         crossfade_secs: f32,
+        /// Move the entity by the root bone's motion and play the clip in place.
+        root_motion: bool,
     },
     Pause,
     Resume,
@@ -25,6 +27,9 @@ pub enum AnimationAction {
     SetLoop { looping: bool },
     SetBlendWeight { clip_name: String, weight: f32 },
     SetClipSpeed { clip_name: String, speed: f32 },
+    /// Replace the entity's 1D blend space; an empty list removes it.
+    SetBlend1d { points: Vec<crate::core::animation::BlendPoint1d> },
+    SetBlend1dValue { value: f32 },
 }
 
 #[derive(Debug, Clone)]
@@ -80,6 +85,13 @@ pub struct AnimationClipRemoval {
     pub entity_id: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct AnimationEventAdd {
+    pub entity_id: String,
+    pub clip_name: String,
+    pub event: crate::core::animation::AnimationEvent,
+}
+
 // === Skeleton 2D Request Structs ===
 
 #[derive(Debug, Clone)]
@@ -109,6 +121,7 @@ pub struct UpdateBone2dRequest {
     pub local_scale: Option<[f32; 2]>,
     pub length: Option<f32>,
     pub color: Option<[f32; 4]>,
+    pub angle_limits: Option<Option<[f32; 2]>>,
 }
 
 #[derive(Debug, Clone)]
@@ -145,6 +158,33 @@ pub struct CreateIkChain2dRequest {
     pub constraint: crate::core::skeleton2d::IkConstraint2d,
 }
 
+#[derive(Debug, Clone)]
+pub struct SetIkTarget2dRequest {
+    pub entity_id: String,
+    pub chain_name: String,
+    /// Target in skeleton space (`None` follows the target entity instead).
+    pub target_position: Option<[f32; 2]>,
+    pub target_entity_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetBlendTree2dRequest {
+    pub entity_id: String,
+    pub tree: crate::core::blend_tree2d::BlendTree2d,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveBlendTree2dRequest {
+    pub entity_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetBlendParam2dRequest {
+    pub entity_id: String,
+    pub name: String,
+    pub value: [f32; 2],
+}
+
 #[derive(Debug, Clone)]
 pub struct GetSkeleton2dRequest {
     pub entity_id: String,
@@ -199,6 +239,10 @@ impl PendingCommands {
         self.animation_clip_removals.push(removal);
     }
 
+    pub fn queue_animation_event_add(&mut self, add: AnimationEventAdd) {
+        self.animation_event_adds.push(add);
+    }
+
     pub fn queue_create_skeleton2d(&mut self, request: CreateSkeleton2dRequest) {
         self.create_skeleton2d_requests.push(request);
     }
@@ -235,6 +279,22 @@ impl PendingCommands {
         self.create_ik_chain2d_requests.push(request);
     }
 
+    pub fn queue_set_ik_target2d(&mut self, request: SetIkTarget2dRequest) {
+        self.set_ik_target2d_requests.push(request);
+    }
+
+    pub fn queue_set_blend_tree2d(&mut self, request: SetBlendTree2dRequest) {
+        self.set_blend_tree2d_requests.push(request);
+    }
+
+    pub fn queue_remove_blend_tree2d(&mut self, request: RemoveBlendTree2dRequest) {
+        self.remove_blend_tree2d_requests.push(request);
+    }
+
+    pub fn queue_set_blend_param2d(&mut self, request: SetBlendParam2dRequest) {
+        self.set_blend_param2d_requests.push(request);
+    }
+
     pub fn queue_get_skeleton2d(&mut self, request: GetSkeleton2dRequest) {
         self.get_skeleton2d_requests.push(request);
     }
@@ -282,6 +342,10 @@ pub fn queue_animation_clip_removal_from_bridge(removal: AnimationClipRemoval) -
     super::with_pending(|pc| pc.queue_animation_clip_removal(removal)).is_some()
 }
 
+pub fn queue_animation_event_add_from_bridge(add: AnimationEventAdd) -> bool {
+    super::with_pending(|pc| pc.queue_animation_event_add(add)).is_some()
+}
+
 pub fn queue_create_skeleton2d_from_bridge(request: CreateSkeleton2dRequest) -> bool {
     super::with_pending(|pc| pc.queue_create_skeleton2d(request)).is_some()
 }
@@ -318,6 +382,22 @@ pub fn queue_create_ik_chain2d_from_bridge(request: CreateIkChain2dRequest) -> b
     super::with_pending(|pc| pc.queue_create_ik_chain2d(request)).is_some()
 }
 
+pub fn queue_set_ik_target2d_from_bridge(request: SetIkTarget2dRequest) -> bool {
+    super::with_pending(|pc| pc.queue_set_ik_target2d(request)).is_some()
+}
+
+pub fn queue_set_blend_tree2d_from_bridge(request: SetBlendTree2dRequest) -> bool {
+    super::with_pending(|pc| pc.queue_set_blend_tree2d(request)).is_some()
+}
+
+pub fn queue_remove_blend_tree2d_from_bridge(request: RemoveBlendTree2dRequest) -> bool {
+    super::with_pending(|pc| pc.queue_remove_blend_tree2d(request)).is_some()
+}
+
+pub fn queue_set_blend_param2d_from_bridge(request: SetBlendParam2dRequest) -> bool {
+    super::with_pending(|pc| pc.queue_set_blend_param2d(request)).is_some()
+}
+
 pub fn queue_get_skeleton2d_from_bridge(request: GetSkeleton2dRequest) -> bool {
     super::with_pending(|pc| pc.queue_get_skeleton2d(request)).is_some()
 }
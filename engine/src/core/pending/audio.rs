@@ -18,6 +18,22 @@ pub struct ScriptRemoval {
     pub entity_id: String,
 }
 
+/// A custom event sent by a gameplay script, forwarded to JS as-is.
+#[derive(Debug, Clone)]
+pub struct ScriptEventRequest {
+    pub entity_id: String,
+    pub event_name: String,
+    pub payload: serde_json::Value,
+}
+
+/// A runtime error reported by the JS script runtime.
+#[derive(Debug, Clone)]
+pub struct ScriptErrorReport {
+    pub entity_id: String,
+    pub message: String,
+    pub line: Option<u32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AudioUpdate {
     pub entity_id: String,
@@ -50,6 +66,8 @@ pub struct AudioBusUpdate {
     pub volume: Option<f32>,
     pub muted: Option<bool>,
     pub soloed: Option<bool>,
+    /// New parent bus ("master" routes straight to master)
+    pub parent: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -58,6 +76,7 @@ pub struct AudioBusCreate {
     pub volume: f32,
     pub muted: bool,
     pub soloed: bool,
+    pub parent: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -99,6 +118,14 @@ impl PendingCommands {
         self.script_removals.push(removal);
     }
 
+    pub fn queue_script_event(&mut self, request: ScriptEventRequest) {
+        self.script_events.push(request);
+    }
+
+    pub fn queue_script_error_report(&mut self, report: ScriptErrorReport) {
+        self.script_error_reports.push(report);
+    }
+
     pub fn queue_audio_update(&mut self, update: AudioUpdate) {
         self.audio_updates.push(update);
     }
@@ -150,6 +177,14 @@ pub fn queue_script_removal_from_bridge(removal: ScriptRemoval) -> bool {
     super::with_pending(|pc| pc.queue_script_removal(removal)).is_some()
 }
 
+pub fn queue_script_event_from_bridge(request: ScriptEventRequest) -> bool {
+    super::with_pending(|pc| pc.queue_script_event(request)).is_some()
+}
+
+pub fn queue_script_error_report_from_bridge(report: ScriptErrorReport) -> bool {
+    super::with_pending(|pc| pc.queue_script_error_report(report)).is_some()
+}
+
 pub fn queue_audio_update_from_bridge(update: AudioUpdate) -> bool {
     super::with_pending(|pc| pc.queue_audio_update(update)).is_some()
 }
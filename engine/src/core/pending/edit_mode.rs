@@ -39,6 +39,11 @@ pub struct RecalcNormalsRequest {
     pub smooth: bool,
 }
 
+#[derive(Debug, Clone)]
+pub struct FlipNormalsRequest {
+    pub entity_id: String,
+}
+
 // === Queue Methods ===
 
 impl PendingCommands {
@@ -65,6 +70,10 @@ impl PendingCommands {
     pub fn queue_recalc_normals(&mut self, request: RecalcNormalsRequest) {
         self.recalc_normals_requests.push(request);
     }
+
+    pub fn queue_flip_normals(&mut self, request: FlipNormalsRequest) {
+        self.flip_normals_requests.push(request);
+    }
 }
 
 // === Bridge Functions ===
@@ -92,3 +101,7 @@ pub fn queue_mesh_operation_from_bridge(request: MeshOperationRequest) -> bool {
 pub fn queue_recalc_normals_from_bridge(request: RecalcNormalsRequest) -> bool {
     super::with_pending(|pc| pc.queue_recalc_normals(request)).is_some()
 }
+
+pub fn queue_flip_normals_from_bridge(request: FlipNormalsRequest) -> bool {
+    super::with_pending(|pc| pc.queue_flip_normals(request)).is_some()
+}
@@ -1,8 +1,10 @@
 //! Game components, game camera, and input pending commands.
 
+use bevy::math::Vec3;
 use super::PendingCommands;
 use crate::core::game_camera::GameCameraMode;
-use crate::core::input::{ActionDef, InputPreset};
+use crate::core::game_components::LookAtConstraint;
+use crate::core::input::{ActionDef, InputContext, InputPreset};
 
 // === Request Structs ===
 
@@ -21,6 +23,13 @@ pub struct InputBindingRemoval {
     pub action_name: String,
 }
 
+#[derive(Debug, Clone)]
+pub enum InputContextRequest {
+    /// Push a context, (re)defining its actions when `context` is given.
+    Push { name: String, context: Option<InputContext> },
+    Pop,
+}
+
 #[derive(Debug, Clone)]
 pub struct GameComponentAddRequest {
     pub entity_id: String,
@@ -41,11 +50,38 @@ pub struct GameComponentRemovalRequest {
     pub component_name: String,
 }
 
+/// Set (`Some`) or remove (`None`) an entity's look-at constraint.
+#[derive(Debug, Clone)]
+pub struct LookAtConstraintUpdate {
+    pub entity_id: String,
+    pub constraint: Option<LookAtConstraint>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatePathRequest {
+    pub name: Option<String>,
+    pub position: Option<[f32; 3]>,
+    pub points: Vec<[f32; 3]>,
+    pub closed: bool,
+}
+
+/// Insert a control point into a path, appending when `index` is `None`.
+#[derive(Debug, Clone)]
+pub struct PathPointAddRequest {
+    pub entity_id: String,
+    pub point: [f32; 3],
+    pub index: Option<usize>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SetGameCameraRequest {
     pub entity_id: String,
     pub mode: GameCameraMode,
     pub target_entity: Option<String>,
+    pub follow_target_id: Option<String>,
+    pub follow_offset: Vec3,
+    pub follow_smoothing: f32,
+    pub deadzone: Vec3,
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +95,36 @@ pub struct CameraShakeRequest {
     pub duration: f32,
 }
 
+#[derive(Debug, Clone)]
+pub struct MoveCharacterRequest {
+    pub entity_id: String,
+    pub direction: [f32; 3],
+    pub dt: f32,
+}
+
+/// Damage (or, when negative, healing) applied to an entity's `health` component.
+#[derive(Debug, Clone)]
+pub struct ApplyDamageRequest {
+    pub entity_id: String,
+    pub amount: f32,
+    /// Entity that dealt the damage, reported with the death event.
+    pub source: Option<String>,
+}
+
+/// What a timer control command does to an entity's `timer` component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerAction {
+    Start,
+    Stop,
+    Reset,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimerRequest {
+    pub entity_id: String,
+    pub action: TimerAction,
+}
+
 #[derive(Debug, Clone)]
 pub struct MouseDeltaRequest {
     pub dx: f32,
@@ -80,6 +146,10 @@ impl PendingCommands {
         self.input_binding_removals.push(removal);
     }
 
+    pub fn queue_input_context(&mut self, request: InputContextRequest) {
+        self.input_context_requests.push(request);
+    }
+
     pub fn queue_game_component_add(&mut self, request: GameComponentAddRequest) {
         self.game_component_adds.push(request);
     }
@@ -92,6 +162,18 @@ impl PendingCommands {
         self.game_component_removals.push(request);
     }
 
+    pub fn queue_look_at_constraint_update(&mut self, update: LookAtConstraintUpdate) {
+        self.look_at_constraint_updates.push(update);
+    }
+
+    pub fn queue_create_path(&mut self, request: CreatePathRequest) {
+        self.create_path_requests.push(request);
+    }
+
+    pub fn queue_path_point_add(&mut self, request: PathPointAddRequest) {
+        self.path_point_adds.push(request);
+    }
+
     pub fn queue_set_game_camera(&mut self, request: SetGameCameraRequest) {
         self.set_game_camera_requests.push(request);
     }
@@ -104,6 +186,18 @@ impl PendingCommands {
         self.camera_shake_requests.push(request);
     }
 
+    pub fn queue_move_character(&mut self, request: MoveCharacterRequest) {
+        self.move_character_requests.push(request);
+    }
+
+    pub fn queue_apply_damage(&mut self, request: ApplyDamageRequest) {
+        self.apply_damage_requests.push(request);
+    }
+
+    pub fn queue_timer_request(&mut self, request: TimerRequest) {
+        self.timer_requests.push(request);
+    }
+
     pub fn queue_mouse_delta(&mut self, dx: f32, dy: f32) {
         self.mouse_delta_requests.push(MouseDeltaRequest { dx, dy });
     }
@@ -123,6 +217,10 @@ pub fn queue_input_binding_removal_from_bridge(removal: InputBindingRemoval) ->
     super::with_pending(|pc| pc.queue_input_binding_removal(removal)).is_some()
 }
 
+pub fn queue_input_context_from_bridge(request: InputContextRequest) -> bool {
+    super::with_pending(|pc| pc.queue_input_context(request)).is_some()
+}
+
 pub fn queue_game_component_add_from_bridge(request: GameComponentAddRequest) -> bool {
     super::with_pending(|pc| pc.queue_game_component_add(request)).is_some()
 }
@@ -135,6 +233,18 @@ pub fn queue_game_component_removal_from_bridge(request: GameComponentRemovalReq
     super::with_pending(|pc| pc.queue_game_component_removal(request)).is_some()
 }
 
+pub fn queue_look_at_constraint_update_from_bridge(update: LookAtConstraintUpdate) -> bool {
+    super::with_pending(|pc| pc.queue_look_at_constraint_update(update)).is_some()
+}
+
+pub fn queue_create_path_from_bridge(request: CreatePathRequest) -> bool {
+    super::with_pending(|pc| pc.queue_create_path(request)).is_some()
+}
+
+pub fn queue_path_point_add_from_bridge(request: PathPointAddRequest) -> bool {
+    super::with_pending(|pc| pc.queue_path_point_add(request)).is_some()
+}
+
 pub fn queue_set_game_camera_from_bridge(request: SetGameCameraRequest) -> bool {
     super::with_pending(|pc| pc.queue_set_game_camera(request)).is_some()
 }
@@ -147,6 +257,18 @@ pub fn queue_camera_shake_from_bridge(request: CameraShakeRequest) -> bool {
     super::with_pending(|pc| pc.queue_camera_shake(request)).is_some()
 }
 
+pub fn queue_move_character_from_bridge(request: MoveCharacterRequest) -> bool {
+    super::with_pending(|pc| pc.queue_move_character(request)).is_some()
+}
+
+pub fn queue_apply_damage_from_bridge(request: ApplyDamageRequest) -> bool {
+    super::with_pending(|pc| pc.queue_apply_damage(request)).is_some()
+}
+
+pub fn queue_timer_request_from_bridge(request: TimerRequest) -> bool {
+    super::with_pending(|pc| pc.queue_timer_request(request)).is_some()
+}
+
 pub fn queue_mouse_delta_from_bridge(dx: f32, dy: f32) -> bool {
     super::with_pending(|pc| pc.queue_mouse_delta(dx, dy)).is_some()
 }
@@ -1,11 +1,12 @@
 //! Material, texture, and lighting pending commands.
 
 use super::PendingCommands;
+use crate::core::environment::FogMode;
 use crate::core::lighting::LightData;
 use crate::core::material::MaterialData;
 use crate::core::post_processing::{
     BloomSettings, ChromaticAberrationSettings, ColorGradingSettings, DepthOfFieldSettings,
-    MotionBlurSettings, SharpeningSettings, SsaoSettings,
+    MotionBlurSettings, SharpeningSettings, SsaoSettings, VignetteSettings,
 };
 use crate::core::shader_effects::ShaderEffectData;
 
@@ -23,6 +24,12 @@ pub struct LightUpdate {
     pub light_data: LightData,
 }
 
+/// Request to bake light probes. `None` bakes every volume in the scene.
+#[derive(Debug, Clone)]
+pub struct LightProbeBakeRequest {
+    pub entity_id: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AmbientLightUpdate {
     pub color: Option<[f32; 3]>,
@@ -39,6 +46,10 @@ pub struct EnvironmentUpdate {
     pub fog_color: Option<[f32; 3]>,
     pub fog_start: Option<f32>,
     pub fog_end: Option<f32>,
+    pub fog_mode: Option<FogMode>,
+    pub fog_density: Option<f32>,
+    pub fog_height_falloff: Option<f32>,
+    pub fog_base_height: Option<f32>,
 }
 
 #[derive(Debug, Clone)]
@@ -50,6 +61,7 @@ pub struct PostProcessingUpdate {
     pub ssao: Option<Option<SsaoSettings>>,
     pub depth_of_field: Option<Option<DepthOfFieldSettings>>,
     pub motion_blur: Option<Option<MotionBlurSettings>>,
+    pub vignette: Option<Option<VignetteSettings>>,
 }
 
 #[derive(Debug, Clone)]
@@ -85,7 +97,22 @@ pub struct UpdateSkyboxRequest {
 #[derive(Debug, Clone)]
 pub struct SetCustomSkyboxRequest {
     pub asset_id: String,
+    /// Equirectangular image as a data URL or raw base64; empty when `faces` is set.
     pub data_base64: String,
+    /// Six face images (+X, -X, +Y, -Y, +Z, -Z); empty for an equirectangular image.
+    pub faces: Vec<String>,
+}
+
+/// Switches to (or adjusts) the procedural sky; unset fields keep their
+/// current values.
+#[derive(Debug, Clone)]
+pub struct SetProceduralSkyRequest {
+    pub sun_azimuth: Option<f32>,
+    pub sun_elevation: Option<f32>,
+    pub turbidity: Option<f32>,
+    pub ground_albedo: Option<f32>,
+    /// Directional light to aim along the sunlight; `Some("")` unlinks it.
+    pub sun_entity_id: Option<String>,
 }
 
 // === Queue Methods ===
@@ -99,6 +126,10 @@ impl PendingCommands {
         self.light_updates.push(update);
     }
 
+    pub fn queue_light_probe_bake(&mut self, request: LightProbeBakeRequest) {
+        self.light_probe_bake_requests.push(request);
+    }
+
     pub fn queue_ambient_light_update(&mut self, update: AmbientLightUpdate) {
         self.ambient_light_updates.push(update);
     }
@@ -134,6 +165,10 @@ impl PendingCommands {
     pub fn queue_custom_skybox(&mut self, request: SetCustomSkyboxRequest) {
         self.custom_skybox_requests.push(request);
     }
+
+    pub fn queue_procedural_sky(&mut self, request: SetProceduralSkyRequest) {
+        self.procedural_sky_requests.push(request);
+    }
 }
 
 // === Bridge Functions ===
@@ -146,6 +181,10 @@ pub fn queue_light_update_from_bridge(update: LightUpdate) -> bool {
     super::with_pending(|pc| pc.queue_light_update(update)).is_some()
 }
 
+pub fn queue_light_probe_bake_from_bridge(request: LightProbeBakeRequest) -> bool {
+    super::with_pending(|pc| pc.queue_light_probe_bake(request)).is_some()
+}
+
 pub fn queue_ambient_light_update_from_bridge(update: AmbientLightUpdate) -> bool {
     super::with_pending(|pc| pc.queue_ambient_light_update(update)).is_some()
 }
@@ -182,6 +221,10 @@ pub fn queue_custom_skybox_from_bridge(request: SetCustomSkyboxRequest) -> bool
     super::with_pending(|pc| pc.queue_custom_skybox(request)).is_some()
 }
 
+pub fn queue_procedural_sky_from_bridge(request: SetProceduralSkyRequest) -> bool {
+    super::with_pending(|pc| pc.queue_procedural_sky(request)).is_some()
+}
+
 // === Custom WGSL Request Structs ===
 
 /// Request to update the scene-global custom WGSL source.
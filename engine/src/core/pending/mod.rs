@@ -10,7 +10,7 @@
 //! - `audio` — Scripts, audio, buses, reverb zones
 //! - `animation` — Animation playback, clips, skeleton 2D
 //! - `particles` — Particle system
-//! - `procedural` — CSG, terrain, extrude, lathe, array, combine
+//! - `procedural` — CSG, terrain, extrude, lathe, loft, sweep, subdivide, apply transform, array, combine
 //! - `game` — Game components, game camera, input bindings
 //! - `sprites` — Sprites, 2D camera, project type
 //! - `scene` — Scene export/load, assets, prefabs, quality
@@ -66,9 +66,14 @@ pub enum EntityType {
     PointLight,
     DirectionalLight,
     SpotLight,
+    LightProbeVolume,
+    /// Transform-only node with no mesh, used to group other entities.
+    Empty,
     GltfModel,
     GltfMesh,
     Sprite,
+    /// Spline through control points, followed by `path_follower` entities.
+    Path,
 }
 
 impl EntityType {
@@ -88,9 +93,12 @@ impl EntityType {
             "point_light" => Some(EntityType::PointLight),
             "directional_light" => Some(EntityType::DirectionalLight),
             "spot_light" => Some(EntityType::SpotLight),
+            "light_probe_volume" => Some(EntityType::LightProbeVolume),
+            "empty" => Some(EntityType::Empty),
             "gltf_model" => Some(EntityType::GltfModel),
             "gltf_mesh" => Some(EntityType::GltfMesh),
             "sprite" => Some(EntityType::Sprite),
+            "path" => Some(EntityType::Path),
             _ => None,
         }
     }
@@ -111,9 +119,12 @@ impl EntityType {
             EntityType::PointLight => "Point Light",
             EntityType::DirectionalLight => "Directional Light",
             EntityType::SpotLight => "Spot Light",
+            EntityType::LightProbeVolume => "Light Probe Volume",
+            EntityType::Empty => "Empty",
             EntityType::GltfModel => "Model",
             EntityType::GltfMesh => "Mesh",
             EntityType::Sprite => "Sprite",
+            EntityType::Path => "Path",
         }
     }
 }
@@ -124,25 +135,48 @@ impl EntityType {
 pub struct PendingCommands {
     // transform domain
     pub transform_updates: Vec<TransformUpdate>,
+    pub transform_batch_updates: Vec<TransformBatchUpdate>,
     pub rename_requests: Vec<RenameRequest>,
     pub camera_focus_requests: Vec<CameraFocusRequest>,
     pub camera_orbit_requests: Vec<CameraOrbitRequest>,
+    pub frame_selection_requests: Vec<FrameSelectionRequest>,
     pub spawn_requests: Vec<SpawnRequest>,
+    pub spawn_configured_requests: Vec<SpawnConfiguredRequest>,
     pub delete_requests: Vec<DeleteRequest>,
     pub duplicate_requests: Vec<DuplicateRequest>,
+    pub smart_duplicate_requests: Vec<()>,
+    pub copy_requests: Vec<CopyEntitiesRequest>,
+    pub paste_requests: Vec<PasteEntitiesRequest>,
     pub reparent_requests: Vec<ReparentRequest>,
+    pub group_requests: Vec<GroupRequest>,
+    pub ungroup_requests: Vec<UngroupRequest>,
     pub snap_settings_updates: Vec<SnapSettingsUpdate>,
+    pub outline_settings_updates: Vec<OutlineSettingsUpdate>,
     pub grid_toggles: Vec<()>,
+    pub measure_requests: Vec<MeasureRequest>,
+    pub drop_to_ground_requests: Vec<DropToGroundRequest>,
+    pub clear_measurement_requests: Vec<()>,
     pub camera_preset_requests: Vec<CameraPresetRequest>,
+    pub camera_projection_requests: Vec<CameraProjectionRequest>,
+    pub viewport_layout_requests: Vec<ViewportLayoutRequest>,
+    pub render_mode_requests: Vec<RenderModeRequest>,
+    pub event_throttle_requests: Vec<EventThrottleRequest>,
     pub coordinate_mode_update: Option<crate::core::gizmo::CoordinateMode>,
     pub selection_requests: Vec<SelectionRequest>,
+    pub selection_bulk_requests: Vec<SelectionBulkRequest>,
+    pub box_select_requests: Vec<BoxSelectRequest>,
     pub visibility_requests: Vec<VisibilityRequest>,
+    pub entity_lock_requests: Vec<EntityLockRequest>,
+    pub entity_tag_requests: Vec<EntityTagRequest>,
+    pub align_requests: Vec<AlignEntitiesRequest>,
+    pub distribute_requests: Vec<DistributeEntitiesRequest>,
     pub clear_selection_requests: Vec<()>,
     pub gizmo_mode_requests: Vec<GizmoModeRequest>,
     pub mode_change_requests: Vec<crate::core::engine_mode::ModeChangeRequest>,
     // material domain
     pub material_updates: Vec<MaterialUpdate>,
     pub light_updates: Vec<LightUpdate>,
+    pub light_probe_bake_requests: Vec<LightProbeBakeRequest>,
     pub ambient_light_updates: Vec<AmbientLightUpdate>,
     pub environment_updates: Vec<EnvironmentUpdate>,
     pub post_processing_updates: Vec<PostProcessingUpdate>,
@@ -152,6 +186,7 @@ pub struct PendingCommands {
     pub remove_skybox_requests: Vec<RemoveSkyboxRequest>,
     pub update_skybox_requests: Vec<UpdateSkyboxRequest>,
     pub custom_skybox_requests: Vec<SetCustomSkyboxRequest>,
+    pub procedural_sky_requests: Vec<SetProceduralSkyRequest>,
     pub custom_wgsl_source_updates: Vec<CustomWgslSourceUpdate>,
     pub register_custom_shader_requests: Vec<RegisterCustomShaderRequest>,
     pub apply_custom_shader_requests: Vec<ApplyCustomShaderRequest>,
@@ -163,7 +198,10 @@ pub struct PendingCommands {
     pub create_joint_requests: Vec<CreateJointRequest>,
     pub update_joint_requests: Vec<UpdateJointRequest>,
     pub remove_joint_requests: Vec<RemoveJointRequest>,
+    pub joint_motor_target_requests: Vec<JointMotorTargetRequest>,
     pub force_applications: Vec<ForceApplication>,
+    pub radial_impulse_requests: Vec<RadialImpulseRequest>,
+    pub bake_physics_requests: Vec<BakePhysicsRequest>,
     pub raycast_requests: Vec<RaycastRequest>,
     pub physics2d_updates: Vec<Physics2dUpdate>,
     pub physics2d_toggles: Vec<Physics2dToggle>,
@@ -175,9 +213,12 @@ pub struct PendingCommands {
     pub raycast2d_requests: Vec<Raycast2dRequest>,
     pub gravity2d_updates: Vec<Gravity2dUpdate>,
     pub debug_physics2d_toggles: Vec<DebugPhysics2dToggle>,
+    pub physics_timestep_requests: Vec<PhysicsTimestepRequest>,
     // audio domain
     pub script_updates: Vec<ScriptUpdate>,
     pub script_removals: Vec<ScriptRemoval>,
+    pub script_events: Vec<ScriptEventRequest>,
+    pub script_error_reports: Vec<ScriptErrorReport>,
     pub audio_updates: Vec<AudioUpdate>,
     pub audio_removals: Vec<AudioRemoval>,
     pub audio_playback: Vec<AudioPlayback>,
@@ -197,6 +238,7 @@ pub struct PendingCommands {
     pub animation_clip_property_updates: Vec<AnimationClipPropertyUpdate>,
     pub animation_clip_previews: Vec<AnimationClipPreview>,
     pub animation_clip_removals: Vec<AnimationClipRemoval>,
+    pub animation_event_adds: Vec<AnimationEventAdd>,
     pub create_skeleton2d_requests: Vec<CreateSkeleton2dRequest>,
     pub add_bone2d_requests: Vec<AddBone2dRequest>,
     pub remove_bone2d_requests: Vec<RemoveBone2dRequest>,
@@ -206,6 +248,10 @@ pub struct PendingCommands {
     pub play_skeletal_animation2d_requests: Vec<PlaySkeletalAnimation2dRequest>,
     pub set_skeleton2d_skin_requests: Vec<SetSkeleton2dSkinRequest>,
     pub create_ik_chain2d_requests: Vec<CreateIkChain2dRequest>,
+    pub set_ik_target2d_requests: Vec<SetIkTarget2dRequest>,
+    pub set_blend_tree2d_requests: Vec<SetBlendTree2dRequest>,
+    pub remove_blend_tree2d_requests: Vec<RemoveBlendTree2dRequest>,
+    pub set_blend_param2d_requests: Vec<SetBlendParam2dRequest>,
     pub get_skeleton2d_requests: Vec<GetSkeleton2dRequest>,
     pub import_skeleton_json_requests: Vec<ImportSkeletonJsonRequest>,
     pub auto_weight_skeleton2d_requests: Vec<AutoWeightSkeleton2dRequest>,
@@ -215,25 +261,43 @@ pub struct PendingCommands {
     pub particle_removals: Vec<ParticleRemoval>,
     pub particle_preset_requests: Vec<ParticlePresetRequest>,
     pub particle_playback: Vec<ParticlePlayback>,
+    pub particle_sub_emitter_updates: Vec<ParticleSubEmitterUpdate>,
+    pub particle_force_field_updates: Vec<ParticleForceFieldUpdate>,
     // procedural domain
     pub csg_requests: Vec<CsgRequest>,
+    pub csg_chain_requests: Vec<CsgChainRequest>,
     pub terrain_spawn_requests: Vec<TerrainSpawnRequest>,
     pub terrain_updates: Vec<TerrainUpdate>,
     pub terrain_sculpts: Vec<TerrainSculpt>,
+    pub terrain_hole_paints: Vec<TerrainHolePaint>,
+    pub terrain_splat_updates: Vec<TerrainSplatUpdate>,
     pub extrude_requests: Vec<ExtrudeRequest>,
     pub lathe_requests: Vec<LatheRequest>,
+    pub loft_requests: Vec<LoftRequest>,
+    pub sweep_requests: Vec<SweepRequest>,
+    pub subdivide_requests: Vec<SubdivideRequest>,
+    pub apply_transform_requests: Vec<ApplyTransformRequest>,
     pub array_requests: Vec<ArrayRequest>,
+    pub instance_array_requests: Vec<ArrayRequest>,
+    pub clone_pattern_requests: Vec<ClonePatternRequest>,
     pub combine_requests: Vec<CombineRequest>,
     // game domain
     pub input_binding_updates: Vec<InputBindingUpdate>,
     pub input_preset_requests: Vec<InputPresetRequest>,
     pub input_binding_removals: Vec<InputBindingRemoval>,
+    pub input_context_requests: Vec<InputContextRequest>,
     pub game_component_adds: Vec<GameComponentAddRequest>,
     pub game_component_updates: Vec<GameComponentUpdateRequest>,
     pub game_component_removals: Vec<GameComponentRemovalRequest>,
+    pub look_at_constraint_updates: Vec<LookAtConstraintUpdate>,
+    pub create_path_requests: Vec<CreatePathRequest>,
+    pub path_point_adds: Vec<PathPointAddRequest>,
     pub set_game_camera_requests: Vec<SetGameCameraRequest>,
     pub set_active_game_camera_requests: Vec<SetActiveGameCameraRequest>,
     pub camera_shake_requests: Vec<CameraShakeRequest>,
+    pub move_character_requests: Vec<MoveCharacterRequest>,
+    pub apply_damage_requests: Vec<ApplyDamageRequest>,
+    pub timer_requests: Vec<TimerRequest>,
     pub mouse_delta_requests: Vec<MouseDeltaRequest>,
     // sprites domain
     pub set_project_type_requests: Vec<SetProjectTypeRequest>,
@@ -244,12 +308,15 @@ pub struct PendingCommands {
     pub sprite_sheet_removals: Vec<SpriteSheetRemoval>,
     pub sprite_animator_updates: Vec<SpriteAnimatorUpdate>,
     pub sprite_animator_removals: Vec<SpriteAnimatorRemoval>,
+    pub set_sprite_animation_requests: Vec<SetSpriteAnimationRequest>,
+    pub sprite_animation_playback_requests: Vec<SpriteAnimationPlaybackRequest>,
     pub animation_state_machine_updates: Vec<AnimationStateMachineUpdate>,
     pub animation_state_machine_removals: Vec<AnimationStateMachineRemoval>,
     pub spawn_sprite_requests: Vec<SpawnSpriteRequest>,
     pub set_sorting_layers_requests: Vec<SetSortingLayersRequest>,
     pub set_tileset_requests: Vec<SetTilesetRequest>,
     pub remove_tileset_requests: Vec<RemoveTilesetRequest>,
+    pub set_tileset_autotile_requests: Vec<SetTilesetAutotileRequest>,
     pub tilemap_data_updates: Vec<TilemapDataUpdate>,
     pub tilemap_data_removals: Vec<TilemapDataRemoval>,
     pub add_mesh_attachment2d_requests: Vec<AddMeshAttachment2dRequest>,
@@ -259,8 +326,17 @@ pub struct PendingCommands {
     pub set_grid_2d_requests: Vec<SetGrid2dRequest>,
     // scene domain
     pub scene_export_requests: Vec<SceneExportRequest>,
+    pub gltf_export_requests: Vec<GltfExportRequest>,
     pub scene_load_requests: Vec<SceneLoadRequest>,
+    pub scene_merge_requests: Vec<SceneMergeRequest>,
+    pub scene_patch_requests: Vec<ScenePatchRequest>,
     pub new_scene_requests: Vec<NewSceneRequest>,
+    pub scene_graph_refresh_requests: Vec<SceneGraphRefreshRequest>,
+    pub command_log_requests: Vec<CommandLogRequest>,
+    /// Set while commands are being recorded (see `command_log`).
+    pub command_recording: Option<crate::core::command_log::RecordingOptions>,
+    /// Commands recorded since the last frame, with their payloads.
+    pub recorded_commands: Vec<(String, serde_json::Value)>,
     pub gltf_import_requests: Vec<GltfImportRequest>,
     pub texture_load_requests: Vec<TextureLoadRequest>,
     pub place_asset_requests: Vec<PlaceAssetRequest>,
@@ -269,6 +345,10 @@ pub struct PendingCommands {
     pub audio_import_requests: Vec<AudioImportRequest>,
     pub quality_preset_requests: Vec<QualityPresetRequest>,
     pub instantiate_prefab_requests: Vec<InstantiatePrefabRequest>,
+    pub create_prefab_requests: Vec<CreatePrefabRequest>,
+    pub prefab_update_requests: Vec<PrefabUpdateRequest>,
+    pub export_prefab_library_requests: Vec<ExportPrefabLibraryRequest>,
+    pub import_prefab_library_requests: Vec<ImportPrefabLibraryRequest>,
     // query domain
     pub query_requests: Vec<QueryRequest>,
     // edit_mode domain
@@ -278,6 +358,7 @@ pub struct PendingCommands {
     pub select_elements_requests: Vec<SelectElementsRequest>,
     pub mesh_operation_requests: Vec<MeshOperationRequest>,
     pub recalc_normals_requests: Vec<RecalcNormalsRequest>,
+    pub flip_normals_requests: Vec<FlipNormalsRequest>,
     // performance domain
     pub set_lod_requests: Vec<SetLodRequest>,
     pub generate_lods_requests: Vec<GenerateLodsRequest>,
@@ -286,6 +367,9 @@ pub struct PendingCommands {
     pub optimize_scene_requests: Vec<OptimizeSceneRequest>,
     pub set_lod_distances_requests: Vec<SetLodDistancesRequest>,
     pub set_simplification_backend_requests: Vec<SetSimplificationBackendRequest>,
+    pub set_culling_enabled_requests: Vec<SetCullingEnabledRequest>,
+    pub set_occlusion_culling_requests: Vec<SetOcclusionCullingRequest>,
+    pub set_stats_stream_requests: Vec<SetStatsStreamRequest>,
 }
 
 // === Thread-Local Bridge Access ===
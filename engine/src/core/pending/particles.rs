@@ -1,7 +1,7 @@
 //! Particle system pending commands.
 
 use super::PendingCommands;
-use crate::core::particles::ParticleData;
+use crate::core::particles::{ParticleData, ParticleForceField, SubEmitterTrigger};
 
 // === Request Structs ===
 
@@ -35,6 +35,21 @@ pub struct ParticlePlayback {
     pub burst_count: Option<u32>,
 }
 
+#[derive(Debug, Clone)]
+pub struct ParticleSubEmitterUpdate {
+    pub entity_id: String,
+    /// None removes the sub-emitter.
+    pub sub_emitter: Option<ParticleData>,
+    pub trigger: SubEmitterTrigger,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParticleForceFieldUpdate {
+    pub entity_id: String,
+    /// None removes the force field.
+    pub force_field: Option<ParticleForceField>,
+}
+
 // === Queue Methods ===
 
 impl PendingCommands {
@@ -57,6 +72,14 @@ impl PendingCommands {
     pub fn queue_particle_playback(&mut self, playback: ParticlePlayback) {
         self.particle_playback.push(playback);
     }
+
+    pub fn queue_particle_sub_emitter_update(&mut self, update: ParticleSubEmitterUpdate) {
+        self.particle_sub_emitter_updates.push(update);
+    }
+
+    pub fn queue_particle_force_field_update(&mut self, update: ParticleForceFieldUpdate) {
+        self.particle_force_field_updates.push(update);
+    }
 }
 
 // === Bridge Functions ===
@@ -80,3 +103,11 @@ pub fn queue_particle_preset_from_bridge(request: ParticlePresetRequest) -> bool
 pub fn queue_particle_playback_from_bridge(playback: ParticlePlayback) -> bool {
     super::with_pending(|pc| pc.queue_particle_playback(playback)).is_some()
 }
+
+pub fn queue_particle_sub_emitter_update_from_bridge(update: ParticleSubEmitterUpdate) -> bool {
+    super::with_pending(|pc| pc.queue_particle_sub_emitter_update(update)).is_some()
+}
+
+pub fn queue_particle_force_field_update_from_bridge(update: ParticleForceFieldUpdate) -> bool {
+    super::with_pending(|pc| pc.queue_particle_force_field_update(update)).is_some()
+}
@@ -41,6 +41,21 @@ pub struct SetSimplificationBackendRequest {
     pub backend_name: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct SetCullingEnabledRequest {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetOcclusionCullingRequest {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetStatsStreamRequest {
+    pub active: bool,
+}
+
 // === Queue Methods ===
 
 impl PendingCommands {
@@ -71,6 +86,18 @@ impl PendingCommands {
     pub fn queue_set_simplification_backend(&mut self, request: SetSimplificationBackendRequest) {
         self.set_simplification_backend_requests.push(request);
     }
+
+    pub fn queue_set_culling_enabled(&mut self, request: SetCullingEnabledRequest) {
+        self.set_culling_enabled_requests.push(request);
+    }
+
+    pub fn queue_set_occlusion_culling(&mut self, request: SetOcclusionCullingRequest) {
+        self.set_occlusion_culling_requests.push(request);
+    }
+
+    pub fn queue_set_stats_stream(&mut self, request: SetStatsStreamRequest) {
+        self.set_stats_stream_requests.push(request);
+    }
 }
 
 // === Bridge Functions ===
@@ -136,3 +163,21 @@ pub fn bridge_set_simplification_backend(backend_name: String) {
         pc.queue_set_simplification_backend(SetSimplificationBackendRequest { backend_name });
     });
 }
+
+pub fn bridge_set_culling_enabled(enabled: bool) {
+    super::with_pending(|pc| {
+        pc.queue_set_culling_enabled(SetCullingEnabledRequest { enabled });
+    });
+}
+
+pub fn bridge_set_occlusion_culling(enabled: bool) {
+    super::with_pending(|pc| {
+        pc.queue_set_occlusion_culling(SetOcclusionCullingRequest { enabled });
+    });
+}
+
+pub fn bridge_set_stats_stream(active: bool) {
+    super::with_pending(|pc| {
+        pc.queue_set_stats_stream(SetStatsStreamRequest { active });
+    });
+}
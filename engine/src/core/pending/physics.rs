@@ -49,6 +49,17 @@ pub struct RemoveJointRequest {
     pub entity_id: String,
 }
 
+/// Live motor update for the joint on `joint_id` (Play mode only). Unset
+/// fields keep the motor's current value.
+#[derive(Debug, Clone)]
+pub struct JointMotorTargetRequest {
+    pub joint_id: String,
+    pub target_vel: Option<f32>,
+    pub target_pos: Option<f32>,
+    pub stiffness: Option<f32>,
+    pub damping: Option<f32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ForceApplication {
     pub entity_id: String,
@@ -57,12 +68,31 @@ pub struct ForceApplication {
     pub is_impulse: bool,
 }
 
+/// Push every rigid body within `radius` of `center` outward.
+#[derive(Debug, Clone)]
+pub struct RadialImpulseRequest {
+    pub center: [f32; 3],
+    pub radius: f32,
+    pub strength: f32,
+    pub falloff: crate::core::physics::RadialFalloff,
+}
+
+/// Record the physics of `entity_ids` for `duration` seconds into a clip.
+#[derive(Debug, Clone)]
+pub struct BakePhysicsRequest {
+    pub entity_ids: Vec<String>,
+    pub duration: f32,
+    pub fps: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct RaycastRequest {
     pub request_id: String,
     pub origin: [f32; 3],
     pub direction: [f32; 3],
     pub max_distance: f32,
+    /// Only hit colliders whose membership overlaps this bitmask (`None` = all).
+    pub filter_groups: Option<u32>,
 }
 
 // === 2D Physics Request Structs ===
@@ -112,11 +142,14 @@ pub struct ImpulseApplication2d {
 
 #[derive(Debug, Clone)]
 pub struct Raycast2dRequest {
+    pub request_id: String,
     pub origin_x: f32,
     pub origin_y: f32,
     pub dir_x: f32,
     pub dir_y: f32,
     pub max_distance: f32,
+    /// Only hit colliders whose membership overlaps this bitmask (`None` = all).
+    pub filter_groups: Option<u32>,
 }
 
 #[derive(Debug, Clone)]
@@ -130,6 +163,14 @@ pub struct DebugPhysics2dToggle {
     pub enabled: bool,
 }
 
+/// Replaces the physics stepping configuration; `fixed_dt: None` follows frame time.
+#[derive(Debug, Clone)]
+pub struct PhysicsTimestepRequest {
+    pub fixed_dt: Option<f32>,
+    pub substeps: usize,
+    pub physics_determinism: bool,
+}
+
 // === Queue Methods ===
 
 impl PendingCommands {
@@ -157,10 +198,22 @@ impl PendingCommands {
         self.remove_joint_requests.push(request);
     }
 
+    pub fn queue_joint_motor_target(&mut self, request: JointMotorTargetRequest) {
+        self.joint_motor_target_requests.push(request);
+    }
+
     pub fn queue_force_application(&mut self, application: ForceApplication) {
         self.force_applications.push(application);
     }
 
+    pub fn queue_radial_impulse(&mut self, request: RadialImpulseRequest) {
+        self.radial_impulse_requests.push(request);
+    }
+
+    pub fn queue_bake_physics(&mut self, request: BakePhysicsRequest) {
+        self.bake_physics_requests.push(request);
+    }
+
     pub fn queue_raycast(&mut self, request: RaycastRequest) {
         self.raycast_requests.push(request);
     }
@@ -204,6 +257,10 @@ impl PendingCommands {
     pub fn queue_debug_physics2d_toggle(&mut self, toggle: DebugPhysics2dToggle) {
         self.debug_physics2d_toggles.push(toggle);
     }
+
+    pub fn queue_physics_timestep(&mut self, request: PhysicsTimestepRequest) {
+        self.physics_timestep_requests.push(request);
+    }
 }
 
 // === Bridge Functions ===
@@ -232,10 +289,22 @@ pub fn queue_remove_joint_from_bridge(request: RemoveJointRequest) -> bool {
     super::with_pending(|pc| pc.queue_remove_joint(request)).is_some()
 }
 
+pub fn queue_joint_motor_target_from_bridge(request: JointMotorTargetRequest) -> bool {
+    super::with_pending(|pc| pc.queue_joint_motor_target(request)).is_some()
+}
+
 pub fn queue_force_application_from_bridge(application: ForceApplication) -> bool {
     super::with_pending(|pc| pc.queue_force_application(application)).is_some()
 }
 
+pub fn queue_radial_impulse_from_bridge(request: RadialImpulseRequest) -> bool {
+    super::with_pending(|pc| pc.queue_radial_impulse(request)).is_some()
+}
+
+pub fn queue_bake_physics_from_bridge(request: BakePhysicsRequest) -> bool {
+    super::with_pending(|pc| pc.queue_bake_physics(request)).is_some()
+}
+
 pub fn queue_raycast_from_bridge(request: RaycastRequest) -> bool {
     super::with_pending(|pc| pc.queue_raycast(request)).is_some()
 }
@@ -279,3 +348,7 @@ pub fn queue_gravity2d_update_from_bridge(update: Gravity2dUpdate) -> bool {
 pub fn queue_debug_physics2d_toggle_from_bridge(toggle: DebugPhysics2dToggle) -> bool {
     super::with_pending(|pc| pc.queue_debug_physics2d_toggle(toggle)).is_some()
 }
+
+pub fn queue_physics_timestep_from_bridge(request: PhysicsTimestepRequest) -> bool {
+    super::with_pending(|pc| pc.queue_physics_timestep(request)).is_some()
+}
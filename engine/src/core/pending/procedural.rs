@@ -1,7 +1,7 @@
 //! CSG, terrain, and procedural mesh pending commands.
 
 use super::PendingCommands;
-use crate::core::terrain::TerrainData;
+use crate::core::terrain::{SplatLayer, TerrainData};
 
 // === Request Structs ===
 
@@ -14,6 +14,20 @@ pub struct CsgRequest {
     pub result_name: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct CsgChainStep {
+    pub operation: crate::core::csg::CsgOperation,
+    pub entity_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CsgChainRequest {
+    pub base_entity_id: String,
+    pub operations: Vec<CsgChainStep>,
+    pub delete_sources: bool,
+    pub result_name: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct TerrainSpawnRequest {
     pub name: Option<String>,
@@ -35,6 +49,21 @@ pub struct TerrainSculpt {
     pub strength: f32,
 }
 
+#[derive(Debug, Clone)]
+pub struct TerrainHolePaint {
+    pub entity_id: String,
+    pub position: [f32; 2],
+    pub radius: f32,
+    pub fill: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TerrainSplatUpdate {
+    pub entity_id: String,
+    pub layers: Vec<SplatLayer>,
+    pub splat_map: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ExtrudeRequest {
     pub shape: String,
@@ -56,6 +85,37 @@ pub struct LatheRequest {
     pub position: Option<bevy::math::Vec3>,
 }
 
+#[derive(Debug, Clone)]
+pub struct LoftRequest {
+    pub profiles: Vec<Vec<[f32; 2]>>,
+    pub closed: bool,
+    pub name: Option<String>,
+    pub position: Option<bevy::math::Vec3>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SweepRequest {
+    pub profile: Vec<[f32; 2]>,
+    pub path: Vec<[f32; 3]>,
+    pub segments: u32,
+    pub name: Option<String>,
+    pub position: Option<bevy::math::Vec3>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubdivideRequest {
+    pub entity_id: String,
+    pub iterations: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApplyTransformRequest {
+    pub entity_id: String,
+    pub position: bool,
+    pub rotation: bool,
+    pub scale: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct ArrayRequest {
     pub entity_id: String,
@@ -70,6 +130,26 @@ pub struct ArrayRequest {
     pub circle_radius: Option<f32>,
 }
 
+/// Where each clone of a `clone_pattern` goes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClonePattern {
+    /// Clone `i` sits at the source plus `offset * i`.
+    Linear { offset: bevy::math::Vec3 },
+    /// Clones are spaced evenly on a circle of `radius` around the source pivot (XZ plane).
+    Radial { radius: f32 },
+}
+
+#[derive(Debug, Clone)]
+pub struct ClonePatternRequest {
+    pub entity_id: String,
+    pub pattern: ClonePattern,
+    pub count: u32,
+    /// Euler XYZ rotation in degrees added per step.
+    pub rotate_each: bevy::math::Vec3,
+    /// Radial only: face each clone toward the source pivot instead of accumulating `rotate_each`.
+    pub look_at_center: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct CombineRequest {
     pub entity_ids: Vec<String>,
@@ -84,6 +164,10 @@ impl PendingCommands {
         self.csg_requests.push(request);
     }
 
+    pub fn queue_csg_chain(&mut self, request: CsgChainRequest) {
+        self.csg_chain_requests.push(request);
+    }
+
     pub fn queue_terrain_spawn(&mut self, request: TerrainSpawnRequest) {
         self.terrain_spawn_requests.push(request);
     }
@@ -96,6 +180,14 @@ impl PendingCommands {
         self.terrain_sculpts.push(sculpt);
     }
 
+    pub fn queue_terrain_hole_paint(&mut self, paint: TerrainHolePaint) {
+        self.terrain_hole_paints.push(paint);
+    }
+
+    pub fn queue_terrain_splat(&mut self, update: TerrainSplatUpdate) {
+        self.terrain_splat_updates.push(update);
+    }
+
     pub fn queue_extrude(&mut self, request: ExtrudeRequest) {
         self.extrude_requests.push(request);
     }
@@ -104,10 +196,34 @@ impl PendingCommands {
         self.lathe_requests.push(request);
     }
 
+    pub fn queue_loft(&mut self, request: LoftRequest) {
+        self.loft_requests.push(request);
+    }
+
+    pub fn queue_sweep(&mut self, request: SweepRequest) {
+        self.sweep_requests.push(request);
+    }
+
+    pub fn queue_subdivide(&mut self, request: SubdivideRequest) {
+        self.subdivide_requests.push(request);
+    }
+
+    pub fn queue_apply_transform(&mut self, request: ApplyTransformRequest) {
+        self.apply_transform_requests.push(request);
+    }
+
     pub fn queue_array(&mut self, request: ArrayRequest) {
         self.array_requests.push(request);
     }
 
+    pub fn queue_instance_array(&mut self, request: ArrayRequest) {
+        self.instance_array_requests.push(request);
+    }
+
+    pub fn queue_clone_pattern(&mut self, request: ClonePatternRequest) {
+        self.clone_pattern_requests.push(request);
+    }
+
     pub fn queue_combine(&mut self, request: CombineRequest) {
         self.combine_requests.push(request);
     }
@@ -119,6 +235,10 @@ pub fn queue_csg_from_bridge(request: CsgRequest) -> bool {
     super::with_pending(|pc| pc.queue_csg(request)).is_some()
 }
 
+pub fn queue_csg_chain_from_bridge(request: CsgChainRequest) -> bool {
+    super::with_pending(|pc| pc.queue_csg_chain(request)).is_some()
+}
+
 pub fn queue_terrain_spawn_from_bridge(request: TerrainSpawnRequest) -> bool {
     super::with_pending(|pc| pc.queue_terrain_spawn(request)).is_some()
 }
@@ -131,6 +251,14 @@ pub fn queue_terrain_sculpt_from_bridge(sculpt: TerrainSculpt) -> bool {
     super::with_pending(|pc| pc.queue_terrain_sculpt(sculpt)).is_some()
 }
 
+pub fn queue_terrain_hole_paint_from_bridge(paint: TerrainHolePaint) -> bool {
+    super::with_pending(|pc| pc.queue_terrain_hole_paint(paint)).is_some()
+}
+
+pub fn queue_terrain_splat_from_bridge(update: TerrainSplatUpdate) -> bool {
+    super::with_pending(|pc| pc.queue_terrain_splat(update)).is_some()
+}
+
 pub fn queue_extrude_from_bridge(request: ExtrudeRequest) -> bool {
     super::with_pending(|pc| pc.queue_extrude(request)).is_some()
 }
@@ -139,10 +267,34 @@ pub fn queue_lathe_from_bridge(request: LatheRequest) -> bool {
     super::with_pending(|pc| pc.queue_lathe(request)).is_some()
 }
 
+pub fn queue_loft_from_bridge(request: LoftRequest) -> bool {
+    super::with_pending(|pc| pc.queue_loft(request)).is_some()
+}
+
+pub fn queue_sweep_from_bridge(request: SweepRequest) -> bool {
+    super::with_pending(|pc| pc.queue_sweep(request)).is_some()
+}
+
+pub fn queue_subdivide_from_bridge(request: SubdivideRequest) -> bool {
+    super::with_pending(|pc| pc.queue_subdivide(request)).is_some()
+}
+
+pub fn queue_apply_transform_from_bridge(request: ApplyTransformRequest) -> bool {
+    super::with_pending(|pc| pc.queue_apply_transform(request)).is_some()
+}
+
 pub fn queue_array_from_bridge(request: ArrayRequest) -> bool {
     super::with_pending(|pc| pc.queue_array(request)).is_some()
 }
 
+pub fn queue_instance_array_from_bridge(request: ArrayRequest) -> bool {
+    super::with_pending(|pc| pc.queue_instance_array(request)).is_some()
+}
+
+pub fn queue_clone_pattern_from_bridge(request: ClonePatternRequest) -> bool {
+    super::with_pending(|pc| pc.queue_clone_pattern(request)).is_some()
+}
+
 pub fn queue_combine_from_bridge(request: CombineRequest) -> bool {
     super::with_pending(|pc| pc.queue_combine(request)).is_some()
 }
@@ -8,6 +8,8 @@ pub enum QueryRequest {
     SceneGraph,
     Selection,
     EntityDetails { entity_id: String },
+    /// Every component an entity has, in one response.
+    EntityFull { entity_id: String },
     CameraState,
     EngineMode,
     InputBindings,
@@ -17,6 +19,8 @@ pub enum QueryRequest {
     AssetList,
     ScriptData { entity_id: String },
     ScriptTemplates,
+    /// Recent script errors for one entity, or for every entity when `None`.
+    ScriptErrors { entity_id: Option<String> },
     AudioData { entity_id: String },
     PostProcessingState,
     AudioBuses,
@@ -39,6 +43,13 @@ pub enum QueryRequest {
     SpriteAnimatorState { entity_id: String },
     PlayState,
     GameComponentTypes,
+    MaterialPresets,
+    EntitiesInRadius { center: [f32; 3], radius: f32 },
+    EntitiesInBox { min: [f32; 3], max: [f32; 3] },
+    EntitiesByTag { tag: String },
+    /// Several queries answered together in one `QUERY_BATCH_RESULT` event.
+    /// Batches cannot be nested.
+    Batch(Vec<QueryRequest>),
 }
 
 // === Queue Methods ===
@@ -38,21 +38,86 @@ pub struct RemoveTextureRequest {
 }
 
 #[derive(Debug, Clone)]
-pub struct SceneExportRequest;
+pub struct SceneExportRequest {
+    /// Emit gzip-compressed base64 (`SCENE_EXPORTED_BINARY`) instead of JSON.
+    pub binary: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct GltfExportRequest {
+    pub name: Option<String>,
+}
 
 #[derive(Debug, Clone)]
 pub struct SceneLoadRequest {
     pub json: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct SceneMergeRequest {
+    pub json: String,
+    pub offset: Option<bevy::math::Vec3>,
+    pub prefix: Option<String>,
+}
+
+/// Entity-level scene patch to apply to the live world.
+#[derive(Debug, Clone)]
+pub struct ScenePatchRequest {
+    pub patch: crate::core::scene_file::ScenePatch,
+}
+
 #[derive(Debug, Clone)]
 pub struct NewSceneRequest;
 
+/// Re-send the full scene graph instead of the next patch.
+#[derive(Debug, Clone)]
+pub struct SceneGraphRefreshRequest;
+
+/// Start or stop recording commands, or replay a recorded log.
+#[derive(Debug, Clone)]
+pub enum CommandLogRequest {
+    Start,
+    Stop,
+    Replay(Vec<crate::core::command_log::RecordedCommand>),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstantiatePrefabRequest {
     pub snapshot_json: String,
     pub position: Option<[f32; 3]>,
     pub name: Option<String>,
+    /// Links the spawned entity to this prefab so `update_prefab` reaches it.
+    #[serde(default)]
+    pub prefab_id: Option<String>,
+}
+
+/// Snapshot an entity and its descendants into a new prefab.
+#[derive(Debug, Clone)]
+pub struct CreatePrefabRequest {
+    pub entity_id: String,
+    pub prefab_name: String,
+    /// Link the source entity to the new prefab as its first instance.
+    pub link_source: bool,
+}
+
+/// Re-apply a prefab's (new) snapshot to all of its linked instances.
+#[derive(Debug, Clone)]
+pub struct PrefabUpdateRequest {
+    pub prefab_id: String,
+    pub snapshot: Box<crate::core::history::EntitySnapshot>,
+}
+
+/// Export top-level entities as a prefab library, one prefab each.
+#[derive(Debug, Clone)]
+pub struct ExportPrefabLibraryRequest {
+    /// Entities to export; the current selection when `None`.
+    pub entity_ids: Option<Vec<String>>,
+}
+
+/// Import a prefab library's prefabs and assets.
+#[derive(Debug, Clone)]
+pub struct ImportPrefabLibraryRequest {
+    pub json: String,
 }
 
 #[derive(Debug, Clone)]
@@ -70,17 +135,54 @@ pub struct QualityPresetRequest {
 
 impl PendingCommands {
     pub fn queue_scene_export(&mut self) {
-        self.scene_export_requests.push(SceneExportRequest);
+        self.scene_export_requests.push(SceneExportRequest { binary: false });
+    }
+
+    pub fn queue_binary_scene_export(&mut self) {
+        self.scene_export_requests.push(SceneExportRequest { binary: true });
+    }
+
+    pub fn queue_gltf_export(&mut self, request: GltfExportRequest) {
+        self.gltf_export_requests.push(request);
     }
 
     pub fn queue_scene_load(&mut self, request: SceneLoadRequest) {
         self.scene_load_requests.push(request);
     }
 
+    pub fn queue_scene_merge(&mut self, request: SceneMergeRequest) {
+        self.scene_merge_requests.push(request);
+    }
+
+    pub fn queue_scene_patch(&mut self, request: ScenePatchRequest) {
+        self.scene_patch_requests.push(request);
+    }
+
     pub fn queue_new_scene(&mut self) {
         self.new_scene_requests.push(NewSceneRequest);
     }
 
+    pub fn queue_scene_graph_refresh(&mut self) {
+        self.scene_graph_refresh_requests.push(SceneGraphRefreshRequest);
+    }
+
+    /// Start recording. Takes effect right away, so commands sent in the same
+    /// batch are recorded too.
+    pub fn queue_start_command_recording(&mut self, options: crate::core::command_log::RecordingOptions) {
+        self.command_recording = Some(options);
+        self.recorded_commands.clear();
+        self.command_log_requests.push(CommandLogRequest::Start);
+    }
+
+    pub fn queue_stop_command_recording(&mut self) {
+        self.command_recording = None;
+        self.command_log_requests.push(CommandLogRequest::Stop);
+    }
+
+    pub fn queue_command_replay(&mut self, commands: Vec<crate::core::command_log::RecordedCommand>) {
+        self.command_log_requests.push(CommandLogRequest::Replay(commands));
+    }
+
     pub fn queue_gltf_import(&mut self, request: GltfImportRequest) {
         self.gltf_import_requests.push(request);
     }
@@ -112,6 +214,22 @@ impl PendingCommands {
     pub fn queue_instantiate_prefab(&mut self, request: InstantiatePrefabRequest) {
         self.instantiate_prefab_requests.push(request);
     }
+
+    pub fn queue_create_prefab(&mut self, request: CreatePrefabRequest) {
+        self.create_prefab_requests.push(request);
+    }
+
+    pub fn queue_prefab_update(&mut self, request: PrefabUpdateRequest) {
+        self.prefab_update_requests.push(request);
+    }
+
+    pub fn queue_export_prefab_library(&mut self, request: ExportPrefabLibraryRequest) {
+        self.export_prefab_library_requests.push(request);
+    }
+
+    pub fn queue_import_prefab_library(&mut self, request: ImportPrefabLibraryRequest) {
+        self.import_prefab_library_requests.push(request);
+    }
 }
 
 // === Bridge Functions ===
@@ -120,14 +238,46 @@ pub fn queue_scene_export_from_bridge() -> bool {
     super::with_pending(|pc| pc.queue_scene_export()).is_some()
 }
 
+pub fn queue_binary_scene_export_from_bridge() -> bool {
+    super::with_pending(|pc| pc.queue_binary_scene_export()).is_some()
+}
+
+pub fn queue_gltf_export_from_bridge(request: GltfExportRequest) -> bool {
+    super::with_pending(|pc| pc.queue_gltf_export(request)).is_some()
+}
+
 pub fn queue_scene_load_from_bridge(request: SceneLoadRequest) -> bool {
     super::with_pending(|pc| pc.queue_scene_load(request)).is_some()
 }
 
+pub fn queue_scene_merge_from_bridge(request: SceneMergeRequest) -> bool {
+    super::with_pending(|pc| pc.queue_scene_merge(request)).is_some()
+}
+
+pub fn queue_scene_patch_from_bridge(request: ScenePatchRequest) -> bool {
+    super::with_pending(|pc| pc.queue_scene_patch(request)).is_some()
+}
+
 pub fn queue_new_scene_from_bridge() -> bool {
     super::with_pending(|pc| pc.queue_new_scene()).is_some()
 }
 
+pub fn queue_scene_graph_refresh_from_bridge() -> bool {
+    super::with_pending(|pc| pc.queue_scene_graph_refresh()).is_some()
+}
+
+pub fn queue_start_command_recording_from_bridge(options: crate::core::command_log::RecordingOptions) -> bool {
+    super::with_pending(|pc| pc.queue_start_command_recording(options)).is_some()
+}
+
+pub fn queue_stop_command_recording_from_bridge() -> bool {
+    super::with_pending(|pc| pc.queue_stop_command_recording()).is_some()
+}
+
+pub fn queue_command_replay_from_bridge(commands: Vec<crate::core::command_log::RecordedCommand>) -> bool {
+    super::with_pending(|pc| pc.queue_command_replay(commands)).is_some()
+}
+
 pub fn queue_gltf_import_from_bridge(request: GltfImportRequest) -> bool {
     super::with_pending(|pc| pc.queue_gltf_import(request)).is_some()
 }
@@ -159,3 +309,19 @@ pub fn queue_quality_preset_from_bridge(request: QualityPresetRequest) -> bool {
 pub fn queue_instantiate_prefab_from_bridge(request: InstantiatePrefabRequest) -> bool {
     super::with_pending(|pc| pc.queue_instantiate_prefab(request)).is_some()
 }
+
+pub fn queue_create_prefab_from_bridge(request: CreatePrefabRequest) -> bool {
+    super::with_pending(|pc| pc.queue_create_prefab(request)).is_some()
+}
+
+pub fn queue_prefab_update_from_bridge(request: PrefabUpdateRequest) -> bool {
+    super::with_pending(|pc| pc.queue_prefab_update(request)).is_some()
+}
+
+pub fn queue_export_prefab_library_from_bridge(request: ExportPrefabLibraryRequest) -> bool {
+    super::with_pending(|pc| pc.queue_export_prefab_library(request)).is_some()
+}
+
+pub fn queue_import_prefab_library_from_bridge(request: ImportPrefabLibraryRequest) -> bool {
+    super::with_pending(|pc| pc.queue_import_prefab_library(request)).is_some()
+}
@@ -20,6 +20,8 @@ pub struct SpriteDataUpdate {
     pub sorting_layer: Option<String>,
     pub sorting_order: Option<i32>,
     pub anchor: Option<String>,
+    pub nine_slice: Option<Option<crate::core::sprite::NineSliceBorders>>,
+    pub parallax_factor: Option<f32>,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +34,7 @@ pub struct Camera2dDataUpdate {
     pub zoom: Option<f32>,
     pub pixel_perfect: Option<bool>,
     pub bounds: Option<Option<Camera2dBounds>>,
+    pub follow_target_id: Option<Option<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -66,6 +69,19 @@ pub struct SpriteAnimatorRemoval {
     pub entity_id: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct SetSpriteAnimationRequest {
+    pub entity_id: String,
+    pub animation: crate::core::sprite::SpriteAnimation,
+}
+
+/// Play (`playing: true`) or stop a sprite's flipbook animation.
+#[derive(Debug, Clone)]
+pub struct SpriteAnimationPlaybackRequest {
+    pub entity_id: String,
+    pub playing: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct AnimationStateMachineUpdate {
     pub entity_id: String,
@@ -108,6 +124,12 @@ pub struct RemoveTilesetRequest {
     pub entity_id: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct SetTilesetAutotileRequest {
+    pub entity_id: String,
+    pub autotile: crate::core::tileset::AutoTileSet,
+}
+
 // === Tilemap Request Structs ===
 
 #[derive(Debug, Clone)]
@@ -230,6 +252,14 @@ impl PendingCommands {
         self.set_sorting_layers_requests.push(request);
     }
 
+    pub fn queue_set_sprite_animation(&mut self, request: SetSpriteAnimationRequest) {
+        self.set_sprite_animation_requests.push(request);
+    }
+
+    pub fn queue_sprite_animation_playback(&mut self, request: SpriteAnimationPlaybackRequest) {
+        self.sprite_animation_playback_requests.push(request);
+    }
+
     pub fn queue_set_tileset(&mut self, request: SetTilesetRequest) {
         self.set_tileset_requests.push(request);
     }
@@ -238,6 +268,10 @@ impl PendingCommands {
         self.remove_tileset_requests.push(request);
     }
 
+    pub fn queue_set_tileset_autotile(&mut self, request: SetTilesetAutotileRequest) {
+        self.set_tileset_autotile_requests.push(request);
+    }
+
     pub fn queue_tilemap_data_update(&mut self, update: TilemapDataUpdate) {
         self.tilemap_data_updates.push(update);
     }
@@ -313,6 +347,14 @@ pub fn queue_set_sorting_layers_from_bridge(request: SetSortingLayersRequest) ->
     super::with_pending(|pc| pc.queue_set_sorting_layers(request)).is_some()
 }
 
+pub fn queue_set_sprite_animation_from_bridge(request: SetSpriteAnimationRequest) -> bool {
+    super::with_pending(|pc| pc.queue_set_sprite_animation(request)).is_some()
+}
+
+pub fn queue_sprite_animation_playback_from_bridge(request: SpriteAnimationPlaybackRequest) -> bool {
+    super::with_pending(|pc| pc.queue_sprite_animation_playback(request)).is_some()
+}
+
 pub fn queue_set_tileset_from_bridge(request: SetTilesetRequest) -> bool {
     super::with_pending(|pc| pc.queue_set_tileset(request)).is_some()
 }
@@ -321,6 +363,10 @@ pub fn queue_remove_tileset_from_bridge(request: RemoveTilesetRequest) -> bool {
     super::with_pending(|pc| pc.queue_remove_tileset(request)).is_some()
 }
 
+pub fn queue_set_tileset_autotile_from_bridge(request: SetTilesetAutotileRequest) -> bool {
+    super::with_pending(|pc| pc.queue_set_tileset_autotile(request)).is_some()
+}
+
 pub fn queue_tilemap_data_update_from_bridge(update: TilemapDataUpdate) -> bool {
     super::with_pending(|pc| pc.queue_tilemap_data_update(update)).is_some()
 }
@@ -2,9 +2,12 @@
 
 use bevy::prelude::*;
 use super::PendingCommands;
-use crate::core::camera_presets::CameraPreset;
+use crate::core::camera_presets::{CameraPreset, CameraProjectionMode};
 use crate::core::gizmo::CoordinateMode;
 use crate::core::engine_mode::ModeChangeRequest;
+use crate::core::measurement::MeasureMode;
+use crate::core::render_mode::RenderMode;
+use crate::core::viewport::ViewportLayout;
 
 // === Request Structs ===
 
@@ -16,6 +19,11 @@ pub struct TransformUpdate {
     pub scale: Option<Vec3>,
 }
 
+#[derive(Debug, Clone)]
+pub struct TransformBatchUpdate {
+    pub updates: Vec<TransformUpdate>,
+}
+
 #[derive(Debug, Clone)]
 pub struct RenameRequest {
     pub entity_id: String,
@@ -34,11 +42,27 @@ pub struct CameraOrbitRequest {
     pub delta_radius: Option<f32>,
 }
 
+/// Frame the selection (or the whole scene) in the editor camera.
+#[derive(Debug, Clone)]
+pub struct FrameSelectionRequest {
+    /// Ease to the new view instead of jumping there.
+    pub animate: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct SpawnRequest {
     pub entity_type: super::EntityType,
     pub name: Option<String>,
     pub position: Option<Vec3>,
+    /// ID to give the entity instead of a fresh one.
+    pub entity_id: Option<String>,
+}
+
+/// Spawn one entity with all of its components configured up front. The
+/// snapshot already carries a fresh entity ID.
+#[derive(Debug, Clone)]
+pub struct SpawnConfiguredRequest {
+    pub snapshot: crate::core::history::EntitySnapshot,
 }
 
 #[derive(Debug, Clone)]
@@ -51,11 +75,48 @@ pub struct DuplicateRequest {
     pub entity_id: String,
 }
 
+/// Copy entities (and their descendants) to the clipboard.
+#[derive(Debug, Clone)]
+pub struct CopyEntitiesRequest {
+    pub entity_ids: Vec<String>,
+}
+
+/// Paste clipboard JSON, or the last copy made in this session if `json` is `None`.
+#[derive(Debug, Clone)]
+pub struct PasteEntitiesRequest {
+    pub json: Option<String>,
+    pub offset: Option<Vec3>,
+}
+
 #[derive(Debug, Clone)]
 pub struct CameraPresetRequest {
     pub preset: CameraPreset,
 }
 
+#[derive(Debug, Clone)]
+pub struct CameraProjectionRequest {
+    pub mode: CameraProjectionMode,
+    /// Orthographic view height in world units; derived from the orbit distance when unset.
+    pub ortho_scale: Option<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ViewportLayoutRequest {
+    pub layout: ViewportLayout,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderModeRequest {
+    pub mode: RenderMode,
+}
+
+/// Rate-limit an event type; `None` removes the limit.
+#[derive(Debug, Clone)]
+pub struct EventThrottleRequest {
+    pub event_type: String,
+    pub max_hz: Option<f64>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReparentRequest {
     pub entity_id: String,
@@ -63,14 +124,55 @@ pub struct ReparentRequest {
     pub insert_index: Option<usize>,
 }
 
+/// Group the current selection under a new empty at its centroid.
+#[derive(Debug, Clone)]
+pub struct GroupRequest {
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UngroupRequest {
+    pub entity_id: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct SnapSettingsUpdate {
     pub translation_snap: Option<f32>,
+    pub translation_snap_x: Option<f32>,
+    pub translation_snap_y: Option<f32>,
+    pub translation_snap_z: Option<f32>,
     pub rotation_snap_degrees: Option<f32>,
     pub scale_snap: Option<f32>,
     pub grid_visible: Option<bool>,
     pub grid_size: Option<f32>,
     pub grid_extent: Option<u32>,
+    pub vertex_snap: Option<bool>,
+    pub surface_snap: Option<bool>,
+}
+
+/// Selection outline colors (RGBA) and width in pixels.
+#[derive(Debug, Clone)]
+pub struct OutlineSettingsUpdate {
+    pub primary_color: Option<[f32; 4]>,
+    pub secondary_color: Option<[f32; 4]>,
+    pub thickness: Option<f32>,
+}
+
+/// Measurement between world points; the point count matches `mode`.
+#[derive(Debug, Clone)]
+pub struct MeasureRequest {
+    pub mode: MeasureMode,
+    pub points: Vec<Vec3>,
+    /// Move each point onto the first surface hit on the way from the editor camera.
+    pub snap_to_surface: bool,
+}
+
+/// Move entities down (or up) onto the surface below them.
+#[derive(Debug, Clone)]
+pub struct DropToGroundRequest {
+    pub entity_ids: Vec<String>,
+    /// Gap left between the entity's bounds and the surface.
+    pub offset: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -86,12 +188,59 @@ pub enum SelectionMode {
     Toggle,
 }
 
+/// Marquee selection rectangle in viewport (logical pixel) coordinates.
+#[derive(Debug, Clone)]
+pub struct BoxSelectRequest {
+    pub min: Vec2,
+    pub max: Vec2,
+    pub mode: SelectionMode,
+}
+
+/// Selection change that touches many entities at once and emits a single event.
+#[derive(Debug, Clone)]
+pub enum SelectionBulkRequest {
+    /// Apply `mode` to every listed entity.
+    Ids { entity_ids: Vec<String>, mode: SelectionMode },
+    /// Replace the selection with every entity of the given type.
+    AllByType(super::EntityType),
+    /// Replace the selection with every selectable entity not currently selected.
+    Invert,
+}
+
 #[derive(Debug, Clone)]
 pub struct VisibilityRequest {
     pub entity_id: String,
     pub visible: bool,
 }
 
+#[derive(Debug, Clone)]
+pub struct EntityLockRequest {
+    pub entity_id: String,
+    pub locked: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityTagRequest {
+    pub entity_id: String,
+    pub tag: String,
+    /// Add the tag when true, remove it when false.
+    pub add: bool,
+}
+
+/// Align the selected entities on one axis.
+#[derive(Debug, Clone)]
+pub struct AlignEntitiesRequest {
+    pub axis: crate::core::align::LayoutAxis,
+    pub mode: crate::core::align::AlignMode,
+}
+
+/// Space the selected entities evenly along one axis.
+#[derive(Debug, Clone)]
+pub struct DistributeEntitiesRequest {
+    pub axis: crate::core::align::LayoutAxis,
+    pub spacing: Option<f32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct GizmoModeRequest {
     pub mode: String,
@@ -104,6 +253,10 @@ impl PendingCommands {
         self.transform_updates.push(update);
     }
 
+    pub fn queue_transform_batch_update(&mut self, batch: TransformBatchUpdate) {
+        self.transform_batch_updates.push(batch);
+    }
+
     pub fn queue_rename(&mut self, request: RenameRequest) {
         self.rename_requests.push(request);
     }
@@ -116,10 +269,18 @@ impl PendingCommands {
         self.camera_orbit_requests.push(request);
     }
 
+    pub fn queue_frame_selection(&mut self, request: FrameSelectionRequest) {
+        self.frame_selection_requests.push(request);
+    }
+
     pub fn queue_spawn(&mut self, request: SpawnRequest) {
         self.spawn_requests.push(request);
     }
 
+    pub fn queue_spawn_configured(&mut self, request: SpawnConfiguredRequest) {
+        self.spawn_configured_requests.push(request);
+    }
+
     pub fn queue_delete(&mut self, request: DeleteRequest) {
         self.delete_requests.push(request);
     }
@@ -128,22 +289,70 @@ impl PendingCommands {
         self.duplicate_requests.push(request);
     }
 
+    pub fn queue_copy_entities(&mut self, request: CopyEntitiesRequest) {
+        self.copy_requests.push(request);
+    }
+
+    pub fn queue_paste_entities(&mut self, request: PasteEntitiesRequest) {
+        self.paste_requests.push(request);
+    }
+
     pub fn queue_snap_settings_update(&mut self, update: SnapSettingsUpdate) {
         self.snap_settings_updates.push(update);
     }
 
+    pub fn queue_outline_settings_update(&mut self, update: OutlineSettingsUpdate) {
+        self.outline_settings_updates.push(update);
+    }
+
     pub fn queue_grid_toggle(&mut self) {
         self.grid_toggles.push(());
     }
 
+    pub fn queue_measure(&mut self, request: MeasureRequest) {
+        self.measure_requests.push(request);
+    }
+
+    pub fn queue_drop_to_ground(&mut self, request: DropToGroundRequest) {
+        self.drop_to_ground_requests.push(request);
+    }
+
+    pub fn queue_clear_measurement(&mut self) {
+        self.clear_measurement_requests.push(());
+    }
+
     pub fn queue_camera_preset(&mut self, request: CameraPresetRequest) {
         self.camera_preset_requests.push(request);
     }
 
+    pub fn queue_camera_projection(&mut self, request: CameraProjectionRequest) {
+        self.camera_projection_requests.push(request);
+    }
+
+    pub fn queue_viewport_layout(&mut self, request: ViewportLayoutRequest) {
+        self.viewport_layout_requests.push(request);
+    }
+
+    pub fn queue_render_mode(&mut self, request: RenderModeRequest) {
+        self.render_mode_requests.push(request);
+    }
+
+    pub fn queue_event_throttle(&mut self, request: EventThrottleRequest) {
+        self.event_throttle_requests.push(request);
+    }
+
     pub fn queue_reparent(&mut self, request: ReparentRequest) {
         self.reparent_requests.push(request);
     }
 
+    pub fn queue_group(&mut self, request: GroupRequest) {
+        self.group_requests.push(request);
+    }
+
+    pub fn queue_ungroup(&mut self, request: UngroupRequest) {
+        self.ungroup_requests.push(request);
+    }
+
     pub fn queue_coordinate_mode_update(&mut self, mode: CoordinateMode) {
         self.coordinate_mode_update = Some(mode);
     }
@@ -152,10 +361,38 @@ impl PendingCommands {
         self.selection_requests.push(request);
     }
 
+    pub fn queue_selection_bulk(&mut self, request: SelectionBulkRequest) {
+        self.selection_bulk_requests.push(request);
+    }
+
+    pub fn queue_box_select(&mut self, request: BoxSelectRequest) {
+        self.box_select_requests.push(request);
+    }
+
     pub fn queue_visibility(&mut self, request: VisibilityRequest) {
         self.visibility_requests.push(request);
     }
 
+    pub fn queue_entity_lock(&mut self, request: EntityLockRequest) {
+        self.entity_lock_requests.push(request);
+    }
+
+    pub fn queue_entity_tag(&mut self, request: EntityTagRequest) {
+        self.entity_tag_requests.push(request);
+    }
+
+    pub fn queue_align_entities(&mut self, request: AlignEntitiesRequest) {
+        self.align_requests.push(request);
+    }
+
+    pub fn queue_distribute_entities(&mut self, request: DistributeEntitiesRequest) {
+        self.distribute_requests.push(request);
+    }
+
+    pub fn queue_smart_duplicate(&mut self) {
+        self.smart_duplicate_requests.push(());
+    }
+
     pub fn queue_clear_selection(&mut self) {
         self.clear_selection_requests.push(());
     }
@@ -175,6 +412,10 @@ pub fn queue_transform_update_from_bridge(update: TransformUpdate) -> bool {
     super::with_pending(|pc| pc.queue_transform_update(update)).is_some()
 }
 
+pub fn queue_transform_batch_update_from_bridge(batch: TransformBatchUpdate) -> bool {
+    super::with_pending(|pc| pc.queue_transform_batch_update(batch)).is_some()
+}
+
 pub fn queue_rename_from_bridge(request: RenameRequest) -> bool {
     super::with_pending(|pc| pc.queue_rename(request)).is_some()
 }
@@ -187,10 +428,18 @@ pub fn queue_camera_orbit_from_bridge(request: CameraOrbitRequest) -> bool {
     super::with_pending(|pc| pc.queue_camera_orbit(request)).is_some()
 }
 
+pub fn queue_frame_selection_from_bridge(request: FrameSelectionRequest) -> bool {
+    super::with_pending(|pc| pc.queue_frame_selection(request)).is_some()
+}
+
 pub fn queue_spawn_from_bridge(request: SpawnRequest) -> bool {
     super::with_pending(|pc| pc.queue_spawn(request)).is_some()
 }
 
+pub fn queue_spawn_configured_from_bridge(request: SpawnConfiguredRequest) -> bool {
+    super::with_pending(|pc| pc.queue_spawn_configured(request)).is_some()
+}
+
 pub fn queue_delete_from_bridge(request: DeleteRequest) -> bool {
     super::with_pending(|pc| pc.queue_delete(request)).is_some()
 }
@@ -199,22 +448,70 @@ pub fn queue_duplicate_from_bridge(request: DuplicateRequest) -> bool {
     super::with_pending(|pc| pc.queue_duplicate(request)).is_some()
 }
 
+pub fn queue_copy_entities_from_bridge(request: CopyEntitiesRequest) -> bool {
+    super::with_pending(|pc| pc.queue_copy_entities(request)).is_some()
+}
+
+pub fn queue_paste_entities_from_bridge(request: PasteEntitiesRequest) -> bool {
+    super::with_pending(|pc| pc.queue_paste_entities(request)).is_some()
+}
+
 pub fn queue_snap_settings_update_from_bridge(update: SnapSettingsUpdate) -> bool {
     super::with_pending(|pc| pc.queue_snap_settings_update(update)).is_some()
 }
 
+pub fn queue_outline_settings_update_from_bridge(update: OutlineSettingsUpdate) -> bool {
+    super::with_pending(|pc| pc.queue_outline_settings_update(update)).is_some()
+}
+
 pub fn queue_grid_toggle_from_bridge() -> bool {
     super::with_pending(|pc| pc.queue_grid_toggle()).is_some()
 }
 
+pub fn queue_measure_from_bridge(request: MeasureRequest) -> bool {
+    super::with_pending(|pc| pc.queue_measure(request)).is_some()
+}
+
+pub fn queue_drop_to_ground_from_bridge(request: DropToGroundRequest) -> bool {
+    super::with_pending(|pc| pc.queue_drop_to_ground(request)).is_some()
+}
+
+pub fn queue_clear_measurement_from_bridge() -> bool {
+    super::with_pending(|pc| pc.queue_clear_measurement()).is_some()
+}
+
 pub fn queue_camera_preset_from_bridge(request: CameraPresetRequest) -> bool {
     super::with_pending(|pc| pc.queue_camera_preset(request)).is_some()
 }
 
+pub fn queue_camera_projection_from_bridge(request: CameraProjectionRequest) -> bool {
+    super::with_pending(|pc| pc.queue_camera_projection(request)).is_some()
+}
+
+pub fn queue_viewport_layout_from_bridge(request: ViewportLayoutRequest) -> bool {
+    super::with_pending(|pc| pc.queue_viewport_layout(request)).is_some()
+}
+
+pub fn queue_render_mode_from_bridge(request: RenderModeRequest) -> bool {
+    super::with_pending(|pc| pc.queue_render_mode(request)).is_some()
+}
+
+pub fn queue_event_throttle_from_bridge(request: EventThrottleRequest) -> bool {
+    super::with_pending(|pc| pc.queue_event_throttle(request)).is_some()
+}
+
 pub fn queue_reparent_from_bridge(request: ReparentRequest) -> bool {
     super::with_pending(|pc| pc.queue_reparent(request)).is_some()
 }
 
+pub fn queue_group_from_bridge(request: GroupRequest) -> bool {
+    super::with_pending(|pc| pc.queue_group(request)).is_some()
+}
+
+pub fn queue_ungroup_from_bridge(request: UngroupRequest) -> bool {
+    super::with_pending(|pc| pc.queue_ungroup(request)).is_some()
+}
+
 pub fn queue_coordinate_mode_update_from_bridge(mode: CoordinateMode) -> bool {
     super::with_pending(|pc| pc.queue_coordinate_mode_update(mode)).is_some()
 }
@@ -223,10 +520,38 @@ pub fn queue_selection_from_bridge(request: SelectionRequest) -> bool {
     super::with_pending(|pc| pc.queue_selection(request)).is_some()
 }
 
+pub fn queue_selection_bulk_from_bridge(request: SelectionBulkRequest) -> bool {
+    super::with_pending(|pc| pc.queue_selection_bulk(request)).is_some()
+}
+
+pub fn queue_box_select_from_bridge(request: BoxSelectRequest) -> bool {
+    super::with_pending(|pc| pc.queue_box_select(request)).is_some()
+}
+
 pub fn queue_visibility_from_bridge(request: VisibilityRequest) -> bool {
     super::with_pending(|pc| pc.queue_visibility(request)).is_some()
 }
 
+pub fn queue_entity_lock_from_bridge(request: EntityLockRequest) -> bool {
+    super::with_pending(|pc| pc.queue_entity_lock(request)).is_some()
+}
+
+pub fn queue_entity_tag_from_bridge(request: EntityTagRequest) -> bool {
+    super::with_pending(|pc| pc.queue_entity_tag(request)).is_some()
+}
+
+pub fn queue_align_entities_from_bridge(request: AlignEntitiesRequest) -> bool {
+    super::with_pending(|pc| pc.queue_align_entities(request)).is_some()
+}
+
+pub fn queue_distribute_entities_from_bridge(request: DistributeEntitiesRequest) -> bool {
+    super::with_pending(|pc| pc.queue_distribute_entities(request)).is_some()
+}
+
+pub fn queue_smart_duplicate_from_bridge() -> bool {
+    super::with_pending(|pc| pc.queue_smart_duplicate()).is_some()
+}
+
 pub fn queue_clear_selection_from_bridge() -> bool {
     super::with_pending(|pc| pc.queue_clear_selection()).is_some()
 }
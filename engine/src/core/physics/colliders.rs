@@ -0,0 +1,135 @@
+//! Collider construction from primitive shapes, meshes and terrain.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+use super::{ColliderShape, PhysicsData, RigidBodyKind};
+use crate::core::terrain::{rebuild_terrain_mesh, TerrainData, TerrainMeshData};
+
+/// Create a Rapier Collider based on shape and entity scale.
+/// For Auto, defaults to cuboid (most common primitive shape). Mesh shapes
+/// fall back to a cuboid too when there is no mesh to build from.
+pub fn make_collider(shape: &ColliderShape, scale: Vec3) -> Collider {
+    match shape {
+        ColliderShape::Cuboid | ColliderShape::Auto | ColliderShape::ConvexHull | ColliderShape::TriMesh => {
+            Collider::cuboid(scale.x * 0.5, scale.y * 0.5, scale.z * 0.5)
+        }
+        ColliderShape::Ball => {
+            let radius: f32 = scale.x.max(scale.y).max(scale.z) * 0.5;
+            Collider::ball(radius)
+        }
+        ColliderShape::Cylinder => {
+            Collider::cylinder(scale.y * 0.5, scale.x * 0.5)
+        }
+        ColliderShape::Capsule => {
+            Collider::capsule_y(scale.y * 0.25, scale.x * 0.25)
+        }
+    }
+}
+
+/// Create a collider from a mesh's vertices and indices for the mesh-based
+/// shapes, or `None` for primitives and meshes Rapier can't use. Rapier
+/// scales it with the entity like any other collider.
+pub fn make_mesh_collider(shape: &ColliderShape, body_type: &RigidBodyKind, mesh: &Mesh) -> Option<Collider> {
+    let computed = match (shape, body_type) {
+        (ColliderShape::ConvexHull, _) => ComputedColliderShape::ConvexHull,
+        (ColliderShape::TriMesh, RigidBodyKind::Dynamic) => ComputedColliderShape::ConvexDecomposition(VHACDParameters::default()),
+        (ColliderShape::TriMesh, _) => ComputedColliderShape::TriMesh(TriMeshFlags::default()),
+        _ => return None,
+    };
+    Collider::from_bevy_mesh(mesh, &computed)
+}
+
+/// Create a triangle-mesh collider matching a terrain's surface. Quads
+/// flagged as holes are left out, so bodies fall through them.
+pub fn make_terrain_collider(data: &TerrainData, mesh_data: &TerrainMeshData) -> Option<Collider> {
+    let mesh = rebuild_terrain_mesh(mesh_data, &data.holes);
+    Collider::from_bevy_mesh(&mesh, &ComputedColliderShape::TriMesh(TriMeshFlags::default()))
+}
+
+/// The collider a body gets in Play: terrain surfaces and mesh shapes are
+/// built from their geometry, everything else from the primitive shape.
+pub fn body_collider(
+    data: &PhysicsData,
+    transform: &Transform,
+    mesh: Option<&Mesh>,
+    terrain: Option<(&TerrainData, &TerrainMeshData)>,
+) -> Collider {
+    terrain
+        .and_then(|(terrain, mesh_data)| make_terrain_collider(terrain, mesh_data))
+        .or_else(|| mesh.and_then(|mesh| make_mesh_collider(&data.collider_shape, &data.body_type, mesh)))
+        .unwrap_or_else(|| make_collider(&data.collider_shape, transform.scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::physics::tests::play_mode_app;
+    use crate::core::physics::PhysicsEnabled;
+
+    /// Unit-cube-sized L extruded along Z: the bounding box is the cube
+    /// around the origin, with the notch in its +X/+Y quarter.
+    fn l_shaped_mesh() -> Mesh {
+        use bevy::asset::RenderAssetUsages;
+        use bevy::mesh::{Indices, PrimitiveTopology};
+
+        let profile = [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.0], [0.0, 0.0], [0.0, 0.5], [-0.5, 0.5]];
+        let at = |[x, y]: [f32; 2], z: f32| [x, y, z];
+        let mut quads = Vec::new();
+        for (i, &a) in profile.iter().enumerate() {
+            let b = profile[(i + 1) % profile.len()];
+            quads.push([at(a, -0.5), at(b, -0.5), at(b, 0.5), at(a, 0.5)]);
+        }
+        for z in [-0.5, 0.5] {
+            quads.push([at([-0.5, -0.5], z), at([0.5, -0.5], z), at([0.5, 0.0], z), at([-0.5, 0.0], z)]);
+            quads.push([at([-0.5, 0.0], z), at([0.0, 0.0], z), at([0.0, 0.5], z), at([-0.5, 0.5], z)]);
+        }
+        let indices = (0..quads.len() as u32).flat_map(|q| [0, 1, 2, 0, 2, 3].map(|i| q * 4 + i)).collect();
+        Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, quads.concat())
+            .with_inserted_indices(Indices::U32(indices))
+    }
+
+    /// Drop a small ball into the notch of a fixed L-shaped mesh and return
+    /// the height it comes to rest at.
+    fn drop_ball_into_l_notch(collider_shape: ColliderShape) -> f32 {
+        let mut app = play_mode_app();
+
+        let mesh = app.world_mut().resource_mut::<Assets<Mesh>>().add(l_shaped_mesh());
+        app.world_mut().spawn((
+            Transform::default(),
+            Mesh3d(mesh),
+            PhysicsData { body_type: RigidBodyKind::Fixed, collider_shape, ..Default::default() },
+            PhysicsEnabled,
+        ));
+        let ball = app.world_mut().spawn((
+            Transform::from_xyz(0.25, 1.5, 0.0).with_scale(Vec3::splat(0.2)),
+            PhysicsData { collider_shape: ColliderShape::Ball, restitution: 0.0, ..Default::default() },
+            PhysicsEnabled,
+        )).id();
+
+        for _ in 0..120 {
+            app.update();
+        }
+        app.world().get::<Transform>(ball).expect("ball transform").translation.y
+    }
+
+    #[test]
+    fn trimesh_collider_lets_ball_into_concave_notch() {
+        let in_notch = drop_ball_into_l_notch(ColliderShape::TriMesh);
+        assert!(in_notch > -0.1 && in_notch < 0.25, "ball should rest on the notch floor, ended at y = {in_notch}");
+
+        let on_box = drop_ball_into_l_notch(ColliderShape::Cuboid);
+        assert!(on_box > 0.45, "a bounding box covers the notch, ball ended at y = {on_box}");
+    }
+
+    #[test]
+    fn dynamic_trimesh_bodies_use_convex_decomposition() {
+        let mesh = l_shaped_mesh();
+        let fixed = make_mesh_collider(&ColliderShape::TriMesh, &RigidBodyKind::Fixed, &mesh).expect("trimesh");
+        assert!(fixed.as_trimesh().is_some());
+        let dynamic = make_mesh_collider(&ColliderShape::TriMesh, &RigidBodyKind::Dynamic, &mesh).expect("decomposition");
+        assert!(dynamic.as_compound().is_some());
+        assert!(make_mesh_collider(&ColliderShape::Cuboid, &RigidBodyKind::Fixed, &mesh).is_none());
+    }
+}
@@ -0,0 +1,102 @@
+//! Contact classification and explosion impulses.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How a contact between two bodies is surfaced to scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactKind<T> {
+    /// Solid contact between two non-sensor bodies.
+    Collision,
+    /// Something entered or left a trigger volume.
+    Trigger { sensor: T, intruder: T },
+}
+
+/// Classify a contact pair. If either body is a sensor the contact is a
+/// trigger (when both are, `a` is reported as the sensor).
+pub fn classify_contact<T>(a: T, b: T, a_is_sensor: bool, b_is_sensor: bool) -> ContactKind<T> {
+    if a_is_sensor {
+        ContactKind::Trigger { sensor: a, intruder: b }
+    } else if b_is_sensor {
+        ContactKind::Trigger { sensor: b, intruder: a }
+    } else {
+        ContactKind::Collision
+    }
+}
+
+/// How a radial impulse weakens with distance from its center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RadialFalloff {
+    /// Full strength at the center, fading to zero at the radius.
+    #[default]
+    Linear,
+    /// Full strength everywhere inside the radius.
+    None,
+}
+
+/// Outward impulse a body at `position` receives from an explosion, or
+/// `None` outside the radius. A body exactly at the center is pushed up.
+pub fn radial_impulse(center: Vec3, radius: f32, strength: f32, falloff: RadialFalloff, position: Vec3) -> Option<Vec3> {
+    let offset = position - center;
+    let distance = offset.length();
+    if distance > radius {
+        return None;
+    }
+    let scale = match falloff {
+        RadialFalloff::Linear if radius > 0.0 => 1.0 - distance / radius,
+        RadialFalloff::Linear | RadialFalloff::None => 1.0,
+    };
+    Some(offset.try_normalize().unwrap_or(Vec3::Y) * strength * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy_rapier3d::prelude::*;
+    use crate::core::physics::tests::play_mode_app;
+    use crate::core::physics::{PhysicsData, PhysicsEnabled, RigidBodyKind};
+
+    #[test]
+    fn contact_with_a_sensor_is_a_trigger() {
+        assert_eq!(classify_contact("a", "b", false, false), ContactKind::Collision);
+        assert_eq!(classify_contact("a", "b", true, false), ContactKind::Trigger { sensor: "a", intruder: "b" });
+        assert_eq!(classify_contact("a", "b", false, true), ContactKind::Trigger { sensor: "b", intruder: "a" });
+    }
+
+    #[test]
+    fn sensor_overlapping_dynamic_body_triggers_without_contact_force() {
+        let mut app = play_mode_app();
+
+        let sensor = app.world_mut().spawn((
+            Transform::from_scale(Vec3::splat(2.0)),
+            PhysicsData { body_type: RigidBodyKind::Fixed, is_sensor: true, ..Default::default() },
+            PhysicsEnabled,
+        )).id();
+        let body = app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.5, 0.0),
+            PhysicsData { gravity_scale: 0.0, ..Default::default() },
+            PhysicsEnabled,
+        )).id();
+
+        let mut started = Vec::new();
+        for _ in 0..30 {
+            app.update();
+            let events = app.world_mut().resource_mut::<Messages<CollisionEvent>>().drain().collect::<Vec<_>>();
+            for event in events {
+                if let CollisionEvent::Started(a, b, _) = event {
+                    let world = app.world();
+                    started.push(classify_contact(a, b, world.get::<Sensor>(a).is_some(), world.get::<Sensor>(b).is_some()));
+                }
+            }
+        }
+
+        assert_eq!(started, vec![ContactKind::Trigger { sensor, intruder: body }]);
+        // The sensor must not push the overlapping body out.
+        let transform = app.world().get::<Transform>(body).expect("body transform");
+        assert!(transform.translation.distance(Vec3::new(0.0, 0.5, 0.0)) < 1e-4);
+        let velocity = app.world().get::<Velocity>(body).map(|v| v.linvel).unwrap_or(Vec3::ZERO);
+        assert!(velocity.length() < 1e-4);
+    }
+}
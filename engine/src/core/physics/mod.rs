@@ -0,0 +1,771 @@
+//! Physics system using bevy_rapier3d.
+//!
+//! Manages physics lifecycle: configuration in Edit mode, simulation in Play mode.
+//! `PhysicsData` stores persistent config; Rapier components are attached/detached
+//! on Play/Stop transitions.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use bevy_rapier3d::rapier::math::Vector;
+use serde::{Deserialize, Serialize};
+
+use super::engine_mode::EngineMode;
+use super::terrain::{TerrainData, TerrainMeshData};
+
+mod colliders;
+mod contacts;
+mod timestep;
+
+pub use colliders::*;
+pub use contacts::*;
+pub use timestep::*;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Collider shape for physics entities.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColliderShape {
+    Cuboid,
+    Ball,
+    Cylinder,
+    Capsule,
+    Auto,
+    /// Convex hull of the entity's mesh.
+    ConvexHull,
+    /// The entity's mesh triangles; dynamic bodies get a convex
+    /// decomposition instead, since Rapier can't give them mass otherwise.
+    #[serde(rename = "trimesh")]
+    TriMesh,
+}
+
+impl ColliderShape {
+    /// Whether the collider is built from the entity's mesh.
+    pub fn uses_mesh(&self) -> bool {
+        matches!(self, ColliderShape::ConvexHull | ColliderShape::TriMesh)
+    }
+}
+
+impl Default for ColliderShape {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+/// Rigid body type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RigidBodyKind {
+    Dynamic,
+    Fixed,
+    KinematicPosition,
+    KinematicVelocity,
+}
+
+impl Default for RigidBodyKind {
+    fn default() -> Self {
+        Self::Dynamic
+    }
+}
+
+fn default_collision_mask() -> u32 {
+    u32::MAX
+}
+
+/// Physics configuration component (stored persistently on entities).
+/// This is the serializable, bridge-friendly representation of physics properties.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicsData {
+    pub body_type: RigidBodyKind,
+    pub collider_shape: ColliderShape,
+    pub restitution: f32,
+    pub friction: f32,
+    pub density: f32,
+    pub gravity_scale: f32,
+    pub lock_translation_x: bool,
+    pub lock_translation_y: bool,
+    pub lock_translation_z: bool,
+    pub lock_rotation_x: bool,
+    pub lock_rotation_y: bool,
+    pub lock_rotation_z: bool,
+    pub is_sensor: bool,
+    /// Continuous collision detection — prevents fast bodies tunneling through thin colliders.
+    #[serde(default)]
+    pub ccd_enabled: bool,
+    /// Bitmask of the collision layers this body belongs to.
+    #[serde(default = "default_collision_mask")]
+    pub collision_membership: u32,
+    /// Bitmask of the collision layers this body interacts with.
+    #[serde(default = "default_collision_mask")]
+    pub collision_filter: u32,
+    /// World-space velocity the surface imparts on bodies touching it, like
+    /// a conveyor belt; the body itself stays where it is.
+    #[serde(default)]
+    pub surface_velocity: [f32; 3],
+}
+
+impl Default for PhysicsData {
+    fn default() -> Self {
+        Self {
+            body_type: RigidBodyKind::Dynamic,
+            collider_shape: ColliderShape::Auto,
+            restitution: 0.3,
+            friction: 0.5,
+            density: 1.0,
+            gravity_scale: 1.0,
+            lock_translation_x: false,
+            lock_translation_y: false,
+            lock_translation_z: false,
+            lock_rotation_x: false,
+            lock_rotation_y: false,
+            lock_rotation_z: false,
+            is_sensor: false,
+            ccd_enabled: false,
+            collision_membership: u32::MAX,
+            collision_filter: u32::MAX,
+            surface_velocity: [0.0; 3],
+        }
+    }
+}
+
+impl PhysicsData {
+    /// Whether contacts with this body need the surface velocity hook.
+    pub fn has_surface_velocity(&self) -> bool {
+        self.surface_velocity != [0.0; 3]
+    }
+}
+
+/// Marker component: entity has active physics simulation enabled.
+/// Separate from PhysicsData to allow toggling physics on/off without losing config.
+#[derive(Component, Debug, Clone)]
+pub struct PhysicsEnabled;
+
+/// Resource controlling debug physics wireframe rendering.
+#[derive(Resource, Debug, Clone)]
+pub struct DebugPhysicsEnabled(pub bool);
+
+impl Default for DebugPhysicsEnabled {
+    fn default() -> Self {
+        Self(false)
+    }
+}
+
+/// Joint type for connecting physics bodies.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JointType {
+    Fixed,
+    Revolute,
+    Spherical,
+    Prismatic,
+    Rope,
+    Spring,
+}
+
+/// Joint limits for constrained motion.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JointLimits {
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Joint motor configuration.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JointMotor {
+    pub target_velocity: f32,
+    pub max_force: f32,
+}
+
+/// Physics joint component (stored persistently on entities).
+/// Connects this entity to another entity with physics constraints.
+#[derive(Component, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JointData {
+    pub joint_type: JointType,
+    pub connected_entity_id: String,
+    pub anchor_self: [f32; 3],
+    pub anchor_other: [f32; 3],
+    pub axis: [f32; 3],  // For revolute/prismatic
+    pub limits: Option<JointLimits>,
+    pub motor: Option<JointMotor>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Convert our RigidBodyKind to Rapier's RigidBody component.
+pub fn to_rapier_body(kind: &RigidBodyKind) -> RigidBody {
+    match kind {
+        RigidBodyKind::Dynamic => RigidBody::Dynamic,
+        RigidBodyKind::Fixed => RigidBody::Fixed,
+        RigidBodyKind::KinematicPosition => RigidBody::KinematicPositionBased,
+        RigidBodyKind::KinematicVelocity => RigidBody::KinematicVelocityBased,
+    }
+}
+
+/// Build LockedAxes bitflags from PhysicsData booleans.
+pub fn build_locked_axes(data: &PhysicsData) -> LockedAxes {
+    let mut axes = LockedAxes::empty();
+    if data.lock_translation_x { axes |= LockedAxes::TRANSLATION_LOCKED_X; }
+    if data.lock_translation_y { axes |= LockedAxes::TRANSLATION_LOCKED_Y; }
+    if data.lock_translation_z { axes |= LockedAxes::TRANSLATION_LOCKED_Z; }
+    if data.lock_rotation_x { axes |= LockedAxes::ROTATION_LOCKED_X; }
+    if data.lock_rotation_y { axes |= LockedAxes::ROTATION_LOCKED_Y; }
+    if data.lock_rotation_z { axes |= LockedAxes::ROTATION_LOCKED_Z; }
+    axes
+}
+
+/// Build Rapier CollisionGroups from the PhysicsData layer bitmasks.
+/// Two bodies interact only if each one's membership overlaps the other's filter.
+pub fn build_collision_groups(data: &PhysicsData) -> CollisionGroups {
+    CollisionGroups::new(
+        Group::from_bits_retain(data.collision_membership),
+        Group::from_bits_retain(data.collision_filter),
+    )
+}
+
+/// Build the query filter for a raycast, optionally restricted to colliders
+/// whose membership overlaps `filter_groups`.
+pub fn raycast_filter(filter_groups: Option<u32>) -> QueryFilter<'static> {
+    match filter_groups {
+        Some(groups) => QueryFilter::default()
+            .groups(CollisionGroups::new(Group::ALL, Group::from_bits_retain(groups))),
+        None => QueryFilter::default(),
+    }
+}
+
+/// Rapier hooks that make contacts with moving surfaces drag the other body
+/// along.
+#[derive(SystemParam)]
+pub struct SurfaceVelocityHooks<'w, 's> {
+    bodies: Query<'w, 's, &'static PhysicsData>,
+}
+
+impl SurfaceVelocityHooks<'_, '_> {
+    fn surface_velocity(&self, entity: Entity) -> Vec3 {
+        self.bodies.get(entity).map_or(Vec3::ZERO, |data| Vec3::from(data.surface_velocity))
+    }
+}
+
+impl BevyPhysicsHooks for SurfaceVelocityHooks<'_, '_> {
+    fn modify_solver_contacts(&self, context: ContactModificationContextView) {
+        // Rapier's tangent velocity is the second body's velocity relative
+        // to the first, so each surface drives the body on the other side.
+        let relative = self.surface_velocity(context.collider1()) - self.surface_velocity(context.collider2());
+        if relative == Vec3::ZERO {
+            return;
+        }
+        for contact in context.raw.solver_contacts.iter_mut() {
+            contact.tangent_velocity = Vector::new(relative.x, relative.y, relative.z);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lifecycle systems
+// ---------------------------------------------------------------------------
+
+/// Physics entities awaiting Rapier components. Meshes and terrain
+/// heightmaps are included so mesh shapes and terrain get colliders built
+/// from their geometry instead of a primitive.
+type PhysicsAttachQuery<'w, 's> = Query<'w, 's,
+    (
+        Entity,
+        &'static PhysicsData,
+        &'static Transform,
+        Option<&'static Mesh3d>,
+        Option<(&'static TerrainData, &'static TerrainMeshData)>,
+    ),
+    (With<PhysicsEnabled>, Without<RigidBody>),
+>;
+
+/// Unified system managing the physics simulation lifecycle.
+/// Handles Edit→Play (attach), Play→Edit (detach), and Paused states.
+fn manage_physics_lifecycle(
+    engine_mode: Res<EngineMode>,
+    mut commands: Commands,
+    to_attach: PhysicsAttachQuery,
+    meshes: Res<Assets<Mesh>>,
+    to_detach: Query<Entity, With<RigidBody>>,
+    mut rapier_config: Query<&mut RapierConfiguration>,
+    mut prev_mode: Local<Option<EngineMode>>,
+) {
+    let current = *engine_mode;
+    let prev = *prev_mode;
+    *prev_mode = Some(current);
+
+    // Sync the physics pipeline active flag:
+    // Active only during Play (not Edit, not Paused).
+    let should_be_active = current == EngineMode::Play;
+    if let Ok(mut config) = rapier_config.single_mut() {
+        if config.physics_pipeline_active != should_be_active {
+            config.physics_pipeline_active = should_be_active;
+        }
+    }
+
+    // Transition: Edit → Play — attach Rapier components
+    let entering_play = current == EngineMode::Play
+        && prev.map_or(true, |p| p == EngineMode::Edit);
+    if entering_play {
+        for (entity, physics_data, transform, mesh, terrain) in to_attach.iter() {
+            let mesh = mesh.filter(|_| physics_data.collider_shape.uses_mesh()).and_then(|m| meshes.get(&m.0));
+            let collider = body_collider(physics_data, transform, mesh, terrain);
+            let rigid_body = to_rapier_body(&physics_data.body_type);
+            let locked_axes = build_locked_axes(physics_data);
+            let collision_groups = build_collision_groups(physics_data);
+
+            let mut ec = commands.entity(entity);
+            ec.insert(rigid_body)
+              .insert(TransformInterpolation::default())
+              .insert(collider)
+              .insert(Restitution::coefficient(physics_data.restitution))
+              .insert(Friction::coefficient(physics_data.friction))
+              .insert(ColliderMassProperties::Density(physics_data.density))
+              .insert(GravityScale(physics_data.gravity_scale))
+              .insert(locked_axes)
+              .insert(collision_groups)
+              .insert(ActiveEvents::COLLISION_EVENTS);
+
+            if physics_data.is_sensor {
+                ec.insert(Sensor);
+            }
+
+            if physics_data.ccd_enabled {
+                ec.insert(Ccd::enabled());
+            }
+
+            if physics_data.has_surface_velocity() {
+                ec.insert(ActiveHooks::MODIFY_SOLVER_CONTACTS);
+            }
+        }
+        tracing::info!("Physics attached: {} entities", to_attach.iter().count());
+    }
+
+    // Transition: Play/Paused → Edit (Stop) — remove all Rapier components
+    let entering_edit = current == EngineMode::Edit
+        && prev.map_or(false, |p| p != EngineMode::Edit);
+    if entering_edit {
+        for entity in to_detach.iter() {
+            commands.entity(entity)
+                .remove::<RigidBody>()
+                .remove::<TransformInterpolation>()
+                .remove::<Collider>()
+                .remove::<Velocity>()
+                .remove::<Restitution>()
+                .remove::<Friction>()
+                .remove::<ColliderMassProperties>()
+                .remove::<GravityScale>()
+                .remove::<LockedAxes>()
+                .remove::<CollisionGroups>()
+                .remove::<Sensor>()
+                .remove::<Ccd>()
+                .remove::<ExternalForce>()
+                .remove::<ExternalImpulse>()
+                .remove::<ActiveEvents>()
+                .remove::<ActiveHooks>();
+        }
+        tracing::info!("Physics detached");
+    }
+}
+
+/// System that keeps `Ccd` on live bodies in step with `PhysicsData.ccd_enabled`,
+/// so toggling CCD mid-play doesn't require recreating the body.
+fn sync_live_ccd(
+    mut commands: Commands,
+    query: Query<(Entity, Ref<PhysicsData>, Has<Ccd>), With<RigidBody>>,
+) {
+    for (entity, physics_data, has_ccd) in query.iter() {
+        if !physics_data.is_changed() {
+            continue;
+        }
+        if physics_data.ccd_enabled && !has_ccd {
+            commands.entity(entity).insert(Ccd::enabled());
+        } else if !physics_data.ccd_enabled && has_ccd {
+            commands.entity(entity).remove::<Ccd>();
+        }
+    }
+}
+
+/// System that turns the surface velocity hook on or off for live bodies
+/// whose `PhysicsData.surface_velocity` changes mid-play.
+fn sync_live_surface_velocity(
+    mut commands: Commands,
+    query: Query<(Entity, Ref<PhysicsData>, Has<ActiveHooks>), With<RigidBody>>,
+) {
+    for (entity, physics_data, has_hooks) in query.iter() {
+        if !physics_data.is_changed() {
+            continue;
+        }
+        if physics_data.has_surface_velocity() && !has_hooks {
+            commands.entity(entity).insert(ActiveHooks::MODIFY_SOLVER_CONTACTS);
+        } else if !physics_data.has_surface_velocity() && has_hooks {
+            commands.entity(entity).remove::<ActiveHooks>();
+        }
+    }
+}
+
+/// System that rebuilds live terrain colliders when the terrain changes
+/// (e.g. holes painted mid-play).
+fn sync_terrain_colliders(
+    mut commands: Commands,
+    query: Query<(Entity, Ref<TerrainData>, &TerrainMeshData), With<RigidBody>>,
+) {
+    for (entity, data, mesh_data) in query.iter() {
+        if !data.is_changed() {
+            continue;
+        }
+        if let Some(collider) = make_terrain_collider(&data, mesh_data) {
+            commands.entity(entity).insert(collider);
+        }
+    }
+}
+
+/// System that syncs the debug render toggle.
+fn sync_debug_physics(
+    debug_enabled: Res<DebugPhysicsEnabled>,
+    mut debug_context: Option<ResMut<DebugRenderContext>>,
+) {
+    if debug_enabled.is_changed() {
+        if let Some(ref mut ctx) = debug_context {
+            ctx.enabled = debug_enabled.0;
+        }
+    }
+}
+
+/// Unified system managing the joint lifecycle.
+/// Handles Edit→Play (attach), Play→Edit (detach).
+fn manage_joint_lifecycle(
+    engine_mode: Res<EngineMode>,
+    mut commands: Commands,
+    to_attach: Query<(Entity, &JointData), (Without<ImpulseJoint>,)>,
+    to_detach: Query<Entity, With<ImpulseJoint>>,
+    entity_id_query: Query<(Entity, &super::entity_id::EntityId)>,
+    mut prev_mode: Local<Option<EngineMode>>,
+) {
+    let current = *engine_mode;
+    let prev = *prev_mode;
+    *prev_mode = Some(current);
+
+    // Transition: Edit → Play — attach Rapier joints
+    let entering_play = current == EngineMode::Play
+        && prev.map_or(true, |p| p == EngineMode::Edit);
+    if entering_play {
+        for (entity, joint_data) in to_attach.iter() {
+            // Resolve connected entity ID to Bevy Entity
+            let connected_entity = entity_id_query
+                .iter()
+                .find(|(_, eid)| eid.0 == joint_data.connected_entity_id)
+                .map(|(e, _)| e);
+
+            let Some(connected_entity) = connected_entity else {
+                tracing::warn!(
+                    "Joint connected entity not found: {}",
+                    joint_data.connected_entity_id
+                );
+                continue;
+            };
+
+            // Build the appropriate Rapier joint
+            let anchor1 = Vec3::from(joint_data.anchor_self);
+            let anchor2 = Vec3::from(joint_data.anchor_other);
+            let axis = Vec3::from(joint_data.axis);
+
+            let joint: TypedJoint = match &joint_data.joint_type {
+                JointType::Fixed => {
+                    FixedJointBuilder::new()
+                        .local_anchor1(anchor1)
+                        .local_anchor2(anchor2)
+                        .build()
+                        .into()
+                }
+                JointType::Revolute => {
+                    let mut builder = RevoluteJointBuilder::new(axis)
+                        .local_anchor1(anchor1)
+                        .local_anchor2(anchor2);
+                    if let Some(limits) = &joint_data.limits {
+                        builder = builder.limits([limits.min, limits.max]);
+                    }
+                    if let Some(motor) = &joint_data.motor {
+                        builder = builder
+                            .motor_velocity(motor.target_velocity, 0.5)
+                            .motor_max_force(motor.max_force);
+                    }
+                    builder.build().into()
+                }
+                JointType::Spherical => {
+                    SphericalJointBuilder::new()
+                        .local_anchor1(anchor1)
+                        .local_anchor2(anchor2)
+                        .build()
+                        .into()
+                }
+                JointType::Prismatic => {
+                    let mut builder = PrismaticJointBuilder::new(axis)
+                        .local_anchor1(anchor1)
+                        .local_anchor2(anchor2);
+                    if let Some(limits) = &joint_data.limits {
+                        builder = builder.limits([limits.min, limits.max]);
+                    }
+                    if let Some(motor) = &joint_data.motor {
+                        builder = builder
+                            .motor_velocity(motor.target_velocity, 0.5)
+                            .motor_max_force(motor.max_force);
+                    }
+                    builder.build().into()
+                }
+                JointType::Rope => {
+                    let max_distance = joint_data.limits.as_ref().map(|l| l.max).unwrap_or(1.0);
+                    RopeJointBuilder::new(max_distance)
+                        .local_anchor1(anchor1)
+                        .local_anchor2(anchor2)
+                        .build()
+                        .into()
+                }
+                JointType::Spring => {
+                    let rest_length = joint_data.limits.as_ref().map(|l| l.min).unwrap_or(1.0);
+                    let stiffness = joint_data.motor.as_ref().map(|m| m.target_velocity).unwrap_or(1.0);
+                    let damping = joint_data.motor.as_ref().map(|m| m.max_force).unwrap_or(0.1);
+                    SpringJointBuilder::new(rest_length, stiffness, damping)
+                        .local_anchor1(anchor1)
+                        .local_anchor2(anchor2)
+                        .build()
+                        .into()
+                }
+            };
+
+            commands.entity(entity).insert(ImpulseJoint::new(connected_entity, joint));
+        }
+        tracing::info!("Joints attached: {} joints", to_attach.iter().count());
+    }
+
+    // Transition: Play/Paused → Edit (Stop) — remove all ImpulseJoint components
+    let entering_edit = current == EngineMode::Edit
+        && prev.map_or(false, |p| p != EngineMode::Edit);
+    if entering_edit {
+        for entity in to_detach.iter() {
+            commands.entity(entity).remove::<ImpulseJoint>();
+        }
+        tracing::info!("Joints detached");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Physics plugin that integrates bevy_rapier3d with the editor's mode system.
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        use super::engine_mode::PlaySystemSet;
+
+        app.add_plugins(RapierPhysicsPlugin::<SurfaceVelocityHooks>::default())
+            .add_plugins(RapierDebugRenderPlugin::default())
+            .init_resource::<DebugPhysicsEnabled>()
+            .init_resource::<PhysicsTimestep>()
+            .add_systems(Update, (
+                apply_physics_timestep,
+                manage_physics_lifecycle,
+                sync_live_ccd.after(manage_physics_lifecycle),
+                sync_live_surface_velocity.after(manage_physics_lifecycle),
+                sync_terrain_colliders.after(manage_physics_lifecycle),
+                sync_debug_physics,
+            ))
+            .add_systems(Update, manage_joint_lifecycle.in_set(PlaySystemSet));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+
+    /// Headless app running the physics lifecycle in Play mode with a fixed 60 Hz step.
+    pub(super) fn play_mode_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), bevy::scene::ScenePlugin))
+            .init_asset::<Mesh>()
+            .add_plugins(RapierPhysicsPlugin::<SurfaceVelocityHooks>::default())
+            .insert_resource(TimestepMode::Fixed { dt: 1.0 / 60.0, substeps: 1 })
+            .insert_resource(EngineMode::Play)
+            .add_systems(Update, (
+                manage_physics_lifecycle,
+                sync_live_ccd.after(manage_physics_lifecycle),
+                sync_live_surface_velocity.after(manage_physics_lifecycle),
+            ));
+        app
+    }
+
+    /// Step the app and report whether any collision started along the way.
+    fn step_and_detect_collision(app: &mut App, steps: usize) -> bool {
+        let mut collided = false;
+        for _ in 0..steps {
+            app.update();
+            let events = app.world_mut().resource_mut::<Messages<CollisionEvent>>().drain().collect::<Vec<_>>();
+            collided |= events.iter().any(|e| matches!(e, CollisionEvent::Started(..)));
+        }
+        collided
+    }
+
+    /// Fire a small sphere at 100 m/s toward a 2 cm thick wall and report
+    /// whether any collision was registered and where the sphere ended up.
+    fn fire_sphere_at_thin_wall(ccd_enabled: bool) -> (bool, f32) {
+        let mut app = play_mode_app();
+
+        app.world_mut().spawn((
+            Transform::from_xyz(5.0, 0.0, 0.0).with_scale(Vec3::new(0.02, 10.0, 10.0)),
+            PhysicsData { body_type: RigidBodyKind::Fixed, collider_shape: ColliderShape::Cuboid, ..Default::default() },
+            PhysicsEnabled,
+        ));
+        let sphere = app.world_mut().spawn((
+            Transform::from_xyz(0.5, 0.0, 0.0).with_scale(Vec3::splat(0.1)),
+            PhysicsData { collider_shape: ColliderShape::Ball, gravity_scale: 0.0, ccd_enabled, ..Default::default() },
+            PhysicsEnabled,
+            Velocity::linear(Vec3::new(100.0, 0.0, 0.0)),
+        )).id();
+
+        let collided = step_and_detect_collision(&mut app, 10);
+        let x = app.world().get::<Transform>(sphere).expect("sphere transform").translation.x;
+        (collided, x)
+    }
+
+    /// Drop a cube onto a fixed floor with the given (membership, filter) layers.
+    fn drop_cube_on_floor(cube_layers: (u32, u32), floor_layers: (u32, u32)) -> bool {
+        let mut app = play_mode_app();
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.0, 0.0).with_scale(Vec3::new(10.0, 1.0, 10.0)),
+            PhysicsData {
+                body_type: RigidBodyKind::Fixed,
+                collider_shape: ColliderShape::Cuboid,
+                collision_membership: floor_layers.0,
+                collision_filter: floor_layers.1,
+                ..Default::default()
+            },
+            PhysicsEnabled,
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            PhysicsData {
+                collider_shape: ColliderShape::Cuboid,
+                collision_membership: cube_layers.0,
+                collision_filter: cube_layers.1,
+                ..Default::default()
+            },
+            PhysicsEnabled,
+        ));
+
+        step_and_detect_collision(&mut app, 60)
+    }
+
+    #[test]
+    fn ccd_stops_fast_sphere_at_thin_wall() {
+        let (collided, x) = fire_sphere_at_thin_wall(true);
+        assert!(collided, "expected a collision with CCD enabled");
+        assert!(x < 5.0, "sphere should stay in front of the wall, ended at x = {x}");
+    }
+
+    #[test]
+    fn fast_sphere_tunnels_through_thin_wall_without_ccd() {
+        let (collided, x) = fire_sphere_at_thin_wall(false);
+        assert!(!collided, "expected no collision with CCD disabled");
+        assert!(x > 5.0, "sphere should pass the wall, ended at x = {x}");
+    }
+
+    #[test]
+    fn bodies_on_default_layers_collide() {
+        assert!(drop_cube_on_floor((u32::MAX, u32::MAX), (u32::MAX, u32::MAX)));
+    }
+
+    #[test]
+    fn bodies_in_non_overlapping_groups_do_not_collide() {
+        assert!(!drop_cube_on_floor((0b01, 0b01), (0b10, 0b10)));
+    }
+
+    #[test]
+    fn collision_requires_both_filters_to_accept() {
+        // The cube wants the floor, but the floor filters the cube's layer out.
+        assert!(!drop_cube_on_floor((0b01, 0b10), (0b10, 0b10)));
+    }
+
+    #[test]
+    fn collision_groups_map_bitmasks_directly() {
+        let data = PhysicsData { collision_membership: 0b0101, collision_filter: 0b0011, ..Default::default() };
+        let groups = build_collision_groups(&data);
+        assert_eq!(groups.memberships.bits(), 0b0101);
+        assert_eq!(groups.filters.bits(), 0b0011);
+    }
+
+    #[test]
+    fn toggling_ccd_on_live_body_updates_component_in_place() {
+        let mut world = World::new();
+        let body = world.spawn((RigidBody::Dynamic, PhysicsData::default())).id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(sync_live_ccd);
+
+        world.get_mut::<PhysicsData>(body).unwrap().ccd_enabled = true;
+        schedule.run(&mut world);
+        assert!(world.get::<Ccd>(body).is_some_and(|c| c.enabled));
+
+        world.get_mut::<PhysicsData>(body).unwrap().ccd_enabled = false;
+        schedule.run(&mut world);
+        assert!(world.get::<Ccd>(body).is_none());
+        assert!(world.get::<RigidBody>(body).is_some(), "body must not be recreated");
+    }
+
+    #[test]
+    fn older_payloads_default_to_no_ccd_and_all_layers() {
+        let data: PhysicsData = serde_json::from_value(serde_json::json!({
+            "bodyType": "dynamic", "colliderShape": "ball", "restitution": 0.3, "friction": 0.5,
+            "density": 1.0, "gravityScale": 1.0,
+            "lockTranslationX": false, "lockTranslationY": false, "lockTranslationZ": false,
+            "lockRotationX": false, "lockRotationY": false, "lockRotationZ": false,
+            "isSensor": false,
+        })).expect("valid physics payload");
+        assert!(!data.ccd_enabled);
+        assert_eq!(data.collision_membership, u32::MAX);
+        assert_eq!(data.collision_filter, u32::MAX);
+        assert!(!data.has_surface_velocity());
+    }
+
+    /// Rest a box on a fixed belt with the given surface velocity for a
+    /// second and return where the box ends up.
+    fn box_on_belt(surface_velocity: [f32; 3]) -> Vec3 {
+        let mut app = play_mode_app();
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.0, 0.0).with_scale(Vec3::new(20.0, 1.0, 4.0)),
+            PhysicsData { body_type: RigidBodyKind::Fixed, collider_shape: ColliderShape::Cuboid, surface_velocity, ..Default::default() },
+            PhysicsEnabled,
+        ));
+        let cargo = app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            PhysicsData { collider_shape: ColliderShape::Cuboid, ..Default::default() },
+            PhysicsEnabled,
+        )).id();
+
+        for _ in 0..60 {
+            app.update();
+        }
+        app.world().get::<Transform>(cargo).expect("box transform").translation
+    }
+
+    #[test]
+    fn conveyor_surface_carries_resting_box() {
+        let carried = box_on_belt([2.0, 0.0, 0.0]);
+        assert!(carried.x > 0.5, "box should drift along +X, ended at {carried}");
+        assert!(carried.z.abs() < 0.05);
+
+        let still = box_on_belt([0.0; 3]);
+        assert!(still.x.abs() < 0.05, "box on a still surface should stay put, ended at {still}");
+    }
+
+}
@@ -0,0 +1,136 @@
+//! Fixed and variable stepping of the physics worlds.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde::Serialize;
+
+/// How the 3D and 2D physics worlds are stepped, set by `set_physics_timestep`.
+///
+/// With a fixed step the simulation advances in steps of exactly `dt`, as many
+/// per frame as real time calls for, and bodies are interpolated in between.
+/// Rapier is built with `enhanced-determinism`, so the same inputs replay to
+/// the same result.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicsTimestep {
+    /// Seconds per frame; `None` follows the frame time.
+    pub fixed_dt: Option<f32>,
+    pub substeps: usize,
+    /// Replay mode: always use a fixed step, falling back to
+    /// `DETERMINISTIC_DT` when no `fixed_dt` is set.
+    pub physics_determinism: bool,
+}
+
+impl Default for PhysicsTimestep {
+    fn default() -> Self {
+        Self { fixed_dt: None, substeps: 1, physics_determinism: false }
+    }
+}
+
+impl PhysicsTimestep {
+    pub const DETERMINISTIC_DT: f32 = 1.0 / 60.0;
+    /// Upper bound on a variable step, matching Rapier's default.
+    pub const MAX_VARIABLE_DT: f32 = 1.0 / 60.0;
+
+    /// The step actually in effect, if fixed.
+    pub fn effective_fixed_dt(&self) -> Option<f32> {
+        match (self.fixed_dt, self.physics_determinism) {
+            (Some(dt), _) => Some(dt),
+            (None, true) => Some(Self::DETERMINISTIC_DT),
+            (None, false) => None,
+        }
+    }
+
+    /// Rapier timestep mode for these settings.
+    pub fn timestep_mode(&self) -> TimestepMode {
+        let substeps = self.substeps.max(1);
+        match self.effective_fixed_dt() {
+            Some(dt) => TimestepMode::Interpolated { dt, time_scale: 1.0, substeps },
+            None => TimestepMode::Variable { max_dt: Self::MAX_VARIABLE_DT, time_scale: 1.0, substeps },
+        }
+    }
+}
+
+/// Push `PhysicsTimestep` into Rapier's timestep mode and integration parameters.
+pub(super) fn apply_physics_timestep(
+    timestep: Res<PhysicsTimestep>,
+    mut mode: ResMut<TimestepMode>,
+    mut simulations: Query<&mut RapierContextSimulation>,
+) {
+    if !timestep.is_changed() {
+        return;
+    }
+    *mode = timestep.timestep_mode();
+    let dt = timestep.effective_fixed_dt().unwrap_or(PhysicsTimestep::MAX_VARIABLE_DT);
+    for mut simulation in simulations.iter_mut() {
+        simulation.integration_parameters.dt = dt / timestep.substeps.max(1) as f32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::engine_mode::EngineMode;
+    use crate::core::physics::{manage_physics_lifecycle, ColliderShape, PhysicsData, PhysicsEnabled, RigidBodyKind};
+
+    /// Tumble a cube onto a floor with the given timestep for `frames` frames
+    /// of `frame_dt` seconds each and return where it ends up.
+    fn drop_tumbling_cube(timestep: PhysicsTimestep, frame_dt: f64, frames: usize) -> (Vec3, Quat) {
+        use bevy::time::TimeUpdateStrategy;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), bevy::scene::ScenePlugin))
+            .init_asset::<Mesh>()
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+            .insert_resource(TimeUpdateStrategy::ManualDuration(std::time::Duration::from_secs_f64(frame_dt)))
+            .insert_resource(timestep)
+            .insert_resource(EngineMode::Play)
+            .add_systems(Update, (apply_physics_timestep, manage_physics_lifecycle).chain());
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.0, 0.0).with_scale(Vec3::new(10.0, 1.0, 10.0)),
+            PhysicsData { body_type: RigidBodyKind::Fixed, collider_shape: ColliderShape::Cuboid, ..Default::default() },
+            PhysicsEnabled,
+        ));
+        let cube = app.world_mut().spawn((
+            Transform::from_xyz(0.3, 3.0, -0.2).with_rotation(Quat::from_rotation_z(0.4)),
+            PhysicsData { collider_shape: ColliderShape::Cuboid, ..Default::default() },
+            PhysicsEnabled,
+            Velocity { linvel: Vec3::new(1.5, 0.0, 0.5), angvel: Vec3::new(0.0, 2.0, 3.0) },
+        )).id();
+
+        for _ in 0..frames {
+            app.update();
+        }
+        assert!(matches!(*app.world().resource::<TimestepMode>(), TimestepMode::Interpolated { .. }));
+        let transform = app.world().get::<Transform>(cube).expect("cube transform");
+        (transform.translation, transform.rotation)
+    }
+
+    #[test]
+    fn fixed_timestep_replays_identically() {
+        let timestep = PhysicsTimestep { fixed_dt: Some(1.0 / 60.0), substeps: 2, physics_determinism: true };
+        let (first_position, first_rotation) = drop_tumbling_cube(timestep, 1.0 / 60.0, 90);
+        let (second_position, second_rotation) = drop_tumbling_cube(timestep, 1.0 / 60.0, 90);
+        assert!(first_position.distance(second_position) < 1e-5, "{first_position} vs {second_position}");
+        assert!(first_rotation.abs_diff_eq(second_rotation, 1e-6), "{first_rotation} vs {second_rotation}");
+        assert!(first_position.y < 3.0, "cube should have fallen, ended at {first_position}");
+    }
+
+    #[test]
+    fn fixed_timestep_follows_real_time_not_frame_count() {
+        let timestep = PhysicsTimestep { fixed_dt: Some(1.0 / 60.0), substeps: 1, physics_determinism: true };
+        // 1.5 seconds either way: the slow run takes two steps per frame, so
+        // the runs end up at most a step apart rather than 0.75s apart
+        let (fast_position, _) = drop_tumbling_cube(timestep, 1.0 / 60.0, 90);
+        let (slow_position, _) = drop_tumbling_cube(timestep, 1.0 / 30.0, 45);
+        assert!(fast_position.distance(slow_position) < 0.05, "{fast_position} vs {slow_position}");
+    }
+
+    #[test]
+    fn determinism_flag_forces_a_fixed_step() {
+        let timestep = PhysicsTimestep { physics_determinism: true, ..Default::default() };
+        assert!(matches!(timestep.timestep_mode(), TimestepMode::Interpolated { dt, substeps: 1, .. } if dt == PhysicsTimestep::DETERMINISTIC_DT));
+        assert!(matches!(PhysicsTimestep::default().timestep_mode(), TimestepMode::Variable { .. }));
+    }
+}
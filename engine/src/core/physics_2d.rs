@@ -33,6 +33,10 @@ pub struct Physics2dData {
     pub continuous_detection: bool,
     /// Platform that only collides from above
     pub one_way_platform: bool,
+    /// Local-space side of a one-way platform that bodies can land on;
+    /// they pass through from the opposite side
+    #[serde(default = "default_one_way_normal")]
+    pub one_way_normal: [f32; 2],
     /// Conveyor belt velocity (static bodies only)
     pub surface_velocity: [f32; 2],
 }
@@ -53,11 +57,16 @@ impl Default for Physics2dData {
             lock_rotation: false,
             continuous_detection: false,
             one_way_platform: false,
+            one_way_normal: default_one_way_normal(),
             surface_velocity: [0.0, 0.0],
         }
     }
 }
 
+fn default_one_way_normal() -> [f32; 2] {
+    [0.0, 1.0]
+}
+
 /// Rigid body type
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum BodyType2d {
@@ -6,11 +6,14 @@
 //!
 //! Architecture mirrors `physics.rs` (3D) for consistency.
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
+use bevy_rapier2d::rapier::math::Vector;
 
 use super::engine_mode::EngineMode;
 use super::entity_id::EntityId;
+use super::physics::PhysicsTimestep;
 use super::physics_2d::{BodyType2d, ColliderShape2d, JointType2d, Physics2dData, Physics2dEnabled, PhysicsJoint2d};
 
 // ---------------------------------------------------------------------------
@@ -90,6 +93,41 @@ pub fn make_collider_2d(data: &Physics2dData) -> Collider {
     }
 }
 
+// ---------------------------------------------------------------------------
+// One-way platforms
+// ---------------------------------------------------------------------------
+
+/// How far, in radians, a contact normal may lean from a one-way platform's
+/// normal and still count as landing on it.
+const ONE_WAY_ALLOWED_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+
+/// Rapier hooks that drop contacts with one-way platforms unless the other
+/// body touches the platform's `one_way_normal` side.
+#[derive(SystemParam)]
+pub struct OneWayPlatformHooks<'w, 's> {
+    platforms: Query<'w, 's, &'static Physics2dData>,
+}
+
+impl OneWayPlatformHooks<'_, '_> {
+    fn platform_normal(&self, entity: Entity) -> Option<Vec2> {
+        let data = self.platforms.get(entity).ok().filter(|data| data.one_way_platform)?;
+        Some(Vec2::from(data.one_way_normal).normalize_or(Vec2::Y))
+    }
+}
+
+impl BevyPhysicsHooks for OneWayPlatformHooks<'_, '_> {
+    fn modify_solver_contacts(&self, context: ContactModificationContextView) {
+        // Rapier checks the normal in the first collider's space; when the
+        // platform is the second one the normal to allow is reversed.
+        let allowed = match (self.platform_normal(context.collider1()), self.platform_normal(context.collider2())) {
+            (Some(normal), _) => normal,
+            (None, Some(normal)) => -normal,
+            (None, None) => return,
+        };
+        context.raw.update_as_oneway_platform(&Vector::new(allowed.x, allowed.y), ONE_WAY_ALLOWED_ANGLE);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Lifecycle systems
 // ---------------------------------------------------------------------------
@@ -132,6 +170,7 @@ fn manage_physics2d_lifecycle(
 
             let mut ec = commands.entity(entity);
             ec.insert(rigid_body)
+              .insert(TransformInterpolation::default())
               .insert(collider)
               .insert(Restitution::coefficient(physics_data.restitution))
               .insert(Friction::coefficient(physics_data.friction))
@@ -147,6 +186,10 @@ fn manage_physics2d_lifecycle(
             if physics_data.continuous_detection {
                 ec.insert(Ccd::enabled());
             }
+
+            if physics_data.one_way_platform {
+                ec.insert(ActiveHooks::MODIFY_SOLVER_CONTACTS);
+            }
         }
         tracing::info!("Physics2D attached: {} entities", to_attach.iter().count());
     }
@@ -158,6 +201,7 @@ fn manage_physics2d_lifecycle(
         for entity in to_detach.iter() {
             commands.entity(entity)
                 .remove::<RigidBody>()
+                .remove::<TransformInterpolation>()
                 .remove::<Collider>()
                 .remove::<Velocity>()
                 .remove::<Restitution>()
@@ -170,6 +214,7 @@ fn manage_physics2d_lifecycle(
                 .remove::<ExternalForce>()
                 .remove::<ExternalImpulse>()
                 .remove::<ActiveEvents>()
+                .remove::<ActiveHooks>()
                 .remove::<ImpulseJoint>();
         }
         tracing::info!("Physics2D detached");
@@ -303,6 +348,36 @@ fn manage_joint2d_lifecycle(
     }
 }
 
+/// Mirror `PhysicsTimestep` onto the 2D Rapier world.
+fn apply_physics_timestep_2d(
+    timestep: Res<PhysicsTimestep>,
+    mut mode: ResMut<TimestepMode>,
+    mut simulations: Query<&mut RapierContextSimulation>,
+) {
+    if !timestep.is_changed() {
+        return;
+    }
+    let substeps = timestep.substeps.max(1);
+    *mode = match timestep.effective_fixed_dt() {
+        Some(dt) => TimestepMode::Interpolated { dt, time_scale: 1.0, substeps },
+        None => TimestepMode::Variable { max_dt: PhysicsTimestep::MAX_VARIABLE_DT, time_scale: 1.0, substeps },
+    };
+    let dt = timestep.effective_fixed_dt().unwrap_or(PhysicsTimestep::MAX_VARIABLE_DT);
+    for mut simulation in simulations.iter_mut() {
+        simulation.integration_parameters.dt = dt / substeps as f32;
+    }
+}
+
+/// Build the query filter for a 2D raycast, optionally restricted to
+/// colliders whose membership overlaps `filter_groups`.
+pub fn raycast_filter_2d(filter_groups: Option<u32>) -> QueryFilter<'static> {
+    match filter_groups {
+        Some(groups) => QueryFilter::default()
+            .groups(CollisionGroups::new(Group::ALL, Group::from_bits_retain(groups))),
+        None => QueryFilter::default(),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Plugin
 // ---------------------------------------------------------------------------
@@ -312,11 +387,13 @@ pub struct Physics2dPlugin;
 
 impl Plugin for Physics2dPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+        app.add_plugins(RapierPhysicsPlugin::<OneWayPlatformHooks>::default())
             .add_plugins(RapierDebugRenderPlugin::default())
             .init_resource::<Gravity2d>()
             .init_resource::<DebugPhysics2dEnabled>()
+            .init_resource::<PhysicsTimestep>()
             .add_systems(Update, (
+                apply_physics_timestep_2d,
                 manage_physics2d_lifecycle,
                 sync_debug_physics2d,
                 sync_gravity2d,
@@ -324,3 +401,63 @@ impl Plugin for Physics2dPlugin {
             ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bodies_jump_up_through_one_way_platforms_and_land_on_top() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), bevy::scene::ScenePlugin))
+            .init_asset::<Mesh>()
+            .add_plugins(RapierPhysicsPlugin::<OneWayPlatformHooks>::default())
+            .insert_resource(TimestepMode::Fixed { dt: 1.0 / 60.0, substeps: 1 })
+            .insert_resource(EngineMode::Play)
+            .init_resource::<Gravity2d>()
+            .add_systems(Update, (manage_physics2d_lifecycle, sync_gravity2d));
+
+        app.world_mut().spawn((
+            Transform::default(),
+            Physics2dData {
+                body_type: BodyType2d::Static,
+                size: [4.0, 0.2],
+                one_way_platform: true,
+                ..Default::default()
+            },
+            Physics2dEnabled,
+        ));
+        let jumper = app.world_mut().spawn((
+            Transform::from_xyz(0.0, -1.0, 0.0),
+            Physics2dData { size: [0.5, 0.5], lock_rotation: true, ..Default::default() },
+            Physics2dEnabled,
+            Velocity::linear(Vect::new(0.0, 8.0)),
+        )).id();
+
+        let mut highest = f32::NEG_INFINITY;
+        for _ in 0..180 {
+            app.update();
+            highest = highest.max(app.world().get::<Transform>(jumper).expect("jumper").translation.y);
+        }
+
+        assert!(highest > 1.0, "jumper should pass up through the platform, peaked at {highest}");
+        let resting = app.world().get::<Transform>(jumper).unwrap().translation.y;
+        assert!((resting - 0.35).abs() < 0.05, "jumper should land on top of the platform, ended at {resting}");
+    }
+
+    #[test]
+    fn physics_timestep_drives_the_2d_world() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default(), bevy::scene::ScenePlugin))
+            .init_asset::<Mesh>()
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+            .insert_resource(PhysicsTimestep { fixed_dt: Some(1.0 / 120.0), substeps: 2, physics_determinism: false })
+            .add_systems(Update, apply_physics_timestep_2d);
+        app.update();
+
+        assert!(matches!(
+            *app.world().resource::<TimestepMode>(),
+            TimestepMode::Interpolated { dt, substeps: 2, .. } if dt == 1.0 / 120.0
+        ));
+    }
+}
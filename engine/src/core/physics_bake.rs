@@ -0,0 +1,158 @@
+//! Physics baking: record a physics simulation as an animation clip.
+//!
+//! The scene's rigid bodies are copied into a standalone Rapier world that is
+//! stepped forward without touching the live ECS, so baking works from Edit
+//! mode and leaves every entity where it was. The chosen bodies' transforms
+//! are sampled once per frame and turned into translation and rotation
+//! curves, which play back the same way every time with physics off. Joints
+//! are not part of the baked world.
+
+use bevy::prelude::*;
+use bevy::animation::{
+    animated_field,
+    animation_curves::{AnimatableCurve, AnimatableKeyframeCurve},
+    AnimationClip, AnimationTargetId,
+};
+use bevy_rapier3d::rapier::math::Isometry;
+use bevy_rapier3d::rapier::prelude as rapier;
+use bevy_rapier3d::utils::iso_to_transform;
+
+use super::physics::{build_collision_groups, build_locked_axes, make_collider, to_rapier_body, PhysicsData};
+
+/// Clip name baked animations are registered under.
+pub const BAKED_CLIP_NAME: &str = "Physics Bake";
+
+/// Largest simulation step; lower frame rates are substepped down to it.
+const MAX_BAKE_DT: f32 = 1.0 / 60.0;
+
+/// A body taking part in the bake, with the collider it would get in Play.
+pub struct BakeBody {
+    pub data: PhysicsData,
+    pub transform: Transform,
+    pub collider: bevy_rapier3d::prelude::Collider,
+}
+
+impl BakeBody {
+    /// Body with the primitive collider `make_collider` picks for it.
+    pub fn new(data: PhysicsData, transform: Transform) -> Self {
+        let collider = make_collider(&data.collider_shape, transform.scale);
+        Self { data, transform, collider }
+    }
+}
+
+/// Number of frames a bake of `duration` seconds at `fps` records.
+pub fn bake_frame_count(duration: f32, fps: f32) -> usize {
+    ((duration * fps).round() as usize).max(1)
+}
+
+/// Simulate `bodies` for `duration` seconds and sample each one's transform
+/// at `fps`. Returns one list per body, holding `bake_frame_count + 1`
+/// samples starting at time zero. Scale is carried over unchanged.
+pub fn simulate_bake(bodies: &[BakeBody], gravity: Vec3, duration: f32, fps: f32) -> Vec<Vec<Transform>> {
+    let frame_dt = 1.0 / fps;
+    let substeps = (frame_dt / MAX_BAKE_DT).ceil().max(1.0) as usize;
+    let params = rapier::IntegrationParameters { dt: frame_dt / substeps as f32, ..Default::default() };
+
+    let mut rigid_bodies = rapier::RigidBodySet::new();
+    let mut colliders = rapier::ColliderSet::new();
+    let handles: Vec<_> = bodies.iter()
+        .map(|body| {
+            let data = &body.data;
+            let rb = rapier::RigidBodyBuilder::new(to_rapier_body(&data.body_type).into())
+                .pose(Isometry::from_parts(body.transform.translation.into(), body.transform.rotation.into()))
+                .gravity_scale(data.gravity_scale)
+                .locked_axes(build_locked_axes(data).into())
+                .ccd_enabled(data.ccd_enabled);
+            let handle = rigid_bodies.insert(rb);
+            let collider = rapier::ColliderBuilder::new(body.collider.raw.clone())
+                .friction(data.friction)
+                .restitution(data.restitution)
+                .density(data.density)
+                .sensor(data.is_sensor)
+                .collision_groups(build_collision_groups(data).into());
+            colliders.insert_with_parent(collider, handle, &mut rigid_bodies);
+            handle
+        })
+        .collect();
+
+    let mut pipeline = rapier::PhysicsPipeline::new();
+    let mut islands = rapier::IslandManager::new();
+    let mut broad_phase = rapier::DefaultBroadPhase::new();
+    let mut narrow_phase = rapier::NarrowPhase::new();
+    let mut impulse_joints = rapier::ImpulseJointSet::new();
+    let mut multibody_joints = rapier::MultibodyJointSet::new();
+    let mut ccd = rapier::CCDSolver::new();
+    let gravity = rapier::Vector::new(gravity.x, gravity.y, gravity.z);
+
+    let sample = |rigid_bodies: &rapier::RigidBodySet, samples: &mut [Vec<Transform>]| {
+        for ((handle, body), track) in handles.iter().zip(bodies).zip(samples.iter_mut()) {
+            let pose = iso_to_transform(rigid_bodies[*handle].position());
+            track.push(pose.with_scale(body.transform.scale));
+        }
+    };
+
+    let frames = bake_frame_count(duration, fps);
+    let mut samples = vec![Vec::with_capacity(frames + 1); bodies.len()];
+    sample(&rigid_bodies, &mut samples);
+    for _ in 0..frames {
+        for _ in 0..substeps {
+            pipeline.step(
+                &gravity,
+                &params,
+                &mut islands,
+                &mut broad_phase,
+                &mut narrow_phase,
+                &mut rigid_bodies,
+                &mut colliders,
+                &mut impulse_joints,
+                &mut multibody_joints,
+                &mut ccd,
+                &(),
+                &(),
+            );
+        }
+        sample(&rigid_bodies, &mut samples);
+    }
+    samples
+}
+
+/// Build a clip animating `target` through `samples` taken at `fps`, or
+/// `None` if there are fewer than two samples.
+pub fn baked_clip(target: AnimationTargetId, samples: &[Transform], fps: f32) -> Option<AnimationClip> {
+    let time = |i: usize| i as f32 / fps;
+    let translation = AnimatableKeyframeCurve::new(samples.iter().enumerate().map(|(i, t)| (time(i), t.translation))).ok()?;
+    let rotation = AnimatableKeyframeCurve::new(samples.iter().enumerate().map(|(i, t)| (time(i), t.rotation))).ok()?;
+
+    let mut clip = AnimationClip::default();
+    clip.add_curve_to_target(target, AnimatableCurve::new(animated_field!(Transform::translation), translation));
+    clip.add_curve_to_target(target, AnimatableCurve::new(animated_field!(Transform::rotation), rotation));
+    clip.set_duration(time(samples.len() - 1));
+    Some(clip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::physics::RigidBodyKind;
+
+    #[test]
+    fn baked_falling_body_descends_every_frame() {
+        let ground = BakeBody::new(
+            PhysicsData { body_type: RigidBodyKind::Fixed, ..Default::default() },
+            Transform::from_xyz(0.0, -50.0, 0.0).with_scale(Vec3::new(10.0, 1.0, 10.0)),
+        );
+        let ball = BakeBody::new(PhysicsData::default(), Transform::from_xyz(0.0, 10.0, 0.0));
+        let samples = simulate_bake(&[ground, ball], Vec3::new(0.0, -9.81, 0.0), 1.0, 30.0);
+
+        let falling = &samples[1];
+        assert_eq!(falling.len(), bake_frame_count(1.0, 30.0) + 1);
+        assert_eq!(falling[0].translation.y, 10.0);
+        assert!(falling.windows(2).all(|w| w[1].translation.y < w[0].translation.y), "{falling:?}");
+        assert!(samples[0].iter().all(|t| t.translation.y == -50.0), "fixed bodies stay put");
+
+        let target = AnimationTargetId::from_name(&Name::new("ball"));
+        let clip = baked_clip(target, falling, 30.0).expect("clip");
+        assert!((clip.duration() - 1.0).abs() < 1e-5);
+        assert!(clip.curves_for_target(target).is_some_and(|curves| curves.len() == 2));
+    }
+}
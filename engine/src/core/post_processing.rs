@@ -3,15 +3,32 @@
 //! Provides PostProcessingSettings -- a serializable resource that syncs
 //! to Bevy's camera components (Bloom, ChromaticAberration, ColorGrading,
 //! ContrastAdaptiveSharpening) via a system in bridge/mod.rs.
+//!
+//! Vignette has no built-in Bevy effect, so it is drawn by our own fullscreen
+//! pass (`VignetteEffect`) that runs after tonemapping.
+//!
+//! Depth of field can autofocus on an entity: while `autofocus_entity_id` is
+//! set, the focal distance follows the distance from the active camera to it.
 
 use bevy::prelude::*;
+use bevy::asset::uuid_handle;
+use bevy::core_pipeline::core_3d::graph::{Core3d, Node3d};
+use bevy::core_pipeline::fullscreen_material::{FullscreenMaterial, FullscreenMaterialPlugin};
 use bevy::post_process::bloom::{Bloom, BloomCompositeMode, BloomPrefilter};
 use bevy::anti_alias::contrast_adaptive_sharpening::ContrastAdaptiveSharpening;
 use bevy::post_process::effect_stack::ChromaticAberration;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_graph::{InternedRenderLabel, InternedRenderSubGraph, RenderLabel, RenderSubGraph};
+use bevy::render::render_resource::ShaderType;
 use bevy::render::view::{ColorGrading, ColorGradingGlobal, ColorGradingSection};
+use bevy::shader::{Shader, ShaderRef};
 use serde::{Serialize, Deserialize};
 
+use bevy::transform::TransformSystems;
+
 use super::camera::EditorCamera;
+use super::entity_id::EntityId;
+use super::game_camera::ActiveGameCamera;
 
 // SSAO import - WebGPU only
 #[cfg(feature = "webgpu")]
@@ -188,6 +205,9 @@ pub struct DepthOfFieldSettings {
     pub sensor_height: f32,
     pub max_circle_of_confusion_diameter: f32,
     pub max_depth: f32,
+    /// Entity to keep in focus; overrides `focal_distance` each frame.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autofocus_entity_id: Option<String>,
 }
 
 impl Default for DepthOfFieldSettings {
@@ -199,6 +219,7 @@ impl Default for DepthOfFieldSettings {
             sensor_height: 0.024,
             max_circle_of_confusion_diameter: 0.1,
             max_depth: 100.0,
+            autofocus_entity_id: None,
         }
     }
 }
@@ -220,6 +241,75 @@ impl Default for MotionBlurSettings {
     }
 }
 
+/// Serializable vignette configuration.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VignetteSettings {
+    pub intensity: f32,
+    /// Distance from the screen center (corners = 1.0) where darkening starts.
+    pub radius: f32,
+    /// Width of the falloff past `radius`.
+    pub smoothness: f32,
+    pub color: [f32; 4],
+}
+
+impl Default for VignetteSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 0.5,
+            radius: 0.5,
+            smoothness: 0.5,
+            color: [0.0, 0.0, 0.0, 1.0],
+        }
+    }
+}
+
+/// Stable handle for the vignette shader, registered via include_str! like
+/// the forge effects shader.
+const FORGE_VIGNETTE_SHADER_HANDLE: Handle<Shader> = uuid_handle!("f09eeffc-e750-4001-a000-000000000004");
+
+/// Camera component read by the vignette fullscreen pass. Field order matches
+/// the uniform in forge_vignette.wgsl.
+#[derive(Component, ExtractComponent, Clone, Copy, Debug, Default, PartialEq, ShaderType)]
+pub struct VignetteEffect {
+    pub color: Vec4,
+    pub intensity: f32,
+    pub radius: f32,
+    pub smoothness: f32,
+    /// Keeps the uniform 16-byte aligned for WebGL2.
+    pub padding: f32,
+}
+
+impl From<&VignetteSettings> for VignetteEffect {
+    fn from(settings: &VignetteSettings) -> Self {
+        Self {
+            color: Vec4::from_array(settings.color),
+            intensity: settings.intensity,
+            radius: settings.radius,
+            smoothness: settings.smoothness,
+            padding: 0.0,
+        }
+    }
+}
+
+impl FullscreenMaterial for VignetteEffect {
+    fn fragment_shader() -> ShaderRef {
+        FORGE_VIGNETTE_SHADER_HANDLE.into()
+    }
+
+    fn node_edges() -> Vec<InternedRenderLabel> {
+        vec![
+            Node3d::Tonemapping.intern(),
+            Self::node_label().intern(),
+            Node3d::EndMainPassPostProcessing.intern(),
+        ]
+    }
+
+    fn sub_graph() -> Option<InternedRenderSubGraph> {
+        Some(Core3d.intern())
+    }
+}
+
 /// Top-level post-processing resource that aggregates all effect settings.
 #[derive(Resource, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -234,6 +324,8 @@ pub struct PostProcessingSettings {
     pub depth_of_field: Option<DepthOfFieldSettings>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub motion_blur: Option<MotionBlurSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vignette: Option<VignetteSettings>,
 }
 
 impl Default for PostProcessingSettings {
@@ -246,6 +338,7 @@ impl Default for PostProcessingSettings {
             ssao: None,
             depth_of_field: None,
             motion_blur: None,
+            vignette: None,
         }
     }
 }
@@ -256,8 +349,53 @@ pub struct PostProcessingPlugin;
 
 impl Plugin for PostProcessingPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<PostProcessingSettings>()
-            .add_systems(Update, sync_post_processing_settings);
+        // Registered only when an asset server is present (not in headless tests).
+        if let Some(mut shaders) = app.world_mut().get_resource_mut::<Assets<Shader>>() {
+            if let Err(err) = shaders.insert(
+                FORGE_VIGNETTE_SHADER_HANDLE.id(),
+                Shader::from_wgsl(include_str!("../shaders/forge_vignette.wgsl"), "shaders/forge_vignette.wgsl"),
+            ) {
+                tracing::warn!("Failed to register forge vignette shader: {err}");
+            }
+        }
+
+        app.add_plugins(FullscreenMaterialPlugin::<VignetteEffect>::default())
+            .init_resource::<PostProcessingSettings>()
+            .add_systems(Update, sync_post_processing_settings)
+            .add_systems(PostUpdate, update_depth_of_field_autofocus.after(TransformSystems::Propagate));
+    }
+}
+
+/// Closest focal distance autofocus will set, so a subject at the lens
+/// doesn't produce a zero focal distance.
+pub const MIN_AUTOFOCUS_DISTANCE: f32 = 0.1;
+
+/// System that points depth of field at its autofocus entity, measuring from
+/// the active game camera (or the editor camera when none is active). A
+/// missing subject leaves the last focal distance in place.
+pub fn update_depth_of_field_autofocus(
+    mut settings: ResMut<PostProcessingSettings>,
+    game_cameras: Query<&GlobalTransform, With<ActiveGameCamera>>,
+    editor_cameras: Query<&GlobalTransform, With<EditorCamera>>,
+    subjects: Query<(&EntityId, &GlobalTransform)>,
+) {
+    let Some(target_id) = settings.depth_of_field.as_ref().and_then(|dof| dof.autofocus_entity_id.as_deref()) else {
+        return;
+    };
+    let Some(camera) = game_cameras.iter().next().or_else(|| editor_cameras.iter().next()) else {
+        return;
+    };
+    let Some((_, subject)) = subjects.iter().find(|(eid, _)| eid.0 == target_id) else {
+        return;
+    };
+
+    let distance = camera.translation().distance(subject.translation()).max(MIN_AUTOFOCUS_DISTANCE);
+    // Only write on a real change so a still subject doesn't re-trigger the settings sync
+    let current = settings.depth_of_field.as_ref().map(|dof| dof.focal_distance);
+    if current.is_some_and(|current| (current - distance).abs() > 1e-4) {
+        if let Some(dof) = settings.depth_of_field.as_mut() {
+            dof.focal_distance = distance;
+        }
     }
 }
 
@@ -330,6 +468,13 @@ fn sync_post_processing_settings(
         commands.entity(camera_entity).remove::<ContrastAdaptiveSharpening>();
     }
 
+    // Handle Vignette (removing an absent component is a no-op)
+    if let Some(vignette) = &settings.vignette {
+        commands.entity(camera_entity).insert(VignetteEffect::from(vignette));
+    } else {
+        commands.entity(camera_entity).remove::<VignetteEffect>();
+    }
+
     // Handle ColorGrading (modify in place, don't insert/remove)
     if let Ok(mut color_grading) = color_grading_query.single_mut() {
         if settings.color_grading.enabled {
@@ -405,3 +550,82 @@ fn sync_post_processing_settings(
     let _ = settings.depth_of_field;
     let _ = settings.motion_blur;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vignette_settings_serialize_camel_case() {
+        let settings = PostProcessingSettings {
+            vignette: Some(VignetteSettings { intensity: 0.8, ..Default::default() }),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&settings).unwrap();
+        assert_eq!(json["vignette"]["intensity"], serde_json::json!(0.8f32));
+        assert_eq!(json["vignette"]["color"], serde_json::json!([0.0, 0.0, 0.0, 1.0]));
+
+        // Disabled by default and omitted from the emitted state
+        let json = serde_json::to_value(PostProcessingSettings::default()).unwrap();
+        assert!(json.get("vignette").is_none());
+    }
+
+    #[test]
+    fn enabling_vignette_adds_the_effect_to_the_editor_camera() {
+        // The full plugin needs a renderer for the fullscreen material, so run
+        // just the sync system
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PostProcessingSettings>()
+            .add_systems(Update, sync_post_processing_settings);
+        let camera = app.world_mut().spawn(EditorCamera).id();
+        app.update();
+        assert!(app.world().get::<VignetteEffect>(camera).is_none());
+
+        app.world_mut().resource_mut::<PostProcessingSettings>().vignette = Some(VignetteSettings {
+            radius: 0.3,
+            color: [0.2, 0.0, 0.0, 1.0],
+            ..Default::default()
+        });
+        app.update();
+        let effect = *app.world().get::<VignetteEffect>(camera).unwrap();
+        assert_eq!(effect.radius, 0.3);
+        assert_eq!(effect.color, Vec4::new(0.2, 0.0, 0.0, 1.0));
+
+        app.world_mut().resource_mut::<PostProcessingSettings>().vignette = None;
+        app.update();
+        assert!(app.world().get::<VignetteEffect>(camera).is_none());
+    }
+
+    #[test]
+    fn autofocus_tracks_the_target_distance() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin))
+            .insert_resource(PostProcessingSettings {
+                depth_of_field: Some(DepthOfFieldSettings {
+                    autofocus_entity_id: Some("hero".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .add_systems(PostUpdate, update_depth_of_field_autofocus.after(TransformSystems::Propagate));
+
+        app.world_mut().spawn((EditorCamera, Transform::from_xyz(0.0, 2.0, 0.0)));
+        let hero = app.world_mut().spawn((EntityId("hero".to_string()), Transform::from_xyz(0.0, 2.0, -4.0))).id();
+
+        let focal_distance = |app: &App| app.world().resource::<PostProcessingSettings>().depth_of_field.as_ref().unwrap().focal_distance;
+        let mut distances = Vec::new();
+        for z in [-4.0, -8.0, -16.0, -32.0] {
+            app.world_mut().get_mut::<Transform>(hero).unwrap().translation.z = z;
+            app.update();
+            distances.push(focal_distance(&app));
+        }
+        assert!((distances[0] - 4.0).abs() < 1e-4, "{:?}", distances);
+        assert!(distances.windows(2).all(|pair| pair[1] > pair[0]), "{:?}", distances);
+
+        // Losing the subject keeps the last focal distance
+        app.world_mut().despawn(hero);
+        app.update();
+        assert_eq!(focal_distance(&app), distances[3]);
+    }
+}
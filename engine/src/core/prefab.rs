@@ -0,0 +1,352 @@
+//! Prefab instances: live links from spawned entities back to their prefab.
+//!
+//! Prefabs themselves are stored by the editor as a `PrefabSnapshot`: the
+//! root entity followed by its descendants. An entity spawned with a prefab id
+//! carries a `PrefabInstance`; when the prefab is updated, its root's
+//! material/light/physics data is re-applied to every instance, except for
+//! fields the user edited on that instance (its overrides).
+//!
+//! A `PrefabLibrary` bundles several prefabs with the metadata of the assets
+//! they reference, for moving prefabs between projects in one file.
+
+use std::collections::{BTreeSet, HashMap};
+
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::asset_manager::{AssetMetadata, AssetRegistry};
+use super::clipboard::referenced_assets;
+use super::entity_id::EntityId;
+use super::history::{EntitySnapshot, HistoryStack, UndoableAction};
+use super::lighting::LightData;
+use super::material::MaterialData;
+use super::pending_commands::PendingCommands;
+use super::physics::PhysicsData;
+use super::scene_file::{MAX_SCENE_JSON_BYTES, SCENE_FORMAT_VERSION};
+
+/// Serialized prefab: the root entity's snapshot first, then its descendants
+/// with parents before children.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrefabSnapshot {
+    pub format_version: u32,
+    pub entities: Vec<EntitySnapshot>,
+}
+
+impl PrefabSnapshot {
+    pub fn new(entities: Vec<EntitySnapshot>) -> Self {
+        Self { format_version: SCENE_FORMAT_VERSION, entities }
+    }
+
+    /// Parse prefab JSON. Older prefabs are a bare entity snapshot and come
+    /// back as a prefab of that one entity.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        if value.get("entities").is_some() {
+            serde_json::from_value(value)
+        } else {
+            Ok(Self::new(vec![serde_json::from_value(value)?]))
+        }
+    }
+}
+
+/// A prefab in a `PrefabLibrary`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryPrefab {
+    pub prefab_id: String,
+    pub name: String,
+    pub snapshot: PrefabSnapshot,
+    /// Keys into the library's `assets` that this prefab references.
+    #[serde(default)]
+    pub asset_ids: Vec<String>,
+}
+
+impl LibraryPrefab {
+    /// A prefab with a fresh id. Asset ids are filled in by `PrefabLibrary::new`.
+    pub fn new(name: impl Into<String>, snapshot: PrefabSnapshot) -> Self {
+        Self { prefab_id: uuid::Uuid::new_v4().to_string(), name: name.into(), snapshot, asset_ids: Vec::new() }
+    }
+}
+
+/// Serialized prefab library: several prefabs sharing one asset table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrefabLibrary {
+    pub format_version: u32,
+    pub prefabs: Vec<LibraryPrefab>,
+    #[serde(default)]
+    pub assets: HashMap<String, AssetMetadata>,
+}
+
+impl PrefabLibrary {
+    /// Bundle `prefabs` with the registry entries for every asset they
+    /// reference, recording each prefab's own references.
+    pub fn new(mut prefabs: Vec<LibraryPrefab>, registry: &AssetRegistry) -> Self {
+        let mut assets = HashMap::new();
+        for prefab in &mut prefabs {
+            let referenced = referenced_assets(&prefab.snapshot.entities, registry);
+            prefab.asset_ids = referenced.keys().cloned().collect();
+            prefab.asset_ids.sort();
+            assets.extend(referenced);
+        }
+        Self { format_version: SCENE_FORMAT_VERSION, prefabs, assets }
+    }
+}
+
+/// Component linking an entity to the prefab it was instantiated from.
+#[derive(Component, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrefabInstance {
+    pub prefab_id: String,
+    /// Locally edited fields as `"<component>.<field>"`, e.g. `"material.baseColor"`.
+    #[serde(default)]
+    pub overrides: BTreeSet<String>,
+}
+
+impl PrefabInstance {
+    pub fn new(prefab_id: impl Into<String>) -> Self {
+        Self { prefab_id: prefab_id.into(), overrides: BTreeSet::new() }
+    }
+
+    /// Record every top-level field that differs between `old` and `new` as
+    /// overridden under `component` (`"material"`, `"light"` or `"physics"`).
+    pub fn record_overrides<T: Serialize>(&mut self, component: &str, old: &T, new: &T) {
+        let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+            (serde_json::to_value(old), serde_json::to_value(new))
+        else {
+            return;
+        };
+        for (field, value) in &new {
+            if old.get(field) != Some(value) {
+                self.overrides.insert(format!("{component}.{field}"));
+            }
+        }
+    }
+
+    /// The prefab's value for `component`, keeping this instance's overridden
+    /// fields from `current`.
+    pub fn merge<T: Serialize + DeserializeOwned>(&self, component: &str, prefab: &T, current: &T) -> Result<T, serde_json::Error> {
+        let mut merged = serde_json::to_value(prefab)?;
+        let current = serde_json::to_value(current)?;
+        if let (Some(merged), Some(current)) = (merged.as_object_mut(), current.as_object()) {
+            let prefix = format!("{component}.");
+            for key in self.overrides.iter().filter_map(|o| o.strip_prefix(&prefix)) {
+                if let Some(value) = current.get(key) {
+                    merged.insert(key.to_string(), value.clone());
+                }
+            }
+        }
+        serde_json::from_value(merged)
+    }
+}
+
+/// Returns true if both values serialize identically.
+fn same<T: Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+/// Prefab instances with the component data a prefab update can change.
+type PrefabInstanceQuery<'w, 's> = Query<'w, 's, (
+    &'static EntityId,
+    &'static PrefabInstance,
+    Option<&'static mut MaterialData>,
+    Option<&'static mut LightData>,
+    Option<&'static mut PhysicsData>,
+)>;
+
+/// System that re-applies updated prefabs to their instances.
+///
+/// All instance changes from one update are recorded as a single history step.
+pub fn apply_prefab_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut history: ResMut<HistoryStack>,
+    mut instances: PrefabInstanceQuery,
+) {
+    for request in pending.prefab_update_requests.drain(..) {
+        let prefab = &request.snapshot;
+        let mut changes = Vec::new();
+        let mut updated_ids = Vec::new();
+
+        for (entity_id, instance, material, light, physics) in instances.iter_mut() {
+            if instance.prefab_id != request.prefab_id {
+                continue;
+            }
+            let changes_before = changes.len();
+
+            if let (Some(prefab_mat), Some(mut mat)) = (&prefab.material_data, material) {
+                match instance.merge("material", prefab_mat, &mat) {
+                    Ok(merged) if !same(&merged, &*mat) => {
+                        changes.push(UndoableAction::MaterialChange {
+                            entity_id: entity_id.0.clone(),
+                            old_material: mat.clone(),
+                            new_material: merged.clone(),
+                        });
+                        *mat = merged;
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::error!("Failed to merge prefab material into {}: {}", entity_id.0, e),
+                }
+            }
+            if let (Some(prefab_light), Some(mut current)) = (&prefab.light_data, light) {
+                match instance.merge("light", prefab_light, &current) {
+                    Ok(mut merged) => {
+                        // An instance keeps its own light type, as with regular light updates
+                        merged.light_type = current.light_type.clone();
+                        if !same(&merged, &*current) {
+                            changes.push(UndoableAction::LightChange {
+                                entity_id: entity_id.0.clone(),
+                                old_light: current.clone(),
+                                new_light: merged.clone(),
+                            });
+                            *current = merged;
+                        }
+                    }
+                    Err(e) => tracing::error!("Failed to merge prefab light into {}: {}", entity_id.0, e),
+                }
+            }
+            if let (Some(prefab_physics), Some(mut current)) = (&prefab.physics_data, physics) {
+                match instance.merge("physics", prefab_physics, &current) {
+                    Ok(merged) if !same(&merged, &*current) => {
+                        changes.push(UndoableAction::PhysicsChange {
+                            entity_id: entity_id.0.clone(),
+                            old_physics: current.clone(),
+                            new_physics: merged.clone(),
+                        });
+                        *current = merged;
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::error!("Failed to merge prefab physics into {}: {}", entity_id.0, e),
+                }
+            }
+
+            if changes.len() > changes_before {
+                updated_ids.push(entity_id.0.clone());
+            }
+        }
+
+        if !changes.is_empty() {
+            history.push(UndoableAction::Transaction {
+                label: format!("Update prefab ({} instances)", updated_ids.len()),
+                actions: changes,
+            });
+        }
+
+        tracing::info!("Prefab '{}' updated {} instances", request.prefab_id, updated_ids.len());
+        #[cfg(target_arch = "wasm32")]
+        crate::bridge::events::emit_prefab_instances_updated(&request.prefab_id, &updated_ids);
+    }
+}
+
+/// System that imports prefab libraries. Their assets are added to the
+/// registry (existing entries win) and the prefabs are handed to the editor,
+/// which stores them for `instantiate_prefab`.
+pub fn apply_prefab_library_imports(
+    mut pending: ResMut<PendingCommands>,
+    mut asset_registry: ResMut<AssetRegistry>,
+) {
+    for request in pending.import_prefab_library_requests.drain(..) {
+        if request.json.len() > MAX_SCENE_JSON_BYTES {
+            tracing::error!("Prefab library rejected: JSON payload {} bytes exceeds 50MB limit", request.json.len());
+            continue;
+        }
+        let library: PrefabLibrary = match serde_json::from_str(&request.json) {
+            Ok(library) => library,
+            Err(e) => {
+                tracing::error!("Failed to deserialize prefab library: {}", e);
+                continue;
+            }
+        };
+        if library.format_version > SCENE_FORMAT_VERSION {
+            tracing::error!("Unsupported prefab library format version: {}", library.format_version);
+            continue;
+        }
+
+        for (asset_id, metadata) in library.assets {
+            asset_registry.assets.entry(asset_id).or_insert(metadata);
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let mut prefabs = Vec::with_capacity(library.prefabs.len());
+            for prefab in &library.prefabs {
+                match serde_json::to_string(&prefab.snapshot) {
+                    Ok(json) => prefabs.push((prefab.prefab_id.clone(), prefab.name.clone(), json)),
+                    Err(e) => tracing::error!("Failed to serialize prefab '{}': {}", prefab.name, e),
+                }
+            }
+            crate::bridge::events::emit_prefab_library_imported(&prefabs);
+        }
+        tracing::info!("Imported prefab library ({} prefabs)", library.prefabs.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::history::{EntitySnapshot, TransformSnapshot};
+    use crate::core::pending_commands::{EntityType, PrefabUpdateRequest};
+
+    fn material(color: [f32; 4], metallic: f32) -> MaterialData {
+        MaterialData { base_color: color, metallic, ..Default::default() }
+    }
+
+    #[test]
+    fn record_overrides_tracks_changed_fields_only() {
+        let mut instance = PrefabInstance::new("crate");
+        instance.record_overrides("material", &material([1.0; 4], 0.0), &material([1.0, 0.0, 0.0, 1.0], 0.0));
+        assert_eq!(instance.overrides.iter().collect::<Vec<_>>(), vec!["material.baseColor"]);
+    }
+
+    #[test]
+    fn updating_prefab_color_skips_instances_that_override_it() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .add_systems(Update, apply_prefab_updates);
+
+        let white = [1.0, 1.0, 1.0, 1.0];
+        let green = [0.0, 1.0, 0.0, 1.0];
+        let red = [1.0, 0.0, 0.0, 1.0];
+
+        let mut overridden = PrefabInstance::new("crate");
+        overridden.record_overrides("material", &material(white, 0.0), &material(green, 0.0));
+        let instances = [
+            ("a", PrefabInstance::new("crate"), material(white, 0.0)),
+            ("b", PrefabInstance::new("crate"), material(white, 0.0)),
+            ("c", overridden, material(green, 0.0)),
+        ]
+        .map(|(id, instance, mat)| app.world_mut().spawn((EntityId(id.to_string()), instance, mat)).id());
+
+        let mut snapshot = EntitySnapshot::new(
+            "prefab-root".to_string(),
+            EntityType::Cube,
+            "Crate".to_string(),
+            TransformSnapshot::from(&Transform::IDENTITY),
+        );
+        snapshot.material_data = Some(material(red, 0.5));
+        app.world_mut().resource_mut::<PendingCommands>().prefab_update_requests.push(PrefabUpdateRequest {
+            prefab_id: "crate".to_string(),
+            snapshot: Box::new(snapshot),
+        });
+        app.update();
+
+        let mat = |e: Entity| app.world().get::<MaterialData>(e).unwrap().clone();
+        assert_eq!(mat(instances[0]).base_color, red);
+        assert_eq!(mat(instances[1]).base_color, red);
+        assert_eq!(mat(instances[2]).base_color, green);
+        // Non-overridden fields still follow the prefab
+        assert_eq!(mat(instances[2]).metallic, 0.5);
+        assert_eq!(
+            app.world().resource::<HistoryStack>().undo_description().as_deref(),
+            Some("Update prefab (3 instances)")
+        );
+    }
+
+    #[test]
+    fn merge_reports_values_that_do_not_round_trip() {
+        // NaN serializes to null, which can't be read back as an f32
+        assert!(PrefabInstance::new("crate").merge("scale", &f32::NAN, &1.0).is_err());
+    }
+}
@@ -1,8 +1,10 @@
-//! Procedural mesh generation: extrude, lathe, and combine operations.
+//! Procedural mesh generation: extrude, lathe, loft, sweep, and combine operations.
 //!
 //! This module provides tools for generating meshes programmatically:
 //! - Extrude: Create 3D geometry by extruding a 2D cross-section along a linear path
 //! - Lathe: Create rotational geometry by revolving a 2D profile around Y-axis
+//! - Loft: Skin a surface through a sequence of 2D profiles stacked along Y
+//! - Sweep: Move a 2D profile along an arbitrary 3D path
 //! - Combine: Merge multiple meshes into a single mesh
 
 use bevy::prelude::*;
@@ -20,13 +22,45 @@ pub struct ProceduralMeshData {
     pub operation: ProceduralOp,
 }
 
+impl ProceduralMeshData {
+    /// Capture the attributes of a generated mesh for snapshots.
+    /// Returns None if the mesh is missing positions, normals, UVs, or indices.
+    pub fn from_mesh(mesh: &Mesh, operation: ProceduralOp) -> Option<Self> {
+        use bevy::mesh::VertexAttributeValues;
+
+        let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+            VertexAttributeValues::Float32x3(v) => v.clone(),
+            _ => return None,
+        };
+        let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL)? {
+            VertexAttributeValues::Float32x3(v) => v.clone(),
+            _ => return None,
+        };
+        let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0)? {
+            VertexAttributeValues::Float32x2(v) => v.clone(),
+            _ => return None,
+        };
+        let indices = match mesh.indices()? {
+            Indices::U32(v) => v.clone(),
+            Indices::U16(v) => v.iter().map(|i| *i as u32).collect(),
+        };
+
+        Some(Self { positions, normals, uvs, indices, operation })
+    }
+}
+
 /// Type of procedural operation that created this mesh.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ProceduralOp {
     Extrude { shape: ExtrudeShape, length: f32, segments: u32 },
     Lathe { profile: Vec<[f32; 2]>, segments: u32 },
+    Loft { profiles: Vec<Vec<[f32; 2]>>, closed: bool },
+    Sweep { profile: Vec<[f32; 2]>, path: Vec<[f32; 3]>, segments: u32 },
+    Subdivide { iterations: u32 },
     Combine,
+    /// Primitive whose transform was baked into its vertices.
+    ApplyTransform,
 }
 
 /// Cross-section shape for extrusion.
@@ -308,6 +342,191 @@ pub fn generate_lathe_mesh(profile: &[[f32; 2]], segments: u32) -> Mesh {
     mesh
 }
 
+/// Generate a lofted mesh through a sequence of 2D profiles.
+/// Each profile is a Vec of [x, z] points; profile `k` is placed at Y = k, so
+/// consecutive profiles are one unit apart. Profiles with fewer points are
+/// resampled by arc length to match the largest one, which lets differently
+/// shaped sections (e.g. a square and a circle) be blended. When `closed` is
+/// set, the last point of each profile connects back to the first.
+pub fn generate_loft_mesh(profiles: &[Vec<[f32; 2]>], closed: bool) -> Mesh {
+    let min_points = if closed { 3 } else { 2 };
+    if profiles.len() < 2 || profiles.iter().any(|p| p.len() < min_points) {
+        // Degenerate case: return an empty mesh
+        return Mesh::new(
+            PrimitiveTopology::TriangleList,
+            bevy::asset::RenderAssetUsages::default(),
+        );
+    }
+
+    let ring_len = profiles.iter().map(|p| p.len()).max().unwrap_or(0);
+    let vert_count = ring_len * profiles.len();
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity(vert_count);
+    let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(vert_count);
+
+    let u_div = if closed { ring_len } else { ring_len - 1 } as f32;
+    let v_div = (profiles.len() - 1) as f32;
+
+    for (k, profile) in profiles.iter().enumerate() {
+        let points: Vec<Vec3> = profile.iter().map(|&[x, z]| Vec3::new(x, 0.0, z)).collect();
+        let ring = if points.len() == ring_len {
+            points
+        } else {
+            resample_polyline(&points, ring_len, closed)
+        };
+
+        let y = k as f32;
+        for (i, p) in ring.iter().enumerate() {
+            positions.push([p.x, y, p.z]);
+            uvs.push([i as f32 / u_div, k as f32 / v_div]);
+        }
+    }
+
+    let indices = ring_strip_indices(profiles.len(), ring_len, closed);
+    let normals = compute_smooth_normals(&positions, &indices);
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        bevy::asset::RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+/// Generate a swept mesh by moving a closed 2D profile along a 3D path.
+/// `profile` is a Vec of [x, y] points in the plane perpendicular to the path.
+/// `path` is resampled by arc length into `segments` spans, and the profile
+/// frame is carried along it by parallel transport so it does not twist.
+pub fn generate_sweep_mesh(profile: &[[f32; 2]], path: &[[f32; 3]], segments: u32) -> Mesh {
+    let path_points: Vec<Vec3> = path.iter().map(|&p| Vec3::from(p)).collect();
+    let path_length: f32 = path_points.windows(2).map(|w| w[0].distance(w[1])).sum();
+    if profile.len() < 3 || path_points.len() < 2 || segments == 0 || path_length <= 0.0 {
+        // Degenerate case: return an empty mesh
+        return Mesh::new(
+            PrimitiveTopology::TriangleList,
+            bevy::asset::RenderAssetUsages::default(),
+        );
+    }
+
+    let rings = resample_polyline(&path_points, segments as usize + 1, false);
+    let ring_len = profile.len();
+    let vert_count = ring_len * rings.len();
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity(vert_count);
+    let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(vert_count);
+
+    // Tangent at each ring from neighbouring rings; reuse the previous one
+    // where the path doubles back on itself.
+    let mut tangents: Vec<Vec3> = Vec::with_capacity(rings.len());
+    for j in 0..rings.len() {
+        let prev = rings[j.saturating_sub(1)];
+        let next = rings[(j + 1).min(rings.len() - 1)];
+        let tangent = (next - prev).normalize_or_zero();
+        let fallback = tangents.last().copied().unwrap_or(Vec3::Y);
+        tangents.push(if tangent == Vec3::ZERO { fallback } else { tangent });
+    }
+
+    // Initial frame matches extrude along +Y: profile x -> world X, profile y -> world Z.
+    let reference = if tangents[0].dot(Vec3::Z).abs() < 0.99 { Vec3::Z } else { Vec3::X };
+    let mut frame_x = tangents[0].cross(reference).normalize();
+
+    for (j, (&center, &tangent)) in rings.iter().zip(tangents.iter()).enumerate() {
+        if j > 0 {
+            frame_x = Quat::from_rotation_arc(tangents[j - 1], tangent) * frame_x;
+        }
+        let frame_y = frame_x.cross(tangent);
+        let v = j as f32 / segments as f32;
+
+        for (i, &[x, y]) in profile.iter().enumerate() {
+            let p = center + frame_x * x + frame_y * y;
+            positions.push([p.x, p.y, p.z]);
+            uvs.push([i as f32 / ring_len as f32, v]);
+        }
+    }
+
+    let indices = ring_strip_indices(rings.len(), ring_len, true);
+    let normals = compute_smooth_normals(&positions, &indices);
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        bevy::asset::RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+/// Resample a polyline to `count` points evenly spaced by arc length.
+/// A closed polyline includes the segment from the last point back to the first.
+fn resample_polyline(points: &[Vec3], count: usize, closed: bool) -> Vec<Vec3> {
+    let seg_count = if closed { points.len() } else { points.len() - 1 };
+    let seg_lengths: Vec<f32> = (0..seg_count)
+        .map(|i| points[i].distance(points[(i + 1) % points.len()]))
+        .collect();
+    let total: f32 = seg_lengths.iter().sum();
+    if total <= 0.0 || count < 2 {
+        return vec![points[0]; count];
+    }
+
+    let step = total / if closed { count } else { count - 1 } as f32;
+    let mut resampled = Vec::with_capacity(count);
+    let mut seg = 0;
+    let mut seg_start = 0.0;
+    for i in 0..count {
+        let target = step * i as f32;
+        while seg < seg_count - 1 && seg_start + seg_lengths[seg] < target {
+            seg_start += seg_lengths[seg];
+            seg += 1;
+        }
+        let t = if seg_lengths[seg] > 0.0 {
+            ((target - seg_start) / seg_lengths[seg]).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        resampled.push(points[seg].lerp(points[(seg + 1) % points.len()], t));
+    }
+    resampled
+}
+
+/// Triangle indices joining consecutive rings of `ring_len` vertices.
+/// Winding matches `generate_extrude_mesh`, so counter-clockwise rings face outward.
+fn ring_strip_indices(ring_count: usize, ring_len: usize, closed: bool) -> Vec<u32> {
+    let quads_per_ring = if closed { ring_len } else { ring_len - 1 };
+    let mut indices: Vec<u32> = Vec::with_capacity((ring_count - 1) * quads_per_ring * 6);
+    for k in 0..ring_count - 1 {
+        for i in 0..quads_per_ring {
+            let next_i = (i + 1) % ring_len;
+            let curr = (k * ring_len + i) as u32;
+            let next = (k * ring_len + next_i) as u32;
+            let up_curr = ((k + 1) * ring_len + i) as u32;
+            let up_next = ((k + 1) * ring_len + next_i) as u32;
+
+            indices.extend_from_slice(&[curr, up_curr, next, next, up_curr, up_next]);
+        }
+    }
+    indices
+}
+
+/// Smooth vertex normals from area-weighted face normals.
+fn compute_smooth_normals(positions: &[[f32; 3]], indices: &[u32]) -> Vec<[f32; 3]> {
+    let mut accum = vec![Vec3::ZERO; positions.len()];
+    for tri in indices.chunks_exact(3) {
+        let (a, b, c) = (tri[0] as usize, tri[1] as usize, tri[2] as usize);
+        let pa = Vec3::from(positions[a]);
+        let face = (Vec3::from(positions[b]) - pa).cross(Vec3::from(positions[c]) - pa);
+        accum[a] += face;
+        accum[b] += face;
+        accum[c] += face;
+    }
+    accum
+        .into_iter()
+        .map(|n| n.try_normalize().unwrap_or(Vec3::Y).to_array())
+        .collect()
+}
+
 /// Combine multiple meshes into a single mesh.
 /// Each mesh is provided as (positions, normals, indices, transform).
 /// The positions and normals are transformed to world space before merging.
@@ -344,6 +563,80 @@ pub fn combine_meshes_data(
     (combined_positions, combined_normals, combined_indices)
 }
 
+/// Compute the copy offsets for an array request, relative to the source.
+/// The source position itself (offset zero) is excluded.
+pub fn array_offsets(request: &crate::core::pending::ArrayRequest) -> Result<Vec<Vec3>, String> {
+    let mut offsets: Vec<Vec3> = Vec::new();
+    match request.pattern.as_str() {
+        "grid" => {
+            let count_x = request.count_x.unwrap_or(2).max(1);
+            let count_y = request.count_y.unwrap_or(1).max(1);
+            let count_z = request.count_z.unwrap_or(2).max(1);
+            let spacing_x = request.spacing_x.unwrap_or(2.0);
+            let spacing_y = request.spacing_y.unwrap_or(2.0);
+            let spacing_z = request.spacing_z.unwrap_or(2.0);
+
+            for x in 0..count_x {
+                for y in 0..count_y {
+                    for z in 0..count_z {
+                        if x == 0 && y == 0 && z == 0 {
+                            continue;
+                        }
+                        offsets.push(Vec3::new(
+                            x as f32 * spacing_x,
+                            y as f32 * spacing_y,
+                            z as f32 * spacing_z,
+                        ));
+                    }
+                }
+            }
+        }
+        "circle" => {
+            let count = request.circle_count.unwrap_or(8).max(2);
+            let radius = request.circle_radius.unwrap_or(5.0);
+            for i in 1..count {
+                let angle = (i as f32) * std::f32::consts::TAU / (count as f32);
+                offsets.push(Vec3::new(radius * angle.cos(), 0.0, radius * angle.sin()));
+            }
+        }
+        _ => return Err(format!("Unknown array pattern: {}", request.pattern)),
+    }
+    Ok(offsets)
+}
+
+/// Compute the transform of every clone in a clone_pattern request.
+/// Step `i` (1-based) adds `i * rotate_each` to the source rotation; radial
+/// clones with `look_at_center` instead face the source pivot.
+pub fn clone_pattern_transforms(
+    source: &Transform,
+    request: &crate::core::pending::ClonePatternRequest,
+) -> Vec<Transform> {
+    use crate::core::pending::ClonePattern;
+
+    let step_rotation = |step: f32| {
+        let r = request.rotate_each * step;
+        Quat::from_euler(EulerRot::XYZ, r.x.to_radians(), r.y.to_radians(), r.z.to_radians())
+    };
+
+    (1..=request.count)
+        .map(|i| {
+            let translation = match request.pattern {
+                ClonePattern::Linear { offset } => source.translation + offset * i as f32,
+                ClonePattern::Radial { radius } => {
+                    let angle = (i - 1) as f32 * std::f32::consts::TAU / request.count as f32;
+                    source.translation + Vec3::new(angle.cos(), 0.0, angle.sin()) * radius
+                }
+            };
+            let clone = Transform { translation, rotation: step_rotation(i as f32) * source.rotation, scale: source.scale };
+            if request.look_at_center && matches!(request.pattern, ClonePattern::Radial { .. }) {
+                clone.looking_at(source.translation, Vec3::Y)
+            } else {
+                clone
+            }
+        })
+        .collect()
+}
+
 /// Rebuild a Bevy Mesh from stored ProceduralMeshData (for undo/redo/save-load).
 pub fn rebuild_procedural_mesh(data: &ProceduralMeshData) -> Mesh {
     let mut mesh = Mesh::new(
@@ -356,3 +649,121 @@ pub fn rebuild_procedural_mesh(data: &ProceduralMeshData) -> Mesh {
     mesh.insert_indices(Indices::U32(data.indices.clone()));
     mesh
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square_profile(size: f32) -> Vec<[f32; 2]> {
+        generate_cross_section(&ExtrudeShape::Square { size })
+    }
+
+    #[test]
+    fn square_to_circle_loft_resamples_to_common_ring() {
+        let square = square_profile(2.0);
+        let circle = generate_cross_section(&ExtrudeShape::Circle { radius: 1.0, segments: 16 });
+        let mesh = generate_loft_mesh(&[square, circle], true);
+
+        let data = ProceduralMeshData::from_mesh(&mesh, ProceduralOp::Loft { profiles: vec![], closed: true })
+            .expect("loft mesh should have all attributes");
+        // Square resampled up to 16 points, two rings, closed loop of 16 quads.
+        assert_eq!(data.positions.len(), 32);
+        assert_eq!(data.normals.len(), 32);
+        assert_eq!(data.uvs.len(), 32);
+        assert_eq!(data.indices.len(), 16 * 6);
+
+        // Resampled square points stay on the square perimeter.
+        for p in &data.positions[..16] {
+            assert_eq!(p[1], 0.0);
+            let on_edge = (p[0].abs() - 1.0).abs() < 1e-4 || (p[2].abs() - 1.0).abs() < 1e-4;
+            assert!(on_edge, "{:?} not on square", p);
+        }
+        // Circle ring sits one unit up.
+        assert!(data.positions[16..].iter().all(|p| (p[1] - 1.0).abs() < 1e-6));
+
+        // Normals face away from the loft axis.
+        for (p, n) in data.positions.iter().zip(data.normals.iter()) {
+            assert!(p[0] * n[0] + p[2] * n[2] > 0.0, "inward normal {:?} at {:?}", n, p);
+        }
+    }
+
+    #[test]
+    fn open_loft_has_one_less_quad_per_ring() {
+        let profiles = vec![vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]; 3];
+        let mesh = generate_loft_mesh(&profiles, false);
+        assert_eq!(mesh.count_vertices(), 9);
+        assert_eq!(mesh.indices().map(|i| i.len()), Some(2 * 2 * 6));
+    }
+
+    #[test]
+    fn straight_line_sweep_matches_extrude_layout() {
+        let profile = square_profile(1.0);
+        let mesh = generate_sweep_mesh(&profile, &[[0.0, 0.0, 0.0], [0.0, 2.0, 0.0]], 4);
+        let data = ProceduralMeshData::from_mesh(
+            &mesh,
+            ProceduralOp::Sweep { profile: profile.clone(), path: vec![], segments: 4 },
+        )
+        .expect("sweep mesh should have all attributes");
+
+        // 5 rings of 4 points, 4 spans of 4 quads.
+        assert_eq!(data.positions.len(), 20);
+        assert_eq!(data.indices.len(), 4 * 4 * 6);
+
+        // Along +Y the profile maps to XZ exactly as extrude does.
+        for (j, ring) in data.positions.chunks(4).enumerate() {
+            for (p, [x, z]) in ring.iter().zip(profile.iter()) {
+                assert!((p[0] - x).abs() < 1e-5);
+                assert!((p[1] - j as f32 * 0.5).abs() < 1e-5);
+                assert!((p[2] - z).abs() < 1e-5);
+            }
+        }
+        for (p, n) in data.positions.iter().zip(data.normals.iter()) {
+            assert!(p[0] * n[0] + p[2] * n[2] > 0.0, "inward normal {:?} at {:?}", n, p);
+        }
+    }
+
+    #[test]
+    fn degenerate_sweep_is_empty() {
+        let profile = square_profile(1.0);
+        let mesh = generate_sweep_mesh(&profile, &[[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]], 4);
+        assert_eq!(mesh.count_vertices(), 0);
+    }
+
+    fn clone_request(pattern: crate::core::pending::ClonePattern, count: u32) -> crate::core::pending::ClonePatternRequest {
+        crate::core::pending::ClonePatternRequest {
+            entity_id: "src".to_string(),
+            pattern,
+            count,
+            rotate_each: Vec3::ZERO,
+            look_at_center: false,
+        }
+    }
+
+    #[test]
+    fn radial_clones_look_at_center() {
+        let source = Transform::from_xyz(1.0, 2.0, 3.0);
+        let mut request = clone_request(crate::core::pending::ClonePattern::Radial { radius: 4.0 }, 4);
+        request.look_at_center = true;
+
+        let clones = clone_pattern_transforms(&source, &request);
+        assert_eq!(clones.len(), 4);
+        for clone in &clones {
+            assert!((clone.translation.distance(source.translation) - 4.0).abs() < 1e-4);
+            let to_center = (source.translation - clone.translation).normalize();
+            assert!(clone.forward().dot(to_center) > 0.9999, "clone at {} faces {:?}", clone.translation, clone.forward());
+        }
+    }
+
+    #[test]
+    fn linear_clones_accumulate_rotation() {
+        let source = Transform::IDENTITY;
+        let mut request = clone_request(crate::core::pending::ClonePattern::Linear { offset: Vec3::X }, 3);
+        request.rotate_each = Vec3::new(0.0, 90.0, 0.0);
+
+        let clones = clone_pattern_transforms(&source, &request);
+        assert_eq!(clones[2].translation, Vec3::new(3.0, 0.0, 0.0));
+        let expected = Quat::from_rotation_y(270f32.to_radians());
+        // q and -q are the same rotation, and angle_between is imprecise near zero
+        assert!(clones[2].rotation.dot(expected).abs() > 1.0 - 1e-6, "got {:?}", clones[2].rotation);
+    }
+}
@@ -3,6 +3,7 @@
 //! Provides QualitySettings resource with Low/Medium/High/Ultra presets
 //! that control MSAA, shadows, post-processing, and particle density.
 
+use bevy::light::{CascadeShadowConfig, CascadeShadowConfigBuilder, DirectionalLightShadowMap};
 use bevy::prelude::*;
 use serde::{Serialize, Deserialize};
 
@@ -31,6 +32,9 @@ pub struct QualitySettings {
     pub msaa_samples: u8,
     pub shadows_enabled: bool,
     pub shadows_directional_only: bool,
+    /// Width and height of each directional shadow cascade (power of two).
+    pub shadow_map_size: u32,
+    pub directional_cascade_count: u32,
     pub bloom_enabled: bool,
     pub chromatic_aberration_enabled: bool,
     pub sharpening_enabled: bool,
@@ -52,6 +56,8 @@ impl QualitySettings {
                 msaa_samples: 1,
                 shadows_enabled: false,
                 shadows_directional_only: false,
+                shadow_map_size: 512,
+                directional_cascade_count: 1,
                 bloom_enabled: false,
                 chromatic_aberration_enabled: false,
                 sharpening_enabled: false,
@@ -62,6 +68,8 @@ impl QualitySettings {
                 msaa_samples: 2,
                 shadows_enabled: true,
                 shadows_directional_only: true,
+                shadow_map_size: 1024,
+                directional_cascade_count: 2,
                 bloom_enabled: true,
                 chromatic_aberration_enabled: false,
                 sharpening_enabled: false,
@@ -72,6 +80,8 @@ impl QualitySettings {
                 msaa_samples: 4,
                 shadows_enabled: true,
                 shadows_directional_only: false,
+                shadow_map_size: 2048,
+                directional_cascade_count: 4,
                 bloom_enabled: true,
                 chromatic_aberration_enabled: true,
                 sharpening_enabled: true,
@@ -82,6 +92,8 @@ impl QualitySettings {
                 msaa_samples: 4,
                 shadows_enabled: true,
                 shadows_directional_only: false,
+                shadow_map_size: 4096,
+                directional_cascade_count: 4,
                 bloom_enabled: true,
                 chromatic_aberration_enabled: true,
                 sharpening_enabled: true,
@@ -101,4 +113,62 @@ impl QualitySettings {
             _ => None,
         }
     }
+
+    /// Cascade layout for directional lights at this quality level.
+    pub fn cascade_shadow_config(&self) -> CascadeShadowConfig {
+        CascadeShadowConfigBuilder {
+            num_cascades: self.directional_cascade_count.max(1) as usize,
+            ..default()
+        }
+        .build()
+    }
+}
+
+/// System that pushes the preset's shadow resolution and cascade count to
+/// the renderer. Runs on preset change and for newly spawned directional lights.
+pub fn apply_quality_shadows(
+    quality: Res<QualitySettings>,
+    mut shadow_map: ResMut<DirectionalLightShadowMap>,
+    mut lights: Query<(&mut CascadeShadowConfig, Ref<DirectionalLight>)>,
+) {
+    if quality.is_changed() {
+        let size = quality.shadow_map_size.max(1).next_power_of_two() as usize;
+        if shadow_map.size != size {
+            shadow_map.size = size;
+        }
+    }
+    for (mut config, light) in lights.iter_mut() {
+        if quality.is_changed() || light.is_added() {
+            *config = quality.cascade_shadow_config();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(app: &mut App, preset: QualityPreset) {
+        app.insert_resource(QualitySettings::from_preset(preset));
+        app.update();
+    }
+
+    #[test]
+    fn low_preset_shrinks_shadows_compared_to_ultra() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<QualitySettings>()
+            .init_resource::<DirectionalLightShadowMap>()
+            .add_systems(Update, apply_quality_shadows);
+        let light = app.world_mut().spawn(DirectionalLight::default()).id();
+
+        apply(&mut app, QualityPreset::Ultra);
+        let ultra_size = app.world().resource::<DirectionalLightShadowMap>().size;
+        assert_eq!(app.world().get::<CascadeShadowConfig>(light).unwrap().bounds.len(), 4);
+
+        apply(&mut app, QualityPreset::Low);
+        let low_size = app.world().resource::<DirectionalLightShadowMap>().size;
+        assert!(low_size < ultra_size, "low {} should be below ultra {}", low_size, ultra_size);
+        assert_eq!(app.world().get::<CascadeShadowConfig>(light).unwrap().bounds.len(), 1);
+    }
 }
@@ -0,0 +1,262 @@
+//! Debug render modes for inspecting geometry in the editor.
+//!
+//! `Wireframe` turns on Bevy's global wireframe overlay. The other debug modes
+//! swap the `StandardMaterial` of every mesh: `Unlit` for an unlit copy of it,
+//! `Normals` and `UvChecker` for a shared `DebugViewMaterial`. The original
+//! handle is kept in `RenderModeOverride` and put back on return to `Shaded`.
+//! Meshes using effect or terrain materials keep their shading. Debug modes
+//! are editor-only and fall back to `Shaded` outside Edit mode.
+
+use bevy::prelude::*;
+use bevy::asset::uuid_handle;
+use bevy::pbr::wireframe::{WireframeConfig, WireframePlugin};
+use bevy::pbr::{Material, MaterialPlugin};
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::{Shader, ShaderRef};
+use serde::{Deserialize, Serialize};
+
+use super::engine_mode::EngineMode;
+use super::material::MaterialData;
+use super::selection_outline::OutlineShell;
+
+/// Stable handle for the debug view shader, registered via include_str! like
+/// the forge effects shader.
+const FORGE_DEBUG_VIEW_SHADER_HANDLE: Handle<Shader> = uuid_handle!("f09eeffc-e750-4001-a000-000000000006");
+
+/// UV checker squares along each texture axis.
+const UV_CHECKER_COUNT: f32 = 8.0;
+
+/// How the editor shades meshes.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RenderMode {
+    /// Regular materials and lighting.
+    #[default]
+    Shaded,
+    /// Regular shading with mesh edges drawn on top.
+    Wireframe,
+    /// Material colors and textures without lighting.
+    Unlit,
+    /// World-space normals mapped to RGB.
+    Normals,
+    /// A checkerboard laid out in texture space, tinted by UV.
+    UvChecker,
+}
+
+impl RenderMode {
+    /// Whether this mode replaces mesh materials.
+    pub fn swaps_materials(self) -> bool {
+        matches!(self, RenderMode::Unlit | RenderMode::Normals | RenderMode::UvChecker)
+    }
+}
+
+/// Records the material a debug render mode replaced on an entity.
+#[derive(Component, Debug, Clone)]
+pub struct RenderModeOverride {
+    pub original: Handle<StandardMaterial>,
+    /// The mode the current replacement was made for.
+    pub mode: RenderMode,
+}
+
+/// Unlit material that visualizes normals (`mode` 0) or UVs (`mode` 1).
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct DebugViewMaterial {
+    #[uniform(0)]
+    pub mode: u32,
+    #[uniform(0)]
+    pub checker_count: f32,
+    /// Keeps the uniform 16-byte aligned for WebGL2.
+    #[uniform(0)]
+    pub padding: Vec2,
+}
+
+impl DebugViewMaterial {
+    fn for_mode(mode: RenderMode) -> Self {
+        Self {
+            mode: u32::from(mode == RenderMode::UvChecker),
+            checker_count: UV_CHECKER_COUNT,
+            padding: Vec2::ZERO,
+        }
+    }
+}
+
+impl Material for DebugViewMaterial {
+    fn fragment_shader() -> ShaderRef {
+        FORGE_DEBUG_VIEW_SHADER_HANDLE.into()
+    }
+}
+
+/// Editor plugin that registers the debug render modes.
+pub struct RenderModePlugin;
+
+impl Plugin for RenderModePlugin {
+    fn build(&self, app: &mut App) {
+        if let Err(err) = app.world_mut()
+            .resource_mut::<Assets<Shader>>()
+            .insert(
+                FORGE_DEBUG_VIEW_SHADER_HANDLE.id(),
+                Shader::from_wgsl(include_str!("../shaders/forge_debug_view.wgsl"), "shaders/forge_debug_view.wgsl"),
+            )
+        {
+            tracing::warn!("Failed to register forge debug view shader: {err}");
+        }
+
+        // The wireframe pass needs POLYGON_MODE_LINE; where the GPU lacks it
+        // the plugin logs a warning and wireframe mode draws nothing extra.
+        app.add_plugins((WireframePlugin::default(), MaterialPlugin::<DebugViewMaterial>::default()))
+            .init_resource::<RenderMode>()
+            .add_systems(Update, sync_render_mode);
+    }
+}
+
+/// System that applies the render mode to the wireframe overlay and to every
+/// mesh's material, restoring the originals when leaving a debug mode.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn sync_render_mode(
+    mut commands: Commands,
+    render_mode: Res<RenderMode>,
+    engine_mode: Res<EngineMode>,
+    wireframe: Option<ResMut<WireframeConfig>>,
+    mut std_materials: ResMut<Assets<StandardMaterial>>,
+    mut debug_materials: ResMut<Assets<DebugViewMaterial>>,
+    mut debug_handles: Local<Vec<(RenderMode, Handle<DebugViewMaterial>)>>,
+    fresh: Query<(Entity, &MeshMaterial3d<StandardMaterial>), (With<Mesh3d>, Without<RenderModeOverride>, Without<OutlineShell>)>,
+    mut overridden: Query<(Entity, &mut RenderModeOverride, Option<&MeshMaterial3d<StandardMaterial>>, Option<&mut MaterialData>)>,
+) {
+    let mode = if engine_mode.is_edit() { *render_mode } else { RenderMode::Shaded };
+
+    if let Some(mut wireframe) = wireframe {
+        let global = mode == RenderMode::Wireframe;
+        if wireframe.global != global {
+            wireframe.global = global;
+        }
+    }
+
+    if !mode.swaps_materials() {
+        for (entity, record, _, data) in overridden.iter_mut() {
+            commands.entity(entity)
+                .remove::<(RenderModeOverride, MeshMaterial3d<DebugViewMaterial>)>()
+                .insert(MeshMaterial3d(record.original.clone()));
+            // Edits made meanwhile went to the unlit copy or nowhere; have
+            // the material sync write them to the original.
+            if let Some(mut data) = data {
+                data.set_changed();
+            }
+        }
+        return;
+    }
+
+    let mut debug_handle = |mode: RenderMode| {
+        if let Some((_, handle)) = debug_handles.iter().find(|(m, _)| *m == mode) {
+            return handle.clone();
+        }
+        let handle = debug_materials.add(DebugViewMaterial::for_mode(mode));
+        debug_handles.push((mode, handle.clone()));
+        handle
+    };
+    let mut swap = |commands: &mut Commands, std_materials: &mut Assets<StandardMaterial>, entity: Entity, original: &Handle<StandardMaterial>| {
+        if mode == RenderMode::Unlit {
+            let Some(material) = std_materials.get(original) else {
+                return false;
+            };
+            let unlit = StandardMaterial { unlit: true, ..material.clone() };
+            let unlit = std_materials.add(unlit);
+            commands.entity(entity)
+                .remove::<MeshMaterial3d<DebugViewMaterial>>()
+                .insert(MeshMaterial3d(unlit));
+        } else {
+            commands.entity(entity)
+                .remove::<MeshMaterial3d<StandardMaterial>>()
+                .insert(MeshMaterial3d(debug_handle(mode)));
+        }
+        true
+    };
+
+    for (entity, material) in fresh.iter() {
+        if swap(&mut commands, &mut std_materials, entity, &material.0) {
+            commands.entity(entity).insert(RenderModeOverride { original: material.0.clone(), mode });
+        }
+    }
+
+    for (entity, mut record, current, _) in overridden.iter_mut() {
+        if record.mode != mode {
+            if swap(&mut commands, &mut std_materials, entity, &record.original) {
+                record.mode = mode;
+            }
+        } else if let Some(current) = current.filter(|_| mode == RenderMode::Unlit) {
+            // Material edits re-sync the unlit copy from MaterialData, which
+            // clears the flag again.
+            if std_materials.get(&current.0).is_some_and(|m| !m.unlit) {
+                if let Some(material) = std_materials.get_mut(&current.0) {
+                    material.unlit = true;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (App, Entity, Handle<StandardMaterial>) {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<StandardMaterial>()
+            .init_asset::<DebugViewMaterial>()
+            .init_resource::<WireframeConfig>()
+            .init_resource::<EngineMode>()
+            .init_resource::<RenderMode>()
+            .add_systems(Update, sync_render_mode);
+        let original = app.world_mut()
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial::default());
+        let entity = app.world_mut().spawn((Mesh3d(Handle::default()), MeshMaterial3d(original.clone()))).id();
+        (app, entity, original)
+    }
+
+    fn set_mode(app: &mut App, mode: RenderMode) {
+        *app.world_mut().resource_mut::<RenderMode>() = mode;
+        app.update();
+    }
+
+    fn std_handle(app: &App, entity: Entity) -> Option<Handle<StandardMaterial>> {
+        app.world().get::<MeshMaterial3d<StandardMaterial>>(entity).map(|m| m.0.clone())
+    }
+
+    #[test]
+    fn wireframe_toggles_global_config_and_shaded_restores_materials() {
+        let (mut app, entity, original) = setup();
+
+        set_mode(&mut app, RenderMode::Wireframe);
+        assert!(app.world().resource::<WireframeConfig>().global);
+        assert_eq!(std_handle(&app, entity), Some(original.clone()));
+
+        set_mode(&mut app, RenderMode::Unlit);
+        assert!(!app.world().resource::<WireframeConfig>().global);
+        let unlit = std_handle(&app, entity).expect("unlit copy");
+        assert_ne!(unlit, original);
+        assert!(app.world().resource::<Assets<StandardMaterial>>().get(&unlit).unwrap().unlit);
+
+        set_mode(&mut app, RenderMode::Normals);
+        assert!(std_handle(&app, entity).is_none());
+        assert!(app.world().get::<MeshMaterial3d<DebugViewMaterial>>(entity).is_some());
+
+        set_mode(&mut app, RenderMode::Shaded);
+        assert_eq!(std_handle(&app, entity), Some(original.clone()));
+        assert!(app.world().get::<MeshMaterial3d<DebugViewMaterial>>(entity).is_none());
+        assert!(app.world().get::<RenderModeOverride>(entity).is_none());
+        assert!(!app.world().resource::<Assets<StandardMaterial>>().get(&original).unwrap().unlit);
+    }
+
+    #[test]
+    fn debug_modes_fall_back_to_shaded_outside_edit_mode() {
+        let (mut app, entity, original) = setup();
+        set_mode(&mut app, RenderMode::UvChecker);
+        assert!(std_handle(&app, entity).is_none());
+
+        *app.world_mut().resource_mut::<EngineMode>() = EngineMode::Play;
+        app.update();
+        assert_eq!(std_handle(&app, entity), Some(original));
+    }
+}
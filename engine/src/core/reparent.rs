@@ -2,11 +2,16 @@
 //!
 //! Handles reparenting entities in the scene hierarchy, including validation
 //! for circular references and proper ChildOf/Children component management.
+//! Grouping places the selection under a new empty; ungrouping dissolves one.
+//! Both keep every moved entity's world transform.
 
 use bevy::prelude::*;
 
-use super::entity_id::EntityId;
-use super::pending_commands::{PendingCommands, ReparentRequest};
+use super::entity_factory::spawn_empty_with_id;
+use super::entity_id::{EntityId, EntityName, EntityVisible};
+use super::history::{EntitySnapshot, GroupedChild, HistoryStack, TransformSnapshot, UndoableAction};
+use super::pending_commands::{EntityType, PendingCommands, ReparentRequest};
+use super::selection::{Selection, SelectionChangedEvent};
 #[cfg(target_arch = "wasm32")]
 use crate::bridge::events::emit_event;
 
@@ -14,6 +19,7 @@ use crate::bridge::events::emit_event;
 pub fn apply_reparent_requests(
     mut pending: ResMut<PendingCommands>,
     mut commands: Commands,
+    mut history: ResMut<HistoryStack>,
     query: Query<(Entity, &EntityId, Option<&ChildOf>, Option<&Children>)>,
 ) {
     for request in pending.reparent_requests.drain(..) {
@@ -21,7 +27,10 @@ pub fn apply_reparent_requests(
 
         // Emit result event for bridge
         let (success, error) = match result {
-            Ok(()) => (true, None),
+            Ok(action) => {
+                history.push(action);
+                (true, None)
+            }
             Err(e) => (false, Some(e)),
         };
 
@@ -29,12 +38,12 @@ pub fn apply_reparent_requests(
     }
 }
 
-/// Process a single reparent request.
+/// Process a single reparent request, returning the history entry for it.
 fn process_reparent(
     request: &ReparentRequest,
     commands: &mut Commands,
     query: &Query<(Entity, &EntityId, Option<&ChildOf>, Option<&Children>)>,
-) -> Result<(), String> {
+) -> Result<UndoableAction, String> {
     // 1. Find the entity to reparent
     let (entity, _, current_parent, _) = query
         .iter()
@@ -60,28 +69,66 @@ fn process_reparent(
         }
     }
 
-    // 4. Remove from current parent
+    // 4. Record where the entity currently sits, before mutating
+    let old_parent = current_parent.map(|child_of| child_of.parent());
+    let old_parent_id = old_parent.and_then(|p| entity_id_of(p, query));
+    let old_index = old_parent.and_then(|p| {
+        query
+            .get(p)
+            .ok()
+            .and_then(|(_, _, _, children)| children?.iter().position(|c| c == entity))
+    });
+
+    // 5. Remove from current parent
     if current_parent.is_some() {
         commands.entity(entity).remove::<ChildOf>();
     }
 
-    // 5. Add to new parent (or keep as root)
-    if let Some(new_parent) = new_parent_entity {
-        if let Some(index) = request.insert_index {
-            // Insert at specific position (clamp to child count for graceful fallback)
-            let child_count = query
-                .iter()
-                .find(|(e, _, _, _)| *e == new_parent)
-                .and_then(|(_, _, _, children)| children.map(|c| c.len()))
-                .unwrap_or(0);
-            let clamped = index.min(child_count);
-            commands.entity(new_parent).insert_children(clamped, &[entity]);
-        } else {
-            commands.entity(new_parent).add_child(entity);
+    // 6. Add to new parent (or keep as root)
+    let new_index = new_parent_entity.map(|new_parent| {
+        let child_count = query
+            .iter()
+            .find(|(e, _, _, _)| *e == new_parent)
+            .and_then(|(_, _, _, children)| children.map(|c| c.len()))
+            .unwrap_or(0);
+        // Siblings once the entity has been detached
+        let sibling_count = if old_parent == Some(new_parent) { child_count - 1 } else { child_count };
+        // Insert at specific position (clamp to sibling count for graceful fallback)
+        let index = request.insert_index.map_or(sibling_count, |i| i.min(sibling_count));
+        place_child(commands, new_parent, entity, index);
+        index
+    });
+
+    Ok(UndoableAction::Reparent {
+        entity_id: request.entity_id.clone(),
+        old_parent_id,
+        new_parent_id: request.new_parent_id.clone(),
+        old_index,
+        new_index,
+    })
+}
+
+/// Make `child` a child of `parent` at sibling position `index`, keeping the
+/// order of the other children. (`insert_children` swaps the displaced sibling
+/// to the end, which would scramble the order undo needs to restore.)
+pub(crate) fn place_child(commands: &mut Commands, parent: Entity, child: Entity, index: usize) {
+    commands.entity(parent).add_child(child).queue(move |mut parent: EntityWorldMut| {
+        if let Some(mut children) = parent.get_mut::<Children>() {
+            let others: Vec<Entity> = children.iter().filter(|c| *c != child).collect();
+            children.sort_by_key(|c| match others.iter().position(|o| o == c) {
+                Some(rank) => 2 * rank + 1,
+                None => 2 * index,
+            });
         }
-    }
+    });
+}
 
-    Ok(())
+/// Look up the `EntityId` string of an entity.
+fn entity_id_of(
+    entity: Entity,
+    query: &Query<(Entity, &EntityId, Option<&ChildOf>, Option<&Children>)>,
+) -> Option<String> {
+    query.get(entity).ok().map(|(_, eid, _, _)| eid.0.clone())
 }
 
 /// Check if `potential_ancestor` is a descendant of `entity`.
@@ -111,6 +158,175 @@ fn is_descendant_of(
     false
 }
 
+/// Entities with an ID, as seen by the group and ungroup systems.
+type HierarchyQuery<'w, 's> = Query<'w, 's, (
+    Entity,
+    &'static EntityId,
+    Option<&'static EntityType>,
+    Option<&'static EntityName>,
+    Option<&'static EntityVisible>,
+    &'static Transform,
+    &'static GlobalTransform,
+    Option<&'static ChildOf>,
+    Option<&'static Children>,
+)>;
+
+/// System that groups the selection under a new empty at its centroid.
+/// Selected entities whose ancestor is also selected move with that ancestor.
+/// The group goes under the members' shared parent, or the root if they differ.
+pub fn apply_group_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: HierarchyQuery,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for request in pending.group_requests.drain(..) {
+        let members: Vec<Entity> = query
+            .iter()
+            .filter(|(entity, ..)| selection.is_selected(*entity))
+            .filter(|(_, _, _, _, _, _, _, child_of, _)| {
+                !has_selected_ancestor(child_of.map(|c| c.parent()), &selection, &query)
+            })
+            .map(|(entity, ..)| entity)
+            .collect();
+        if members.is_empty() {
+            tracing::warn!("group_entities: nothing selected");
+            continue;
+        }
+
+        let parent_of = |entity: Entity| query.get(entity).ok().and_then(|item| item.7).map(|c| c.parent());
+        let shared_parent = parent_of(members[0]).filter(|p| members.iter().all(|m| parent_of(*m) == Some(*p)));
+        let parent_global = shared_parent.and_then(|p| query.get(p).ok()).map(|item| *item.6);
+        let parent_id = shared_parent.and_then(|p| query.get(p).ok()).map(|item| item.1 .0.clone());
+
+        let centroid = members
+            .iter()
+            .filter_map(|m| query.get(*m).ok())
+            .map(|item| item.6.translation())
+            .sum::<Vec3>()
+            / members.len() as f32;
+        let group_global = GlobalTransform::from_translation(centroid);
+        let group_local = match parent_global {
+            Some(parent_global) => group_global.reparented_to(&parent_global),
+            None => Transform::from_translation(centroid),
+        };
+
+        let name = request.name.unwrap_or_else(|| "Group".to_string());
+        let (group, group_id, _) = spawn_empty_with_id(&mut commands, &name, None);
+        commands.entity(group).insert(group_local);
+        if let Some(parent) = shared_parent {
+            commands.entity(group).insert(ChildOf(parent));
+        }
+
+        let mut children = Vec::with_capacity(members.len());
+        for member in &members {
+            let Ok((_, eid, _, _, _, transform, global, child_of, _)) = query.get(*member) else {
+                continue;
+            };
+            let new_transform = global.reparented_to(&group_global);
+            commands.entity(*member).insert((new_transform, ChildOf(group)));
+            children.push(GroupedChild {
+                entity_id: eid.0.clone(),
+                old_parent_id: child_of.and_then(|c| query.get(c.parent()).ok()).map(|item| item.1 .0.clone()),
+                old_transform: TransformSnapshot::from(transform),
+                new_parent_id: Some(group_id.clone()),
+                new_transform: TransformSnapshot::from(&new_transform),
+            });
+        }
+
+        let mut snapshot = EntitySnapshot::new(group_id.clone(), EntityType::Empty, name.clone(), TransformSnapshot::from(&group_local));
+        snapshot.parent_id = parent_id;
+        history.push(UndoableAction::GroupEntities { group: snapshot, children });
+
+        selection.select_one(group, group_id);
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: selection.selected_ids(),
+            primary_id: selection.primary_id.clone(),
+            primary_name: Some(name),
+        });
+    }
+}
+
+/// System that dissolves an empty, moving its children to its parent.
+pub fn apply_ungroup_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut commands: Commands,
+    query: HierarchyQuery,
+    mut selection: ResMut<Selection>,
+    mut selection_events: MessageWriter<SelectionChangedEvent>,
+    mut history: ResMut<HistoryStack>,
+) {
+    for request in pending.ungroup_requests.drain(..) {
+        let Some((group, eid, entity_type, name, visible, transform, _, child_of, group_children)) =
+            query.iter().find(|item| item.1 .0 == request.entity_id)
+        else {
+            tracing::warn!("ungroup_entity: entity not found: {}", request.entity_id);
+            continue;
+        };
+        if entity_type != Some(&EntityType::Empty) {
+            tracing::warn!("ungroup_entity: {} is not an empty group", request.entity_id);
+            continue;
+        }
+
+        let parent = child_of.map(|c| c.parent());
+        let parent_item = parent.and_then(|p| query.get(p).ok());
+        let parent_id = parent_item.as_ref().map(|item| item.1 .0.clone());
+        let parent_global = parent_item.as_ref().map(|item| *item.6);
+
+        selection.clear();
+        let mut children = Vec::new();
+        for child in group_children.into_iter().flat_map(|c| c.iter()) {
+            let Ok((_, child_id, _, _, _, child_transform, child_global, _, _)) = query.get(child) else {
+                continue;
+            };
+            let new_transform = match parent_global {
+                Some(parent_global) => child_global.reparented_to(&parent_global),
+                None => child_global.compute_transform(),
+            };
+            let mut ec = commands.entity(child);
+            ec.insert(new_transform);
+            match parent {
+                Some(parent) => { ec.insert(ChildOf(parent)); }
+                None => { ec.remove::<ChildOf>(); }
+            }
+            children.push(GroupedChild {
+                entity_id: child_id.0.clone(),
+                old_parent_id: Some(eid.0.clone()),
+                old_transform: TransformSnapshot::from(child_transform),
+                new_parent_id: parent_id.clone(),
+                new_transform: TransformSnapshot::from(&new_transform),
+            });
+            selection.add(child, child_id.0.clone());
+        }
+        commands.entity(group).despawn();
+
+        let group_name = name.map(|n| n.0.clone()).unwrap_or_default();
+        let mut snapshot = EntitySnapshot::new(eid.0.clone(), EntityType::Empty, group_name, TransformSnapshot::from(transform));
+        snapshot.parent_id = parent_id;
+        snapshot.visible = visible.is_none_or(|v| v.0);
+        history.push(UndoableAction::UngroupEntities { group: snapshot, children });
+
+        selection_events.write(SelectionChangedEvent {
+            selected_ids: selection.selected_ids(),
+            primary_id: selection.primary_id.clone(),
+            primary_name: None,
+        });
+    }
+}
+
+/// Whether `entity` or any of its ancestors is selected.
+fn has_selected_ancestor(mut entity: Option<Entity>, selection: &Selection, query: &HierarchyQuery) -> bool {
+    while let Some(current) = entity {
+        if selection.is_selected(current) {
+            return true;
+        }
+        entity = query.get(current).ok().and_then(|item| item.7).map(|c| c.parent());
+    }
+    false
+}
+
 /// Emit reparent result event to JavaScript.
 #[cfg(target_arch = "wasm32")]
 fn emit_reparent_result(entity_id: &str, success: bool, error: Option<String>) {
@@ -126,3 +342,139 @@ fn emit_reparent_result(entity_id: &str, success: bool, error: Option<String>) {
 /// No-op on non-wasm targets.
 #[cfg(not(target_arch = "wasm32"))]
 fn emit_reparent_result(_entity_id: &str, _success: bool, _error: Option<String>) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending_commands::{GroupRequest, UngroupRequest};
+
+    fn grouping_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin))
+            .init_resource::<PendingCommands>()
+            .init_resource::<Selection>()
+            .init_resource::<HistoryStack>()
+            .add_message::<SelectionChangedEvent>()
+            .add_systems(Update, (apply_group_requests, apply_ungroup_requests).chain());
+        app
+    }
+
+    fn spawn_cube(app: &mut App, id: &str, position: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                EntityType::Cube,
+                EntityId(id.to_string()),
+                EntityName::new(id),
+                EntityVisible::default(),
+                Transform::from_translation(position),
+            ))
+            .id()
+    }
+
+    fn empties(app: &mut App) -> Vec<Entity> {
+        let mut query = app.world_mut().query::<(Entity, &EntityType)>();
+        query
+            .iter(app.world())
+            .filter(|(_, t)| **t == EntityType::Empty)
+            .map(|(e, _)| e)
+            .collect()
+    }
+
+    #[test]
+    fn group_then_ungroup_restores_hierarchy() {
+        let mut app = grouping_app();
+        let positions = [Vec3::new(-2.0, 0.0, 0.0), Vec3::new(0.0, 3.0, 0.0), Vec3::new(2.0, 0.0, 3.0)];
+        let cubes: Vec<Entity> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, p)| spawn_cube(&mut app, &format!("cube-{i}"), *p))
+            .collect();
+        app.update(); // propagate GlobalTransform
+
+        {
+            let world = app.world_mut();
+            let mut selection = world.resource_mut::<Selection>();
+            for (i, cube) in cubes.iter().enumerate() {
+                selection.add(*cube, format!("cube-{i}"));
+            }
+            world.resource_mut::<PendingCommands>().queue_group(GroupRequest { name: None });
+        }
+        app.update();
+        app.update(); // propagate the new hierarchy
+
+        let groups = empties(&mut app);
+        assert_eq!(groups.len(), 1);
+        let group = groups[0];
+        let children = app.world().get::<Children>(group).expect("group has children");
+        assert_eq!(children.len(), 3);
+        assert_eq!(
+            app.world().get::<Transform>(group).unwrap().translation,
+            Vec3::new(0.0, 1.0, 1.0)
+        );
+        for (cube, position) in cubes.iter().zip(positions) {
+            let global = app.world().get::<GlobalTransform>(*cube).unwrap().translation();
+            assert!(global.abs_diff_eq(position, 1e-5), "world position moved: {global}");
+        }
+        assert!(app.world().resource::<Selection>().is_selected(group));
+        assert_eq!(
+            app.world().resource::<HistoryStack>().undo_description().as_deref(),
+            Some("Group 3 objects")
+        );
+
+        let group_id = app.world().get::<EntityId>(group).unwrap().0.clone();
+        app.world_mut()
+            .resource_mut::<PendingCommands>()
+            .queue_ungroup(UngroupRequest { entity_id: group_id });
+        app.update();
+
+        assert!(empties(&mut app).is_empty());
+        for (cube, position) in cubes.iter().zip(positions) {
+            assert!(app.world().get::<ChildOf>(*cube).is_none());
+            let translation = app.world().get::<Transform>(*cube).unwrap().translation;
+            assert!(translation.abs_diff_eq(position, 1e-5), "local position moved: {translation}");
+        }
+    }
+
+    #[test]
+    fn reparent_to_root_then_undo_restores_parent_and_order() {
+        use crate::core::entity_factory::apply_undo_requests;
+        use crate::core::history::queue_undo_from_bridge;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin))
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, (apply_reparent_requests, apply_undo_requests).chain());
+
+        let parent = spawn_cube(&mut app, "parent", Vec3::ZERO);
+        let kids: Vec<Entity> = ["a", "b", "c"]
+            .iter()
+            .map(|id| spawn_cube(&mut app, id, Vec3::ZERO))
+            .collect();
+        app.world_mut().entity_mut(parent).add_children(&kids);
+
+        app.world_mut().resource_mut::<PendingCommands>().queue_reparent(ReparentRequest {
+            entity_id: "b".to_string(),
+            new_parent_id: None,
+            insert_index: None,
+        });
+        app.update();
+
+        assert!(app.world().get::<ChildOf>(kids[1]).is_none());
+        let children: Vec<Entity> = app.world().get::<Children>(parent).unwrap().iter().collect();
+        assert_eq!(children, vec![kids[0], kids[2]]);
+        assert_eq!(
+            app.world().resource::<HistoryStack>().undo_description().as_deref(),
+            Some("Reparent")
+        );
+
+        queue_undo_from_bridge();
+        app.update();
+
+        assert_eq!(app.world().get::<ChildOf>(kids[1]).map(|c| c.parent()), Some(parent));
+        let children: Vec<Entity> = app.world().get::<Children>(parent).unwrap().iter().collect();
+        assert_eq!(children, kids);
+    }
+}
@@ -1,10 +1,17 @@
 //! Reverb zone component for spatial audio reverb.
 //!
 //! Stores reverb zone configuration on entities. All reverb processing happens in JS
-//! via the Web Audio API. This component is metadata-only.
+//! via the Web Audio API. This component is metadata-only; during Play mode the
+//! engine only tracks which zones contain the audio listener (the active game
+//! camera) and reports boundary crossings so JS can swap convolution presets.
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::engine_mode::EngineMode;
+use super::entity_id::EntityId;
+use super::game_camera::ActiveGameCamera;
 
 /// Shape of the reverb zone trigger volume.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -59,3 +66,132 @@ impl Default for ReverbZoneData {
 /// Marker component: entity has reverb zone enabled.
 #[derive(Component, Debug, Clone)]
 pub struct ReverbZoneEnabled;
+
+impl ReverbZoneData {
+    /// Whether a world-space point lies inside the zone volume. The shape is
+    /// defined in the zone entity's local space, so rotation and scale apply.
+    pub fn contains(&self, zone: &GlobalTransform, point: Vec3) -> bool {
+        let local = zone.affine().inverse().transform_point3(point);
+        match &self.shape {
+            ReverbShape::Box { size } => {
+                let half = Vec3::from_array(*size) * 0.5;
+                local.abs().cmple(half).all()
+            }
+            ReverbShape::Sphere { radius } => local.length() <= *radius,
+        }
+    }
+}
+
+/// Message fired when the audio listener enters or leaves a reverb zone.
+#[derive(Message, Debug, Clone)]
+pub struct ReverbZoneTransition {
+    pub entity_id: String,
+    pub entered: bool,
+    pub data: ReverbZoneData,
+}
+
+/// Zones currently containing the listener, with the data last seen for each
+/// (so exit events still carry params if the zone was removed).
+#[derive(Resource, Default)]
+pub struct ListenerReverbZones(pub HashMap<Entity, (String, ReverbZoneData)>);
+
+/// System that tracks the active game camera against enabled reverb zones and
+/// fires one `ReverbZoneTransition` per boundary crossing. Returning to Edit
+/// mode exits every zone; Paused keeps the current state.
+pub fn track_listener_reverb_zones(
+    engine_mode: Res<EngineMode>,
+    listener: Query<&GlobalTransform, With<ActiveGameCamera>>,
+    zones: Query<(Entity, &EntityId, &ReverbZoneData, &GlobalTransform), With<ReverbZoneEnabled>>,
+    mut inside: ResMut<ListenerReverbZones>,
+    mut transitions: MessageWriter<ReverbZoneTransition>,
+) {
+    if *engine_mode == EngineMode::Paused {
+        return;
+    }
+
+    let mut now: HashMap<Entity, (String, ReverbZoneData)> = HashMap::new();
+    if engine_mode.is_playing() {
+        if let Some(position) = listener.iter().next().map(|t| t.translation()) {
+            for (entity, entity_id, data, zone_transform) in zones.iter() {
+                if data.contains(zone_transform, position) {
+                    now.insert(entity, (entity_id.0.clone(), data.clone()));
+                }
+            }
+        }
+    }
+
+    for (entity, (entity_id, data)) in inside.0.iter() {
+        if !now.contains_key(entity) {
+            transitions.write(ReverbZoneTransition { entity_id: entity_id.clone(), entered: false, data: data.clone() });
+        }
+    }
+    for (entity, (entity_id, data)) in now.iter() {
+        if !inside.0.contains_key(entity) {
+            transitions.write(ReverbZoneTransition { entity_id: entity_id.clone(), entered: true, data: data.clone() });
+        }
+    }
+    inside.0 = now;
+}
+
+/// Plugin that tracks the listener's reverb zone membership.
+pub struct ReverbZonePlugin;
+
+impl Plugin for ReverbZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ListenerReverbZones>()
+            .add_message::<ReverbZoneTransition>()
+            .add_systems(Update, track_listener_reverb_zones);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+
+    fn drain_transitions(app: &mut App) -> Vec<ReverbZoneTransition> {
+        app.world_mut().resource_mut::<Messages<ReverbZoneTransition>>().drain().collect()
+    }
+
+    #[test]
+    fn box_zone_respects_rotation() {
+        let data = ReverbZoneData { shape: ReverbShape::Box { size: [10.0, 2.0, 2.0] }, ..Default::default() };
+        let zone = GlobalTransform::from(Transform::from_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2)));
+        assert!(data.contains(&zone, Vec3::new(0.0, 0.0, 4.0)));
+        assert!(!data.contains(&zone, Vec3::new(4.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn listener_crossing_zone_emits_one_enter_and_one_exit() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(ReverbZonePlugin)
+            .insert_resource(EngineMode::Play);
+
+        app.world_mut().spawn((
+            EntityId("cave".to_string()),
+            ReverbZoneData { shape: ReverbShape::Sphere { radius: 5.0 }, preset: "cave".to_string(), ..Default::default() },
+            ReverbZoneEnabled,
+            GlobalTransform::IDENTITY,
+        ));
+        let listener = app.world_mut()
+            .spawn((ActiveGameCamera, GlobalTransform::from_translation(Vec3::new(20.0, 0.0, 0.0))))
+            .id();
+
+        let move_listener = |app: &mut App, x: f32| {
+            *app.world_mut().get_mut::<GlobalTransform>(listener).expect("listener") =
+                GlobalTransform::from_translation(Vec3::new(x, 0.0, 0.0));
+            app.update();
+        };
+
+        let mut seen = Vec::new();
+        for x in [20.0, 3.0, 1.0, -2.0, 12.0, 30.0] {
+            move_listener(&mut app, x);
+            seen.extend(drain_transitions(&mut app));
+        }
+
+        assert_eq!(seen.len(), 2, "{:?}", seen);
+        assert!(seen[0].entered && seen[0].entity_id == "cave" && seen[0].data.preset == "cave");
+        assert!(!seen[1].entered && seen[1].entity_id == "cave");
+    }
+}
@@ -0,0 +1,699 @@
+//! Scene file serialization for save/load.
+//!
+//! Defines the `.forge` JSON scene format and provides helper functions.
+//! The actual ECS queries live in bridge systems — this module is pure data.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::asset_manager::AssetMetadata;
+use super::audio::AudioBusConfig;
+use super::custom_wgsl::CustomWgslSource;
+use super::environment::EnvironmentSettings;
+use super::history::EntitySnapshot;
+use super::input::InputMap;
+use super::post_processing::PostProcessingSettings;
+
+mod patch;
+
+pub use patch::*;
+
+// ---------------------------------------------------------------------------
+// Structs
+// ---------------------------------------------------------------------------
+
+/// Maximum size of a scene JSON payload in bytes (50 MB).
+pub const MAX_SCENE_JSON_BYTES: usize = 50 * 1024 * 1024;
+
+/// Scene format version written by `build_scene_file`.
+pub const SCENE_FORMAT_VERSION: u32 = 3;
+
+/// Top-level scene file container.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneFile {
+    pub format_version: u32,
+    pub metadata: SceneMetadata,
+    #[serde(default)]
+    pub environment: EnvironmentSettings,
+    #[serde(default)]
+    pub ambient_light: AmbientLightData,
+    #[serde(default)]
+    pub input_bindings: InputMap,
+    #[serde(default)]
+    pub assets: HashMap<String, AssetMetadata>,
+    #[serde(default)]
+    pub post_processing: PostProcessingSettings,
+    #[serde(default)]
+    pub audio_buses: AudioBusConfig,
+    pub entities: Vec<EntitySnapshot>,
+    #[serde(default)]
+    pub game_ui: Option<String>,
+    /// Scene-global custom WGSL shader source (optional, preserved across save/load).
+    #[serde(default)]
+    pub custom_wgsl_source: Option<CustomWgslSource>,
+}
+
+/// Scene metadata (name, timestamps).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneMetadata {
+    pub name: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub modified_at: String,
+}
+
+/// Serializable representation of Bevy's AmbientLight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmbientLightData {
+    pub color: [f32; 3],
+    pub brightness: f32,
+}
+
+impl Default for AmbientLightData {
+    fn default() -> Self {
+        Self {
+            color: [1.0, 1.0, 1.0],
+            brightness: 300.0,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resource: scene name stored in Bevy world
+// ---------------------------------------------------------------------------
+
+/// Resource holding the current scene name.
+#[derive(Resource, Debug, Clone)]
+pub struct SceneName(pub String);
+
+impl Default for SceneName {
+    fn default() -> Self {
+        Self("Untitled".to_string())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Build helper
+// ---------------------------------------------------------------------------
+
+/// Build a `SceneFile` from pre-collected data.
+pub fn build_scene_file(
+    scene_name: &str,
+    env: &EnvironmentSettings,
+    ambient: &GlobalAmbientLight,
+    input_map: &InputMap,
+    assets: HashMap<String, AssetMetadata>,
+    post_processing: &PostProcessingSettings,
+    audio_buses: &AudioBusConfig,
+    entities: Vec<EntitySnapshot>,
+    game_ui: Option<String>,
+    custom_wgsl_source: Option<CustomWgslSource>,
+) -> SceneFile {
+    SceneFile {
+        format_version: SCENE_FORMAT_VERSION,
+        metadata: SceneMetadata {
+            name: scene_name.to_string(),
+            created_at: String::new(),
+            modified_at: String::new(),
+        },
+        environment: env.clone(),
+        ambient_light: AmbientLightData {
+            color: [
+                ambient.color.to_linear().red,
+                ambient.color.to_linear().green,
+                ambient.color.to_linear().blue,
+            ],
+            brightness: ambient.brightness,
+        },
+        input_bindings: input_map.clone(),
+        assets,
+        post_processing: post_processing.clone(),
+        audio_buses: audio_buses.clone(),
+        entities,
+        game_ui,
+        custom_wgsl_source,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Migration
+// ---------------------------------------------------------------------------
+
+/// Upgrade an older scene file in place to `SCENE_FORMAT_VERSION`.
+///
+/// Sections a file predates are already defaulted by serde; this fills in
+/// the ones whose serde default isn't enough and bumps the version. Returns
+/// the migrations that ran (empty for current files).
+pub fn migrate_scene_file(scene: &mut SceneFile) -> Vec<&'static str> {
+    let mut applied = Vec::new();
+
+    if scene.format_version < 2 {
+        // v1 predates audio buses; every scene needs at least the master bus.
+        if scene.audio_buses.buses.is_empty() {
+            scene.audio_buses = AudioBusConfig::default();
+        } else if !scene.audio_buses.buses.iter().any(|b| b.name == "master") {
+            let master = AudioBusConfig::default().buses.remove(0);
+            scene.audio_buses.buses.insert(0, master);
+        }
+        applied.push("v1 -> v2: default audio buses");
+    }
+
+    if scene.format_version < 3 {
+        // v2 predates post-processing and custom WGSL; both default to off.
+        applied.push("v2 -> v3: default post-processing");
+    }
+
+    for migration in &applied {
+        tracing::info!("Scene '{}' migration: {}", scene.metadata.name, migration);
+    }
+    scene.format_version = scene.format_version.max(SCENE_FORMAT_VERSION);
+    applied
+}
+
+// ---------------------------------------------------------------------------
+// Compressed encoding
+// ---------------------------------------------------------------------------
+
+/// Encode a scene as gzip-compressed JSON for compact transfer out of WASM.
+///
+/// The payload is still JSON underneath: snapshots skip `None` fields when
+/// serializing, which non-self-describing formats such as bincode can't read
+/// back. Scene JSON is highly repetitive, so gzip does most of the shrinking.
+pub fn encode_scene_binary(scene: &SceneFile) -> Result<Vec<u8>, String> {
+    use std::io::Write;
+
+    let json = serde_json::to_vec(scene).map_err(|e| format!("Failed to serialize scene: {}", e))?;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&json)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| format!("Failed to compress scene: {}", e))
+}
+
+/// Decompress a scene produced by `encode_scene_binary` back to its JSON.
+/// Fails if the decompressed JSON would exceed `max_json_bytes`.
+pub fn decode_scene_binary(bytes: &[u8], max_json_bytes: usize) -> Result<String, String> {
+    use std::io::Read;
+
+    let mut json = String::new();
+    flate2::read::GzDecoder::new(bytes)
+        .take((max_json_bytes as u64).saturating_add(1))
+        .read_to_string(&mut json)
+        .map_err(|e| format!("Failed to decompress scene: {}", e))?;
+    if json.len() > max_json_bytes {
+        return Err(format!("Decompressed scene exceeds {} bytes", max_json_bytes));
+    }
+    Ok(json)
+}
+
+// ---------------------------------------------------------------------------
+// Merge helper
+// ---------------------------------------------------------------------------
+
+/// Prepare scene entities for merging into a populated world.
+///
+/// Every `entity_id` is replaced with a fresh UUID so merged entities can never
+/// collide with existing ones, and `parent_id` / joint references are rewritten
+/// through the same map. Parents that are not part of the merged set are dropped
+/// so those entities become roots. The optional offset is applied to roots only
+/// (children keep their local transforms), and the optional prefix to every name.
+pub fn remap_for_merge(
+    mut entities: Vec<EntitySnapshot>,
+    offset: Option<Vec3>,
+    prefix: Option<&str>,
+) -> Vec<EntitySnapshot> {
+    let id_map: HashMap<String, String> = entities
+        .iter()
+        .map(|snap| (snap.entity_id.clone(), uuid::Uuid::new_v4().to_string()))
+        .collect();
+
+    for snap in &mut entities {
+        snap.entity_id = id_map[&snap.entity_id].clone();
+        snap.parent_id = snap.parent_id.as_ref().and_then(|p| id_map.get(p).cloned());
+
+        if let Some(ref mut joint) = snap.joint_data {
+            if let Some(new_id) = id_map.get(&joint.connected_entity_id) {
+                joint.connected_entity_id = new_id.clone();
+            }
+        }
+
+        if snap.parent_id.is_none() {
+            if let Some(offset) = offset {
+                snap.transform.position[0] += offset.x;
+                snap.transform.position[1] += offset.y;
+                snap.transform.position[2] += offset.z;
+            }
+        }
+
+        if let Some(prefix) = prefix {
+            snap.name = format!("{}{}", prefix, snap.name);
+        }
+    }
+
+    entities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::history::TransformSnapshot;
+    use crate::core::pending_commands::EntityType;
+
+    pub(super) fn snap(id: &str, parent: Option<&str>, pos: [f32; 3]) -> EntitySnapshot {
+        let mut s = EntitySnapshot::new(
+            id.to_string(),
+            EntityType::Cube,
+            format!("Cube_{}", id),
+            TransformSnapshot {
+                position: pos,
+                rotation: [0.0, 0.0, 0.0, 1.0],
+                scale: [1.0, 1.0, 1.0],
+            },
+        );
+        s.parent_id = parent.map(|p| p.to_string());
+        s
+    }
+
+    #[test]
+    fn remap_for_merge_assigns_fresh_ids_and_rewrites_parents() {
+        let entities = vec![snap("a", None, [0.0; 3]), snap("b", Some("a"), [1.0, 0.0, 0.0])];
+        let merged = remap_for_merge(entities, None, None);
+
+        assert_ne!(merged[0].entity_id, "a");
+        assert_ne!(merged[1].entity_id, "b");
+        assert_ne!(merged[0].entity_id, merged[1].entity_id);
+        assert_eq!(merged[1].parent_id.as_deref(), Some(merged[0].entity_id.as_str()));
+    }
+
+    #[test]
+    fn remap_for_merge_offsets_roots_only_and_prefixes_names() {
+        let entities = vec![snap("a", None, [1.0, 2.0, 3.0]), snap("b", Some("a"), [1.0, 0.0, 0.0])];
+        let merged = remap_for_merge(entities, Some(Vec3::new(10.0, 0.0, -5.0)), Some("Imported_"));
+
+        assert_eq!(merged[0].transform.position, [11.0, 2.0, -2.0]);
+        assert_eq!(merged[1].transform.position, [1.0, 0.0, 0.0]);
+        assert_eq!(merged[0].name, "Imported_Cube_a");
+        assert_eq!(merged[1].name, "Imported_Cube_b");
+    }
+
+    #[test]
+    fn remap_for_merge_drops_parents_outside_the_merged_set() {
+        let entities = vec![snap("orphan", Some("missing"), [0.0; 3])];
+        let merged = remap_for_merge(entities, Some(Vec3::X), None);
+
+        assert!(merged[0].parent_id.is_none());
+        assert_eq!(merged[0].transform.position, [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn terrain_splat_layers_round_trip_through_scene_file() {
+        use crate::core::terrain::{SplatLayer, TerrainData};
+
+        let layers = vec![
+            SplatLayer { texture_asset_id: Some("sand".into()), tiling: 0.25, height_range: [0.0, 0.2], slope_range: [0.0, 30.0] },
+            SplatLayer { texture_asset_id: Some("grass".into()), tiling: 0.1, height_range: [0.15, 0.6], slope_range: [0.0, 35.0] },
+            SplatLayer { texture_asset_id: Some("rock".into()), tiling: 0.05, height_range: [0.0, 1.0], slope_range: [30.0, 90.0] },
+            SplatLayer { texture_asset_id: None, tiling: 0.2, height_range: [0.7, 1.0], slope_range: [0.0, 90.0] },
+        ];
+        let mut terrain = snap("terrain", None, [0.0; 3]);
+        terrain.entity_type = EntityType::Terrain;
+        terrain.terrain_data = Some(TerrainData {
+            splat_layers: layers.clone(),
+            splat_map: Some("splat".into()),
+            ..Default::default()
+        });
+
+        let scene = build_scene_file(
+            "Splat",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![terrain],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        let data = loaded.entities[0].terrain_data.as_ref().expect("terrain data");
+        assert_eq!(data.splat_layers, layers);
+        assert_eq!(data.splat_map.as_deref(), Some("splat"));
+    }
+
+    #[test]
+    fn particle_sub_emitter_round_trips_through_scene_file() {
+        use crate::core::particles::{ParticleData, ParticlePreset, SubEmitterTrigger};
+
+        let mut particles = ParticleData::fire();
+        particles.sub_emitter = Some(Box::new(ParticleData::smoke()));
+        particles.sub_emitter_trigger = SubEmitterTrigger::OnCollision;
+        let mut emitter = snap("emitter", None, [0.0; 3]);
+        emitter.particle_data = Some(particles);
+
+        let scene = build_scene_file(
+            "Sub-emitter",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![emitter],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        let data = loaded.entities[0].particle_data.as_ref().expect("particle data");
+        assert_eq!(data.sub_emitter_trigger, SubEmitterTrigger::OnCollision);
+        let sub = data.sub_emitter.as_ref().expect("sub-emitter");
+        assert_eq!(sub.preset, ParticlePreset::Smoke);
+        assert!(sub.sub_emitter.is_none());
+    }
+
+    #[test]
+    fn particle_flipbook_fields_round_trip_through_scene_file() {
+        use crate::core::particles::ParticleData;
+
+        let mut particles = ParticleData::explosion();
+        particles.texture_asset_id = Some("explosion_sheet".into());
+        particles.flipbook_cols = 8;
+        particles.flipbook_rows = 4;
+        particles.flipbook_fps = 30.0;
+        let mut emitter = snap("emitter", None, [0.0; 3]);
+        emitter.particle_data = Some(particles);
+
+        let scene = build_scene_file(
+            "Flipbook",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![emitter],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        let data = loaded.entities[0].particle_data.as_ref().expect("particle data");
+        assert_eq!(data.texture_asset_id.as_deref(), Some("explosion_sheet"));
+        assert_eq!((data.flipbook_cols, data.flipbook_rows), (8, 4));
+        assert_eq!(data.flipbook_fps, 30.0);
+        assert_eq!(data.flipbook_frame_count(), 32);
+    }
+
+    #[test]
+    fn particle_force_field_round_trips_through_scene_file() {
+        use crate::core::particles::{ForceFieldKind, ParticleForceField};
+
+        let field = ParticleForceField { kind: ForceFieldKind::Repulsor, strength: 8.0, radius: 6.5 };
+        let mut source = snap("field", None, [0.0; 3]);
+        source.particle_force_field = Some(field);
+
+        let scene = build_scene_file(
+            "Force Field",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![source],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        assert_eq!(loaded.entities[0].particle_force_field, Some(field));
+    }
+
+    #[test]
+    fn look_at_constraint_round_trips_through_scene_file() {
+        use crate::core::game_components::LookAtConstraint;
+
+        let constraint = LookAtConstraint {
+            target_entity_id: "player".to_string(),
+            up_axis: [0.0, 0.0, 1.0],
+            weight: 0.2,
+        };
+        let mut source = snap("turret", None, [0.0; 3]);
+        source.look_at_constraint = Some(constraint.clone());
+
+        let scene = build_scene_file(
+            "Turret",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![source],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        assert_eq!(loaded.entities[0].look_at_constraint, Some(constraint));
+    }
+
+    #[test]
+    fn path_round_trips_through_scene_file() {
+        use crate::core::path::PathData;
+
+        let path = PathData { points: vec![[0.0; 3], [2.0, 0.0, 0.0], [2.0, 1.0, 2.0]], closed: true };
+        let mut source = snap("patrol", None, [0.0; 3]);
+        source.entity_type = EntityType::Path;
+        source.path_data = Some(path.clone());
+
+        let scene = build_scene_file(
+            "Patrol",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![source],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        assert_eq!(loaded.entities[0].entity_type, EntityType::Path);
+        assert_eq!(loaded.entities[0].path_data, Some(path));
+    }
+
+    #[test]
+    fn instanced_array_round_trips_through_scene_file() {
+        let instances = vec![
+            TransformSnapshot::from(&Transform::IDENTITY),
+            TransformSnapshot::from(&Transform::from_xyz(0.0, 0.0, 3.0)),
+        ];
+        let mut source = snap("fence", None, [0.0; 3]);
+        source.instance_transforms = Some(instances.clone());
+
+        let scene = build_scene_file(
+            "Fence",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![source],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        assert_eq!(loaded.entities[0].instance_transforms, Some(instances));
+    }
+
+    #[test]
+    fn light_cookie_round_trips_through_scene_file() {
+        use crate::core::lighting::LightData;
+
+        let mut source = snap("spot", None, [0.0; 3]);
+        source.light_data = Some(LightData { cookie_texture: Some("gobo-window".to_string()), ..LightData::spot() });
+
+        let scene = build_scene_file(
+            "Cookie",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![source],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        let light = loaded.entities[0].light_data.as_ref().expect("light data");
+        assert_eq!(light.cookie_texture.as_deref(), Some("gobo-window"));
+    }
+
+    #[test]
+    fn baked_light_probes_round_trip_through_scene_file() {
+        use crate::core::lighting::LightProbeVolume;
+
+        let mut source = snap("probes", None, [0.0, 2.5, 0.0]);
+        source.entity_type = EntityType::LightProbeVolume;
+        let volume = LightProbeVolume {
+            baked: Some((0..27).map(|i| [[i as f32, 0.5, 0.25], [0.1; 3], [-0.1; 3], [0.0; 3]]).collect()),
+            ..LightProbeVolume::default()
+        };
+        source.light_probe_volume = Some(volume.clone());
+
+        let scene = build_scene_file(
+            "Probes",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            vec![source],
+            None,
+            None,
+        );
+        let json = serde_json::to_string(&scene).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        let entity = &loaded.entities[0];
+        assert_eq!(entity.entity_type, EntityType::LightProbeVolume);
+        let loaded_volume = entity.light_probe_volume.as_ref().expect("light probe volume");
+        assert_eq!(loaded_volume, &volume);
+        assert_eq!(loaded_volume.baked_probes().map(<[_]>::len), Some(27));
+    }
+
+    #[test]
+    fn sprite_nine_slice_round_trips_through_scene_file() {
+        use crate::core::sprite::{NineSliceBorders, SpriteData};
+
+        let borders = NineSliceBorders { left: 4.0, right: 6.0, top: 2.0, bottom: 8.0 };
+        let mut source = snap("panel", None, [0.0; 3]);
+        source.entity_type = EntityType::Sprite;
+        source.sprite_data = Some(SpriteData { nine_slice: Some(borders), ..SpriteData::default() });
+
+        let json = serde_json::to_string(&scene_with(vec![source])).expect("serialize scene");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("deserialize scene");
+
+        let sprite = loaded.entities[0].sprite_data.as_ref().expect("sprite data");
+        assert_eq!(sprite.nine_slice, Some(borders));
+    }
+
+    pub(super) fn scene_with(entities: Vec<EntitySnapshot>) -> SceneFile {
+        build_scene_file(
+            "Patch",
+            &EnvironmentSettings::default(),
+            &GlobalAmbientLight::default(),
+            &InputMap::default(),
+            HashMap::new(),
+            &PostProcessingSettings::default(),
+            &AudioBusConfig::default(),
+            entities,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn migrate_v1_scene_fills_audio_buses_and_post_processing() {
+        let json = r#"{
+            "formatVersion": 1,
+            "metadata": { "name": "Old Project" },
+            "audioBuses": { "buses": [] },
+            "entities": []
+        }"#;
+        let mut scene: SceneFile = serde_json::from_str(json).expect("parse v1 scene");
+
+        let applied = migrate_scene_file(&mut scene);
+
+        assert_eq!(applied.len(), 2);
+        assert_eq!(scene.format_version, SCENE_FORMAT_VERSION);
+        let names: Vec<&str> = scene.audio_buses.buses.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names[0], "master");
+        assert_eq!(scene.audio_buses.buses.len(), AudioBusConfig::default().buses.len());
+        assert_eq!(
+            serde_json::to_value(&scene.post_processing).unwrap(),
+            serde_json::to_value(PostProcessingSettings::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn migrate_current_scene_is_noop() {
+        let mut scene = scene_with(vec![snap("a", None, [0.0; 3])]);
+        assert!(migrate_scene_file(&mut scene).is_empty());
+        assert_eq!(scene.format_version, SCENE_FORMAT_VERSION);
+    }
+
+    #[test]
+    fn scene_round_trips_vignette() {
+        let mut scene = scene_with(vec![]);
+        scene.post_processing.vignette = Some(crate::core::post_processing::VignetteSettings {
+            intensity: 0.7,
+            radius: 0.4,
+            smoothness: 0.25,
+            color: [0.1, 0.0, 0.2, 1.0],
+        });
+
+        let json = serde_json::to_string(&scene).unwrap();
+        let restored: SceneFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.post_processing.vignette, scene.post_processing.vignette);
+    }
+
+    #[test]
+    fn binary_scene_round_trips_entities() {
+        let entities: Vec<EntitySnapshot> = (0..20)
+            .map(|i| snap(&format!("e{i}"), None, [i as f32, 0.0, 0.0]))
+            .collect();
+        let scene = scene_with(entities);
+
+        let bytes = encode_scene_binary(&scene).expect("encode");
+        let json = decode_scene_binary(&bytes, usize::MAX).expect("decode");
+        let loaded: SceneFile = serde_json::from_str(&json).expect("parse");
+
+        assert_eq!(loaded.entities.len(), scene.entities.len());
+        let names = |s: &SceneFile| s.entities.iter().map(|e| e.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&loaded), names(&scene));
+    }
+
+    #[test]
+    fn binary_scene_is_much_smaller_than_json() {
+        let entities: Vec<EntitySnapshot> = (0..500)
+            .map(|i| snap(&format!("entity-{i}"), None, [i as f32, (i % 7) as f32, 0.0]))
+            .collect();
+        let scene = scene_with(entities);
+
+        let json_len = serde_json::to_vec(&scene).unwrap().len();
+        let binary_len = encode_scene_binary(&scene).unwrap().len();
+        assert!(binary_len * 5 < json_len, "binary {binary_len} bytes vs json {json_len} bytes");
+    }
+
+    #[test]
+    fn binary_scene_decode_enforces_size_limit() {
+        let scene = scene_with(vec![snap("a", None, [0.0; 3])]);
+        let bytes = encode_scene_binary(&scene).unwrap();
+        assert!(decode_scene_binary(&bytes, 16).unwrap_err().contains("exceeds"));
+        assert!(decode_scene_binary(b"not gzip", usize::MAX).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+//! Entity-level diffs between scene files.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::SceneFile;
+use crate::core::history::EntitySnapshot;
+
+/// Entity-level difference between two scenes, for sending incremental edits
+/// instead of whole scene files. Modified entries carry the full new snapshot.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePatch {
+    #[serde(default)]
+    pub added: Vec<EntitySnapshot>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<EntitySnapshot>,
+}
+
+impl ScenePatch {
+    /// True when the patch changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Compare two scenes' entities by `entity_id`.
+///
+/// Snapshots are compared through their serialized form, so any field that
+/// round-trips through the scene file counts as a modification. Scene-level
+/// settings (environment, input, etc.) are not part of the patch.
+pub fn diff_scene(old: &SceneFile, new: &SceneFile) -> ScenePatch {
+    let old_by_id: HashMap<&str, &EntitySnapshot> =
+        old.entities.iter().map(|e| (e.entity_id.as_str(), e)).collect();
+    let new_ids: std::collections::HashSet<&str> =
+        new.entities.iter().map(|e| e.entity_id.as_str()).collect();
+
+    let mut patch = ScenePatch::default();
+    for snap in &new.entities {
+        match old_by_id.get(snap.entity_id.as_str()) {
+            None => patch.added.push(snap.clone()),
+            Some(prev) => {
+                if serde_json::to_value(prev).ok() != serde_json::to_value(snap).ok() {
+                    patch.modified.push(snap.clone());
+                }
+            }
+        }
+    }
+    patch.removed = old
+        .entities
+        .iter()
+        .filter(|e| !new_ids.contains(e.entity_id.as_str()))
+        .map(|e| e.entity_id.clone())
+        .collect();
+    patch
+}
+
+/// Apply a patch to a list of snapshots (e.g. a peer's copy of the scene).
+/// Modified entries replace the snapshot with the same id in place; added
+/// entries whose id already exists are treated as modifications.
+pub fn patch_entities(entities: &mut Vec<EntitySnapshot>, patch: &ScenePatch) {
+    entities.retain(|e| !patch.removed.contains(&e.entity_id));
+    for snap in patch.modified.iter().chain(&patch.added) {
+        match entities.iter_mut().find(|e| e.entity_id == snap.entity_id) {
+            Some(existing) => *existing = snap.clone(),
+            None => entities.push(snap.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scene_file::tests::{scene_with, snap};
+
+    #[test]
+    fn diff_scene_against_itself_is_empty() {
+        let scene = scene_with(vec![snap("a", None, [0.0; 3]), snap("b", Some("a"), [1.0, 0.0, 0.0])]);
+        assert!(diff_scene(&scene, &scene).is_empty());
+    }
+
+    #[test]
+    fn patch_moving_one_entity_updates_only_that_entity() {
+        let old = scene_with(vec![snap("a", None, [0.0; 3]), snap("b", None, [1.0, 0.0, 0.0])]);
+        let new = scene_with(vec![snap("a", None, [0.0; 3]), snap("b", None, [5.0, 2.0, 0.0])]);
+
+        let patch = diff_scene(&old, &new);
+        assert!(patch.added.is_empty() && patch.removed.is_empty());
+        assert_eq!(patch.modified.len(), 1);
+        assert_eq!(patch.modified[0].entity_id, "b");
+
+        let json = serde_json::to_string(&patch).expect("serialize patch");
+        let patch: ScenePatch = serde_json::from_str(&json).expect("deserialize patch");
+
+        let mut entities = old.entities.clone();
+        patch_entities(&mut entities, &patch);
+        assert_eq!(entities[0].transform.position, [0.0; 3]);
+        assert_eq!(entities[1].transform.position, [5.0, 2.0, 0.0]);
+        assert!(diff_scene(&scene_with(entities), &new).is_empty());
+    }
+
+    #[test]
+    fn diff_scene_reports_added_and_removed() {
+        let old = scene_with(vec![snap("a", None, [0.0; 3]), snap("b", None, [0.0; 3])]);
+        let new = scene_with(vec![snap("a", None, [0.0; 3]), snap("c", None, [0.0; 3])]);
+
+        let patch = diff_scene(&old, &new);
+        assert_eq!(patch.removed, vec!["b".to_string()]);
+        assert_eq!(patch.added.len(), 1);
+        assert_eq!(patch.added[0].entity_id, "c");
+        assert!(patch.modified.is_empty());
+    }
+}
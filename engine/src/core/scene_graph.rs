@@ -1,16 +1,25 @@
 //! Scene graph tracking and serialization.
 //!
 //! Tracks all entities with EntityId and serializes them for the React hierarchy panel.
-//! Emits SCENE_GRAPH_UPDATE events when the graph changes.
+//! The full graph is sent as SCENE_GRAPH_UPDATE when a scene is loaded (or a
+//! refresh is requested); after that, changes are sent as SCENE_GRAPH_PATCH
+//! events holding only the nodes that changed.
+//!
+//! The output is ordered deterministically: children keep their `Children`
+//! order (which reparenting controls), roots are ordered by `SortIndex`, and
+//! nodes are keyed in ID order, so the same scene always serializes the same.
 
 use bevy::prelude::*;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
+use super::entity_factory::Locked;
 use super::entity_id::{EntityId, EntityName, EntityVisible};
+use super::pending_commands::PendingCommands;
+use super::scene_file::SceneName;
 
 /// Data for a single node in the scene graph.
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SceneNodeData {
     pub entity_id: String,
@@ -19,21 +28,62 @@ pub struct SceneNodeData {
     pub children: Vec<String>,
     pub components: Vec<String>,
     pub visible: bool,
+    pub locked: bool,
+    pub sort_index: u64,
 }
 
 /// Full scene graph data sent to React.
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SceneGraphData {
-    pub nodes: HashMap<String, SceneNodeData>,
+    pub nodes: BTreeMap<String, SceneNodeData>,
     pub root_ids: Vec<String>,
 }
 
+/// Changes between two scene graphs, sent to React as SCENE_GRAPH_PATCH.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneGraphPatch {
+    /// Added nodes and nodes that changed (renamed, reparented, new children...), in full.
+    pub upserted: Vec<SceneNodeData>,
+    pub removed: Vec<String>,
+    /// The new root order, if it changed.
+    pub root_ids: Option<Vec<String>>,
+}
+
+impl SceneGraphPatch {
+    /// The patch that turns `old` into `new`.
+    pub fn diff(old: &SceneGraphData, new: &SceneGraphData) -> Self {
+        let upserted = new.nodes.iter()
+            .filter(|(id, node)| old.nodes.get(*id) != Some(*node))
+            .map(|(_, node)| node.clone())
+            .collect();
+        let removed = old.nodes.keys().filter(|id| !new.nodes.contains_key(*id)).cloned().collect();
+        let root_ids = (old.root_ids != new.root_ids).then(|| new.root_ids.clone());
+        Self { upserted, removed, root_ids }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.upserted.is_empty() && self.removed.is_empty() && self.root_ids.is_none()
+    }
+}
+
 /// Resource that caches the current scene graph.
 #[derive(Resource, Default)]
 pub struct SceneGraphCache {
     pub data: SceneGraphData,
     pub dirty: bool,
+    /// The graph as React last received it, which patches are diffed
+    /// against. `None` until the next full update is sent.
+    pub emitted: Option<SceneGraphData>,
+}
+
+impl SceneGraphCache {
+    /// Send the whole graph next time instead of a patch.
+    pub fn request_full_refresh(&mut self) {
+        self.emitted = None;
+        self.dirty = true;
+    }
 }
 
 /// Event fired when scene graph needs to be sent to React.
@@ -42,23 +92,45 @@ pub struct SceneGraphUpdateEvent {
     pub data: SceneGraphData,
 }
 
+/// Creation order of an entity, used to order the scene graph's roots. It is
+/// a component, so it moves with the entity when it is reparented.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SortIndex(pub u64);
+
+/// System that gives every new entity the next `SortIndex`. Entities created
+/// in the same frame are numbered in spawn order.
+pub fn assign_sort_indices(
+    mut commands: Commands,
+    query: Query<Entity, (With<EntityId>, Without<SortIndex>)>,
+    mut next: Local<u64>,
+) {
+    let mut new_entities: Vec<Entity> = query.iter().collect();
+    new_entities.sort_by_key(|entity| entity.index_u32());
+    for entity in new_entities {
+        commands.entity(entity).insert(SortIndex(*next));
+        *next += 1;
+    }
+}
+
 /// System that builds the scene graph from entities.
 pub fn build_scene_graph(
     query: Query<(
         Entity,
         &EntityId,
+        Option<&SortIndex>,
         Option<&EntityName>,
         Option<&EntityVisible>,
         Option<&ChildOf>,
         Option<&Children>,
+        Has<Locked>,
     )>,
     parent_query: Query<&EntityId>,
     mut cache: ResMut<SceneGraphCache>,
 ) {
-    let mut nodes = HashMap::new();
-    let mut root_ids = Vec::new();
+    let mut nodes = BTreeMap::new();
+    let mut roots = Vec::new();
 
-    for (entity, entity_id, name, visible, child_of, children) in query.iter() {
+    for (entity, entity_id, sort_index, name, visible, child_of, children, locked) in query.iter() {
         let id = entity_id.0.clone();
 
         // Get parent ID if exists
@@ -85,18 +157,47 @@ pub fn build_scene_graph(
             children: child_ids,
             components,
             visible: visible.map(|v| v.0).unwrap_or(true),
+            locked,
+            sort_index: sort_index.map_or(u64::MAX, |s| s.0),
         };
 
         // Track root nodes
         if parent_id.is_none() {
-            root_ids.push(id.clone());
+            roots.push((node.sort_index, id.clone()));
         }
 
         nodes.insert(id, node);
     }
 
+    // Query order depends on archetypes, so order roots explicitly
+    roots.sort();
+    let root_ids = roots.into_iter().map(|(_, id)| id).collect();
+
     cache.data = SceneGraphData { nodes, root_ids };
-    cache.dirty = true;
+    cache.dirty = cache.emitted.as_ref() != Some(&cache.data);
+}
+
+/// System that schedules a full scene graph update when a scene is loaded
+/// or created, since most of the graph is replaced then.
+pub fn detect_scene_replaced(
+    scene_name: Res<SceneName>,
+    mut cache: ResMut<SceneGraphCache>,
+) {
+    if scene_name.is_changed() {
+        cache.request_full_refresh();
+    }
+}
+
+/// System that processes `refresh_scene_graph` requests, used by the editor
+/// to resync its hierarchy.
+pub fn apply_scene_graph_refresh_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut cache: ResMut<SceneGraphCache>,
+) {
+    if !pending.scene_graph_refresh_requests.is_empty() {
+        pending.scene_graph_refresh_requests.clear();
+        cache.request_full_refresh();
+    }
 }
 
 /// Detect what notable components an entity has (for hierarchy icons).
@@ -155,3 +256,48 @@ pub fn detect_parent_changed(
         cache.dirty = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_named(app: &mut App, id: &str) -> Entity {
+        app.world_mut().spawn((EntityId::new(id), EntityName::new(id))).id()
+    }
+
+    fn ordering(app: &App) -> (Vec<String>, Vec<(String, Vec<String>)>) {
+        let data = &app.world().resource::<SceneGraphCache>().data;
+        let children = data.nodes.values().map(|n| (n.entity_id.clone(), n.children.clone())).collect();
+        (data.root_ids.clone(), children)
+    }
+
+    #[test]
+    fn scene_graph_ordering_is_stable_across_builds() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<SceneGraphCache>()
+            .add_systems(PostUpdate, (assign_sort_indices, build_scene_graph).chain());
+
+        let ids = ["zeta", "alpha", "mid", "child-b", "child-a"];
+        let entities: Vec<Entity> = ids.iter().map(|id| spawn_named(&mut app, id)).collect();
+        app.world_mut().entity_mut(entities[2]).add_children(&[entities[3], entities[4]]);
+        app.update();
+        let first = ordering(&app);
+        assert_eq!(first.0, vec!["zeta", "alpha", "mid"]);
+
+        // Moving entities between archetypes changes query order, not the graph
+        app.world_mut().entity_mut(entities[1]).insert(Locked);
+        app.world_mut().entity_mut(entities[3]).insert(EntityVisible(false));
+        app.update();
+        assert_eq!(ordering(&app), first);
+
+        // The sort index moves with the entity when it is reparented
+        let zeta_index = *app.world().get::<SortIndex>(entities[0]).unwrap();
+        app.world_mut().entity_mut(entities[1]).add_child(entities[0]);
+        app.update();
+        app.world_mut().entity_mut(entities[0]).remove::<ChildOf>();
+        app.update();
+        assert_eq!(app.world().get::<SortIndex>(entities[0]), Some(&zeta_index));
+        assert_eq!(ordering(&app).0, first.0);
+    }
+}
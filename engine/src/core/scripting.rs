@@ -1,9 +1,14 @@
 //! Script data component for entity scripting.
 //!
-//! Stores TypeScript source code on entities. All execution happens in JS.
+//! Stores TypeScript source code on entities and the built-in starter
+//! templates. All execution happens in JS.
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Errors kept per entity; older ones are dropped first.
+pub const MAX_SCRIPT_ERRORS_PER_ENTITY: usize = 20;
 
 /// Script data attached to an entity.
 #[derive(Component, Clone, Debug, Serialize, Deserialize)]
@@ -14,3 +19,271 @@ pub struct ScriptData {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template: Option<String>,
 }
+
+/// A runtime error the JS script runtime reported for an entity's script.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptError {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+}
+
+/// Recent script errors per entity ID, cleared when the entity's script is
+/// replaced or removed.
+#[derive(Resource, Debug, Default)]
+pub struct ScriptErrorLog {
+    errors: HashMap<String, Vec<ScriptError>>,
+}
+
+impl ScriptErrorLog {
+    pub fn record(&mut self, entity_id: &str, error: ScriptError) {
+        let errors = self.errors.entry(entity_id.to_string()).or_default();
+        if errors.len() == MAX_SCRIPT_ERRORS_PER_ENTITY {
+            errors.remove(0);
+        }
+        errors.push(error);
+    }
+
+    /// Forget an entity's errors. Returns whether there were any.
+    pub fn clear(&mut self, entity_id: &str) -> bool {
+        self.errors.remove(entity_id).is_some()
+    }
+
+    pub fn errors_for(&self, entity_id: &str) -> &[ScriptError] {
+        self.errors.get(entity_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[ScriptError])> {
+        self.errors.iter().map(|(id, errors)| (id.as_str(), errors.as_slice()))
+    }
+}
+
+/// A built-in starter script offered by `apply_script_template`.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptTemplate {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Components and scene conventions the script expects.
+    pub requires: &'static [&'static str],
+    pub source: &'static str,
+}
+
+/// Look up a built-in template by id.
+pub fn script_template(id: &str) -> Option<&'static ScriptTemplate> {
+    SCRIPT_TEMPLATES.iter().find(|template| template.id == id)
+}
+
+/// Built-in script templates, in the order the editor lists them.
+pub const SCRIPT_TEMPLATES: &[ScriptTemplate] = &[
+    ScriptTemplate {
+        id: "character_controller",
+        name: "Character Controller",
+        description: "WASD movement + jump using physics",
+        requires: &["physics (dynamic rigid body)", "input actions: move_*, jump"],
+        source: r#"const speed = 5;
+const jumpForce = 8;
+
+function onUpdate(dt) {
+  let dx = 0, dz = 0;
+  if (forge.input.isPressed("move_forward")) dz -= speed * dt;
+  if (forge.input.isPressed("move_backward")) dz += speed * dt;
+  if (forge.input.isPressed("move_left")) dx -= speed * dt;
+  if (forge.input.isPressed("move_right")) dx += speed * dt;
+
+  forge.translate(entityId, dx, 0, dz);
+
+  if (forge.input.justPressed("jump")) {
+    forge.physics.applyImpulse(entityId, 0, jumpForce, 0);
+  }
+}"#,
+    },
+    ScriptTemplate {
+        id: "collectible",
+        name: "Collectible",
+        description: "Slowly rotating pickup item",
+        requires: &[],
+        source: r#"function onUpdate(dt) {
+  forge.rotate(entityId, 0, 90 * dt, 0);
+}"#,
+    },
+    ScriptTemplate {
+        id: "rotating_object",
+        name: "Rotating Object",
+        description: "Continuous Y-axis rotation",
+        requires: &[],
+        source: r#"const speed = 45; // degrees per second
+
+function onUpdate(dt) {
+  forge.rotate(entityId, 0, speed * dt, 0);
+}"#,
+    },
+    ScriptTemplate {
+        id: "follow_camera",
+        name: "Follow Camera",
+        description: "Smoothly follows a target entity",
+        requires: &["state: followTarget (entity ID)"],
+        source: r#"const offset = { x: 0, y: 5, z: 8 };
+const smoothing = 5;
+let targetId = null;
+
+function onStart() {
+  targetId = forge.state.get("followTarget") || null;
+}
+
+function onUpdate(dt) {
+  if (!targetId) return;
+  const target = forge.getTransform(targetId);
+  const cam = forge.getTransform(entityId);
+  if (!target || !cam) return;
+
+  const t = Math.min(1, smoothing * dt);
+  forge.setPosition(entityId,
+    cam.position[0] + (target.position[0] + offset.x - cam.position[0]) * t,
+    cam.position[1] + (target.position[1] + offset.y - cam.position[1]) * t,
+    cam.position[2] + (target.position[2] + offset.z - cam.position[2]) * t
+  );
+}"#,
+    },
+    ScriptTemplate {
+        id: "patrol",
+        name: "Patrol",
+        description: "Walks between waypoint entities in order, pausing at each",
+        requires: &["entities named \"Waypoint\" (falls back to a square around the start)"],
+        source: r#"// Patrol - loops through every entity named "Waypoint"
+const SPEED = 2;        // units per second
+const PAUSE = 0.5;      // seconds to wait at each waypoint
+const ARRIVE_DISTANCE = 0.2;
+let waypoints = [];
+let current = 0;
+let waitTimer = 0;
+
+function onStart() {
+  waypoints = forge.scene.findByName("Waypoint")
+    .map((id) => forge.getTransform(id)?.position)
+    .filter(Boolean);
+  if (waypoints.length === 0) {
+    const start = forge.getTransform(entityId)?.position ?? [0, 0, 0];
+    waypoints = [[0, 0], [4, 0], [4, 4], [0, 4]]
+      .map(([x, z]) => [start[0] + x, start[1], start[2] + z]);
+  }
+}
+
+function onUpdate(dt) {
+  const pos = forge.getTransform(entityId)?.position;
+  if (!pos || waypoints.length === 0) return;
+  if (waitTimer > 0) {
+    waitTimer -= dt;
+    return;
+  }
+
+  const target = waypoints[current];
+  const dx = target[0] - pos[0];
+  const dz = target[2] - pos[2];
+  const dist = Math.sqrt(dx * dx + dz * dz);
+  if (dist < ARRIVE_DISTANCE) {
+    current = (current + 1) % waypoints.length;
+    waitTimer = PAUSE;
+    return;
+  }
+
+  const step = Math.min(SPEED * dt, dist);
+  forge.translate(entityId, (dx / dist) * step, 0, (dz / dist) * step);
+  forge.setRotation(entityId, 0, Math.atan2(-dx, -dz) * 180 / Math.PI, 0);
+}"#,
+    },
+    ScriptTemplate {
+        id: "health_pickup",
+        name: "Health Pickup",
+        description: "Restores the player's health on touch, then disappears",
+        requires: &["physics collider (sensor, so the player walks through it)", "entity named \"Player\"", "state: playerHealth, playerMaxHealth"],
+        source: r#"// Health Pickup - heals whoever is named "Player" on contact
+const HEAL_AMOUNT = 25;
+const SPIN_SPEED = 90; // degrees per second
+let used = false;
+
+function onStart() {
+  // Sensor overlaps report collision enter too
+  forge.physics.onCollisionEnter(entityId, (otherId) => {
+    if (used) return;
+    const name = forge.scene.getEntityName(otherId) || "";
+    if (name !== "Player") return;
+
+    const max = forge.state.get("playerMaxHealth") ?? 100;
+    const health = forge.state.get("playerHealth") ?? max;
+    forge.state.set("playerHealth", Math.min(max, health + HEAL_AMOUNT));
+    used = true;
+    forge.destroy(entityId);
+  });
+}
+
+function onUpdate(dt) {
+  forge.rotate(entityId, 0, SPIN_SPEED * dt, 0);
+}"#,
+    },
+    ScriptTemplate {
+        id: "door",
+        name: "Door",
+        description: "Slides open when the player comes near and closes after they leave",
+        requires: &["entity named \"Player\""],
+        source: r#"// Door - slides up when the player is within range
+const OPEN_DISTANCE = 3;
+const OPEN_HEIGHT = 3;  // how far the door rises
+const SPEED = 4;        // units per second
+let closedY = 0;
+let openAmount = 0;
+
+function onStart() {
+  closedY = forge.getTransform(entityId)?.position[1] ?? 0;
+}
+
+function onUpdate(dt) {
+  const pos = forge.getTransform(entityId)?.position;
+  if (!pos) return;
+
+  const players = forge.scene.findByNameExact("Player");
+  const near = players.length > 0 && forge.physics.distanceTo(entityId, players[0]) < OPEN_DISTANCE;
+  const goal = near ? OPEN_HEIGHT : 0;
+  const step = SPEED * dt;
+  openAmount = goal > openAmount ? Math.min(goal, openAmount + step) : Math.max(goal, openAmount - step);
+
+  forge.setPosition(entityId, pos[0], closedY + openAmount, pos[2]);
+}"#,
+    },
+    ScriptTemplate {
+        id: "projectile",
+        name: "Projectile",
+        description: "Flies forward along its facing and despawns on hit or after its lifetime",
+        requires: &["physics collider"],
+        source: r#"// Projectile - moves along its facing (-Z rotated by yaw)
+const SPEED = 15;    // units per second
+const LIFETIME = 3;  // seconds
+let direction = [0, 0, -1];
+let age = 0;
+let destroyed = false;
+
+function despawn() {
+  if (destroyed) return;
+  destroyed = true;
+  forge.destroy(entityId);
+}
+
+function onStart() {
+  const yaw = (forge.getTransform(entityId)?.rotation[1] ?? 0) * Math.PI / 180;
+  direction = [-Math.sin(yaw), 0, -Math.cos(yaw)];
+  forge.physics.onCollisionEnter(entityId, () => despawn());
+}
+
+function onUpdate(dt) {
+  if (destroyed) return;
+  age += dt;
+  if (age >= LIFETIME) {
+    despawn();
+    return;
+  }
+  forge.translate(entityId, direction[0] * SPEED * dt, direction[1] * SPEED * dt, direction[2] * SPEED * dt);
+}"#,
+    },
+];
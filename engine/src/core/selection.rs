@@ -4,7 +4,9 @@
 //! Primary selection is the last clicked entity, used for Inspector focus.
 
 use bevy::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+use super::pending_commands::{EntityType, SelectionBulkRequest, SelectionMode};
 
 /// Currently selected entities (supports multi-selection).
 #[derive(Resource, Default, Debug)]
@@ -90,6 +92,71 @@ impl Selection {
     pub fn selected_ids(&self) -> Vec<String> {
         self.entity_ids.iter().cloned().collect()
     }
+
+    /// Apply a bulk selection request against the selectable entities in the scene.
+    /// `candidates` must already exclude non-selectable entities (e.g. the editor camera);
+    /// nothing outside it is ever selected.
+    pub fn apply_bulk(
+        &mut self,
+        request: &SelectionBulkRequest,
+        candidates: &[(Entity, String, Option<EntityType>)],
+    ) {
+        match request {
+            SelectionBulkRequest::Ids { entity_ids, mode } => {
+                let by_id: HashMap<&str, Entity> = candidates
+                    .iter()
+                    .map(|(entity, id, _)| (id.as_str(), *entity))
+                    .collect();
+                if matches!(mode, SelectionMode::Replace) {
+                    self.clear();
+                }
+                for id in entity_ids {
+                    let Some(&entity) = by_id.get(id.as_str()) else {
+                        continue;
+                    };
+                    match mode {
+                        SelectionMode::Toggle => self.toggle(entity, id.clone()),
+                        SelectionMode::Replace | SelectionMode::Add => self.add(entity, id.clone()),
+                    }
+                }
+            }
+            SelectionBulkRequest::AllByType(entity_type) => {
+                self.clear();
+                for (entity, id, ty) in candidates {
+                    if *ty == Some(*entity_type) {
+                        self.add(*entity, id.clone());
+                    }
+                }
+            }
+            SelectionBulkRequest::Invert => {
+                let previous = std::mem::take(&mut self.entity_ids);
+                self.clear();
+                for (entity, id, _) in candidates {
+                    if !previous.contains(id) {
+                        self.add(*entity, id.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Viewport-space bounds of an entity's projected points (e.g. its AABB corners).
+/// Points that failed to project (behind the camera) are ignored; returns `None`
+/// when none projected, i.e. the entity is entirely behind the camera.
+pub fn projected_bounds(points: impl IntoIterator<Item = Option<Vec2>>) -> Option<Rect> {
+    points.into_iter().flatten().fold(None, |acc: Option<Rect>, p| {
+        Some(match acc {
+            Some(rect) => rect.union_point(p),
+            None => Rect::from_corners(p, p),
+        })
+    })
+}
+
+/// Whether two viewport rectangles overlap. Touching edges count, so the
+/// zero-size bounds of point-like entities (lights) can still be box-selected.
+pub fn rects_overlap(a: Rect, b: Rect) -> bool {
+    a.min.x <= b.max.x && a.max.x >= b.min.x && a.min.y <= b.max.y && a.max.y >= b.min.y
 }
 
 /// Message fired when selection changes, used to trigger bridge events.
@@ -99,3 +166,116 @@ pub struct SelectionChangedEvent {
     pub primary_id: Option<String>,
     pub primary_name: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Spawn a mixed scene and return the selectable candidates.
+    fn mixed_scene(world: &mut World) -> Vec<(Entity, String, Option<EntityType>)> {
+        let types = [
+            EntityType::Cube,
+            EntityType::PointLight,
+            EntityType::Sphere,
+            EntityType::PointLight,
+            EntityType::DirectionalLight,
+            EntityType::PointLight,
+        ];
+        types
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| (world.spawn_empty().id(), format!("e{i}"), Some(*ty)))
+            .collect()
+    }
+
+    fn sorted_ids(selection: &Selection) -> Vec<String> {
+        let mut ids = selection.selected_ids();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn select_all_by_type_selects_exactly_point_lights() {
+        let mut world = World::new();
+        let candidates = mixed_scene(&mut world);
+        let mut selection = Selection::default();
+        selection.select_one(candidates[0].0, candidates[0].1.clone());
+
+        selection.apply_bulk(&SelectionBulkRequest::AllByType(EntityType::PointLight), &candidates);
+
+        assert_eq!(sorted_ids(&selection), vec!["e1", "e3", "e5"]);
+        assert_eq!(selection.count(), 3);
+        assert!(selection.primary_id.as_deref().is_some_and(|id| ["e1", "e3", "e5"].contains(&id)));
+    }
+
+    #[test]
+    fn invert_selection_selects_complement() {
+        let mut world = World::new();
+        let candidates = mixed_scene(&mut world);
+        let mut selection = Selection::default();
+        selection.add(candidates[1].0, candidates[1].1.clone());
+        selection.add(candidates[2].0, candidates[2].1.clone());
+
+        selection.apply_bulk(&SelectionBulkRequest::Invert, &candidates);
+
+        assert_eq!(sorted_ids(&selection), vec!["e0", "e3", "e4", "e5"]);
+        assert!(!selection.is_selected(candidates[1].0));
+    }
+
+    #[test]
+    fn invert_selection_never_selects_entities_outside_candidates() {
+        let mut world = World::new();
+        let candidates = mixed_scene(&mut world);
+        // Selected but not selectable (e.g. stale entry for the editor camera)
+        let camera = world.spawn_empty().id();
+        let mut selection = Selection::default();
+        selection.add(camera, "camera".to_string());
+
+        selection.apply_bulk(&SelectionBulkRequest::Invert, &candidates);
+
+        assert_eq!(selection.count(), candidates.len());
+        assert!(!selection.is_id_selected("camera"));
+    }
+
+    #[test]
+    fn projected_bounds_skips_points_behind_camera() {
+        let bounds = projected_bounds([Some(Vec2::new(10.0, 5.0)), None, Some(Vec2::new(2.0, 30.0))])
+            .expect("two points projected");
+        assert_eq!(bounds.min, Vec2::new(2.0, 5.0));
+        assert_eq!(bounds.max, Vec2::new(10.0, 30.0));
+    }
+
+    #[test]
+    fn projected_bounds_is_none_when_fully_behind_camera() {
+        assert!(projected_bounds([None, None, None]).is_none());
+    }
+
+    #[test]
+    fn rects_overlap_handles_partial_containment_and_points() {
+        let marquee = Rect::new(0.0, 0.0, 100.0, 100.0);
+        assert!(rects_overlap(Rect::new(90.0, 90.0, 150.0, 150.0), marquee), "partial overlap");
+        assert!(rects_overlap(Rect::new(10.0, 10.0, 20.0, 20.0), marquee), "fully inside");
+        assert!(rects_overlap(Rect::new(-10.0, -10.0, 200.0, 200.0), marquee), "covers marquee");
+        assert!(rects_overlap(Rect::new(50.0, 50.0, 50.0, 50.0), marquee), "zero-size point");
+        assert!(!rects_overlap(Rect::new(101.0, 0.0, 120.0, 20.0), marquee), "disjoint");
+    }
+
+    #[test]
+    fn bulk_ids_replace_skips_unknown_ids() {
+        let mut world = World::new();
+        let candidates = mixed_scene(&mut world);
+        let mut selection = Selection::default();
+        selection.add(candidates[0].0, candidates[0].1.clone());
+
+        selection.apply_bulk(
+            &SelectionBulkRequest::Ids {
+                entity_ids: vec!["e2".into(), "missing".into(), "e4".into()],
+                mode: SelectionMode::Replace,
+            },
+            &candidates,
+        );
+
+        assert_eq!(sorted_ids(&selection), vec!["e2", "e4"]);
+        assert_eq!(selection.primary_id.as_deref(), Some("e4"));
+    }
+}
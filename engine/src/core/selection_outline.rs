@@ -0,0 +1,313 @@
+//! Viewport outline around selected entities.
+//!
+//! Every selected entity is tagged with `SelectionOutline`. Selected meshes
+//! get an outline shell: a separate entity drawing the same mesh with
+//! `OutlineMaterial`, which culls front faces and pushes vertices out along
+//! their normals by a fixed number of pixels in clip space, leaving a colored
+//! silhouette around the object at any zoom level. The primary selection uses
+//! its own color. Outlines are editor-only and hidden outside Edit mode.
+
+use bevy::prelude::*;
+use bevy::asset::uuid_handle;
+use bevy::mesh::MeshVertexBufferLayoutRef;
+use bevy::pbr::{Material, MaterialPipeline, MaterialPipelineKey, MaterialPlugin};
+use bevy::picking::Pickable;
+use bevy::render::render_resource::{AsBindGroup, Face, RenderPipelineDescriptor, SpecializedMeshPipelineError};
+use bevy::shader::{Shader, ShaderRef};
+use bevy::transform::TransformSystems;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::engine_mode::EngineMode;
+use super::pending_commands::PendingCommands;
+use super::selection::Selection;
+
+/// Stable handle for the outline shader, registered via include_str! like
+/// the forge effects shader.
+const FORGE_OUTLINE_SHADER_HANDLE: Handle<Shader> = uuid_handle!("f09eeffc-e750-4001-a000-000000000005");
+
+/// Outline colors and width, set by the `set_outline_color` command.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlineSettings {
+    pub primary_color: [f32; 4],
+    pub secondary_color: [f32; 4],
+    /// Outline width in pixels.
+    pub thickness: f32,
+}
+
+impl Default for OutlineSettings {
+    fn default() -> Self {
+        Self {
+            primary_color: [1.0, 0.55, 0.1, 1.0],
+            secondary_color: [0.3, 0.65, 1.0, 1.0],
+            thickness: 3.0,
+        }
+    }
+}
+
+impl OutlineSettings {
+    pub fn material(&self, primary: bool) -> OutlineMaterial {
+        let color = if primary { self.primary_color } else { self.secondary_color };
+        OutlineMaterial {
+            color: LinearRgba::from_f32_array(color),
+            thickness: self.thickness.max(0.0),
+        }
+    }
+}
+
+/// Marks an entity as selected for outlining.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionOutline {
+    pub primary: bool,
+}
+
+/// An outline shell drawn around `source`. Shells are standalone entities
+/// (not children) so they never show up in the scene hierarchy or exports.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OutlineShell {
+    pub source: Entity,
+}
+
+/// Unlit, front-face-culled material that inflates the mesh in screen space.
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct OutlineMaterial {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    /// Outline width in pixels.
+    #[uniform(0)]
+    pub thickness: f32,
+}
+
+impl Material for OutlineMaterial {
+    fn vertex_shader() -> ShaderRef {
+        FORGE_OUTLINE_SHADER_HANDLE.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        FORGE_OUTLINE_SHADER_HANDLE.into()
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // Only the inflated back faces remain, so the object itself covers
+        // the shell everywhere except the rim.
+        descriptor.primitive.cull_mode = Some(Face::Front);
+        Ok(())
+    }
+}
+
+/// Editor plugin that draws selection outlines.
+pub struct SelectionOutlinePlugin;
+
+impl Plugin for SelectionOutlinePlugin {
+    fn build(&self, app: &mut App) {
+        if let Err(err) = app.world_mut()
+            .resource_mut::<Assets<Shader>>()
+            .insert(
+                FORGE_OUTLINE_SHADER_HANDLE.id(),
+                Shader::from_wgsl(include_str!("../shaders/forge_outline.wgsl"), "shaders/forge_outline.wgsl"),
+            )
+        {
+            tracing::warn!("Failed to register forge outline shader: {err}");
+        }
+
+        app.add_plugins(MaterialPlugin::<OutlineMaterial>::default())
+            .init_resource::<OutlineSettings>()
+            .add_systems(Update, (
+                apply_outline_settings_updates,
+                sync_selection_outlines,
+                sync_outline_shells,
+            ).chain())
+            .add_systems(PostUpdate, follow_outline_sources.after(TransformSystems::Propagate));
+    }
+}
+
+/// System that applies pending set_outline_color requests.
+pub fn apply_outline_settings_updates(
+    mut pending: ResMut<PendingCommands>,
+    mut settings: ResMut<OutlineSettings>,
+) {
+    for update in pending.outline_settings_updates.drain(..) {
+        if let Some(color) = update.primary_color {
+            settings.primary_color = color;
+        }
+        if let Some(color) = update.secondary_color {
+            settings.secondary_color = color;
+        }
+        if let Some(thickness) = update.thickness {
+            settings.thickness = thickness.max(0.0);
+        }
+    }
+}
+
+/// System that tags selected entities with `SelectionOutline` and untags
+/// everything else. Nothing is outlined outside Edit mode.
+pub fn sync_selection_outlines(
+    mut commands: Commands,
+    selection: Res<Selection>,
+    mode: Res<EngineMode>,
+    outlined: Query<(Entity, &SelectionOutline)>,
+    entities: Query<(), Without<OutlineShell>>,
+) {
+    if !selection.is_changed() && !mode.is_changed() {
+        return;
+    }
+
+    let wanted: HashMap<Entity, SelectionOutline> = if mode.is_edit() {
+        selection.entities.iter()
+            .filter(|entity| entities.contains(**entity))
+            .map(|&entity| (entity, SelectionOutline { primary: selection.primary == Some(entity) }))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    for (entity, outline) in outlined.iter() {
+        if !wanted.contains_key(&entity) {
+            commands.entity(entity).remove::<SelectionOutline>();
+        } else if wanted[&entity] != *outline {
+            commands.entity(entity).insert(wanted[&entity]);
+        }
+    }
+    for (&entity, &outline) in &wanted {
+        if !outlined.contains(entity) {
+            commands.entity(entity).insert(outline);
+        }
+    }
+}
+
+/// System that spawns, updates and despawns outline shells to match the
+/// outlined meshes.
+pub fn sync_outline_shells(
+    mut commands: Commands,
+    settings: Res<OutlineSettings>,
+    mut materials: ResMut<Assets<OutlineMaterial>>,
+    mut handles: Local<Option<[Handle<OutlineMaterial>; 2]>>,
+    outlined: Query<(Entity, &SelectionOutline, &Mesh3d)>,
+    shells: Query<(Entity, &OutlineShell, &Mesh3d, &MeshMaterial3d<OutlineMaterial>)>,
+) {
+    let [primary, secondary] = handles
+        .get_or_insert_with(|| [materials.add(settings.material(true)), materials.add(settings.material(false))])
+        .clone();
+    if settings.is_changed() {
+        for (handle, is_primary) in [(&primary, true), (&secondary, false)] {
+            if let Some(material) = materials.get_mut(handle) {
+                *material = settings.material(is_primary);
+            }
+        }
+    }
+    let material_for = |outline: &SelectionOutline| if outline.primary { primary.clone() } else { secondary.clone() };
+
+    let mut shell_of = HashMap::new();
+    for (shell, link, mesh, material) in shells.iter() {
+        match outlined.get(link.source) {
+            Ok((_, outline, source_mesh)) if shell_of.insert(link.source, shell).is_none() => {
+                if mesh.0 != source_mesh.0 {
+                    commands.entity(shell).insert(Mesh3d(source_mesh.0.clone()));
+                }
+                let wanted = material_for(outline);
+                if material.0 != wanted {
+                    commands.entity(shell).insert(MeshMaterial3d(wanted));
+                }
+            }
+            _ => commands.entity(shell).despawn(),
+        }
+    }
+
+    for (source, outline, mesh) in outlined.iter() {
+        if shell_of.contains_key(&source) {
+            continue;
+        }
+        commands.spawn((
+            Name::new("Selection Outline"),
+            OutlineShell { source },
+            Mesh3d(mesh.0.clone()),
+            MeshMaterial3d(material_for(outline)),
+            Transform::default(),
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+/// System that keeps each shell on top of its source, including its
+/// visibility, after transform propagation.
+pub fn follow_outline_sources(
+    mut shells: Query<(&OutlineShell, &mut Transform, &mut GlobalTransform, &mut Visibility)>,
+    sources: Query<(&GlobalTransform, &InheritedVisibility), Without<OutlineShell>>,
+) {
+    for (link, mut transform, mut global, mut visibility) in shells.iter_mut() {
+        let Ok((source_global, source_visible)) = sources.get(link.source) else {
+            continue;
+        };
+        transform.set_if_neq(source_global.compute_transform());
+        global.set_if_neq(*source_global);
+        visibility.set_if_neq(if source_visible.get() { Visibility::Inherited } else { Visibility::Hidden });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Selection>()
+            .init_resource::<EngineMode>()
+            .add_systems(Update, sync_selection_outlines);
+        app
+    }
+
+    #[test]
+    fn outlines_follow_the_selection() {
+        let mut app = app();
+        let a = app.world_mut().spawn_empty().id();
+        let b = app.world_mut().spawn_empty().id();
+        app.update();
+        assert!(app.world().get::<SelectionOutline>(a).is_none());
+
+        {
+            let mut selection = app.world_mut().resource_mut::<Selection>();
+            selection.select_one(a, "a".to_string());
+            selection.add(b, "b".to_string());
+        }
+        app.update();
+        assert_eq!(app.world().get::<SelectionOutline>(a), Some(&SelectionOutline { primary: false }));
+        assert_eq!(app.world().get::<SelectionOutline>(b), Some(&SelectionOutline { primary: true }));
+
+        app.world_mut().resource_mut::<Selection>().clear();
+        app.update();
+        assert!(app.world().get::<SelectionOutline>(a).is_none());
+        assert!(app.world().get::<SelectionOutline>(b).is_none());
+    }
+
+    #[test]
+    fn outlines_hide_in_play_mode() {
+        let mut app = app();
+        let a = app.world_mut().spawn_empty().id();
+        app.world_mut().resource_mut::<Selection>().select_one(a, "a".to_string());
+        app.update();
+        assert!(app.world().get::<SelectionOutline>(a).is_some());
+
+        *app.world_mut().resource_mut::<EngineMode>() = EngineMode::Play;
+        app.update();
+        assert!(app.world().get::<SelectionOutline>(a).is_none());
+
+        *app.world_mut().resource_mut::<EngineMode>() = EngineMode::Edit;
+        app.update();
+        assert_eq!(app.world().get::<SelectionOutline>(a), Some(&SelectionOutline { primary: true }));
+    }
+}
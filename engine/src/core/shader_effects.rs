@@ -9,6 +9,7 @@ use bevy::pbr::{ExtendedMaterial, MaterialExtension};
 use bevy::render::render_resource::AsBindGroup;
 use bevy::shader::{Shader, ShaderRef};
 use serde::{Deserialize, Serialize};
+use crate::core::material::MaterialData;
 
 /// Stable handle for the forge effects shader, registered manually to avoid
 /// `embedded_asset!` panicking on Windows due to backslash path separators.
@@ -175,6 +176,14 @@ pub struct ForgeShaderExtension {
     /// Used by both built-in effects and custom mega-shader slots.
     #[uniform(100)]
     pub time: f32,
+
+    /// 1 = sample the base color texture by world-space triplanar projection.
+    /// Mirrors `MaterialData::triplanar`.
+    #[uniform(100)]
+    pub triplanar: u32,
+    /// Texture repeats per world unit for triplanar projection.
+    #[uniform(100)]
+    pub triplanar_scale: f32,
 }
 
 impl Default for ForgeShaderExtension {
@@ -212,10 +221,39 @@ impl From<&ShaderEffectData> for ForgeShaderExtension {
             custom_params_2: Vec4::ZERO,
             custom_params_3: Vec4::ZERO,
             time: 0.0,
+            // Triplanar is a material property; callers copy it in via set_triplanar.
+            triplanar: 0,
+            triplanar_scale: 1.0,
         }
     }
 }
 
+impl ForgeShaderExtension {
+    /// Copy the triplanar projection settings from the entity's MaterialData.
+    pub fn set_triplanar(&mut self, data: &MaterialData) {
+        self.triplanar = u32::from(data.triplanar);
+        self.triplanar_scale = data.triplanar_scale;
+    }
+}
+
+/// Swap an entity's StandardMaterial for a ForgeMaterial wrapping a copy of it.
+/// Returns the new handle, or None if the StandardMaterial asset is not available yet.
+pub fn upgrade_to_forge_material(
+    commands: &mut Commands,
+    entity: Entity,
+    std_handle: &MeshMaterial3d<StandardMaterial>,
+    std_materials: &Assets<StandardMaterial>,
+    ext_materials: &mut Assets<ForgeMaterial>,
+    extension: ForgeShaderExtension,
+) -> Option<Handle<ForgeMaterial>> {
+    let std_mat = std_materials.get(std_handle.0.id())?;
+    let ext_handle = ext_materials.add(ForgeMaterial { base: std_mat.clone(), extension });
+    commands.entity(entity)
+        .remove::<MeshMaterial3d<StandardMaterial>>()
+        .insert(MeshMaterial3d(ext_handle.clone()));
+    Some(ext_handle)
+}
+
 impl MaterialExtension for ForgeShaderExtension {
     fn fragment_shader() -> ShaderRef {
         FORGE_EFFECTS_SHADER_HANDLE.into()
@@ -253,3 +291,57 @@ impl Plugin for ShaderEffectsPlugin {
         info!("Shader effects plugin initialized");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn set_triplanar_copies_material_settings() {
+        let data = MaterialData { triplanar: true, triplanar_scale: 0.25, ..Default::default() };
+        let mut ext = ForgeShaderExtension::default();
+        assert_eq!(ext.triplanar, 0);
+        ext.set_triplanar(&data);
+        assert_eq!(ext.triplanar, 1);
+        assert_eq!(ext.triplanar_scale, 0.25);
+    }
+
+    #[test]
+    fn triplanar_material_is_swapped_to_forge_material() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<StandardMaterial>()
+            .init_asset::<ForgeMaterial>();
+
+        let std_handle = app.world_mut()
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial::default());
+        let data = MaterialData { triplanar: true, triplanar_scale: 2.0, ..Default::default() };
+        let entity = app.world_mut().spawn((data, MeshMaterial3d(std_handle))).id();
+
+        app.world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 query: Query<(Entity, &MaterialData, &MeshMaterial3d<StandardMaterial>)>,
+                 std_materials: Res<Assets<StandardMaterial>>,
+                 mut ext_materials: ResMut<Assets<ForgeMaterial>>| {
+                    for (entity, data, handle) in query.iter() {
+                        let mut extension = ForgeShaderExtension::default();
+                        extension.set_triplanar(data);
+                        assert!(upgrade_to_forge_material(
+                            &mut commands, entity, handle, &std_materials, &mut ext_materials, extension,
+                        ).is_some());
+                    }
+                },
+            )
+            .expect("upgrade system should run");
+
+        let world = app.world();
+        assert!(world.get::<MeshMaterial3d<StandardMaterial>>(entity).is_none());
+        let handle = world.get::<MeshMaterial3d<ForgeMaterial>>(entity).expect("ForgeMaterial handle");
+        let ext_mat = world.resource::<Assets<ForgeMaterial>>().get(handle).expect("ForgeMaterial asset");
+        assert_eq!(ext_mat.extension.triplanar, 1);
+        assert_eq!(ext_mat.extension.triplanar_scale, 2.0);
+    }
+}
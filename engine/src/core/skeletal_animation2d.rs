@@ -1,12 +1,16 @@
 //! 2D Skeletal animation system.
 //!
 //! This module provides keyframe animation for 2D skeletons,
-//! including animation clips, playback, and blending.
+//! including animation clips, playback, blending, and IK solving.
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::entity_id::EntityId;
+use super::pending_commands::PendingCommands;
+use super::skeleton2d::{compute_bone_world_transforms, Bone2dDef, SkeletonData2d};
+
 /// Component containing a skeletal animation clip.
 #[derive(Component, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -76,3 +80,304 @@ impl Default for EasingType2d {
         Self::Linear
     }
 }
+
+// ========== Keyframe Sampling ==========
+
+impl SkeletalAnimation2d {
+    /// Pose `bones` at time `t`, interpolating every bone that has a track.
+    pub fn sample(&self, bones: &mut [Bone2dDef], t: f32) {
+        for bone in bones.iter_mut() {
+            if let Some(keyframes) = self.tracks.get(&bone.name) {
+                if keyframes.is_empty() {
+                    continue;
+                }
+                interpolate_bone(bone, keyframes, t);
+            }
+        }
+    }
+}
+
+/// Interpolate bone properties from keyframes at the given time.
+pub fn interpolate_bone(bone: &mut Bone2dDef, keyframes: &[BoneKeyframe], t: f32) {
+    // Find the two keyframes surrounding `t`
+    let mut prev_idx = 0;
+    for (i, kf) in keyframes.iter().enumerate() {
+        if kf.time <= t {
+            prev_idx = i;
+        }
+    }
+
+    let prev = &keyframes[prev_idx];
+    let next_idx = if prev_idx + 1 < keyframes.len() {
+        prev_idx + 1
+    } else if keyframes.len() > 1 {
+        0 // wrap for looping
+    } else {
+        prev_idx // single keyframe
+    };
+    let next = &keyframes[next_idx];
+
+    let alpha = if (next.time - prev.time).abs() < 1e-6 {
+        0.0
+    } else {
+        ((t - prev.time) / (next.time - prev.time)).clamp(0.0, 1.0)
+    };
+
+    let eased = apply_easing(alpha, prev.easing);
+
+    if let (Some(p0), Some(p1)) = (prev.position, next.position) {
+        // Preserve Z from current bone — only interpolate XY from 2D keyframes.
+        bone.local_position = [
+            p0[0] + (p1[0] - p0[0]) * eased,
+            p0[1] + (p1[1] - p0[1]) * eased,
+            bone.local_position[2],
+        ];
+    } else if let Some(p) = prev.position {
+        bone.local_position = [p[0], p[1], bone.local_position[2]];
+    }
+
+    if let (Some(r0), Some(r1)) = (prev.rotation, next.rotation) {
+        bone.local_rotation = r0 + (r1 - r0) * eased;
+    } else if let Some(r) = prev.rotation {
+        bone.local_rotation = r;
+    }
+
+    if let (Some(s0), Some(s1)) = (prev.scale, next.scale) {
+        bone.local_scale = [
+            s0[0] + (s1[0] - s0[0]) * eased,
+            s0[1] + (s1[1] - s0[1]) * eased,
+        ];
+    } else if let Some(s) = prev.scale {
+        bone.local_scale = s;
+    }
+}
+
+pub fn apply_easing(t: f32, easing: EasingType2d) -> f32 {
+    match easing {
+        EasingType2d::Linear => t,
+        EasingType2d::EaseIn => t * t,
+        EasingType2d::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+        EasingType2d::EaseInOut => {
+            if t < 0.5 {
+                2.0 * t * t
+            } else {
+                1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+            }
+        }
+        EasingType2d::Step => if t >= 1.0 { 1.0 } else { 0.0 },
+    }
+}
+
+// ========== Inverse Kinematics ==========
+
+/// Maximum CCD passes over a chain per frame.
+pub const IK_MAX_ITERATIONS: usize = 32;
+/// Distance (skeleton units) at which the end effector counts as on target.
+pub const IK_TOLERANCE: f32 = 0.01;
+
+/// Resolve a constraint's bone chain to bone indices, root first. The last
+/// name is the tip; the chain is walked up through parents for as many bones
+/// as the constraint lists.
+pub fn resolve_ik_chain(bones: &[Bone2dDef], bone_chain: &[String]) -> Option<Vec<usize>> {
+    let index_of = |name: &str| bones.iter().position(|b| b.name == name);
+    let mut current = index_of(bone_chain.last()?)?;
+    let mut chain = vec![current];
+    while chain.len() < bone_chain.len() {
+        let Some(parent) = bones[current].parent_bone.as_deref().and_then(index_of) else { break };
+        current = parent;
+        chain.push(current);
+    }
+    chain.reverse();
+    Some(chain)
+}
+
+/// Skeleton-space position of the tip of `bone`.
+pub fn bone_tip(bones: &[Bone2dDef], bone: usize) -> Vec2 {
+    let world = compute_bone_world_transforms(bones);
+    let (pos, rot, _) = world.get(&bones[bone].name).copied().unwrap_or((Vec2::ZERO, 0.0, Vec2::ONE));
+    pos + Vec2::from_angle(rot.to_radians()) * bones[bone].length
+}
+
+pub(crate) fn wrap_degrees(angle: f32) -> f32 {
+    (angle + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Rotate the bones of `chain` (root first) with cyclic coordinate descent so
+/// the tip of the last bone reaches `target`, honouring each bone's angle
+/// limits. Returns the remaining distance to the target.
+pub fn solve_ik_chain_ccd(bones: &mut [Bone2dDef], chain: &[usize], target: Vec2) -> f32 {
+    let Some(&tip) = chain.last() else { return f32::INFINITY };
+    for _ in 0..IK_MAX_ITERATIONS {
+        if bone_tip(bones, tip).distance(target) <= IK_TOLERANCE {
+            break;
+        }
+        for &bone in chain.iter().rev() {
+            let world = compute_bone_world_transforms(bones);
+            let joint = world.get(&bones[bone].name).map(|t| t.0).unwrap_or(Vec2::ZERO);
+            let to_tip = bone_tip(bones, tip) - joint;
+            let to_target = target - joint;
+            if to_tip.length_squared() < f32::EPSILON || to_target.length_squared() < f32::EPSILON {
+                continue;
+            }
+
+            let bone = &mut bones[bone];
+            let mut rotation = wrap_degrees(bone.local_rotation + to_tip.angle_to(to_target).to_degrees());
+            if let Some([min, max]) = bone.angle_limits {
+                rotation = rotation.clamp(min.min(max), min.max(max));
+            }
+            bone.local_rotation = rotation;
+        }
+    }
+    bone_tip(bones, tip).distance(target)
+}
+
+/// System that moves IK chain targets from pending set_ik_target2d requests.
+pub fn apply_set_ik_target2d_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut skeleton_query: Query<(&EntityId, &mut SkeletonData2d)>,
+) {
+    for request in pending.set_ik_target2d_requests.drain(..) {
+        let Some((_, mut skeleton)) = skeleton_query.iter_mut().find(|(eid, _)| eid.0 == request.entity_id) else {
+            continue;
+        };
+        let Some(constraint) = skeleton.ik_constraints.iter_mut().find(|c| c.name == request.chain_name) else {
+            tracing::warn!("set_ik_target2d: no IK chain '{}' on {}", request.chain_name, request.entity_id);
+            continue;
+        };
+        constraint.target_position = request.target_position;
+        if let Some(target_entity_id) = request.target_entity_id {
+            constraint.target_entity_id = target_entity_id;
+        }
+    }
+}
+
+/// System that solves every IK constraint toward its target each frame,
+/// blending the solved rotations with the current pose by the constraint's mix.
+/// Entity targets are mapped into the skeleton's space.
+pub fn solve_ik_constraints_2d(
+    mut skeleton_query: Query<(&EntityId, &mut SkeletonData2d, &Transform)>,
+    target_query: Query<(&EntityId, &Transform)>,
+) {
+    for (_, mut skeleton, skeleton_transform) in skeleton_query.iter_mut() {
+        if skeleton.ik_constraints.is_empty() {
+            continue;
+        }
+        let to_skeleton = skeleton_transform.compute_affine().inverse();
+        let mut bones = skeleton.bones.clone();
+
+        for constraint in &skeleton.ik_constraints {
+            if constraint.mix <= 0.0 {
+                continue;
+            }
+            let target = match constraint.target_position {
+                Some(position) => Vec2::from(position),
+                None => {
+                    let Some((_, target)) = target_query.iter().find(|(eid, _)| eid.0 == constraint.target_entity_id) else {
+                        continue;
+                    };
+                    to_skeleton.transform_point3(target.translation).truncate()
+                }
+            };
+            let Some(chain) = resolve_ik_chain(&bones, &constraint.bone_chain) else {
+                continue;
+            };
+
+            let fk: Vec<f32> = chain.iter().map(|&i| bones[i].local_rotation).collect();
+            solve_ik_chain_ccd(&mut bones, &chain, target);
+            let mix = constraint.mix.min(1.0);
+            for (&i, fk_rotation) in chain.iter().zip(fk) {
+                bones[i].local_rotation = fk_rotation + wrap_degrees(bones[i].local_rotation - fk_rotation) * mix;
+            }
+        }
+
+        // Only write back when the pose moved, so a settled chain doesn't
+        // flag the skeleton as changed every frame.
+        let moved = bones.iter().zip(&skeleton.bones)
+            .any(|(solved, current)| (solved.local_rotation - current.local_rotation).abs() > 1e-4);
+        if moved {
+            skeleton.bones = bones;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending_commands::SetIkTarget2dRequest;
+    use crate::core::skeleton2d::IkConstraint2d;
+
+    fn bone(name: &str, parent: Option<&str>, length: f32) -> Bone2dDef {
+        Bone2dDef {
+            name: name.to_string(),
+            parent_bone: parent.map(str::to_string),
+            local_position: [0.0; 3],
+            local_rotation: 0.0,
+            local_scale: [1.0, 1.0],
+            length,
+            color: [1.0; 4],
+            angle_limits: None,
+        }
+    }
+
+    fn leg() -> Vec<Bone2dDef> {
+        vec![bone("root", None, 0.0), bone("thigh", Some("root"), 50.0), bone("shin", Some("thigh"), 40.0)]
+    }
+
+    #[test]
+    fn two_bone_chain_reaches_target_in_range() {
+        let mut bones = leg();
+        let chain = resolve_ik_chain(&bones, &["thigh".to_string(), "shin".to_string()]).unwrap();
+        assert_eq!(chain, vec![1, 2]);
+
+        let target = Vec2::new(30.0, -60.0);
+        let error = solve_ik_chain_ccd(&mut bones, &chain, target);
+        assert!(error <= IK_TOLERANCE, "error {}", error);
+        assert!(bone_tip(&bones, 2).distance(target) <= IK_TOLERANCE);
+    }
+
+    #[test]
+    fn ik_respects_angle_limits() {
+        let mut bones = leg();
+        bones[2].angle_limits = Some([-30.0, 30.0]);
+        // Reaching this close to the hip needs a knee bend well past 30 degrees
+        let target = Vec2::new(20.0, 0.0);
+        let error = solve_ik_chain_ccd(&mut bones, &[1, 2], target);
+        assert!(error > 1.0);
+        assert!((-30.0..=30.0).contains(&bones[2].local_rotation), "knee at {}", bones[2].local_rotation);
+    }
+
+    #[test]
+    fn set_ik_target_moves_the_end_effector() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .add_systems(Update, (apply_set_ik_target2d_requests, solve_ik_constraints_2d).chain());
+
+        let skeleton = SkeletonData2d {
+            bones: leg(),
+            ik_constraints: vec![IkConstraint2d {
+                name: "leg".to_string(),
+                bone_chain: vec!["shin".to_string(), "shin".to_string()],
+                target_entity_id: String::new(),
+                target_position: None,
+                bend_direction: 1.0,
+                mix: 1.0,
+            }],
+            ..Default::default()
+        };
+        let entity = app.world_mut()
+            .spawn((EntityId("hero".to_string()), skeleton, Transform::from_xyz(100.0, 0.0, 0.0)))
+            .id();
+
+        app.world_mut().resource_mut::<PendingCommands>().set_ik_target2d_requests.push(SetIkTarget2dRequest {
+            entity_id: "hero".to_string(),
+            chain_name: "leg".to_string(),
+            target_position: Some([10.0, -70.0]),
+            target_entity_id: None,
+        });
+        app.update();
+
+        let bones = &app.world().get::<SkeletonData2d>(entity).unwrap().bones;
+        assert!(bone_tip(bones, 2).distance(Vec2::new(10.0, -70.0)) <= IK_TOLERANCE);
+    }
+}
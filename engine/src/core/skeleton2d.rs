@@ -30,6 +30,9 @@ pub struct Bone2dDef {
     pub local_scale: [f32; 2],
     pub length: f32,
     pub color: [f32; 4],
+    /// Allowed `local_rotation` range `[min, max]` in degrees, enforced by IK.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub angle_limits: Option<[f32; 2]>,
 }
 
 /// A slot connects a bone to a visual attachment.
@@ -97,6 +100,9 @@ pub struct IkConstraint2d {
     pub name: String,
     pub bone_chain: Vec<String>, // 2 bones (e.g., ["upper_arm", "forearm"])
     pub target_entity_id: String, // EntityId of target (UUID string)
+    /// Fixed target in skeleton space; takes precedence over `target_entity_id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_position: Option<[f32; 2]>,
     pub bend_direction: f32,     // +1.0 or -1.0
     pub mix: f32,                // 0.0 = FK, 1.0 = IK
 }
@@ -143,6 +149,52 @@ pub struct BoneWorldTransforms2d {
     pub transforms: Vec<(Vec2, f32, Vec2)>,
 }
 
+/// Compute world-space bone transforms from the skeleton hierarchy.
+pub fn compute_bone_world_transforms(bones: &[Bone2dDef]) -> HashMap<String, (Vec2, f32, Vec2)> {
+    let mut world_transforms: HashMap<String, (Vec2, f32, Vec2)> = HashMap::new();
+
+    // Build parent index for efficient lookup
+    let bone_index: HashMap<&str, usize> = bones
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b.name.as_str(), i))
+        .collect();
+
+    // Process bones in order (parents before children assumed by convention)
+    for bone in bones {
+        let (parent_pos, parent_rot) = if let Some(ref parent_name) = bone.parent_bone {
+            if let Some(&(pos, rot, _scale)) = world_transforms.get(parent_name.as_str()) {
+                // Offset along parent bone's direction by parent length
+                let parent_bone = &bones[bone_index[parent_name.as_str()]];
+                let parent_rad = rot.to_radians();
+                let end_pos = pos + Vec2::new(
+                    parent_bone.length * parent_rad.cos(),
+                    parent_bone.length * parent_rad.sin(),
+                );
+                (end_pos, rot)
+            } else {
+                (Vec2::ZERO, 0.0)
+            }
+        } else {
+            (Vec2::ZERO, 0.0)
+        };
+
+        let world_rot = parent_rot + bone.local_rotation;
+        let rot_rad = parent_rot.to_radians();
+        let local_offset = Vec2::new(bone.local_position[0], bone.local_position[1]);
+        let rotated_offset = Vec2::new(
+            local_offset.x * rot_rad.cos() - local_offset.y * rot_rad.sin(),
+            local_offset.x * rot_rad.sin() + local_offset.y * rot_rad.cos(),
+        );
+        let world_pos = parent_pos + rotated_offset;
+        let world_scale = Vec2::new(bone.local_scale[0], bone.local_scale[1]);
+
+        world_transforms.insert(bone.name.clone(), (world_pos, world_rot, world_scale));
+    }
+
+    world_transforms
+}
+
 impl Default for SkeletonData2d {
     fn default() -> Self {
         Self {
@@ -154,6 +206,7 @@ impl Default for SkeletonData2d {
                 local_scale: [1.0, 1.0],
                 length: 50.0,
                 color: [1.0, 1.0, 1.0, 1.0],
+                angle_limits: None,
             }],
             slots: vec![],
             skins: HashMap::from([(
@@ -1,12 +1,31 @@
 //! Grid snapping system for precise object placement.
 //!
 //! Provides configurable snapping for translate, rotate, and scale operations
-//! when the Ctrl key modifier is held during gizmo manipulation.
+//! when the Ctrl key modifier is held during gizmo manipulation. Translate
+//! drags can also snap the pivot to a vertex or surface under the cursor.
+//! Translation snaps per axis, so grids can have different cell sizes along
+//! X, Y and Z.
 
+use std::collections::HashMap;
+
+use bevy::mesh::VertexAttributeValues;
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 use serde::{Deserialize, Serialize};
 use transform_gizmo_bevy::prelude::*;
 
+use super::camera::EditorCamera;
+use super::gizmo::ActiveGizmoMode;
+use super::selection::Selection;
+use super::selection_outline::OutlineShell;
+
+/// Screen distance, in logical pixels, within which a vertex captures the dragged pivot.
+pub const VERTEX_SNAP_PIXELS: f32 = 12.0;
+
+/// Rotation snap increments, in degrees, offered as presets.
+pub const ROTATION_SNAP_PRESETS: [f32; 3] = [15.0, 45.0, 90.0];
+
 /// Resource storing snap configuration.
 #[derive(Resource, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,9 +33,15 @@ pub struct SnapSettings {
     /// Whether snapping is currently enabled (Ctrl held)
     pub snap_enabled: bool,
 
-    /// Translation snap increment in world units (default: 0.5)
+    /// Translation snap increment in world units (default: 0.5). Setting it
+    /// sets all three per-axis increments.
     pub translation_snap: f32,
 
+    /// Per-axis translation snap increments; zero or less leaves that axis free
+    pub translation_snap_x: f32,
+    pub translation_snap_y: f32,
+    pub translation_snap_z: f32,
+
     /// Rotation snap increment in degrees (default: 15.0)
     pub rotation_snap_degrees: f32,
 
@@ -31,6 +56,14 @@ pub struct SnapSettings {
 
     /// Grid extent (number of cells in each direction from origin)
     pub grid_extent: u32,
+
+    /// Snap the dragged pivot to the nearest mesh vertex under the cursor
+    #[serde(default)]
+    pub vertex_snap: bool,
+
+    /// Snap the dragged pivot to the mesh surface under the cursor
+    #[serde(default)]
+    pub surface_snap: bool,
 }
 
 impl Default for SnapSettings {
@@ -38,11 +71,16 @@ impl Default for SnapSettings {
         Self {
             snap_enabled: false,
             translation_snap: 0.5,
+            translation_snap_x: 0.5,
+            translation_snap_y: 0.5,
+            translation_snap_z: 0.5,
             rotation_snap_degrees: 15.0,
             scale_snap: 0.25,
             grid_visible: false,
             grid_size: 0.5,
             grid_extent: 20, // 20 cells in each direction = 40x40 grid
+            vertex_snap: false,
+            surface_snap: false,
         }
     }
 }
@@ -52,17 +90,41 @@ impl SnapSettings {
     pub fn rotation_snap_radians(&self) -> f32 {
         self.rotation_snap_degrees.to_radians()
     }
+
+    /// Set the uniform translation snap, overriding the per-axis increments.
+    pub fn set_translation_snap(&mut self, snap: f32) {
+        self.translation_snap = snap;
+        self.translation_snap_x = snap;
+        self.translation_snap_y = snap;
+        self.translation_snap_z = snap;
+    }
+
+    /// Translation snap increments as a vector.
+    pub fn translation_snap_axes(&self) -> Vec3 {
+        Vec3::new(self.translation_snap_x, self.translation_snap_y, self.translation_snap_z)
+    }
 }
 
+/// Resource holding the vertex or surface point the current drag is snapped to.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct SnapTarget(pub Option<Vec3>);
+
 /// Plugin that adds snap functionality.
 pub struct SnapPlugin;
 
 impl Plugin for SnapPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SnapSettings>()
+            .init_resource::<SnapTarget>()
             .add_systems(
                 Update,
                 (detect_snap_modifier, apply_snap_to_gizmo, render_grid_overlay).chain(),
+            )
+            .add_systems(
+                Update,
+                (apply_vertex_surface_snap, apply_axis_translation_snap, render_snap_target)
+                    .chain()
+                    .before(super::gizmo::track_gizmo_interaction),
             );
     }
 }
@@ -91,18 +153,226 @@ fn apply_snap_to_gizmo(
     mut gizmo_options: ResMut<GizmoOptions>,
 ) {
     if snap_settings.is_changed() {
+        // Translation is snapped per axis by `apply_axis_translation_snap`;
+        // the gizmo only takes a single distance.
+        gizmo_options.snap_distance = 0.0;
         if snap_settings.snap_enabled {
-            gizmo_options.snap_distance = snap_settings.translation_snap;
             gizmo_options.snap_angle = snap_settings.rotation_snap_radians();
             gizmo_options.snap_scale = snap_settings.scale_snap;
         } else {
-            gizmo_options.snap_distance = 0.0;
             gizmo_options.snap_angle = 0.0;
             gizmo_options.snap_scale = 0.0;
         }
     }
 }
 
+/// System that moves the dragged selection so its pivot lands on the vertex
+/// (within `VERTEX_SNAP_PIXELS` of the cursor) or surface under the cursor.
+///
+/// The gizmo applies per-frame deltas in `Last`, so the override is reapplied
+/// every frame of the drag, including the release frame before
+/// `track_gizmo_interaction` records the final transforms for undo.
+#[allow(clippy::too_many_arguments)]
+fn apply_vertex_surface_snap(
+    snap_settings: Res<SnapSettings>,
+    gizmo_mode: Res<ActiveGizmoMode>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    selection: Res<Selection>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<EditorCamera>>,
+    mut targets: Query<(Entity, &GizmoTarget, &mut Transform, Option<&ChildOf>)>,
+    global_transforms: Query<&GlobalTransform>,
+    mesh_query: Query<&Mesh3d>,
+    meshes: Res<Assets<Mesh>>,
+    mut ray_cast: MeshRayCast,
+    shells: Query<(), With<OutlineShell>>,
+    mut snap_target: ResMut<SnapTarget>,
+) {
+    let dragging = (mouse_button.pressed(MouseButton::Left) || mouse_button.just_released(MouseButton::Left))
+        && targets.iter().any(|(_, target, _, _)| target.is_active());
+    let enabled = (snap_settings.vertex_snap || snap_settings.surface_snap)
+        && *gizmo_mode == ActiveGizmoMode::Translate;
+
+    let target = if dragging && enabled {
+        find_drag_snap_target(
+            &snap_settings,
+            &windows,
+            &camera_query,
+            &targets.iter().map(|(entity, ..)| entity).collect::<Vec<_>>(),
+            &global_transforms,
+            &mesh_query,
+            &meshes,
+            &mut ray_cast,
+            &shells,
+        )
+    } else {
+        None
+    };
+
+    if snap_target.0 != target {
+        snap_target.0 = target;
+
+        #[cfg(target_arch = "wasm32")]
+        crate::bridge::events::emit_snap_target(target.map(|p| p.to_array()));
+    }
+
+    let Some(target) = target else {
+        return;
+    };
+
+    // World-space pivot of the primary selection (or the first dragged entity).
+    let parent_transform = |child_of: Option<&ChildOf>| {
+        child_of
+            .and_then(|c| global_transforms.get(c.parent()).ok())
+            .copied()
+            .unwrap_or_default()
+    };
+    let pivot = selection
+        .primary
+        .and_then(|primary| targets.get(primary).ok())
+        .or_else(|| targets.iter().next())
+        .map(|(_, _, transform, child_of)| parent_transform(child_of).transform_point(transform.translation));
+    let Some(pivot) = pivot else {
+        return;
+    };
+
+    let delta = target - pivot;
+    for (_, _, mut transform, child_of) in targets.iter_mut() {
+        let local_delta = parent_transform(child_of).affine().inverse().transform_vector3(delta);
+        transform.translation += local_delta;
+    }
+}
+
+/// Per-entity state of a translate drag snapped by `apply_axis_translation_snap`.
+#[derive(Debug, Clone, Copy)]
+struct SnappedDrag {
+    /// Translation when the drag started.
+    start: Vec3,
+    /// Unsnapped offset from `start` accumulated from the gizmo's deltas.
+    offset: Vec3,
+    /// Translation written last frame, to tell the gizmo's delta apart.
+    written: Vec3,
+}
+
+/// System that snaps translate drags per axis while snapping is enabled.
+///
+/// The gizmo moves its targets by per-frame deltas, so the unsnapped offset
+/// from the drag start is accumulated here and each target is set to the
+/// start plus that offset rounded to the axis increments (in its parent's
+/// space). Vertex and surface snapping take precedence.
+fn apply_axis_translation_snap(
+    snap_settings: Res<SnapSettings>,
+    gizmo_mode: Res<ActiveGizmoMode>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    snap_target: Res<SnapTarget>,
+    mut targets: Query<(Entity, &GizmoTarget, &mut Transform)>,
+    mut drags: Local<HashMap<Entity, SnappedDrag>>,
+) {
+    let dragging = (mouse_button.pressed(MouseButton::Left) || mouse_button.just_released(MouseButton::Left))
+        && targets.iter().any(|(_, target, _)| target.is_active());
+    let enabled = snap_settings.snap_enabled
+        && *gizmo_mode == ActiveGizmoMode::Translate
+        && snap_target.0.is_none();
+
+    if !(dragging && enabled) {
+        // Keep the start positions current so the first delta of a drag counts.
+        drags.clear();
+        for (entity, _, transform) in targets.iter() {
+            let translation = transform.translation;
+            drags.insert(entity, SnappedDrag { start: translation, offset: Vec3::ZERO, written: translation });
+        }
+        return;
+    }
+
+    let step = snap_settings.translation_snap_axes();
+    for (entity, _, mut transform) in targets.iter_mut() {
+        let translation = transform.translation;
+        let drag = drags.entry(entity).or_insert(SnappedDrag { start: translation, offset: Vec3::ZERO, written: translation });
+        drag.offset += translation - drag.written;
+        let snapped = drag.start + snap_translation(drag.offset, step);
+        if translation != snapped {
+            transform.translation = snapped;
+        }
+        drag.written = snapped;
+    }
+}
+
+/// Ray casts from the cursor and returns the vertex or surface point to snap to.
+#[allow(clippy::too_many_arguments)]
+fn find_drag_snap_target(
+    snap_settings: &SnapSettings,
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    camera_query: &Query<(&Camera, &GlobalTransform), With<EditorCamera>>,
+    dragged: &[Entity],
+    global_transforms: &Query<&GlobalTransform>,
+    mesh_query: &Query<&Mesh3d>,
+    meshes: &Assets<Mesh>,
+    ray_cast: &mut MeshRayCast,
+    shells: &Query<(), With<OutlineShell>>,
+) -> Option<Vec3> {
+    let cursor = windows.single().ok()?.cursor_position()?;
+    let (camera, camera_transform) = camera_query.single().ok()?;
+    let ray = camera.viewport_to_world(camera_transform, cursor).ok()?;
+
+    // Skip the dragged meshes (and selection outlines) so they don't snap onto themselves.
+    let filter = |entity: Entity| !dragged.contains(&entity) && !shells.contains(entity);
+    let settings = MeshRayCastSettings::default()
+        .with_filter(&filter)
+        .never_early_exit();
+    let hits = ray_cast.cast_ray(ray, &settings);
+
+    if snap_settings.vertex_snap {
+        let vertices = hits.iter().flat_map(|(entity, _)| {
+            let transform = global_transforms.get(*entity).ok();
+            let positions = mesh_query
+                .get(*entity)
+                .ok()
+                .and_then(|mesh| meshes.get(&mesh.0))
+                .and_then(|mesh| match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+                    Some(VertexAttributeValues::Float32x3(positions)) => Some(positions.as_slice()),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            positions
+                .iter()
+                .filter_map(move |p| transform.map(|t| t.transform_point(Vec3::from(*p))))
+        });
+        let project = |p: Vec3| camera.world_to_viewport(camera_transform, p).ok();
+        if let Some(vertex) = closest_vertex_on_screen(cursor, vertices, project, VERTEX_SNAP_PIXELS) {
+            return Some(vertex);
+        }
+    }
+
+    if snap_settings.surface_snap {
+        return hits.first().map(|(_, hit)| hit.point);
+    }
+    None
+}
+
+/// Returns the world-space vertex whose projection lies closest to `cursor`,
+/// if it is within `max_pixels`. Vertices that don't project (behind the
+/// camera) are skipped.
+pub fn closest_vertex_on_screen(
+    cursor: Vec2,
+    vertices: impl IntoIterator<Item = Vec3>,
+    project: impl Fn(Vec3) -> Option<Vec2>,
+    max_pixels: f32,
+) -> Option<Vec3> {
+    vertices
+        .into_iter()
+        .filter_map(|vertex| project(vertex).map(|screen| (vertex, screen.distance(cursor))))
+        .filter(|(_, distance)| *distance <= max_pixels)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(vertex, _)| vertex)
+}
+
+/// System that highlights the current drag snap target.
+fn render_snap_target(snap_target: Res<SnapTarget>, mut gizmos: Gizmos) {
+    if let Some(point) = snap_target.0 {
+        gizmos.sphere(Isometry3d::from_translation(point), 0.06, Color::srgb(0.1, 0.9, 1.0));
+    }
+}
+
 /// System that renders the visual grid overlay using Bevy gizmos.
 fn render_grid_overlay(snap_settings: Res<SnapSettings>, mut gizmos: Gizmos) {
     if !snap_settings.grid_visible {
@@ -147,3 +417,58 @@ pub fn snap_to_grid(value: f32, snap: f32) -> f32 {
     }
     (value / snap).round() * snap
 }
+
+/// Snap each axis of a translation to its own increment.
+pub fn snap_translation(value: Vec3, snap: Vec3) -> Vec3 {
+    Vec3::new(
+        snap_to_grid(value.x, snap.x),
+        snap_to_grid(value.y, snap.y),
+        snap_to_grid(value.z, snap.z),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertex_snap_lands_on_cube_corner() {
+        let cube = Mesh::from(Cuboid::new(1.0, 1.0, 1.0));
+        let Some(VertexAttributeValues::Float32x3(positions)) = cube.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            panic!("cube has no positions");
+        };
+        // Perspective-style projection from a camera at z = 5 looking down -Z.
+        let project = |p: Vec3| {
+            let depth = 5.0 - p.z;
+            (depth > 0.0).then(|| p.truncate() / depth * 400.0)
+        };
+
+        // Cursor a few pixels off the front top-right corner.
+        let corner = Vec3::new(0.5, 0.5, 0.5);
+        let cursor = project(corner).unwrap() + Vec2::new(3.0, -2.0);
+        let snapped = closest_vertex_on_screen(cursor, positions.iter().map(|p| Vec3::from(*p)), project, VERTEX_SNAP_PIXELS);
+        assert_eq!(snapped, Some(corner));
+    }
+
+    #[test]
+    fn per_axis_snaps_round_each_axis_independently() {
+        let mut settings = SnapSettings {
+            translation_snap_x: 1.0,
+            translation_snap_y: 0.25,
+            translation_snap_z: 0.0,
+            ..Default::default()
+        };
+        let snapped = snap_translation(Vec3::new(1.4, 0.3, 0.37), settings.translation_snap_axes());
+        assert_eq!(snapped, Vec3::new(1.0, 0.25, 0.37));
+
+        settings.set_translation_snap(2.0);
+        assert_eq!(snap_translation(Vec3::new(1.4, 0.3, 3.2), settings.translation_snap_axes()), Vec3::new(2.0, 0.0, 4.0));
+    }
+
+    #[test]
+    fn vertex_snap_ignores_vertices_beyond_threshold() {
+        let project = |p: Vec3| Some(p.truncate() * 100.0);
+        let snapped = closest_vertex_on_screen(Vec2::new(20.0, 0.0), [Vec3::ZERO], project, VERTEX_SNAP_PIXELS);
+        assert_eq!(snapped, None);
+    }
+}
@@ -0,0 +1,160 @@
+//! Spatial queries over scene entities (find entities in a radius or box).
+//!
+//! Entity positions are bucketed into a coarse uniform grid so region queries
+//! only visit nearby cells. The grid is rebuilt in `PostUpdate`, after
+//! transform propagation, on frames where any entity moved, appeared or was
+//! removed.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+
+use super::entity_id::EntityId;
+
+/// Default grid cell edge length, in world units.
+pub const DEFAULT_CELL_SIZE: f32 = 8.0;
+
+/// Resource bucketing entity world positions into grid cells.
+#[derive(Resource, Debug, Clone)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec3, Vec<(String, Vec3)>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(DEFAULT_CELL_SIZE)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self { cell_size: cell_size.max(f32::EPSILON), cells: HashMap::new() }
+    }
+
+    fn cell_of(&self, position: Vec3) -> IVec3 {
+        (position / self.cell_size).floor().as_ivec3()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    pub fn insert(&mut self, entity_id: String, position: Vec3) {
+        let cell = self.cell_of(position);
+        self.cells.entry(cell).or_default().push((entity_id, position));
+    }
+
+    /// Entries in every cell overlapping the box `min..=max`.
+    fn candidates(&self, min: Vec3, max: Vec3) -> impl Iterator<Item = &(String, Vec3)> {
+        let (lo, hi) = (self.cell_of(min), self.cell_of(max));
+        (lo.x..=hi.x)
+            .flat_map(move |x| (lo.y..=hi.y).flat_map(move |y| (lo.z..=hi.z).map(move |z| IVec3::new(x, y, z))))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+    }
+
+    /// Entities within `radius` of `center`, nearest first.
+    pub fn query_radius(&self, center: Vec3, radius: f32) -> Vec<(String, f32)> {
+        let radius = radius.max(0.0);
+        let hits = self
+            .candidates(center - Vec3::splat(radius), center + Vec3::splat(radius))
+            .map(|(id, pos)| (id.clone(), pos.distance(center)))
+            .filter(|(_, distance)| *distance <= radius)
+            .collect();
+        sorted_by_distance(hits)
+    }
+
+    /// Entities inside the box `min..=max`, nearest to the box center first.
+    pub fn query_box(&self, min: Vec3, max: Vec3) -> Vec<(String, f32)> {
+        let (min, max) = (min.min(max), min.max(max));
+        let center = min.midpoint(max);
+        let hits = self
+            .candidates(min, max)
+            .filter(|(_, pos)| pos.cmpge(min).all() && pos.cmple(max).all())
+            .map(|(id, pos)| (id.clone(), pos.distance(center)))
+            .collect();
+        sorted_by_distance(hits)
+    }
+}
+
+/// Sort hits by distance, breaking ties by entity id so results are stable.
+fn sorted_by_distance(mut hits: Vec<(String, f32)>) -> Vec<(String, f32)> {
+    hits.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    hits
+}
+
+/// Plugin that maintains the spatial grid.
+pub struct SpatialQueryPlugin;
+
+impl Plugin for SpatialQueryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialGrid>()
+            .add_systems(PostUpdate, update_spatial_grid.after(TransformSystems::Propagate));
+    }
+}
+
+/// Entities that moved or appeared since the grid was last built.
+type GridChangeQuery<'w, 's> = Query<'w, 's, (), (With<EntityId>, Or<(Changed<GlobalTransform>, Added<EntityId>)>)>;
+
+/// System that rebuilds the grid when any entity moved, appeared or was removed.
+fn update_spatial_grid(
+    mut grid: ResMut<SpatialGrid>,
+    entities: Query<(&EntityId, &GlobalTransform)>,
+    changed: GridChangeQuery,
+    mut removed: RemovedComponents<EntityId>,
+) {
+    let any_removed = removed.read().count() > 0;
+    if changed.is_empty() && !any_removed {
+        return;
+    }
+
+    grid.clear();
+    for (entity_id, transform) in entities.iter() {
+        grid.insert(entity_id.0.clone(), transform.translation());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_query_includes_only_contained_entities() {
+        let mut grid = SpatialGrid::new(2.0);
+        grid.insert("inside".to_string(), Vec3::new(1.0, 1.0, 1.0));
+        grid.insert("edge".to_string(), Vec3::new(3.0, 0.0, 0.0));
+        grid.insert("outside".to_string(), Vec3::new(5.0, 0.0, 0.0));
+
+        let ids: Vec<_> = grid
+            .query_box(Vec3::new(3.0, 2.0, 2.0), Vec3::ZERO)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec!["inside", "edge"]);
+    }
+
+    #[test]
+    fn radius_query_returns_nearby_cubes_sorted_by_distance() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, SpatialQueryPlugin));
+
+        let [far, ..] = [("far", 20.0), ("center", 0.0), ("near", 3.0), ("nearer", -1.5), ("edge", 9.5)]
+            .map(|(id, x)| app.world_mut().spawn((EntityId(id.to_string()), Transform::from_xyz(x, 0.0, 0.0))).id());
+        app.update();
+
+        let hits = app.world().resource::<SpatialGrid>().query_radius(Vec3::ZERO, 10.0);
+        let ids: Vec<_> = hits.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["center", "nearer", "near", "edge"]);
+        assert_eq!(hits[2].1, 3.0);
+
+        // Moving an entity is picked up on the next frame
+        app.world_mut().get_mut::<Transform>(far).unwrap().translation.x = 1.0;
+        app.update();
+
+        let hits = app.world().resource::<SpatialGrid>().query_radius(Vec3::ZERO, 2.0);
+        let ids: Vec<_> = hits.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["center", "far", "nearer"]);
+    }
+}
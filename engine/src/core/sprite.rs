@@ -1,9 +1,14 @@
 //! 2D sprite rendering and animation components.
 
 use bevy::prelude::*;
+use bevy::sprite::{BorderRect, TextureSlicer};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::engine_mode::EngineMode;
+use super::entity_id::EntityId;
+use super::pending_commands::PendingCommands;
+
 /// Resource holding the runtime sorting layer configuration.
 /// Populated via the `set_sorting_layers` command.
 /// Layers are ordered from index 0 (back) to N-1 (front).
@@ -31,6 +36,58 @@ pub struct SpriteData {
     pub sorting_order: i32,
     /// Anchor point of the sprite
     pub anchor: SpriteAnchor,
+    /// 9-slice border insets (None = stretch the whole texture)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nine_slice: Option<NineSliceBorders>,
+    /// Share of the 2D camera's movement the sprite ignores in Play mode
+    /// (1 = fixed in the world, 0 = fixed on screen, between = background layer)
+    #[serde(default = "default_parallax_factor")]
+    pub parallax_factor: f32,
+}
+
+fn default_parallax_factor() -> f32 {
+    1.0
+}
+
+/// 9-slice border insets in texture pixels. Corners keep their size when the
+/// sprite is resized; edges stretch along one axis and the center along both.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct NineSliceBorders {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl NineSliceBorders {
+    /// Insets clamped to the texture: each is non-negative, and opposite
+    /// insets are scaled down together so at least one pixel of center remains.
+    pub fn clamped(self, texture_size: Vec2) -> Self {
+        fn fit(a: f32, b: f32, extent: f32) -> (f32, f32) {
+            let (a, b) = (a.max(0.0), b.max(0.0));
+            let limit = (extent - 1.0).max(0.0);
+            if a + b <= limit {
+                (a, b)
+            } else {
+                let k = limit / (a + b);
+                (a * k, b * k)
+            }
+        }
+        let (left, right) = fit(self.left, self.right, texture_size.x);
+        let (top, bottom) = fit(self.top, self.bottom, texture_size.y);
+        Self { left, right, top, bottom }
+    }
+
+    /// Bevy texture slicer for these insets (sides and center stretch).
+    pub fn to_slicer(self) -> TextureSlicer {
+        TextureSlicer {
+            border: BorderRect {
+                min_inset: Vec2::new(self.left, self.top),
+                max_inset: Vec2::new(self.right, self.bottom),
+            },
+            ..default()
+        }
+    }
 }
 
 /// Anchor point for sprite rendering.
@@ -58,6 +115,8 @@ impl Default for SpriteData {
             sorting_layer: "Default".to_string(),
             sorting_order: 0,
             anchor: SpriteAnchor::Center,
+            nine_slice: None,
+            parallax_factor: 1.0,
         }
     }
 }
@@ -211,6 +270,119 @@ pub struct SpriteAnimationTimer {
     pub forward: bool,
 }
 
+// ========== Flipbook Animation ==========
+
+/// Simple flipbook animation: steps the sprite's atlas index through `frames`
+/// at a fixed rate while the engine is in Play mode.
+#[derive(Component, Clone, Debug, Serialize, Deserialize)]
+pub struct SpriteAnimation {
+    /// Atlas indices to show, in order.
+    pub frames: Vec<usize>,
+    /// Playback rate in frames per second.
+    pub fps: f32,
+    /// Whether playback wraps back to the first frame.
+    pub looping: bool,
+    /// Whether the animation is currently playing.
+    #[serde(default)]
+    pub playing: bool,
+    /// Seconds of playback since the first frame.
+    #[serde(default)]
+    pub elapsed: f32,
+}
+
+impl SpriteAnimation {
+    pub fn new(frames: Vec<usize>, fps: f32, looping: bool) -> Self {
+        Self { frames, fps, looping, playing: false, elapsed: 0.0 }
+    }
+
+    /// Position within `frames` for the current playback time.
+    pub fn frame_position(&self) -> usize {
+        let len = self.frames.len();
+        if len == 0 || self.fps <= 0.0 {
+            return 0;
+        }
+        let step = (self.elapsed * self.fps) as usize;
+        if self.looping { step % len } else { step.min(len - 1) }
+    }
+
+    /// Atlas index currently shown, or `None` if there are no frames.
+    pub fn current_frame(&self) -> Option<usize> {
+        self.frames.get(self.frame_position()).copied()
+    }
+
+    /// Advance playback by `dt` seconds. Does nothing while stopped.
+    pub fn advance(&mut self, dt: f32) {
+        if !self.playing || self.frames.is_empty() || self.fps <= 0.0 {
+            return;
+        }
+        self.elapsed += dt;
+        let cycle = self.frames.len() as f32 / self.fps;
+        if self.looping {
+            // Keep the clock within one cycle so precision doesn't drift
+            self.elapsed %= cycle;
+        } else if self.elapsed >= cycle {
+            self.elapsed = cycle;
+            self.playing = false;
+        }
+    }
+}
+
+/// System that applies pending set/play/stop sprite animation requests.
+pub fn apply_sprite_animation_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(Entity, &EntityId, Option<&mut SpriteAnimation>)>,
+    mut commands: Commands,
+) {
+    for request in pending.set_sprite_animation_requests.drain(..) {
+        let found = query.iter_mut().find(|(_, eid, _)| eid.0 == request.entity_id);
+        let Some((entity, _, existing)) = found else { continue };
+
+        match existing {
+            Some(mut animation) => *animation = request.animation,
+            None => {
+                commands.entity(entity).insert(request.animation);
+            }
+        }
+    }
+
+    for request in pending.sprite_animation_playback_requests.drain(..) {
+        let found = query.iter_mut().find(|(_, eid, _)| eid.0 == request.entity_id);
+        let Some((_, _, Some(mut animation))) = found else { continue };
+
+        animation.playing = request.playing;
+        if !request.playing {
+            // Stopping rewinds to the first frame
+            animation.elapsed = 0.0;
+        }
+    }
+}
+
+/// System that advances flipbook animations and writes the current frame into
+/// the sprite's texture atlas. Runs only in Play mode, so Paused freezes playback.
+pub fn advance_sprite_animations(
+    mode: Res<EngineMode>,
+    time: Res<Time>,
+    mut query: Query<(&mut SpriteAnimation, Option<&mut Sprite>)>,
+) {
+    if !mode.is_playing() {
+        return;
+    }
+    let dt = time.delta_secs();
+    for (mut animation, sprite) in query.iter_mut() {
+        if !animation.playing {
+            continue;
+        }
+        animation.advance(dt);
+
+        let frame = animation.current_frame();
+        if let (Some(frame), Some(mut sprite)) = (frame, sprite) {
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                atlas.index = frame;
+            }
+        }
+    }
+}
+
 // ========== Animation State Machine ==========
 
 /// A parameter value for the state machine.
@@ -278,3 +450,95 @@ impl Default for AnimationStateMachineData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending_commands::SpriteAnimationPlaybackRequest;
+
+    #[test]
+    fn flipbook_at_ten_fps_shows_frame_two_after_quarter_second_and_wraps() {
+        let mut animation = SpriteAnimation::new(vec![10, 11, 12, 13], 10.0, true);
+        animation.playing = true;
+
+        animation.advance(0.25);
+        assert_eq!(animation.frame_position(), 2);
+        assert_eq!(animation.current_frame(), Some(12));
+
+        // 0.45s in total: frame 4 wraps to the first frame
+        animation.advance(0.2);
+        assert_eq!(animation.current_frame(), Some(10));
+        animation.advance(0.3);
+        assert_eq!(animation.current_frame(), Some(13));
+
+        let mut once = SpriteAnimation::new(vec![1, 2], 10.0, false);
+        once.playing = true;
+        once.advance(1.0);
+        assert_eq!(once.current_frame(), Some(2));
+        assert!(!once.playing);
+    }
+
+    #[test]
+    fn flipbook_plays_in_play_mode_and_freezes_when_paused() {
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .insert_resource(EngineMode::Play)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(50)))
+            .add_systems(Update, (apply_sprite_animation_requests, advance_sprite_animations).chain());
+        let sprite = Sprite { texture_atlas: Some(TextureAtlas::default()), ..default() };
+        let entity = app.world_mut()
+            .spawn((EntityId("hero".to_string()), SpriteAnimation::new(vec![0, 1, 2, 3], 10.0, true), sprite))
+            .id();
+        let playback = |playing| SpriteAnimationPlaybackRequest { entity_id: "hero".to_string(), playing };
+
+        app.world_mut().resource_mut::<PendingCommands>().sprite_animation_playback_requests.push(playback(true));
+        for _ in 0..4 {
+            app.update();
+        }
+        let animation = app.world().get::<SpriteAnimation>(entity).unwrap().clone();
+        assert!(animation.elapsed > 0.0);
+        let atlas_index = app.world().get::<Sprite>(entity).unwrap().texture_atlas.as_ref().unwrap().index;
+        assert_eq!(Some(atlas_index), animation.current_frame());
+
+        *app.world_mut().resource_mut::<EngineMode>() = EngineMode::Paused;
+        app.update();
+        app.update();
+        assert_eq!(app.world().get::<SpriteAnimation>(entity).unwrap().elapsed, animation.elapsed);
+
+        app.world_mut().resource_mut::<PendingCommands>().sprite_animation_playback_requests.push(playback(false));
+        app.update();
+        let stopped = app.world().get::<SpriteAnimation>(entity).unwrap();
+        assert!(!stopped.playing);
+        assert_eq!(stopped.current_frame(), Some(0));
+    }
+
+    #[test]
+    fn nine_slice_insets_are_clamped_to_texture() {
+        let borders = NineSliceBorders { left: 40.0, right: 40.0, top: -5.0, bottom: 10.0 };
+        let clamped = borders.clamped(Vec2::new(41.0, 32.0));
+        assert_eq!(clamped, NineSliceBorders { left: 20.0, right: 20.0, top: 0.0, bottom: 10.0 });
+    }
+
+    #[test]
+    fn nine_slice_corners_keep_size_when_stretched() {
+        let texture = Rect::new(0.0, 0.0, 64.0, 32.0);
+        let slicer = NineSliceBorders { left: 8.0, right: 12.0, top: 6.0, bottom: 10.0 }
+            .clamped(texture.size())
+            .to_slicer();
+
+        let base = slicer.compute_slices(texture, None);
+        let wide = slicer.compute_slices(texture, Some(Vec2::new(256.0, 32.0)));
+        assert_eq!((base.len(), wide.len()), (9, 9));
+
+        // Corners come first (TL, TR, BL, BR), then the center
+        let corner_sizes = |slices: &[bevy::sprite::TextureSlice]| slices[..4].iter().map(|s| s.draw_size).collect::<Vec<_>>();
+        assert_eq!(corner_sizes(&base), corner_sizes(&wide));
+        assert_eq!(wide[0].draw_size, Vec2::new(8.0, 6.0));
+        assert_eq!(base[4].draw_size, Vec2::new(44.0, 16.0));
+        assert_eq!(wide[4].draw_size, Vec2::new(236.0, 16.0));
+    }
+}
@@ -0,0 +1,365 @@
+//! Catmull-Clark mesh subdivision.
+//!
+//! Works on indexed triangle meshes. Vertices are welded by position first so
+//! UV/normal seams do not tear the surface, and coplanar triangle pairs are
+//! merged back into quads so boxy inputs (primitives, CSG results) subdivide
+//! like the quad meshes they were built from. Boundary edges use the crease
+//! rules, so open meshes (lathe, loft, sweep) keep their rims.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::mesh::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues};
+
+/// Polygon mesh with welded positions and per-corner UVs.
+struct PolyMesh {
+    positions: Vec<Vec3>,
+    faces: Vec<Vec<usize>>,
+    face_uvs: Vec<Vec<Vec2>>,
+}
+
+/// Run `iterations` Catmull-Clark passes over an indexed triangle mesh.
+/// Returns a new triangle mesh with smooth normals and interpolated UVs.
+pub fn subdivide_mesh(mesh: &Mesh, iterations: u32) -> Result<Mesh, String> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return Err("Only triangle-list meshes can be subdivided".to_string());
+    }
+    let positions: Vec<Vec3> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(v)) => v.iter().map(|p| Vec3::from(*p)).collect(),
+        _ => return Err("Mesh has no positions".to_string()),
+    };
+    let uvs: Vec<Vec2> = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        Some(VertexAttributeValues::Float32x2(v)) => v.iter().map(|uv| Vec2::from(*uv)).collect(),
+        _ => vec![Vec2::ZERO; positions.len()],
+    };
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(Indices::U32(v)) => v.iter().map(|i| *i as usize).collect(),
+        Some(Indices::U16(v)) => v.iter().map(|i| *i as usize).collect(),
+        None => return Err("Mesh has no indices".to_string()),
+    };
+    if indices.len() < 3 || indices.iter().any(|i| *i >= positions.len()) {
+        return Err("Mesh has no valid triangles".to_string());
+    }
+
+    let mut poly = PolyMesh::from_triangles(&positions, &uvs, &indices);
+    for _ in 0..iterations {
+        poly = poly.catmull_clark();
+    }
+    Ok(poly.into_mesh())
+}
+
+impl PolyMesh {
+    /// Weld triangle corners by position and pair coplanar triangles into quads.
+    fn from_triangles(positions: &[Vec3], uvs: &[Vec2], indices: &[usize]) -> Self {
+        let mut welded: Vec<Vec3> = Vec::new();
+        let mut weld_map: HashMap<[i64; 3], usize> = HashMap::new();
+        let remap: Vec<usize> = positions
+            .iter()
+            .map(|p| {
+                let key = [
+                    (p.x * 1e5).round() as i64,
+                    (p.y * 1e5).round() as i64,
+                    (p.z * 1e5).round() as i64,
+                ];
+                *weld_map.entry(key).or_insert_with(|| {
+                    welded.push(*p);
+                    welded.len() - 1
+                })
+            })
+            .collect();
+
+        // Triangles as (welded corner, uv) triples, dropping collapsed ones.
+        let tris: Vec<[(usize, Vec2); 3]> = indices
+            .chunks_exact(3)
+            .map(|t| [0, 1, 2].map(|k| (remap[t[k]], uvs[t[k]])))
+            .filter(|t| t[0].0 != t[1].0 && t[1].0 != t[2].0 && t[2].0 != t[0].0)
+            .collect();
+
+        let tri_normal = |t: &[(usize, Vec2); 3]| {
+            let a = welded[t[0].0];
+            (welded[t[1].0] - a).cross(welded[t[2].0] - a).normalize_or_zero()
+        };
+
+        // Directed edge -> triangle, used to find the neighbour across an edge.
+        let mut edge_owner: HashMap<(usize, usize), usize> = HashMap::new();
+        for (ti, t) in tris.iter().enumerate() {
+            for k in 0..3 {
+                edge_owner.insert((t[k].0, t[(k + 1) % 3].0), ti);
+            }
+        }
+
+        let mut used = vec![false; tris.len()];
+        let mut faces = Vec::with_capacity(tris.len());
+        let mut face_uvs = Vec::with_capacity(tris.len());
+        for (ti, t) in tris.iter().enumerate() {
+            if used[ti] {
+                continue;
+            }
+            used[ti] = true;
+
+            // The quad diagonal is the triangle's longest edge.
+            let k = (0..3)
+                .max_by(|&a, &b| {
+                    let len = |k: usize| welded[t[k].0].distance_squared(welded[t[(k + 1) % 3].0]);
+                    len(a).total_cmp(&len(b))
+                })
+                .unwrap_or(0);
+            let (b, c, a) = (t[k], t[(k + 1) % 3], t[(k + 2) % 3]);
+
+            let partner = edge_owner.get(&(c.0, b.0)).copied().filter(|&oi| {
+                let o = &tris[oi];
+                !used[oi] && tri_normal(t).dot(tri_normal(o)) > 0.999 && {
+                    // The shared edge must also be the partner's longest edge.
+                    let shared = welded[b.0].distance_squared(welded[c.0]);
+                    (0..3).all(|m| welded[o[m].0].distance_squared(welded[o[(m + 1) % 3].0]) <= shared + 1e-6)
+                }
+            });
+
+            match partner {
+                Some(oi) => {
+                    used[oi] = true;
+                    let o = &tris[oi];
+                    let Some(d) = o.iter().find(|(v, _)| *v != b.0 && *v != c.0).copied() else {
+                        continue;
+                    };
+                    faces.push(vec![a.0, b.0, d.0, c.0]);
+                    face_uvs.push(vec![a.1, b.1, d.1, c.1]);
+                }
+                None => {
+                    faces.push(vec![a.0, b.0, c.0]);
+                    face_uvs.push(vec![a.1, b.1, c.1]);
+                }
+            }
+        }
+
+        Self { positions: welded, faces, face_uvs }
+    }
+
+    /// One Catmull-Clark refinement. Every n-gon becomes n quads.
+    fn catmull_clark(&self) -> Self {
+        let vert_count = self.positions.len();
+
+        let face_points: Vec<Vec3> = self
+            .faces
+            .iter()
+            .map(|f| f.iter().map(|&v| self.positions[v]).sum::<Vec3>() / f.len() as f32)
+            .collect();
+
+        // Undirected edge -> adjacent faces, in first-seen order.
+        let mut edge_index: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut edges: Vec<((usize, usize), Vec<usize>)> = Vec::new();
+        for (fi, f) in self.faces.iter().enumerate() {
+            for k in 0..f.len() {
+                let (a, b) = (f[k], f[(k + 1) % f.len()]);
+                let key = (a.min(b), a.max(b));
+                let ei = *edge_index.entry(key).or_insert_with(|| {
+                    edges.push((key, Vec::new()));
+                    edges.len() - 1
+                });
+                edges[ei].1.push(fi);
+            }
+        }
+
+        let edge_points: Vec<Vec3> = edges
+            .iter()
+            .map(|((a, b), faces)| {
+                let (pa, pb) = (self.positions[*a], self.positions[*b]);
+                if faces.len() == 2 {
+                    (pa + pb + face_points[faces[0]] + face_points[faces[1]]) / 4.0
+                } else {
+                    (pa + pb) / 2.0
+                }
+            })
+            .collect();
+
+        // Per-vertex adjacency for the vertex rule.
+        let mut vert_faces: Vec<Vec<usize>> = vec![Vec::new(); vert_count];
+        for (fi, f) in self.faces.iter().enumerate() {
+            for &v in f {
+                vert_faces[v].push(fi);
+            }
+        }
+        let mut vert_edges: Vec<Vec<usize>> = vec![Vec::new(); vert_count];
+        for (ei, ((a, b), _)) in edges.iter().enumerate() {
+            vert_edges[*a].push(ei);
+            vert_edges[*b].push(ei);
+        }
+
+        let mut positions: Vec<Vec3> = (0..vert_count)
+            .map(|v| {
+                let p = self.positions[v];
+                let boundary: Vec<usize> = vert_edges[v]
+                    .iter()
+                    .copied()
+                    .filter(|&ei| edges[ei].1.len() != 2)
+                    .collect();
+                if !boundary.is_empty() {
+                    // Crease rule: smooth along exactly two boundary edges, pin corners.
+                    if boundary.len() != 2 {
+                        return p;
+                    }
+                    let other = |ei: usize| {
+                        let (a, b) = edges[ei].0;
+                        self.positions[if a == v { b } else { a }]
+                    };
+                    return p * 0.75 + (other(boundary[0]) + other(boundary[1])) * 0.125;
+                }
+                let n = vert_faces[v].len() as f32;
+                if n == 0.0 {
+                    return p;
+                }
+                let f = vert_faces[v].iter().map(|&fi| face_points[fi]).sum::<Vec3>() / n;
+                let r = vert_edges[v]
+                    .iter()
+                    .map(|&ei| {
+                        let (a, b) = edges[ei].0;
+                        (self.positions[a] + self.positions[b]) / 2.0
+                    })
+                    .sum::<Vec3>()
+                    / vert_edges[v].len() as f32;
+                (f + 2.0 * r + (n - 3.0) * p) / n
+            })
+            .collect();
+
+        let face_base = positions.len();
+        positions.extend_from_slice(&face_points);
+        let edge_base = positions.len();
+        positions.extend_from_slice(&edge_points);
+
+        let edge_vert = |a: usize, b: usize| edge_base + edge_index[&(a.min(b), a.max(b))];
+
+        let quad_count: usize = self.faces.iter().map(|f| f.len()).sum();
+        let mut faces = Vec::with_capacity(quad_count);
+        let mut face_uvs = Vec::with_capacity(quad_count);
+        for (fi, (f, uvs)) in self.faces.iter().zip(self.face_uvs.iter()).enumerate() {
+            let n = f.len();
+            let center_uv = uvs.iter().sum::<Vec2>() / n as f32;
+            for k in 0..n {
+                let (prev, next) = ((k + n - 1) % n, (k + 1) % n);
+                faces.push(vec![
+                    f[k],
+                    edge_vert(f[k], f[next]),
+                    face_base + fi,
+                    edge_vert(f[prev], f[k]),
+                ]);
+                face_uvs.push(vec![
+                    uvs[k],
+                    (uvs[k] + uvs[next]) / 2.0,
+                    center_uv,
+                    (uvs[prev] + uvs[k]) / 2.0,
+                ]);
+            }
+        }
+
+        Self { positions, faces, face_uvs }
+    }
+
+    /// Triangulate into a Bevy mesh. Corners sharing a position and UV share a vertex.
+    fn into_mesh(self) -> Mesh {
+        // Smooth normals accumulated on welded positions.
+        let mut accum = vec![Vec3::ZERO; self.positions.len()];
+        for f in &self.faces {
+            for k in 1..f.len() - 1 {
+                let (a, b, c) = (f[0], f[k], f[k + 1]);
+                let pa = self.positions[a];
+                let n = (self.positions[b] - pa).cross(self.positions[c] - pa);
+                accum[a] += n;
+                accum[b] += n;
+                accum[c] += n;
+            }
+        }
+
+        let mut positions: Vec<[f32; 3]> = Vec::new();
+        let mut normals: Vec<[f32; 3]> = Vec::new();
+        let mut uvs: Vec<[f32; 2]> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+        let mut vertex_map: HashMap<(usize, [u32; 2]), u32> = HashMap::new();
+
+        for (f, face_uv) in self.faces.iter().zip(self.face_uvs.iter()) {
+            let corners: Vec<u32> = f
+                .iter()
+                .zip(face_uv.iter())
+                .map(|(&v, uv)| {
+                    *vertex_map.entry((v, [uv.x.to_bits(), uv.y.to_bits()])).or_insert_with(|| {
+                        positions.push(self.positions[v].to_array());
+                        normals.push(accum[v].try_normalize().unwrap_or(Vec3::Y).to_array());
+                        uvs.push(uv.to_array());
+                        (positions.len() - 1) as u32
+                    })
+                })
+                .collect();
+            for k in 1..corners.len() - 1 {
+                indices.extend_from_slice(&[corners[0], corners[k], corners[k + 1]]);
+            }
+        }
+
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            bevy::asset::RenderAssetUsages::default(),
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.insert_indices(Indices::U32(indices));
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle_count(mesh: &Mesh) -> usize {
+        mesh.indices().map(|i| i.len() / 3).unwrap_or(0)
+    }
+
+    fn bounds(mesh: &Mesh) -> (Vec3, Vec3) {
+        let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            panic!("missing positions");
+        };
+        positions.iter().fold((Vec3::MAX, Vec3::MIN), |(lo, hi), p| {
+            (lo.min(Vec3::from(*p)), hi.max(Vec3::from(*p)))
+        })
+    }
+
+    #[test]
+    fn subdividing_cube_quadruples_faces_and_keeps_bounds() {
+        let cube = Mesh::from(Cuboid::new(2.0, 2.0, 2.0));
+        let result = subdivide_mesh(&cube, 1).expect("cube should subdivide");
+
+        assert_eq!(triangle_count(&cube), 12);
+        assert_eq!(triangle_count(&result), 48);
+
+        let (lo, hi) = bounds(&result);
+        assert!(lo.abs_diff_eq(Vec3::splat(-1.0), 1e-5), "min {:?}", lo);
+        assert!(hi.abs_diff_eq(Vec3::splat(1.0), 1e-5), "max {:?}", hi);
+
+        // Normals stay outward after smoothing.
+        let Some(VertexAttributeValues::Float32x3(positions)) = result.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            panic!("missing positions");
+        };
+        let Some(VertexAttributeValues::Float32x3(normals)) = result.attribute(Mesh::ATTRIBUTE_NORMAL) else {
+            panic!("missing normals");
+        };
+        for (p, n) in positions.iter().zip(normals.iter()) {
+            assert!(Vec3::from(*p).dot(Vec3::from(*n)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn repeated_passes_multiply_faces() {
+        let cube = Mesh::from(Cuboid::new(1.0, 1.0, 1.0));
+        let result = subdivide_mesh(&cube, 2).expect("cube should subdivide");
+        assert_eq!(triangle_count(&result), 6 * 16 * 2);
+    }
+
+    #[test]
+    fn mesh_without_indices_is_rejected() {
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            bevy::asset::RenderAssetUsages::default(),
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0f32, 0.0, 0.0]; 3]);
+        assert_eq!(subdivide_mesh(&mesh, 1).unwrap_err(), "Mesh has no indices");
+    }
+}
@@ -3,6 +3,8 @@
 //! This module provides heightmap-based terrain generation using the `noise` crate
 //! with support for multiple noise algorithms (Perlin, Simplex, Value) and fractal
 //! Brownian motion (Fbm). Height-based vertex coloring provides visual feedback.
+//! Up to four texture splat layers can be blended by height and slope; see
+//! `terrain_material` for the rendering side.
 
 use bevy::prelude::*;
 use bevy::mesh::{Indices, Mesh, PrimitiveTopology};
@@ -45,6 +47,87 @@ pub struct TerrainData {
     pub resolution: u32,
     /// World-space size of the terrain (width and depth)
     pub size: f32,
+    /// Texture layers blended across the surface (at most `MAX_SPLAT_LAYERS`).
+    /// Empty means the plain height-colored material.
+    #[serde(default)]
+    pub splat_layers: Vec<SplatLayer>,
+    /// Asset ID of a painted RGBA weight texture (one channel per layer).
+    /// When unset, weights are derived from each layer's height/slope ranges.
+    #[serde(default)]
+    pub splat_map: Option<String>,
+    /// Hole flags, one per grid quad (row-major, (resolution - 1)^2 entries).
+    /// Quads flagged true are left out of the mesh and collider. Empty means no holes.
+    #[serde(default)]
+    pub holes: Vec<bool>,
+}
+
+/// Maximum number of splat layers (one per splat map channel).
+pub const MAX_SPLAT_LAYERS: usize = 4;
+
+/// Feather width for layer range edges, in normalized height units.
+const SPLAT_HEIGHT_FEATHER: f32 = 0.05;
+/// Feather width for layer range edges, in degrees of slope.
+const SPLAT_SLOPE_FEATHER: f32 = 5.0;
+
+fn default_splat_tiling() -> f32 { 0.1 }
+fn default_height_range() -> [f32; 2] { [0.0, 1.0] }
+fn default_slope_range() -> [f32; 2] { [0.0, 90.0] }
+
+/// One texture layer of a splatted terrain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplatLayer {
+    /// Texture asset ID. Layers without a texture use the height vertex color.
+    #[serde(default)]
+    pub texture_asset_id: Option<String>,
+    /// Texture repeats per world unit.
+    #[serde(default = "default_splat_tiling")]
+    pub tiling: f32,
+    /// Normalized height band [min, max] (0 = lowest point, 1 = highest).
+    #[serde(default = "default_height_range")]
+    pub height_range: [f32; 2],
+    /// Slope band [min, max] in degrees (0 = flat, 90 = vertical).
+    #[serde(default = "default_slope_range")]
+    pub slope_range: [f32; 2],
+}
+
+impl Default for SplatLayer {
+    fn default() -> Self {
+        Self {
+            texture_asset_id: None,
+            tiling: default_splat_tiling(),
+            height_range: default_height_range(),
+            slope_range: default_slope_range(),
+        }
+    }
+}
+
+/// Membership of `value` in [lo, hi], feathered outside the band.
+fn splat_range_weight(value: f32, range: [f32; 2], feather: f32) -> f32 {
+    let rise = ((value - (range[0] - feather)) / feather).clamp(0.0, 1.0);
+    let fall = (((range[1] + feather) - value) / feather).clamp(0.0, 1.0);
+    rise * rise * (3.0 - 2.0 * rise) * fall * fall * (3.0 - 2.0 * fall)
+}
+
+/// Automatic layer weights for a surface point from its normalized height and
+/// slope in degrees. Weights are normalized to sum to 1; if no layer covers the
+/// point, the first layer takes it. Mirrors `auto_splat_weights` in
+/// `forge_terrain.wgsl`.
+pub fn splat_weights(layers: &[SplatLayer], height: f32, slope_degrees: f32) -> [f32; MAX_SPLAT_LAYERS] {
+    let mut weights = [0.0; MAX_SPLAT_LAYERS];
+    for (weight, layer) in weights.iter_mut().zip(layers.iter()) {
+        *weight = splat_range_weight(height, layer.height_range, SPLAT_HEIGHT_FEATHER)
+            * splat_range_weight(slope_degrees, layer.slope_range, SPLAT_SLOPE_FEATHER);
+    }
+
+    let total: f32 = weights.iter().sum();
+    if total <= 1e-4 {
+        if !layers.is_empty() {
+            weights = [1.0, 0.0, 0.0, 0.0];
+        }
+        return weights;
+    }
+    weights.map(|w| w / total)
 }
 
 impl Default for TerrainData {
@@ -58,6 +141,9 @@ impl Default for TerrainData {
             seed: 42,
             resolution: 64,
             size: 50.0,
+            splat_layers: Vec::new(),
+            splat_map: None,
+            holes: Vec::new(),
         }
     }
 }
@@ -129,7 +215,10 @@ fn sample_noise<N: NoiseFn<f64, 2>>(noise: &N, heights: &mut [f32], data: &Terra
 
 /// Build a Bevy Mesh from heightmap data.
 /// Includes positions, normals (computed from gradient), and vertex colors.
-pub fn build_terrain_mesh(heights: &[f32], resolution: u32, size: f32) -> Mesh {
+/// UV_0 spans the terrain once (splat map lookup); UV_1.x carries the
+/// normalized height used for automatic splat weights. Quads flagged in
+/// `holes` get no triangles.
+pub fn build_terrain_mesh(heights: &[f32], resolution: u32, size: f32, holes: &[bool]) -> Mesh {
     let res = resolution as usize;
     let vertex_count = res * res;
     let half_size = size / 2.0;
@@ -138,6 +227,8 @@ pub fn build_terrain_mesh(heights: &[f32], resolution: u32, size: f32) -> Mesh {
     let mut positions = Vec::with_capacity(vertex_count);
     let mut normals = Vec::with_capacity(vertex_count);
     let mut colors = Vec::with_capacity(vertex_count);
+    let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(vertex_count);
+    let mut height_uvs: Vec<[f32; 2]> = Vec::with_capacity(vertex_count);
 
     // Track min/max height for color normalization
     let min_h = heights
@@ -162,6 +253,8 @@ pub fn build_terrain_mesh(heights: &[f32], resolution: u32, size: f32) -> Mesh {
             let t = (py - min_h) / height_range; // 0.0 = lowest, 1.0 = highest
             let color = height_to_color(t);
             colors.push(color);
+            uvs.push([x as f32 / (res as f32 - 1.0), z as f32 / (res as f32 - 1.0)]);
+            height_uvs.push([t, 0.0]);
         }
     }
 
@@ -197,11 +290,15 @@ pub fn build_terrain_mesh(heights: &[f32], resolution: u32, size: f32) -> Mesh {
         }
     }
 
-    // Generate triangle indices (two triangles per grid cell)
+    // Generate triangle indices (two triangles per grid cell, none for holes)
     let quad_count = (res - 1) * (res - 1);
     let mut indices: Vec<u32> = Vec::with_capacity(quad_count * 6);
     for z in 0..(res - 1) {
         for x in 0..(res - 1) {
+            if holes.get(z * (res - 1) + x).copied().unwrap_or(false) {
+                continue;
+            }
+
             let top_left = (z * res + x) as u32;
             let top_right = top_left + 1;
             let bottom_left = ((z + 1) * res + x) as u32;
@@ -226,13 +323,15 @@ pub fn build_terrain_mesh(heights: &[f32], resolution: u32, size: f32) -> Mesh {
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_1, height_uvs);
     mesh.insert_indices(Indices::U32(indices));
     mesh
 }
 
 /// Rebuild mesh from stored TerrainMeshData (for undo/redo/save/load).
-pub fn rebuild_terrain_mesh(mesh_data: &TerrainMeshData) -> Mesh {
-    build_terrain_mesh(&mesh_data.heights, mesh_data.resolution, mesh_data.size)
+pub fn rebuild_terrain_mesh(mesh_data: &TerrainMeshData, holes: &[bool]) -> Mesh {
+    build_terrain_mesh(&mesh_data.heights, mesh_data.resolution, mesh_data.size, holes)
 }
 
 /// Convert normalized height (0.0-1.0) to vertex color.
@@ -317,3 +416,112 @@ pub fn sculpt_heightmap(
         }
     }
 }
+
+/// Paint terrain holes: flag every quad whose center lies within `radius` of
+/// `position` (local terrain space x, z). `fill` clears the flags instead.
+/// The grid is resized to match `resolution` first. Returns true if any flag changed.
+pub fn paint_holes(
+    holes: &mut Vec<bool>,
+    resolution: u32,
+    size: f32,
+    position: [f32; 2],
+    radius: f32,
+    fill: bool,
+) -> bool {
+    let quads = resolution.saturating_sub(1) as usize;
+    holes.resize(quads * quads, false);
+    if quads == 0 {
+        return false;
+    }
+
+    let half_size = size / 2.0;
+    let step = size / quads as f32;
+    let mut changed = false;
+    for z in 0..quads {
+        for x in 0..quads {
+            let cx = -half_size + (x as f32 + 0.5) * step;
+            let cz = -half_size + (z as f32 + 0.5) * step;
+            let dist = ((cx - position[0]).powi(2) + (cz - position[1]).powi(2)).sqrt();
+            if dist > radius {
+                continue;
+            }
+
+            let flag = &mut holes[z * quads + x];
+            if *flag == fill {
+                *flag = !fill;
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(height_range: [f32; 2], slope_range: [f32; 2]) -> SplatLayer {
+        SplatLayer { height_range, slope_range, ..Default::default() }
+    }
+
+    #[test]
+    fn splat_weights_follow_height_and_slope() {
+        let layers = vec![
+            layer([0.0, 0.4], [0.0, 30.0]),  // grass: low and flat
+            layer([0.0, 1.0], [40.0, 90.0]), // rock: any steep slope
+            layer([0.8, 1.0], [0.0, 30.0]),  // snow: high and flat
+        ];
+
+        let low_flat = splat_weights(&layers, 0.1, 5.0);
+        assert_eq!(low_flat, [1.0, 0.0, 0.0, 0.0]);
+
+        let steep = splat_weights(&layers, 0.5, 60.0);
+        assert_eq!(steep, [0.0, 1.0, 0.0, 0.0]);
+
+        let peak = splat_weights(&layers, 0.95, 10.0);
+        assert_eq!(peak, [0.0, 0.0, 1.0, 0.0]);
+
+        // Adjacent bands blend across the feather and still sum to one.
+        let bands = vec![layer([0.0, 0.5], [0.0, 90.0]), layer([0.5, 1.0], [0.0, 90.0])];
+        let edge = splat_weights(&bands, 0.52, 0.0);
+        assert!(edge[0] > 0.0 && edge[0] < edge[1], "{:?}", edge);
+        assert!((edge.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn uncovered_points_fall_back_to_first_layer() {
+        let layers = vec![layer([0.0, 0.2], [0.0, 10.0]), layer([0.9, 1.0], [0.0, 10.0])];
+        assert_eq!(splat_weights(&layers, 0.5, 45.0), [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(splat_weights(&[], 0.5, 45.0), [0.0; MAX_SPLAT_LAYERS]);
+    }
+
+    #[test]
+    fn terrain_mesh_carries_splat_uvs() {
+        let mesh = build_terrain_mesh(&[0.0, 1.0, 2.0, 3.0], 2, 10.0, &[]);
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_some());
+        let Some(bevy::mesh::VertexAttributeValues::Float32x2(heights)) = mesh.attribute(Mesh::ATTRIBUTE_UV_1) else {
+            panic!("missing UV_1");
+        };
+        assert_eq!(heights[0][0], 0.0);
+        assert_eq!(heights[3][0], 1.0);
+    }
+
+    #[test]
+    fn painted_hole_removes_quad_triangles() {
+        // 5x5 vertices over 4 units: 16 quads, centers at ±0.5 and ±1.5.
+        let heights = vec![0.0; 25];
+        let mut holes = Vec::new();
+        assert!(paint_holes(&mut holes, 5, 4.0, [0.0, 0.0], 0.8, false));
+        assert_eq!(holes.len(), 16);
+        assert_eq!(holes.iter().filter(|h| **h).count(), 4);
+
+        let mesh = build_terrain_mesh(&heights, 5, 4.0, &holes);
+        assert_eq!(mesh.indices().map(|i| i.len()), Some((16 - 4) * 6));
+
+        // Filling restores the full surface; repainting the same state is a no-op.
+        assert!(paint_holes(&mut holes, 5, 4.0, [0.0, 0.0], 0.8, true));
+        assert!(!paint_holes(&mut holes, 5, 4.0, [0.0, 0.0], 0.8, true));
+        let mesh = build_terrain_mesh(&heights, 5, 4.0, &holes);
+        assert_eq!(mesh.indices().map(|i| i.len()), Some(16 * 6));
+    }
+}
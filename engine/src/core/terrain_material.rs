@@ -0,0 +1,240 @@
+//! Splatted terrain material.
+//!
+//! Terrain entities with splat layers render through `TerrainMaterial`, a
+//! StandardMaterial extension that blends up to four textures in
+//! `forge_terrain.wgsl`. Terrain without layers keeps its StandardMaterial.
+
+use bevy::prelude::*;
+use bevy::asset::uuid_handle;
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::{Shader, ShaderRef};
+
+use crate::core::asset_manager::TextureHandleMap;
+use crate::core::material::{apply_material_data_to_standard, MaterialData};
+use crate::core::terrain::{TerrainData, MAX_SPLAT_LAYERS};
+
+/// Stable handle for the terrain splat shader, registered via include_str!
+/// for the same reason as the forge effects shader.
+const FORGE_TERRAIN_SHADER_HANDLE: Handle<Shader> = uuid_handle!("f09eeffc-e750-4001-a000-000000000003");
+
+/// GPU extension for splatted terrain. Uniforms share binding 100; the four
+/// layer textures and the splat map share the layer 0 sampler.
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone, Default)]
+pub struct TerrainSplatExtension {
+    #[uniform(100)]
+    pub layer_count: u32,
+    /// 1 = weights come from `splat_map`; 0 = derived from height/slope bands.
+    #[uniform(100)]
+    pub use_splat_map: u32,
+    /// Bit i set when layer i has a texture; untextured layers use the vertex color.
+    #[uniform(100)]
+    pub textured_mask: u32,
+    #[uniform(100)]
+    pub tiling: Vec4,
+    #[uniform(100)]
+    pub height_min: Vec4,
+    #[uniform(100)]
+    pub height_max: Vec4,
+    #[uniform(100)]
+    pub slope_min: Vec4,
+    #[uniform(100)]
+    pub slope_max: Vec4,
+
+    #[texture(101)]
+    #[sampler(102)]
+    pub layer_0: Option<Handle<Image>>,
+    #[texture(103)]
+    pub layer_1: Option<Handle<Image>>,
+    #[texture(104)]
+    pub layer_2: Option<Handle<Image>>,
+    #[texture(105)]
+    pub layer_3: Option<Handle<Image>>,
+    #[texture(106)]
+    pub splat_map: Option<Handle<Image>>,
+}
+
+impl TerrainSplatExtension {
+    /// Build the extension from a terrain's splat settings. Textures that are
+    /// not loaded yet are left unbound and picked up on the next sync.
+    pub fn from_terrain(data: &TerrainData, textures: &TextureHandleMap) -> Self {
+        let lookup = |id: &Option<String>| id.as_ref().and_then(|id| textures.0.get(id)).cloned();
+
+        let mut ext = Self {
+            layer_count: data.splat_layers.len().min(MAX_SPLAT_LAYERS) as u32,
+            splat_map: lookup(&data.splat_map),
+            ..Default::default()
+        };
+        ext.use_splat_map = u32::from(ext.splat_map.is_some());
+
+        let slots = [&mut ext.layer_0, &mut ext.layer_1, &mut ext.layer_2, &mut ext.layer_3];
+        for (i, (layer, slot)) in data.splat_layers.iter().zip(slots).enumerate() {
+            *slot = lookup(&layer.texture_asset_id);
+            if slot.is_some() {
+                ext.textured_mask |= 1 << i;
+            }
+            ext.tiling[i] = layer.tiling;
+            ext.height_min[i] = layer.height_range[0];
+            ext.height_max[i] = layer.height_range[1];
+            ext.slope_min[i] = layer.slope_range[0];
+            ext.slope_max[i] = layer.slope_range[1];
+        }
+        ext
+    }
+}
+
+impl MaterialExtension for TerrainSplatExtension {
+    fn fragment_shader() -> ShaderRef {
+        FORGE_TERRAIN_SHADER_HANDLE.into()
+    }
+}
+
+/// Type alias for the splatted terrain material.
+pub type TerrainMaterial = ExtendedMaterial<StandardMaterial, TerrainSplatExtension>;
+
+/// Terrain entities with their current material, whichever kind it is.
+type TerrainMaterialQuery<'w, 's> = Query<'w, 's, (
+    Entity,
+    Ref<'static, TerrainData>,
+    Option<Ref<'static, MaterialData>>,
+    Option<&'static MeshMaterial3d<StandardMaterial>>,
+    Option<&'static MeshMaterial3d<TerrainMaterial>>,
+)>;
+
+/// System that keeps each terrain's material in step with its splat layers:
+/// swaps to a TerrainMaterial when layers are set, rebuilds it when layers,
+/// textures, or MaterialData change, and swaps back when layers are cleared.
+pub fn sync_terrain_materials(
+    mut commands: Commands,
+    texture_handles: Res<TextureHandleMap>,
+    query: TerrainMaterialQuery,
+    mut std_materials: ResMut<Assets<StandardMaterial>>,
+    mut terrain_materials: ResMut<Assets<TerrainMaterial>>,
+) {
+    let textures_changed = texture_handles.is_changed();
+    for (entity, data, mat_data, std_handle, terrain_handle) in query.iter() {
+        let mat_changed = mat_data.as_ref().is_some_and(|m| m.is_changed());
+        if !data.is_changed() && !textures_changed && !mat_changed {
+            continue;
+        }
+
+        match (data.splat_layers.is_empty(), std_handle, terrain_handle) {
+            (false, _, Some(handle)) => {
+                let Some(material) = terrain_materials.get_mut(&handle.0) else { continue };
+                material.extension = TerrainSplatExtension::from_terrain(&data, &texture_handles);
+                if let Some(mat_data) = &mat_data {
+                    apply_material_data_to_standard(&mut material.base, mat_data, &texture_handles);
+                }
+            }
+            (false, Some(handle), None) => {
+                let Some(base) = std_materials.get(&handle.0).cloned() else { continue };
+                let extension = TerrainSplatExtension::from_terrain(&data, &texture_handles);
+                let new_handle = terrain_materials.add(TerrainMaterial { base, extension });
+                commands.entity(entity)
+                    .remove::<MeshMaterial3d<StandardMaterial>>()
+                    .insert(MeshMaterial3d(new_handle));
+            }
+            (true, None, Some(handle)) => {
+                let Some(material) = terrain_materials.get(&handle.0) else { continue };
+                let new_handle = std_materials.add(material.base.clone());
+                commands.entity(entity)
+                    .remove::<MeshMaterial3d<TerrainMaterial>>()
+                    .insert(MeshMaterial3d(new_handle));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Plugin that registers the terrain splat material.
+pub struct TerrainMaterialPlugin;
+
+impl Plugin for TerrainMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        if let Err(err) = app.world_mut()
+            .resource_mut::<Assets<Shader>>()
+            .insert(
+                FORGE_TERRAIN_SHADER_HANDLE.id(),
+                Shader::from_wgsl(
+                    include_str!("../shaders/forge_terrain.wgsl"),
+                    "shaders/forge_terrain.wgsl",
+                ),
+            )
+        {
+            tracing::warn!("Failed to register forge terrain shader: {err}");
+        }
+
+        app.add_plugins(MaterialPlugin::<TerrainMaterial>::default())
+            .add_systems(Update, sync_terrain_materials);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::terrain::SplatLayer;
+
+    fn sync_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_asset::<StandardMaterial>()
+            .init_asset::<TerrainMaterial>()
+            .init_resource::<TextureHandleMap>()
+            .add_systems(Update, sync_terrain_materials);
+        app
+    }
+
+    #[test]
+    fn from_terrain_packs_layer_bands() {
+        let mut textures = TextureHandleMap::default();
+        textures.0.insert("rock".to_string(), Handle::default());
+        let data = TerrainData {
+            splat_layers: vec![
+                SplatLayer { tiling: 0.5, height_range: [0.0, 0.3], ..Default::default() },
+                SplatLayer {
+                    texture_asset_id: Some("rock".to_string()),
+                    slope_range: [35.0, 90.0],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let ext = TerrainSplatExtension::from_terrain(&data, &textures);
+        assert_eq!(ext.layer_count, 2);
+        assert_eq!(ext.textured_mask, 0b10);
+        assert_eq!(ext.use_splat_map, 0);
+        assert!(ext.layer_0.is_none() && ext.layer_1.is_some());
+        assert_eq!(ext.tiling.x, 0.5);
+        assert_eq!(ext.height_max.x, 0.3);
+        assert_eq!(ext.slope_min.y, 35.0);
+    }
+
+    #[test]
+    fn terrain_material_follows_splat_layers() {
+        let mut app = sync_app();
+        let std_handle = app.world_mut()
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial::default());
+        let data = TerrainData {
+            splat_layers: vec![SplatLayer::default(); 2],
+            ..Default::default()
+        };
+        let entity = app.world_mut().spawn((data, MeshMaterial3d(std_handle))).id();
+
+        app.update();
+        let world = app.world();
+        assert!(world.get::<MeshMaterial3d<StandardMaterial>>(entity).is_none());
+        let handle = world.get::<MeshMaterial3d<TerrainMaterial>>(entity).expect("TerrainMaterial handle");
+        let material = world.resource::<Assets<TerrainMaterial>>().get(handle).expect("TerrainMaterial asset");
+        assert_eq!(material.extension.layer_count, 2);
+
+        // Clearing the layers restores a plain StandardMaterial.
+        app.world_mut().get_mut::<TerrainData>(entity).expect("terrain").splat_layers.clear();
+        app.update();
+        let world = app.world();
+        assert!(world.get::<MeshMaterial3d<TerrainMaterial>>(entity).is_none());
+        assert!(world.get::<MeshMaterial3d<StandardMaterial>>(entity).is_some());
+    }
+}
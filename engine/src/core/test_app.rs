@@ -0,0 +1,101 @@
+//! Headless test harness: the engine's `App` without a window or canvas.
+//!
+//! `init_engine` needs a canvas and never returns on WASM, so tests build
+//! the app with `test_app()` instead and advance it a frame at a time with
+//! `TestApp::step`. On wasm32 the app gets the full `SelectionPlugin`; the
+//! native build, which has no bridge, gets the entity lifecycle systems.
+//! Systems whose resources only exist with a renderer are skipped with a
+//! warning rather than failing the frame.
+
+use bevy::prelude::*;
+
+use super::commands::{self, CommandResult};
+use super::pending_commands::{register_pending_commands, PendingCommands};
+#[cfg(not(target_arch = "wasm32"))]
+use super::{entity_factory, history::HistoryStack, selection::{Selection, SelectionChangedEvent}};
+
+/// A headless engine app, stepped by hand.
+pub struct TestApp {
+    pub app: App,
+}
+
+impl TestApp {
+    /// Run one frame.
+    pub fn step(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Point the command bridge at this app's `PendingCommands`. Needed again
+    /// whenever another app on the same thread registered its own.
+    pub fn register_pending(&mut self) -> &mut Self {
+        let pending = self.app.world_mut().resource_mut::<PendingCommands>().into_inner();
+        register_pending_commands(pending as *mut _);
+        self
+    }
+
+    /// Send a command as the editor would. It takes effect on the next step.
+    pub fn dispatch(&mut self, command: &str, payload: serde_json::Value) -> CommandResult {
+        self.register_pending();
+        commands::dispatch(command, payload)
+    }
+}
+
+/// Build a headless engine app with its command bridge registered.
+pub fn test_app() -> TestApp {
+    let mut app = App::new();
+    // Must come before any plugin adds systems
+    app.set_error_handler(bevy::ecs::error::warn);
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), TransformPlugin))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>();
+
+    #[cfg(target_arch = "wasm32")]
+    app.add_plugins(crate::bridge::SelectionPlugin);
+    #[cfg(not(target_arch = "wasm32"))]
+    app.init_resource::<PendingCommands>()
+        .init_resource::<HistoryStack>()
+        .init_resource::<Selection>()
+        .add_message::<SelectionChangedEvent>()
+        .add_systems(Update, (
+            entity_factory::apply_spawn_requests,
+            entity_factory::apply_spawn_configured_requests,
+            entity_factory::apply_delete_requests,
+        ));
+
+    let mut test_app = TestApp { app };
+    test_app.register_pending();
+    test_app
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::entity_id::{EntityId, EntityName};
+    use crate::core::pending_commands::EntityType;
+    use serde_json::json;
+
+    #[test]
+    fn spawned_cube_is_in_the_world_after_one_step() {
+        let mut app = test_app();
+        app.dispatch("spawn_entity", json!({ "entityType": "cube", "name": "Crate" })).unwrap();
+        app.step();
+
+        let world = app.world_mut();
+        let mut query = world.query::<(&EntityId, &EntityName, &EntityType, &Transform)>();
+        let spawned: Vec<_> = query.iter(world).collect();
+        assert_eq!(spawned.len(), 1);
+        let (_, name, entity_type, _) = spawned[0];
+        assert_eq!(name.0, "Crate");
+        assert_eq!(*entity_type, EntityType::Cube);
+    }
+}
@@ -1,8 +1,14 @@
-//! Tilemap data component for 2D tile-based levels.
+//! Tilemap data component for 2D tile-based levels, and the tile
+//! paint/erase/fill systems.
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::entity_id::EntityId;
+use super::history::{HistoryStack, UndoableAction};
+use super::pending_commands::PendingCommands;
+use super::tileset::{AutoTileSet, TilesetData, NEIGHBOR_OFFSETS};
+
 /// Tilemap configuration component.
 #[derive(Component, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -71,3 +77,352 @@ impl Default for TilemapData {
         }
     }
 }
+
+/// A rectangle of tile cells, in tile coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TileRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl TileRect {
+    /// Smallest rectangle containing every `(x, y)` cell, or `None` if empty.
+    pub fn bounding(cells: impl IntoIterator<Item = (u32, u32)>) -> Option<Self> {
+        let mut bounds: Option<(u32, u32, u32, u32)> = None;
+        for (x, y) in cells {
+            bounds = Some(match bounds {
+                None => (x, y, x, y),
+                Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+            });
+        }
+        bounds.map(|(x0, y0, x1, y1)| Self { x: x0, y: y0, width: x1 - x0 + 1, height: y1 - y0 + 1 })
+    }
+
+    /// The rectangle grown by one cell on every side, clipped to a `map_size` map.
+    pub fn expanded(self, map_size: [u32; 2]) -> Self {
+        let (x, y) = (self.x.saturating_sub(1), self.y.saturating_sub(1));
+        let right = (self.x + self.width + 1).min(map_size[0]);
+        let bottom = (self.y + self.height + 1).min(map_size[1]);
+        Self { x, y, width: right.saturating_sub(x), height: bottom.saturating_sub(y) }
+    }
+
+    /// Cells of the rectangle in row-major order.
+    pub fn cells(self) -> impl Iterator<Item = (u32, u32)> {
+        (self.y..self.y + self.height).flat_map(move |y| (self.x..self.x + self.width).map(move |x| (x, y)))
+    }
+}
+
+/// Result of editing tiles in one layer: the dirty rectangle and its tiles
+/// before and after the edit (row-major).
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileEdit {
+    pub rect: TileRect,
+    pub old_tiles: Vec<Option<u32>>,
+    pub new_tiles: Vec<Option<u32>>,
+}
+
+impl TilemapData {
+    fn tile_slot(&self, x: u32, y: u32) -> Option<usize> {
+        let [w, h] = self.map_size;
+        if x < w && y < h {
+            Some(y as usize * w as usize + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Tiles of `layer` inside `rect`, row-major. Out-of-bounds cells read as `None`.
+    pub fn read_region(&self, layer: usize, rect: TileRect) -> Vec<Option<u32>> {
+        let tiles = self.layers.get(layer).map(|l| l.tiles.as_slice()).unwrap_or_default();
+        rect.cells()
+            .map(|(x, y)| self.tile_slot(x, y).and_then(|i| tiles.get(i).copied().flatten()))
+            .collect()
+    }
+
+    /// Write row-major `tiles` into `rect` of `layer`, skipping out-of-bounds cells.
+    pub fn write_region(&mut self, layer: usize, rect: TileRect, tiles: &[Option<u32>]) {
+        let slots: Vec<Option<usize>> = rect.cells().map(|(x, y)| self.tile_slot(x, y)).collect();
+        let Some(layer) = self.layers.get_mut(layer) else { return };
+        for (slot, tile) in slots.into_iter().zip(tiles) {
+            if let Some(cell) = slot.and_then(|i| layer.tiles.get_mut(i)) {
+                *cell = *tile;
+            }
+        }
+    }
+
+    /// Bitmask of the neighbours of `(x, y)` in `layer` that hold a tile of the
+    /// auto-tile terrain. Cells outside the map never count.
+    pub fn neighbor_mask(&self, layer: usize, x: u32, y: u32, autotile: &AutoTileSet) -> u8 {
+        let tiles = self.layers.get(layer).map(|l| l.tiles.as_slice()).unwrap_or_default();
+        NEIGHBOR_OFFSETS.iter().fold(0, |mask, &(dx, dy, bit)| {
+            let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                return mask;
+            };
+            let is_terrain = self.tile_slot(nx, ny)
+                .and_then(|i| tiles.get(i).copied().flatten())
+                .is_some_and(|t| autotile.is_terrain(t));
+            if is_terrain { mask | bit } else { mask }
+        })
+    }
+
+    /// Re-pick every terrain tile in `rect` from its neighbours.
+    fn apply_autotile(&mut self, layer: usize, rect: TileRect, autotile: &AutoTileSet) {
+        // Resolving only swaps one terrain tile for another, so terrain
+        // membership (and thus every mask) is unaffected by update order.
+        let resolved: Vec<(usize, u32)> = rect.cells()
+            .filter_map(|(x, y)| {
+                let slot = self.tile_slot(x, y)?;
+                let tile = self.layers[layer].tiles.get(slot).copied().flatten()?;
+                if !autotile.is_terrain(tile) {
+                    return None;
+                }
+                autotile.resolve(self.neighbor_mask(layer, x, y, autotile)).map(|t| (slot, t))
+            })
+            .collect();
+        let tiles = &mut self.layers[layer].tiles;
+        for (slot, tile) in resolved {
+            tiles[slot] = Some(tile);
+        }
+    }
+
+    /// Set each `(x, y, tile)` in `layer`, ignoring cells outside the map.
+    /// With enabled auto-tiling, the edited cells and their neighbours are
+    /// then re-picked from the rules, and the dirty rectangle covers them all.
+    /// Returns `None` if the layer doesn't exist or no cell was in bounds.
+    pub fn edit_tiles(
+        &mut self,
+        layer: usize,
+        edits: &[(u32, u32, Option<u32>)],
+        autotile: Option<&AutoTileSet>,
+    ) -> Option<TileEdit> {
+        if layer >= self.layers.len() {
+            return None;
+        }
+        let in_bounds: Vec<(usize, Option<u32>, u32, u32)> = edits
+            .iter()
+            .filter_map(|&(x, y, tile)| self.tile_slot(x, y).map(|i| (i, tile, x, y)))
+            .collect();
+        let mut rect = TileRect::bounding(in_bounds.iter().map(|&(_, _, x, y)| (x, y)))?;
+        let autotile = autotile.filter(|a| a.enabled);
+        if autotile.is_some() {
+            rect = rect.expanded(self.map_size);
+        }
+
+        let old_tiles = self.read_region(layer, rect);
+        let tiles = &mut self.layers[layer].tiles;
+        for (i, tile, _, _) in in_bounds {
+            if let Some(cell) = tiles.get_mut(i) {
+                *cell = tile;
+            }
+        }
+        if let Some(autotile) = autotile {
+            self.apply_autotile(layer, rect, autotile);
+        }
+        let new_tiles = self.read_region(layer, rect);
+        Some(TileEdit { rect, old_tiles, new_tiles })
+    }
+}
+
+/// Apply one paint/erase/fill stroke: record it as a single history step and
+/// notify the UI of the dirty rectangle. Tile sprites are rebuilt by the
+/// renderer when it sees the changed data.
+fn apply_tile_stroke(
+    query: &mut Query<(&EntityId, &mut TilemapData)>,
+    tilesets: &Query<&TilesetData>,
+    history: &mut HistoryStack,
+    entity_id: &str,
+    layer: usize,
+    edits: &[(u32, u32, Option<u32>)],
+) {
+    let Some((_, mut tilemap)) = query.iter_mut().find(|(eid, _)| eid.0 == entity_id) else {
+        return;
+    };
+    let autotile = tilesets.iter()
+        .find(|t| t.asset_id == tilemap.tileset_asset_id)
+        .map(|t| &t.autotile);
+    let Some(edit) = tilemap.edit_tiles(layer, edits, autotile) else {
+        return;
+    };
+    if edit.old_tiles == edit.new_tiles {
+        return;
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    crate::bridge::events::emit_tilemap_tiles_changed(entity_id, &*tilemap, layer, edit.rect);
+    history.push(UndoableAction::TilemapTilesEdit {
+        entity_id: entity_id.to_string(),
+        layer,
+        rect: edit.rect,
+        old_tiles: edit.old_tiles,
+        new_tiles: edit.new_tiles,
+    });
+}
+
+/// System that applies pending paint_tile requests.
+pub fn apply_paint_tile_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut history: ResMut<HistoryStack>,
+    mut query: Query<(&EntityId, &mut TilemapData)>,
+    tilesets: Query<&TilesetData>,
+) {
+    for request in pending.paint_tile_requests.drain(..) {
+        let edit = (request.x as u32, request.y as u32, Some(request.tile_index));
+        apply_tile_stroke(&mut query, &tilesets, &mut history, &request.entity_id, request.layer, &[edit]);
+    }
+}
+
+/// System that applies pending erase_tile requests.
+pub fn apply_erase_tile_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut history: ResMut<HistoryStack>,
+    mut query: Query<(&EntityId, &mut TilemapData)>,
+    tilesets: Query<&TilesetData>,
+) {
+    for request in pending.erase_tile_requests.drain(..) {
+        let edit = (request.x as u32, request.y as u32, None);
+        apply_tile_stroke(&mut query, &tilesets, &mut history, &request.entity_id, request.layer, &[edit]);
+    }
+}
+
+/// System that applies pending fill_tiles requests.
+pub fn apply_fill_tiles_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut history: ResMut<HistoryStack>,
+    mut query: Query<(&EntityId, &mut TilemapData)>,
+    tilesets: Query<&TilesetData>,
+) {
+    for request in pending.fill_tiles_requests.drain(..) {
+        let edits: Vec<_> = request.tiles.iter()
+            .map(|t| (t.x as u32, t.y as u32, Some(t.tile_index)))
+            .collect();
+        apply_tile_stroke(&mut query, &tilesets, &mut history, &request.entity_id, request.layer, &edits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pending_commands::{EraseTileRequest, FillTilesRequest, TilePlacement};
+
+    fn tile_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .add_systems(Update, (apply_fill_tiles_requests, apply_erase_tile_requests).chain());
+        let entity = app.world_mut().spawn((EntityId("map".to_string()), TilemapData::default())).id();
+        (app, entity)
+    }
+
+    fn set_tiles(app: &App, entity: Entity) -> usize {
+        app.world().get::<TilemapData>(entity).unwrap().layers[0].tiles.iter().filter(|t| t.is_some()).count()
+    }
+
+    #[test]
+    fn tile_rect_bounding_and_cells() {
+        let rect = TileRect::bounding([(3, 1), (1, 2)]).unwrap();
+        assert_eq!(rect, TileRect { x: 1, y: 1, width: 3, height: 2 });
+        assert_eq!(rect.cells().count(), 6);
+        assert_eq!(rect.cells().next(), Some((1, 1)));
+        assert_eq!(TileRect::bounding([]), None);
+    }
+
+    #[test]
+    fn fill_four_by_four_then_erase_one_leaves_fifteen() {
+        let (mut app, entity) = tile_app();
+        let tiles = TileRect { x: 2, y: 3, width: 4, height: 4 }
+            .cells()
+            .map(|(x, y)| TilePlacement { x: x as usize, y: y as usize, tile_index: 7 })
+            .collect();
+        app.world_mut().resource_mut::<PendingCommands>().fill_tiles_requests.push(FillTilesRequest {
+            entity_id: "map".to_string(),
+            layer: 0,
+            tiles,
+        });
+        app.update();
+        assert_eq!(set_tiles(&app, entity), 16);
+
+        app.world_mut().resource_mut::<PendingCommands>().erase_tile_requests.push(EraseTileRequest {
+            entity_id: "map".to_string(),
+            layer: 0,
+            x: 3,
+            y: 4,
+        });
+        app.update();
+        assert_eq!(set_tiles(&app, entity), 15);
+
+        // One history step per stroke, each storing only its dirty region
+        let history = app.world_mut().resource_mut::<HistoryStack>().pop_undo();
+        let Some(UndoableAction::TilemapTilesEdit { rect, old_tiles, new_tiles, .. }) = history else {
+            panic!("expected a tile edit");
+        };
+        assert_eq!(rect, TileRect { x: 3, y: 4, width: 1, height: 1 });
+        assert_eq!((old_tiles, new_tiles), (vec![Some(7)], vec![None]));
+        let fill = app.world_mut().resource_mut::<HistoryStack>().pop_undo();
+        assert!(matches!(fill, Some(UndoableAction::TilemapTilesEdit { rect, .. }) if rect.width == 4 && rect.height == 4));
+    }
+
+    #[test]
+    fn edits_outside_the_map_are_ignored() {
+        let mut data = TilemapData::default();
+        assert_eq!(data.edit_tiles(0, &[(100, 0, Some(1))], None), None);
+        assert_eq!(data.edit_tiles(5, &[(0, 0, Some(1))], None), None);
+    }
+
+    #[test]
+    fn painting_a_ground_block_picks_center_edge_and_corner_tiles() {
+        use crate::core::pending_commands::PaintTileRequest;
+        use crate::core::tileset::*;
+
+        // A 4-bit (edges only) ground terrain: tile = 10 + edge mask, so
+        // 10 + N|E|S|W is the center, 10 + E|S the top-left corner, etc.
+        let care = NEIGHBOR_N | NEIGHBOR_E | NEIGHBOR_S | NEIGHBOR_W;
+        let rules = (0..=u8::MAX)
+            .filter(|mask| mask & !care == 0)
+            .map(|mask| AutoTileRule { mask, care, tile_index: 10 + mask as u32 })
+            .collect();
+        let tileset = TilesetData {
+            asset_id: "ground".to_string(),
+            tile_size: [16, 16],
+            grid_size: [16, 16],
+            spacing: 0,
+            margin: 0,
+            tiles: Vec::new(),
+            autotile: AutoTileSet { enabled: true, rules },
+        };
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PendingCommands>()
+            .init_resource::<HistoryStack>()
+            .add_systems(Update, apply_paint_tile_requests);
+        app.world_mut().spawn((EntityId("tiles".to_string()), tileset));
+        let map = TilemapData { tileset_asset_id: "ground".to_string(), ..default() };
+        let entity = app.world_mut().spawn((EntityId("map".to_string()), map)).id();
+
+        for (x, y) in (TileRect { x: 1, y: 1, width: 3, height: 3 }).cells() {
+            app.world_mut().resource_mut::<PendingCommands>().paint_tile_requests.push(PaintTileRequest {
+                entity_id: "map".to_string(),
+                layer: 0,
+                x: x as usize,
+                y: y as usize,
+                tile_index: 10,
+            });
+        }
+        app.update();
+
+        let data = app.world().get::<TilemapData>(entity).unwrap();
+        let block = data.read_region(0, TileRect { x: 1, y: 1, width: 3, height: 3 });
+        let tile = |mask: u8| Some(10 + mask as u32);
+        assert_eq!(block, vec![
+            tile(NEIGHBOR_E | NEIGHBOR_S), tile(NEIGHBOR_E | NEIGHBOR_S | NEIGHBOR_W), tile(NEIGHBOR_S | NEIGHBOR_W),
+            tile(NEIGHBOR_N | NEIGHBOR_E | NEIGHBOR_S), tile(care), tile(NEIGHBOR_N | NEIGHBOR_S | NEIGHBOR_W),
+            tile(NEIGHBOR_N | NEIGHBOR_E), tile(NEIGHBOR_N | NEIGHBOR_E | NEIGHBOR_W), tile(NEIGHBOR_N | NEIGHBOR_W),
+        ]);
+        // Painting never spills onto the empty cells around the block
+        assert_eq!(data.read_region(0, TileRect { x: 0, y: 0, width: 5, height: 1 }), vec![None; 5]);
+    }
+}
@@ -1,8 +1,12 @@
-//! Tileset data component for sprite sheet-based tile atlases.
+//! Tileset data component for sprite sheet-based tile atlases, including
+//! auto-tiling (bitmask) rules.
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::entity_id::EntityId;
+use super::pending_commands::PendingCommands;
+
 /// Tileset configuration component (stores tileset metadata).
 #[derive(Component, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -13,6 +17,9 @@ pub struct TilesetData {
     pub spacing: u32,
     pub margin: u32,
     pub tiles: Vec<TileMetadata>,
+    /// Auto-tiling rules applied when painting tilemaps that use this tileset.
+    #[serde(default, skip_serializing_if = "AutoTileSet::is_empty")]
+    pub autotile: AutoTileSet,
 }
 
 /// Metadata for a single tile in a tileset.
@@ -32,3 +39,118 @@ pub struct TileAnimation {
     pub frame_ids: Vec<u32>,
     pub frame_duration: f32,
 }
+
+// Neighbour bits of an auto-tile mask. Tilemap rows grow downward, so north is y - 1.
+pub const NEIGHBOR_N: u8 = 1;
+pub const NEIGHBOR_NE: u8 = 1 << 1;
+pub const NEIGHBOR_E: u8 = 1 << 2;
+pub const NEIGHBOR_SE: u8 = 1 << 3;
+pub const NEIGHBOR_S: u8 = 1 << 4;
+pub const NEIGHBOR_SW: u8 = 1 << 5;
+pub const NEIGHBOR_W: u8 = 1 << 6;
+pub const NEIGHBOR_NW: u8 = 1 << 7;
+
+/// Neighbour offsets `(dx, dy, bit)`, clockwise from north.
+pub const NEIGHBOR_OFFSETS: [(i32, i32, u8); 8] = [
+    (0, -1, NEIGHBOR_N),
+    (1, -1, NEIGHBOR_NE),
+    (1, 0, NEIGHBOR_E),
+    (1, 1, NEIGHBOR_SE),
+    (0, 1, NEIGHBOR_S),
+    (-1, 1, NEIGHBOR_SW),
+    (-1, 0, NEIGHBOR_W),
+    (-1, -1, NEIGHBOR_NW),
+];
+
+fn all_neighbors() -> u8 {
+    u8::MAX
+}
+
+/// Maps a neighbour bitmask to the tile index to use.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoTileRule {
+    /// Neighbours that must be the same terrain (`NEIGHBOR_*` bits).
+    pub mask: u8,
+    /// Neighbours this rule looks at; others may be anything. Defaults to all eight.
+    #[serde(default = "all_neighbors")]
+    pub care: u8,
+    pub tile_index: u32,
+}
+
+impl AutoTileRule {
+    pub fn matches(&self, neighbors: u8) -> bool {
+        neighbors & self.care == self.mask & self.care
+    }
+}
+
+/// A terrain's auto-tiling rules. The terrain is made of every tile index its
+/// rules produce: a cell counts as a neighbour when it holds one of them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoTileSet {
+    pub enabled: bool,
+    /// Checked in order; the first match wins.
+    pub rules: Vec<AutoTileRule>,
+}
+
+impl AutoTileSet {
+    pub fn is_empty(&self) -> bool {
+        !self.enabled && self.rules.is_empty()
+    }
+
+    /// Whether `tile` belongs to this terrain.
+    pub fn is_terrain(&self, tile: u32) -> bool {
+        self.rules.iter().any(|r| r.tile_index == tile)
+    }
+
+    /// Tile index for a terrain cell with the given neighbours, if a rule matches.
+    pub fn resolve(&self, neighbors: u8) -> Option<u32> {
+        self.rules.iter().find(|r| r.matches(neighbors)).map(|r| r.tile_index)
+    }
+}
+
+/// System that applies pending set_tileset_autotile requests.
+pub fn apply_set_tileset_autotile_requests(
+    mut pending: ResMut<PendingCommands>,
+    mut query: Query<(&EntityId, &mut TilesetData)>,
+) {
+    for request in pending.set_tileset_autotile_requests.drain(..) {
+        let Some((_, mut tileset)) = query.iter_mut().find(|(eid, _)| eid.0 == request.entity_id) else {
+            tracing::warn!("set_tileset_autotile: entity {} has no tileset", request.entity_id);
+            continue;
+        };
+        tileset.autotile = request.autotile;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_ignores_neighbours_outside_care() {
+        let rule = AutoTileRule { mask: NEIGHBOR_N, care: NEIGHBOR_N | NEIGHBOR_S, tile_index: 1 };
+        assert!(rule.matches(NEIGHBOR_N | NEIGHBOR_E));
+        assert!(!rule.matches(NEIGHBOR_N | NEIGHBOR_S));
+    }
+
+    #[test]
+    fn autotile_rules_round_trip_with_tileset() {
+        let json = serde_json::json!({
+            "assetId": "terrain",
+            "tileSize": [16, 16],
+            "gridSize": [8, 8],
+            "spacing": 0,
+            "margin": 0,
+            "tiles": [],
+            "autotile": { "enabled": true, "rules": [{ "mask": 255, "tileIndex": 4 }] },
+        });
+        let tileset: TilesetData = serde_json::from_value(json).unwrap();
+        assert_eq!(tileset.autotile.rules[0].care, u8::MAX);
+        assert_eq!(tileset.autotile.resolve(255), Some(4));
+
+        let round_trip: TilesetData = serde_json::from_value(serde_json::to_value(&tileset).unwrap()).unwrap();
+        assert_eq!(round_trip.autotile, tileset.autotile);
+    }
+}
@@ -1,7 +1,17 @@
-//! Viewport management - handles canvas resize and camera projection sync.
+//! Viewport management - handles canvas resize, camera projection sync, and
+//! split layouts that show several views of the scene at once.
 
+use bevy::camera::{ScalingMode, Viewport};
+use bevy::math::URect;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_panorbit_camera::PanOrbitCamera;
 use serde::{Deserialize, Serialize};
 
+use super::camera::EditorCamera;
+use super::camera_presets::CameraPreset;
+use super::engine_mode::EngineMode;
+
 /// Payload received from React when the viewport resizes.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResizePayload {
@@ -109,6 +119,208 @@ pub fn get_viewport() -> Result<ViewportResource, String> {
     VIEWPORT.read().map(|v| v.clone()).map_err(|e| e.to_string())
 }
 
+/// How the editor canvas is divided between views.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewportLayout {
+    /// One perspective view filling the canvas.
+    #[default]
+    Single,
+    /// Perspective and front views side by side.
+    SplitHorizontal,
+    /// Perspective view above the top view.
+    SplitVertical,
+    /// Top, front, right and perspective views in a 2×2 grid.
+    Quad,
+}
+
+impl ViewportLayout {
+    /// Views in this layout with their canvas rectangles as fractions
+    /// `[x, y, width, height]` from the top-left corner.
+    pub fn panes(self) -> &'static [(CameraPreset, [f32; 4])] {
+        match self {
+            ViewportLayout::Single => &[(CameraPreset::Perspective, [0.0, 0.0, 1.0, 1.0])],
+            ViewportLayout::SplitHorizontal => &[
+                (CameraPreset::Perspective, [0.0, 0.0, 0.5, 1.0]),
+                (CameraPreset::Front, [0.5, 0.0, 0.5, 1.0]),
+            ],
+            ViewportLayout::SplitVertical => &[
+                (CameraPreset::Perspective, [0.0, 0.0, 1.0, 0.5]),
+                (CameraPreset::Top, [0.0, 0.5, 1.0, 0.5]),
+            ],
+            ViewportLayout::Quad => &[
+                (CameraPreset::Top, [0.0, 0.0, 0.5, 0.5]),
+                (CameraPreset::Front, [0.5, 0.0, 0.5, 0.5]),
+                (CameraPreset::Right, [0.0, 0.5, 0.5, 0.5]),
+                (CameraPreset::Perspective, [0.5, 0.5, 0.5, 0.5]),
+            ],
+        }
+    }
+
+    /// Physical pixel rectangle of each view on a canvas of `size`. Shared
+    /// edges round the same way on both sides, so the views tile the canvas.
+    pub fn pane_rects(self, size: UVec2) -> Vec<(CameraPreset, URect)> {
+        let edge = |fraction: f32, extent: u32| (fraction * extent as f32).round() as u32;
+        self.panes()
+            .iter()
+            .map(|(view, [x, y, width, height])| {
+                let min = UVec2::new(edge(*x, size.x), edge(*y, size.y));
+                let max = UVec2::new(edge(x + width, size.x), edge(y + height, size.y));
+                (*view, URect::from_corners(min, max))
+            })
+            .collect()
+    }
+}
+
+/// Extra camera rendering an axis view of a split layout. The perspective
+/// view is always the `EditorCamera` itself.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ViewportPane {
+    pub view: CameraPreset,
+}
+
+/// Distance of axis-view cameras from the orbit focus, so the default
+/// orthographic depth range reaches equally far past it.
+const PANE_CAMERA_DISTANCE: f32 = 500.0;
+
+/// Transform of an axis-view camera looking at `focus`.
+fn pane_transform(view: CameraPreset, focus: Vec3) -> Transform {
+    let (offset, up) = match view {
+        CameraPreset::Top => (Vec3::Y, Vec3::NEG_Z),
+        CameraPreset::Right => (Vec3::X, Vec3::Y),
+        CameraPreset::Front | CameraPreset::Perspective => (Vec3::Z, Vec3::Y),
+    };
+    Transform::from_translation(focus + offset * PANE_CAMERA_DISTANCE).looking_at(focus, up)
+}
+
+/// Orthographic projection showing `view_height` world units vertically.
+fn pane_projection(view_height: f32) -> Projection {
+    Projection::Orthographic(OrthographicProjection {
+        scaling_mode: ScalingMode::FixedVertical { viewport_height: view_height },
+        ..OrthographicProjection::default_3d()
+    })
+}
+
+fn to_viewport(rect: URect) -> Viewport {
+    Viewport {
+        physical_position: rect.min,
+        physical_size: rect.size(),
+        ..default()
+    }
+}
+
+fn same_viewport(a: &Option<Viewport>, b: &Option<Viewport>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => a.physical_position == b.physical_position && a.physical_size == b.physical_size,
+        _ => false,
+    }
+}
+
+/// Editor plugin that arranges the editor cameras per `ViewportLayout`.
+pub struct ViewportLayoutPlugin;
+
+impl Plugin for ViewportLayoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ViewportLayout>()
+            .add_systems(Update, sync_viewport_panes);
+    }
+}
+
+/// System that fits the editor camera into its pane, spawns and despawns the
+/// axis-view cameras, and keeps them centred on the orbit focus and zoomed to
+/// the orbit radius. Outside Edit mode the canvas shows a single view.
+#[allow(clippy::type_complexity)]
+pub fn sync_viewport_panes(
+    layout: Res<ViewportLayout>,
+    mode: Res<EngineMode>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut editor_camera: Query<(&mut Camera, Option<&PanOrbitCamera>), (With<EditorCamera>, Without<ViewportPane>)>,
+    mut panes: Query<(Entity, &ViewportPane, &mut Camera, &mut Transform, &mut Projection), Without<EditorCamera>>,
+    mut commands: Commands,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let size = window.physical_size();
+    if size.x == 0 || size.y == 0 {
+        return;
+    }
+    let layout = if mode.is_edit() { *layout } else { ViewportLayout::Single };
+    let rects = layout.pane_rects(size);
+
+    let (mut focus, mut view_height) = (Vec3::ZERO, 10.0);
+    if let Ok((mut camera, orbit)) = editor_camera.single_mut() {
+        let viewport = match layout {
+            ViewportLayout::Single => None,
+            _ => rects.iter()
+                .find(|(view, _)| *view == CameraPreset::Perspective)
+                .map(|(_, rect)| to_viewport(*rect)),
+        };
+        if !same_viewport(&camera.viewport, &viewport) {
+            camera.viewport = viewport;
+        }
+        if let Some(orbit) = orbit {
+            focus = orbit.focus;
+            view_height = orbit.radius.unwrap_or(view_height);
+        }
+    }
+
+    // Axis views render after the editor camera, in layout order.
+    let axis_views: Vec<(isize, CameraPreset, URect)> = rects.iter()
+        .filter(|(view, _)| *view != CameraPreset::Perspective)
+        .enumerate()
+        .map(|(i, (view, rect))| (i as isize + 1, *view, *rect))
+        .collect();
+
+    let mut present = Vec::new();
+    for (entity, pane, mut camera, mut transform, mut projection) in panes.iter_mut() {
+        let Some((order, _, rect)) = axis_views.iter().find(|(_, view, _)| *view == pane.view) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        present.push(pane.view);
+
+        let viewport = Some(to_viewport(*rect));
+        if !same_viewport(&camera.viewport, &viewport) || camera.order != *order {
+            camera.viewport = viewport;
+            camera.order = *order;
+        }
+        transform.set_if_neq(pane_transform(pane.view, focus));
+        let zoomed = matches!(
+            &*projection,
+            Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical { viewport_height },
+                ..
+            }) if *viewport_height == view_height
+        );
+        if !zoomed {
+            *projection = pane_projection(view_height);
+        }
+    }
+
+    for (order, view, rect) in axis_views {
+        if present.contains(&view) {
+            continue;
+        }
+        commands.spawn((
+            ViewportPane { view },
+            Camera3d::default(),
+            Camera {
+                order,
+                viewport: Some(to_viewport(rect)),
+                ..default()
+            },
+            pane_projection(view_height),
+            pane_transform(view, focus),
+            #[cfg(feature = "webgl2")]
+            Msaa::Off,
+            #[cfg(feature = "webgpu")]
+            Msaa::Sample4,
+        ));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,4 +362,60 @@ mod tests {
         let resource = ViewportResource::default();
         assert_eq!(resource.aspect_ratio(), 1.0);
     }
+
+    fn layout_app(layout: ViewportLayout) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(layout)
+            .init_resource::<EngineMode>()
+            .add_systems(Update, sync_viewport_panes);
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        app.world_mut().spawn((EditorCamera, Camera::default()));
+        app
+    }
+
+    fn camera_rects(app: &mut App) -> Vec<URect> {
+        let mut query = app.world_mut().query::<&Camera>();
+        query.iter(app.world())
+            .map(|camera| {
+                let viewport = camera.viewport.as_ref().expect("split views set a viewport");
+                URect::from_corners(viewport.physical_position, viewport.physical_position + viewport.physical_size)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn quad_layout_partitions_canvas_between_four_cameras() {
+        let mut app = layout_app(ViewportLayout::Quad);
+        app.update();
+
+        let size = Window::default().physical_size();
+        let rects = camera_rects(&mut app);
+        assert_eq!(rects.len(), 4);
+        let area: u32 = rects.iter().map(|r| r.width() * r.height()).sum();
+        assert_eq!(area, size.x * size.y);
+        for (i, a) in rects.iter().enumerate() {
+            assert!(a.max.x <= size.x && a.max.y <= size.y);
+            for b in &rects[i + 1..] {
+                assert!(a.intersect(*b).is_empty(), "{a:?} overlaps {b:?}");
+            }
+        }
+
+        let mut panes = app.world_mut().query::<(&ViewportPane, &Projection)>();
+        assert_eq!(panes.iter(app.world()).count(), 3);
+        assert!(panes.iter(app.world()).all(|(_, p)| matches!(p, Projection::Orthographic(_))));
+    }
+
+    #[test]
+    fn single_layout_removes_axis_views() {
+        let mut app = layout_app(ViewportLayout::SplitVertical);
+        app.update();
+        assert_eq!(app.world_mut().query::<&ViewportPane>().iter(app.world()).count(), 1);
+
+        app.insert_resource(ViewportLayout::Single);
+        app.update();
+        assert_eq!(app.world_mut().query::<&ViewportPane>().iter(app.world()).count(), 0);
+        let mut editor = app.world_mut().query_filtered::<&Camera, With<EditorCamera>>();
+        assert!(editor.single(app.world()).unwrap().viewport.is_none());
+    }
 }
@@ -0,0 +1,32 @@
+//! Forge debug view shader.
+//!
+//! Unlit fragment shader behind the `normals` and `uv_checker` render modes.
+//! Mode 0 maps the world-space normal to RGB; mode 1 draws a checkerboard in
+//! texture space tinted by the UV coordinates, so stretching, seams and
+//! flipped islands stand out. Meshes without UVs are drawn magenta.
+
+#import bevy_pbr::forward_io::VertexOutput
+
+struct DebugViewMaterial {
+    mode: u32,
+    checker_count: f32,
+    padding: vec2<f32>,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> material: DebugViewMaterial;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    if (material.mode == 0u) {
+        return vec4<f32>(normalize(in.world_normal) * 0.5 + 0.5, 1.0);
+    }
+#ifdef VERTEX_UVS_A
+    let cell = floor(in.uv * material.checker_count);
+    let odd = abs(cell.x + cell.y) % 2.0;
+    let shade = mix(0.35, 1.0, odd);
+    let tint = vec3<f32>(fract(in.uv), 0.5);
+    return vec4<f32>(mix(vec3<f32>(1.0), tint, 0.6) * shade, 1.0);
+#else
+    return vec4<f32>(1.0, 0.0, 1.0, 1.0);
+#endif
+}
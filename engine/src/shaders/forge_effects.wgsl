@@ -7,13 +7,21 @@
 //! 4. Lava/Flow - scrolling UV + noise distortion
 //! 5. Toon - quantized lighting bands (cel-shading)
 //! 6. Fresnel Glow - rim emission overlay
+//!
+//! Independently of the effect, `triplanar` re-samples the base color texture
+//! by world-space triplanar projection for meshes without usable UVs.
 
 #import bevy_pbr::{
     pbr_fragment::pbr_input_from_standard_material,
     pbr_functions::alpha_discard,
+    pbr_types::STANDARD_MATERIAL_FLAGS_BASE_COLOR_TEXTURE_BIT,
     mesh_view_bindings::view,
 }
 
+#ifndef BINDLESS
+#import bevy_pbr::pbr_bindings::{material, base_color_texture, base_color_sampler}
+#endif
+
 #ifdef PREPASS_PIPELINE
 #import bevy_pbr::{
     prepass_io::{VertexOutput, FragmentOutput},
@@ -47,6 +55,9 @@ struct ForgeShaderUniforms {
     custom_params_3: vec4<f32>,
     // Elapsed time in seconds, synced from Bevy Time resource each frame.
     time: f32,
+    // Triplanar base color projection (mirrors MaterialData)
+    triplanar: u32,
+    triplanar_scale: f32,
 }
 
 @group(2) @binding(100)
@@ -89,6 +100,22 @@ fn fbm_noise(p: vec2<f32>, octaves: i32) -> f32 {
     return value;
 }
 
+// --- Triplanar projection ---
+
+#ifndef BINDLESS
+fn triplanar_base_color(world_position: vec3<f32>, world_normal: vec3<f32>, scale: f32) -> vec4<f32> {
+    // Sharpened normal weights so each face is dominated by one projection.
+    var weights = pow(abs(world_normal), vec3(4.0));
+    weights = weights / max(weights.x + weights.y + weights.z, 0.0001);
+
+    let p = world_position * scale;
+    let x = textureSample(base_color_texture, base_color_sampler, p.zy);
+    let y = textureSample(base_color_texture, base_color_sampler, p.xz);
+    let z = textureSample(base_color_texture, base_color_sampler, p.xy);
+    return x * weights.x + y * weights.y + z * weights.z;
+}
+#endif
+
 // --- Mega-shader slot functions ---
 // FORGE_CUSTOM_SLOT_INJECTION_START
 fn custom_shader_1(color: vec4<f32>, uv: vec2<f32>, time: f32, params: array<f32, 16>) -> vec4<f32> { return color; }
@@ -111,6 +138,21 @@ fn fragment(
     // Generate the base PBR input
     var pbr_input = pbr_input_from_standard_material(in, is_front);
 
+    #ifndef PREPASS_PIPELINE
+    #ifndef BINDLESS
+        // Replace the UV-sampled base color with a world-space triplanar sample.
+        if (forge_uniforms.triplanar != 0u
+            && (material.flags & STANDARD_MATERIAL_FLAGS_BASE_COLOR_TEXTURE_BIT) != 0u) {
+            let projected = triplanar_base_color(
+                in.world_position.xyz,
+                normalize(in.world_normal),
+                forge_uniforms.triplanar_scale,
+            );
+            pbr_input.material.base_color = material.base_color * projected;
+        }
+    #endif
+    #endif
+
     // Apply standard PBR lighting to get the base lit color
     var out: FragmentOutput;
 
@@ -0,0 +1,59 @@
+//! Forge GPU instancing shader.
+//!
+//! Draws every copy of an instanced array in a single call. Each instance
+//! supplies its world matrix and base color as per-instance vertex attributes
+//! (locations 8-12), so no per-mesh uniform is bound.
+
+#import bevy_pbr::mesh_view_bindings::view
+
+struct Vertex {
+    @location(0) position: vec3<f32>,
+#ifdef VERTEX_NORMALS
+    @location(1) normal: vec3<f32>,
+#endif
+    @location(8) world_from_local_0: vec4<f32>,
+    @location(9) world_from_local_1: vec4<f32>,
+    @location(10) world_from_local_2: vec4<f32>,
+    @location(11) world_from_local_3: vec4<f32>,
+    @location(12) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_normal: vec3<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    let world_from_local = mat4x4<f32>(
+        vertex.world_from_local_0,
+        vertex.world_from_local_1,
+        vertex.world_from_local_2,
+        vertex.world_from_local_3,
+    );
+    let world_position = world_from_local * vec4<f32>(vertex.position, 1.0);
+
+    var out: VertexOutput;
+    out.clip_position = view.clip_from_world * world_position;
+#ifdef VERTEX_NORMALS
+    out.world_normal = normalize((world_from_local * vec4<f32>(vertex.normal, 0.0)).xyz);
+#else
+    out.world_normal = vec3<f32>(0.0, 1.0, 0.0);
+#endif
+    out.color = vertex.color;
+    return out;
+}
+
+// Fixed key light direction (towards the light). Instanced arrays skip the
+// full PBR light loop to keep the per-instance cost minimal.
+const KEY_LIGHT_DIR: vec3<f32> = vec3<f32>(0.4, 0.8, 0.45);
+const AMBIENT: f32 = 0.25;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let n = normalize(in.world_normal);
+    let diffuse = max(dot(n, normalize(KEY_LIGHT_DIR)), 0.0);
+    let shade = AMBIENT + (1.0 - AMBIENT) * diffuse;
+    return vec4<f32>(in.color.rgb * shade, in.color.a);
+}
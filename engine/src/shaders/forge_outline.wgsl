@@ -0,0 +1,45 @@
+//! Forge selection outline shader.
+//!
+//! Draws the back faces of a selected mesh pushed out along their normals by
+//! `thickness` pixels in clip space, so the outline keeps the same width at
+//! any distance. The object itself hides the shell everywhere but the rim.
+
+#import bevy_pbr::{
+    mesh_functions::{get_world_from_local, mesh_position_local_to_world, mesh_normal_local_to_world},
+    mesh_view_bindings::view,
+    view_transformations::position_world_to_clip,
+}
+
+struct OutlineMaterial {
+    color: vec4<f32>,
+    thickness: f32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> material: OutlineMaterial;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> @builtin(position) vec4<f32> {
+    let world_from_local = get_world_from_local(vertex.instance_index);
+    let world_position = mesh_position_local_to_world(world_from_local, vec4<f32>(vertex.position, 1.0));
+    let world_normal = mesh_normal_local_to_world(vertex.normal, vertex.instance_index);
+
+    var clip = position_world_to_clip(world_position.xyz);
+    let clip_normal = (view.clip_from_world * vec4<f32>(world_normal, 0.0)).xy;
+    if (dot(clip_normal, clip_normal) > 1e-12) {
+        // Pixels -> NDC is 2 / viewport size; scale by w to undo the divide.
+        let offset = normalize(clip_normal) * material.thickness * 2.0 / view.viewport.zw;
+        clip = vec4<f32>(clip.xy + offset * clip.w, clip.zw);
+    }
+    return clip;
+}
+
+@fragment
+fn fragment() -> @location(0) vec4<f32> {
+    return material.color;
+}
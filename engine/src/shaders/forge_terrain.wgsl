@@ -0,0 +1,132 @@
+//! Forge terrain splat shader.
+//!
+//! Extends StandardMaterial on terrain entities. The base color is replaced by
+//! up to four texture layers, weighted either by a painted RGBA splat map or,
+//! without one, by each layer's normalized height and slope bands. Layers
+//! without a texture fall back to the height-based vertex color.
+
+#import bevy_pbr::{
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::alpha_discard,
+}
+
+#ifdef PREPASS_PIPELINE
+#import bevy_pbr::{
+    prepass_io::{VertexOutput, FragmentOutput},
+    pbr_deferred_functions::deferred_output,
+}
+#else
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_functions::{apply_pbr_lighting, main_pass_post_lighting_processing},
+}
+#endif
+
+struct TerrainSplatUniforms {
+    layer_count: u32,
+    use_splat_map: u32,
+    textured_mask: u32,
+    tiling: vec4<f32>,
+    height_min: vec4<f32>,
+    height_max: vec4<f32>,
+    slope_min: vec4<f32>,
+    slope_max: vec4<f32>,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var<uniform> terrain: TerrainSplatUniforms;
+@group(#{MATERIAL_BIND_GROUP}) @binding(101) var layer_0_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(102) var layer_sampler: sampler;
+@group(#{MATERIAL_BIND_GROUP}) @binding(103) var layer_1_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(104) var layer_2_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(105) var layer_3_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(106) var splat_map_texture: texture_2d<f32>;
+
+// Feather widths for band edges (normalized height / degrees).
+const HEIGHT_FEATHER: f32 = 0.05;
+const SLOPE_FEATHER: f32 = 5.0;
+
+fn band_weight(value: f32, lo: f32, hi: f32, feather: f32) -> f32 {
+    return smoothstep(lo - feather, lo, value) * (1.0 - smoothstep(hi, hi + feather, value));
+}
+
+// Mirrors terrain::splat_weights on the Rust side.
+fn auto_splat_weights(height: f32, slope: f32) -> vec4<f32> {
+    var weights = vec4<f32>(0.0);
+    for (var i = 0u; i < min(terrain.layer_count, 4u); i++) {
+        weights[i] = band_weight(height, terrain.height_min[i], terrain.height_max[i], HEIGHT_FEATHER)
+            * band_weight(slope, terrain.slope_min[i], terrain.slope_max[i], SLOPE_FEATHER);
+    }
+    let total = weights.x + weights.y + weights.z + weights.w;
+    if (total <= 1e-4) {
+        return vec4<f32>(1.0, 0.0, 0.0, 0.0);
+    }
+    return weights / total;
+}
+
+// Tiled layer sample. Gradients come from the unwrapped coordinates so the
+// fract() wrap does not produce mip seams.
+fn sample_layer(index: u32, world_xz: vec2<f32>, ddx: vec2<f32>, ddy: vec2<f32>) -> vec4<f32> {
+    let tiling = terrain.tiling[index];
+    let uv = fract(world_xz * tiling);
+    switch index {
+        case 0u: { return textureSampleGrad(layer_0_texture, layer_sampler, uv, ddx * tiling, ddy * tiling); }
+        case 1u: { return textureSampleGrad(layer_1_texture, layer_sampler, uv, ddx * tiling, ddy * tiling); }
+        case 2u: { return textureSampleGrad(layer_2_texture, layer_sampler, uv, ddx * tiling, ddy * tiling); }
+        default: { return textureSampleGrad(layer_3_texture, layer_sampler, uv, ddx * tiling, ddy * tiling); }
+    }
+}
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+#ifdef VERTEX_UVS_A
+    let world_xz = in.world_position.xz;
+    let ddx = dpdx(world_xz);
+    let ddy = dpdy(world_xz);
+    let uv_ddx = dpdx(in.uv);
+    let uv_ddy = dpdy(in.uv);
+
+    var weights: vec4<f32>;
+    if (terrain.use_splat_map != 0u) {
+        let painted = textureSampleGrad(splat_map_texture, layer_sampler, in.uv, uv_ddx, uv_ddy);
+        weights = painted / max(painted.x + painted.y + painted.z + painted.w, 1e-4);
+    } else {
+#ifdef VERTEX_UVS_B
+        let height = in.uv_b.x;
+#else
+        let height = 0.5;
+#endif
+        let slope = degrees(acos(clamp(normalize(in.world_normal).y, -1.0, 1.0)));
+        weights = auto_splat_weights(height, slope);
+    }
+
+    let vertex_color = pbr_input.material.base_color;
+    var color = vec3<f32>(0.0);
+    for (var i = 0u; i < min(terrain.layer_count, 4u); i++) {
+        if (weights[i] <= 0.0) {
+            continue;
+        }
+        var layer_color = vertex_color;
+        if ((terrain.textured_mask & (1u << i)) != 0u) {
+            layer_color = sample_layer(i, world_xz, ddx, ddy);
+        }
+        color += layer_color.rgb * weights[i];
+    }
+    pbr_input.material.base_color = vec4<f32>(color, vertex_color.a);
+#endif
+
+    pbr_input.material.base_color = alpha_discard(pbr_input.material, pbr_input.material.base_color);
+
+    var out: FragmentOutput;
+#ifdef PREPASS_PIPELINE
+    out = deferred_output(in, pbr_input);
+#else
+    out.color = apply_pbr_lighting(pbr_input);
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+#endif
+    return out;
+}
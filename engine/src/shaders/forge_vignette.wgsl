@@ -0,0 +1,28 @@
+//! Forge vignette post-process shader.
+//!
+//! Darkens (or tints) the screen toward its corners. Distance is measured from
+//! the screen center in UV space and normalized so the corners sit at 1.0;
+//! the effect starts at `radius` and ramps up over `smoothness`.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct VignetteEffect {
+    color: vec4<f32>,
+    intensity: f32,
+    radius: f32,
+    smoothness: f32,
+    padding: f32,
+};
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: VignetteEffect;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let scene = textureSample(screen_texture, screen_sampler, in.uv);
+    let distance_from_center = distance(in.uv, vec2<f32>(0.5)) * 1.41421356;
+    let edge = smoothstep(settings.radius, settings.radius + max(settings.smoothness, 0.0001), distance_from_center);
+    let amount = clamp(edge * settings.intensity, 0.0, 1.0) * settings.color.a;
+    return vec4<f32>(mix(scene.rgb, settings.color.rgb, amount), scene.a);
+}
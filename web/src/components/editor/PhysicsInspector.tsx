@@ -40,6 +40,8 @@ const COLLIDER_SHAPE_OPTIONS = [
   { value: 'ball', label: 'Ball' },
   { value: 'cylinder', label: 'Cylinder' },
   { value: 'capsule', label: 'Capsule' },
+  { value: 'convex_hull', label: 'Convex Hull' },
+  { value: 'trimesh', label: 'Mesh' },
 ];
 
 export function PhysicsInspector() {
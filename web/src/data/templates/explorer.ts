@@ -465,7 +465,7 @@ function onStart() {
   // Listen for orb collection
   const players = forge.scene.findByName("Player");
   if (players.length > 0) {
-    forge.physics.onCollisionEnter(players[0], (otherId) => {
+    forge.physics.onTriggerEnter(players[0], (otherId) => {
       const name = forge.scene.getEntityName(otherId) || "";
       if (name.startsWith("Orb_")) {
         const count = (forge.state.get("orbsCollected") || 0) + 1;
@@ -1121,7 +1121,7 @@ function onStart() {
   // Listen for collectible pickups on the player
   const players = forge.scene.findByName("Player");
   if (players.length > 0) {
-    forge.physics.onCollisionEnter(players[0], (otherId) => {
+    forge.physics.onTriggerEnter(players[0], (otherId) => {
       const name = forge.scene.getEntityName(otherId) || "";
       if (name.startsWith("Coin_")) {
         score++;
@@ -1151,7 +1151,7 @@ function onStart() {
   const goals = forge.scene.findByName("GoalFlag");
   const players = forge.scene.findByName("Player");
   if (goals.length > 0 && players.length > 0) {
-    forge.physics.onCollisionEnter(players[0], (otherId) => {
+    forge.physics.onTriggerEnter(players[0], (otherId) => {
       if (otherId === goals[0] && !hasWon) {
         const allCollected = forge.state.get("allCoinsCollected");
         if (allCollected) {
@@ -474,7 +474,7 @@ let hasWon = false;
 function onStart() {
   const players = forge.scene.findByName("Player");
   if (players.length > 0) {
-    forge.physics.onCollisionEnter(entityId, (otherId) => {
+    forge.physics.onTriggerEnter(entityId, (otherId) => {
       if (otherId === players[0] && !hasWon) {
         hasWon = true;
         forge.ui.showText("win", "PUZZLE SOLVED!", 30, 40, {
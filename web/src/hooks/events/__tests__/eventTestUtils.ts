@@ -97,6 +97,7 @@ export function createMockActions() {
     addNode: vi.fn(),
     removeNode: vi.fn(),
     updateNode: vi.fn(),
+    applyGraphPatch: vi.fn(),
     // State properties used by animation tests
     primaryId: null as string | null,
     skeletons2d: {} as Record<string, unknown>,
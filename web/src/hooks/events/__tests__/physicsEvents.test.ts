@@ -14,6 +14,7 @@ vi.mock('@/stores/editorStore', () => ({
 // Mock the script collision callback
 vi.mock('@/lib/scripting/useScriptRunner', () => ({
   getScriptCollisionCallback: vi.fn(),
+  getScriptTriggerCallback: vi.fn(),
 }));
 
 // Mock the audio manager
@@ -24,7 +25,7 @@ vi.mock('@/lib/audio/audioManager', () => ({
 }));
 
 import { useEditorStore } from '@/stores/editorStore';
-import { getScriptCollisionCallback } from '@/lib/scripting/useScriptRunner';
+import { getScriptCollisionCallback, getScriptTriggerCallback } from '@/lib/scripting/useScriptRunner';
 import { audioManager } from '@/lib/audio/audioManager';
 import { handlePhysicsEvent } from '../physicsEvents';
 
@@ -424,6 +425,37 @@ describe('handlePhysicsEvent', () => {
     });
   });
 
+  describe('TRIGGER_ENTERED / TRIGGER_EXITED', () => {
+    it('invokes script trigger callback with entered flag', () => {
+      const mockCallback = vi.fn();
+      vi.mocked(getScriptTriggerCallback).mockReturnValue(mockCallback);
+
+      const payload = { sensorEntityId: 'coin-1', intruderEntityId: 'player' };
+
+      expect(handlePhysicsEvent('TRIGGER_ENTERED', payload, mockSetGet.set, mockSetGet.get)).toBe(true);
+      expect(handlePhysicsEvent('TRIGGER_EXITED', payload, mockSetGet.set, mockSetGet.get)).toBe(true);
+
+      expect(mockCallback).toHaveBeenNthCalledWith(1, { ...payload, entered: true });
+      expect(mockCallback).toHaveBeenNthCalledWith(2, { ...payload, entered: false });
+    });
+
+    it('does not forward triggers to the collision callback', () => {
+      const collisionCallback = vi.fn();
+      vi.mocked(getScriptCollisionCallback).mockReturnValue(collisionCallback);
+      vi.mocked(getScriptTriggerCallback).mockReturnValue(null);
+
+      const result = handlePhysicsEvent(
+        'TRIGGER_ENTERED',
+        { sensorEntityId: 'coin-1', intruderEntityId: 'player' },
+        mockSetGet.set,
+        mockSetGet.get
+      );
+
+      expect(result).toBe(true);
+      expect(collisionCallback).not.toHaveBeenCalled();
+    });
+  });
+
   describe('RAYCAST_RESULT', () => {
     it('forwards non-occlusion raycast to script callback', () => {
       const mockRaycastCb = vi.fn();
@@ -18,6 +18,7 @@ vi.mock('@/stores/editorStore', () => ({
 
 vi.mock('@/lib/scripting/useScriptRunner', () => ({
   getScriptCollisionCallback: vi.fn(),
+  getScriptTriggerCallback: vi.fn(),
 }));
 
 vi.mock('@/lib/audio/audioManager', () => ({
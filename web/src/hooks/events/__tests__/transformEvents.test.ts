@@ -145,6 +145,39 @@ describe('handleTransformEvent', () => {
     });
   });
 
+  describe('SCENE_GRAPH_PATCH', () => {
+    const patch = {
+      upserted: [
+        { entityId: 'cube', name: 'Renamed', parentId: null, children: [], components: ['Mesh'], visible: true },
+      ],
+      removed: ['p1'],
+      rootIds: null,
+    };
+
+    it('calls applyGraphPatch with payload and marks scene as modified', () => {
+      const result = handleTransformEvent('SCENE_GRAPH_PATCH', patch, mockSetGet.set, mockSetGet.get);
+
+      expect(result).toBe(true);
+      expect(actions.applyGraphPatch).toHaveBeenCalledWith(patch);
+      expect(useEditorStore.setState).toHaveBeenCalledWith({ sceneModified: true });
+    });
+
+    it('calls recomputeLightState with the patched graph', () => {
+      const patchedGraph = {
+        nodes: { cube: patch.upserted[0] },
+        rootIds: ['cube'],
+      };
+      vi.mocked(useEditorStore.getState).mockReturnValue({
+        ...actions,
+        sceneGraph: patchedGraph,
+      } as unknown as StoreState);
+
+      handleTransformEvent('SCENE_GRAPH_PATCH', patch, mockSetGet.set, mockSetGet.get);
+
+      expect(actions.recomputeLightState).toHaveBeenCalledWith(patchedGraph);
+    });
+  });
+
   describe('SCENE_NODE_ADDED', () => {
     it('calls addNode with the node payload', () => {
       const node = {
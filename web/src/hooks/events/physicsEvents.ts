@@ -1,9 +1,9 @@
 /**
- * Event handlers for physics, joints, physics2d, collisions, raycasts.
+ * Event handlers for physics, joints, physics2d, collisions, triggers, raycasts.
  */
 
 import { useEditorStore, type PhysicsData, type JointData } from '@/stores/editorStore';
-import { getScriptCollisionCallback } from '@/lib/scripting/useScriptRunner';
+import { getScriptCollisionCallback, getScriptTriggerCallback } from '@/lib/scripting/useScriptRunner';
 import { audioManager } from '@/lib/audio/audioManager';
 import { castPayload, type SetFn, type GetFn } from './types';
 
@@ -75,6 +75,16 @@ export function handlePhysicsEvent(
       return true;
     }
 
+    case 'TRIGGER_ENTERED':
+    case 'TRIGGER_EXITED': {
+      const payload = castPayload<{ sensorEntityId: string; intruderEntityId: string }>(data);
+      const triggerCb = getScriptTriggerCallback();
+      if (triggerCb) {
+        triggerCb({ ...payload, entered: type === 'TRIGGER_ENTERED' });
+      }
+      return true;
+    }
+
     case 'RAYCAST_RESULT': {
       const payload = castPayload<{ requestId: string; hitEntity: string | null; point: [number, number, number]; distance: number }>(data);
       // Handle audio occlusion raycasts
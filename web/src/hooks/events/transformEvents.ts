@@ -2,7 +2,7 @@
  * Event handlers for transform/scene graph/selection/history/snap/mode.
  */
 
-import { useEditorStore, type SceneGraph, type TransformData, type SnapSettings, type CameraPreset, type CoordinateMode, type EngineMode } from '@/stores/editorStore';
+import { useEditorStore, type SceneGraph, type SceneGraphPatch, type TransformData, type SnapSettings, type CameraPreset, type CoordinateMode, type EngineMode } from '@/stores/editorStore';
 import { saveAutoSave } from '@/lib/sceneFile';
 import { setLastExportedScene } from '@/lib/storage/autoSave';
 import { invalidateSceneCache } from '@/lib/ai/cachedContext';
@@ -61,6 +61,16 @@ export function handleTransformEvent(
       return true;
     }
 
+    case 'SCENE_GRAPH_PATCH': {
+      const payload = castPayload<SceneGraphPatch>(data);
+      useEditorStore.getState().applyGraphPatch(payload);
+      useEditorStore.getState().recomputeLightState(useEditorStore.getState().sceneGraph);
+      useEditorStore.setState({ sceneModified: true });
+      scheduleAutoSave();
+      invalidateSceneCache();
+      return true;
+    }
+
     case 'SCENE_NODE_ADDED': {
       const node = castPayload<SceneNode>(data);
       useEditorStore.getState().addNode(node);
@@ -1205,6 +1205,38 @@ describe('scriptWorker', () => {
     expect(logs).toHaveLength(0);
   });
 
+  it('TRIGGER_EVENT fires trigger callbacks for the sensor and the intruder', async () => {
+    const handler = await setupWorker();
+    const code = `function onStart() {
+      forge.physics.onTriggerEnter("coin", function(otherId) { forge.log("sensor:" + otherId); });
+      forge.physics.onTriggerEnter("player", function(otherId) { forge.log("player:" + otherId); });
+      forge.physics.onCollisionEnter("player", function(otherId) { forge.log("collision:" + otherId); });
+    }`;
+
+    await handler(initMsg([{ entityId: 'player', enabled: true, source: code }]));
+    mockPostMessage.mockClear();
+
+    await handler({ data: { type: 'TRIGGER_EVENT', sensorEntityId: 'coin', intruderEntityId: 'player', entered: true } });
+
+    expect(mockPostMessage).toHaveBeenCalledWith(expect.objectContaining({ type: 'log', message: 'sensor:player' }));
+    expect(mockPostMessage).toHaveBeenCalledWith(expect.objectContaining({ type: 'log', message: 'player:coin' }));
+    expect(mockPostMessage).not.toHaveBeenCalledWith(expect.objectContaining({ message: 'collision:coin' }));
+  });
+
+  it('TRIGGER_EVENT fires exit callbacks', async () => {
+    const handler = await setupWorker();
+    const code = `function onStart() {
+      forge.physics.onTriggerExit("zone", function(otherId) { forge.log("left:" + otherId); });
+    }`;
+
+    await handler(initMsg([{ entityId: 'zone', enabled: true, source: code }]));
+    mockPostMessage.mockClear();
+
+    await handler({ data: { type: 'TRIGGER_EVENT', sensorEntityId: 'zone', intruderEntityId: 'player', entered: false } });
+
+    expect(mockPostMessage).toHaveBeenCalledWith(expect.objectContaining({ type: 'log', message: 'left:player' }));
+  });
+
   // ─── Command Limiting ──────────────────────────────────────────
 
   it('flushCommands truncates commands exceeding MAX_COMMANDS_PER_FRAME', async () => {
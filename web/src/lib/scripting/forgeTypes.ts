@@ -90,7 +90,11 @@ declare namespace forge {
     function onCollisionEnter(entityId: string, callback: (otherEntityId: string) => void): void;
     /** Register a callback for when this entity stops colliding with another (collision end) */
     function onCollisionExit(entityId: string, callback: (otherEntityId: string) => void): void;
-    /** Remove all collision callbacks for this entity */
+    /** Register a callback for when another body enters this sensor, or this body enters a sensor */
+    function onTriggerEnter(entityId: string, callback: (otherEntityId: string) => void): void;
+    /** Register a callback for when another body leaves this sensor, or this body leaves a sensor */
+    function onTriggerExit(entityId: string, callback: (otherEntityId: string) => void): void;
+    /** Remove all collision and trigger callbacks for this entity */
     function offCollision(entityId: string): void;
   }
 
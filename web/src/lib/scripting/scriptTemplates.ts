@@ -146,8 +146,8 @@ let invulnTimer = 0;
 
 function onStart() {
   forge.ui.showText("hp", "HP: 100 / 100", 5, 5, { fontSize: 20, color: "#00ff00" });
-  // Register collision callback for damage
-  forge.physics.onCollisionEnter(entityId, (otherId) => {
+  // Take damage from solid enemies and from hazard sensors alike
+  const onContact = (otherId) => {
     if (invulnerable) return;
     const name = forge.scene.getEntityName(otherId) || "";
     if (name.toLowerCase().includes("enemy") || name.toLowerCase().includes("hazard")) {
@@ -155,7 +155,9 @@ function onStart() {
       invulnerable = true;
       invulnTimer = 1.0; // 1 second invulnerability
     }
-  });
+  };
+  forge.physics.onCollisionEnter(entityId, onContact);
+  forge.physics.onTriggerEnter(entityId, onContact);
 }
 
 function onUpdate(dt) {
@@ -188,8 +190,8 @@ let collected = new Set();
 function onStart() {
   forge.ui.showText("score", "Score: 0", 85, 5, { fontSize: 22, color: "#ffdd00" });
   forge.ui.showText("info", "Collect the items!", 30, 90, { fontSize: 16, color: "#ffffff" });
-  // Register collision callback for pickups
-  forge.physics.onCollisionEnter(entityId, (otherId) => {
+  // Pickups are sensors, so they report trigger overlaps
+  forge.physics.onTriggerEnter(entityId, (otherId) => {
     if (collected.has(otherId)) return;
     const name = forge.scene.getEntityName(otherId) || "";
     if (name.toLowerCase().includes("coin") || name.toLowerCase().includes("collect") || name.toLowerCase().includes("pickup")) {
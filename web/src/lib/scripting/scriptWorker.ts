@@ -172,6 +172,10 @@ const _sharedState: Record<string, unknown> & { cameraMode: string; dialogueActi
 const collisionEnterCallbacks: Map<string, (otherId: string) => void> = new Map();
 const collisionExitCallbacks: Map<string, (otherId: string) => void> = new Map();
 
+// Trigger (sensor overlap) callback registries
+const triggerEnterCallbacks: Map<string, (otherId: string) => void> = new Map();
+const triggerExitCallbacks: Map<string, (otherId: string) => void> = new Map();
+
 function distanceBetween(a: [number, number, number], b: [number, number, number]): number {
   const dx = a[0] - b[0];
   const dy = a[1] - b[1];
@@ -358,6 +362,14 @@ function buildForgeApi(scriptEntityId: string) {
       offCollision: (eid: string) => {
         collisionEnterCallbacks.delete(eid);
         collisionExitCallbacks.delete(eid);
+        triggerEnterCallbacks.delete(eid);
+        triggerExitCallbacks.delete(eid);
+      },
+      onTriggerEnter: (eid: string, callback: (otherId: string) => void) => {
+        triggerEnterCallbacks.set(eid, callback);
+      },
+      onTriggerExit: (eid: string, callback: (otherId: string) => void) => {
+        triggerExitCallbacks.set(eid, callback);
       },
     },
     physics2d: {
@@ -1278,6 +1290,8 @@ self.onmessage = (e: MessageEvent) => {
       sharedState = {};
       collisionEnterCallbacks.clear();
       collisionExitCallbacks.clear();
+      triggerEnterCallbacks.clear();
+      triggerExitCallbacks.clear();
       // Send final UI clear
       (self as unknown as Worker).postMessage({ type: 'ui', elements: [] });
       break;
@@ -1309,6 +1323,24 @@ self.onmessage = (e: MessageEvent) => {
       break;
     }
 
+    case 'TRIGGER_EVENT': {
+      const { sensorEntityId, intruderEntityId, entered } = msg;
+      const callbacks = entered ? triggerEnterCallbacks : triggerExitCallbacks;
+      // Fire for both the sensor and the body that entered or left it
+      const pairs: Array<[string, string]> = [[sensorEntityId, intruderEntityId], [intruderEntityId, sensorEntityId]];
+      for (const [entityId, otherId] of pairs) {
+        const cb = callbacks.get(entityId);
+        if (!cb) continue;
+        try {
+          cb(otherId);
+        } catch (err) {
+          const msg_ = err instanceof Error ? err.message : String(err);
+          (self as unknown as Worker).postMessage({ type: 'error', entityId, line: 0, message: `Trigger callback error: ${msg_}` });
+        }
+      }
+      break;
+    }
+
     case 'scene_info': {
       (self as unknown as Record<string, unknown>).__currentScene = msg.currentScene;
       (self as unknown as Record<string, unknown>).__allSceneNames = msg.allSceneNames;
@@ -66,6 +66,13 @@ export function getScriptCollisionCallback() {
   return _scriptCollisionCallback;
 }
 
+// Module-level trigger callback for sensor overlaps
+let _scriptTriggerCallback: ((event: { sensorEntityId: string; intruderEntityId: string; entered: boolean }) => void) | null = null;
+
+export function getScriptTriggerCallback() {
+  return _scriptTriggerCallback;
+}
+
 interface ScriptRunnerOptions {
   wasmModule: {
     handle_command?: (command: string, payload: unknown) => unknown;
@@ -154,6 +161,7 @@ export function useScriptRunner({ wasmModule }: ScriptRunnerOptions) {
   const lastTickRef = useRef(0);
   const lastOcclusionCheckRef = useRef(0);
   const collisionEventCallbackRef = useRef<((event: { entityA: string; entityB: string; started: boolean }) => void) | null>(null);
+  const triggerEventCallbackRef = useRef<((event: { sensorEntityId: string; intruderEntityId: string; entered: boolean }) => void) | null>(null);
   const routerRef = useRef<AsyncChannelRouter | null>(null);
   const entityDeltaRef = useRef<DeltaSerializer | null>(null);
   const entityInfoDeltaRef = useRef<DeltaSerializer | null>(null);
@@ -559,6 +567,16 @@ export function useScriptRunner({ wasmModule }: ScriptRunnerOptions) {
         });
       };
 
+      // Set up trigger event callback
+      triggerEventCallbackRef.current = (event: { sensorEntityId: string; intruderEntityId: string; entered: boolean }) => {
+        worker.postMessage({
+          type: 'TRIGGER_EVENT',
+          sensorEntityId: event.sensorEntityId,
+          intruderEntityId: event.intruderEntityId,
+          entered: event.entered,
+        });
+      };
+
       workerRef.current = worker;
     }
 
@@ -566,6 +584,7 @@ export function useScriptRunner({ wasmModule }: ScriptRunnerOptions) {
     if (engineMode === 'edit' && workerRef.current) {
       setPlayTickCallback(null);
       collisionEventCallbackRef.current = null;
+      triggerEventCallbackRef.current = null;
       if (watchdogRef.current) {
         clearTimeout(watchdogRef.current);
         watchdogRef.current = null;
@@ -591,13 +610,18 @@ export function useScriptRunner({ wasmModule }: ScriptRunnerOptions) {
     }
   }, [engineMode, wasmModule, dispatchCommand, addScriptLog]);
 
-  // Export collision callback via module-level variable (not window global)
+  // Export collision and trigger callbacks via module-level variables (not window globals)
   useEffect(() => {
     if (engineMode === 'play' && collisionEventCallbackRef.current) {
       _scriptCollisionCallback = collisionEventCallbackRef.current;
     } else {
       _scriptCollisionCallback = null;
     }
+    if (engineMode === 'play' && triggerEventCallbackRef.current) {
+      _scriptTriggerCallback = triggerEventCallbackRef.current;
+    } else {
+      _scriptTriggerCallback = null;
+    }
   }, [engineMode]);
 
   // Cleanup on unmount
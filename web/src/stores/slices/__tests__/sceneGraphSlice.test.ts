@@ -2,7 +2,7 @@ import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
 import { create } from 'zustand';
 import { createMockDispatch } from './sliceTestTemplate';
 import { createSceneGraphSlice, setSceneGraphDispatcher, type SceneGraphSlice } from '../sceneGraphSlice';
-import type { SceneGraph, SceneNode } from '../types';
+import type { SceneGraph, SceneGraphPatch, SceneNode } from '../types';
 
 // SceneGraphSlice depends on external state (selectedIds, primaryId, etc.)
 // so we compose a test store with the required extra fields.
@@ -241,6 +241,58 @@ describe('sceneGraphSlice', () => {
     });
   });
 
+  describe('applyGraphPatch', () => {
+    it('should replace upserted nodes and keep the rest', () => {
+      store.getState().setFullGraph(mockGraph);
+      const patch: SceneGraphPatch = {
+        upserted: [{ ...mockGraph.nodes['cam-1'], name: 'MainCamera' }],
+        removed: [],
+        rootIds: null,
+      };
+      store.getState().applyGraphPatch(patch);
+
+      const { sceneGraph, nodeCount } = store.getState();
+      expect(sceneGraph.nodes['cam-1'].name).toBe('MainCamera');
+      expect(sceneGraph.nodes['cube-1']).toEqual(mockGraph.nodes['cube-1']);
+      expect(sceneGraph.rootIds).toEqual(['cam-1', 'cube-1']);
+      expect(nodeCount).toBe(3);
+    });
+
+    it('should add new nodes along with their updated parent', () => {
+      store.getState().setFullGraph(mockGraph);
+      const light: SceneNode = {
+        entityId: 'light-1', name: 'Light', parentId: 'cube-1', children: [], components: ['PointLight'], visible: true,
+      };
+      store.getState().applyGraphPatch({
+        upserted: [light, { ...mockGraph.nodes['cube-1'], children: ['sphere-1', 'light-1'] }],
+        removed: [],
+        rootIds: null,
+      });
+
+      const { sceneGraph, nodeCount } = store.getState();
+      expect(sceneGraph.nodes['light-1']).toEqual(light);
+      expect(sceneGraph.nodes['cube-1'].children).toEqual(['sphere-1', 'light-1']);
+      expect(nodeCount).toBe(4);
+    });
+
+    it('should remove nodes and drop them from rootIds when no new order is sent', () => {
+      store.getState().setFullGraph(mockGraph);
+      store.getState().applyGraphPatch({ upserted: [], removed: ['cam-1'], rootIds: null });
+
+      const { sceneGraph, nodeCount } = store.getState();
+      expect(sceneGraph.nodes['cam-1']).toBeUndefined();
+      expect(sceneGraph.rootIds).toEqual(['cube-1']);
+      expect(nodeCount).toBe(2);
+    });
+
+    it('should use the new root order when one is sent', () => {
+      store.getState().setFullGraph(mockGraph);
+      store.getState().applyGraphPatch({ upserted: [], removed: [], rootIds: ['cube-1', 'cam-1'] });
+
+      expect(store.getState().sceneGraph.rootIds).toEqual(['cube-1', 'cam-1']);
+    });
+  });
+
   describe('addNode', () => {
     const newRootNode: SceneNode = {
       entityId: 'light-1',
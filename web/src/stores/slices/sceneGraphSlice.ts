@@ -7,7 +7,7 @@
  */
 
 import { StateCreator } from 'zustand';
-import type { SceneGraph, SceneNode, EntityType } from './types';
+import type { SceneGraph, SceneGraphPatch, SceneNode, EntityType } from './types';
 
 /** Partial node properties that may be changed in-place. */
 export interface SceneNodeChanges {
@@ -29,6 +29,8 @@ export interface SceneGraphSlice {
   setFullGraph: (graph: SceneGraph) => void;
   /** @deprecated Use setFullGraph for full replacement. Kept for backward compatibility. */
   updateSceneGraph: (graph: SceneGraph) => void;
+  /** Apply an engine-computed diff. Upserted nodes replace their entries whole. O(patch). */
+  applyGraphPatch: (patch: SceneGraphPatch) => void;
 
   // Incremental operations — O(1) per call
   /** Insert a new node. Attaches to parent's children list and, if root, to rootIds. */
@@ -85,6 +87,23 @@ export const createSceneGraphSlice: StateCreator<
     set({ sceneGraph: graph, nodeCount: Object.keys(graph.nodes).length });
   },
 
+  applyGraphPatch: (patch) => {
+    const { sceneGraph } = get();
+    const newNodes = { ...sceneGraph.nodes };
+    for (const node of patch.upserted) {
+      newNodes[node.entityId] = node;
+    }
+    for (const entityId of patch.removed) {
+      delete newNodes[entityId];
+    }
+
+    // The engine only sends rootIds when the root order changed
+    const newRootIds =
+      patch.rootIds ?? sceneGraph.rootIds.filter((id) => newNodes[id] !== undefined);
+
+    set({ sceneGraph: { nodes: newNodes, rootIds: newRootIds }, nodeCount: Object.keys(newNodes).length });
+  },
+
   // ---------------------------------------------------------------------------
   // Incremental operations
   // ---------------------------------------------------------------------------
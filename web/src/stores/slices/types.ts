@@ -19,6 +19,14 @@ export interface SceneGraph {
   rootIds: string[];
 }
 
+// Changes between two scene graphs, matching Rust's SceneGraphPatch struct
+export interface SceneGraphPatch {
+  upserted: SceneNode[];
+  removed: string[];
+  /** The new root order, or null when it did not change. */
+  rootIds: string[] | null;
+}
+
 // Transform data for an entity
 export interface TransformData {
   entityId: string;
@@ -324,7 +332,7 @@ export interface EnvironmentData {
 // Physics data matching Rust's PhysicsData struct
 export interface PhysicsData {
   bodyType: 'dynamic' | 'fixed' | 'kinematic_position' | 'kinematic_velocity';
-  colliderShape: 'cuboid' | 'ball' | 'cylinder' | 'capsule' | 'auto';
+  colliderShape: 'cuboid' | 'ball' | 'cylinder' | 'capsule' | 'auto' | 'convex_hull' | 'trimesh';
   restitution: number;
   friction: number;
   density: number;